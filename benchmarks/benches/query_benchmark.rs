@@ -0,0 +1,96 @@
+use {
+    criterion::{criterion_group, criterion_main, Criterion},
+    futures::executor::block_on,
+    gluesql_benchmarks::{bulk_insert_sql, index_sql, setup_sqls, QUERIES},
+    gluesql_core::{
+        prelude::Glue,
+        store::{GStore, GStoreMut},
+    },
+    memory_storage::MemoryStorage,
+    sled_storage::SledStorage,
+};
+
+fn bench_glue<T: GStore + GStoreMut>(
+    c: &mut Criterion,
+    storage_name: &str,
+    storage: T,
+    supports_index: bool,
+) {
+    let mut glue = Glue::new(storage);
+    for sql in setup_sqls() {
+        block_on(glue.execute(&sql)).unwrap();
+    }
+
+    // MemoryStorage has no index support, so `range_select` runs as a scan there
+    if supports_index {
+        block_on(glue.execute(index_sql())).unwrap();
+    }
+
+    for (name, sql) in QUERIES {
+        c.bench_function(&format!("{name}/{storage_name}"), |b| {
+            b.iter(|| block_on(glue.execute(sql)).unwrap())
+        });
+    }
+
+    // Rows are appended to a table without a primary key, so every iteration inserts the same rows
+    let sql = bulk_insert_sql();
+    c.bench_function(&format!("bulk_insert/{storage_name}"), |b| {
+        b.iter(|| block_on(glue.execute(&sql)).unwrap())
+    });
+}
+
+pub fn bench_memory(c: &mut Criterion) {
+    bench_glue(c, "memory", MemoryStorage::default(), false);
+}
+
+pub fn bench_sled(c: &mut Criterion) {
+    let path = "data/query_benchmark";
+
+    // Silently ignore, the directory is usually removed by the temporary config
+    let _ = std::fs::remove_dir_all(path);
+
+    let config = sled::Config::default()
+        .path(path)
+        .temporary(true)
+        .mode(sled::Mode::HighThroughput);
+    let storage = SledStorage::try_from(config).unwrap();
+
+    bench_glue(c, "sled", storage, true);
+}
+
+#[cfg(feature = "compare_with_sqlite")]
+pub fn bench_sqlite(c: &mut Criterion) {
+    let connection = rusqlite::Connection::open_in_memory().unwrap();
+    for sql in setup_sqls() {
+        connection.execute_batch(&sql).unwrap();
+    }
+    connection.execute_batch(index_sql()).unwrap();
+
+    for (name, sql) in QUERIES {
+        let mut statement = connection.prepare(sql).unwrap();
+        let column_count = statement.column_count();
+
+        c.bench_function(&format!("{name}/sqlite"), |b| {
+            b.iter(|| {
+                // Reads every value, as GlueSQL materializes every value of the result rows
+                let mut rows = statement.query([]).unwrap();
+                while let Some(row) = rows.next().unwrap() {
+                    for index in 0..column_count {
+                        criterion::black_box(row.get_ref(index).unwrap());
+                    }
+                }
+            })
+        });
+    }
+
+    let sql = bulk_insert_sql();
+    c.bench_function("bulk_insert/sqlite", |b| {
+        b.iter(|| connection.execute_batch(&sql).unwrap())
+    });
+}
+
+#[cfg(not(feature = "compare_with_sqlite"))]
+criterion_group!(benches, bench_memory, bench_sled);
+#[cfg(feature = "compare_with_sqlite")]
+criterion_group!(benches, bench_memory, bench_sled, bench_sqlite);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+//! Shared data set and queries of the GlueSQL benchmarks.
+//!
+//! Every statement here is valid in both GlueSQL and SQLite, so the `compare_with_sqlite`
+//! feature can run the same queries against rusqlite.
+
+pub const ITEM_SIZE: usize = 5000;
+pub const CATEGORY_SIZE: usize = 10;
+pub const BULK_SIZE: usize = 100;
+
+/// `(name, sql)` pairs of the read-only queries, run against the data set of [`setup_sqls`].
+pub const QUERIES: [(&str, &str); 5] = [
+    ("point_select", "SELECT * FROM Item WHERE id = 2500"),
+    ("range_select", "SELECT * FROM Item WHERE price < 50"),
+    ("scan_filter", "SELECT * FROM Item WHERE name LIKE '%77%'"),
+    (
+        "group_by",
+        "SELECT category, COUNT(*), SUM(price) FROM Item GROUP BY category",
+    ),
+    (
+        "join",
+        "SELECT Item.name, Category.name FROM Item JOIN Category ON Item.category = Category.id WHERE Item.price < 100",
+    ),
+];
+
+/// Statements which create and fill the `Item`, `Category` and `Bulk` tables.
+pub fn setup_sqls() -> Vec<String> {
+    let categories = (0..CATEGORY_SIZE)
+        .map(|id| format!("({id}, 'Category {id}')"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let items = (0..ITEM_SIZE)
+        .map(|id| {
+            let category = id % CATEGORY_SIZE;
+            let price = id * 7 % 1000;
+
+            format!("({id}, {category}, {price}, 'Item {id}')")
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    vec![
+        "CREATE TABLE Category (id INTEGER PRIMARY KEY, name TEXT)".to_owned(),
+        "CREATE TABLE Item (id INTEGER PRIMARY KEY, category INTEGER, price INTEGER, name TEXT)"
+            .to_owned(),
+        "CREATE TABLE Bulk (id INTEGER, name TEXT)".to_owned(),
+        format!("INSERT INTO Category VALUES {categories}"),
+        format!("INSERT INTO Item VALUES {items}"),
+    ]
+}
+
+/// Index used by `range_select`, created only on storages which support indexes.
+pub fn index_sql() -> &'static str {
+    "CREATE INDEX idx_price ON Item (price)"
+}
+
+/// Statement inserting [`BULK_SIZE`] rows at once into the `Bulk` table.
+pub fn bulk_insert_sql() -> String {
+    let rows = (0..BULK_SIZE)
+        .map(|id| format!("({id}, 'Bulk {id}')"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("INSERT INTO Bulk VALUES {rows}")
+}
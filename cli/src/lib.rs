@@ -147,11 +147,13 @@ pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()
                     table_name: schema.table_name.clone(),
                     columns: Vec::new(),
                     source: gluesql_core::ast::Query {
+                        with: Vec::new(),
                         body: SetExpr::Values(Values(exprs_list)),
                         order_by: Vec::new(),
                         limit: None,
                         offset: None,
                     },
+                    on_conflict: None,
                 }
                 .to_sql();
 
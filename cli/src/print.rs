@@ -106,12 +106,18 @@ impl<'a, W: Write> Print<W> {
             Payload::Create => self.write("Table created")?,
             Payload::DropTable => self.write("Table dropped")?,
             Payload::DropFunction => self.write("Function dropped")?,
+            Payload::DropSequence => self.write("Sequence dropped")?,
+            Payload::DropType => self.write("Type dropped")?,
+            Payload::DropView => self.write("View dropped")?,
             Payload::AlterTable => self.write("Table altered")?,
+            Payload::AlterType => self.write("Type altered")?,
             Payload::CreateIndex => self.write("Index created")?,
             Payload::DropIndex => self.write("Index dropped")?,
+            Payload::Analyze => self.write("Table analyzed")?,
             Payload::Commit => self.write("Commit completed")?,
             Payload::Rollback => self.write("Rollback completed")?,
             Payload::StartTransaction => self.write("Transaction started")?,
+            Payload::SetTransaction => self.write("Transaction mode set")?,
             Payload::Insert(n) => affected(*n, "inserted")?,
             Payload::Delete(n) => affected(*n, "deleted")?,
             Payload::Update(n) => affected(*n, "updated")?,
@@ -393,10 +399,16 @@ mod tests {
         test!(Payload::AlterTable, "Table altered");
         test!(Payload::CreateIndex, "Index created");
         test!(Payload::DropIndex, "Index dropped");
+        test!(Payload::Analyze, "Table analyzed");
         test!(Payload::DropFunction, "Function dropped");
+        test!(Payload::DropSequence, "Sequence dropped");
+        test!(Payload::DropType, "Type dropped");
+        test!(Payload::AlterType, "Type altered");
+        test!(Payload::DropView, "View dropped");
         test!(Payload::Commit, "Commit completed");
         test!(Payload::Rollback, "Rollback completed");
         test!(Payload::StartTransaction, "Transaction started");
+        test!(Payload::SetTransaction, "Transaction mode set");
         test!(Payload::Insert(0), "0 row inserted");
         test!(Payload::Insert(1), "1 row inserted");
         test!(Payload::Insert(7), "7 rows inserted");
@@ -1,9 +1,10 @@
 use {
     serde::{Deserialize, Serialize},
-    strum_macros::Display,
+    std::fmt,
+    strum_macros::AsRefStr,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, AsRefStr)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum DataType {
     Boolean,
@@ -24,6 +25,8 @@ pub enum DataType {
     Inet,
     Date,
     Timestamp,
+    #[strum(to_string = "TIMESTAMPTZ")]
+    TimestampTz,
     Time,
     Interval,
     Uuid,
@@ -31,4 +34,42 @@ pub enum DataType {
     List,
     Decimal,
     Point,
+    /// Text restricted to the listed variants, `ENUM('happy', 'sad')`, held in `Value::Enum`
+    Enum(Vec<String>),
+    /// Enum type created with `CREATE TYPE`, referred to by its name
+    UserDefined(String),
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Enum(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| format!("'{}'", variant.replace('\'', "''")))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(f, "ENUM({variants})")
+            }
+            DataType::UserDefined(name) => f.write_str(name),
+            _ => f.write_str(self.as_ref()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DataType;
+
+    #[test]
+    fn display() {
+        assert_eq!(DataType::Int.to_string(), "INT");
+        assert_eq!(DataType::TimestampTz.to_string(), "TIMESTAMPTZ");
+        assert_eq!(
+            DataType::Enum(vec!["happy".to_owned(), "it's ok".to_owned()]).to_string(),
+            "ENUM('happy', 'it''s ok')"
+        );
+        assert_eq!(DataType::UserDefined("mood".to_owned()).to_string(), "mood");
+    }
 }
@@ -12,6 +12,8 @@ pub enum AlterTableOperation {
     DropColumn {
         column_name: String,
         if_exists: bool,
+        /// Drops the indexes which depend on the column as well
+        cascade: bool,
     },
     /// `RENAME [ COLUMN ] <old_column_name> TO <new_column_name>`
     RenameColumn {
@@ -20,6 +22,29 @@ pub enum AlterTableOperation {
     },
     /// `RENAME TO <table_name>`
     RenameTable { table_name: String },
+    /// `ALTER [ COLUMN ] <column_name> <operation>`
+    AlterColumn {
+        column_name: String,
+        operation: AlterColumnOperation,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AlterColumnOperation {
+    /// `SET NOT NULL`
+    SetNotNull,
+    /// `DROP NOT NULL`
+    DropNotNull,
+    /// `SET DEFAULT <expr>`
+    SetDefault(Expr),
+    /// `DROP DEFAULT`
+    DropDefault,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AlterTypeOperation {
+    /// `ADD VALUE [ IF NOT EXISTS ] '<value>'`
+    AddValue { value: String, if_not_exists: bool },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -33,6 +58,34 @@ pub struct ColumnDef {
     pub unique: Option<ColumnUniqueOption>,
 }
 
+/// `[ CONSTRAINT <name> ] CHECK (<expr>)` of a column or of a table,
+/// a row violates it when `expr` evaluates to `FALSE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CheckConstraint {
+    pub name: String,
+    pub expr: Expr,
+}
+
+/// `[ CONSTRAINT <name> ] FOREIGN KEY (<column>) REFERENCES <table> (<column>) [ ON DELETE <action> ]`
+/// of a column or of a table
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ForeignKey {
+    pub name: String,
+    pub column_name: String,
+    pub referenced_table_name: String,
+    pub referenced_column_name: String,
+    pub on_delete: ReferentialAction,
+}
+
+/// Action taken on the referencing rows when the referenced row is deleted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ColumnUniqueOption {
     pub is_primary: bool,
@@ -46,6 +99,17 @@ pub struct OperateFunctionArg {
     pub default: Option<Expr>,
 }
 
+/// `[ START [ WITH ] <expr> ] [ INCREMENT [ BY ] <expr> ] [ MINVALUE <expr> ] [ MAXVALUE <expr> ]
+/// [ [ NO ] CYCLE ]` of `CREATE SEQUENCE`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SequenceOptions {
+    pub start: Option<Expr>,
+    pub increment: Option<Expr>,
+    pub min_value: Option<Expr>,
+    pub max_value: Option<Expr>,
+    pub cycle: bool,
+}
+
 impl ToSql for AlterTableOperation {
     fn to_sql(&self) -> String {
         match self {
@@ -55,10 +119,13 @@ impl ToSql for AlterTableOperation {
             AlterTableOperation::DropColumn {
                 column_name,
                 if_exists,
-            } => match if_exists {
-                true => format!(r#"DROP COLUMN IF EXISTS "{column_name}""#),
-                false => format!(r#"DROP COLUMN "{column_name}""#),
-            },
+                cascade,
+            } => {
+                let if_exists = if *if_exists { "IF EXISTS " } else { "" };
+                let cascade = if *cascade { " CASCADE" } else { "" };
+
+                format!(r#"DROP COLUMN {if_exists}"{column_name}"{cascade}"#)
+            }
             AlterTableOperation::RenameColumn {
                 old_column_name,
                 new_column_name,
@@ -66,6 +133,37 @@ impl ToSql for AlterTableOperation {
             AlterTableOperation::RenameTable { table_name } => {
                 format!(r#"RENAME TO "{table_name}""#)
             }
+            AlterTableOperation::AlterColumn {
+                column_name,
+                operation,
+            } => format!(r#"ALTER COLUMN "{column_name}" {}"#, operation.to_sql()),
+        }
+    }
+}
+
+impl ToSql for AlterTypeOperation {
+    fn to_sql(&self) -> String {
+        match self {
+            AlterTypeOperation::AddValue {
+                value,
+                if_not_exists,
+            } => {
+                let if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" };
+                let value = value.replace('\'', "''");
+
+                format!("ADD VALUE {if_not_exists}'{value}'")
+            }
+        }
+    }
+}
+
+impl ToSql for AlterColumnOperation {
+    fn to_sql(&self) -> String {
+        match self {
+            AlterColumnOperation::SetNotNull => "SET NOT NULL".to_owned(),
+            AlterColumnOperation::DropNotNull => "DROP NOT NULL".to_owned(),
+            AlterColumnOperation::SetDefault(expr) => format!("SET DEFAULT {}", expr.to_sql()),
+            AlterColumnOperation::DropDefault => "DROP DEFAULT".to_owned(),
         }
     }
 }
@@ -110,6 +208,47 @@ impl ToSql for ColumnUniqueOption {
     }
 }
 
+impl ToSql for CheckConstraint {
+    fn to_sql(&self) -> String {
+        let CheckConstraint { name, expr } = self;
+
+        format!(r#"CONSTRAINT "{name}" CHECK ({})"#, expr.to_sql())
+    }
+}
+
+impl ToSql for ForeignKey {
+    fn to_sql(&self) -> String {
+        let ForeignKey {
+            name,
+            column_name,
+            referenced_table_name,
+            referenced_column_name,
+            on_delete,
+        } = self;
+
+        let foreign_key = format!(
+            r#"CONSTRAINT "{name}" FOREIGN KEY ("{column_name}") REFERENCES "{referenced_table_name}" ("{referenced_column_name}")"#
+        );
+
+        match on_delete {
+            ReferentialAction::NoAction => foreign_key,
+            on_delete => format!("{foreign_key} ON DELETE {}", on_delete.to_sql()),
+        }
+    }
+}
+
+impl ToSql for ReferentialAction {
+    fn to_sql(&self) -> String {
+        match self {
+            ReferentialAction::NoAction => "NO ACTION",
+            ReferentialAction::Restrict => "RESTRICT",
+            ReferentialAction::Cascade => "CASCADE",
+            ReferentialAction::SetNull => "SET NULL",
+        }
+        .to_owned()
+    }
+}
+
 impl ToSql for OperateFunctionArg {
     fn to_sql(&self) -> String {
         let OperateFunctionArg {
@@ -125,6 +264,34 @@ impl ToSql for OperateFunctionArg {
     }
 }
 
+impl ToSql for SequenceOptions {
+    fn to_sql(&self) -> String {
+        let SequenceOptions {
+            start,
+            increment,
+            min_value,
+            max_value,
+            cycle,
+        } = self;
+        let option = |keyword: &str, expr: &Option<Expr>| {
+            expr.as_ref()
+                .map(|expr| format!("{keyword} {}", expr.to_sql()))
+        };
+
+        [
+            option("START WITH", start),
+            option("INCREMENT BY", increment),
+            option("MINVALUE", min_value),
+            option("MAXVALUE", max_value),
+            cycle.then(|| "CYCLE".to_owned()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::{
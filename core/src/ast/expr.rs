@@ -1,7 +1,7 @@
 use {
     super::{
-        Aggregate, AstLiteral, BinaryOperator, DataType, DateTimeField, Function, Query, ToSql,
-        ToSqlUnquoted, UnaryOperator,
+        Aggregate, AstLiteral, BinaryOperator, DataType, DateTimeField, Function, GroupByModifier,
+        Query, ToSql, ToSqlUnquoted, UnaryOperator, Window,
     },
     serde::{Deserialize, Serialize},
 };
@@ -41,6 +41,11 @@ pub enum Expr {
         negated: bool,
         pattern: Box<Expr>,
     },
+    SimilarTo {
+        expr: Box<Expr>,
+        negated: bool,
+        pattern: Box<Expr>,
+    },
     BinaryOp {
         left: Box<Expr>,
         op: BinaryOperator,
@@ -58,6 +63,7 @@ pub enum Expr {
     },
     Function(Box<Function>),
     Aggregate(Box<Aggregate>),
+    Window(Box<Window>),
     Exists {
         subquery: Box<Query>,
         negated: bool,
@@ -72,11 +78,23 @@ pub enum Expr {
         obj: Box<Expr>,
         indexes: Vec<Expr>,
     },
+    /// Row constructor, `(a, b)` or `ROW(a, b)`
+    Tuple(Vec<Expr>),
+    /// Array constructor, `ARRAY[a, b]`
+    Array {
+        elem: Vec<Expr>,
+    },
     Interval {
         expr: Box<Expr>,
         leading_field: Option<DateTimeField>,
         last_field: Option<DateTimeField>,
     },
+    /// `DEFAULT` used as a value of `INSERT` or `UPDATE`
+    Default,
+    /// Parameter of a prepared statement, `$1` or the first `?` is `Parameter(1)`
+    Parameter(usize),
+    /// `ROLLUP`, `CUBE` or `GROUPING SETS` used as an item of `GROUP BY`
+    GroupByModifier(Box<GroupByModifier>),
 }
 
 impl ToSql for Expr {
@@ -170,6 +188,19 @@ impl Expr {
                     false => format!("{expr} ILIKE {pattern}"),
                 }
             }
+            Expr::SimilarTo {
+                expr,
+                negated,
+                pattern,
+            } => {
+                let expr = expr.to_sql_with(quoted);
+                let pattern = pattern.to_sql_with(quoted);
+
+                match negated {
+                    true => format!("{expr} NOT SIMILAR TO {pattern}"),
+                    false => format!("{expr} SIMILAR TO {pattern}"),
+                }
+            }
             Expr::UnaryOp { op, expr } => match op {
                 UnaryOperator::Factorial => {
                     format!("{}{}", expr.to_sql_with(quoted), op.to_sql())
@@ -213,6 +244,7 @@ impl Expr {
                 }
             }
             Expr::Aggregate(a) => a.to_sql(),
+            Expr::Window(window) => window.to_sql(),
             Expr::Function(func) => func.to_sql(),
             Expr::InSubquery {
                 expr,
@@ -239,6 +271,24 @@ impl Expr {
                     .join("");
                 format!("{obj}{indexes}")
             }
+            Expr::Tuple(exprs) => {
+                let exprs = exprs
+                    .iter()
+                    .map(|expr| expr.to_sql_with(quoted))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("({exprs})")
+            }
+            Expr::Array { elem } => {
+                let elem = elem
+                    .iter()
+                    .map(|expr| expr.to_sql_with(quoted))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("ARRAY[{elem}]")
+            }
             Expr::Subquery(query) => format!("({})", query.to_sql()),
             Expr::Interval {
                 expr,
@@ -256,6 +306,9 @@ impl Expr {
                     None => format!("INTERVAL {expr} {leading_field}"),
                 }
             }
+            Expr::Default => "DEFAULT".to_owned(),
+            Expr::Parameter(index) => format!("${index}"),
+            Expr::GroupByModifier(modifier) => modifier.to_sql(),
         }
     }
 }
@@ -398,6 +451,26 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#""id" SIMILAR TO '(a|b)%'"#,
+            Expr::SimilarTo {
+                expr: Box::new(Expr::Identifier("id".to_owned())),
+                negated: false,
+                pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("(a|b)%".to_owned()))),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#""id" NOT SIMILAR TO '(a|b)%'"#,
+            Expr::SimilarTo {
+                expr: Box::new(Expr::Identifier("id".to_owned())),
+                negated: true,
+                pattern: Box::new(Expr::Literal(AstLiteral::QuotedString("(a|b)%".to_owned()))),
+            }
+            .to_sql()
+        );
+
         assert_eq!(
             r#""id" IN ('a', 'b', 'c')"#,
             Expr::InList {
@@ -431,6 +504,7 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: Vec::new(),
                     body: SetExpr::Select(Box::new(Select {
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
@@ -459,6 +533,7 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: Vec::new(),
                     body: SetExpr::Select(Box::new(Select {
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
@@ -486,6 +561,7 @@ mod tests {
             r#"EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: Vec::new(),
                     body: SetExpr::Select(Box::new(Select {
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
@@ -513,6 +589,7 @@ mod tests {
             r#"NOT EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: Vec::new(),
                     body: SetExpr::Select(Box::new(Select {
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
@@ -539,6 +616,7 @@ mod tests {
         assert_eq!(
             r#"(SELECT * FROM "FOO")"#,
             Expr::Subquery(Box::new(Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(Select {
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
@@ -659,6 +737,28 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"("id", 2)"#,
+            Expr::Tuple(vec![
+                Expr::Identifier("id".to_owned()),
+                Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap()))
+            ])
+            .to_sql()
+        );
+
+        assert_eq!("$2", Expr::Parameter(2).to_sql());
+
+        assert_eq!(
+            r#"ARRAY["id", 2]"#,
+            Expr::Array {
+                elem: vec![
+                    Expr::Identifier("id".to_owned()),
+                    Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap()))
+                ]
+            }
+            .to_sql()
+        );
+
         assert_eq!(
             r#"INTERVAL "col1" + 3 DAY"#,
             &Expr::Interval {
@@ -1,6 +1,7 @@
 use {
-    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr},
+    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr, OrderByExpr},
     crate::ast::ToSql,
+    itertools::Itertools,
     serde::{Deserialize, Serialize},
     strum_macros::Display,
 };
@@ -37,6 +38,10 @@ pub enum Function {
         expr: Expr,
         data_type: DataType,
     },
+    TryCast {
+        expr: Expr,
+        data_type: DataType,
+    },
     Ceil(Expr),
     Concat(Vec<Expr>),
     ConcatWs {
@@ -51,8 +56,16 @@ pub enum Function {
         expr: Expr,
         then: Expr,
     },
+    Coalesce(Vec<Expr>),
+    NullIf {
+        expr1: Expr,
+        expr2: Expr,
+    },
     Rand(Option<Expr>),
-    Round(Expr),
+    Round {
+        expr: Expr,
+        scale: Option<Expr>,
+    },
     Floor(Expr),
     Trim {
         expr: Expr,
@@ -64,6 +77,10 @@ pub enum Function {
         field: DateTimeField,
         expr: Expr,
     },
+    AtTimeZone {
+        expr: Expr,
+        time_zone: String,
+    },
     Ln(Expr),
     Log {
         antilog: Expr,
@@ -122,7 +139,19 @@ pub enum Function {
         expr: Expr,
         selector: Expr,
     },
+    JsonExtract {
+        expr: Expr,
+        path: Expr,
+    },
     GenerateUuid(),
+    UuidToBin {
+        expr: Expr,
+        swap: Option<Expr>,
+    },
+    BinToUuid {
+        expr: Expr,
+        swap: Option<Expr>,
+    },
     Format {
         expr: Expr,
         format: Expr,
@@ -148,9 +177,27 @@ pub enum Function {
         sub_expr: Expr,
         start: Option<Expr>,
     },
+    SplitPart {
+        expr: Expr,
+        delimiter: Expr,
+        index: Expr,
+    },
+    StringToArray {
+        expr: Expr,
+        delimiter: Expr,
+    },
     Ascii(Expr),
     Chr(Expr),
     Md5(Expr),
+    OctetLength(Expr),
+    Encode {
+        expr: Expr,
+        format: Expr,
+    },
+    Decode {
+        expr: Expr,
+        format: Expr,
+    },
     Append {
         expr: Expr,
         value: Expr,
@@ -159,6 +206,19 @@ pub enum Function {
         expr: Expr,
         value: Expr,
     },
+    ArrayRemove {
+        expr: Expr,
+        value: Expr,
+    },
+    ArrayRemoveNulls(Expr),
+    ArrayLength(Expr),
+    ArrayReplace {
+        expr: Expr,
+        from: Expr,
+        to: Expr,
+    },
+    Greatest(Vec<Expr>),
+    Least(Vec<Expr>),
     GetX(Expr),
     GetY(Expr),
     Point {
@@ -169,6 +229,14 @@ pub enum Function {
         geometry1: Expr,
         geometry2: Expr,
     },
+    /// Replaced with a constant by the planner in queries with `GROUP BY`
+    Grouping(Vec<Expr>),
+    Nextval(Expr),
+    Currval(Expr),
+    Setval {
+        name: Expr,
+        value: Expr,
+    },
 }
 
 impl ToSql for Function {
@@ -206,6 +274,9 @@ impl ToSql for Function {
             Function::Cast { expr, data_type } => {
                 format!("CAST({} AS {data_type})", expr.to_sql())
             }
+            Function::TryCast { expr, data_type } => {
+                format!("TRY_CAST({} AS {data_type})", expr.to_sql())
+            }
             Function::Ceil(e) => format!("CEIL({})", e.to_sql()),
             Function::Concat(items) => {
                 let items = items
@@ -234,11 +305,25 @@ impl ToSql for Function {
             Function::IfNull { expr, then } => {
                 format!("IFNULL({}, {})", expr.to_sql(), then.to_sql())
             }
+            Function::Coalesce(items) => {
+                let items = items
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("COALESCE({items})")
+            }
+            Function::NullIf { expr1, expr2 } => {
+                format!("NULLIF({}, {})", expr1.to_sql(), expr2.to_sql())
+            }
             Function::Rand(e) => match e {
                 Some(v) => format!("RAND({})", v.to_sql()),
                 None => "RAND()".to_owned(),
             },
-            Function::Round(e) => format!("ROUND({})", e.to_sql()),
+            Function::Round { expr, scale } => match scale {
+                None => format!("ROUND({})", expr.to_sql()),
+                Some(scale) => format!("ROUND({}, {})", expr.to_sql(), scale.to_sql()),
+            },
             Function::Floor(e) => format!("FLOOR({})", e.to_sql()),
             Function::Trim {
                 expr,
@@ -263,7 +348,7 @@ impl ToSql for Function {
             Function::Exp(e) => format!("EXP({})", e.to_sql()),
             Function::Ln(e) => format!("LN({})", e.to_sql()),
             Function::Log { antilog, base } => {
-                format!("LOG({}, {})", antilog.to_sql(), base.to_sql())
+                format!("LOG({}, {})", base.to_sql(), antilog.to_sql())
             }
             Function::Log2(e) => format!("LOG2({})", e.to_sql()),
             Function::Log10(e) => format!("LOG10({})", e.to_sql()),
@@ -311,7 +396,18 @@ impl ToSql for Function {
             Function::Unwrap { expr, selector } => {
                 format!("UNWRAP({}, {})", expr.to_sql(), selector.to_sql())
             }
+            Function::JsonExtract { expr, path } => {
+                format!("JSON_EXTRACT({}, {})", expr.to_sql(), path.to_sql())
+            }
             Function::GenerateUuid() => "GENERATE_UUID()".to_owned(),
+            Function::UuidToBin { expr, swap } => match swap {
+                None => format!("UUID_TO_BIN({})", expr.to_sql()),
+                Some(swap) => format!("UUID_TO_BIN({}, {})", expr.to_sql(), swap.to_sql()),
+            },
+            Function::BinToUuid { expr, swap } => match swap {
+                None => format!("BIN_TO_UUID({})", expr.to_sql()),
+                Some(swap) => format!("BIN_TO_UUID({}, {})", expr.to_sql(), swap.to_sql()),
+            },
             Function::Format { expr, format } => {
                 format!("FORMAT({}, {})", expr.to_sql(), format.to_sql())
             }
@@ -341,12 +437,35 @@ impl ToSql for Function {
                     start_expr.to_sql()
                 ),
             },
+            Function::SplitPart {
+                expr,
+                delimiter,
+                index,
+            } => format!(
+                "SPLIT_PART({}, {}, {})",
+                expr.to_sql(),
+                delimiter.to_sql(),
+                index.to_sql()
+            ),
+            Function::StringToArray { expr, delimiter } => {
+                format!("STRING_TO_ARRAY({}, {})", expr.to_sql(), delimiter.to_sql())
+            }
             Function::Extract { field, expr } => {
                 format!("EXTRACT({field} FROM {})", expr.to_sql())
             }
+            Function::AtTimeZone { expr, time_zone } => {
+                format!("{} AT TIME ZONE '{time_zone}'", expr.to_sql())
+            }
             Function::Ascii(e) => format!("ASCII({})", e.to_sql()),
             Function::Chr(e) => format!("CHR({})", e.to_sql()),
             Function::Md5(e) => format!("MD5({})", e.to_sql()),
+            Function::OctetLength(e) => format!("OCTET_LENGTH({})", e.to_sql()),
+            Function::Encode { expr, format } => {
+                format!("ENCODE({}, {})", expr.to_sql(), format.to_sql())
+            }
+            Function::Decode { expr, format } => {
+                format!("DECODE({}, {})", expr.to_sql(), format.to_sql())
+            }
             Function::Append { expr, value } => {
                 format!(
                     "APPEND({items}, {value})",
@@ -361,6 +480,41 @@ impl ToSql for Function {
                     value = value.to_sql()
                 }
             }
+            Function::ArrayRemove { expr, value } => {
+                format!("ARRAY_REMOVE({}, {})", expr.to_sql(), value.to_sql())
+            }
+            Function::ArrayRemoveNulls(e) => format!("ARRAY_REMOVE_NULLS({})", e.to_sql()),
+            Function::ArrayLength(e) => format!("ARRAY_LENGTH({})", e.to_sql()),
+            Function::ArrayReplace { expr, from, to } => format!(
+                "ARRAY_REPLACE({}, {}, {})",
+                expr.to_sql(),
+                from.to_sql(),
+                to.to_sql()
+            ),
+            Function::Greatest(items) => {
+                let items = items
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("GREATEST({items})")
+            }
+            Function::Least(items) => {
+                let items = items
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("LEAST({items})")
+            }
+            Function::Grouping(items) => {
+                let items = items
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("GROUPING({items})")
+            }
             Function::GetX(e) => format!("GET_X({})", e.to_sql()),
             Function::GetY(e) => format!("GET_Y({})", e.to_sql()),
             Function::Point { x, y } => format!("POINT({}, {})", x.to_sql(), y.to_sql()),
@@ -374,6 +528,11 @@ impl ToSql for Function {
                     geometry2.to_sql()
                 )
             }
+            Function::Nextval(e) => format!("NEXTVAL({})", e.to_sql()),
+            Function::Currval(e) => format!("CURRVAL({})", e.to_sql()),
+            Function::Setval { name, value } => {
+                format!("SETVAL({}, {})", name.to_sql(), value.to_sql())
+            }
         }
     }
 }
@@ -387,6 +546,43 @@ pub enum Aggregate {
     Avg(Expr),
     Variance(Expr),
     Stdev(Expr),
+    /// First value of the group in the order rows are scanned, `NULL` included
+    First(Expr),
+    /// Last value of the group in the order rows are scanned, `NULL` included
+    Last(Expr),
+    /// Approximate number of distinct non-null values, estimated with a HyperLogLog sketch
+    CountApproxDistinct {
+        expr: Expr,
+        precision: u8,
+    },
+    /// Non-null values of the group joined with `separator`, in `order_by` order if given and
+    /// in scan order otherwise
+    StringAgg {
+        expr: Expr,
+        separator: String,
+        order_by: Vec<OrderByExpr>,
+    },
+    /// Values of the group collected into a list, `NULL` skipped unless `include_nulls` is set
+    JsonAgg {
+        expr: Expr,
+        include_nulls: bool,
+    },
+    /// Key-value pairs of the group collected into a map, the last value wins on duplicate keys
+    JsonObjectAgg {
+        key: Expr,
+        value: Expr,
+        include_nulls: bool,
+    },
+    /// Value at `fraction` of the sorted non-null values, interpolated between neighbours
+    PercentileCont {
+        fraction: Expr,
+        expr: Expr,
+    },
+    /// First sorted non-null value whose position reaches `fraction`
+    PercentileDisc {
+        fraction: Expr,
+        expr: Expr,
+    },
 }
 
 impl ToSql for Aggregate {
@@ -399,6 +595,55 @@ impl ToSql for Aggregate {
             Aggregate::Avg(e) => format!("AVG({})", e.to_sql()),
             Aggregate::Variance(e) => format!("VARIANCE({})", e.to_sql()),
             Aggregate::Stdev(e) => format!("STDEV({})", e.to_sql()),
+            Aggregate::First(e) => format!("FIRST({})", e.to_sql()),
+            Aggregate::Last(e) => format!("LAST({})", e.to_sql()),
+            Aggregate::CountApproxDistinct { expr, precision } => {
+                format!("COUNT_APPROX_DISTINCT({}, {precision})", expr.to_sql())
+            }
+            Aggregate::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } if order_by.is_empty() => {
+                format!("STRING_AGG({}, '{separator}')", expr.to_sql())
+            }
+            Aggregate::StringAgg {
+                expr,
+                separator,
+                order_by,
+            } => format!(
+                "LISTAGG({}, '{separator}') WITHIN GROUP (ORDER BY {})",
+                expr.to_sql(),
+                order_by.iter().map(ToSql::to_sql).join(", ")
+            ),
+            Aggregate::JsonAgg {
+                expr,
+                include_nulls: false,
+            } => format!("JSON_AGG({})", expr.to_sql()),
+            Aggregate::JsonAgg {
+                expr,
+                include_nulls: true,
+            } => format!("JSON_AGG({}, TRUE)", expr.to_sql()),
+            Aggregate::JsonObjectAgg {
+                key,
+                value,
+                include_nulls: false,
+            } => format!("JSON_OBJECT_AGG({}, {})", key.to_sql(), value.to_sql()),
+            Aggregate::JsonObjectAgg {
+                key,
+                value,
+                include_nulls: true,
+            } => format!(
+                "JSON_OBJECT_AGG({}, {}, TRUE)",
+                key.to_sql(),
+                value.to_sql()
+            ),
+            Aggregate::PercentileCont { fraction, expr } => {
+                format!("PERCENTILE_CONT({}, {})", fraction.to_sql(), expr.to_sql())
+            }
+            Aggregate::PercentileDisc { fraction, expr } => {
+                format!("PERCENTILE_DISC({}, {})", fraction.to_sql(), expr.to_sql())
+            }
         }
     }
 }
@@ -418,12 +663,87 @@ impl ToSql for CountArgExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Window {
+    pub function: WindowFunction,
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFunction {
+    Aggregate(Aggregate),
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+    Lead {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+}
+
+impl ToSql for Window {
+    fn to_sql(&self) -> String {
+        let function = match &self.function {
+            WindowFunction::Aggregate(aggregate) => aggregate.to_sql(),
+            WindowFunction::RowNumber => "ROW_NUMBER()".to_owned(),
+            WindowFunction::Rank => "RANK()".to_owned(),
+            WindowFunction::DenseRank => "DENSE_RANK()".to_owned(),
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            } => format!(
+                "LAG({})",
+                [Some(expr), offset.as_ref(), default.as_ref()]
+                    .into_iter()
+                    .flatten()
+                    .map(ToSql::to_sql)
+                    .join(", ")
+            ),
+            WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => format!(
+                "LEAD({})",
+                [Some(expr), offset.as_ref(), default.as_ref()]
+                    .into_iter()
+                    .flatten()
+                    .map(ToSql::to_sql)
+                    .join(", ")
+            ),
+        };
+        let partition_by = (!self.partition_by.is_empty()).then(|| {
+            format!(
+                "PARTITION BY {}",
+                self.partition_by.iter().map(ToSql::to_sql).join(", ")
+            )
+        });
+        let order_by = (!self.order_by.is_empty()).then(|| {
+            format!(
+                "ORDER BY {}",
+                self.order_by.iter().map(ToSql::to_sql).join(", ")
+            )
+        });
+        let spec = [partition_by, order_by].into_iter().flatten().join(" ");
+
+        format!("{function} OVER ({spec})")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
         crate::ast::{
-            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function, ToSql,
-            TrimWhereField,
+            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function,
+            OrderByExpr, ToSql, TrimWhereField, Window, WindowFunction,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -551,6 +871,15 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"TRY_CAST("num" AS DECIMAL)"#,
+            &Expr::Function(Box::new(Function::TryCast {
+                expr: Expr::Identifier("num".to_owned()),
+                data_type: DataType::Decimal
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"CEIL("num")"#,
             &Expr::Function(Box::new(Function::Ceil(Expr::Identifier("num".to_owned())))).to_sql()
@@ -618,6 +947,25 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"COALESCE("nickname", "name", 'unknown')"#,
+            &Expr::Function(Box::new(Function::Coalesce(vec![
+                Expr::Identifier("nickname".to_owned()),
+                Expr::Identifier("name".to_owned()),
+                Expr::Literal(AstLiteral::QuotedString("unknown".to_owned()))
+            ])))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"NULLIF("score", 0)"#,
+            &Expr::Function(Box::new(Function::NullIf {
+                expr1: Expr::Identifier("score".to_owned()),
+                expr2: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("0").unwrap()))
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "RAND()",
             &Expr::Function(Box::new(Function::Rand(None))).to_sql()
@@ -633,9 +981,21 @@ mod tests {
 
         assert_eq!(
             r#"ROUND("num")"#,
-            &Expr::Function(Box::new(Function::Round(Expr::Identifier(
-                "num".to_owned()
-            ))))
+            &Expr::Function(Box::new(Function::Round {
+                expr: Expr::Identifier("num".to_owned()),
+                scale: None
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ROUND("num", 2)"#,
+            &Expr::Function(Box::new(Function::Round {
+                expr: Expr::Identifier("num".to_owned()),
+                scale: Some(Expr::Literal(AstLiteral::Number(
+                    BigDecimal::from_str("2").unwrap()
+                )))
+            }))
             .to_sql()
         );
 
@@ -714,7 +1074,7 @@ mod tests {
         );
 
         assert_eq!(
-            "LOG(64, 8)",
+            "LOG(8, 64)",
             &Expr::Function(Box::new(Function::Log {
                 antilog: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("64").unwrap())),
                 base: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("8").unwrap()))
@@ -924,11 +1284,38 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"JSON_EXTRACT("doc", '$.a[0]')"#,
+            &Expr::Function(Box::new(Function::JsonExtract {
+                expr: Expr::Identifier("doc".to_owned()),
+                path: Expr::Literal(AstLiteral::QuotedString("$.a[0]".to_owned()))
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "GENERATE_UUID()",
             &Expr::Function(Box::new(Function::GenerateUuid())).to_sql()
         );
 
+        assert_eq!(
+            r#"UUID_TO_BIN("id")"#,
+            &Expr::Function(Box::new(Function::UuidToBin {
+                expr: Expr::Identifier("id".to_owned()),
+                swap: None
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"BIN_TO_UUID("bin", TRUE)"#,
+            &Expr::Function(Box::new(Function::BinToUuid {
+                expr: Expr::Identifier("bin".to_owned()),
+                swap: Some(Expr::Literal(AstLiteral::Boolean(true)))
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "FORMAT(DATE '2022-10-12', '%Y-%m')",
             &Expr::Function(Box::new(Function::Format {
@@ -999,6 +1386,25 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            "SPLIT_PART('a,b,c', ',', 2)",
+            &Expr::Function(Box::new(Function::SplitPart {
+                expr: Expr::Literal(AstLiteral::QuotedString("a,b,c".to_owned())),
+                delimiter: Expr::Literal(AstLiteral::QuotedString(",".to_owned())),
+                index: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap())),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "STRING_TO_ARRAY('a,b,c', ',')",
+            &Expr::Function(Box::new(Function::StringToArray {
+                expr: Expr::Literal(AstLiteral::QuotedString("a,b,c".to_owned())),
+                delimiter: Expr::Literal(AstLiteral::QuotedString(",".to_owned())),
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             "ASCII('H')",
             &Expr::Function(Box::new(Function::Ascii(Expr::Literal(
@@ -1023,6 +1429,32 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"OCTET_LENGTH("bytes")"#,
+            &Expr::Function(Box::new(Function::OctetLength(Expr::Identifier(
+                "bytes".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ENCODE("bytes", 'base64')"#,
+            &Expr::Function(Box::new(Function::Encode {
+                expr: Expr::Identifier("bytes".to_owned()),
+                format: Expr::Literal(AstLiteral::QuotedString("base64".to_owned()))
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "DECODE('00ff', 'hex')",
+            &Expr::Function(Box::new(Function::Decode {
+                expr: Expr::Literal(AstLiteral::QuotedString("00ff".to_owned())),
+                format: Expr::Literal(AstLiteral::QuotedString("hex".to_owned()))
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"EXTRACT(MINUTE FROM '2022-05-05 01:02:03')"#,
             &Expr::Function(Box::new(Function::Extract {
@@ -1032,6 +1464,15 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#""created" AT TIME ZONE 'Asia/Seoul'"#,
+            &Expr::Function(Box::new(Function::AtTimeZone {
+                expr: Expr::Identifier("created".to_owned()),
+                time_zone: "Asia/Seoul".to_owned()
+            }))
+            .to_sql()
+        );
+
         assert_eq!(
             r#"APPEND("list", "value")"#,
             &Expr::Function(Box::new(Function::Append {
@@ -1050,6 +1491,69 @@ mod tests {
             .to_sql()
         );
 
+        assert_eq!(
+            r#"ARRAY_REMOVE("list", "value")"#,
+            &Expr::Function(Box::new(Function::ArrayRemove {
+                expr: Expr::Identifier("list".to_owned()),
+                value: Expr::Identifier("value".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ARRAY_REMOVE_NULLS("list")"#,
+            &Expr::Function(Box::new(Function::ArrayRemoveNulls(Expr::Identifier(
+                "list".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ARRAY_LENGTH("list")"#,
+            &Expr::Function(Box::new(Function::ArrayLength(Expr::Identifier(
+                "list".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ARRAY_REPLACE("list", "from", "to")"#,
+            &Expr::Function(Box::new(Function::ArrayReplace {
+                expr: Expr::Identifier("list".to_owned()),
+                from: Expr::Identifier("from".to_owned()),
+                to: Expr::Identifier("to".to_owned())
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"GREATEST("a", "b")"#,
+            &Expr::Function(Box::new(Function::Greatest(vec![
+                Expr::Identifier("a".to_owned()),
+                Expr::Identifier("b".to_owned())
+            ])))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"GROUPING("a", "b")"#,
+            &Expr::Function(Box::new(Function::Grouping(vec![
+                Expr::Identifier("a".to_owned()),
+                Expr::Identifier("b".to_owned())
+            ])))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LEAST("a", "b", "c")"#,
+            &Expr::Function(Box::new(Function::Least(vec![
+                Expr::Identifier("a".to_owned()),
+                Expr::Identifier("b".to_owned()),
+                Expr::Identifier("c".to_owned())
+            ])))
+            .to_sql()
+        );
+
         assert_eq!(
             "GET_X(\"point\")",
             &Expr::Function(Box::new(Function::GetX(Expr::Identifier(
@@ -1089,6 +1593,31 @@ mod tests {
             }))
             .to_sql()
         );
+
+        assert_eq!(
+            "NEXTVAL('seq')",
+            &Expr::Function(Box::new(Function::Nextval(Expr::Literal(
+                AstLiteral::QuotedString("seq".to_owned())
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "CURRVAL('seq')",
+            &Expr::Function(Box::new(Function::Currval(Expr::Literal(
+                AstLiteral::QuotedString("seq".to_owned())
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "SETVAL('seq', 10)",
+            &Expr::Function(Box::new(Function::Setval {
+                name: Expr::Literal(AstLiteral::QuotedString("seq".to_owned())),
+                value: Expr::Literal(AstLiteral::Number(BigDecimal::from(10)))
+            }))
+            .to_sql()
+        );
     }
 
     #[test]
@@ -1134,5 +1663,192 @@ mod tests {
             ))))
             .to_sql()
         );
+
+        assert_eq!(
+            r#"FIRST("name")"#,
+            &Expr::Aggregate(Box::new(Aggregate::First(Expr::Identifier(
+                "name".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LAST("name")"#,
+            &Expr::Aggregate(Box::new(Aggregate::Last(Expr::Identifier(
+                "name".to_owned()
+            ))))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"COUNT_APPROX_DISTINCT("id", 12)"#,
+            &Expr::Aggregate(Box::new(Aggregate::CountApproxDistinct {
+                expr: Expr::Identifier("id".to_owned()),
+                precision: 12,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"STRING_AGG("name", ', ')"#,
+            &Expr::Aggregate(Box::new(Aggregate::StringAgg {
+                expr: Expr::Identifier("name".to_owned()),
+                separator: ", ".to_owned(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LISTAGG("name", '') WITHIN GROUP (ORDER BY "id" DESC)"#,
+            &Expr::Aggregate(Box::new(Aggregate::StringAgg {
+                expr: Expr::Identifier("name".to_owned()),
+                separator: String::new(),
+                order_by: vec![OrderByExpr {
+                    expr: Expr::Identifier("id".to_owned()),
+                    asc: Some(false),
+                    nulls_first: None,
+                }],
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"JSON_AGG("name")"#,
+            &Expr::Aggregate(Box::new(Aggregate::JsonAgg {
+                expr: Expr::Identifier("name".to_owned()),
+                include_nulls: false,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"JSON_OBJECT_AGG("id", "name", TRUE)"#,
+            &Expr::Aggregate(Box::new(Aggregate::JsonObjectAgg {
+                key: Expr::Identifier("id".to_owned()),
+                value: Expr::Identifier("name".to_owned()),
+                include_nulls: true,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"PERCENTILE_CONT(0.5, "price")"#,
+            &Expr::Aggregate(Box::new(Aggregate::PercentileCont {
+                fraction: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("0.5").unwrap())),
+                expr: Expr::Identifier("price".to_owned()),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"PERCENTILE_DISC(1, "price")"#,
+            &Expr::Aggregate(Box::new(Aggregate::PercentileDisc {
+                fraction: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
+                expr: Expr::Identifier("price".to_owned()),
+            }))
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_window() {
+        assert_eq!(
+            r#"SUM("price") OVER ()"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Aggregate(Aggregate::Sum(Expr::Identifier(
+                    "price".to_owned()
+                ))),
+                partition_by: Vec::new(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "COUNT(*) OVER ()",
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Aggregate(Aggregate::Count(CountArgExpr::Wildcard)),
+                partition_by: Vec::new(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ROW_NUMBER() OVER (ORDER BY "a" ASC, "b" DESC NULLS LAST)"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::RowNumber,
+                partition_by: Vec::new(),
+                order_by: vec![
+                    OrderByExpr {
+                        expr: Expr::Identifier("a".to_owned()),
+                        asc: Some(true),
+                        nulls_first: None,
+                    },
+                    OrderByExpr {
+                        expr: Expr::Identifier("b".to_owned()),
+                        asc: Some(false),
+                        nulls_first: Some(false),
+                    },
+                ],
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"RANK() OVER (PARTITION BY "a", "b" ORDER BY "c")"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Rank,
+                partition_by: vec![
+                    Expr::Identifier("a".to_owned()),
+                    Expr::Identifier("b".to_owned()),
+                ],
+                order_by: vec![OrderByExpr {
+                    expr: Expr::Identifier("c".to_owned()),
+                    asc: None,
+                    nulls_first: None,
+                }],
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            "DENSE_RANK() OVER ()",
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::DenseRank,
+                partition_by: Vec::new(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LAG("price") OVER ()"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Lag {
+                    expr: Expr::Identifier("price".to_owned()),
+                    offset: None,
+                    default: None,
+                },
+                partition_by: Vec::new(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LEAD("price", 2, 0) OVER ()"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Lead {
+                    expr: Expr::Identifier("price".to_owned()),
+                    offset: Some(Expr::Literal(AstLiteral::Number(2.into()))),
+                    default: Some(Expr::Literal(AstLiteral::Number(0.into()))),
+                },
+                partition_by: Vec::new(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
     }
 }
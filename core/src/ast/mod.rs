@@ -11,7 +11,7 @@ pub use {
     data_type::DataType,
     ddl::*,
     expr::Expr,
-    function::{Aggregate, CountArgExpr, Function},
+    function::{Aggregate, CountArgExpr, Function, Window, WindowFunction},
     operator::*,
     query::*,
 };
@@ -33,6 +33,8 @@ pub enum Statement {
     },
     /// SELECT, VALUES
     Query(Query),
+    /// SELECT ... FOR UPDATE
+    SelectForUpdate(Query),
     /// INSERT
     Insert {
         /// TABLE
@@ -41,11 +43,15 @@ pub enum Statement {
         columns: Vec<String>,
         /// A SQL query that specifies what to insert
         source: Query,
+        /// ON CONFLICT
+        on_conflict: Option<OnConflict>,
     },
     /// UPDATE
     Update {
         /// TABLE
         table_name: String,
+        /// AS
+        alias: Option<String>,
         /// Column assignments
         assignments: Vec<Assignment>,
         /// WHERE
@@ -55,6 +61,8 @@ pub enum Statement {
     Delete {
         /// FROM
         table_name: String,
+        /// AS
+        alias: Option<String>,
         /// WHERE
         selection: Option<Expr>,
     },
@@ -65,9 +73,23 @@ pub enum Statement {
         name: String,
         /// Optional schema
         columns: Option<Vec<ColumnDef>>,
+        /// `CHECK` constraints of the columns and of the table
+        checks: Vec<CheckConstraint>,
+        /// `FOREIGN KEY` constraints of the columns and of the table
+        foreign_keys: Vec<ForeignKey>,
+        /// Sequences created for the `SERIAL` columns
+        sequences: Vec<String>,
         source: Option<Box<Query>>,
         engine: Option<String>,
     },
+    /// CREATE VIEW
+    CreateView {
+        or_replace: bool,
+        name: String,
+        /// Names of the columns, the labels of the query are used if empty
+        columns: Vec<String>,
+        query: Box<Query>,
+    },
     /// CREATE FUNCTION
     CreateFunction {
         or_replace: bool,
@@ -96,11 +118,44 @@ pub enum Statement {
         /// One or more objects to drop. (ANSI SQL requires exactly one.)
         names: Vec<String>,
     },
-    /// CREATE INDEX
+    /// DROP VIEW
+    DropView {
+        if_exists: bool,
+        names: Vec<String>,
+    },
+    /// CREATE SEQUENCE
+    CreateSequence {
+        if_not_exists: bool,
+        name: String,
+        options: SequenceOptions,
+    },
+    /// DROP SEQUENCE
+    DropSequence {
+        if_exists: bool,
+        names: Vec<String>,
+    },
+    /// CREATE TYPE ... AS ENUM
+    CreateType {
+        name: String,
+        variants: Vec<String>,
+    },
+    /// ALTER TYPE
+    AlterType {
+        name: String,
+        operation: AlterTypeOperation,
+    },
+    /// DROP TYPE
+    DropType {
+        if_exists: bool,
+        names: Vec<String>,
+    },
+    /// CREATE [UNIQUE] INDEX
     CreateIndex {
         name: String,
         table_name: String,
+        /// `Expr::Tuple` of the columns for a composite unique index
         column: OrderByExpr,
+        unique: bool,
     },
     /// DROP INDEX
     DropIndex {
@@ -108,7 +163,13 @@ pub enum Statement {
         table_name: String,
     },
     /// START TRANSACTION, BEGIN
-    StartTransaction,
+    StartTransaction {
+        read_only: bool,
+    },
+    /// SET TRANSACTION
+    SetTransaction {
+        read_only: bool,
+    },
     /// COMMIT
     Commit,
     /// ROLLBACK
@@ -116,6 +177,17 @@ pub enum Statement {
     /// SHOW VARIABLE
     ShowVariable(Variable),
     ShowIndexes(String),
+    /// SHOW CREATE TABLE
+    ShowCreateTable(String),
+    /// ANALYZE TABLE
+    Analyze {
+        table_name: String,
+    },
+    /// EXPLAIN [VERBOSE], shows the planned statement instead of executing it
+    Explain {
+        verbose: bool,
+        statement: Box<Statement>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -124,6 +196,17 @@ pub struct Assignment {
     pub value: Expr,
 }
 
+/// Action taken on the rows of `INSERT` which violate a unique or primary key constraint
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnConflict {
+    DoNothing,
+    /// `ON CONFLICT (target) DO UPDATE SET ...`, `EXCLUDED` refers to the row proposed for insertion
+    DoUpdate {
+        target: Vec<String>,
+        assignments: Vec<Assignment>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Variable {
     Tables,
@@ -141,19 +224,45 @@ impl ToSql for Statement {
                 table_name,
                 columns,
                 source,
+                on_conflict,
             } => {
                 let columns = match columns.is_empty() {
                     true => "".to_owned(),
                     false => format!("({}) ", columns.join(", ")),
                 };
+                let on_conflict = match on_conflict {
+                    Some(OnConflict::DoNothing) => " ON CONFLICT DO NOTHING".to_owned(),
+                    Some(OnConflict::DoUpdate {
+                        target,
+                        assignments,
+                    }) => {
+                        let target = target.join(", ");
+                        let assignments = assignments
+                            .iter()
+                            .map(ToSql::to_sql)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        format!(" ON CONFLICT ({target}) DO UPDATE SET {assignments}")
+                    }
+                    None => "".to_owned(),
+                };
 
-                format!("INSERT INTO {table_name} {columns}{};", source.to_sql())
+                format!(
+                    "INSERT INTO {table_name} {columns}{}{on_conflict};",
+                    source.to_sql()
+                )
             }
             Statement::Update {
                 table_name,
+                alias,
                 assignments,
                 selection,
             } => {
+                let alias = match alias {
+                    Some(alias) => format!(r#" AS "{alias}""#),
+                    None => "".to_owned(),
+                };
                 let assignments = assignments
                     .iter()
                     .map(ToSql::to_sql)
@@ -162,26 +271,39 @@ impl ToSql for Statement {
                 match selection {
                     Some(expr) => {
                         format!(
-                            r#"UPDATE "{table_name}" SET {assignments} WHERE {};"#,
+                            r#"UPDATE "{table_name}"{alias} SET {assignments} WHERE {};"#,
                             expr.to_sql()
                         )
                     }
-                    None => format!(r#"UPDATE "{table_name}" SET {assignments};"#),
+                    None => format!(r#"UPDATE "{table_name}"{alias} SET {assignments};"#),
                 }
             }
             Statement::Delete {
                 table_name,
+                alias,
                 selection,
-            } => match selection {
-                Some(expr) => format!(r#"DELETE FROM "{table_name}" WHERE {};"#, expr.to_sql()),
-                None => format!(r#"DELETE FROM "{table_name}";"#),
-            },
+            } => {
+                let alias = match alias {
+                    Some(alias) => format!(r#" AS "{alias}""#),
+                    None => "".to_owned(),
+                };
+                match selection {
+                    Some(expr) => format!(
+                        r#"DELETE FROM "{table_name}"{alias} WHERE {};"#,
+                        expr.to_sql()
+                    ),
+                    None => format!(r#"DELETE FROM "{table_name}"{alias};"#),
+                }
+            }
             Statement::CreateTable {
                 if_not_exists,
                 name,
                 columns,
+                checks,
+                foreign_keys,
                 source,
                 engine,
+                ..
             } => {
                 let if_not_exists = if_not_exists.then_some("IF NOT EXISTS");
                 let body = match source {
@@ -189,15 +311,13 @@ impl ToSql for Statement {
                     None if columns.is_none() => None,
                     None => {
                         let columns = columns
-                            .as_ref()
-                            .map(|columns| {
-                                columns
-                                    .iter()
-                                    .map(ToSql::to_sql)
-                                    .collect::<Vec<_>>()
-                                    .join(", ")
-                            })
-                            .unwrap_or_else(|| "".to_owned());
+                            .iter()
+                            .flatten()
+                            .map(ToSql::to_sql)
+                            .chain(checks.iter().map(ToSql::to_sql))
+                            .chain(foreign_keys.iter().map(ToSql::to_sql))
+                            .collect::<Vec<_>>()
+                            .join(", ");
 
                         Some(format!("({columns})"))
                     }
@@ -254,20 +374,119 @@ impl ToSql for Statement {
                     false => format!("DROP FUNCTION {};", names),
                 }
             }
+            Statement::CreateView {
+                or_replace,
+                name,
+                columns,
+                query,
+            } => {
+                let or_replace = if *or_replace { "OR REPLACE " } else { "" };
+                let columns = match columns.is_empty() {
+                    true => "".to_owned(),
+                    false => format!(
+                        " ({})",
+                        columns
+                            .iter()
+                            .map(|column| format!(r#""{column}""#))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+
+                format!(
+                    r#"CREATE {or_replace}VIEW "{name}"{columns} AS {};"#,
+                    query.to_sql()
+                )
+            }
+            Statement::DropView { if_exists, names } => {
+                let if_exists = if *if_exists { "IF EXISTS " } else { "" };
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("DROP VIEW {if_exists}{names};")
+            }
+            Statement::CreateSequence {
+                if_not_exists,
+                name,
+                options,
+            } => {
+                let if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" };
+                let sql = format!(r#"CREATE SEQUENCE {if_not_exists}"{name}""#);
+
+                match options.to_sql() {
+                    options if options.is_empty() => format!("{sql};"),
+                    options => format!("{sql} {options};"),
+                }
+            }
+            Statement::DropSequence { if_exists, names } => {
+                let if_exists = if *if_exists { "IF EXISTS " } else { "" };
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("DROP SEQUENCE {if_exists}{names};")
+            }
+            Statement::CreateType { name, variants } => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| format!("'{}'", variant.replace('\'', "''")))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(r#"CREATE TYPE "{name}" AS ENUM ({variants});"#)
+            }
+            Statement::AlterType { name, operation } => {
+                format!(r#"ALTER TYPE "{name}" {};"#, operation.to_sql())
+            }
+            Statement::DropType { if_exists, names } => {
+                let if_exists = if *if_exists { "IF EXISTS " } else { "" };
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("DROP TYPE {if_exists}{names};")
+            }
             Statement::CreateIndex {
                 name,
                 table_name,
                 column,
+                unique,
             } => {
-                format!(
-                    r#"CREATE INDEX "{name}" ON "{table_name}" ({});"#,
-                    column.to_sql()
-                )
+                let unique = if *unique { "UNIQUE " } else { "" };
+                let column = match column {
+                    OrderByExpr {
+                        expr: Expr::Tuple(exprs),
+                        ..
+                    } => exprs
+                        .iter()
+                        .map(ToSql::to_sql)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    column => column.to_sql(),
+                };
+
+                format!(r#"CREATE {unique}INDEX "{name}" ON "{table_name}" ({column});"#)
             }
             Statement::DropIndex { name, table_name } => {
                 format!("DROP INDEX {table_name}.{name};")
             }
-            Statement::StartTransaction => "START TRANSACTION;".to_owned(),
+            Statement::StartTransaction { read_only: false } => "START TRANSACTION;".to_owned(),
+            Statement::StartTransaction { read_only: true } => {
+                "START TRANSACTION READ ONLY;".to_owned()
+            }
+            Statement::SetTransaction { read_only: false } => {
+                "SET TRANSACTION READ WRITE;".to_owned()
+            }
+            Statement::SetTransaction { read_only: true } => {
+                "SET TRANSACTION READ ONLY;".to_owned()
+            }
             Statement::Commit => "COMMIT;".to_owned(),
             Statement::Rollback => "ROLLBACK;".to_owned(),
             Statement::ShowVariable(variable) => match variable {
@@ -278,6 +497,17 @@ impl ToSql for Statement {
             Statement::ShowIndexes(object_name) => {
                 format!(r#"SHOW INDEXES FROM "{object_name}";"#)
             }
+            Statement::ShowCreateTable(table_name) => {
+                format!(r#"SHOW CREATE TABLE "{table_name}";"#)
+            }
+            Statement::Analyze { table_name } => format!(r#"ANALYZE TABLE "{table_name}";"#),
+            Statement::Query(query) => format!("{};", query.to_sql()),
+            Statement::SelectForUpdate(query) => format!("{} FOR UPDATE;", query.to_sql()),
+            Statement::Explain { verbose, statement } => {
+                let verbose = if *verbose { "VERBOSE " } else { "" };
+
+                format!("EXPLAIN {verbose}{}", statement.to_sql())
+            }
             _ => "(..statement..)".to_owned(),
         }
     }
@@ -293,9 +523,11 @@ impl ToSql for Assignment {
 mod tests {
     use {
         crate::ast::{
-            AlterTableOperation, Assignment, AstLiteral, BinaryOperator, ColumnDef, DataType, Expr,
-            OperateFunctionArg, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
-            TableFactor, TableWithJoins, ToSql, Values, Variable,
+            AlterColumnOperation, AlterTableOperation, AlterTypeOperation, Assignment, AstLiteral,
+            BinaryOperator, CheckConstraint, ColumnDef, DataType, Expr, ForeignKey, OnConflict,
+            OperateFunctionArg, OrderByExpr, Query, ReferentialAction, Select, SelectItem,
+            SequenceOptions, SetExpr, Statement, TableFactor, TableWithJoins, ToSql, Values,
+            Variable,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -320,6 +552,7 @@ mod tests {
                 table_name: "Test".into(),
                 columns: vec!["id".to_owned(), "num".to_owned(), "name".to_owned()],
                 source: Query {
+                    with: Vec::new(),
                     body: SetExpr::Values(Values(vec![vec![
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap())),
@@ -328,7 +561,55 @@ mod tests {
                     order_by: vec![],
                     limit: None,
                     offset: None
-                }
+                },
+                on_conflict: None,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            "INSERT INTO Test VALUES (1) ON CONFLICT DO NOTHING;",
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: Vec::new(),
+                source: Query {
+                    with: Vec::new(),
+                    body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    ))]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: Some(OnConflict::DoNothing),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"INSERT INTO Test VALUES (1) ON CONFLICT (id) DO UPDATE SET "num" = "EXCLUDED"."num";"#,
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: Vec::new(),
+                source: Query {
+                    with: Vec::new(),
+                    body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    ))]])),
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                },
+                on_conflict: Some(OnConflict::DoUpdate {
+                    target: vec!["id".to_owned()],
+                    assignments: vec![Assignment {
+                        id: "num".to_owned(),
+                        value: Expr::CompoundIdentifier {
+                            alias: "EXCLUDED".to_owned(),
+                            ident: "num".to_owned()
+                        }
+                    }],
+                }),
             }
             .to_sql()
         );
@@ -340,6 +621,7 @@ mod tests {
             r#"UPDATE "Foo" SET "id" = 4, "color" = 'blue';"#,
             Statement::Update {
                 table_name: "Foo".into(),
+                alias: None,
                 assignments: vec![
                     Assignment {
                         id: "id".to_owned(),
@@ -361,6 +643,7 @@ mod tests {
             r#"UPDATE "Foo" SET "name" = 'first' WHERE "a" > "b";"#,
             Statement::Update {
                 table_name: "Foo".into(),
+                alias: None,
                 assignments: vec![Assignment {
                     id: "name".to_owned(),
                     value: Expr::Literal(AstLiteral::QuotedString("first".to_owned()))
@@ -372,6 +655,29 @@ mod tests {
                 })
             }
             .to_sql()
+        );
+
+        assert_eq!(
+            r#"UPDATE "Foo" AS "F" SET "name" = 'first' WHERE "F"."id" = 1;"#,
+            Statement::Update {
+                table_name: "Foo".into(),
+                alias: Some("F".into()),
+                assignments: vec![Assignment {
+                    id: "name".to_owned(),
+                    value: Expr::Literal(AstLiteral::QuotedString("first".to_owned()))
+                }],
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "F".to_owned(),
+                        ident: "id".to_owned()
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("1").unwrap()
+                    )))
+                })
+            }
+            .to_sql()
         )
     }
 
@@ -381,6 +687,7 @@ mod tests {
             r#"DELETE FROM "Foo";"#,
             Statement::Delete {
                 table_name: "Foo".into(),
+                alias: None,
                 selection: None
             }
             .to_sql()
@@ -390,6 +697,7 @@ mod tests {
             r#"DELETE FROM "Foo" WHERE "item" = 'glue';"#,
             Statement::Delete {
                 table_name: "Foo".into(),
+                alias: None,
                 selection: Some(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier("item".to_owned())),
                     op: BinaryOperator::Eq,
@@ -398,6 +706,23 @@ mod tests {
             }
             .to_sql()
         );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" AS "F" WHERE "F"."item" = 'glue';"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                alias: Some("F".into()),
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "F".to_owned(),
+                        ident: "item".to_owned()
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Literal(AstLiteral::QuotedString("glue".to_owned())))
+                })
+            }
+            .to_sql()
+        );
     }
 
     #[test]
@@ -408,6 +733,9 @@ mod tests {
                 if_not_exists: true,
                 name: "Foo".into(),
                 columns: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: None,
                 engine: None,
             }
@@ -420,6 +748,9 @@ mod tests {
                 if_not_exists: false,
                 name: "Foo".into(),
                 columns: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: None,
                 engine: None,
             }
@@ -438,6 +769,9 @@ mod tests {
                     default: None,
                     unique: None,
                 },]),
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: None,
                 engine: None,
             }
@@ -472,6 +806,45 @@ mod tests {
                         unique: None,
                     }
                 ]),
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
+                source: None,
+                engine: None,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE TABLE "Foo" ("id" INT NOT NULL, CONSTRAINT "Foo_id_check" CHECK ("id" > 0), CONSTRAINT "Foo_id_fkey" FOREIGN KEY ("id") REFERENCES "Bar" ("id") ON DELETE CASCADE);"#,
+            Statement::CreateTable {
+                if_not_exists: false,
+                name: "Foo".into(),
+                columns: Some(vec![ColumnDef {
+                    name: "id".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: false,
+                    default: None,
+                    unique: None,
+                }]),
+                checks: vec![CheckConstraint {
+                    name: "Foo_id_check".to_owned(),
+                    expr: Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("id".to_owned())),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::Literal(AstLiteral::Number(
+                            BigDecimal::from_str("0").unwrap()
+                        ))),
+                    },
+                }],
+                foreign_keys: vec![ForeignKey {
+                    name: "Foo_id_fkey".to_owned(),
+                    column_name: "id".to_owned(),
+                    referenced_table_name: "Bar".to_owned(),
+                    referenced_column_name: "id".to_owned(),
+                    on_delete: ReferentialAction::Cascade,
+                }],
+                sequences: Vec::new(),
                 source: None,
                 engine: None,
             }
@@ -487,7 +860,11 @@ mod tests {
                 if_not_exists: false,
                 name: "Foo".into(),
                 columns: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: Some(Box::new(Query {
+                    with: Vec::new(),
                     body: SetExpr::Select(Box::new(Select {
                         projection: vec![
                             SelectItem::Expr {
@@ -526,7 +903,11 @@ mod tests {
                 if_not_exists: true,
                 name: "Foo".into(),
                 columns: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: Some(Box::new(Query {
+                    with: Vec::new(),
                     body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Boolean(
                         true
                     ))]])),
@@ -548,6 +929,9 @@ mod tests {
                 if_not_exists: false,
                 name: "Foo".into(),
                 columns: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: None,
                 engine: Some("MEMORY".to_owned()),
             }
@@ -566,6 +950,9 @@ mod tests {
                     default: None,
                     unique: None,
                 },]),
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                sequences: Vec::new(),
                 source: None,
                 engine: Some("SLED".to_owned()),
             }
@@ -630,7 +1017,8 @@ mod tests {
                 name: "Foo".into(),
                 operation: AlterTableOperation::DropColumn {
                     column_name: "something".to_owned(),
-                    if_exists: false
+                    if_exists: false,
+                    cascade: false
                 }
             }
             .to_sql()
@@ -642,7 +1030,21 @@ mod tests {
                 name: "Foo".into(),
                 operation: AlterTableOperation::DropColumn {
                     column_name: "something".to_owned(),
-                    if_exists: true
+                    if_exists: true,
+                    cascade: false
+                }
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" DROP COLUMN "something" CASCADE;"#,
+            Statement::AlterTable {
+                name: "Foo".into(),
+                operation: AlterTableOperation::DropColumn {
+                    column_name: "something".to_owned(),
+                    if_exists: false,
+                    cascade: true
                 }
             }
             .to_sql()
@@ -670,6 +1072,56 @@ mod tests {
             }
             .to_sql()
         );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" ALTER COLUMN "num" SET NOT NULL;"#,
+            Statement::AlterTable {
+                name: "Foo".to_owned(),
+                operation: AlterTableOperation::AlterColumn {
+                    column_name: "num".to_owned(),
+                    operation: AlterColumnOperation::SetNotNull,
+                }
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" ALTER COLUMN "num" DROP NOT NULL;"#,
+            Statement::AlterTable {
+                name: "Foo".to_owned(),
+                operation: AlterTableOperation::AlterColumn {
+                    column_name: "num".to_owned(),
+                    operation: AlterColumnOperation::DropNotNull,
+                }
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" ALTER COLUMN "num" SET DEFAULT 0;"#,
+            Statement::AlterTable {
+                name: "Foo".to_owned(),
+                operation: AlterTableOperation::AlterColumn {
+                    column_name: "num".to_owned(),
+                    operation: AlterColumnOperation::SetDefault(Expr::Literal(AstLiteral::Number(
+                        BigDecimal::from_str("0").unwrap()
+                    ))),
+                }
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" ALTER COLUMN "num" DROP DEFAULT;"#,
+            Statement::AlterTable {
+                name: "Foo".to_owned(),
+                operation: AlterTableOperation::AlterColumn {
+                    column_name: "num".to_owned(),
+                    operation: AlterColumnOperation::DropDefault,
+                }
+            }
+            .to_sql()
+        );
     }
 
     #[test]
@@ -732,6 +1184,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_create_sequence() {
+        assert_eq!(
+            r#"CREATE SEQUENCE "Seq";"#,
+            Statement::CreateSequence {
+                if_not_exists: false,
+                name: "Seq".to_owned(),
+                options: SequenceOptions::default(),
+            }
+            .to_sql()
+        );
+
+        let number = |n: i64| Expr::Literal(AstLiteral::Number(BigDecimal::from(n)));
+        assert_eq!(
+            r#"CREATE SEQUENCE IF NOT EXISTS "Seq" START WITH 10 INCREMENT BY 5 MINVALUE 1 MAXVALUE 100 CYCLE;"#,
+            Statement::CreateSequence {
+                if_not_exists: true,
+                name: "Seq".to_owned(),
+                options: SequenceOptions {
+                    start: Some(number(10)),
+                    increment: Some(number(5)),
+                    min_value: Some(number(1)),
+                    max_value: Some(number(100)),
+                    cycle: true,
+                },
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_sequence() {
+        assert_eq!(
+            r#"DROP SEQUENCE "Foo", "Bar";"#,
+            Statement::DropSequence {
+                if_exists: false,
+                names: vec!["Foo".to_owned(), "Bar".to_owned()]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP SEQUENCE IF EXISTS "Seq";"#,
+            Statement::DropSequence {
+                if_exists: true,
+                names: vec!["Seq".to_owned()]
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_view() {
+        let query = Query {
+            with: Vec::new(),
+            body: SetExpr::Select(Box::new(Select {
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: vec![],
+                },
+                selection: None,
+                group_by: vec![],
+                having: None,
+            })),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        };
+
+        assert_eq!(
+            r#"CREATE VIEW "Foo" AS SELECT * FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: false,
+                name: "Foo".to_owned(),
+                columns: Vec::new(),
+                query: Box::new(query.clone()),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE OR REPLACE VIEW "Foo" ("a", "b") AS SELECT * FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: true,
+                name: "Foo".to_owned(),
+                columns: vec!["a".to_owned(), "b".to_owned()],
+                query: Box::new(query),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_view() {
+        assert_eq!(
+            r#"DROP VIEW IF EXISTS "Foo", "Bar";"#,
+            Statement::DropView {
+                if_exists: true,
+                names: vec!["Foo".to_owned(), "Bar".to_owned()]
+            }
+            .to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_create_index() {
         assert_eq!(
@@ -741,8 +1302,28 @@ mod tests {
                 table_name: "Test".into(),
                 column: OrderByExpr {
                     expr: Expr::Identifier("LastName".to_owned()),
-                    asc: None
-                }
+                    asc: None,
+                    nulls_first: None,
+                },
+                unique: false,
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE UNIQUE INDEX "idx_name" ON "Test" ("FirstName", "LastName");"#,
+            Statement::CreateIndex {
+                name: "idx_name".into(),
+                table_name: "Test".into(),
+                column: OrderByExpr {
+                    expr: Expr::Tuple(vec![
+                        Expr::Identifier("FirstName".to_owned()),
+                        Expr::Identifier("LastName".to_owned()),
+                    ]),
+                    asc: None,
+                    nulls_first: None,
+                },
+                unique: true,
             }
             .to_sql()
         );
@@ -762,11 +1343,77 @@ mod tests {
 
     #[test]
     fn to_sql_transaction() {
-        assert_eq!("START TRANSACTION;", Statement::StartTransaction.to_sql());
+        assert_eq!(
+            "START TRANSACTION;",
+            Statement::StartTransaction { read_only: false }.to_sql()
+        );
+        assert_eq!(
+            "START TRANSACTION READ ONLY;",
+            Statement::StartTransaction { read_only: true }.to_sql()
+        );
+        assert_eq!(
+            "SET TRANSACTION READ ONLY;",
+            Statement::SetTransaction { read_only: true }.to_sql()
+        );
         assert_eq!("COMMIT;", Statement::Commit.to_sql());
         assert_eq!("ROLLBACK;", Statement::Rollback.to_sql());
     }
 
+    #[test]
+    fn to_sql_type() {
+        assert_eq!(
+            r#"CREATE TYPE "mood" AS ENUM ('sad', 'it''s ok');"#,
+            Statement::CreateType {
+                name: "mood".to_owned(),
+                variants: vec!["sad".to_owned(), "it's ok".to_owned()],
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"ALTER TYPE "mood" ADD VALUE IF NOT EXISTS 'happy';"#,
+            Statement::AlterType {
+                name: "mood".to_owned(),
+                operation: AlterTypeOperation::AddValue {
+                    value: "happy".to_owned(),
+                    if_not_exists: true,
+                },
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"DROP TYPE IF EXISTS "mood", "size";"#,
+            Statement::DropType {
+                if_exists: true,
+                names: vec!["mood".to_owned(), "size".to_owned()],
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_explain() {
+        let statement = Box::new(Statement::Analyze {
+            table_name: "Foo".to_owned(),
+        });
+
+        assert_eq!(
+            r#"EXPLAIN ANALYZE TABLE "Foo";"#,
+            Statement::Explain {
+                verbose: false,
+                statement: statement.clone(),
+            }
+            .to_sql()
+        );
+        assert_eq!(
+            r#"EXPLAIN VERBOSE ANALYZE TABLE "Foo";"#,
+            Statement::Explain {
+                verbose: true,
+                statement,
+            }
+            .to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_show_variable() {
         assert_eq!(
@@ -791,6 +1438,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_show_create_table() {
+        assert_eq!(
+            r#"SHOW CREATE TABLE "Test";"#,
+            Statement::ShowCreateTable("Test".into()).to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_analyze() {
+        assert_eq!(
+            r#"ANALYZE TABLE "Test";"#,
+            Statement::Analyze {
+                table_name: "Test".into()
+            }
+            .to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_assignment() {
         assert_eq!(
@@ -9,6 +9,7 @@ pub enum UnaryOperator {
     Minus,
     Not,
     Factorial,
+    BitwiseNot,
 }
 
 impl ToSql for UnaryOperator {
@@ -18,6 +19,7 @@ impl ToSql for UnaryOperator {
             UnaryOperator::Minus => "-".to_owned(),
             UnaryOperator::Not => "NOT ".to_owned(),
             UnaryOperator::Factorial => "!".to_owned(),
+            UnaryOperator::BitwiseNot => "~".to_owned(),
         }
     }
 }
@@ -39,6 +41,10 @@ pub enum BinaryOperator {
     And,
     Or,
     Xor,
+    RegexMatch,
+    RegexIMatch,
+    RegexNotMatch,
+    RegexNotIMatch,
 }
 
 impl ToSql for BinaryOperator {
@@ -59,6 +65,10 @@ impl ToSql for BinaryOperator {
             BinaryOperator::And => "AND".to_owned(),
             BinaryOperator::Or => "OR".to_owned(),
             BinaryOperator::Xor => "XOR".to_owned(),
+            BinaryOperator::RegexMatch => "~".to_owned(),
+            BinaryOperator::RegexIMatch => "~*".to_owned(),
+            BinaryOperator::RegexNotMatch => "!~".to_owned(),
+            BinaryOperator::RegexNotIMatch => "!~*".to_owned(),
         }
     }
 }
@@ -246,6 +256,15 @@ mod tests {
             }
             .to_sql()
         );
+        assert_eq!(
+            r#""name" ~* '^glue'"#,
+            &Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("name".to_owned())),
+                op: BinaryOperator::RegexIMatch,
+                right: Box::new(Expr::Literal(AstLiteral::QuotedString("^glue".to_owned())))
+            }
+            .to_sql()
+        );
 
         assert_eq!(
             "+8",
@@ -281,6 +300,15 @@ mod tests {
                 expr: Box::new(Expr::Literal(AstLiteral::Number(BigDecimal::from(5)))),
             }
             .to_sql(),
+        );
+
+        assert_eq!(
+            r#"~"flags""#,
+            Expr::UnaryOp {
+                op: UnaryOperator::BitwiseNot,
+                expr: Box::new(Expr::Identifier("flags".to_owned())),
+            }
+            .to_sql(),
         )
     }
 }
@@ -1,6 +1,6 @@
 use {
     super::{Expr, IndexOperator, ToSqlUnquoted},
-    crate::ast::ToSql,
+    crate::{ast::ToSql, executor::DEFAULT_MAX_RECURSION},
     itertools::Itertools,
     serde::{Deserialize, Serialize},
     strum_macros::Display,
@@ -8,16 +8,58 @@ use {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Query {
+    /// Common table expressions of `WITH`, each of which is evaluated once per execution
+    #[serde(default)]
+    pub with: Vec<Cte>,
     pub body: SetExpr,
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Expr>,
 }
 
+/// `alias AS (query)`, which is referred to by the name of `alias` as a table.
+/// The query of `WITH RECURSIVE` has a `SetExpr::Recursive` body.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cte {
+    pub alias: TableAlias,
+    pub query: Query,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    SetOperation {
+        op: SetOperator,
+        /// `ALL` keeps duplicate rows, otherwise the result is deduplicated
+        all: bool,
+        left: Box<SetExpr>,
+        right: Box<SetExpr>,
+    },
+    /// `WITH RECURSIVE`, `step` reads the rows of `alias` produced by the previous iteration
+    Recursive {
+        alias: TableAlias,
+        /// `UNION ALL` keeps duplicate rows, `UNION` discards the rows already produced
+        all: bool,
+        base: Box<SetExpr>,
+        step: Box<SetExpr>,
+        /// Times `step` runs before `RecursiveCteError::MaxRecursionExceeded` is returned
+        #[serde(default = "max_recursion_by_default")]
+        max_recursion: usize,
+    },
+}
+
+fn max_recursion_by_default() -> usize {
+    DEFAULT_MAX_RECURSION
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetOperator {
+    Except,
+    Intersect,
+    /// Only produced by the planner to combine the groups of `ROLLUP`, `CUBE` and `GROUPING SETS`
+    Union,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -30,6 +72,15 @@ pub struct Select {
     pub having: Option<Expr>,
 }
 
+/// `ROLLUP`, `CUBE` and `GROUPING SETS` of `GROUP BY`, each element is a list of expressions
+/// which are grouped together
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GroupByModifier {
+    Rollup(Vec<Vec<Expr>>),
+    Cube(Vec<Vec<Expr>>),
+    GroupingSets(Vec<Vec<Expr>>),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SelectItem {
     /// An expression
@@ -67,11 +118,20 @@ pub enum TableFactor {
     Derived {
         subquery: Query,
         alias: TableAlias,
+        /// `LATERAL` subquery, which is executed for each row of the preceding tables and can
+        /// refer to their columns
+        lateral: bool,
     },
     Series {
         alias: TableAlias,
         size: Expr,
     },
+    /// `UNNEST(expr)`, a row for each element of the list, which is evaluated for each row of
+    /// the preceding tables like a `LATERAL` subquery
+    Unnest {
+        alias: TableAlias,
+        expr: Expr,
+    },
     Dictionary {
         dict: Dictionary,
         alias: TableAlias,
@@ -114,11 +174,17 @@ pub enum JoinExecutor {
 pub enum JoinOperator {
     Inner(JoinConstraint),
     LeftOuter(JoinConstraint),
+    /// Always executed as a nested loop join, to find the right rows which match no left row
+    FullOuter(JoinConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum JoinConstraint {
     On(Expr),
+    /// `USING (...)`, rewritten to `On` by the planner
+    Using(Vec<String>),
+    /// `NATURAL` join on the columns of the same name, rewritten to `On` by the planner
+    Natural,
     None,
 }
 
@@ -126,6 +192,7 @@ pub enum JoinConstraint {
 pub struct OrderByExpr {
     pub expr: Expr,
     pub asc: Option<bool>,
+    pub nulls_first: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -151,12 +218,27 @@ impl Query {
         };
 
         let Query {
+            with,
             body,
             order_by,
             limit,
             offset,
         } = self;
 
+        let with = if with.is_empty() {
+            "".to_owned()
+        } else {
+            let recursive = with
+                .iter()
+                .any(|cte| matches!(cte.query.body, SetExpr::Recursive { .. }));
+            let recursive = if recursive { " RECURSIVE" } else { "" };
+
+            format!(
+                "WITH{recursive} {} ",
+                with.iter().map(|cte| cte.to_sql_with(quoted)).join(", ")
+            )
+        };
+
         let order_by = if order_by.is_empty() {
             "".to_owned()
         } else {
@@ -185,13 +267,39 @@ impl Query {
             .join(" ");
 
         if string.is_empty() {
-            body.to_sql_with(quoted)
+            format!("{with}{}", body.to_sql_with(quoted))
         } else {
-            format!("{} {}", body.to_sql_with(quoted), string)
+            format!("{with}{} {}", body.to_sql_with(quoted), string)
         }
     }
 }
 
+impl Cte {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        let Cte {
+            alias: TableAlias { name, columns },
+            query,
+        } = self;
+
+        let name = match quoted {
+            true => format!(r#""{name}""#),
+            false => name.to_owned(),
+        };
+        let columns = if columns.is_empty() {
+            "".to_owned()
+        } else {
+            let columns = columns.iter().map(|column| match quoted {
+                true => format!(r#""{column}""#),
+                false => column.to_owned(),
+            });
+
+            format!("({})", columns.join(", "))
+        };
+
+        format!("{name}{columns} AS ({})", query.to_sql_with(quoted))
+    }
+}
+
 impl ToSql for SetExpr {
     fn to_sql(&self) -> String {
         self.to_sql_with(true)
@@ -211,6 +319,37 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left,
+                    right,
+                },
+                _,
+            ) => {
+                let all = if *all { " ALL" } else { "" };
+
+                format!(
+                    "{} {op}{all} {}",
+                    left.to_sql_with(quoted),
+                    right.to_sql_with(quoted)
+                )
+            }
+            (
+                SetExpr::Recursive {
+                    all, base, step, ..
+                },
+                _,
+            ) => {
+                let all = if *all { " ALL" } else { "" };
+
+                format!(
+                    "{} UNION{all} {}",
+                    base.to_sql_with(quoted),
+                    step.to_sql_with(quoted)
+                )
+            }
         }
     }
 }
@@ -281,6 +420,32 @@ impl Select {
     }
 }
 
+impl ToSql for GroupByModifier {
+    fn to_sql(&self) -> String {
+        let to_sql = |exprs: &Vec<Expr>| match exprs.as_slice() {
+            [expr] => expr.to_sql(),
+            exprs => format!("({})", exprs.iter().map(ToSql::to_sql).join(", ")),
+        };
+
+        match self {
+            GroupByModifier::Rollup(elems) => {
+                format!("ROLLUP({})", elems.iter().map(to_sql).join(", "))
+            }
+            GroupByModifier::Cube(elems) => {
+                format!("CUBE({})", elems.iter().map(to_sql).join(", "))
+            }
+            GroupByModifier::GroupingSets(sets) => {
+                let sets = sets
+                    .iter()
+                    .map(|exprs| format!("({})", exprs.iter().map(ToSql::to_sql).join(", ")))
+                    .join(", ");
+
+                format!("GROUPING SETS ({sets})")
+            }
+        }
+    }
+}
+
 impl ToSql for SelectItem {
     fn to_sql(&self) -> String {
         self.to_sql_with(true)
@@ -374,9 +539,18 @@ impl TableFactor {
                 Some(alias) => format!("{} {}", name, alias.to_sql_with(quoted)),
                 None => name.to_owned(),
             },
-            (TableFactor::Derived { subquery, alias }, _) => {
+            (
+                TableFactor::Derived {
+                    subquery,
+                    alias,
+                    lateral,
+                },
+                _,
+            ) => {
+                let lateral = if *lateral { "LATERAL " } else { "" };
+
                 format!(
-                    "({}) {}",
+                    "{lateral}({}) {}",
                     subquery.to_sql_with(quoted),
                     alias.to_sql_with(quoted)
                 )
@@ -384,6 +558,9 @@ impl TableFactor {
             (TableFactor::Series { alias, size }, _) => {
                 format!("SERIES({}) {}", to_sql(size), alias.to_sql_with(quoted))
             }
+            (TableFactor::Unnest { alias, expr }, _) => {
+                format!("UNNEST({}) {}", to_sql(expr), alias.to_sql_with(quoted))
+            }
             (TableFactor::Dictionary { dict, alias }, true) => {
                 format!(r#""{dict}" {}"#, alias.to_sql_with(quoted))
             }
@@ -440,8 +617,23 @@ impl Join {
         let (join_operator, join_constraint) = match join_operator {
             JoinOperator::Inner(join_constraint) => ("INNER JOIN", join_constraint),
             JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER JOIN", join_constraint),
+            JoinOperator::FullOuter(join_constraint) => ("FULL OUTER JOIN", join_constraint),
         };
 
+        match join_constraint {
+            JoinConstraint::Natural => {
+                return format!("NATURAL {join_operator} {}", relation.to_sql_with(quoted));
+            }
+            JoinConstraint::Using(_) => {
+                return format!(
+                    "{join_operator} {} {}",
+                    relation.to_sql_with(quoted),
+                    join_constraint.to_sql_with(quoted)
+                );
+            }
+            JoinConstraint::On(_) | JoinConstraint::None => {}
+        }
+
         let (join_constraint, join_executor) = match quoted {
             true => (join_constraint.to_sql(), join_executor.to_sql()),
             false => (
@@ -519,7 +711,13 @@ impl JoinConstraint {
         match (self, quoted) {
             (JoinConstraint::On(expr), true) => expr.to_sql(),
             (JoinConstraint::On(expr), false) => expr.to_sql_unquoted(),
-            (JoinConstraint::None, _) => "".to_owned(),
+            (JoinConstraint::Using(columns), true) => {
+                let columns = columns.iter().map(|column| format!(r#""{column}""#));
+
+                format!("USING ({})", columns.join(", "))
+            }
+            (JoinConstraint::Using(columns), false) => format!("USING ({})", columns.join(", ")),
+            (JoinConstraint::Natural | JoinConstraint::None, _) => "".to_owned(),
         }
     }
 }
@@ -538,16 +736,25 @@ impl ToSqlUnquoted for OrderByExpr {
 
 impl OrderByExpr {
     fn to_sql_with(&self, quoted: bool) -> String {
-        let OrderByExpr { expr, asc } = self;
+        let OrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        } = self;
         let expr = match quoted {
             true => expr.to_sql(),
             false => expr.to_sql_unquoted(),
         };
-
-        match asc {
+        let expr = match asc {
             Some(true) => format!("{} ASC", expr),
             Some(false) => format!("{} DESC", expr),
             None => expr,
+        };
+
+        match nulls_first {
+            Some(true) => format!("{} NULLS FIRST", expr),
+            Some(false) => format!("{} NULLS LAST", expr),
+            None => expr,
         }
     }
 }
@@ -590,10 +797,12 @@ mod tests {
     use {
         crate::{
             ast::{
-                AstLiteral, BinaryOperator, Dictionary, Expr, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, TableAlias,
-                TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                AstLiteral, BinaryOperator, Cte, Dictionary, Expr, GroupByModifier, Join,
+                JoinConstraint, JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem,
+                SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, ToSql,
+                ToSqlUnquoted, Values,
             },
+            executor::DEFAULT_MAX_RECURSION,
             parse_sql::parse_expr,
             translate::translate_expr,
         },
@@ -612,10 +821,12 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual =
             r#"SELECT * FROM "FOO" AS "F" ORDER BY "name" ASC LIMIT 10 OFFSET 3"#.to_owned();
         let expected = Query {
+            with: Vec::new(),
             body: SetExpr::Select(Box::new(Select {
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
@@ -650,9 +861,11 @@ mod tests {
         let order_by = vec![OrderByExpr {
             expr: Expr::Identifier("name".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }];
         let actual = "SELECT * FROM FOO AS F ORDER BY name ASC LIMIT 10 OFFSET 3".to_owned();
         let expected = Query {
+            with: Vec::new(),
             body: SetExpr::Select(Box::new(Select {
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
@@ -682,6 +895,64 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_sql_query_with() {
+        let one = || SetExpr::Values(Values(vec![vec![expr("1")]]));
+        let numbers = TableAlias {
+            name: "Numbers".to_owned(),
+            columns: vec!["n".to_owned()],
+        };
+        let select_numbers = SetExpr::Select(Box::new(Select {
+            projection: vec![SelectItem::Wildcard],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "Numbers".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+        }));
+        let query = |body| Query {
+            with: Vec::new(),
+            body,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+
+        let actual = r#"WITH "Numbers"("n") AS (VALUES (1)) SELECT * FROM "Numbers""#.to_owned();
+        let expected = Query {
+            with: vec![Cte {
+                alias: numbers.clone(),
+                query: query(one()),
+            }],
+            ..query(select_numbers.clone())
+        };
+        assert_eq!(actual, expected.to_sql());
+
+        let actual =
+            "WITH RECURSIVE Numbers(n) AS (VALUES (1) UNION VALUES (1)) SELECT * FROM Numbers"
+                .to_owned();
+        let expected = Query {
+            with: vec![Cte {
+                alias: numbers.clone(),
+                query: query(SetExpr::Recursive {
+                    alias: numbers,
+                    all: false,
+                    base: Box::new(one()),
+                    step: Box::new(one()),
+                    max_recursion: DEFAULT_MAX_RECURSION,
+                }),
+            }],
+            ..query(select_numbers)
+        };
+        assert_eq!(actual, expected.to_sql_unquoted());
+    }
+
     #[test]
     fn to_sql_set_expr() {
         let actual = r#"SELECT * FROM "FOO" AS "F" INNER JOIN "PlayerItem""#.to_owned();
@@ -728,6 +999,48 @@ mod tests {
         ]))
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = "VALUES (1), (1) EXCEPT ALL VALUES (1)".to_owned();
+        let values = |n: usize| {
+            SetExpr::Values(Values(vec![
+                vec![Expr::Literal(AstLiteral::Number(
+                    BigDecimal::from_str("1").unwrap()
+                ))];
+                n
+            ]))
+        };
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Except,
+            all: true,
+            left: Box::new(values(2)),
+            right: Box::new(values(1)),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = "VALUES (1) UNION VALUES (1)".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: false,
+            left: Box::new(values(1)),
+            right: Box::new(values(1)),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = "VALUES (1) UNION ALL VALUES (1)".to_owned();
+        let expected = SetExpr::Recursive {
+            alias: TableAlias {
+                name: "Numbers".to_owned(),
+                columns: Vec::new(),
+            },
+            all: true,
+            base: Box::new(values(1)),
+            step: Box::new(values(1)),
+            max_recursion: DEFAULT_MAX_RECURSION,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -792,6 +1105,24 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_sql_group_by_modifier() {
+        let id = || vec![expr("id")];
+        let id_name = || vec![expr("id"), expr("name")];
+
+        let actual = r#"ROLLUP("id", ("id", "name"))"#.to_owned();
+        let expected = GroupByModifier::Rollup(vec![id(), id_name()]).to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"CUBE("id", "name")"#.to_owned();
+        let expected = GroupByModifier::Cube(vec![id(), vec![expr("name")]]).to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"GROUPING SETS (("id", "name"), ("id"), ())"#.to_owned();
+        let expected = GroupByModifier::GroupingSets(vec![id_name(), id(), Vec::new()]).to_sql();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn to_sql_select() {
         let actual =
@@ -980,6 +1311,7 @@ mod tests {
         let actual = r#"(SELECT * FROM "FOO") AS "F""#;
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(Select {
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
@@ -1002,6 +1334,7 @@ mod tests {
                 name: "F".to_owned(),
                 columns: Vec::new(),
             },
+            lateral: false,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1017,6 +1350,17 @@ mod tests {
         .to_sql();
         assert_eq!(actual, expected);
 
+        let actual = r#"UNNEST("items") AS "U""#;
+        let expected = TableFactor::Unnest {
+            alias: TableAlias {
+                name: "U".to_owned(),
+                columns: Vec::new(),
+            },
+            expr: Expr::Identifier("items".to_owned()),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
         let actual = r#""GLUE_TABLES" AS "glue""#;
         let expected = TableFactor::Dictionary {
             dict: Dictionary::GlueTables,
@@ -1043,9 +1387,10 @@ mod tests {
         .to_sql_unquoted();
         assert_eq!(actual, expected);
 
-        let actual = "(SELECT * FROM FOO) AS F";
+        let actual = "LATERAL (SELECT * FROM FOO) AS F";
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(Select {
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
@@ -1068,6 +1413,7 @@ mod tests {
                 name: "F".to_owned(),
                 columns: Vec::new(),
             },
+            lateral: true,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1197,6 +1543,50 @@ mod tests {
         }
         .to_sql();
         assert_eq!(actual, expected);
+
+        let actual = r#"INNER JOIN "PlayerItem" USING ("id", "name")"#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::Using(vec![
+                "id".to_owned(),
+                "name".to_owned(),
+            ])),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"FULL OUTER JOIN "PlayerItem" ON "PlayerItem"."user_id" = "Player"."id""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::FullOuter(JoinConstraint::On(expr(
+                r#""PlayerItem"."user_id" = "Player"."id""#,
+            ))),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#"NATURAL LEFT OUTER JOIN "PlayerItem""#;
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::LeftOuter(JoinConstraint::Natural),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
     }
 
     #[test]
@@ -1233,6 +1623,19 @@ mod tests {
         .to_sql_unquoted();
         assert_eq!(actual, expected);
 
+        let actual = "INNER JOIN PlayerItem USING (id)";
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::Inner(JoinConstraint::Using(vec!["id".to_owned()])),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
         let actual = "LEFT OUTER JOIN PlayerItem";
         let expected = Join {
             relation: TableFactor::Table {
@@ -1291,6 +1694,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1299,6 +1703,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1307,6 +1712,16 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = r#""foo" DESC NULLS LAST"#;
+        let expected = OrderByExpr {
+            expr: Expr::Identifier("foo".to_owned()),
+            asc: Some(false),
+            nulls_first: Some(false),
         }
         .to_sql();
         assert_eq!(actual, expected);
@@ -1318,6 +1733,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(true),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1326,6 +1742,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: Some(false),
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1334,6 +1751,7 @@ mod tests {
         let expected = OrderByExpr {
             expr: Expr::Identifier("foo".to_owned()),
             asc: None,
+            nulls_first: None,
         }
         .to_sql_unquoted();
         assert_eq!(actual, expected);
@@ -1,7 +1,7 @@
 use {
     super::Build,
     crate::{
-        ast::{AlterTableOperation, Statement},
+        ast::{AlterColumnOperation, AlterTableOperation, Statement},
         ast_builder::ColumnDefNode,
         result::Result,
     },
@@ -29,6 +29,7 @@ impl AlterTableNode {
             table_node: self,
             column_name: column_name.to_owned(),
             if_exists: false,
+            cascade: false,
         }
     }
 
@@ -37,6 +38,7 @@ impl AlterTableNode {
             table_node: self,
             column_name: column_name.to_owned(),
             if_exists: true,
+            cascade: false,
         }
     }
 
@@ -54,6 +56,22 @@ impl AlterTableNode {
             new_table_name: new_table_name.to_owned(),
         }
     }
+
+    pub fn set_not_null(self, column_name: &str) -> AlterColumnNode {
+        AlterColumnNode {
+            table_node: self,
+            column_name: column_name.to_owned(),
+            operation: AlterColumnOperation::SetNotNull,
+        }
+    }
+
+    pub fn drop_not_null(self, column_name: &str) -> AlterColumnNode {
+        AlterColumnNode {
+            table_node: self,
+            column_name: column_name.to_owned(),
+            operation: AlterColumnOperation::DropNotNull,
+        }
+    }
 }
 
 pub struct AddColumnNode {
@@ -78,6 +96,14 @@ pub struct DropColumnNode {
     table_node: AlterTableNode,
     column_name: String,
     if_exists: bool,
+    cascade: bool,
+}
+
+impl DropColumnNode {
+    pub fn cascade(mut self) -> Self {
+        self.cascade = true;
+        self
+    }
 }
 
 impl Build for DropColumnNode {
@@ -86,6 +112,7 @@ impl Build for DropColumnNode {
         let operation = AlterTableOperation::DropColumn {
             column_name: self.column_name,
             if_exists: self.if_exists,
+            cascade: self.cascade,
         };
         Ok(Statement::AlterTable {
             name: table_name,
@@ -132,6 +159,26 @@ impl Build for RenameTableNode {
     }
 }
 
+pub struct AlterColumnNode {
+    table_node: AlterTableNode,
+    column_name: String,
+    operation: AlterColumnOperation,
+}
+
+impl Build for AlterColumnNode {
+    fn build(self) -> Result<Statement> {
+        let table_name = self.table_node.table_name;
+        let operation = AlterTableOperation::AlterColumn {
+            column_name: self.column_name,
+            operation: self.operation,
+        };
+        Ok(Statement::AlterTable {
+            name: table_name,
+            operation,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast_builder::{table, test, Build};
@@ -156,6 +203,14 @@ mod tests {
         let expected = "ALTER TABLE Foo DROP COLUMN IF EXISTS col_name";
         test(actual, expected);
 
+        let actual = table("Foo")
+            .alter_table()
+            .drop_column("col_name")
+            .cascade()
+            .build();
+        let expected = "ALTER TABLE Foo DROP COLUMN col_name CASCADE";
+        test(actual, expected);
+
         let actual = table("Foo")
             .alter_table()
             .rename_column("old", "new")
@@ -169,5 +224,13 @@ mod tests {
             .build();
         let expected = "ALTER TABLE Foo RENAME TO new_table_name";
         test(actual, expected);
+
+        let actual = table("Foo").alter_table().set_not_null("num").build();
+        let expected = "ALTER TABLE Foo ALTER COLUMN num SET NOT NULL";
+        test(actual, expected);
+
+        let actual = table("Foo").alter_table().drop_not_null("num").build();
+        let expected = "ALTER TABLE Foo ALTER COLUMN num DROP NOT NULL";
+        test(actual, expected);
     }
 }
@@ -50,6 +50,9 @@ impl Build for CreateTableNode {
             name: table_name,
             if_not_exists: self.if_not_exists,
             columns,
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
+            sequences: Vec::new(),
             source: None,
             engine: None,
         })
@@ -34,6 +34,7 @@ impl<'a> Build for DeleteNode<'a> {
 
         Ok(Statement::Delete {
             table_name,
+            alias: None,
             selection,
         })
     }
@@ -169,7 +169,9 @@ impl<'a> TryFrom<FunctionNode<'a>> for Function {
             FunctionNode::Rand(expr_node) => Ok(Function::Rand(
                 expr_node.map(TryInto::try_into).transpose()?,
             )),
-            FunctionNode::Round(expr_node) => expr_node.try_into().map(Function::Round),
+            FunctionNode::Round(expr_node) => expr_node
+                .try_into()
+                .map(|expr| Function::Round { expr, scale: None }),
             FunctionNode::Floor(expr_node) => expr_node.try_into().map(Function::Floor),
             FunctionNode::Asin(expr_node) => expr_node.try_into().map(Function::Asin),
             FunctionNode::Acos(expr_node) => expr_node.try_into().map(Function::Acos),
@@ -1015,11 +1017,11 @@ mod tests {
     #[test]
     fn function_log() {
         let actual = log(num(64), num(8));
-        let expected = "log(64,8)";
+        let expected = "log(8,64)";
         test_expr(actual, expected);
 
         let actual = num(64).log(num(8));
-        let expected = "LOG(64,8)";
+        let expected = "LOG(8,64)";
         test_expr(actual, expected);
     }
 
@@ -167,6 +167,7 @@ mod test {
             };
 
             let query = Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -32,6 +32,7 @@ impl<'a> Build for CreateIndexNode<'a> {
             name,
             table_name,
             column,
+            unique: false,
         })
     }
 }
@@ -59,6 +59,7 @@ impl<'a> Build for InsertSourceNode<'a> {
             table_name,
             columns,
             source,
+            on_conflict: None,
         })
     }
 }
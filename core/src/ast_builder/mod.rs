@@ -57,12 +57,13 @@ pub use {
 
 /// Available expression builder functions
 pub use expr::{
-    case, col, date, exists, expr, factorial, minus, nested, not, not_exists, null, num,
-    numeric::NumericNode, plus, subquery, text, time, timestamp, ExprNode,
+    bitwise_not, case, col, date, exists, expr, factorial, minus, nested, not, not_exists, null,
+    num, numeric::NumericNode, plus, subquery, text, time, timestamp, ExprNode,
 };
 
 pub use alter_table::{
-    AddColumnNode, AlterTableNode, DropColumnNode, RenameColumnNode, RenameTableNode,
+    AddColumnNode, AlterColumnNode, AlterTableNode, DropColumnNode, RenameColumnNode,
+    RenameTableNode,
 };
 
 pub use {index::CreateIndexNode, index::DropIndexNode};
@@ -95,6 +95,7 @@ impl<'a> TryFrom<QueryNode<'a>> for Query {
                     .collect::<Result<Vec<_>>>()?;
 
                 Ok(Query {
+                    with: Vec::new(),
                     body: SetExpr::Values(Values(values)),
                     order_by: Vec::new(),
                     limit: None,
@@ -187,6 +188,7 @@ mod test {
             };
 
             Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -229,6 +229,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -188,6 +188,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -237,6 +238,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -289,6 +291,7 @@ mod tests {
                 from: TableWithJoins {
                     relation: TableFactor::Derived {
                         subquery: Query {
+                            with: Vec::new(),
                             body: SetExpr::Select(Box::new(subquery)),
                             order_by: Vec::new(),
                             limit: None,
@@ -298,6 +301,7 @@ mod tests {
                             name: "Sub".to_owned(),
                             columns: Vec::new(),
                         },
+                        lateral: false,
                     },
                     joins: Vec::new(),
                 },
@@ -307,6 +311,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
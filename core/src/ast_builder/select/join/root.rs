@@ -602,6 +602,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
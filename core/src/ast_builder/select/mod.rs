@@ -41,6 +41,7 @@ impl<T: Prebuild<Select>> Prebuild<Query> for T {
         let select = self.prebuild()?;
         let body = SetExpr::Select(Box::new(select));
         let query = Query {
+            with: Vec::new(),
             body,
             order_by: Vec::new(),
             limit: None,
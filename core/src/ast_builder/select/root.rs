@@ -104,6 +104,7 @@ impl<'a> Prebuild<Select> for SelectNode<'a> {
                     name: alias,
                     columns: Vec::new(),
                 },
+                lateral: false,
             },
         };
 
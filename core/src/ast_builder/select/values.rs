@@ -43,6 +43,7 @@ impl<'a> Prebuild<Query> for ValuesNode<'a> {
         let body = SetExpr::Values(Values(values));
 
         Ok(Query {
+            with: Vec::new(),
             body,
             order_by: Vec::new(),
             limit: None,
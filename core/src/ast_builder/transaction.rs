@@ -1,7 +1,7 @@
 use crate::{ast::Statement, result::Result};
 
 pub fn begin() -> Result<Statement> {
-    Ok(Statement::StartTransaction)
+    Ok(Statement::StartTransaction { read_only: false })
 }
 pub fn commit() -> Result<Statement> {
     Ok(Statement::Commit)
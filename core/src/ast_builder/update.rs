@@ -45,6 +45,7 @@ impl<'a> Build for UpdateNode<'a> {
             .collect::<Result<Vec<_>>>()?;
         Ok(Statement::Update {
             table_name,
+            alias: None,
             assignments,
             selection,
         })
@@ -0,0 +1,118 @@
+use {
+    serde::{Deserialize, Serialize},
+    thiserror::Error,
+};
+
+#[derive(Error, Serialize, Debug, PartialEq, Eq)]
+pub enum EnumTypeError {
+    #[error("type not found: {0}")]
+    TypeNotFound(String),
+
+    #[error("type already exists: {0}")]
+    TypeAlreadyExists(String),
+
+    #[error("enum type '{0}' must have at least one variant")]
+    EmptyVariants(String),
+
+    #[error("variant '{variant}' of enum type '{name}' already exists")]
+    VariantAlreadyExists { name: String, variant: String },
+
+    #[error("type '{name}' is used by column '{column}' of table '{table}'")]
+    TypeInUse {
+        name: String,
+        table: String,
+        column: String,
+    },
+}
+
+/// Enum type created with `CREATE TYPE ... AS ENUM`, the columns of the type accept the variants.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumType {
+    pub name: String,
+    pub variants: Vec<String>,
+}
+
+impl EnumType {
+    pub fn new(name: String, variants: Vec<String>) -> Result<Self, EnumTypeError> {
+        if variants.is_empty() {
+            return Err(EnumTypeError::EmptyVariants(name));
+        }
+
+        let mut enum_type = Self {
+            name,
+            variants: Vec::with_capacity(variants.len()),
+        };
+        for variant in variants {
+            enum_type.add_variant(variant, false)?;
+        }
+
+        Ok(enum_type)
+    }
+
+    /// Appends the variant, an existing variant is an error unless `if_not_exists` is set.
+    pub fn add_variant(
+        &mut self,
+        variant: String,
+        if_not_exists: bool,
+    ) -> Result<(), EnumTypeError> {
+        if !self.variants.contains(&variant) {
+            self.variants.push(variant);
+
+            return Ok(());
+        }
+
+        match if_not_exists {
+            true => Ok(()),
+            false => Err(EnumTypeError::VariantAlreadyExists {
+                name: self.name.to_owned(),
+                variant,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EnumType, EnumTypeError};
+
+    fn variants(variants: &[&str]) -> Vec<String> {
+        variants.iter().map(|variant| variant.to_string()).collect()
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            EnumType::new("mood".to_owned(), variants(&["sad", "ok"])),
+            Ok(EnumType {
+                name: "mood".to_owned(),
+                variants: variants(&["sad", "ok"]),
+            })
+        );
+        assert_eq!(
+            EnumType::new("mood".to_owned(), Vec::new()),
+            Err(EnumTypeError::EmptyVariants("mood".to_owned()))
+        );
+        assert_eq!(
+            EnumType::new("mood".to_owned(), variants(&["sad", "ok", "sad"])),
+            Err(EnumTypeError::VariantAlreadyExists {
+                name: "mood".to_owned(),
+                variant: "sad".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn add_variant() {
+        let mut enum_type = EnumType::new("mood".to_owned(), variants(&["sad"])).unwrap();
+        assert_eq!(enum_type.add_variant("happy".to_owned(), false), Ok(()));
+        assert_eq!(enum_type.add_variant("happy".to_owned(), true), Ok(()));
+        assert_eq!(
+            enum_type.add_variant("sad".to_owned(), false),
+            Err(EnumTypeError::VariantAlreadyExists {
+                name: "mood".to_owned(),
+                variant: "sad".to_owned(),
+            })
+        );
+        assert_eq!(enum_type.variants, variants(&["sad", "happy"]));
+    }
+}
@@ -14,6 +14,20 @@ use {
 
 pub use error::IntervalError;
 
+/// Moves the timestamp by the given number of months, the day is clamped to the last day of
+/// the resulting month, so 2024-01-31 + 1 month is 2024-02-29.
+fn add_months(timestamp: &NaiveDateTime, months: i32) -> Result<NaiveDateTime> {
+    let months = timestamp.year() * 12 + timestamp.month0() as i32 + months;
+    let year = months.div_euclid(12);
+    let month = months.rem_euclid(12) + 1;
+
+    (1..=timestamp.day())
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month as u32, day))
+        .map(|date| NaiveDateTime::new(date, timestamp.time()))
+        .ok_or_else(|| IntervalError::DateOverflow { year, month }.into())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Interval {
     Month(i32),
@@ -88,34 +102,14 @@ impl Interval {
 
     pub fn add_timestamp(&self, timestamp: &NaiveDateTime) -> Result<NaiveDateTime> {
         match self {
-            Interval::Month(n) => {
-                let month = timestamp.month() as i32 + n;
-
-                let year = timestamp.year() + month / 12;
-                let month = month % 12;
-
-                timestamp
-                    .with_year(year)
-                    .and_then(|d| d.with_month(month as u32))
-                    .ok_or_else(|| IntervalError::DateOverflow { year, month }.into())
-            }
+            Interval::Month(n) => add_months(timestamp, *n),
             Interval::Microsecond(n) => Ok(*timestamp + Duration::microseconds(*n)),
         }
     }
 
     pub fn subtract_from_timestamp(&self, timestamp: &NaiveDateTime) -> Result<NaiveDateTime> {
         match self {
-            Interval::Month(n) => {
-                let months = timestamp.year() * 12 + timestamp.month() as i32 - n;
-
-                let year = months / 12;
-                let month = months % 12;
-
-                timestamp
-                    .with_year(year)
-                    .and_then(|d| d.with_month(month as u32))
-                    .ok_or_else(|| IntervalError::DateOverflow { year, month }.into())
-            }
+            Interval::Month(n) => add_months(timestamp, -n),
             Interval::Microsecond(n) => Ok(*timestamp - Duration::microseconds(*n)),
         }
     }
@@ -377,11 +371,35 @@ mod tests {
         assert_eq!(
             Interval::years(999_999).subtract_from_date(&date(2021, 11, 11)),
             Err(IntervalError::DateOverflow {
-                year: -997977,
-                month: -1,
+                year: -997978,
+                month: 11,
             }
             .into())
         );
+        assert_eq!(
+            Month(1).add_date(&date(2021, 12, 15)),
+            Ok(date(2022, 1, 15).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            Month(1).add_date(&date(2024, 1, 31)),
+            Ok(date(2024, 2, 29).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            Month(13).add_date(&date(2023, 1, 31)),
+            Ok(date(2024, 2, 29).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            Month(1).subtract_from_date(&date(2023, 3, 31)),
+            Ok(date(2023, 2, 28).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            Month(12).subtract_from_date(&date(2023, 12, 1)),
+            Ok(date(2022, 12, 1).and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            Interval::years(2000).subtract_from_date(&date(1970, 1, 1)),
+            Ok(date(-30, 1, 1).and_hms_opt(0, 0, 0).unwrap())
+        );
 
         // timestamp
         assert_eq!(
@@ -418,11 +436,15 @@ mod tests {
             Interval::years(999_999)
                 .subtract_from_timestamp(&date(2021, 11, 11).and_hms_opt(0, 0, 0).unwrap()),
             Err(IntervalError::DateOverflow {
-                year: -997977,
-                month: -1,
+                year: -997978,
+                month: 11,
             }
             .into())
         );
+        assert_eq!(
+            Month(1).add_timestamp(&date(2023, 12, 31).and_hms_opt(23, 59, 59).unwrap()),
+            Ok(date(2024, 1, 31).and_hms_opt(23, 59, 59).unwrap())
+        );
 
         // time
         assert_eq!(
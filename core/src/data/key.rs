@@ -3,7 +3,7 @@ use {
         data::{Interval, Value},
         result::{Error, Result},
     },
-    chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike},
+    chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike},
     ordered_float::OrderedFloat,
     rust_decimal::Decimal,
     serde::{Deserialize, Serialize},
@@ -46,6 +46,7 @@ pub enum Key {
     Bytea(Vec<u8>),
     Date(NaiveDate),
     Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<FixedOffset>),
     Time(NaiveTime),
     Interval(Interval),
     Uuid(u128),
@@ -74,6 +75,7 @@ impl Ord for Key {
             (Key::Bytea(l), Key::Bytea(r)) => l.cmp(r),
             (Key::Date(l), Key::Date(r)) => l.cmp(r),
             (Key::Timestamp(l), Key::Timestamp(r)) => l.cmp(r),
+            (Key::TimestampTz(l), Key::TimestampTz(r)) => l.cmp(r),
             (Key::Time(l), Key::Time(r)) => l.cmp(r),
             (Key::Interval(l), Key::Interval(r)) => l.cmp(r),
             (Key::Uuid(l), Key::Uuid(r)) => l.cmp(r),
@@ -100,6 +102,7 @@ impl Ord for Key {
             | (Key::Bytea(_), _)
             | (Key::Date(_), _)
             | (Key::Timestamp(_), _)
+            | (Key::TimestampTz(_), _)
             | (Key::Time(_), _)
             | (Key::Interval(_), _)
             | (Key::Uuid(_), _)
@@ -127,6 +130,7 @@ impl PartialOrd for Key {
             (Key::Inet(l), Key::Inet(r)) => Some(l.cmp(r)),
             (Key::Date(l), Key::Date(r)) => Some(l.cmp(r)),
             (Key::Timestamp(l), Key::Timestamp(r)) => Some(l.cmp(r)),
+            (Key::TimestampTz(l), Key::TimestampTz(r)) => Some(l.cmp(r)),
             (Key::Time(l), Key::Time(r)) => Some(l.cmp(r)),
             (Key::Interval(l), Key::Interval(r)) => l.partial_cmp(r),
             (Key::Uuid(l), Key::Uuid(r)) => Some(l.cmp(r)),
@@ -157,11 +161,12 @@ impl TryFrom<Value> for Key {
             F32(v) => Ok(Key::F32(OrderedFloat(v))),
             F64(v) => Ok(Key::F64(OrderedFloat(v))),
             Decimal(v) => Ok(Key::Decimal(v)),
-            Str(v) => Ok(Key::Str(v)),
+            Str(v) | Enum { value: v, .. } => Ok(Key::Str(v)),
             Bytea(v) => Ok(Key::Bytea(v)),
             Inet(v) => Ok(Key::Inet(v)),
             Date(v) => Ok(Key::Date(v)),
             Timestamp(v) => Ok(Key::Timestamp(v)),
+            TimestampTz(v) => Ok(Key::TimestampTz(v)),
             Time(v) => Ok(Key::Time(v)),
             Interval(v) => Ok(Key::Interval(v)),
             Uuid(v) => Ok(Key::Uuid(v)),
@@ -203,6 +208,7 @@ impl From<Key> for Value {
             Key::Inet(v) => Value::Inet(v),
             Key::Date(v) => Value::Date(v),
             Key::Timestamp(v) => Value::Timestamp(v),
+            Key::TimestampTz(v) => Value::TimestampTz(v),
             Key::Time(v) => Value::Time(v),
             Key::Interval(v) => Value::Interval(v),
             Key::Uuid(v) => Value::Uuid(v),
@@ -343,19 +349,8 @@ impl Key {
                     .copied()
                     .collect::<Vec<_>>()
             }
-            Key::Timestamp(datetime) => {
-                let date = datetime.num_days_from_ce();
-                let secs = datetime.num_seconds_from_midnight();
-                let frac = datetime.nanosecond();
-
-                [VALUE]
-                    .iter()
-                    .chain(date.to_be_bytes().iter())
-                    .chain(secs.to_be_bytes().iter())
-                    .chain(frac.to_be_bytes().iter())
-                    .copied()
-                    .collect::<Vec<_>>()
-            }
+            Key::Timestamp(datetime) => timestamp_to_cmp_be_bytes(datetime),
+            Key::TimestampTz(datetime) => timestamp_to_cmp_be_bytes(&datetime.naive_utc()),
             Key::Interval(interval) => {
                 let (month, microsec) = match interval {
                     Interval::Month(month) => (*month, 0),
@@ -379,6 +374,20 @@ impl Key {
     }
 }
 
+fn timestamp_to_cmp_be_bytes(datetime: &NaiveDateTime) -> Vec<u8> {
+    let date = datetime.num_days_from_ce();
+    let secs = datetime.num_seconds_from_midnight();
+    let frac = datetime.nanosecond();
+
+    [VALUE]
+        .iter()
+        .chain(date.to_be_bytes().iter())
+        .chain(secs.to_be_bytes().iter())
+        .chain(frac.to_be_bytes().iter())
+        .copied()
+        .collect::<Vec<_>>()
+}
+
 #[cfg(test)]
 mod tests {
     use {
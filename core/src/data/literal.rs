@@ -1,12 +1,12 @@
 use {
-    super::StringExt,
+    super::{value::parse_timestamp, StringExt},
     crate::{
         ast::AstLiteral,
         result::{Error, Result},
     },
     bigdecimal::BigDecimal,
     serde::Serialize,
-    std::{borrow::Cow, cmp::Ordering, convert::TryFrom, fmt::Debug},
+    std::{borrow::Cow, cmp::Ordering, convert::TryFrom, fmt::Debug, str::FromStr},
     thiserror::Error,
     Literal::*,
 };
@@ -74,11 +74,18 @@ impl<'a> Literal<'a> {
         }
     }
 
+    /// Texts are compared as dates or timestamps when both of them are in ISO format,
+    /// and a text is compared with a number when it is a numeric string.
     pub fn evaluate_cmp(&self, other: &Literal<'a>) -> Option<Ordering> {
         match (self, other) {
             (Boolean(l), Boolean(r)) => Some(l.cmp(r)),
             (Number(l), Number(r)) => Some(l.cmp(r)),
-            (Text(l), Text(r)) => Some(l.cmp(r)),
+            (Number(l), Text(r)) => BigDecimal::from_str(r).ok().map(|r| l.cmp(&Cow::Owned(r))),
+            (Text(l), Number(r)) => BigDecimal::from_str(l).ok().map(|l| Cow::Owned(l).cmp(r)),
+            (Text(l), Text(r)) => match (parse_timestamp(l), parse_timestamp(r)) {
+                (Some(l), Some(r)) => Some(l.cmp(&r)),
+                _ => Some(l.cmp(r)),
+            },
             (Bytea(l), Bytea(r)) => Some(l.cmp(r)),
             _ => None,
         }
@@ -112,9 +119,12 @@ impl<'a> Literal<'a> {
             Bytea(_) | Null => None,
         };
 
-        match (convert(self), convert(other)) {
-            (Some(l), Some(r)) => Literal::Text(Cow::Owned(l + &r)),
-            _ => Literal::Null,
+        match (self, other) {
+            (Bytea(l), Bytea(r)) => Bytea([l, r].concat()),
+            (l, r) => match (convert(l), convert(r)) {
+                (Some(l), Some(r)) => Literal::Text(Cow::Owned(l + &r)),
+                _ => Literal::Null,
+            },
         }
     }
 
@@ -192,6 +202,7 @@ impl<'a> Literal<'a> {
 
     pub fn like(&self, other: &Literal<'a>, case_sensitive: bool) -> Result<Self> {
         match (self, other) {
+            (Null, _) | (_, Null) => Ok(Null),
             (Text(l), Text(r)) => l.like(r, case_sensitive).map(Boolean),
             _ => Err(LiteralError::LikeOnNonString {
                 base: format!("{:?}", self),
@@ -301,6 +312,11 @@ mod tests {
         assert_eq!(text().concat(Null), Null);
         assert_eq!(Null.concat(Boolean(true)), Null);
         assert_eq!(Null.concat(Null), Null);
+        assert_eq!(
+            Bytea(vec![0, 1]).concat(Bytea(vec![255])),
+            Bytea(vec![0, 1, 255])
+        );
+        assert_eq!(Bytea(vec![0, 1]).concat(Null), Null);
     }
 
     #[test]
@@ -445,7 +461,15 @@ mod tests {
             num!("1234").evaluate_cmp(&num!("123")),
             Some(Ordering::Greater)
         );
-        assert_eq!(num!("123").evaluate_cmp(&text!("123")), None);
+        assert_eq!(
+            num!("123").evaluate_cmp(&text!("123.0")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            text!("12.5").evaluate_cmp(&num!("123")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(num!("123").evaluate_cmp(&text!("Foo")), None);
         assert_eq!(num!("123").evaluate_cmp(&Null), None);
         //text
         assert_eq!(text!("a").evaluate_cmp(&text!("b")), Some(Ordering::Less));
@@ -454,6 +478,14 @@ mod tests {
             text!("b").evaluate_cmp(&text!("a")),
             Some(Ordering::Greater)
         );
+        assert_eq!(
+            text!("2023-9-1").evaluate_cmp(&text!("2023-10-01")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            text!("2023-10-01").evaluate_cmp(&text!("2023-10-01 00:00:00")),
+            Some(Ordering::Equal)
+        );
         assert_eq!(text!("a").evaluate_cmp(&Null), None);
         //Bytea
         assert_eq!(
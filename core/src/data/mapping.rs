@@ -0,0 +1,197 @@
+use {
+    super::{Point, Value, ValueError},
+    crate::result::Result,
+    chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime},
+    rust_decimal::Decimal,
+    std::{collections::HashMap, net::IpAddr},
+};
+
+/// Converts a column value into a field of a struct deriving `FromRow`.
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Result<Self>;
+}
+
+/// Converts a field of a struct deriving `IntoRow` into a column value.
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+macro_rules! impl_from_value {
+    ($($target:ty), *) => {$(
+        impl FromValue for $target {
+            fn from_value(value: &Value) -> Result<Self> {
+                Self::try_from(value)
+            }
+        }
+    )*}
+}
+
+impl_from_value!(
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    f32,
+    f64,
+    Decimal,
+    NaiveDate,
+    NaiveTime,
+    NaiveDateTime,
+    DateTime<FixedOffset>,
+    IpAddr,
+    Point
+);
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Null => Err(ValueError::ImpossibleCast.into()),
+            _ => Ok(String::from(value)),
+        }
+    }
+}
+
+impl FromValue for HashMap<String, Value> {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Map(values) => Ok(values.clone()),
+            _ => Err(ValueError::ImpossibleCast.into()),
+        }
+    }
+}
+
+impl FromValue for Value {
+    fn from_value(value: &Value) -> Result<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Null => Ok(None),
+            _ => T::from_value(value).map(Some),
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::List(values) => values.iter().map(T::from_value).collect(),
+            _ => Err(ValueError::ImpossibleCast.into()),
+        }
+    }
+}
+
+macro_rules! impl_into_value {
+    ($($target:ty => $variant:ident), *) => {$(
+        impl IntoValue for $target {
+            fn into_value(self) -> Value {
+                Value::$variant(self)
+            }
+        }
+    )*}
+}
+
+impl_into_value!(
+    bool => Bool,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    f32 => F32,
+    f64 => F64,
+    Decimal => Decimal,
+    String => Str,
+    NaiveDate => Date,
+    NaiveTime => Time,
+    NaiveDateTime => Timestamp,
+    DateTime<FixedOffset> => TimestampTz,
+    IpAddr => Inet,
+    Point => Point,
+    HashMap<String, Value> => Map
+);
+
+impl IntoValue for &str {
+    fn into_value(self) -> Value {
+        Value::Str(self.to_owned())
+    }
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl<T: IntoValue> IntoValue for Option<T> {
+    fn into_value(self) -> Value {
+        self.map(IntoValue::into_value).unwrap_or(Value::Null)
+    }
+}
+
+impl<T: IntoValue> IntoValue for Vec<T> {
+    fn into_value(self) -> Value {
+        Value::List(self.into_iter().map(IntoValue::into_value).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{FromValue, IntoValue},
+        crate::data::{Value, ValueError},
+        std::collections::HashMap,
+    };
+
+    #[test]
+    fn from_value() {
+        assert_eq!(i64::from_value(&Value::I64(3)), Ok(3));
+        assert_eq!(
+            String::from_value(&Value::Str("glue".to_owned())),
+            Ok("glue".to_owned())
+        );
+        assert_eq!(
+            String::from_value(&Value::Null),
+            Err(ValueError::ImpossibleCast.into())
+        );
+        assert_eq!(Option::<i64>::from_value(&Value::Null), Ok(None));
+        assert_eq!(
+            Option::<bool>::from_value(&Value::Bool(true)),
+            Ok(Some(true))
+        );
+        assert_eq!(
+            Vec::<i64>::from_value(&Value::List(vec![Value::I64(1), Value::I64(2)])),
+            Ok(vec![1, 2])
+        );
+        assert_eq!(
+            HashMap::<String, Value>::from_value(&Value::I64(1)),
+            Err(ValueError::ImpossibleCast.into())
+        );
+    }
+
+    #[test]
+    fn into_value() {
+        assert_eq!(3_i32.into_value(), Value::I32(3));
+        assert_eq!("glue".into_value(), Value::Str("glue".to_owned()));
+        assert_eq!(None::<i64>.into_value(), Value::Null);
+        assert_eq!(
+            vec![Some(1_i64), None].into_value(),
+            Value::List(vec![Value::I64(1), Value::Null])
+        );
+    }
+}
@@ -1,10 +1,16 @@
 mod bigdecimal_ext;
+mod enum_type;
 mod function;
+mod hyper_log_log;
 mod interval;
 mod key;
 mod literal;
+mod mapping;
 mod point;
 mod row;
+mod schema_diff;
+mod schema_toml;
+mod sequence;
 mod string_ext;
 mod table;
 
@@ -13,14 +19,23 @@ pub mod value;
 
 pub use {
     bigdecimal_ext::BigDecimalExt,
+    enum_type::{EnumType, EnumTypeError},
     function::CustomFunction,
+    gluesql_derive::{FromRow, IntoRow},
+    hyper_log_log::HyperLogLog,
     interval::{Interval, IntervalError},
     key::{Key, KeyError},
     literal::{Literal, LiteralError},
+    mapping::{FromValue, IntoValue},
     point::Point,
     row::{Row, RowError},
-    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
+    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError, SchemaView},
+    schema_diff::{diff_schemas, SchemaChange},
+    schema_toml::{export_schema_to_toml, schemas_from_toml},
+    sequence::{CurrentValues, Sequence, SequenceError},
     string_ext::{StringExt, StringExtError},
-    table::{get_alias, get_index, TableError},
-    value::{HashMapJsonExt, NumericBinaryOperator, Value, ValueError},
+    table::{get_alias, get_index, unnest_column, TableError},
+    value::{
+        HashMapJsonExt, LogicalBinaryOperator, NumericBinaryOperator, TimeZone, Value, ValueError,
+    },
 };
@@ -12,6 +12,9 @@ pub enum RowError {
 
     #[error("conflict - map expected but vec row found")]
     ConflictOnUnexpectedVecRowFound,
+
+    #[error("column not found in row: {0}")]
+    ColumnNotFound(String),
 }
 
 #[derive(Clone, Debug, PartialEq)]
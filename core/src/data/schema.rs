@@ -1,6 +1,6 @@
 use {
     crate::{
-        ast::{ColumnDef, Expr, OrderByExpr, Statement, ToSql},
+        ast::{CheckConstraint, ColumnDef, Expr, ForeignKey, OrderByExpr, Query, Statement, ToSql},
         prelude::{parse, translate},
         result::Result,
     },
@@ -25,14 +25,32 @@ pub struct SchemaIndex {
     pub expr: Expr,
     pub order: SchemaIndexOrd,
     pub created: NaiveDateTime,
+    /// `CREATE UNIQUE INDEX`, `expr` is an `Expr::Tuple` of the columns when it is composite
+    #[serde(default)]
+    pub unique: bool,
+}
+
+/// Query of a view created by `CREATE VIEW`, which is expanded where the view is referenced
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchemaView {
+    /// Names of the columns, the labels of the query are used if empty
+    pub columns: Vec<String>,
+    pub query: Query,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Schema {
     pub table_name: String,
     pub column_defs: Option<Vec<ColumnDef>>,
+    #[serde(default)]
+    pub checks: Vec<CheckConstraint>,
+    #[serde(default)]
+    pub foreign_keys: Vec<ForeignKey>,
     pub indexes: Vec<SchemaIndex>,
     pub engine: Option<String>,
+    /// `Some` if the schema is a view, which has no columns and rows of its own
+    #[serde(default)]
+    pub view: Option<SchemaView>,
 }
 
 impl Schema {
@@ -40,25 +58,52 @@ impl Schema {
         let Schema {
             table_name,
             column_defs,
+            checks,
+            foreign_keys,
             indexes,
             engine,
-            ..
+            view,
         } = self;
 
+        if let Some(SchemaView { columns, query }) = view {
+            return Statement::CreateView {
+                or_replace: false,
+                name: table_name.to_owned(),
+                columns: columns.to_owned(),
+                query: Box::new(query.to_owned()),
+            }
+            .to_sql();
+        }
+
         let create_table = Statement::CreateTable {
             if_not_exists: false,
             name: table_name.to_owned(),
             columns: column_defs.to_owned(),
+            checks: checks.to_owned(),
+            foreign_keys: foreign_keys.to_owned(),
+            sequences: Vec::new(),
             engine: engine.to_owned(),
             source: None,
         }
         .to_sql();
 
-        let create_indexes = indexes.iter().map(|SchemaIndex { name, expr, .. }| {
-            let expr = expr.to_sql();
-
-            format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({expr});"#)
-        });
+        let create_indexes = indexes.iter().map(
+            |SchemaIndex {
+                 name, expr, unique, ..
+             }| {
+                Statement::CreateIndex {
+                    name: name.to_owned(),
+                    table_name: table_name.to_owned(),
+                    column: OrderByExpr {
+                        expr: expr.clone(),
+                        asc: None,
+                        nulls_first: None,
+                    },
+                    unique: *unique,
+                }
+                .to_sql()
+            },
+        );
 
         iter::once(create_table)
             .chain(create_indexes)
@@ -78,7 +123,8 @@ impl Schema {
                 match create_index {
                     Statement::CreateIndex {
                         name,
-                        column: OrderByExpr { expr, asc },
+                        column: OrderByExpr { expr, asc, .. },
+                        unique,
                         ..
                     } => {
                         let order = asc
@@ -90,6 +136,7 @@ impl Schema {
                             expr,
                             order,
                             created,
+                            unique,
                         };
 
                         Ok(index)
@@ -106,13 +153,35 @@ impl Schema {
             Statement::CreateTable {
                 name,
                 columns,
+                checks,
+                foreign_keys,
                 engine,
                 ..
             } => Ok(Schema {
                 table_name: name,
                 column_defs: columns,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view: None,
+            }),
+            Statement::CreateView {
+                name,
+                columns,
+                query,
+                ..
+            } => Ok(Schema {
+                table_name: name,
+                column_defs: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                indexes,
+                engine: None,
+                view: Some(SchemaView {
+                    columns,
+                    query: *query,
+                }),
             }),
             _ => Err(SchemaParseError::CannotParseDDL.into()),
         }
@@ -123,6 +192,18 @@ impl Schema {
 pub enum SchemaParseError {
     #[error("cannot parse ddl")]
     CannotParseDDL,
+
+    #[error("cannot read schema file: {0}")]
+    CannotReadFile(String),
+
+    #[error("cannot parse toml schema: {0}")]
+    CannotParseToml(String),
+
+    #[error("cannot serialize schema to toml: {0}")]
+    CannotSerializeToml(String),
+
+    #[error("column not found in schema: {0}")]
+    ColumnNotFound(String),
 }
 
 #[cfg(test)]
@@ -130,7 +211,10 @@ mod tests {
     use {
         super::SchemaParseError,
         crate::{
-            ast::{AstLiteral, ColumnDef, ColumnUniqueOption, Expr},
+            ast::{
+                AstLiteral, CheckConstraint, ColumnDef, ColumnUniqueOption, Expr, ForeignKey,
+                ReferentialAction,
+            },
             chrono::Utc,
             data::{Schema, SchemaIndex, SchemaIndexOrd},
             prelude::DataType,
@@ -141,22 +225,29 @@ mod tests {
         let Schema {
             table_name,
             column_defs,
+            checks,
+            foreign_keys,
             indexes,
             engine,
-            ..
+            view,
         } = actual;
 
         let Schema {
             table_name: table_name_e,
             column_defs: column_defs_e,
+            checks: checks_e,
+            foreign_keys: foreign_keys_e,
             indexes: indexes_e,
             engine: engine_e,
-            ..
+            view: view_e,
         } = expected;
 
         assert_eq!(table_name, table_name_e);
         assert_eq!(column_defs, column_defs_e);
+        assert_eq!(checks, checks_e);
+        assert_eq!(foreign_keys, foreign_keys_e);
         assert_eq!(engine, engine_e);
+        assert_eq!(view, view_e);
         indexes
             .into_iter()
             .zip(indexes_e)
@@ -165,18 +256,24 @@ mod tests {
 
     fn assert_index(actual: SchemaIndex, expected: SchemaIndex) {
         let SchemaIndex {
-            name, expr, order, ..
+            name,
+            expr,
+            order,
+            unique,
+            ..
         } = actual;
         let SchemaIndex {
             name: name_e,
             expr: expr_e,
             order: order_e,
+            unique: unique_e,
             ..
         } = expected;
 
         assert_eq!(name, name_e);
         assert_eq!(expr, expr_e);
         assert_eq!(order, order_e);
+        assert_eq!(unique, unique_e);
     }
 
     #[test]
@@ -199,8 +296,11 @@ mod tests {
                     unique: None,
                 },
             ]),
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
             indexes: Vec::new(),
             engine: None,
+            view: None,
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NULL DEFAULT 'glue');"#;
@@ -212,8 +312,11 @@ mod tests {
         let schema = Schema {
             table_name: "Test".to_owned(),
             column_defs: None,
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
             indexes: Vec::new(),
             engine: None,
+            view: None,
         };
         let ddl = r#"CREATE TABLE "Test";"#;
         assert_eq!(schema.to_ddl(), ddl);
@@ -233,8 +336,11 @@ mod tests {
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
             }]),
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
             indexes: Vec::new(),
             engine: None,
+            view: None,
         };
 
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL PRIMARY KEY);"#;
@@ -244,6 +350,77 @@ mod tests {
         assert_schema(actual, schema);
     }
 
+    #[test]
+    fn table_with_check() {
+        let schema = Schema {
+            table_name: "User".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "age".to_owned(),
+                data_type: DataType::Int,
+                nullable: false,
+                default: None,
+                unique: None,
+            }]),
+            checks: vec![CheckConstraint {
+                name: "User_age_check".to_owned(),
+                expr: Expr::IsNotNull(Box::new(Expr::Identifier("age".to_owned()))),
+            }],
+            foreign_keys: Vec::new(),
+            indexes: Vec::new(),
+            engine: None,
+            view: None,
+        };
+
+        let ddl = r#"CREATE TABLE "User" ("age" INT NOT NULL, CONSTRAINT "User_age_check" CHECK ("age" IS NOT NULL));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn table_with_foreign_key() {
+        let schema = Schema {
+            table_name: "Item".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "user_id".to_owned(),
+                data_type: DataType::Int,
+                nullable: true,
+                default: None,
+                unique: None,
+            }]),
+            checks: Vec::new(),
+            foreign_keys: vec![ForeignKey {
+                name: "Item_user_id_fkey".to_owned(),
+                column_name: "user_id".to_owned(),
+                referenced_table_name: "User".to_owned(),
+                referenced_column_name: "id".to_owned(),
+                on_delete: ReferentialAction::SetNull,
+            }],
+            indexes: Vec::new(),
+            engine: None,
+            view: None,
+        };
+
+        let ddl = r#"CREATE TABLE "Item" ("user_id" INT NULL, CONSTRAINT "Item_user_id_fkey" FOREIGN KEY ("user_id") REFERENCES "User" ("id") ON DELETE SET NULL);"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn view() {
+        let ddl = r#"CREATE VIEW "UserName" ("user_name") AS SELECT "name" AS "name" FROM "User";"#;
+        let schema = Schema::from_ddl(ddl).unwrap();
+        assert_eq!(schema.to_ddl(), ddl);
+        assert_eq!(schema.column_defs, None);
+        assert_eq!(
+            schema.view.map(|view| view.columns),
+            Some(vec!["user_name".to_owned()])
+        );
+    }
+
     #[test]
     fn invalid_ddl() {
         // Only Statement::CreateTable is supported
@@ -272,25 +449,30 @@ mod tests {
                     unique: None,
                 },
             ]),
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
             indexes: vec![
                 SchemaIndex {
                     name: "User_id".to_owned(),
                     expr: Expr::Identifier("id".to_owned()),
                     order: SchemaIndexOrd::Both,
                     created: Utc::now().naive_utc(),
+                    unique: false,
                 },
                 SchemaIndex {
                     name: "User_name".to_owned(),
                     expr: Expr::Identifier("name".to_owned()),
                     order: SchemaIndexOrd::Both,
                     created: Utc::now().naive_utc(),
+                    unique: true,
                 },
             ],
             engine: None,
+            view: None,
         };
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
 CREATE INDEX "User_id" ON "User" ("id");
-CREATE INDEX "User_name" ON "User" ("name");"#;
+CREATE UNIQUE INDEX "User_name" ON "User" ("name");"#;
         assert_eq!(schema.to_ddl(), ddl);
 
         let actual = Schema::from_ddl(ddl).unwrap();
@@ -322,13 +504,17 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     unique: None,
                 },
             ]),
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
             indexes: vec![SchemaIndex {
                 name: ".".to_owned(),
                 expr: Expr::Identifier(";".to_owned()),
                 order: SchemaIndexOrd::Both,
                 created: Utc::now().naive_utc(),
+                unique: false,
             }],
             engine: None,
+            view: None,
         };
         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
 CREATE INDEX "." ON "1" (";");"#;
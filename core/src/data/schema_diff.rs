@@ -0,0 +1,410 @@
+use {
+    super::{Schema, SchemaIndex},
+    crate::{
+        ast::{AlterTableOperation, ColumnDef, ColumnUniqueOption, Expr, OrderByExpr, Statement},
+        executor::AlterError,
+        result::Result,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SchemaChange {
+    AddColumn {
+        table_name: String,
+        column_def: ColumnDef,
+    },
+    DropColumn {
+        table_name: String,
+        column_name: String,
+    },
+    RenameColumn {
+        table_name: String,
+        old_column_name: String,
+        new_column_name: String,
+    },
+    /// Changes the data type, nullability or default value of the column
+    ModifyColumnType {
+        table_name: String,
+        column_def: ColumnDef,
+    },
+    AddConstraint {
+        table_name: String,
+        column_name: String,
+        unique: ColumnUniqueOption,
+    },
+    DropConstraint {
+        table_name: String,
+        column_name: String,
+    },
+    AddIndex {
+        table_name: String,
+        name: String,
+        expr: Expr,
+        unique: bool,
+    },
+    DropIndex {
+        table_name: String,
+        name: String,
+    },
+}
+
+impl SchemaChange {
+    /// Returns the statement which applies the change.
+    /// Changes which `ALTER TABLE` does not support yet return `AlterError::UnsupportedSchemaChange`.
+    pub fn to_statement(&self) -> Result<Statement> {
+        let alter_table = |table_name: &String, operation| Statement::AlterTable {
+            name: table_name.to_owned(),
+            operation,
+        };
+
+        let statement = match self {
+            SchemaChange::AddColumn {
+                table_name,
+                column_def,
+            } => alter_table(
+                table_name,
+                AlterTableOperation::AddColumn {
+                    column_def: column_def.clone(),
+                },
+            ),
+            SchemaChange::DropColumn {
+                table_name,
+                column_name,
+            } => alter_table(
+                table_name,
+                AlterTableOperation::DropColumn {
+                    column_name: column_name.to_owned(),
+                    if_exists: false,
+                    cascade: false,
+                },
+            ),
+            SchemaChange::RenameColumn {
+                table_name,
+                old_column_name,
+                new_column_name,
+            } => alter_table(
+                table_name,
+                AlterTableOperation::RenameColumn {
+                    old_column_name: old_column_name.to_owned(),
+                    new_column_name: new_column_name.to_owned(),
+                },
+            ),
+            SchemaChange::AddIndex {
+                table_name,
+                name,
+                expr,
+                unique,
+            } => Statement::CreateIndex {
+                name: name.to_owned(),
+                table_name: table_name.to_owned(),
+                column: OrderByExpr {
+                    expr: expr.clone(),
+                    asc: None,
+                    nulls_first: None,
+                },
+                unique: *unique,
+            },
+            SchemaChange::DropIndex { table_name, name } => Statement::DropIndex {
+                name: name.to_owned(),
+                table_name: table_name.to_owned(),
+            },
+            SchemaChange::ModifyColumnType { .. }
+            | SchemaChange::AddConstraint { .. }
+            | SchemaChange::DropConstraint { .. } => {
+                return Err(AlterError::UnsupportedSchemaChange(format!("{self:?}")).into());
+            }
+        };
+
+        Ok(statement)
+    }
+}
+
+/// Computes the changes which migrate the table of `from` into `to`.
+///
+/// A dropped column and an added column which share the same definition apart from the name
+/// are treated as a rename. Indexes are compared by their names and expressions.
+pub fn diff_schemas(from: &Schema, to: &Schema) -> Vec<SchemaChange> {
+    let table_name = &from.table_name;
+    let from_columns = from.column_defs.as_deref().unwrap_or_default();
+    let to_columns = to.column_defs.as_deref().unwrap_or_default();
+    let find_column = |column_defs: &'_ [ColumnDef], name: &str| {
+        column_defs
+            .iter()
+            .find(|column_def| column_def.name == name)
+            .cloned()
+    };
+
+    let mut dropped_columns = from_columns
+        .iter()
+        .filter(|column_def| find_column(to_columns, &column_def.name).is_none())
+        .collect::<Vec<_>>();
+    let mut renamed_columns = Vec::new();
+    let mut added_columns = Vec::new();
+    for column_def in to_columns
+        .iter()
+        .filter(|column_def| find_column(from_columns, &column_def.name).is_none())
+    {
+        let renamed = dropped_columns.iter().position(|dropped| {
+            let dropped = ColumnDef {
+                name: column_def.name.to_owned(),
+                ..(*dropped).clone()
+            };
+
+            &dropped == column_def
+        });
+
+        match renamed {
+            Some(i) => renamed_columns.push(SchemaChange::RenameColumn {
+                table_name: table_name.to_owned(),
+                old_column_name: dropped_columns.remove(i).name.to_owned(),
+                new_column_name: column_def.name.to_owned(),
+            }),
+            None => added_columns.push(SchemaChange::AddColumn {
+                table_name: table_name.to_owned(),
+                column_def: column_def.clone(),
+            }),
+        }
+    }
+    let dropped_columns = dropped_columns
+        .into_iter()
+        .map(|column_def| SchemaChange::DropColumn {
+            table_name: table_name.to_owned(),
+            column_name: column_def.name.to_owned(),
+        });
+
+    let modified_columns = to_columns.iter().flat_map(|column_def| {
+        let from_column_def = match find_column(from_columns, &column_def.name) {
+            Some(from_column_def) => from_column_def,
+            None => return Vec::new(),
+        };
+
+        let mut changes = Vec::new();
+        let ColumnDef {
+            name,
+            data_type,
+            nullable,
+            default,
+            unique,
+        } = column_def;
+
+        if (data_type, nullable, default)
+            != (
+                &from_column_def.data_type,
+                &from_column_def.nullable,
+                &from_column_def.default,
+            )
+        {
+            changes.push(SchemaChange::ModifyColumnType {
+                table_name: table_name.to_owned(),
+                column_def: column_def.clone(),
+            });
+        }
+
+        if unique != &from_column_def.unique {
+            if from_column_def.unique.is_some() {
+                changes.push(SchemaChange::DropConstraint {
+                    table_name: table_name.to_owned(),
+                    column_name: name.to_owned(),
+                });
+            }
+
+            if let Some(unique) = unique {
+                changes.push(SchemaChange::AddConstraint {
+                    table_name: table_name.to_owned(),
+                    column_name: name.to_owned(),
+                    unique: *unique,
+                });
+            }
+        }
+
+        changes
+    });
+
+    let contains_index = |indexes: &[SchemaIndex], index: &SchemaIndex| {
+        indexes.iter().any(|other| {
+            other.name == index.name && other.expr == index.expr && other.unique == index.unique
+        })
+    };
+    let dropped_indexes = from
+        .indexes
+        .iter()
+        .filter(|index| !contains_index(&to.indexes, index))
+        .map(|SchemaIndex { name, .. }| SchemaChange::DropIndex {
+            table_name: table_name.to_owned(),
+            name: name.to_owned(),
+        });
+    let added_indexes = to
+        .indexes
+        .iter()
+        .filter(|index| !contains_index(&from.indexes, index))
+        .map(
+            |SchemaIndex {
+                 name, expr, unique, ..
+             }| SchemaChange::AddIndex {
+                table_name: table_name.to_owned(),
+                name: name.to_owned(),
+                expr: expr.clone(),
+                unique: *unique,
+            },
+        );
+
+    dropped_indexes
+        .chain(renamed_columns)
+        .chain(dropped_columns)
+        .chain(added_columns)
+        .chain(modified_columns)
+        .chain(added_indexes)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{diff_schemas, SchemaChange},
+        crate::{
+            ast::{AstLiteral, BinaryOperator, ColumnDef, ColumnUniqueOption, Expr},
+            data::Schema,
+            executor::AlterError,
+            prelude::DataType,
+        },
+        bigdecimal::BigDecimal,
+    };
+
+    fn diff(from: &str, to: &str) -> Vec<SchemaChange> {
+        let from = Schema::from_ddl(from).unwrap();
+        let to = Schema::from_ddl(to).unwrap();
+
+        diff_schemas(&from, &to)
+    }
+
+    #[test]
+    fn columns() {
+        let actual = diff(
+            "CREATE TABLE Foo (id INTEGER, name TEXT);",
+            "CREATE TABLE Foo (id INTEGER, name TEXT);",
+        );
+        assert_eq!(actual, Vec::new(), "no changes");
+
+        let actual = diff(
+            "CREATE TABLE Foo (id INTEGER, name TEXT);",
+            "CREATE TABLE Foo (id INTEGER, title TEXT);",
+        );
+        let expected = vec![SchemaChange::RenameColumn {
+            table_name: "Foo".to_owned(),
+            old_column_name: "name".to_owned(),
+            new_column_name: "title".to_owned(),
+        }];
+        assert_eq!(actual, expected, "rename column");
+
+        let actual = diff(
+            "CREATE TABLE Foo (id INTEGER, name TEXT);",
+            "CREATE TABLE Foo (id INTEGER, age INTEGER NULL);",
+        );
+        let expected = vec![
+            SchemaChange::DropColumn {
+                table_name: "Foo".to_owned(),
+                column_name: "name".to_owned(),
+            },
+            SchemaChange::AddColumn {
+                table_name: "Foo".to_owned(),
+                column_def: ColumnDef {
+                    name: "age".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                },
+            },
+        ];
+        assert_eq!(actual, expected, "drop and add column");
+
+        let actual = diff(
+            "CREATE TABLE Foo (id INTEGER, name TEXT);",
+            "CREATE TABLE Foo (id INTEGER NOT NULL, name TEXT);",
+        );
+        let expected = vec![SchemaChange::ModifyColumnType {
+            table_name: "Foo".to_owned(),
+            column_def: ColumnDef {
+                name: "id".to_owned(),
+                data_type: DataType::Int,
+                nullable: false,
+                default: None,
+                unique: None,
+            },
+        }];
+        assert_eq!(actual, expected, "modify column");
+
+        let actual = diff(
+            "CREATE TABLE Foo (id INTEGER PRIMARY KEY, name TEXT);",
+            "CREATE TABLE Foo (id INTEGER NOT NULL UNIQUE, name TEXT);",
+        );
+        let expected = vec![
+            SchemaChange::DropConstraint {
+                table_name: "Foo".to_owned(),
+                column_name: "id".to_owned(),
+            },
+            SchemaChange::AddConstraint {
+                table_name: "Foo".to_owned(),
+                column_name: "id".to_owned(),
+                unique: ColumnUniqueOption { is_primary: false },
+            },
+        ];
+        assert_eq!(actual, expected, "change constraint");
+    }
+
+    #[test]
+    fn indexes() {
+        let actual = diff(
+            r#"
+            CREATE TABLE Foo (id INTEGER, name TEXT);
+            CREATE INDEX "idx_id" ON "Foo" ("id");
+            CREATE INDEX "idx_name" ON "Foo" ("name");
+            "#,
+            r#"
+            CREATE TABLE Foo (id INTEGER, name TEXT);
+            CREATE UNIQUE INDEX "idx_id" ON "Foo" ("id");
+            CREATE INDEX "idx_name" ON "Foo" ("id" + 1);
+            "#,
+        );
+        let expected = vec![
+            SchemaChange::DropIndex {
+                table_name: "Foo".to_owned(),
+                name: "idx_id".to_owned(),
+            },
+            SchemaChange::DropIndex {
+                table_name: "Foo".to_owned(),
+                name: "idx_name".to_owned(),
+            },
+            SchemaChange::AddIndex {
+                table_name: "Foo".to_owned(),
+                name: "idx_id".to_owned(),
+                expr: Expr::Identifier("id".to_owned()),
+                unique: true,
+            },
+            SchemaChange::AddIndex {
+                table_name: "Foo".to_owned(),
+                name: "idx_name".to_owned(),
+                expr: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("id".to_owned())),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(BigDecimal::from(1)))),
+                },
+                unique: false,
+            },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_statement() {
+        let change = SchemaChange::DropConstraint {
+            table_name: "Foo".to_owned(),
+            column_name: "id".to_owned(),
+        };
+        assert_eq!(
+            change.to_statement(),
+            Err(AlterError::UnsupportedSchemaChange(format!("{change:?}")).into())
+        );
+    }
+}
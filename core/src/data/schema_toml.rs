@@ -0,0 +1,272 @@
+use {
+    super::{Schema, SchemaParseError},
+    crate::{
+        ast::{ColumnDef, ColumnUniqueOption, ToSql},
+        parse_sql::{parse_data_type, parse_expr},
+        result::Result,
+        translate::{translate_data_type, translate_expr},
+    },
+    serde::{Deserialize, Serialize},
+    std::collections::BTreeMap,
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct TomlTable {
+    primary_key: Option<String>,
+    /// `None` for schemaless tables
+    columns: Option<Vec<TomlColumn>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    constraints: Vec<TomlConstraint>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct TomlColumn {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: String,
+    #[serde(default = "nullable_by_default")]
+    nullable: bool,
+    /// SQL expression, e.g. `'glue'` or `NOW()`
+    default: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TomlConstraint {
+    Unique { column: String },
+}
+
+fn nullable_by_default() -> bool {
+    true
+}
+
+/// Parses table definitions written as TOML tables keyed by table name.
+///
+/// ```toml
+/// [Item]
+/// primary_key = "id"
+/// columns = [
+///     { name = "id", type = "INTEGER" },
+///     { name = "name", type = "TEXT", default = "'glue'" },
+/// ]
+/// constraints = [{ type = "unique", column = "name" }]
+/// ```
+pub fn schemas_from_toml(toml: &str) -> Result<Vec<Schema>> {
+    let tables = toml::from_str::<BTreeMap<String, TomlTable>>(toml)
+        .map_err(|e| SchemaParseError::CannotParseToml(e.to_string()))?;
+
+    tables
+        .into_iter()
+        .map(|(table_name, table)| {
+            let TomlTable {
+                primary_key,
+                columns,
+                constraints,
+            } = table;
+
+            let mut column_defs = match columns {
+                Some(columns) => columns
+                    .into_iter()
+                    .map(|column| {
+                        let TomlColumn {
+                            name,
+                            data_type,
+                            nullable,
+                            default,
+                        } = column;
+
+                        let data_type = translate_data_type(&parse_data_type(data_type)?)?;
+                        let default = default
+                            .map(|expr| parse_expr(expr).and_then(|expr| translate_expr(&expr)))
+                            .transpose()?;
+
+                        Ok(ColumnDef {
+                            name,
+                            data_type,
+                            nullable,
+                            default,
+                            unique: None,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                None => {
+                    return Ok(Schema {
+                        table_name,
+                        column_defs: None,
+                        checks: Vec::new(),
+                        foreign_keys: Vec::new(),
+                        indexes: Vec::new(),
+                        engine: None,
+                        view: None,
+                    });
+                }
+            };
+
+            let unique_columns = constraints
+                .into_iter()
+                .map(|TomlConstraint::Unique { column }| (column, false))
+                .chain(primary_key.map(|column| (column, true)));
+            for (column, is_primary) in unique_columns {
+                let column_def = column_defs
+                    .iter_mut()
+                    .find(|column_def| column_def.name == column)
+                    .ok_or(SchemaParseError::ColumnNotFound(column))?;
+
+                column_def.unique = Some(ColumnUniqueOption { is_primary });
+                if is_primary {
+                    column_def.nullable = false;
+                }
+            }
+
+            Ok(Schema {
+                table_name,
+                column_defs: Some(column_defs),
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                indexes: Vec::new(),
+                engine: None,
+                view: None,
+            })
+        })
+        .collect()
+}
+
+/// Generates the TOML table definition of the schema.
+/// Indexes and the storage engine are not included.
+pub fn export_schema_to_toml(schema: &Schema) -> Result<String> {
+    let column_defs = schema.column_defs.as_deref();
+    let primary_key = column_defs
+        .unwrap_or_default()
+        .iter()
+        .find(|column_def| {
+            matches!(
+                column_def.unique,
+                Some(ColumnUniqueOption { is_primary: true })
+            )
+        })
+        .map(|column_def| column_def.name.to_owned());
+    let constraints = column_defs
+        .unwrap_or_default()
+        .iter()
+        .filter(|column_def| {
+            matches!(
+                column_def.unique,
+                Some(ColumnUniqueOption { is_primary: false })
+            )
+        })
+        .map(|column_def| TomlConstraint::Unique {
+            column: column_def.name.to_owned(),
+        })
+        .collect();
+    let columns = column_defs.map(|column_defs| {
+        column_defs
+            .iter()
+            .map(|column_def| TomlColumn {
+                name: column_def.name.to_owned(),
+                data_type: column_def.data_type.to_string(),
+                nullable: column_def.nullable,
+                default: column_def.default.as_ref().map(ToSql::to_sql),
+            })
+            .collect()
+    });
+
+    let table = TomlTable {
+        primary_key,
+        columns,
+        constraints,
+    };
+    let tables = BTreeMap::from([(schema.table_name.to_owned(), table)]);
+
+    toml::to_string(&tables)
+        .map_err(|e| SchemaParseError::CannotSerializeToml(e.to_string()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{export_schema_to_toml, schemas_from_toml},
+        crate::{
+            ast::{AstLiteral, ColumnDef, ColumnUniqueOption, DataType, Expr},
+            data::{Schema, SchemaParseError},
+        },
+    };
+
+    #[test]
+    fn from_toml() {
+        let toml = r#"
+            [Item]
+            primary_key = "id"
+            columns = [
+                { name = "id", type = "INTEGER" },
+                { name = "name", type = "TEXT", nullable = false, default = "'glue'" },
+                { name = "rate", type = "FLOAT" },
+            ]
+            constraints = [{ type = "unique", column = "name" }]
+
+            [Log]
+        "#;
+        let actual = schemas_from_toml(toml).unwrap();
+        let expected = vec![
+            Schema {
+                table_name: "Item".to_owned(),
+                column_defs: Some(vec![
+                    ColumnDef {
+                        name: "id".to_owned(),
+                        data_type: DataType::Int,
+                        nullable: false,
+                        default: None,
+                        unique: Some(ColumnUniqueOption { is_primary: true }),
+                    },
+                    ColumnDef {
+                        name: "name".to_owned(),
+                        data_type: DataType::Text,
+                        nullable: false,
+                        default: Some(Expr::Literal(AstLiteral::QuotedString("glue".to_owned()))),
+                        unique: Some(ColumnUniqueOption { is_primary: false }),
+                    },
+                    ColumnDef {
+                        name: "rate".to_owned(),
+                        data_type: DataType::Float,
+                        nullable: true,
+                        default: None,
+                        unique: None,
+                    },
+                ]),
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                indexes: Vec::new(),
+                engine: None,
+                view: None,
+            },
+            Schema {
+                table_name: "Log".to_owned(),
+                column_defs: None,
+                checks: Vec::new(),
+                foreign_keys: Vec::new(),
+                indexes: Vec::new(),
+                engine: None,
+                view: None,
+            },
+        ];
+        assert_eq!(actual, expected);
+
+        for schema in expected {
+            let toml = export_schema_to_toml(&schema).unwrap();
+            assert_eq!(
+                schemas_from_toml(&toml),
+                Ok(vec![schema]),
+                "round trip:\n{toml}"
+            );
+        }
+
+        let toml = r#"
+            [Item]
+            primary_key = "key"
+            columns = [{ name = "id", type = "INTEGER" }]
+        "#;
+        assert_eq!(
+            schemas_from_toml(toml),
+            Err(SchemaParseError::ColumnNotFound("key".to_owned()).into())
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use {
+    serde::{Deserialize, Serialize},
+    std::{cell::RefCell, collections::HashMap},
+    thiserror::Error,
+};
+
+#[derive(Error, Serialize, Debug, PartialEq, Eq)]
+pub enum SequenceError {
+    #[error("sequence not found: {0}")]
+    SequenceNotFound(String),
+
+    #[error("sequence already exists: {0}")]
+    SequenceAlreadyExists(String),
+
+    #[error("INCREMENT of sequence '{0}' must not be zero")]
+    ZeroIncrement(String),
+
+    #[error("MINVALUE {min_value} of sequence '{name}' must be less than MAXVALUE {max_value}")]
+    InvalidRange {
+        name: String,
+        min_value: i64,
+        max_value: i64,
+    },
+
+    #[error("value {value} of sequence '{name}' is out of range [{min_value}, {max_value}]")]
+    ValueOutOfRange {
+        name: String,
+        value: i64,
+        min_value: i64,
+        max_value: i64,
+    },
+
+    #[error("sequence '{name}' reached its maximum value {max_value}")]
+    MaxValueReached { name: String, max_value: i64 },
+
+    #[error("sequence '{name}' reached its minimum value {min_value}")]
+    MinValueReached { name: String, min_value: i64 },
+
+    #[error("CURRVAL of sequence '{0}' is not yet defined in this session")]
+    CurrentValueNotDefined(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sequence {
+    pub name: String,
+    pub start: i64,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub cycle: bool,
+    /// Table whose `SERIAL` column created the sequence, the sequence is dropped with the table
+    pub owned_by: Option<String>,
+    /// Value returned by the last `NEXTVAL` or set by `SETVAL`, `None` before the first `NEXTVAL`
+    pub last_value: Option<i64>,
+}
+
+impl Sequence {
+    /// Creates a sequence, the bounds and the start which are not given follow the direction of
+    /// `increment`: `1..=i64::MAX` starting from `1` for an ascending sequence and
+    /// `i64::MIN..=-1` starting from `-1` for a descending one.
+    pub fn new(
+        name: String,
+        start: Option<i64>,
+        increment: Option<i64>,
+        min_value: Option<i64>,
+        max_value: Option<i64>,
+        cycle: bool,
+    ) -> Result<Self, SequenceError> {
+        let increment = increment.unwrap_or(1);
+        if increment == 0 {
+            return Err(SequenceError::ZeroIncrement(name));
+        }
+
+        let ascending = increment > 0;
+        let min_value = min_value.unwrap_or(if ascending { 1 } else { i64::MIN });
+        let max_value = max_value.unwrap_or(if ascending { i64::MAX } else { -1 });
+        if min_value >= max_value {
+            return Err(SequenceError::InvalidRange {
+                name,
+                min_value,
+                max_value,
+            });
+        }
+
+        let start = start.unwrap_or(if ascending { min_value } else { max_value });
+        let sequence = Self {
+            name,
+            start,
+            increment,
+            min_value,
+            max_value,
+            cycle,
+            owned_by: None,
+            last_value: None,
+        };
+
+        sequence.validate_range(start)?;
+
+        Ok(sequence)
+    }
+
+    /// Advances the sequence and returns the new value.
+    /// A sequence with `CYCLE` wraps around to the other bound when it passes one.
+    pub fn next_value(&mut self) -> Result<i64, SequenceError> {
+        let value = match self.last_value {
+            None => self.start,
+            Some(last_value) => match last_value
+                .checked_add(self.increment)
+                .filter(|value| (self.min_value..=self.max_value).contains(value))
+            {
+                Some(value) => value,
+                None if self.cycle && self.increment > 0 => self.min_value,
+                None if self.cycle => self.max_value,
+                None if self.increment > 0 => {
+                    return Err(SequenceError::MaxValueReached {
+                        name: self.name.to_owned(),
+                        max_value: self.max_value,
+                    });
+                }
+                None => {
+                    return Err(SequenceError::MinValueReached {
+                        name: self.name.to_owned(),
+                        min_value: self.min_value,
+                    });
+                }
+            },
+        };
+
+        self.last_value = Some(value);
+
+        Ok(value)
+    }
+
+    /// Sets the last value of the sequence, the next `NEXTVAL` returns the value after it.
+    pub fn set_value(&mut self, value: i64) -> Result<(), SequenceError> {
+        self.validate_range(value)?;
+        self.last_value = Some(value);
+
+        Ok(())
+    }
+
+    fn validate_range(&self, value: i64) -> Result<(), SequenceError> {
+        if (self.min_value..=self.max_value).contains(&value) {
+            return Ok(());
+        }
+
+        Err(SequenceError::ValueOutOfRange {
+            name: self.name.to_owned(),
+            value,
+            min_value: self.min_value,
+            max_value: self.max_value,
+        })
+    }
+}
+
+/// Values returned by `NEXTVAL` or set by `SETVAL` in a session, read by `CURRVAL`.
+/// Each `Glue` keeps its own values and lends them to the statements it executes.
+#[derive(Debug, Default)]
+pub struct CurrentValues(RefCell<HashMap<String, i64>>);
+
+impl CurrentValues {
+    pub(crate) fn get(&self, name: &str) -> Result<i64, SequenceError> {
+        self.0
+            .borrow()
+            .get(name)
+            .copied()
+            .ok_or_else(|| SequenceError::CurrentValueNotDefined(name.to_owned()))
+    }
+
+    pub(crate) fn set(&self, name: &str, value: i64) {
+        self.0.borrow_mut().insert(name.to_owned(), value);
+    }
+
+    /// Forgets the value of a sequence which is created or dropped.
+    pub(crate) fn reset(&self, name: &str) {
+        self.0.borrow_mut().remove(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurrentValues, Sequence, SequenceError};
+
+    #[test]
+    fn next_value() {
+        let mut sequence = Sequence::new("s".to_owned(), None, None, None, None, false).unwrap();
+        assert_eq!(sequence.next_value(), Ok(1));
+        assert_eq!(sequence.next_value(), Ok(2));
+
+        let mut sequence =
+            Sequence::new("s".to_owned(), Some(5), Some(5), None, Some(10), false).unwrap();
+        assert_eq!(sequence.next_value(), Ok(5));
+        assert_eq!(sequence.next_value(), Ok(10));
+        assert_eq!(
+            sequence.next_value(),
+            Err(SequenceError::MaxValueReached {
+                name: "s".to_owned(),
+                max_value: 10,
+            })
+        );
+
+        let mut sequence =
+            Sequence::new("s".to_owned(), None, Some(-1), Some(-2), None, true).unwrap();
+        assert_eq!(sequence.next_value(), Ok(-1));
+        assert_eq!(sequence.next_value(), Ok(-2));
+        assert_eq!(sequence.next_value(), Ok(-1));
+
+        let mut sequence =
+            Sequence::new("s".to_owned(), Some(i64::MAX), None, None, None, false).unwrap();
+        assert_eq!(sequence.next_value(), Ok(i64::MAX));
+        assert_eq!(
+            sequence.next_value(),
+            Err(SequenceError::MaxValueReached {
+                name: "s".to_owned(),
+                max_value: i64::MAX,
+            })
+        );
+    }
+
+    #[test]
+    fn set_value() {
+        let mut sequence =
+            Sequence::new("s".to_owned(), None, Some(2), None, Some(10), false).unwrap();
+        assert_eq!(sequence.set_value(4), Ok(()));
+        assert_eq!(sequence.next_value(), Ok(6));
+        assert_eq!(
+            sequence.set_value(11),
+            Err(SequenceError::ValueOutOfRange {
+                name: "s".to_owned(),
+                value: 11,
+                min_value: 1,
+                max_value: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Sequence::new("s".to_owned(), None, Some(0), None, None, false),
+            Err(SequenceError::ZeroIncrement("s".to_owned()))
+        );
+        assert_eq!(
+            Sequence::new("s".to_owned(), None, None, Some(10), Some(1), false),
+            Err(SequenceError::InvalidRange {
+                name: "s".to_owned(),
+                min_value: 10,
+                max_value: 1,
+            })
+        );
+        assert_eq!(
+            Sequence::new("s".to_owned(), Some(0), None, None, None, false),
+            Err(SequenceError::ValueOutOfRange {
+                name: "s".to_owned(),
+                value: 0,
+                min_value: 1,
+                max_value: i64::MAX,
+            })
+        );
+    }
+
+    #[test]
+    fn current_values() {
+        let not_defined = Err(SequenceError::CurrentValueNotDefined("s".to_owned()));
+
+        let values = CurrentValues::default();
+        assert_eq!(values.get("s"), not_defined);
+
+        values.set("s", 3);
+        values.set("s", 4);
+        assert_eq!(values.get("s"), Ok(4));
+
+        values.reset("s");
+        assert_eq!(values.get("s"), not_defined);
+    }
+}
@@ -22,13 +22,54 @@ impl StringExt for str {
             }
         };
 
-        Ok(Regex::new(&format!(
-            "^{}$",
-            regex::escape(match_pattern.as_str())
-                .replace('%', ".*")
-                .replace('_', ".")
-        ))
-        .map_err(|_| StringExtError::UnreachablePatternParsing)?
-        .is_match(match_string.as_str()))
+        let regex = Regex::new(&format!("(?s)^{}$", pattern_to_regex(&match_pattern)))
+            .map_err(|_| StringExtError::UnreachablePatternParsing)?;
+
+        Ok(regex.is_match(match_string.as_str()))
     }
 }
+
+/// `%` matches any sequence of characters and `_` matches a single character.
+/// A backslash makes the character after it match literally, so `\_` matches an underscore.
+fn pattern_to_regex(pattern: &str) -> String {
+    let mut translated = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => translated.push_str(".*"),
+            '_' => translated.push('.'),
+            '\\' => {
+                let escaped = chars.next().unwrap_or('\\');
+                translated.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+            }
+            _ => translated.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+
+    translated
+}
+
+/// Translates a `SIMILAR TO` pattern into a regular expression. `%` and `_` are the `LIKE`
+/// wildcards, `|`, `*`, `+`, `?`, `{`, `}`, `(`, `)` and `[`, `]` keep their regex meaning,
+/// and every other character, including `.`, matches literally. A backslash escapes the next
+/// character.
+pub(crate) fn similar_to_regex(pattern: &str) -> String {
+    let mut translated = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => translated.push_str(".*"),
+            '_' => translated.push('.'),
+            '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' | '[' | ']' => translated.push(c),
+            '\\' => {
+                let escaped = chars.next().unwrap_or('\\');
+                translated.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+            }
+            _ => translated.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+
+    translated
+}
@@ -28,6 +28,10 @@ pub fn get_alias(table_factor: &TableFactor) -> &String {
             alias: TableAlias { name, .. },
             ..
         }
+        | TableFactor::Unnest {
+            alias: TableAlias { name, .. },
+            ..
+        }
         | TableFactor::Dictionary {
             alias: TableAlias { name, .. },
             ..
@@ -40,6 +44,12 @@ pub fn get_index(table_factor: &TableFactor) -> Option<&IndexItem> {
         TableFactor::Table { index, .. } => index.as_ref(),
         TableFactor::Derived { .. }
         | TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
         | TableFactor::Dictionary { .. } => None,
     }
 }
+
+/// `UNNEST` has a single column, which is named by the alias when no column alias is given.
+pub fn unnest_column(alias: &TableAlias) -> String {
+    alias.columns.first().unwrap_or(&alias.name).to_owned()
+}
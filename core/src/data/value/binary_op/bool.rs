@@ -0,0 +1,131 @@
+use {
+    super::TryBooleanOperator,
+    crate::{
+        data::{LogicalBinaryOperator, ValueError},
+        prelude::Value,
+        result::Result,
+    },
+    Value::*,
+};
+
+impl TryBooleanOperator for bool {
+    type Rhs = Value;
+
+    fn try_and(&self, rhs: &Self::Rhs) -> Result<Value> {
+        let lhs = *self;
+
+        match *rhs {
+            Bool(rhs) => Ok(Bool(lhs && rhs)),
+            Null if !lhs => Ok(Bool(false)),
+            Null => Ok(Null),
+            _ => Err(ValueError::NonBooleanLogicalOperation {
+                lhs: Bool(lhs),
+                operator: LogicalBinaryOperator::And,
+                rhs: rhs.clone(),
+            }
+            .into()),
+        }
+    }
+
+    fn try_or(&self, rhs: &Self::Rhs) -> Result<Value> {
+        let lhs = *self;
+
+        match *rhs {
+            Bool(rhs) => Ok(Bool(lhs || rhs)),
+            Null if lhs => Ok(Bool(true)),
+            Null => Ok(Null),
+            _ => Err(ValueError::NonBooleanLogicalOperation {
+                lhs: Bool(lhs),
+                operator: LogicalBinaryOperator::Or,
+                rhs: rhs.clone(),
+            }
+            .into()),
+        }
+    }
+
+    fn try_xor(&self, rhs: &Self::Rhs) -> Result<Value> {
+        let lhs = *self;
+
+        match *rhs {
+            Bool(rhs) => Ok(Bool(lhs ^ rhs)),
+            Null => Ok(Null),
+            _ => Err(ValueError::NonBooleanLogicalOperation {
+                lhs: Bool(lhs),
+                operator: LogicalBinaryOperator::Xor,
+                rhs: rhs.clone(),
+            }
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::TryBooleanOperator,
+        crate::{
+            data::{LogicalBinaryOperator, ValueError},
+            prelude::Value::*,
+        },
+    };
+
+    #[test]
+    fn try_and() {
+        assert_eq!(true.try_and(&Bool(true)), Ok(Bool(true)));
+        assert_eq!(true.try_and(&Bool(false)), Ok(Bool(false)));
+        assert_eq!(true.try_and(&Null), Ok(Null));
+        assert_eq!(false.try_and(&Bool(true)), Ok(Bool(false)));
+        assert_eq!(false.try_and(&Bool(false)), Ok(Bool(false)));
+        assert_eq!(false.try_and(&Null), Ok(Bool(false)));
+
+        assert_eq!(
+            true.try_and(&I64(1)),
+            Err(ValueError::NonBooleanLogicalOperation {
+                lhs: Bool(true),
+                operator: LogicalBinaryOperator::And,
+                rhs: I64(1),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn try_or() {
+        assert_eq!(true.try_or(&Bool(true)), Ok(Bool(true)));
+        assert_eq!(true.try_or(&Bool(false)), Ok(Bool(true)));
+        assert_eq!(true.try_or(&Null), Ok(Bool(true)));
+        assert_eq!(false.try_or(&Bool(true)), Ok(Bool(true)));
+        assert_eq!(false.try_or(&Bool(false)), Ok(Bool(false)));
+        assert_eq!(false.try_or(&Null), Ok(Null));
+
+        assert_eq!(
+            false.try_or(&Str("true".to_owned())),
+            Err(ValueError::NonBooleanLogicalOperation {
+                lhs: Bool(false),
+                operator: LogicalBinaryOperator::Or,
+                rhs: Str("true".to_owned()),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn try_xor() {
+        assert_eq!(true.try_xor(&Bool(true)), Ok(Bool(false)));
+        assert_eq!(true.try_xor(&Bool(false)), Ok(Bool(true)));
+        assert_eq!(true.try_xor(&Null), Ok(Null));
+        assert_eq!(false.try_xor(&Bool(true)), Ok(Bool(true)));
+        assert_eq!(false.try_xor(&Bool(false)), Ok(Bool(false)));
+        assert_eq!(false.try_xor(&Null), Ok(Null));
+
+        assert_eq!(
+            true.try_xor(&F64(1.0)),
+            Err(ValueError::NonBooleanLogicalOperation {
+                lhs: Bool(true),
+                operator: LogicalBinaryOperator::Xor,
+                rhs: F64(1.0),
+            }
+            .into())
+        );
+    }
+}
@@ -5,7 +5,7 @@ use {
         prelude::Value,
         result::Result,
     },
-    rust_decimal::prelude::Decimal,
+    rust_decimal::{prelude::Decimal, MathematicalOps},
     std::cmp::Ordering,
     Value::*,
 };
@@ -833,6 +833,34 @@ impl TryBinaryOperator for Decimal {
             .into()),
         }
     }
+
+    fn try_power(&self, rhs: &Self::Rhs) -> Result<Value> {
+        let lhs = *self;
+
+        match *rhs {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) | F32(_) | F64(_) | Decimal(_) => lhs
+                .checked_powd(Decimal::try_from(rhs)?)
+                .ok_or_else(|| {
+                    ValueError::BinaryOperationOverflow {
+                        lhs: Decimal(lhs),
+                        rhs: rhs.clone(),
+                        operator: NumericBinaryOperator::Power,
+                    }
+                    .into()
+                })
+                .map(Decimal),
+            Null => Ok(Null),
+            _ => Err(ValueError::NonNumericMathOperation {
+                lhs: Decimal(lhs),
+                operator: NumericBinaryOperator::Power,
+                rhs: rhs.clone(),
+            }
+            .into()),
+        }
+    }
+
+    super::impl_non_integer_bitwise_op!(Decimal);
 }
 
 #[cfg(test)]
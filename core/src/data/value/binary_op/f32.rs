@@ -5,7 +5,7 @@ use {
         prelude::Value,
         result::Result,
     },
-    rust_decimal::prelude::Decimal,
+    rust_decimal::{prelude::Decimal, MathematicalOps},
     std::cmp::Ordering,
     Value::*,
 };
@@ -75,7 +75,7 @@ impl TryBinaryOperator for f32 {
             U32(rhs) => Ok(F32(lhs + rhs as f32)),
             U64(rhs) => Ok(F32(lhs + rhs as f32)),
             U128(rhs) => Ok(F32(lhs + rhs as f32)),
-            F64(rhs) => Ok(F32(lhs + rhs as f32)),
+            F64(rhs) => Ok(F64(lhs as f64 + rhs)),
             F32(rhs) => Ok(F32(lhs + rhs)),
             Decimal(rhs) => Decimal::from_f32_retain(lhs)
                 .map(|x| Ok(Decimal(x + rhs)))
@@ -106,7 +106,7 @@ impl TryBinaryOperator for f32 {
             U32(rhs) => Ok(F32(lhs - rhs as f32)),
             U64(rhs) => Ok(F32(lhs - rhs as f32)),
             U128(rhs) => Ok(F32(lhs - rhs as f32)),
-            F64(rhs) => Ok(F32(lhs - rhs as f32)),
+            F64(rhs) => Ok(F64(lhs as f64 - rhs)),
             F32(rhs) => Ok(F32(lhs - rhs)),
             Decimal(rhs) => Decimal::from_f32_retain(lhs)
                 .map(|x| Ok(Decimal(x - rhs)))
@@ -137,7 +137,7 @@ impl TryBinaryOperator for f32 {
             U32(rhs) => Ok(F32(lhs * rhs as f32)),
             U64(rhs) => Ok(F32(lhs * rhs as f32)),
             U128(rhs) => Ok(F32(lhs * rhs as f32)),
-            F64(rhs) => Ok(F32(lhs * rhs as f32)),
+            F64(rhs) => Ok(F64(lhs as f64 * rhs)),
             F32(rhs) => Ok(F32(lhs * rhs)),
             Interval(rhs) => Ok(Interval(lhs * rhs)),
             Decimal(rhs) => Decimal::from_f32_retain(lhs)
@@ -158,6 +158,15 @@ impl TryBinaryOperator for f32 {
     fn try_divide(&self, rhs: &Self::Rhs) -> Result<Value> {
         let lhs = *self;
 
+        if rhs.is_zero() {
+            return Err(ValueError::BinaryOperationOverflow {
+                lhs: F32(lhs),
+                rhs: rhs.clone(),
+                operator: NumericBinaryOperator::Divide,
+            }
+            .into());
+        }
+
         match *rhs {
             I8(rhs) => Ok(F32(lhs / rhs as f32)),
             I16(rhs) => Ok(F32(lhs / rhs as f32)),
@@ -169,13 +178,22 @@ impl TryBinaryOperator for f32 {
             U32(rhs) => Ok(F32(lhs / rhs as f32)),
             U64(rhs) => Ok(F32(lhs / rhs as f32)),
             U128(rhs) => Ok(F32(lhs / rhs as f32)),
-            F64(rhs) => Ok(F32(lhs / rhs as f32)),
+            F64(rhs) => Ok(F64(lhs as f64 / rhs)),
             F32(rhs) => Ok(F32(lhs / rhs)),
-            Decimal(rhs) => Decimal::from_f32_retain(lhs)
-                .map(|x| Ok(Decimal(x * rhs)))
-                .unwrap_or_else(|| {
-                    Err(ValueError::FloatToDecimalConversionFailure(lhs.into()).into())
-                }),
+            Decimal(rhs) => match Decimal::from_f32_retain(lhs) {
+                Some(x) => x
+                    .checked_div(rhs)
+                    .map(|y| Ok(Decimal(y)))
+                    .unwrap_or_else(|| {
+                        Err(ValueError::BinaryOperationOverflow {
+                            lhs: F32(lhs),
+                            operator: NumericBinaryOperator::Divide,
+                            rhs: Decimal(rhs),
+                        }
+                        .into())
+                    }),
+                _ => Err(ValueError::FloatToDecimalConversionFailure(lhs.into()).into()),
+            },
             Null => Ok(Null),
             _ => Err(ValueError::NonNumericMathOperation {
                 lhs: F32(lhs),
@@ -189,6 +207,15 @@ impl TryBinaryOperator for f32 {
     fn try_modulo(&self, rhs: &Self::Rhs) -> Result<Value> {
         let lhs = *self;
 
+        if rhs.is_zero() {
+            return Err(ValueError::BinaryOperationOverflow {
+                lhs: F32(lhs),
+                rhs: rhs.clone(),
+                operator: NumericBinaryOperator::Modulo,
+            }
+            .into());
+        }
+
         match *rhs {
             I8(rhs) => Ok(F32(lhs % rhs as f32)),
             I16(rhs) => Ok(F32(lhs % rhs as f32)),
@@ -200,7 +227,7 @@ impl TryBinaryOperator for f32 {
             U32(rhs) => Ok(F32(lhs % rhs as f32)),
             U64(rhs) => Ok(F32(lhs % rhs as f32)),
             U128(rhs) => Ok(F32(lhs % rhs as f32)),
-            F64(rhs) => Ok(F32(lhs % rhs as f32)),
+            F64(rhs) => Ok(F64(lhs as f64 % rhs)),
             F32(rhs) => Ok(F32(lhs % rhs)),
             Decimal(rhs) => match Decimal::from_f32_retain(lhs) {
                 Some(x) => x
@@ -225,13 +252,52 @@ impl TryBinaryOperator for f32 {
             .into()),
         }
     }
+
+    fn try_power(&self, rhs: &Self::Rhs) -> Result<Value> {
+        let lhs = *self;
+
+        match *rhs {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) | F32(_) => Ok(F32(lhs.powf(f32::try_from(rhs)?))),
+            F64(rhs) => Ok(F64((lhs as f64).powf(rhs))),
+            Decimal(rhs) => match Decimal::from_f32_retain(lhs) {
+                Some(x) => x
+                    .checked_powd(rhs)
+                    .map(|y| Ok(Decimal(y)))
+                    .unwrap_or_else(|| {
+                        Err(ValueError::BinaryOperationOverflow {
+                            lhs: F32(lhs),
+                            operator: NumericBinaryOperator::Power,
+                            rhs: Decimal(rhs),
+                        }
+                        .into())
+                    }),
+                _ => Err(ValueError::FloatToDecimalConversionFailure(lhs.into()).into()),
+            },
+            Null => Ok(Null),
+            _ => Err(ValueError::NonNumericMathOperation {
+                lhs: F32(lhs),
+                operator: NumericBinaryOperator::Power,
+                rhs: rhs.clone(),
+            }
+            .into()),
+        }
+    }
+
+    super::impl_non_integer_bitwise_op!(F32);
 }
 
 #[cfg(test)]
 mod tests {
     use {
-        super::{TryBinaryOperator, Value::*},
-        crate::data::{NumericBinaryOperator, ValueError},
+        super::{
+            TryBinaryOperator,
+            Value::{self, *},
+        },
+        crate::{
+            data::{NumericBinaryOperator, ValueError},
+            result::Result,
+        },
         rust_decimal::prelude::Decimal,
         std::cmp::Ordering,
     };
@@ -295,7 +361,7 @@ mod tests {
         assert!(matches!(base.try_add(&U32(1)),Ok(F32(x)) if (x-2.0).abs() < f32::EPSILON));
         assert!(matches!(base.try_add(&U64(1)),Ok(F32(x)) if (x-2.0).abs() < f32::EPSILON));
         assert!(matches!(base.try_add(&U128(1)),Ok(F32(x)) if (x-2.0).abs()<f32::EPSILON));
-        assert!(matches!(base.try_add(&F64(1.0)), Ok(F32(x)) if (x - 2.0).abs() < f32::EPSILON ));
+        assert!(matches!(base.try_add(&F64(1.0)), Ok(F64(x)) if (x - 2.0).abs() < f64::EPSILON ));
         assert!(
             matches!(base.try_add(&F32(1.0_f32)), Ok(F32(x)) if (x - 2.0).abs() < f32::EPSILON )
         );
@@ -351,7 +417,7 @@ mod tests {
         );
 
         assert!(
-            matches!(base.try_subtract(&F64(1.0)), Ok(F32(x)) if (x - 0.0).abs() < f32::EPSILON )
+            matches!(base.try_subtract(&F64(1.0)), Ok(F64(x)) if (x - 0.0).abs() < f64::EPSILON )
         );
         assert!(
             matches!(base.try_subtract(&F32(1.0_f32)), Ok(F32(x)) if (x - 0.0).abs() < f32::EPSILON )
@@ -406,7 +472,7 @@ mod tests {
             matches!(base.try_multiply(&U128(1)), Ok(F32(x)) if (x - 1.0).abs() < f32::EPSILON )
         );
         assert!(
-            matches!(base.try_multiply(&F64(1.0)), Ok(F32(x)) if (x - 1.0).abs() < f32::EPSILON )
+            matches!(base.try_multiply(&F64(1.0)), Ok(F64(x)) if (x - 1.0).abs() < f64::EPSILON )
         );
         assert!(
             matches!(base.try_multiply(&F32(1.0_f32)), Ok(F32(x)) if (x - 1.0).abs() < f32::EPSILON )
@@ -446,7 +512,7 @@ mod tests {
         assert!(matches!(base.try_divide(&U128(1)), Ok(F32(x)) if (x - 1.0).abs() < f32::EPSILON ));
 
         assert!(
-            matches!(base.try_divide(&F64(1.0)), Ok(F32(x)) if (x - 1.0).abs() < f32::EPSILON )
+            matches!(base.try_divide(&F64(1.0)), Ok(F64(x)) if (x - 1.0).abs() < f64::EPSILON )
         );
         assert!(
             matches!(base.try_divide(&F32(1.0_f32)), Ok(F32(x)) if (x - 1.0).abs() < f32::EPSILON )
@@ -486,7 +552,7 @@ mod tests {
         assert!(matches!(base.try_modulo(&U128(1)), Ok(F32(x)) if (x - 0.0).abs() < f32::EPSILON ));
 
         assert!(
-            matches!(base.try_modulo(&F64(1.0)), Ok(F32(x)) if (x - 0.0).abs() < f32::EPSILON )
+            matches!(base.try_modulo(&F64(1.0)), Ok(F64(x)) if (x - 0.0).abs() < f64::EPSILON )
         );
         assert!(
             matches!(base.try_modulo(&F32(1.0_f32)), Ok(F32(x)) if (x - 0.0).abs() < f32::EPSILON )
@@ -518,4 +584,44 @@ mod tests {
             .into())
         );
     }
+
+    #[test]
+    fn try_divide_by_zero() {
+        let base = 1.0_f32;
+        let overflow_err = |rhs: Value, operator| -> Result<Value> {
+            Err(ValueError::BinaryOperationOverflow {
+                lhs: F32(base),
+                rhs,
+                operator,
+            }
+            .into())
+        };
+
+        for rhs in [
+            F32(0.0),
+            F32(-0.0),
+            F64(0.0),
+            I64(0),
+            U8(0),
+            Decimal(Decimal::ZERO),
+        ] {
+            assert_eq!(
+                base.try_divide(&rhs),
+                overflow_err(rhs.clone(), NumericBinaryOperator::Divide)
+            );
+            assert_eq!(
+                base.try_modulo(&rhs),
+                overflow_err(rhs, NumericBinaryOperator::Modulo)
+            );
+        }
+
+        let subnormal = f32::MIN_POSITIVE / 2.0;
+        assert!(subnormal.is_subnormal());
+        assert!(matches!(base.try_divide(&F32(subnormal)), Ok(F32(x)) if x.is_finite() && x > 0.0));
+        assert!(matches!(base.try_modulo(&F32(subnormal)), Ok(F32(_))));
+        assert_eq!(
+            base.try_divide(&Decimal(Decimal::TWO)),
+            Ok(Decimal(Decimal::new(5, 1)))
+        );
+    }
 }
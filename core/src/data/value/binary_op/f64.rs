@@ -5,7 +5,7 @@ use {
         prelude::Value,
         result::Result,
     },
-    rust_decimal::prelude::Decimal,
+    rust_decimal::{prelude::Decimal, MathematicalOps},
     std::cmp::Ordering,
     Value::*,
 };
@@ -152,6 +152,15 @@ impl TryBinaryOperator for f64 {
     fn try_divide(&self, rhs: &Self::Rhs) -> Result<Value> {
         let lhs = *self;
 
+        if rhs.is_zero() {
+            return Err(ValueError::BinaryOperationOverflow {
+                lhs: F64(lhs),
+                rhs: rhs.clone(),
+                operator: NumericBinaryOperator::Divide,
+            }
+            .into());
+        }
+
         match *rhs {
             I8(rhs) => Ok(F64(lhs / rhs as f64)),
             I16(rhs) => Ok(F64(lhs / rhs as f64)),
@@ -165,9 +174,20 @@ impl TryBinaryOperator for f64 {
             U128(rhs) => Ok(F64(lhs / rhs as f64)),
             F32(rhs) => Ok(F64(lhs / rhs as f64)),
             F64(rhs) => Ok(F64(lhs / rhs)),
-            Decimal(rhs) => Decimal::from_f64_retain(lhs)
-                .map(|x| Ok(Decimal(x * rhs)))
-                .unwrap_or_else(|| Err(ValueError::FloatToDecimalConversionFailure(lhs).into())),
+            Decimal(rhs) => match Decimal::from_f64_retain(lhs) {
+                Some(x) => x
+                    .checked_div(rhs)
+                    .map(|y| Ok(Decimal(y)))
+                    .unwrap_or_else(|| {
+                        Err(ValueError::BinaryOperationOverflow {
+                            lhs: F64(lhs),
+                            operator: NumericBinaryOperator::Divide,
+                            rhs: Decimal(rhs),
+                        }
+                        .into())
+                    }),
+                _ => Err(ValueError::FloatToDecimalConversionFailure(lhs).into()),
+            },
             Null => Ok(Null),
             _ => Err(ValueError::NonNumericMathOperation {
                 lhs: F64(lhs),
@@ -181,6 +201,15 @@ impl TryBinaryOperator for f64 {
     fn try_modulo(&self, rhs: &Self::Rhs) -> Result<Value> {
         let lhs = *self;
 
+        if rhs.is_zero() {
+            return Err(ValueError::BinaryOperationOverflow {
+                lhs: F64(lhs),
+                rhs: rhs.clone(),
+                operator: NumericBinaryOperator::Modulo,
+            }
+            .into());
+        }
+
         match *rhs {
             I8(rhs) => Ok(F64(lhs % rhs as f64)),
             I16(rhs) => Ok(F64(lhs % rhs as f64)),
@@ -217,13 +246,51 @@ impl TryBinaryOperator for f64 {
             .into()),
         }
     }
+
+    fn try_power(&self, rhs: &Self::Rhs) -> Result<Value> {
+        let lhs = *self;
+
+        match *rhs {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) | F32(_) | F64(_) => Ok(F64(lhs.powf(f64::try_from(rhs)?))),
+            Decimal(rhs) => match Decimal::from_f64_retain(lhs) {
+                Some(x) => x
+                    .checked_powd(rhs)
+                    .map(|y| Ok(Decimal(y)))
+                    .unwrap_or_else(|| {
+                        Err(ValueError::BinaryOperationOverflow {
+                            lhs: F64(lhs),
+                            operator: NumericBinaryOperator::Power,
+                            rhs: Decimal(rhs),
+                        }
+                        .into())
+                    }),
+                _ => Err(ValueError::FloatToDecimalConversionFailure(lhs).into()),
+            },
+            Null => Ok(Null),
+            _ => Err(ValueError::NonNumericMathOperation {
+                lhs: F64(lhs),
+                operator: NumericBinaryOperator::Power,
+                rhs: rhs.clone(),
+            }
+            .into()),
+        }
+    }
+
+    super::impl_non_integer_bitwise_op!(F64);
 }
 
 #[cfg(test)]
 mod tests {
     use {
-        super::{TryBinaryOperator, Value::*},
-        crate::data::{NumericBinaryOperator, ValueError},
+        super::{
+            TryBinaryOperator,
+            Value::{self, *},
+        },
+        crate::{
+            data::{NumericBinaryOperator, ValueError},
+            result::Result,
+        },
         rust_decimal::prelude::Decimal,
         std::cmp::Ordering,
     };
@@ -494,4 +561,44 @@ mod tests {
             .into())
         );
     }
+
+    #[test]
+    fn try_divide_by_zero() {
+        let base = 1.0_f64;
+        let overflow_err = |rhs: Value, operator| -> Result<Value> {
+            Err(ValueError::BinaryOperationOverflow {
+                lhs: F64(base),
+                rhs,
+                operator,
+            }
+            .into())
+        };
+
+        for rhs in [
+            F64(0.0),
+            F64(-0.0),
+            F32(0.0),
+            I64(0),
+            U8(0),
+            Decimal(Decimal::ZERO),
+        ] {
+            assert_eq!(
+                base.try_divide(&rhs),
+                overflow_err(rhs.clone(), NumericBinaryOperator::Divide)
+            );
+            assert_eq!(
+                base.try_modulo(&rhs),
+                overflow_err(rhs, NumericBinaryOperator::Modulo)
+            );
+        }
+
+        let subnormal = f64::MIN_POSITIVE / 2.0;
+        assert!(subnormal.is_subnormal());
+        assert!(matches!(base.try_divide(&F64(subnormal)), Ok(F64(x)) if x.is_finite() && x > 0.0));
+        assert!(matches!(base.try_modulo(&F64(subnormal)), Ok(F64(_))));
+        assert_eq!(
+            base.try_divide(&Decimal(Decimal::TWO)),
+            Ok(Decimal(Decimal::new(5, 1)))
+        );
+    }
 }
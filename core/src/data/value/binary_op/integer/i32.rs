@@ -1,6 +1,6 @@
 use {crate::prelude::Value, std::cmp::Ordering};
 
-super::macros::impl_try_binary_op!(I32, i32);
+super::macros::impl_try_binary_op!(I32, i32, [I64(i64), I128(i128)]);
 #[cfg(test)]
 super::macros::generate_binary_op_tests!(I32, i32);
 
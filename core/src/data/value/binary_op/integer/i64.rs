@@ -1,6 +1,6 @@
 use {crate::prelude::Value, std::cmp::Ordering};
 
-super::macros::impl_try_binary_op!(I64, i64);
+super::macros::impl_try_binary_op!(I64, i64, [I128(i128)]);
 #[cfg(test)]
 super::macros::generate_binary_op_tests!(I64, i64);
 
@@ -160,8 +160,31 @@ macro_rules! impl_method {
     }};
 }
 
+macro_rules! impl_bitwise_method {
+    ($lhs_variant: ident, $lhs: ident, $op: ident, $rhs: ident, $apply: expr) => {{
+        match *$rhs {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) => ($apply)($rhs)?.map($lhs_variant).ok_or_else(|| {
+                ValueError::BinaryOperationOverflow {
+                    lhs: $lhs_variant($lhs),
+                    rhs: $rhs.clone(),
+                    operator: $op,
+                }
+                .into()
+            }),
+            Null => Ok(Null),
+            _ => Err(ValueError::NonNumericMathOperation {
+                lhs: $lhs_variant($lhs),
+                operator: $op,
+                rhs: $rhs.clone(),
+            }
+            .into()),
+        }
+    }};
+}
+
 macro_rules! impl_try_binary_op {
-    ($variant: ident, $primitive: ident) => {
+    ($variant: ident, $primitive: ident, [$($wider_variant: ident($wider: ident)),*]) => {
         use $crate::{
             data::value::{
                 error::{NumericBinaryOperator::*, ValueError},
@@ -198,6 +221,94 @@ macro_rules! impl_try_binary_op {
                 let lhs = *self;
                 super::macros::impl_method!($variant, $primitive, lhs, checked_rem, Modulo, rhs)
             }
+
+            /// Integer exponents keep the result integer. When it does not fit into the base type,
+            /// it is widened one width at a time (e.g. `I8` -> `I16` -> `I32` -> `I64` -> `I128`),
+            /// and signed bases finally fall back to `U128`. Negative integer exponents and float
+            /// exponents return `F64`, and `Decimal` exponents return `Decimal`.
+            fn try_power(&self, rhs: &Self::Rhs) -> Result<Value> {
+                use rust_decimal::{Decimal, MathematicalOps};
+
+                let lhs = *self;
+                let overflow = || -> $crate::result::Error {
+                    ValueError::BinaryOperationOverflow {
+                        lhs: $variant(lhs),
+                        rhs: rhs.clone(),
+                        operator: Power,
+                    }
+                    .into()
+                };
+
+                match *rhs {
+                    I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_)
+                    | U64(_) | U128(_) => {
+                        let exp = f64::try_from(rhs)?;
+                        if exp < 0.0 {
+                            return Ok(F64((lhs as f64).powf(exp)));
+                        }
+
+                        let exp = u32::try_from(rhs).map_err(|_| overflow())?;
+
+                        lhs.checked_pow(exp)
+                            .map($variant)
+                            $(.or_else(|| $wider::from(lhs).checked_pow(exp).map($wider_variant)))*
+                            .or_else(|| u128::try_from(lhs).ok()?.checked_pow(exp).map(U128))
+                            .ok_or_else(overflow)
+                    }
+                    F32(rhs) => Ok(F64((lhs as f64).powf(rhs.into()))),
+                    F64(rhs) => Ok(F64((lhs as f64).powf(rhs))),
+                    Decimal(rhs) => Decimal::from(lhs)
+                        .checked_powd(rhs)
+                        .map(Decimal)
+                        .ok_or_else(overflow),
+                    Null => Ok(Null),
+                    _ => Err(ValueError::NonNumericMathOperation {
+                        lhs: $variant(lhs),
+                        operator: Power,
+                        rhs: rhs.clone(),
+                    }
+                    .into()),
+                }
+            }
+
+            fn try_bitwise_and(&self, rhs: &Self::Rhs) -> Result<Value> {
+                let lhs = *self;
+                super::macros::impl_bitwise_method!($variant, lhs, BitwiseAnd, rhs, |rhs| {
+                    $primitive::try_from(rhs).map(|rhs| Some(lhs & rhs))
+                })
+            }
+
+            fn try_bitwise_or(&self, rhs: &Self::Rhs) -> Result<Value> {
+                let lhs = *self;
+                super::macros::impl_bitwise_method!($variant, lhs, BitwiseOr, rhs, |rhs| {
+                    $primitive::try_from(rhs).map(|rhs| Some(lhs | rhs))
+                })
+            }
+
+            fn try_bitwise_xor(&self, rhs: &Self::Rhs) -> Result<Value> {
+                let lhs = *self;
+                super::macros::impl_bitwise_method!($variant, lhs, BitwiseXor, rhs, |rhs| {
+                    $primitive::try_from(rhs).map(|rhs| Some(lhs ^ rhs))
+                })
+            }
+
+            fn try_bitwise_not(&self) -> Result<Value> {
+                Ok($variant(!*self))
+            }
+
+            fn try_shift_left(&self, rhs: &Self::Rhs) -> Result<Value> {
+                let lhs = *self;
+                super::macros::impl_bitwise_method!($variant, lhs, ShiftLeft, rhs, |rhs| {
+                    u32::try_from(rhs).map(|rhs| lhs.checked_shl(rhs))
+                })
+            }
+
+            fn try_shift_right(&self, rhs: &Self::Rhs) -> Result<Value> {
+                let lhs = *self;
+                super::macros::impl_bitwise_method!($variant, lhs, ShiftRight, rhs, |rhs| {
+                    u32::try_from(rhs).map(|rhs| lhs.checked_shr(rhs))
+                })
+            }
         }
     };
 }
@@ -663,6 +774,46 @@ macro_rules! generate_binary_op_tests {
                     .into())
                 );
             }
+
+            #[test]
+            fn try_bitwise() {
+                let base: $primitive = 6;
+
+                assert_eq!(base.try_bitwise_and(&I8(3)), Ok($variant(2)));
+                assert_eq!(base.try_bitwise_and(&I64(3)), Ok($variant(2)));
+                assert_eq!(base.try_bitwise_and(&U128(3)), Ok($variant(2)));
+                assert_eq!(base.try_bitwise_or(&I16(3)), Ok($variant(7)));
+                assert_eq!(base.try_bitwise_or(&U32(3)), Ok($variant(7)));
+                assert_eq!(base.try_bitwise_xor(&I32(3)), Ok($variant(5)));
+                assert_eq!(base.try_bitwise_xor(&U8(3)), Ok($variant(5)));
+                assert_eq!(base.try_bitwise_not(), Ok($variant(!base)));
+                assert_eq!(base.try_shift_left(&I64(1)), Ok($variant(12)));
+                assert_eq!(base.try_shift_right(&U16(1)), Ok($variant(3)));
+                assert_eq!(base.try_bitwise_and(&Null), Ok(Null));
+
+                assert_eq!(
+                    base.try_shift_left(&I64(200)),
+                    overflow_err($variant(base), I64(200), ShiftLeft)
+                );
+                assert_eq!(
+                    base.try_bitwise_and(&F64(3.0)),
+                    Err(ValueError::NonNumericMathOperation {
+                        lhs: $variant(base),
+                        operator: NumericBinaryOperator::BitwiseAnd,
+                        rhs: F64(3.0)
+                    }
+                    .into())
+                );
+                assert_eq!(
+                    base.try_bitwise_or(&Decimal(Decimal::ONE)),
+                    Err(ValueError::NonNumericMathOperation {
+                        lhs: $variant(base),
+                        operator: NumericBinaryOperator::BitwiseOr,
+                        rhs: Decimal(Decimal::ONE)
+                    }
+                    .into())
+                );
+            }
         }
     };
 }
@@ -796,5 +947,6 @@ macro_rules! generate_cmp_ord_tests {
 #[cfg(test)]
 pub(crate) use {generate_binary_op_tests, generate_cmp_ord_tests};
 pub(crate) use {
-    impl_interval_method, impl_method, impl_partial_cmp_ord_method, impl_try_binary_op,
+    impl_bitwise_method, impl_interval_method, impl_method, impl_partial_cmp_ord_method,
+    impl_try_binary_op,
 };
@@ -1,6 +1,6 @@
 use {crate::prelude::Value, std::cmp::Ordering};
 
-super::macros::impl_try_binary_op!(U64, u64);
+super::macros::impl_try_binary_op!(U64, u64, [U128(u128)]);
 #[cfg(test)]
 super::macros::generate_binary_op_tests!(U64, u64);
 
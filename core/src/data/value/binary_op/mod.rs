@@ -1,5 +1,6 @@
 use crate::{prelude::Value, result::Result};
 
+mod bool;
 mod decimal;
 mod f32;
 mod f64;
@@ -14,4 +15,47 @@ pub trait TryBinaryOperator {
     fn try_multiply(&self, rhs: &Self::Rhs) -> Result<Value>;
     fn try_divide(&self, rhs: &Self::Rhs) -> Result<Value>;
     fn try_modulo(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_power(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_bitwise_and(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_bitwise_or(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_bitwise_xor(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_bitwise_not(&self) -> Result<Value>;
+    fn try_shift_left(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_shift_right(&self, rhs: &Self::Rhs) -> Result<Value>;
 }
+
+/// Logical operations following SQL three-valued logic, where `Null` stands for an unknown value
+pub trait TryBooleanOperator {
+    type Rhs;
+
+    fn try_and(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_or(&self, rhs: &Self::Rhs) -> Result<Value>;
+    fn try_xor(&self, rhs: &Self::Rhs) -> Result<Value>;
+}
+
+/// Bitwise operations of the non-integer types, which always fail
+macro_rules! impl_non_integer_bitwise_op {
+    ($variant: ident) => {
+        $crate::data::value::binary_op::impl_non_integer_bitwise_op!(@binary $variant, try_bitwise_and, BitwiseAnd);
+        $crate::data::value::binary_op::impl_non_integer_bitwise_op!(@binary $variant, try_bitwise_or, BitwiseOr);
+        $crate::data::value::binary_op::impl_non_integer_bitwise_op!(@binary $variant, try_bitwise_xor, BitwiseXor);
+        $crate::data::value::binary_op::impl_non_integer_bitwise_op!(@binary $variant, try_shift_left, ShiftLeft);
+        $crate::data::value::binary_op::impl_non_integer_bitwise_op!(@binary $variant, try_shift_right, ShiftRight);
+
+        fn try_bitwise_not(&self) -> $crate::result::Result<$crate::data::Value> {
+            Err($crate::data::ValueError::UnaryBitwiseNotOnNonInteger.into())
+        }
+    };
+    (@binary $variant: ident, $method: ident, $op: ident) => {
+        fn $method(&self, rhs: &Self::Rhs) -> $crate::result::Result<$crate::data::Value> {
+            Err($crate::data::ValueError::NonNumericMathOperation {
+                lhs: $variant(*self),
+                operator: $crate::data::NumericBinaryOperator::$op,
+                rhs: rhs.clone(),
+            }
+            .into())
+        }
+    };
+}
+
+pub(crate) use impl_non_integer_bitwise_op;
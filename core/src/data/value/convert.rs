@@ -1,13 +1,14 @@
 use {
     super::{
-        date::{parse_date, parse_time, parse_timestamp},
+        date::{parse_date, parse_time, parse_timestamp, parse_timestamp_tz},
+        time_zone::TimeZone,
         Value, ValueError,
     },
     crate::{
         data::{IntervalError, Point},
         result::{Error, Result},
     },
-    chrono::{NaiveDate, NaiveDateTime, NaiveTime},
+    chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime},
     rust_decimal::prelude::{Decimal, FromPrimitive, FromStr, ToPrimitive},
     std::net::IpAddr,
     uuid::Uuid,
@@ -34,6 +35,7 @@ impl From<&Value> for String {
             Value::F64(value) => value.to_string(),
             Value::Date(value) => value.to_string(),
             Value::Timestamp(value) => value.to_string(),
+            Value::TimestampTz(value) => value.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string(),
             Value::Time(value) => value.to_string(),
             Value::Interval(value) => value.to_sql_str(),
             Value::Uuid(value) => Uuid::from_u128(*value).to_string(),
@@ -45,6 +47,7 @@ impl From<&Value> for String {
                 .to_string(),
             Value::Decimal(value) => value.to_string(),
             Value::Point(value) => value.to_string(),
+            Value::Enum { value, .. } => value.to_owned(),
             Value::Null => String::from("NULL"),
         }
     }
@@ -53,7 +56,7 @@ impl From<&Value> for String {
 impl From<Value> for String {
     fn from(v: Value) -> String {
         match v {
-            Value::Str(value) => value,
+            Value::Str(value) | Value::Enum { value, .. } => value,
             _ => String::from(&v),
         }
     }
@@ -149,6 +152,7 @@ impl TryFrom<&Value> for bool {
             }
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -186,6 +190,7 @@ impl TryFrom<&Value> for i8 {
             Value::Decimal(value) => value.to_i8().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -223,6 +228,7 @@ impl TryFrom<&Value> for i16 {
             Value::Decimal(value) => value.to_i16().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -260,6 +266,7 @@ impl TryFrom<&Value> for i32 {
             Value::Decimal(value) => value.to_i32().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -297,6 +304,7 @@ impl TryFrom<&Value> for i64 {
             Value::Decimal(value) => value.to_i64().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -334,6 +342,7 @@ impl TryFrom<&Value> for i128 {
             Value::Decimal(value) => value.to_i128().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -371,6 +380,7 @@ impl TryFrom<&Value> for u8 {
             Value::Decimal(value) => value.to_u8().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -407,6 +417,7 @@ impl TryFrom<&Value> for u16 {
             Value::Decimal(value) => value.to_u16().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -445,6 +456,7 @@ impl TryFrom<&Value> for u32 {
             Value::Inet(IpAddr::V4(value)) => u32::from(*value),
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -482,6 +494,7 @@ impl TryFrom<&Value> for u64 {
             Value::Decimal(value) => value.to_u64().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -521,6 +534,7 @@ impl TryFrom<&Value> for u128 {
             Value::Uuid(value) => *value,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Map(_)
@@ -563,6 +577,7 @@ impl TryFrom<&Value> for f32 {
             Value::Decimal(value) => value.to_f32().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -606,6 +621,7 @@ impl TryFrom<&Value> for f64 {
             Value::Decimal(value) => value.to_f64().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -643,6 +659,7 @@ impl TryFrom<&Value> for usize {
             Value::Decimal(value) => value.to_usize().ok_or(ValueError::ImpossibleCast)?,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -686,6 +703,7 @@ impl TryFrom<&Value> for Decimal {
             Value::Decimal(value) => *value,
             Value::Date(_)
             | Value::Timestamp(_)
+            | Value::TimestampTz(_)
             | Value::Time(_)
             | Value::Interval(_)
             | Value::Uuid(_)
@@ -723,6 +741,7 @@ impl TryFrom<&Value> for NaiveDate {
         Ok(match v {
             Value::Date(value) => *value,
             Value::Timestamp(value) => value.date(),
+            Value::TimestampTz(value) => value.naive_utc().date(),
             Value::Str(value) => parse_date(value).ok_or(ValueError::ImpossibleCast)?,
             _ => return Err(ValueError::ImpossibleCast.into()),
         })
@@ -751,6 +770,29 @@ impl TryFrom<&Value> for NaiveDateTime {
                 .ok_or_else(|| IntervalError::FailedToParseTime(value.to_string()))?,
             Value::Str(value) => parse_timestamp(value).ok_or(ValueError::ImpossibleCast)?,
             Value::Timestamp(value) => *value,
+            Value::TimestampTz(value) => value.naive_utc(),
+            _ => return Err(ValueError::ImpossibleCast.into()),
+        })
+    }
+}
+
+impl TryFrom<&Value> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(v: &Value) -> Result<DateTime<FixedOffset>> {
+        Ok(match v {
+            Value::Date(value) => {
+                let value = value
+                    .and_hms_opt(0, 0, 0)
+                    .ok_or_else(|| IntervalError::FailedToParseTime(value.to_string()))?;
+
+                TimeZone::UTC.localize(&value)?
+            }
+            Value::Str(value) => {
+                parse_timestamp_tz(value, TimeZone::UTC).ok_or(ValueError::ImpossibleCast)?
+            }
+            Value::Timestamp(value) => TimeZone::UTC.localize(value)?,
+            Value::TimestampTz(value) => *value,
             _ => return Err(ValueError::ImpossibleCast.into()),
         })
     }
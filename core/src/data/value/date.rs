@@ -1,4 +1,7 @@
-use chrono::{offset::Utc, DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use {
+    super::time_zone::TimeZone,
+    chrono::{offset::Utc, DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime},
+};
 
 pub fn parse_date(v: &str) -> Option<NaiveDate> {
     if let Ok(v) = v.parse::<NaiveDate>() {
@@ -68,3 +71,21 @@ pub fn parse_timestamp(v: &str) -> Option<NaiveDateTime> {
 
     None
 }
+
+/// Parses a timestamp with a UTC offset such as `2024-01-01T00:00:00+09:00`,
+/// a timestamp without one is read in `time_zone`.
+pub fn parse_timestamp_tz(v: &str, time_zone: TimeZone) -> Option<DateTime<FixedOffset>> {
+    if let Ok(v) = v.parse::<DateTime<FixedOffset>>() {
+        return Some(v);
+    }
+
+    let forms = ["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%dT%H:%M:%S%.f%#z"];
+
+    for form in forms.iter() {
+        if let Ok(v) = DateTime::parse_from_str(v, form) {
+            return Some(v);
+        }
+    }
+
+    parse_timestamp(v).and_then(|v| time_zone.localize(&v).ok())
+}
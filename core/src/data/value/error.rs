@@ -20,6 +20,12 @@ pub enum ValueError {
     #[error("incompatible data type, data type: {data_type:#?}, value: {value:#?}")]
     IncompatibleDataType { data_type: DataType, value: Value },
 
+    #[error("'{value}' is not one of the enum variants: {variants:?}")]
+    InvalidEnumVariant {
+        value: String,
+        variants: Vec<String>,
+    },
+
     #[error("null value on not null field")]
     NullValueOnNotNullField,
 
@@ -38,6 +44,18 @@ pub enum ValueError {
     #[error("failed to parse time: {0}")]
     FailedToParseTime(String),
 
+    #[error("failed to parse time zone: {0}")]
+    FailedToParseTimeZone(String),
+
+    #[error("{timestamp} does not exist in time zone {time_zone}")]
+    NonexistentLocalTime {
+        timestamp: String,
+        time_zone: String,
+    },
+
+    #[error("AT TIME ZONE requires a timestamp value: {0:?}")]
+    AtTimeZoneOnNonTimestamp(Value),
+
     #[error("failed to UUID: {0}")]
     FailedToParseUUID(String),
 
@@ -60,6 +78,13 @@ pub enum ValueError {
         operator: NumericBinaryOperator,
     },
 
+    #[error("non-boolean values {lhs:?} {operator} {rhs:?}")]
+    NonBooleanLogicalOperation {
+        lhs: Value,
+        rhs: Value,
+        operator: LogicalBinaryOperator,
+    },
+
     #[error("the divisor should not be zero")]
     DivisorShouldNotBeZero,
 
@@ -69,6 +94,9 @@ pub enum ValueError {
     #[error("unary minus operation for non numeric value")]
     UnaryMinusOnNonNumeric,
 
+    #[error("unary bitwise not operation for non integer value")]
+    UnaryBitwiseNotOnNonInteger,
+
     #[error("unary factorial operation for non numeric value")]
     FactorialOnNonNumeric,
 
@@ -91,8 +119,8 @@ pub enum ValueError {
     #[error("unimplemented cast")]
     UnimplementedCast,
 
-    #[error("failed to cast from hex string to bytea: {0}")]
-    CastFromHexToByteaFailed(String),
+    #[error("failed to cast {from:?} to {to}")]
+    CastFailed { from: Value, to: DataType },
 
     #[error("function CONCAT requires at least 1 argument")]
     EmptyArgNotAllowedInConcat,
@@ -177,6 +205,19 @@ pub enum ValueError {
         case_sensitive: bool,
     },
 
+    #[error("operator doesn't exist: {base:?} {operator} {pattern:?}", operator = if *case_insensitive { "~*" } else { "~" })]
+    RegexpOnNonString {
+        base: Value,
+        pattern: Value,
+        case_insensitive: bool,
+    },
+
+    #[error("invalid regex pattern: {0}")]
+    InvalidRegexPattern(String),
+
+    #[error("operator doesn't exist: {base:?} SIMILAR TO {pattern:?}")]
+    SimilarToOnNonString { base: Value, pattern: Value },
+
     #[error("extract format not matched: {value:?} FROM {field:?})")]
     ExtractFormatNotMatched { value: Value, field: DateTimeField },
 
@@ -198,6 +239,12 @@ pub enum ValueError {
     #[error("selector requires MAP or LIST types")]
     SelectorRequiresMapOrListTypes,
 
+    #[error("invalid json path: {0}")]
+    InvalidJsonPath(String),
+
+    #[error("json extract requires MAP, LIST or json string: {0:?}")]
+    JsonExtractOnNonJson(Value),
+
     #[error("overflow occurred: {lhs:?} {operator} {rhs:?}")]
     BinaryOperationOverflow {
         lhs: Value,
@@ -208,6 +255,33 @@ pub enum ValueError {
     #[error("non numeric value in sqrt {0:?}")]
     SqrtOnNonNumeric(Value),
 
+    #[error("negative value in sqrt {0:?}")]
+    NegativeSqrt(Value),
+
+    #[error("non numeric value in abs {0:?}")]
+    AbsOnNonNumeric(Value),
+
+    #[error("overflow occurred in abs {0:?}")]
+    AbsOverflow(Value),
+
+    #[error("non numeric value in floor {0:?}")]
+    FloorOnNonNumeric(Value),
+
+    #[error("non numeric value in ceil {0:?}")]
+    CeilOnNonNumeric(Value),
+
+    #[error("non numeric value in round {0:?}")]
+    RoundOnNonNumeric(Value),
+
+    #[error("overflow occurred in round {value:?} with scale {scale}")]
+    RoundOverflow { value: Value, scale: i32 },
+
+    #[error("non numeric value in {function}: {value:?}")]
+    MathFunctionOnNonNumeric { function: String, value: Value },
+
+    #[error("{function} is not defined for {value:?}")]
+    DomainError { function: String, value: Value },
+
     #[error("non-string parameter in position: {} IN {}", String::from(.from), String::from(.sub))]
     NonStringParameterInPosition { from: Value, sub: Value },
 
@@ -233,4 +307,26 @@ pub enum NumericBinaryOperator {
     Divide,
     #[strum(to_string = "%")]
     Modulo,
+    #[strum(to_string = "^")]
+    Power,
+    #[strum(to_string = "&")]
+    BitwiseAnd,
+    #[strum(to_string = "|")]
+    BitwiseOr,
+    #[strum(to_string = "^")]
+    BitwiseXor,
+    #[strum(to_string = "<<")]
+    ShiftLeft,
+    #[strum(to_string = ">>")]
+    ShiftRight,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Display)]
+pub enum LogicalBinaryOperator {
+    #[strum(to_string = "AND")]
+    And,
+    #[strum(to_string = "OR")]
+    Or,
+    #[strum(to_string = "XOR")]
+    Xor,
 }
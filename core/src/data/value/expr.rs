@@ -71,6 +71,10 @@ impl TryFrom<Value> for Expr {
                 data_type: DataType::Timestamp,
                 value: DateTime::<Utc>::from_utc(v, Utc).to_string(),
             },
+            Value::TimestampTz(v) => Expr::TypedString {
+                data_type: DataType::TimestampTz,
+                value: v.to_rfc3339(),
+            },
             Value::Time(v) => Expr::TypedString {
                 data_type: DataType::Time,
                 value: v.to_string(),
@@ -115,6 +119,7 @@ impl TryFrom<Value> for Expr {
                 Expr::Literal(AstLiteral::QuotedString(json.to_string()))
             }
             Value::Point(v) => Expr::Literal(AstLiteral::QuotedString(v.to_string())),
+            Value::Enum { value, .. } => Expr::Literal(AstLiteral::QuotedString(value)),
             Value::Null => Expr::Literal(AstLiteral::Null),
         };
 
@@ -4,7 +4,7 @@ use {
     chrono::{offset::Utc, DateTime},
     core::str::FromStr,
     serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue},
-    std::collections::HashMap,
+    std::{collections::HashMap, iter},
     uuid::Uuid,
 };
 
@@ -48,6 +48,97 @@ impl Value {
 
         value.try_into()
     }
+
+    /// Extracts the nested value at `path`, which is a simplified JSONPath such as
+    /// `$.items[0].name` or `$["key"]`.
+    ///
+    /// `Str` values are parsed as JSON first, and paths which do not exist return `Null`.
+    pub fn try_json_extract(&self, path: &str) -> Result<Value> {
+        let segments = parse_json_path(path)?;
+        let parsed;
+        let mut value = match self {
+            Value::Null => return Ok(Value::Null),
+            Value::Map(_) | Value::List(_) => self,
+            Value::Str(v) => {
+                let json_value: JsonValue = serde_json::from_str(v)
+                    .map_err(|_| ValueError::InvalidJsonString(v.to_owned()))?;
+                parsed = Value::try_from(json_value)?;
+
+                &parsed
+            }
+            _ => return Err(ValueError::JsonExtractOnNonJson(self.clone()).into()),
+        };
+
+        for segment in segments {
+            let next = match (value, segment) {
+                (Value::Map(map), JsonPathSegment::Key(key)) => map.get(&key),
+                (Value::List(list), JsonPathSegment::Index(index)) => list.get(index),
+                _ => None,
+            };
+
+            match next {
+                Some(next) => value = next,
+                None => return Ok(Value::Null),
+            }
+        }
+
+        Ok(value.clone())
+    }
+}
+
+enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+fn parse_json_path(path: &str) -> Result<Vec<JsonPathSegment>> {
+    let invalid = || -> Error { ValueError::InvalidJsonPath(path.to_owned()).into() };
+    let mut chars = path
+        .strip_prefix('$')
+        .ok_or_else(invalid)?
+        .chars()
+        .peekable();
+    let mut segments = Vec::new();
+
+    while let Some(c) = chars.next() {
+        let segment = match c {
+            '.' => {
+                let key: String =
+                    iter::from_fn(|| chars.next_if(|c| *c != '.' && *c != '[')).collect();
+
+                if key.is_empty() {
+                    return Err(invalid());
+                }
+
+                JsonPathSegment::Key(key)
+            }
+            '[' => {
+                let inner: String = iter::from_fn(|| chars.next_if(|c| *c != ']')).collect();
+
+                if chars.next() != Some(']') {
+                    return Err(invalid());
+                }
+
+                let quoted = ['"', '\'']
+                    .into_iter()
+                    .find_map(|q| inner.strip_prefix(q).and_then(|v| v.strip_suffix(q)));
+
+                match quoted {
+                    Some(key) => JsonPathSegment::Key(key.to_owned()),
+                    None => inner
+                        .trim()
+                        .parse()
+                        .map(JsonPathSegment::Index)
+                        .map_err(|_| invalid())?,
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        segments.push(segment);
+    }
+
+    Ok(segments)
 }
 
 impl TryFrom<Value> for JsonValue {
@@ -80,6 +171,7 @@ impl TryFrom<Value> for JsonValue {
             Value::Inet(v) => Ok(v.to_string().into()),
             Value::Date(v) => Ok(v.to_string().into()),
             Value::Timestamp(v) => Ok(DateTime::<Utc>::from_utc(v, Utc).to_string().into()),
+            Value::TimestampTz(v) => Ok(v.to_rfc3339().into()),
             Value::Time(v) => Ok(v.to_string().into()),
             Value::Interval(v) => Ok(v.to_sql_str().into()),
             Value::Uuid(v) => Ok(Uuid::from_u128(v).hyphenated().to_string().into()),
@@ -94,6 +186,7 @@ impl TryFrom<Value> for JsonValue {
                 .collect::<Result<Vec<JsonValue>>>()
                 .map(|v| v.into()),
             Value::Point(v) => Ok(v.to_string().into()),
+            Value::Enum { value, .. } => Ok(value.into()),
             Value::Null => Ok(JsonValue::Null),
         }
     }
@@ -305,4 +398,48 @@ mod tests {
                 [("a".to_owned(), Value::Bool(true))].into_iter().collect()
             )));
     }
+
+    #[test]
+    fn try_json_extract() {
+        let doc = Value::Str(
+            r#"{ "a": { "b": [10, { "c": "glue" }] }, "d e": null, "f": true }"#.to_owned(),
+        );
+        let extract = |path: &str| doc.try_json_extract(path);
+
+        assert_eq!(extract("$.a.b[0]"), Ok(Value::I64(10)));
+        assert_eq!(extract("$.a.b[1].c"), Ok(Value::Str("glue".to_owned())));
+        assert_eq!(extract(r#"$["f"]"#), Ok(Value::Bool(true)));
+        assert_eq!(
+            extract("$.a.b[1]"),
+            Ok(Value::Map(
+                [("c".to_owned(), Value::Str("glue".to_owned()))]
+                    .into_iter()
+                    .collect()
+            ))
+        );
+        assert_eq!(extract("$['d e']"), Ok(Value::Null));
+        assert_eq!(extract("$.a.b[5]"), Ok(Value::Null));
+        assert_eq!(extract("$.missing.key"), Ok(Value::Null));
+        assert_eq!(extract("$.a[0]"), Ok(Value::Null));
+        assert!(matches!(extract("$"), Ok(Value::Map(_))));
+
+        for path in ["a.b", "$.", "$..a", "$[0", "$[x]", "$a"] {
+            assert_eq!(
+                extract(path),
+                Err(ValueError::InvalidJsonPath(path.to_owned()).into())
+            );
+        }
+
+        let list = Value::List(vec![Value::I64(1), Value::List(vec![Value::I64(2)])]);
+        assert_eq!(list.try_json_extract("$[1][0]"), Ok(Value::I64(2)));
+        assert_eq!(Value::Null.try_json_extract("$.a"), Ok(Value::Null));
+        assert_eq!(
+            Value::Str("{".to_owned()).try_json_extract("$.a"),
+            Err(ValueError::InvalidJsonString("{".to_owned()).into())
+        );
+        assert_eq!(
+            Value::I64(1).try_json_extract("$.a"),
+            Err(ValueError::JsonExtractOnNonJson(Value::I64(1)).into())
+        );
+    }
 }
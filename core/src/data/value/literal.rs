@@ -1,8 +1,8 @@
 use {
     super::{
-        date::{parse_date, parse_time, parse_timestamp},
+        date::{parse_date, parse_time, parse_timestamp, parse_timestamp_tz},
         error::ValueError,
-        Value,
+        validate_enum_variant, TimeZone, Value,
     },
     crate::{
         ast::DataType,
@@ -15,6 +15,7 @@ use {
         cmp::Ordering,
         net::{IpAddr, Ipv4Addr, Ipv6Addr},
         str::FromStr,
+        sync::Arc,
     },
 };
 
@@ -48,7 +49,8 @@ impl TryFrom<Literal<'_>> for Value {
 }
 
 impl Value {
-    pub fn evaluate_eq_with_literal(&self, other: &Literal<'_>) -> bool {
+    /// A text literal is read in `time_zone` against a `TIMESTAMPTZ` unless it has an offset.
+    pub fn evaluate_eq_with_literal(&self, other: &Literal<'_>, time_zone: TimeZone) -> bool {
         match (self, other) {
             (Value::Bool(l), Literal::Boolean(r)) => l == r,
             (Value::I8(l), Literal::Number(r)) => r.to_i8().map(|r| *l == r).unwrap_or(false),
@@ -63,7 +65,7 @@ impl Value {
             (Value::U128(l), Literal::Number(r)) => r.to_u128().map(|r| *l == r).unwrap_or(false),
             (Value::F32(l), Literal::Number(r)) => r.to_f32().map(|r| *l == r).unwrap_or(false),
             (Value::F64(l), Literal::Number(r)) => r.to_f64().map(|r| *l == r).unwrap_or(false),
-            (Value::Str(l), Literal::Text(r)) => l == r.as_ref(),
+            (Value::Str(l) | Value::Enum { value: l, .. }, Literal::Text(r)) => l == r.as_ref(),
             (Value::Bytea(l), Literal::Bytea(r)) => l == r,
             (Value::Date(l), Literal::Text(r)) => match r.parse::<NaiveDate>() {
                 Ok(r) => l == &r,
@@ -73,6 +75,10 @@ impl Value {
                 Some(r) => l == &r,
                 None => false,
             },
+            (Value::TimestampTz(l), Literal::Text(r)) => match parse_timestamp_tz(r, time_zone) {
+                Some(r) => l == &r,
+                None => false,
+            },
             (Value::Time(l), Literal::Text(r)) => match parse_time(r) {
                 Some(r) => l == &r,
                 None => false,
@@ -96,7 +102,12 @@ impl Value {
         }
     }
 
-    pub fn evaluate_cmp_with_literal(&self, other: &Literal<'_>) -> Option<Ordering> {
+    /// A text literal is read in `time_zone` against a `TIMESTAMPTZ` unless it has an offset.
+    pub fn evaluate_cmp_with_literal(
+        &self,
+        other: &Literal<'_>,
+        time_zone: TimeZone,
+    ) -> Option<Ordering> {
         match (self, other) {
             (Value::I8(l), Literal::Number(r)) => {
                 r.to_i8().map(|r| l.partial_cmp(&r)).unwrap_or(None)
@@ -135,7 +146,7 @@ impl Value {
             (Value::F64(l), Literal::Number(r)) => {
                 r.to_f64().map(|r| l.partial_cmp(&r)).unwrap_or(None)
             }
-            (Value::Str(l), Literal::Text(r)) => {
+            (Value::Str(l) | Value::Enum { value: l, .. }, Literal::Text(r)) => {
                 let l: &str = l.as_ref();
                 Some(l.cmp(r))
             }
@@ -147,6 +158,10 @@ impl Value {
                 Some(r) => l.partial_cmp(&r),
                 None => None,
             },
+            (Value::TimestampTz(l), Literal::Text(r)) => match parse_timestamp_tz(r, time_zone) {
+                Some(r) => l.partial_cmp(&r),
+                None => None,
+            },
             (Value::Time(l), Literal::Text(r)) => match parse_time(r) {
                 Some(r) => l.partial_cmp(&r),
                 None => None,
@@ -171,7 +186,12 @@ impl Value {
         }
     }
 
-    pub fn try_from_literal(data_type: &DataType, literal: &Literal<'_>) -> Result<Value> {
+    /// A `TIMESTAMPTZ` text without an offset is read in `time_zone`.
+    pub fn try_from_literal(
+        data_type: &DataType,
+        literal: &Literal<'_>,
+        time_zone: TimeZone,
+    ) -> Result<Value> {
         match (data_type, literal) {
             (DataType::Boolean, Literal::Boolean(v)) => Ok(Value::Bool(*v)),
             (DataType::Int8, Literal::Number(v)) => v
@@ -223,6 +243,15 @@ impl Value {
                 .map(Value::F64)
                 .ok_or_else(|| ValueError::UnreachableNumberParsing.into()),
             (DataType::Text, Literal::Text(v)) => Ok(Value::Str(v.to_string())),
+            (DataType::Enum(variants), Literal::Text(v)) => {
+                validate_enum_variant(v, variants).map(|_| Value::Enum {
+                    value: v.to_string(),
+                    variants: Arc::new(variants.to_vec()),
+                })
+            }
+            // the executor validates the text against the variants of the type before it is
+            // written, e.g. the default of a column added with `ALTER TABLE ... ADD COLUMN`
+            (DataType::UserDefined(_), Literal::Text(v)) => Ok(Value::Str(v.to_string())),
             (DataType::Bytea, Literal::Bytea(v)) => Ok(Value::Bytea(v.to_vec())),
             (DataType::Bytea, Literal::Text(v)) => hex::decode(v.as_ref())
                 .map(Value::Bytea)
@@ -246,6 +275,9 @@ impl Value {
             (DataType::Timestamp, Literal::Text(v)) => parse_timestamp(v)
                 .map(Value::Timestamp)
                 .ok_or_else(|| ValueError::FailedToParseTimestamp(v.to_string()).into()),
+            (DataType::TimestampTz, Literal::Text(v)) => parse_timestamp_tz(v, time_zone)
+                .map(Value::TimestampTz)
+                .ok_or_else(|| ValueError::FailedToParseTimestamp(v.to_string()).into()),
             (DataType::Time, Literal::Text(v)) => parse_time(v)
                 .map(Value::Time)
                 .ok_or_else(|| ValueError::FailedToParseTime(v.to_string()).into()),
@@ -267,7 +299,12 @@ impl Value {
         }
     }
 
-    pub fn try_cast_from_literal(data_type: &DataType, literal: &Literal<'_>) -> Result<Value> {
+    /// A `TIMESTAMPTZ` text without an offset is read in `time_zone`.
+    pub fn try_cast_from_literal(
+        data_type: &DataType,
+        literal: &Literal<'_>,
+        time_zone: TimeZone,
+    ) -> Result<Value> {
         match (data_type, literal) {
             (DataType::Boolean, Literal::Boolean(v)) => Ok(Value::Bool(*v)),
             (DataType::Boolean, Literal::Text(v)) => match v.to_uppercase().as_str() {
@@ -465,6 +502,12 @@ impl Value {
 
             (DataType::Text, Literal::Number(v)) => Ok(Value::Str(v.to_string())),
             (DataType::Text, Literal::Text(v)) => Ok(Value::Str(v.to_string())),
+            (DataType::Enum(variants), Literal::Text(v)) => {
+                validate_enum_variant(v, variants).map(|_| Value::Enum {
+                    value: v.to_string(),
+                    variants: Arc::new(variants.to_vec()),
+                })
+            }
             (DataType::Text, Literal::Boolean(v)) => {
                 let v = if *v { "TRUE" } else { "FALSE" };
 
@@ -488,7 +531,9 @@ impl Value {
             | (DataType::Float32, Literal::Null)
             | (DataType::Float, Literal::Null)
             | (DataType::Decimal, Literal::Null)
-            | (DataType::Text, Literal::Null) => Ok(Value::Null),
+            | (DataType::Text, Literal::Null)
+            | (DataType::Enum(_), Literal::Null)
+            | (DataType::UserDefined(_), Literal::Null) => Ok(Value::Null),
             (DataType::Date, Literal::Text(v)) => parse_date(v)
                 .map(Value::Date)
                 .ok_or_else(|| ValueError::LiteralCastToDateFailed(v.to_string()).into()),
@@ -498,6 +543,9 @@ impl Value {
             (DataType::Timestamp, Literal::Text(v)) => parse_timestamp(v)
                 .map(Value::Timestamp)
                 .ok_or_else(|| ValueError::LiteralCastToTimestampFailed(v.to_string()).into()),
+            (DataType::TimestampTz, Literal::Text(v)) => parse_timestamp_tz(v, time_zone)
+                .map(Value::TimestampTz)
+                .ok_or_else(|| ValueError::LiteralCastToTimestampFailed(v.to_string()).into()),
             (DataType::Inet, Literal::Number(v)) => {
                 if let Some(x) = v.to_u32() {
                     Ok(Value::Inet(IpAddr::V4(Ipv4Addr::from(x))))
@@ -514,6 +562,8 @@ impl Value {
                 .map(Value::Point)
                 .map_err(|_| ValueError::FailedToParsePoint(v.to_string()).into()),
             (DataType::List, Literal::Text(v)) => Value::parse_json_list(v),
+            (DataType::Bytea, Literal::Text(v)) => Ok(Value::Bytea(v.as_bytes().to_vec())),
+            (DataType::Bytea, Literal::Bytea(v)) => Ok(Value::Bytea(v.to_vec())),
             _ => Err(ValueError::UnimplementedLiteralCast {
                 data_type: data_type.clone(),
                 literal: format!("{:?}", literal),
@@ -526,7 +576,10 @@ impl Value {
 #[cfg(test)]
 mod tests {
     use {
-        crate::{data::Literal, prelude::Value},
+        crate::{
+            data::{Literal, TimeZone},
+            prelude::Value,
+        },
         bigdecimal::BigDecimal,
         chrono::{NaiveDate, NaiveDateTime, NaiveTime},
         std::net::{IpAddr, Ipv4Addr, Ipv6Addr},
@@ -572,38 +625,53 @@ mod tests {
         let bytea = || hex::decode("123456").unwrap();
         let inet = |v: &str| Value::Inet(IpAddr::from_str(v).unwrap());
 
-        assert!(Value::Bool(true).evaluate_eq_with_literal(&Literal::Boolean(true)));
-        assert!(Value::I8(8).evaluate_eq_with_literal(num!("8")));
-        assert!(Value::I32(32).evaluate_eq_with_literal(num!("32")));
-        assert!(Value::I16(16).evaluate_eq_with_literal(num!("16")));
-        assert!(Value::I32(32).evaluate_eq_with_literal(num!("32")));
-        assert!(Value::I64(64).evaluate_eq_with_literal(num!("64")));
-        assert!(Value::I128(128).evaluate_eq_with_literal(num!("128")));
-        assert!(Value::U8(7).evaluate_eq_with_literal(num!("7")));
-        assert!(Value::U16(64).evaluate_eq_with_literal(num!("64")));
-        assert!(Value::U32(64).evaluate_eq_with_literal(num!("64")));
-        assert!(Value::U64(64).evaluate_eq_with_literal(num!("64")));
-        assert!(Value::U128(64).evaluate_eq_with_literal(num!("64")));
-        assert!(Value::F32(7.123).evaluate_eq_with_literal(num!("7.123")));
-        assert!(Value::F64(7.123).evaluate_eq_with_literal(num!("7.123")));
-        assert!(Value::Str("Hello".to_owned()).evaluate_eq_with_literal(text!("Hello")));
-        assert!(Value::Bytea(bytea()).evaluate_eq_with_literal(&Literal::Bytea(bytea())));
-        assert!(inet("127.0.0.1").evaluate_eq_with_literal(text!("127.0.0.1")));
-        assert!(inet("::1").evaluate_eq_with_literal(text!("::1")));
-        assert!(inet("0.0.0.0").evaluate_eq_with_literal(num!("0")));
-        assert!(!inet("::1").evaluate_eq_with_literal(num!("0")));
-        assert!(inet("::2:4cb0:16ea").evaluate_eq_with_literal(num!("9876543210")));
-        assert!(!inet("::1").evaluate_eq_with_literal(text!("-1")));
-        assert!(!inet("::1").evaluate_eq_with_literal(num!("-1")));
-        assert!(Value::Date(date(2021, 11, 20)).evaluate_eq_with_literal(text!("2021-11-20")));
-        assert!(!Value::Date(date(2021, 11, 20)).evaluate_eq_with_literal(text!("202=abcdef")));
+        assert!(Value::Bool(true).evaluate_eq_with_literal(&Literal::Boolean(true), TimeZone::UTC));
+        assert!(Value::I8(8).evaluate_eq_with_literal(num!("8"), TimeZone::UTC));
+        assert!(Value::I32(32).evaluate_eq_with_literal(num!("32"), TimeZone::UTC));
+        assert!(Value::I16(16).evaluate_eq_with_literal(num!("16"), TimeZone::UTC));
+        assert!(Value::I32(32).evaluate_eq_with_literal(num!("32"), TimeZone::UTC));
+        assert!(Value::I64(64).evaluate_eq_with_literal(num!("64"), TimeZone::UTC));
+        assert!(Value::I128(128).evaluate_eq_with_literal(num!("128"), TimeZone::UTC));
+        assert!(Value::U8(7).evaluate_eq_with_literal(num!("7"), TimeZone::UTC));
+        assert!(Value::U16(64).evaluate_eq_with_literal(num!("64"), TimeZone::UTC));
+        assert!(Value::U32(64).evaluate_eq_with_literal(num!("64"), TimeZone::UTC));
+        assert!(Value::U64(64).evaluate_eq_with_literal(num!("64"), TimeZone::UTC));
+        assert!(Value::U128(64).evaluate_eq_with_literal(num!("64"), TimeZone::UTC));
+        assert!(Value::F32(7.123).evaluate_eq_with_literal(num!("7.123"), TimeZone::UTC));
+        assert!(Value::F64(7.123).evaluate_eq_with_literal(num!("7.123"), TimeZone::UTC));
+        assert!(
+            Value::Str("Hello".to_owned()).evaluate_eq_with_literal(text!("Hello"), TimeZone::UTC)
+        );
+        assert!(
+            Value::Bytea(bytea()).evaluate_eq_with_literal(&Literal::Bytea(bytea()), TimeZone::UTC)
+        );
+        assert!(inet("127.0.0.1").evaluate_eq_with_literal(text!("127.0.0.1"), TimeZone::UTC));
+        assert!(inet("::1").evaluate_eq_with_literal(text!("::1"), TimeZone::UTC));
+        assert!(inet("0.0.0.0").evaluate_eq_with_literal(num!("0"), TimeZone::UTC));
+        assert!(!inet("::1").evaluate_eq_with_literal(num!("0"), TimeZone::UTC));
+        assert!(inet("::2:4cb0:16ea").evaluate_eq_with_literal(num!("9876543210"), TimeZone::UTC));
+        assert!(!inet("::1").evaluate_eq_with_literal(text!("-1"), TimeZone::UTC));
+        assert!(!inet("::1").evaluate_eq_with_literal(num!("-1"), TimeZone::UTC));
+        assert!(Value::Date(date(2021, 11, 20))
+            .evaluate_eq_with_literal(text!("2021-11-20"), TimeZone::UTC));
+        assert!(!Value::Date(date(2021, 11, 20))
+            .evaluate_eq_with_literal(text!("202=abcdef"), TimeZone::UTC));
         assert!(Value::Timestamp(date_time(2021, 11, 20, 10, 0, 0, 0))
-            .evaluate_eq_with_literal(text!("2021-11-20T10:00:00Z")));
+            .evaluate_eq_with_literal(text!("2021-11-20T10:00:00Z"), TimeZone::UTC));
         assert!(!Value::Timestamp(date_time(2021, 11, 20, 10, 0, 0, 0))
-            .evaluate_eq_with_literal(text!("2021-11-Hello")));
-        assert!(Value::Time(time(10, 0, 0, 0)).evaluate_eq_with_literal(text!("10:00:00")));
-        assert!(!Value::Time(time(10, 0, 0, 0)).evaluate_eq_with_literal(text!("FALSE")));
-        assert!(Value::Uuid(uuid).evaluate_eq_with_literal(text!(uuid_text)));
+            .evaluate_eq_with_literal(text!("2021-11-Hello"), TimeZone::UTC));
+        assert!(Value::Time(time(10, 0, 0, 0))
+            .evaluate_eq_with_literal(text!("10:00:00"), TimeZone::UTC));
+        assert!(
+            !Value::Time(time(10, 0, 0, 0)).evaluate_eq_with_literal(text!("FALSE"), TimeZone::UTC)
+        );
+        assert!(Value::Uuid(uuid).evaluate_eq_with_literal(text!(uuid_text), TimeZone::UTC));
+
+        let seoul = TimeZone::parse("Asia/Seoul").unwrap();
+        let timestamptz = Value::TimestampTz("2021-11-20T01:00:00Z".parse().unwrap());
+        assert!(timestamptz.evaluate_eq_with_literal(text!("2021-11-20 10:00:00"), seoul));
+        assert!(!timestamptz.evaluate_eq_with_literal(text!("2021-11-20 10:00:00"), TimeZone::UTC));
+        assert!(timestamptz.evaluate_eq_with_literal(text!("2021-11-20T01:00:00Z"), seoul));
     }
 
     #[test]
@@ -680,7 +748,10 @@ mod tests {
 
         macro_rules! test {
             ($to: expr, $from: expr, $expected: expr) => {
-                assert_eq!(Value::try_from_literal(&$to, &$from), Ok($expected));
+                assert_eq!(
+                    Value::try_from_literal(&$to, &$from, TimeZone::UTC),
+                    Ok($expected)
+                );
             };
         }
 
@@ -717,7 +788,7 @@ mod tests {
         );
         test!(DataType::Bytea, text!("1234"), Value::Bytea(bytea("1234")));
         assert_eq!(
-            Value::try_from_literal(&DataType::Bytea, &text!("123")),
+            Value::try_from_literal(&DataType::Bytea, &text!("123"), TimeZone::UTC),
             Err(ValueError::FailedToParseHexString("123".to_owned()).into())
         );
         test!(DataType::Inet, text!("::1"), Value::Inet(inet("::1")));
@@ -737,7 +808,7 @@ mod tests {
             Value::Inet(inet("::2:4cb0:16ea"))
         );
         assert_eq!(
-            Value::try_from_literal(&DataType::Inet, &text!("123")),
+            Value::try_from_literal(&DataType::Inet, &text!("123"), TimeZone::UTC),
             Err(ValueError::FailedToParseInetString("123".to_owned()).into())
         );
         test!(
@@ -774,7 +845,8 @@ mod tests {
             "name": "John Doe",
             "age": 43
         }"#
-                )
+                ),
+                TimeZone::UTC
             ),
             Value::parse_json_map(
                 r#"{
@@ -791,7 +863,8 @@ mod tests {
             "+44 1234567",
             "+44 2345678"
         ]"#
-                )
+                ),
+                TimeZone::UTC
             ),
             Value::parse_json_list(
                 r#"[
@@ -864,7 +937,7 @@ mod tests {
 
         macro_rules! test {
             ($to: expr, $from: expr, $expected: expr) => {
-                let actual = Value::try_cast_from_literal(&$to, &$from);
+                let actual = Value::try_cast_from_literal(&$to, &$from, TimeZone::UTC);
 
                 assert_eq!(actual, Ok($expected))
             };
@@ -873,7 +946,7 @@ mod tests {
         macro_rules! test_null {
             ($to: expr, $from: expr) => {
                 assert!(matches!(
-                    Value::try_cast_from_literal(&$to, &$from),
+                    Value::try_cast_from_literal(&$to, &$from, TimeZone::UTC),
                     Ok(Value::Null)
                 ))
             };
@@ -1037,5 +1110,11 @@ mod tests {
             text!("::1"),
             Value::Inet(IpAddr::from_str("::1").unwrap())
         );
+        test!(DataType::Bytea, text!("ab"), Value::Bytea(b"ab".to_vec()));
+        test!(
+            DataType::Bytea,
+            Literal::Bytea(vec![0, 255]),
+            Value::Bytea(vec![0, 255])
+        );
     }
 }
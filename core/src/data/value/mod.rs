@@ -1,16 +1,17 @@
 use {
-    super::{Interval, Key, StringExt},
+    super::{string_ext::similar_to_regex, Interval, Key, StringExt},
     crate::{
         ast::{DataType, DateTimeField},
         data::point::Point,
         result::Result,
     },
-    binary_op::TryBinaryOperator,
-    chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike},
+    binary_op::{TryBinaryOperator, TryBooleanOperator},
+    chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike},
     core::ops::Sub,
+    regex::{Regex, RegexBuilder},
     rust_decimal::Decimal,
     serde::{Deserialize, Serialize},
-    std::{cmp::Ordering, collections::HashMap, fmt::Debug, net::IpAddr},
+    std::{cell::RefCell, cmp::Ordering, collections::HashMap, fmt::Debug, net::IpAddr, sync::Arc},
 };
 
 mod binary_op;
@@ -21,13 +22,20 @@ mod expr;
 mod json;
 mod literal;
 mod selector;
+mod time_zone;
 mod uuid;
 
 pub use {
-    error::{NumericBinaryOperator, ValueError},
+    error::{LogicalBinaryOperator, NumericBinaryOperator, ValueError},
     json::HashMapJsonExt,
 };
 
+pub use time_zone::TimeZone;
+
+pub(crate) use date::parse_timestamp;
+use date::parse_timestamp_tz;
+pub(crate) use uuid::parse_uuid;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum Value {
     Bool(bool),
@@ -49,6 +57,7 @@ pub enum Value {
     Inet(IpAddr),
     Date(NaiveDate),
     Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<FixedOffset>),
     Time(NaiveTime),
     Interval(Interval),
     Uuid(u128),
@@ -56,10 +65,22 @@ pub enum Value {
     List(Vec<Value>),
     Point(Point),
     Null,
+    /// Variant of an enum type, which is compared as its text
+    Enum {
+        value: String,
+        variants: Arc<Vec<String>>,
+    },
 }
 
 impl Value {
+    /// A `TIMESTAMP` is read in UTC against a `TIMESTAMPTZ`, see `evaluate_eq_in`.
     pub fn evaluate_eq(&self, other: &Value) -> bool {
+        self.evaluate_eq_in(other, TimeZone::UTC)
+    }
+
+    /// A `TIMESTAMP`, or a `DATE` at its midnight, is read as a wall clock time of `time_zone`
+    /// against a `TIMESTAMPTZ`.
+    pub fn evaluate_eq_in(&self, other: &Value, time_zone: TimeZone) -> bool {
         match (self, other) {
             (Value::I8(l), _) => l == other,
             (Value::I16(l), _) => l == other,
@@ -81,12 +102,42 @@ impl Value {
                 .and_hms_opt(0, 0, 0)
                 .map(|date_time| l == &date_time)
                 .unwrap_or(false),
+            (Value::Date(l), Value::TimestampTz(r)) => l
+                .and_hms_opt(0, 0, 0)
+                .and_then(|date_time| time_zone.localize(&date_time).ok())
+                .map(|l| &l == r)
+                .unwrap_or(false),
+            (Value::TimestampTz(l), Value::Date(r)) => r
+                .and_hms_opt(0, 0, 0)
+                .and_then(|date_time| time_zone.localize(&date_time).ok())
+                .map(|r| l == &r)
+                .unwrap_or(false),
+            (Value::Timestamp(l), Value::TimestampTz(r)) => {
+                time_zone.localize(l).map(|l| &l == r).unwrap_or(false)
+            }
+            (Value::TimestampTz(l), Value::Timestamp(r)) => {
+                time_zone.localize(r).map(|r| l == &r).unwrap_or(false)
+            }
+            (
+                Value::Str(l) | Value::Enum { value: l, .. },
+                Value::Str(r) | Value::Enum { value: r, .. },
+            ) => l == r,
             (Value::Null, Value::Null) => false,
+            (Value::List(l), Value::List(r)) => {
+                l.len() == r.len() && l.iter().zip(r).all(|(l, r)| l.evaluate_eq_in(r, time_zone))
+            }
             _ => self == other,
         }
     }
 
+    /// A `TIMESTAMP` is read in UTC against a `TIMESTAMPTZ`, see `evaluate_cmp_in`.
     pub fn evaluate_cmp(&self, other: &Value) -> Option<Ordering> {
+        self.evaluate_cmp_in(other, TimeZone::UTC)
+    }
+
+    /// A `TIMESTAMP`, or a `DATE` at its midnight, is read as a wall clock time of `time_zone`
+    /// against a `TIMESTAMPTZ`.
+    pub fn evaluate_cmp_in(&self, other: &Value, time_zone: TimeZone) -> Option<Ordering> {
         match (self, other) {
             (Value::I8(l), _) => l.partial_cmp(other),
             (Value::I16(l), _) => l.partial_cmp(other),
@@ -102,7 +153,10 @@ impl Value {
             (Value::F64(l), _) => l.partial_cmp(other),
             (Value::Decimal(l), Value::Decimal(r)) => Some(l.cmp(r)),
             (Value::Bool(l), Value::Bool(r)) => Some(l.cmp(r)),
-            (Value::Str(l), Value::Str(r)) => Some(l.cmp(r)),
+            (
+                Value::Str(l) | Value::Enum { value: l, .. },
+                Value::Str(r) | Value::Enum { value: r, .. },
+            ) => Some(l.cmp(r)),
             (Value::Bytea(l), Value::Bytea(r)) => Some(l.cmp(r)),
             (Value::Inet(l), Value::Inet(r)) => Some(l.cmp(r)),
             (Value::Date(l), Value::Date(r)) => Some(l.cmp(r)),
@@ -112,10 +166,31 @@ impl Value {
             (Value::Timestamp(l), Value::Date(r)) => {
                 r.and_hms_opt(0, 0, 0).map(|date_time| l.cmp(&date_time))
             }
+            (Value::Date(l), Value::TimestampTz(r)) => l
+                .and_hms_opt(0, 0, 0)
+                .and_then(|date_time| time_zone.localize(&date_time).ok())
+                .map(|l| l.cmp(r)),
+            (Value::TimestampTz(l), Value::Date(r)) => r
+                .and_hms_opt(0, 0, 0)
+                .and_then(|date_time| time_zone.localize(&date_time).ok())
+                .map(|r| l.cmp(&r)),
             (Value::Timestamp(l), Value::Timestamp(r)) => Some(l.cmp(r)),
+            (Value::Timestamp(l), Value::TimestampTz(r)) => {
+                time_zone.localize(l).ok().map(|l| l.cmp(r))
+            }
+            (Value::TimestampTz(l), Value::Timestamp(r)) => {
+                time_zone.localize(r).ok().map(|r| l.cmp(&r))
+            }
+            (Value::TimestampTz(l), Value::TimestampTz(r)) => Some(l.cmp(r)),
             (Value::Time(l), Value::Time(r)) => Some(l.cmp(r)),
             (Value::Interval(l), Value::Interval(r)) => l.partial_cmp(r),
             (Value::Uuid(l), Value::Uuid(r)) => Some(l.cmp(r)),
+            (Value::List(l), Value::List(r)) => l
+                .iter()
+                .zip(r)
+                .map(|(l, r)| l.evaluate_cmp_in(r, time_zone))
+                .find(|ordering| ordering != &Some(Ordering::Equal))
+                .unwrap_or_else(|| Some(l.len().cmp(&r.len()))),
             _ => None,
         }
     }
@@ -160,12 +235,14 @@ impl Value {
             Value::Inet(_) => Some(DataType::Inet),
             Value::Date(_) => Some(DataType::Date),
             Value::Timestamp(_) => Some(DataType::Timestamp),
+            Value::TimestampTz(_) => Some(DataType::TimestampTz),
             Value::Time(_) => Some(DataType::Time),
             Value::Interval(_) => Some(DataType::Interval),
             Value::Uuid(_) => Some(DataType::Uuid),
             Value::Map(_) => Some(DataType::Map),
             Value::List(_) => Some(DataType::List),
             Value::Point(_) => Some(DataType::Point),
+            Value::Enum { variants, .. } => Some(DataType::Enum(variants.to_vec())),
             Value::Null => None,
         }
     }
@@ -186,17 +263,25 @@ impl Value {
             Value::F64(_) => matches!(data_type, DataType::Float),
             Value::Decimal(_) => matches!(data_type, DataType::Decimal),
             Value::Bool(_) => matches!(data_type, DataType::Boolean),
-            Value::Str(_) => matches!(data_type, DataType::Text),
+            Value::Str(v) => match data_type {
+                DataType::Enum(variants) => return validate_enum_variant(v, variants),
+                _ => matches!(data_type, DataType::Text),
+            },
             Value::Bytea(_) => matches!(data_type, DataType::Bytea),
             Value::Inet(_) => matches!(data_type, DataType::Inet),
             Value::Date(_) => matches!(data_type, DataType::Date),
             Value::Timestamp(_) => matches!(data_type, DataType::Timestamp),
+            Value::TimestampTz(_) => matches!(data_type, DataType::TimestampTz),
             Value::Time(_) => matches!(data_type, DataType::Time),
             Value::Interval(_) => matches!(data_type, DataType::Interval),
             Value::Uuid(_) => matches!(data_type, DataType::Uuid),
             Value::Map(_) => matches!(data_type, DataType::Map),
             Value::List(_) => matches!(data_type, DataType::List),
             Value::Point(_) => matches!(data_type, DataType::Point),
+            Value::Enum { value, .. } => match data_type {
+                DataType::Enum(variants) => return validate_enum_variant(value, variants),
+                _ => false,
+            },
             Value::Null => true,
         };
 
@@ -211,6 +296,17 @@ impl Value {
         Ok(())
     }
 
+    /// Validates the value written to a column of `data_type`. A text written to an `ENUM` column
+    /// becomes a `Value::Enum` holding the variants of the column.
+    pub fn into_column_value(self, data_type: &DataType) -> Result<Value> {
+        match (data_type, self) {
+            (DataType::Enum(_), value @ (Value::Str(_) | Value::Enum { .. })) => {
+                value.cast(data_type)
+            }
+            (_, value) => value.validate_type(data_type).map(|_| value),
+        }
+    }
+
     pub fn validate_null(&self, nullable: bool) -> Result<()> {
         if !nullable && matches!(self, Value::Null) {
             return Err(ValueError::NullValueOnNotNullField.into());
@@ -219,7 +315,14 @@ impl Value {
         Ok(())
     }
 
+    /// A `TIMESTAMP` is cast from or to a `TIMESTAMPTZ` in UTC, see `cast_in`.
     pub fn cast(&self, data_type: &DataType) -> Result<Self> {
+        self.cast_in(data_type, TimeZone::UTC)
+    }
+
+    /// A `TIMESTAMPTZ` is cast to the wall clock time of `time_zone`, and a `DATE`, `TIMESTAMP`
+    /// or text without an offset is read as a wall clock time of `time_zone`.
+    pub fn cast_in(&self, data_type: &DataType, time_zone: TimeZone) -> Result<Self> {
         match (data_type, self) {
             (DataType::Int8, Value::I8(_))
             | (DataType::Int16, Value::I16(_))
@@ -241,12 +344,28 @@ impl Value {
             | (DataType::Point, Value::Point(_))
             | (DataType::Date, Value::Date(_))
             | (DataType::Timestamp, Value::Timestamp(_))
+            | (DataType::TimestampTz, Value::TimestampTz(_))
             | (DataType::Time, Value::Time(_))
             | (DataType::Interval, Value::Interval(_))
             | (DataType::Uuid, Value::Uuid(_)) => Ok(self.clone()),
 
             (_, Value::Null) => Ok(Value::Null),
 
+            (DataType::Date, Value::TimestampTz(value)) => Ok(Value::Date(
+                time_zone.convert(&value.naive_utc()).naive_local().date(),
+            )),
+            (DataType::Timestamp, Value::TimestampTz(value)) => Ok(Value::Timestamp(
+                time_zone.convert(&value.naive_utc()).naive_local(),
+            )),
+            (DataType::TimestampTz, Value::Str(value)) => parse_timestamp_tz(value, time_zone)
+                .map(Value::TimestampTz)
+                .ok_or_else(|| ValueError::ImpossibleCast.into()),
+            (DataType::TimestampTz, value @ (Value::Date(_) | Value::Timestamp(_))) => {
+                let value = NaiveDateTime::try_from(value)?;
+
+                time_zone.localize(&value).map(Value::TimestampTz)
+            }
+
             (DataType::Boolean, value) => value.try_into().map(Value::Bool),
             (DataType::Int8, value) => value.try_into().map(Value::I8),
             (DataType::Int16, value) => value.try_into().map(Value::I16),
@@ -262,17 +381,30 @@ impl Value {
             (DataType::Float, value) => value.try_into().map(Value::F64),
             (DataType::Decimal, value) => value.try_into().map(Value::Decimal),
             (DataType::Text, value) => Ok(Value::Str(value.into())),
+            (DataType::Enum(variants), value) => {
+                let value = String::from(value);
+                validate_enum_variant(&value, variants)?;
+
+                Ok(Value::Enum {
+                    value,
+                    variants: Arc::new(variants.to_vec()),
+                })
+            }
+            // the executor resolves the variants of the type from the storage before it casts,
+            // a value read back from a storage was validated when it was written
+            (DataType::UserDefined(_), value @ (Value::Str(_) | Value::Enum { .. })) => {
+                Ok(value.clone())
+            }
             (DataType::Date, value) => value.try_into().map(Value::Date),
             (DataType::Time, value) => value.try_into().map(Value::Time),
             (DataType::Interval, Value::Str(value)) => Interval::parse(value).map(Value::Interval),
             (DataType::Timestamp, value) => value.try_into().map(Value::Timestamp),
+            (DataType::TimestampTz, value) => value.try_into().map(Value::TimestampTz),
             (DataType::Uuid, Value::Str(value)) => uuid::parse_uuid(value).map(Value::Uuid),
             (DataType::Uuid, value) => value.try_into().map(Value::Uuid),
             (DataType::Inet, value) => value.try_into().map(Value::Inet),
             (DataType::Point, value) => value.try_into().map(Value::Point),
-            (DataType::Bytea, Value::Str(value)) => hex::decode(value)
-                .map_err(|_| ValueError::CastFromHexToByteaFailed(value.clone()).into())
-                .map(Value::Bytea),
+            (DataType::Bytea, Value::Str(value)) => Ok(Value::Bytea(value.as_bytes().to_vec())),
 
             _ => Err(ValueError::UnimplementedCast.into()),
         }
@@ -282,13 +414,65 @@ impl Value {
         match (self, other) {
             (Value::Null, _) | (_, Value::Null) => Value::Null,
             (Value::List(l), Value::List(r)) => Value::List([l, r].concat()),
+            (Value::Bytea(l), Value::Bytea(r)) => Value::Bytea([l, r].concat()),
             (l, r) => Value::Str(String::from(l) + &String::from(r)),
         }
     }
 
+    /// Converts a pair of a signed and an unsigned integer into the smallest signed integer type
+    /// which holds both ranges, e.g. `U64` and `I64` into `I128`, so the arithmetic of the pair
+    /// does not depend on the order of the operands. `None` for the other pairs, and for `U128`
+    /// which no signed integer type holds.
+    fn widen_mixed_sign(&self, other: &Value) -> Option<(Value, Value)> {
+        use Value::*;
+
+        let signed_bits = |value: &Value| match value {
+            I8(_) => Some(8),
+            I16(_) => Some(16),
+            I32(_) => Some(32),
+            I64(_) => Some(64),
+            I128(_) => Some(128),
+            _ => None,
+        };
+        let unsigned_bits = |value: &Value| match value {
+            U8(_) => Some(8),
+            U16(_) => Some(16),
+            U32(_) => Some(32),
+            U64(_) => Some(64),
+            _ => None,
+        };
+        let bits = match (
+            signed_bits(self),
+            unsigned_bits(other),
+            unsigned_bits(self),
+            signed_bits(other),
+        ) {
+            (Some(signed), Some(unsigned), ..) | (.., Some(unsigned), Some(signed)) => {
+                signed.max(unsigned * 2)
+            }
+            _ => return None,
+        };
+        let widen = |value: &Value| {
+            let value = i128::try_from(value).ok()?;
+
+            match bits {
+                16 => i16::try_from(value).ok().map(I16),
+                32 => i32::try_from(value).ok().map(I32),
+                64 => i64::try_from(value).ok().map(I64),
+                _ => Some(I128(value)),
+            }
+        };
+
+        Some((widen(self)?, widen(other)?))
+    }
+
     pub fn add(&self, other: &Value) -> Result<Value> {
         use Value::*;
 
+        if let Some((lhs, rhs)) = self.widen_mixed_sign(other) {
+            return lhs.add(&rhs);
+        }
+
         match (self, other) {
             (I8(a), b) => a.try_add(b),
             (I16(a), b) => a.try_add(b),
@@ -303,10 +487,16 @@ impl Value {
             (F32(a), b) => a.try_add(b),
             (F64(a), b) => a.try_add(b),
             (Decimal(a), b) => a.try_add(b),
-            (Date(a), Time(b)) => Ok(Timestamp(NaiveDateTime::new(*a, *b))),
-            (Date(a), Interval(b)) => b.add_date(a).map(Timestamp),
-            (Timestamp(a), Interval(b)) => b.add_timestamp(a).map(Timestamp),
-            (Time(a), Interval(b)) => b.add_time(a).map(Time),
+            (Date(a), Time(b)) | (Time(b), Date(a)) => Ok(Timestamp(NaiveDateTime::new(*a, *b))),
+            (Date(a), Interval(b)) | (Interval(b), Date(a)) => b.add_date(a).map(Timestamp),
+            (Timestamp(a), Interval(b)) | (Interval(b), Timestamp(a)) => {
+                b.add_timestamp(a).map(Timestamp)
+            }
+            (TimestampTz(a), Interval(b)) | (Interval(b), TimestampTz(a)) => b
+                .add_timestamp(&a.naive_local())
+                .and_then(|v| TimeZone::Offset(*a.offset()).localize(&v))
+                .map(TimestampTz),
+            (Time(a), Interval(b)) | (Interval(b), Time(a)) => b.add_time(a).map(Time),
             (Interval(a), Interval(b)) => a.add(b).map(Interval),
             (Null, I8(_))
             | (Null, I16(_))
@@ -323,9 +513,12 @@ impl Value {
             | (Null, Decimal(_))
             | (Null, Date(_))
             | (Null, Timestamp(_))
+            | (Null, TimestampTz(_))
+            | (Null, Time(_))
             | (Null, Interval(_))
             | (Date(_), Null)
             | (Timestamp(_), Null)
+            | (TimestampTz(_), Null)
             | (Time(_), Null)
             | (Interval(_), Null)
             | (Null, Null) => Ok(Null),
@@ -341,6 +534,10 @@ impl Value {
     pub fn subtract(&self, other: &Value) -> Result<Value> {
         use {super::Interval as I, Value::*};
 
+        if let Some((lhs, rhs)) = self.widen_mixed_sign(other) {
+            return lhs.subtract(&rhs);
+        }
+
         match (self, other) {
             (I8(a), _) => a.try_subtract(other),
             (I16(a), _) => a.try_subtract(other),
@@ -358,6 +555,17 @@ impl Value {
             (Date(a), Date(b)) => Ok(Interval(I::days((*a - *b).num_days() as i32))),
             (Date(a), Interval(b)) => b.subtract_from_date(a).map(Timestamp),
             (Timestamp(a), Interval(b)) => b.subtract_from_timestamp(a).map(Timestamp),
+            (TimestampTz(a), Interval(b)) => b
+                .subtract_from_timestamp(&a.naive_local())
+                .and_then(|v| TimeZone::Offset(*a.offset()).localize(&v))
+                .map(TimestampTz),
+            (TimestampTz(a), TimestampTz(b)) => a
+                .sub(*b)
+                .num_microseconds()
+                .ok_or_else(|| {
+                    ValueError::UnreachableIntegerOverflow(format!("{:?} - {:?}", a, b)).into()
+                })
+                .map(|v| Interval(I::microseconds(v))),
             (Timestamp(a), Timestamp(b)) => a
                 .sub(*b)
                 .num_microseconds()
@@ -389,10 +597,12 @@ impl Value {
             | (Null, Decimal(_))
             | (Null, Date(_))
             | (Null, Timestamp(_))
+            | (Null, TimestampTz(_))
             | (Null, Time(_))
             | (Null, Interval(_))
             | (Date(_), Null)
             | (Timestamp(_), Null)
+            | (TimestampTz(_), Null)
             | (Time(_), Null)
             | (Interval(_), Null)
             | (Null, Null) => Ok(Null),
@@ -408,6 +618,10 @@ impl Value {
     pub fn multiply(&self, other: &Value) -> Result<Value> {
         use Value::*;
 
+        if let Some((lhs, rhs)) = self.widen_mixed_sign(other) {
+            return lhs.multiply(&rhs);
+        }
+
         match (self, other) {
             (I8(a), _) => a.try_multiply(other),
             (I16(a), _) => a.try_multiply(other),
@@ -461,6 +675,10 @@ impl Value {
             return Err(ValueError::DivisorShouldNotBeZero.into());
         }
 
+        if let Some((lhs, rhs)) = self.widen_mixed_sign(other) {
+            return lhs.divide(&rhs);
+        }
+
         match (self, other) {
             (I8(a), _) => a.try_divide(other),
             (I16(a), _) => a.try_divide(other),
@@ -518,6 +736,10 @@ impl Value {
             return Err(ValueError::DivisorShouldNotBeZero.into());
         }
 
+        if let Some((lhs, rhs)) = self.widen_mixed_sign(other) {
+            return lhs.modulo(&rhs);
+        }
+
         match (self, other) {
             (I8(a), _) => a.try_modulo(other),
             (I16(a), _) => a.try_modulo(other),
@@ -555,6 +777,78 @@ impl Value {
         }
     }
 
+    pub fn power(&self, other: &Value) -> Result<Value> {
+        use Value::*;
+
+        match (self, other) {
+            (I8(a), _) => a.try_power(other),
+            (I16(a), _) => a.try_power(other),
+            (I32(a), _) => a.try_power(other),
+            (I64(a), _) => a.try_power(other),
+            (I128(a), _) => a.try_power(other),
+            (U8(a), _) => a.try_power(other),
+            (U16(a), _) => a.try_power(other),
+            (U32(a), _) => a.try_power(other),
+            (U64(a), _) => a.try_power(other),
+            (U128(a), _) => a.try_power(other),
+            (F32(a), _) => a.try_power(other),
+            (F64(a), _) => a.try_power(other),
+            (Decimal(a), _) => a.try_power(other),
+            (Null, I8(_))
+            | (Null, I16(_))
+            | (Null, I32(_))
+            | (Null, I64(_))
+            | (Null, I128(_))
+            | (Null, U8(_))
+            | (Null, U16(_))
+            | (Null, U32(_))
+            | (Null, U64(_))
+            | (Null, U128(_))
+            | (Null, F32(_))
+            | (Null, F64(_))
+            | (Null, Decimal(_))
+            | (Null, Null) => Ok(Null),
+            _ => Err(ValueError::NonNumericMathOperation {
+                lhs: self.clone(),
+                operator: NumericBinaryOperator::Power,
+                rhs: other.clone(),
+            }
+            .into()),
+        }
+    }
+
+    pub fn and(&self, other: &Value) -> Result<Value> {
+        self.logical_op(other, LogicalBinaryOperator::And, bool::try_and)
+    }
+
+    pub fn or(&self, other: &Value) -> Result<Value> {
+        self.logical_op(other, LogicalBinaryOperator::Or, bool::try_or)
+    }
+
+    pub fn xor(&self, other: &Value) -> Result<Value> {
+        self.logical_op(other, LogicalBinaryOperator::Xor, bool::try_xor)
+    }
+
+    /// `AND`, `OR` and `XOR` are commutative, so `Null` on the left is handed to the right operand.
+    fn logical_op<F>(&self, other: &Value, operator: LogicalBinaryOperator, op: F) -> Result<Value>
+    where
+        F: Fn(&bool, &Value) -> Result<Value>,
+    {
+        use Value::*;
+
+        match (self, other) {
+            (Bool(a), _) => op(a, other),
+            (Null, Bool(b)) => op(b, self),
+            (Null, Null) => Ok(Null),
+            _ => Err(ValueError::NonBooleanLogicalOperation {
+                lhs: self.clone(),
+                operator,
+                rhs: other.clone(),
+            }
+            .into()),
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         matches!(self, Value::Null)
     }
@@ -619,11 +913,31 @@ impl Value {
         }
     }
 
+    pub fn unary_bitwise_not(&self) -> Result<Value> {
+        use Value::*;
+
+        match self {
+            I8(a) => a.try_bitwise_not(),
+            I16(a) => a.try_bitwise_not(),
+            I32(a) => a.try_bitwise_not(),
+            I64(a) => a.try_bitwise_not(),
+            I128(a) => a.try_bitwise_not(),
+            U8(a) => a.try_bitwise_not(),
+            U16(a) => a.try_bitwise_not(),
+            U32(a) => a.try_bitwise_not(),
+            U64(a) => a.try_bitwise_not(),
+            U128(a) => a.try_bitwise_not(),
+            Null => Ok(Null),
+            _ => Err(ValueError::UnaryBitwiseNotOnNonInteger.into()),
+        }
+    }
+
     pub fn like(&self, other: &Value, case_sensitive: bool) -> Result<Value> {
         use Value::*;
 
         match (self, other) {
-            (Str(a), Str(b)) => a.like(b, case_sensitive).map(Bool),
+            (Null, _) | (_, Null) => Ok(Null),
+            (Str(a) | Enum { value: a, .. }, Str(b)) => a.like(b, case_sensitive).map(Bool),
             _ => Err(ValueError::LikeOnNonString {
                 base: self.clone(),
                 pattern: other.clone(),
@@ -633,6 +947,41 @@ impl Value {
         }
     }
 
+    pub fn try_regexp_match(&self, pattern: &Value, case_insensitive: bool) -> Result<Value> {
+        use Value::*;
+
+        match (self, pattern) {
+            (Null, _) | (_, Null) => Ok(Null),
+            (Str(base), Str(pattern)) => regex_is_match(base, pattern, case_insensitive).map(Bool),
+            _ => Err(ValueError::RegexpOnNonString {
+                base: self.clone(),
+                pattern: pattern.clone(),
+                case_insensitive,
+            }
+            .into()),
+        }
+    }
+
+    /// Matches the whole string against a `SIMILAR TO` pattern, where `%` and `_` are the `LIKE`
+    /// wildcards and `|`, `*`, `+`, `?`, `{m,n}`, `(...)` and `[...]` work as in regular expressions.
+    pub fn try_similar_to(&self, pattern: &Value) -> Result<Value> {
+        use Value::*;
+
+        match (self, pattern) {
+            (Null, _) | (_, Null) => Ok(Null),
+            (Str(base), Str(pattern)) => {
+                let pattern = format!("^(?s:{})$", similar_to_regex(pattern));
+
+                regex_is_match(base, &pattern, false).map(Bool)
+            }
+            _ => Err(ValueError::SimilarToOnNonString {
+                base: self.clone(),
+                pattern: pattern.clone(),
+            }
+            .into()),
+        }
+    }
+
     pub fn extract(&self, date_type: &DateTimeField) -> Result<Value> {
         let value = match (self, date_type) {
             (Value::Date(v), DateTimeField::Year) => v.year().into(),
@@ -647,9 +996,16 @@ impl Value {
             (Value::Timestamp(v), DateTimeField::Hour) => v.hour().into(),
             (Value::Timestamp(v), DateTimeField::Minute) => v.minute().into(),
             (Value::Timestamp(v), DateTimeField::Second) => v.second().into(),
+            (Value::TimestampTz(v), DateTimeField::Year) => v.year().into(),
+            (Value::TimestampTz(v), DateTimeField::Month) => v.month().into(),
+            (Value::TimestampTz(v), DateTimeField::Day) => v.day().into(),
+            (Value::TimestampTz(v), DateTimeField::Hour) => v.hour().into(),
+            (Value::TimestampTz(v), DateTimeField::Minute) => v.minute().into(),
+            (Value::TimestampTz(v), DateTimeField::Second) => v.second().into(),
             (Value::Interval(v), _) => {
                 return v.extract(date_type);
             }
+            (Value::Null, _) => return Ok(Value::Null),
             _ => {
                 return Err(ValueError::ExtractFormatNotMatched {
                     value: self.clone(),
@@ -662,22 +1018,271 @@ impl Value {
         Ok(Value::I64(value))
     }
 
-    pub fn sqrt(&self) -> Result<Value> {
-        use Value::*;
+    /// `TIMESTAMPTZ` is converted to the wall clock `TIMESTAMP` of `time_zone`,
+    /// and `TIMESTAMP` is read as a wall clock time of `time_zone`.
+    pub fn at_time_zone(&self, time_zone: &str) -> Result<Value> {
+        let time_zone = TimeZone::parse(time_zone)?;
+
         match self {
-            I8(_) | I16(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_) | U128(_)
-            | F32(_) | F64(_) => {
+            Value::TimestampTz(v) => Ok(Value::Timestamp(
+                time_zone.convert(&v.naive_utc()).naive_local(),
+            )),
+            Value::Timestamp(v) => time_zone.localize(v).map(Value::TimestampTz),
+            Value::Null => Ok(Value::Null),
+            _ => Err(ValueError::AtTimeZoneOnNonTimestamp(self.clone()).into()),
+        }
+    }
+
+    pub fn try_sqrt(&self) -> Result<Value> {
+        use {rust_decimal::MathematicalOps, Value::*};
+
+        match self {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) | F32(_) | F64(_) => {
                 let a: f64 = self.try_into()?;
-                Ok(Value::F64(a.sqrt()))
+                if a < 0.0 {
+                    return Err(ValueError::NegativeSqrt(self.clone()).into());
+                }
+
+                Ok(F64(a.sqrt()))
             }
-            Null => Ok(Value::Null),
+            Decimal(a) => a
+                .sqrt()
+                .map(Decimal)
+                .ok_or_else(|| ValueError::NegativeSqrt(self.clone()).into()),
+            Null => Ok(Null),
             _ => Err(ValueError::SqrtOnNonNumeric(self.clone()).into()),
         }
     }
 
-    /// Value to Big-Endian for comparison purpose
+    pub fn try_abs(&self) -> Result<Value> {
+        use Value::*;
+
+        let overflow = || ValueError::AbsOverflow(self.clone()).into();
+
+        match self {
+            I8(a) => a.checked_abs().map(I8).ok_or_else(overflow),
+            I16(a) => a.checked_abs().map(I16).ok_or_else(overflow),
+            I32(a) => a.checked_abs().map(I32).ok_or_else(overflow),
+            I64(a) => a.checked_abs().map(I64).ok_or_else(overflow),
+            I128(a) => a.checked_abs().map(I128).ok_or_else(overflow),
+            U8(_) | U16(_) | U32(_) | U64(_) | U128(_) => Ok(self.clone()),
+            F32(a) => Ok(F32(a.abs())),
+            F64(a) => Ok(F64(a.abs())),
+            Decimal(a) => Ok(Decimal(a.abs())),
+            Null => Ok(Null),
+            _ => Err(ValueError::AbsOnNonNumeric(self.clone()).into()),
+        }
+    }
+
+    pub fn try_floor(&self) -> Result<Value> {
+        use Value::*;
+
+        match self {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) => Ok(self.clone()),
+            F32(a) => Ok(F32(a.floor())),
+            F64(a) => Ok(F64(a.floor())),
+            Decimal(a) => Ok(Decimal(a.floor())),
+            Null => Ok(Null),
+            _ => Err(ValueError::FloorOnNonNumeric(self.clone()).into()),
+        }
+    }
+
+    pub fn try_ceil(&self) -> Result<Value> {
+        use Value::*;
+
+        match self {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) => Ok(self.clone()),
+            F32(a) => Ok(F32(a.ceil())),
+            F64(a) => Ok(F64(a.ceil())),
+            Decimal(a) => Ok(Decimal(a.ceil())),
+            Null => Ok(Null),
+            _ => Err(ValueError::CeilOnNonNumeric(self.clone()).into()),
+        }
+    }
+
+    /// Rounds half away from zero to `scale` decimal places,
+    /// a negative `scale` rounds to the left of the decimal point.
+    pub fn try_round(&self, scale: i32) -> Result<Value> {
+        use {
+            rust_decimal::{MathematicalOps, RoundingStrategy},
+            Value::*,
+        };
+
+        fn round_integer<T>(value: T, scale: i32) -> Option<T>
+        where
+            T: TryInto<i128> + TryFrom<i128>,
+        {
+            if scale >= 0 {
+                return Some(value);
+            }
+
+            let value: i128 = value.try_into().ok()?;
+            let rounded = match 10_i128.checked_pow(scale.unsigned_abs()) {
+                Some(factor) => {
+                    let remainder = value % factor;
+                    let truncated = value - remainder;
+
+                    if remainder >= factor / 2 {
+                        truncated.checked_add(factor)?
+                    } else if remainder <= -factor / 2 {
+                        truncated.checked_sub(factor)?
+                    } else {
+                        truncated
+                    }
+                }
+                None => 0,
+            };
+
+            T::try_from(rounded).ok()
+        }
+
+        fn round_float(value: f64, scale: i32) -> f64 {
+            let factor = 10_f64.powi(scale);
+            if factor == 0.0 {
+                return 0.0;
+            }
+
+            let rounded = (value * factor).round() / factor;
+            if rounded.is_finite() {
+                rounded
+            } else {
+                value
+            }
+        }
+
+        let overflow = || {
+            ValueError::RoundOverflow {
+                value: self.clone(),
+                scale,
+            }
+            .into()
+        };
+
+        match self {
+            I8(a) => round_integer(*a, scale).map(I8).ok_or_else(overflow),
+            I16(a) => round_integer(*a, scale).map(I16).ok_or_else(overflow),
+            I32(a) => round_integer(*a, scale).map(I32).ok_or_else(overflow),
+            I64(a) => round_integer(*a, scale).map(I64).ok_or_else(overflow),
+            I128(a) => round_integer(*a, scale).map(I128).ok_or_else(overflow),
+            U8(a) => round_integer(*a, scale).map(U8).ok_or_else(overflow),
+            U16(a) => round_integer(*a, scale).map(U16).ok_or_else(overflow),
+            U32(a) => round_integer(*a, scale).map(U32).ok_or_else(overflow),
+            U64(a) => round_integer(*a, scale).map(U64).ok_or_else(overflow),
+            U128(a) => round_integer(*a, scale).map(U128).ok_or_else(overflow),
+            F32(a) => Ok(F32(round_float(f64::from(*a), scale) as f32)),
+            F64(a) => Ok(F64(round_float(*a, scale))),
+            Decimal(a) if scale >= 0 => Ok(Decimal(a.round_dp_with_strategy(
+                scale.unsigned_abs(),
+                RoundingStrategy::MidpointAwayFromZero,
+            ))),
+            Decimal(a) => {
+                match rust_decimal::Decimal::TEN.checked_powu(scale.unsigned_abs().into()) {
+                    Some(factor) => a
+                        .checked_div(factor)
+                        .map(|a| {
+                            a.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                        })
+                        .and_then(|a| a.checked_mul(factor))
+                        .map(Decimal)
+                        .ok_or_else(overflow),
+                    None => Ok(Decimal(rust_decimal::Decimal::ZERO)),
+                }
+            }
+            Null => Ok(Null),
+            _ => Err(ValueError::RoundOnNonNumeric(self.clone()).into()),
+        }
+    }
+
+    pub fn try_exp(&self) -> Result<Value> {
+        match self {
+            Value::Null => Ok(Value::Null),
+            _ => self.to_math_operand("EXP").map(|a| Value::F64(a.exp())),
+        }
+    }
+
+    pub fn try_ln(&self) -> Result<Value> {
+        self.try_logarithm("LN", f64::ln)
+    }
+
+    pub fn try_log2(&self) -> Result<Value> {
+        self.try_logarithm("LOG2", f64::log2)
+    }
+
+    pub fn try_log10(&self) -> Result<Value> {
+        self.try_logarithm("LOG10", f64::log10)
+    }
+
+    pub fn try_log(&self, base: &Value) -> Result<Value> {
+        if self.is_null() || base.is_null() {
+            return Ok(Value::Null);
+        }
+
+        let b = base.to_math_operand("LOG")?;
+        if b.is_nan() || b <= 0.0 || b == 1.0 {
+            return Err(ValueError::DomainError {
+                function: "LOG".to_owned(),
+                value: base.clone(),
+            }
+            .into());
+        }
+
+        if b == 2.0 {
+            self.try_logarithm("LOG", f64::log2)
+        } else if b == 10.0 {
+            self.try_logarithm("LOG", f64::log10)
+        } else {
+            self.try_logarithm("LOG", |a| a.log(b))
+        }
+    }
+
+    fn try_logarithm(&self, function: &str, log: impl Fn(f64) -> f64) -> Result<Value> {
+        if self.is_null() {
+            return Ok(Value::Null);
+        }
+
+        let a = self.to_math_operand(function)?;
+        if a.is_nan() || a <= 0.0 {
+            return Err(ValueError::DomainError {
+                function: function.to_owned(),
+                value: self.clone(),
+            }
+            .into());
+        }
+
+        Ok(Value::F64(log(a)))
+    }
+
+    fn to_math_operand(&self, function: &str) -> Result<f64> {
+        use Value::*;
+
+        match self {
+            I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | U8(_) | U16(_) | U32(_) | U64(_)
+            | U128(_) | F32(_) | F64(_) | Decimal(_) => self.try_into(),
+            _ => Err(ValueError::MathFunctionOnNonNumeric {
+                function: function.to_owned(),
+                value: self.clone(),
+            }
+            .into()),
+        }
+    }
+
+    /// Value to Big-Endian for comparison purpose.
+    /// The items of a list, the value of a composite index, are prefixed with their length.
     pub fn to_cmp_be_bytes(&self) -> Result<Vec<u8>> {
-        self.try_into().and_then(|key: Key| key.to_cmp_be_bytes())
+        match self {
+            Value::List(values) => values.iter().try_fold(Vec::new(), |mut bytes, value| {
+                let item = value.to_cmp_be_bytes()?;
+
+                bytes.extend((item.len() as u32).to_be_bytes());
+                bytes.extend(item);
+
+                Ok(bytes)
+            }),
+            _ => self.try_into().and_then(|key: Key| key.to_cmp_be_bytes()),
+        }
     }
 
     /// # Description
@@ -685,6 +1290,7 @@ impl Value {
     /// 1. If both arguments are String
     ///     - Support only [`Value::Str`] variant
     ///     - Returns the position where the first letter of the substring starts if the string contains a substring.
+    ///     - Positions are counted in characters, not bytes.
     ///     - Returns [`Value::I64`] 0 if the string to be found is not found.
     ///     - Returns minimum value [`Value::I64`] 1 when the string is found.
     ///     - Returns [`Value::Null`] if NULL parameter found.
@@ -725,7 +1331,11 @@ impl Value {
         }
         let from = &String::from(self);
         let sub = &String::from(sub_val);
-        let position = str_position(&from[(start - 1) as usize..].to_owned(), sub) as i64;
+        let offset = match from.char_indices().nth((start - 1) as usize) {
+            Some((offset, _)) => offset,
+            None => return Ok(Value::I64(0)),
+        };
+        let position = str_position(&from[offset..].to_owned(), sub) as i64;
         let position = match position {
             0 => 0,
             _ => position + start - 1,
@@ -734,21 +1344,68 @@ impl Value {
     }
 }
 
+fn validate_enum_variant(value: &str, variants: &[String]) -> Result<()> {
+    if !variants.iter().any(|variant| variant == value) {
+        return Err(ValueError::InvalidEnumVariant {
+            value: value.to_owned(),
+            variants: variants.to_vec(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 fn str_position(from_str: &String, sub_str: &String) -> usize {
     if from_str.is_empty() || sub_str.is_empty() {
         return 0;
     }
     from_str
         .find(sub_str)
-        .map(|position| position + 1)
+        .map(|offset| from_str[..offset].chars().count() + 1)
         .unwrap_or(0)
 }
 
+/// Matches `base` against `pattern`, compiled regexes are cached per thread since the same
+/// pattern is usually applied to every row of a scan.
+fn regex_is_match(base: &str, pattern: &str, case_insensitive: bool) -> Result<bool> {
+    const CACHE_CAPACITY: usize = 64;
+
+    thread_local! {
+        static CACHE: RefCell<HashMap<(String, bool), Regex>> = RefCell::new(HashMap::new());
+    }
+
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let key = (pattern.to_owned(), case_insensitive);
+
+        if let Some(regex) = cache.get(&key) {
+            return Ok(regex.is_match(base));
+        }
+
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| ValueError::InvalidRegexPattern(e.to_string()))?;
+        let matched = regex.is_match(base);
+
+        if cache.len() >= CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(key, regex);
+
+        Ok(matched)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use {
-        super::{Interval, Value::*},
-        crate::data::{point::Point, value::uuid::parse_uuid, ValueError},
+        super::{Interval, TimeZone, Value::*},
+        crate::{
+            data::{point::Point, value::uuid::parse_uuid, NumericBinaryOperator, ValueError},
+            result::Error,
+        },
         chrono::{NaiveDate, NaiveTime},
         rust_decimal::Decimal,
         std::{net::IpAddr, str::FromStr},
@@ -818,6 +1475,32 @@ mod tests {
 
         assert!(date.evaluate_eq(&timestamp));
         assert!(timestamp.evaluate_eq(&date));
+
+        let timestamptz = TimestampTz("2020-04-30T15:00:00Z".parse().unwrap());
+        let seoul = TimeZone::parse("Asia/Seoul").unwrap();
+
+        assert!(!timestamp.evaluate_eq(&timestamptz));
+        assert!(timestamp.evaluate_eq_in(&timestamptz, seoul));
+        assert!(timestamptz.evaluate_eq_in(&timestamp, seoul));
+        assert_eq!(
+            timestamp.evaluate_cmp(&timestamptz),
+            Some(std::cmp::Ordering::Greater)
+        );
+        assert_eq!(
+            timestamp.evaluate_cmp_in(&timestamptz, seoul),
+            Some(std::cmp::Ordering::Equal)
+        );
+        assert!(!date.evaluate_eq(&timestamptz));
+        assert!(date.evaluate_eq_in(&timestamptz, seoul));
+        assert!(timestamptz.evaluate_eq_in(&date, seoul));
+        assert_eq!(
+            date.evaluate_cmp_in(&timestamptz, seoul),
+            Some(std::cmp::Ordering::Equal)
+        );
+
+        assert!(List(vec![I64(1), F64(2.0)]).evaluate_eq(&List(vec![I8(1), I64(2)])));
+        assert!(!List(vec![I64(1), I64(2)]).evaluate_eq(&List(vec![I64(1)])));
+        assert!(!List(vec![I64(1), Null]).evaluate_eq(&List(vec![I64(1), Null])));
     }
 
     #[test]
@@ -908,6 +1591,25 @@ mod tests {
             inet("::1").evaluate_cmp(&inet("::1")),
             Some(Ordering::Equal)
         );
+
+        let list = |values: &[i64]| List(values.iter().copied().map(I64).collect());
+        assert_eq!(
+            list(&[1, 2]).evaluate_cmp(&list(&[1, 3])),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            list(&[2, 0]).evaluate_cmp(&list(&[1, 3])),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            list(&[1, 2]).evaluate_cmp(&list(&[1, 2])),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            list(&[1, 2]).evaluate_cmp(&list(&[1, 2, 0])),
+            Some(Ordering::Less)
+        );
+        assert_eq!(List(vec![I64(1), Null]).evaluate_cmp(&list(&[1, 2])), None);
     }
 
     #[test]
@@ -1156,6 +1858,30 @@ mod tests {
             =>
             Time(time(4, 10, 0))
         );
+        test!(add
+            mon!(1),
+            Date(date(2024, 1, 31))
+            =>
+            Timestamp(date(2024, 2, 29).and_hms_opt(0, 0, 0).unwrap())
+        );
+        test!(add
+            Time(time(12, 0, 0)),
+            Date(date(2021, 5, 7))
+            =>
+            Timestamp(date(2021, 5, 7).and_hms_opt(12, 0, 0).unwrap())
+        );
+        test!(add
+            Interval(Interval::hours(1)),
+            Timestamp(date(2021, 12, 31).and_hms_opt(23, 30, 0).unwrap())
+            =>
+            Timestamp(date(2022, 1, 1).and_hms_opt(0, 30, 0).unwrap())
+        );
+        test!(add
+            Interval(Interval::minutes(5)),
+            Time(time(1, 0, 0))
+            =>
+            Time(time(1, 5, 0))
+        );
         test!(add mon!(1),    mon!(2)    => mon!(3));
 
         test!(subtract I8(3),    I8(2)    => I8(1));
@@ -1763,10 +2489,36 @@ mod tests {
         null_test!(modulo   Null, Null);
     }
 
+    #[test]
+    fn widen_mixed_sign() {
+        assert_eq!(U64(u64::MAX).add(&I64(1)), Ok(I128(u64::MAX as i128 + 1)));
+        assert_eq!(I64(1).add(&U64(u64::MAX)), Ok(I128(u64::MAX as i128 + 1)));
+        assert_eq!(I8(-1).add(&U8(255)), Ok(I16(254)));
+        assert_eq!(U8(255).add(&I8(-1)), Ok(I16(254)));
+        assert_eq!(U8(1).subtract(&I8(2)), Ok(I16(-1)));
+        assert_eq!(U16(1).subtract(&I8(2)), Ok(I32(-1)));
+        assert_eq!(U32(1).multiply(&I8(-3)), Ok(I64(-3)));
+        assert_eq!(U16(7).divide(&I64(-2)), Ok(I64(-3)));
+        assert_eq!(U8(7).modulo(&I16(-4)), Ok(I16(3)));
+        assert_eq!(U8(1).add(&I64(2)), Ok(I64(3)));
+        assert_eq!(I128(1).add(&U64(2)), Ok(I128(3)));
+
+        assert_eq!(U128(1).add(&I8(2)), Ok(U128(3)));
+        assert_eq!(U8(1).add(&U16(2)), Ok(U8(3)));
+        assert_eq!(
+            U8(1).divide(&I8(0)),
+            Err(ValueError::DivisorShouldNotBeZero.into())
+        );
+    }
+
     #[test]
     fn cast() {
         use {
-            crate::{ast::DataType::*, data::Point, prelude::Value},
+            crate::{
+                ast::DataType::*,
+                data::{Point, TimeZone},
+                prelude::Value,
+            },
             chrono::{NaiveDate, NaiveTime},
         };
 
@@ -1977,12 +2729,37 @@ mod tests {
         cast!(Str("2021-05-01 08:05:30".to_owned())                     => Timestamp, Value::Timestamp(NaiveDate::from_ymd_opt(2021, 5, 1).unwrap().and_hms_opt(8, 5, 30).unwrap()));
         cast!(Null                                                      => Timestamp, Null);
 
-        // Bytea
-        cast!(Value::Str("0abc".to_owned()) => Bytea, Value::Bytea(hex::decode("0abc").unwrap()));
+        // TimestampTz
+        let timestamptz = |v: &str| Value::TimestampTz(v.parse().unwrap());
+        cast!(Str("2021-05-01T08:05:30+09:00".to_owned())               => TimestampTz, timestamptz("2021-04-30T23:05:30Z"));
+        cast!(Str("2021-05-01 08:05:30".to_owned())                     => TimestampTz, timestamptz("2021-05-01T08:05:30Z"));
+        cast!(Value::Timestamp(NaiveDate::from_ymd_opt(2021, 5, 1).unwrap().and_hms_opt(8, 5, 30).unwrap()) => TimestampTz, timestamptz("2021-05-01T08:05:30Z"));
+        cast!(timestamptz("2021-05-01T08:05:30+09:00")                  => Timestamp, Value::Timestamp(NaiveDate::from_ymd_opt(2021, 4, 30).unwrap().and_hms_opt(23, 5, 30).unwrap()));
+        cast!(timestamptz("2021-05-01T08:05:30+09:00")                  => Text, Str("2021-05-01 08:05:30+09:00".to_owned()));
+        cast!(Null                                                      => TimestampTz, Null);
+
+        let seoul = TimeZone::parse("Asia/Seoul").unwrap();
         assert_eq!(
-            Value::Str("!@#$5".to_owned()).cast(&Bytea),
-            Err(ValueError::CastFromHexToByteaFailed("!@#$5".to_owned()).into()),
+            Str("2021-05-01 08:05:30".to_owned()).cast_in(&TimestampTz, seoul),
+            Ok(timestamptz("2021-04-30T23:05:30Z"))
         );
+        assert_eq!(
+            timestamptz("2021-04-30T23:05:30Z").cast_in(&Timestamp, seoul),
+            Ok(Value::Timestamp(
+                NaiveDate::from_ymd_opt(2021, 5, 1)
+                    .unwrap()
+                    .and_hms_opt(8, 5, 30)
+                    .unwrap()
+            ))
+        );
+        assert_eq!(
+            timestamptz("2021-04-30T23:05:30Z").cast_in(&Date, seoul),
+            Ok(Value::Date(NaiveDate::from_ymd_opt(2021, 5, 1).unwrap()))
+        );
+
+        // Bytea
+        cast!(Value::Str("0abc".to_owned()) => Bytea, Value::Bytea(b"0abc".to_vec()));
+        cast!(Value::Str("é".to_owned()) => Bytea, Value::Bytea(vec![0xC3, 0xA9]));
 
         // Inet
         cast!(inet("::1") => Inet, inet("::1"));
@@ -2030,9 +2807,81 @@ mod tests {
             List(vec![I64(1), I64(2)])
         );
         assert_eq!(I64(2).concat(I64(1)), Str("21".to_owned()));
+        assert_eq!(
+            Bytea(vec![0, 1]).concat(Bytea(vec![0, 255])),
+            Bytea(vec![0, 1, 0, 255])
+        );
         assert!(Str("A".to_owned()).concat(Null).is_null());
     }
 
+    #[test]
+    fn try_regexp_match() {
+        let glue = Str("GlueSQL".to_owned());
+        let pattern = |p: &str| Str(p.to_owned());
+
+        assert_eq!(
+            glue.try_regexp_match(&pattern("^Glue"), false),
+            Ok(Bool(true))
+        );
+        assert_eq!(
+            glue.try_regexp_match(&pattern("sql$"), false),
+            Ok(Bool(false))
+        );
+        assert_eq!(
+            glue.try_regexp_match(&pattern("sql$"), true),
+            Ok(Bool(true))
+        );
+        assert!(glue.try_regexp_match(&Null, false).unwrap().is_null());
+        assert!(Null
+            .try_regexp_match(&pattern("a"), true)
+            .unwrap()
+            .is_null());
+        assert!(matches!(
+            glue.try_regexp_match(&pattern("(unclosed"), false),
+            Err(Error::Value(ValueError::InvalidRegexPattern(_)))
+        ));
+        assert_eq!(
+            I64(1).try_regexp_match(&pattern("1"), false),
+            Err(ValueError::RegexpOnNonString {
+                base: I64(1),
+                pattern: pattern("1"),
+                case_insensitive: false,
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn try_similar_to() {
+        let pattern = |p: &str| Str(p.to_owned());
+        let similar_to = |base: &str, p: &str| Str(base.to_owned()).try_similar_to(&pattern(p));
+
+        assert_eq!(similar_to("abc", "abc"), Ok(Bool(true)));
+        assert_eq!(similar_to("abc", "a"), Ok(Bool(false)));
+        assert_eq!(similar_to("abc", "(a|x)%"), Ok(Bool(true)));
+        assert_eq!(similar_to("xbc", "(a|x)_c"), Ok(Bool(true)));
+        assert_eq!(similar_to("abc", "a|abc"), Ok(Bool(true)));
+        assert_eq!(similar_to("abcabc", "(abc)+"), Ok(Bool(true)));
+        assert_eq!(similar_to("aaa", "a{2}"), Ok(Bool(false)));
+        assert_eq!(similar_to("a.c", "a.c"), Ok(Bool(true)));
+        assert_eq!(similar_to("abc", "a.c"), Ok(Bool(false)));
+        assert_eq!(similar_to("a%c", r"a\%c"), Ok(Bool(true)));
+        assert_eq!(similar_to("abc", r"a\%c"), Ok(Bool(false)));
+        assert!(Null.try_similar_to(&pattern("%")).unwrap().is_null());
+        assert!(matches!(
+            similar_to("abc", "(a"),
+            Err(Error::Value(ValueError::InvalidRegexPattern(_)))
+        ));
+        assert_eq!(
+            I64(1).try_similar_to(&pattern("1")),
+            Err(ValueError::SimilarToOnNonString {
+                base: I64(1),
+                pattern: pattern("1"),
+            }
+            .into())
+        );
+    }
+
     #[test]
     fn validate_type() {
         use {
@@ -2125,6 +2974,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn into_column_value() {
+        use {
+            super::{Value, ValueError},
+            crate::ast::DataType as D,
+            std::sync::Arc,
+        };
+
+        let variants = || vec!["happy".to_owned(), "sad".to_owned()];
+        let happy = Value::Enum {
+            value: "happy".to_owned(),
+            variants: Arc::new(variants()),
+        };
+
+        assert_eq!(
+            Str("happy".to_owned()).into_column_value(&D::Enum(variants())),
+            Ok(happy.clone())
+        );
+        assert_eq!(
+            happy.clone().into_column_value(&D::Enum(variants())),
+            Ok(happy.clone())
+        );
+        assert_eq!(
+            Str("ok".to_owned()).into_column_value(&D::Enum(variants())),
+            Err(ValueError::InvalidEnumVariant {
+                value: "ok".to_owned(),
+                variants: variants(),
+            }
+            .into())
+        );
+        assert_eq!(Null.into_column_value(&D::Enum(variants())), Ok(Null));
+        assert_eq!(
+            Str("a".to_owned()).into_column_value(&D::Text),
+            Ok(Str("a".to_owned()))
+        );
+        assert!(happy.clone().into_column_value(&D::Text).is_err());
+
+        assert!(happy.evaluate_eq(&Str("happy".to_owned())));
+        assert_eq!(
+            happy.evaluate_cmp(&Str("ok".to_owned())),
+            Some(std::cmp::Ordering::Less)
+        );
+    }
+
     #[test]
     fn unary_minus() {
         use crate::data::Interval as I;
@@ -2152,6 +3045,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unary_bitwise_not() {
+        assert_eq!(I8(5).unary_bitwise_not(), Ok(I8(-6)));
+        assert_eq!(I16(5).unary_bitwise_not(), Ok(I16(-6)));
+        assert_eq!(I32(5).unary_bitwise_not(), Ok(I32(-6)));
+        assert_eq!(I64(5).unary_bitwise_not(), Ok(I64(-6)));
+        assert_eq!(I128(5).unary_bitwise_not(), Ok(I128(-6)));
+        assert_eq!(U8(5).unary_bitwise_not(), Ok(U8(250)));
+        assert_eq!(U16(5).unary_bitwise_not(), Ok(U16(u16::MAX - 5)));
+        assert_eq!(U32(5).unary_bitwise_not(), Ok(U32(u32::MAX - 5)));
+        assert_eq!(U64(5).unary_bitwise_not(), Ok(U64(u64::MAX - 5)));
+        assert_eq!(U128(5).unary_bitwise_not(), Ok(U128(u128::MAX - 5)));
+        assert_eq!(Null.unary_bitwise_not(), Ok(Null));
+
+        assert_eq!(
+            F64(1.0).unary_bitwise_not(),
+            Err(ValueError::UnaryBitwiseNotOnNonInteger.into())
+        );
+        assert_eq!(
+            Decimal(Decimal::ONE).unary_bitwise_not(),
+            Err(ValueError::UnaryBitwiseNotOnNonInteger.into())
+        );
+        assert_eq!(
+            Bool(true).unary_bitwise_not(),
+            Err(ValueError::UnaryBitwiseNotOnNonInteger.into())
+        );
+    }
+
     #[test]
     fn unary_plus() {
         assert_eq!(U8(1).unary_plus(), Ok(U8(1)));
@@ -2186,25 +3107,219 @@ mod tests {
     }
 
     #[test]
-    fn sqrt() {
-        assert_eq!(I8(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(I16(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(I64(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(I128(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(U8(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(U16(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(U32(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(U64(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(U128(9).sqrt(), Ok(F64(3.0)));
-        assert_eq!(F32(9.0_f32).sqrt(), Ok(F64(3.0)));
-        assert_eq!(F64(9.0).sqrt(), Ok(F64(3.0)));
-        assert!(Null.sqrt().unwrap().is_null());
-        assert_eq!(
-            Str("9".to_owned()).sqrt(),
+    fn try_sqrt() {
+        assert_eq!(I8(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(I16(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(I32(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(I64(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(I128(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(U8(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(U16(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(U32(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(U64(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(U128(9).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(F32(9.0_f32).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(F64(9.0).try_sqrt(), Ok(F64(3.0)));
+        assert_eq!(
+            Decimal(Decimal::from(9)).try_sqrt(),
+            Ok(Decimal(Decimal::from(3)))
+        );
+        assert!(Null.try_sqrt().unwrap().is_null());
+        assert_eq!(
+            I64(-9).try_sqrt(),
+            Err(ValueError::NegativeSqrt(I64(-9)).into())
+        );
+        assert_eq!(
+            Decimal(Decimal::NEGATIVE_ONE).try_sqrt(),
+            Err(ValueError::NegativeSqrt(Decimal(Decimal::NEGATIVE_ONE)).into())
+        );
+        assert_eq!(
+            Str("9".to_owned()).try_sqrt(),
             Err(ValueError::SqrtOnNonNumeric(Str("9".to_owned())).into())
         );
     }
 
+    #[test]
+    fn try_abs() {
+        assert_eq!(I8(-3).try_abs(), Ok(I8(3)));
+        assert_eq!(I16(-3).try_abs(), Ok(I16(3)));
+        assert_eq!(I64(3).try_abs(), Ok(I64(3)));
+        assert_eq!(U8(3).try_abs(), Ok(U8(3)));
+        assert_eq!(F64(-1.5).try_abs(), Ok(F64(1.5)));
+        assert_eq!(
+            Decimal(Decimal::NEGATIVE_ONE).try_abs(),
+            Ok(Decimal(Decimal::ONE))
+        );
+        assert!(Null.try_abs().unwrap().is_null());
+        assert_eq!(
+            I8(i8::MIN).try_abs(),
+            Err(ValueError::AbsOverflow(I8(i8::MIN)).into())
+        );
+        assert_eq!(
+            Bool(true).try_abs(),
+            Err(ValueError::AbsOnNonNumeric(Bool(true)).into())
+        );
+    }
+
+    #[test]
+    fn try_floor_and_ceil() {
+        assert_eq!(I8(3).try_floor(), Ok(I8(3)));
+        assert_eq!(U64(3).try_ceil(), Ok(U64(3)));
+        assert_eq!(F32(-1.5).try_floor(), Ok(F32(-2.0)));
+        assert_eq!(F64(-1.5).try_ceil(), Ok(F64(-1.0)));
+        assert_eq!(
+            Decimal(Decimal::new(15, 1)).try_floor(),
+            Ok(Decimal(Decimal::ONE))
+        );
+        assert_eq!(
+            Decimal(Decimal::new(15, 1)).try_ceil(),
+            Ok(Decimal(Decimal::TWO))
+        );
+        assert!(Null.try_floor().unwrap().is_null());
+        assert!(Null.try_ceil().unwrap().is_null());
+        assert_eq!(
+            Str("1".to_owned()).try_floor(),
+            Err(ValueError::FloorOnNonNumeric(Str("1".to_owned())).into())
+        );
+        assert_eq!(
+            Str("1".to_owned()).try_ceil(),
+            Err(ValueError::CeilOnNonNumeric(Str("1".to_owned())).into())
+        );
+    }
+
+    #[test]
+    fn try_round() {
+        assert_eq!(I64(1234).try_round(2), Ok(I64(1234)));
+        assert_eq!(I64(1250).try_round(-2), Ok(I64(1300)));
+        assert_eq!(I64(-1250).try_round(-2), Ok(I64(-1300)));
+        assert_eq!(U8(249).try_round(-1), Ok(U8(250)));
+        assert_eq!(I64(1234).try_round(-40), Ok(I64(0)));
+        assert_eq!(
+            I8(125).try_round(-1),
+            Err(ValueError::RoundOverflow {
+                value: I8(125),
+                scale: -1
+            }
+            .into())
+        );
+        assert_eq!(F64(2.5).try_round(0), Ok(F64(3.0)));
+        assert_eq!(F64(-2.5).try_round(0), Ok(F64(-3.0)));
+        assert_eq!(F64(1.2345).try_round(2), Ok(F64(1.23)));
+        assert_eq!(F64(1250.0).try_round(-2), Ok(F64(1300.0)));
+        assert_eq!(F32(1.25).try_round(1), Ok(F32(1.3)));
+        assert_eq!(
+            Decimal(Decimal::new(12345, 3)).try_round(2),
+            Ok(Decimal(Decimal::new(1235, 2)))
+        );
+        assert_eq!(
+            Decimal(Decimal::new(1250, 0)).try_round(-2),
+            Ok(Decimal(Decimal::new(1300, 0)))
+        );
+        assert!(Null.try_round(0).unwrap().is_null());
+        assert_eq!(
+            Str("1".to_owned()).try_round(0),
+            Err(ValueError::RoundOnNonNumeric(Str("1".to_owned())).into())
+        );
+    }
+
+    #[test]
+    fn try_exp_and_log() {
+        let domain_error = |function: &str, value| -> crate::result::Result<super::Value> {
+            Err(ValueError::DomainError {
+                function: function.to_owned(),
+                value,
+            }
+            .into())
+        };
+
+        assert_eq!(I64(0).try_exp(), Ok(F64(1.0)));
+        assert_eq!(F64(1.0).try_exp(), Ok(F64(std::f64::consts::E)));
+        assert!(Null.try_exp().unwrap().is_null());
+        assert_eq!(
+            Str("1".to_owned()).try_exp(),
+            Err(ValueError::MathFunctionOnNonNumeric {
+                function: "EXP".to_owned(),
+                value: Str("1".to_owned())
+            }
+            .into())
+        );
+
+        assert_eq!(F64(std::f64::consts::E).try_ln(), Ok(F64(1.0)));
+        assert_eq!(U8(8).try_log2(), Ok(F64(3.0)));
+        assert_eq!(Decimal(Decimal::from(1000)).try_log10(), Ok(F64(3.0)));
+        assert!(Null.try_log2().unwrap().is_null());
+        assert_eq!(I64(0).try_ln(), domain_error("LN", I64(0)));
+        assert_eq!(I8(-2).try_log2(), domain_error("LOG2", I8(-2)));
+        assert_eq!(F64(-0.5).try_log10(), domain_error("LOG10", F64(-0.5)));
+        assert!(F64(f64::NAN).try_ln().is_err());
+
+        assert_eq!(I64(8).try_log(&I64(2)), Ok(F64(3.0)));
+        assert_eq!(I64(1000).try_log(&F64(10.0)), Ok(F64(3.0)));
+        assert_eq!(F64(81.0).try_log(&I64(3)), Ok(F64(81.0_f64.log(3.0))));
+        assert!(Null.try_log(&I64(2)).unwrap().is_null());
+        assert!(I64(8).try_log(&Null).unwrap().is_null());
+        assert_eq!(I64(0).try_log(&I64(2)), domain_error("LOG", I64(0)));
+        assert_eq!(I64(8).try_log(&I64(1)), domain_error("LOG", I64(1)));
+        assert_eq!(I64(8).try_log(&I64(-2)), domain_error("LOG", I64(-2)));
+        assert_eq!(
+            I64(8).try_log(&Bool(true)),
+            Err(ValueError::MathFunctionOnNonNumeric {
+                function: "LOG".to_owned(),
+                value: Bool(true)
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!(I64(2).power(&I64(10)), Ok(I64(1024)));
+        assert_eq!(U8(3).power(&U8(4)), Ok(U8(81)));
+        assert_eq!(I8(2).power(&I64(10)), Ok(I16(1024)));
+        assert_eq!(I8(2).power(&I64(20)), Ok(I32(1_048_576)));
+        assert_eq!(U8(2).power(&I64(10)), Ok(U16(1024)));
+        assert_eq!(I64(-3).power(&I8(3)), Ok(I64(-27)));
+        assert_eq!(
+            U64(u64::MAX).power(&I64(2)),
+            Ok(U128(u128::from(u64::MAX).pow(2)))
+        );
+        assert_eq!(I64(2).power(&I64(-1)), Ok(F64(0.5)));
+        assert_eq!(I64(4).power(&F64(0.5)), Ok(F64(2.0)));
+        assert_eq!(
+            I64(2).power(&Decimal(Decimal::TWO)),
+            Ok(Decimal(Decimal::from(4)))
+        );
+        assert_eq!(
+            I64(2).power(&I64(200)),
+            Err(ValueError::BinaryOperationOverflow {
+                lhs: I64(2),
+                rhs: I64(200),
+                operator: NumericBinaryOperator::Power,
+            }
+            .into())
+        );
+
+        assert_eq!(F64(2.0).power(&I64(3)), Ok(F64(8.0)));
+        assert_eq!(F32(2.0).power(&I64(3)), Ok(F32(8.0)));
+        assert_eq!(F32(2.0).power(&F64(3.0)), Ok(F64(8.0)));
+        assert_eq!(
+            Decimal(Decimal::new(15, 1)).power(&I64(2)),
+            Ok(Decimal(Decimal::new(225, 2)))
+        );
+
+        assert!(Null.power(&I64(2)).unwrap().is_null());
+        assert!(I64(2).power(&Null).unwrap().is_null());
+        assert_eq!(
+            Str("2".to_owned()).power(&I64(2)),
+            Err(ValueError::NonNumericMathOperation {
+                lhs: Str("2".to_owned()),
+                operator: NumericBinaryOperator::Power,
+                rhs: I64(2),
+            }
+            .into())
+        );
+    }
+
     #[test]
     fn position() {
         let str1 = Str("ramen".to_owned());
@@ -2225,6 +3340,21 @@ mod tests {
             }
             .into())
         );
+
+        let korean = Str("한국어 문장".to_owned());
+        assert_eq!(korean.position(&Str("어".to_owned())), Ok(I64(3)));
+        assert_eq!(korean.position(&Str("문장".to_owned())), Ok(I64(5)));
+    }
+
+    #[test]
+    fn find_idx() {
+        let str1 = Str("한국어 한국".to_owned());
+        let str2 = Str("한".to_owned());
+
+        assert_eq!(str1.find_idx(&str2, &I64(1)), Ok(I64(1)));
+        assert_eq!(str1.find_idx(&str2, &I64(2)), Ok(I64(5)));
+        assert_eq!(str1.find_idx(&str2, &I64(6)), Ok(I64(0)));
+        assert_eq!(str1.find_idx(&str2, &I64(10)), Ok(I64(0)));
     }
 
     #[test]
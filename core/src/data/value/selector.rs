@@ -56,10 +56,15 @@ impl Value {
             .map(Clone::clone)
     }
 
+    /// Subscripts of `LIST` start from 1, as in PostgreSQL.
     fn get_value_from_compound_type(&self, key: &str) -> Result<&Value> {
         let value = match self {
             Value::Map(map) => map.get(key),
-            Value::List(list) => key.parse::<usize>().ok().and_then(|i| list.get(i)),
+            Value::List(list) => key
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| i.checked_sub(1))
+                .and_then(|i| list.get(i)),
             _ => return Err(ValueError::SelectorRequiresMapOrListTypes.into()),
         };
 
@@ -0,0 +1,175 @@
+use {
+    super::ValueError,
+    crate::result::Result,
+    chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone as _},
+    chrono_tz::Tz,
+    std::fmt,
+};
+
+/// Time zone in which a `TIMESTAMP` is read as a `TIMESTAMPTZ` and the other way around
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeZone {
+    Offset(FixedOffset),
+    Named(Tz),
+}
+
+impl TimeZone {
+    pub const UTC: Self = Self::Named(Tz::UTC);
+
+    pub fn parse(name: &str) -> Result<Self> {
+        if let Ok(tz) = name.parse::<Tz>() {
+            return Ok(Self::Named(tz));
+        }
+
+        parse_offset(name)
+            .map(Self::Offset)
+            .ok_or_else(|| ValueError::FailedToParseTimeZone(name.to_owned()).into())
+    }
+
+    /// Reads `datetime` as the wall clock time of this time zone.
+    /// A time skipped by a DST transition fails, and a repeated one resolves to the earlier offset.
+    pub fn localize(&self, datetime: &NaiveDateTime) -> Result<DateTime<FixedOffset>> {
+        let converted = match self {
+            Self::Offset(offset) => offset.from_local_datetime(datetime).earliest(),
+            Self::Named(tz) => tz
+                .from_local_datetime(datetime)
+                .earliest()
+                .map(|v| v.with_timezone(&v.offset().fix())),
+        };
+
+        converted.ok_or_else(|| {
+            ValueError::NonexistentLocalTime {
+                timestamp: datetime.to_string(),
+                time_zone: self.to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Converts `datetime`, which is in UTC, to the offset this time zone has at that moment.
+    pub fn convert(&self, datetime: &NaiveDateTime) -> DateTime<FixedOffset> {
+        let offset = match self {
+            Self::Offset(offset) => *offset,
+            Self::Named(tz) => tz.offset_from_utc_datetime(datetime).fix(),
+        };
+
+        DateTime::from_utc(*datetime, offset)
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Offset(offset) => write!(f, "{offset}"),
+            Self::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+/// Parses `+09:00`, `+0900` or `+09` formed offsets
+fn parse_offset(v: &str) -> Option<FixedOffset> {
+    let (sign, v) = match v.as_bytes().first()? {
+        b'+' => (1, &v[1..]),
+        b'-' => (-1, &v[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match (v.len(), v.split_once(':')) {
+        (_, Some((hours, minutes))) => (hours, minutes),
+        (4, None) => v.split_at(2),
+        (1 | 2, None) => (v, "0"),
+        _ => return None,
+    };
+
+    if hours.len() > 2 || minutes.len() > 2 {
+        return None;
+    }
+
+    let hours = hours.parse::<i32>().ok()?;
+    let minutes = minutes.parse::<i32>().ok()?;
+    if minutes >= 60 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{parse_offset, TimeZone},
+        crate::data::ValueError,
+        chrono::{FixedOffset, NaiveDate, NaiveDateTime},
+        chrono_tz::Tz,
+    };
+
+    fn timestamp(y: i32, m: u32, d: u32, hh: u32, mm: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(hh, mm, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let offset = |secs| Some(FixedOffset::east_opt(secs).unwrap());
+
+        assert_eq!(parse_offset("+09:00"), offset(9 * 3600));
+        assert_eq!(parse_offset("+0930"), offset(9 * 3600 + 1800));
+        assert_eq!(parse_offset("-05"), offset(-5 * 3600));
+        assert_eq!(parse_offset("+9"), offset(9 * 3600));
+        assert_eq!(parse_offset("09:00"), None);
+        assert_eq!(parse_offset("+09:60"), None);
+        assert_eq!(parse_offset("+123"), None);
+        assert_eq!(parse_offset("+25:00"), None);
+
+        assert_eq!(TimeZone::parse("UTC"), Ok(TimeZone::UTC));
+        assert_eq!(
+            TimeZone::parse("America/New_York"),
+            Ok(TimeZone::Named(Tz::America__New_York))
+        );
+        assert_eq!(
+            TimeZone::parse("Mars/Olympus"),
+            Err(ValueError::FailedToParseTimeZone("Mars/Olympus".to_owned()).into())
+        );
+    }
+
+    #[test]
+    fn dst_transitions() {
+        let new_york = TimeZone::Named(Tz::America__New_York);
+
+        assert_eq!(
+            new_york
+                .localize(&timestamp(2024, 3, 10, 1, 30))
+                .map(|v| v.to_rfc3339()),
+            Ok("2024-03-10T01:30:00-05:00".to_owned())
+        );
+        assert_eq!(
+            new_york.localize(&timestamp(2024, 3, 10, 2, 30)),
+            Err(ValueError::NonexistentLocalTime {
+                timestamp: "2024-03-10 02:30:00".to_owned(),
+                time_zone: "America/New_York".to_owned(),
+            }
+            .into())
+        );
+        assert_eq!(
+            new_york
+                .localize(&timestamp(2024, 11, 3, 1, 30))
+                .map(|v| v.to_rfc3339()),
+            Ok("2024-11-03T01:30:00-04:00".to_owned())
+        );
+
+        assert_eq!(
+            new_york
+                .convert(&timestamp(2024, 11, 3, 5, 30))
+                .to_rfc3339(),
+            "2024-11-03T01:30:00-04:00"
+        );
+        assert_eq!(
+            new_york
+                .convert(&timestamp(2024, 11, 3, 6, 30))
+                .to_rfc3339(),
+            "2024-11-03T01:30:00-05:00"
+        );
+    }
+}
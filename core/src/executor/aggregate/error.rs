@@ -4,4 +4,10 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error};
 pub enum AggregateError {
     #[error("unreachable rc unwrap failure")]
     UnreachableRcUnwrapFailure,
+
+    #[error("JSON_OBJECT_AGG key must not be NULL")]
+    NullKeyInJsonObjectAgg,
+
+    #[error("percentile fraction must be a number between 0 and 1, found: {0}")]
+    InvalidPercentileFraction(String),
 }
@@ -2,14 +2,14 @@ mod error;
 mod state;
 
 use {
-    self::state::State,
     super::{
         context::{AggregateContext, RowContext},
         evaluate::{evaluate, Evaluated},
         filter::check_expr,
+        Session,
     },
     crate::{
-        ast::{Expr, SelectItem},
+        ast::{Expr, OrderByExpr, SelectItem},
         data::Key,
         result::Result,
         store::GStore,
@@ -19,13 +19,16 @@ use {
     std::{convert::identity, rc::Rc},
 };
 
-pub use error::AggregateError;
+pub use {error::AggregateError, state::State};
 
 pub struct Aggregator<'a, T: GStore> {
     storage: &'a T,
+    session: Session<'a>,
     fields: &'a [SelectItem],
     group_by: &'a [Expr],
     having: Option<&'a Expr>,
+    /// Aggregates used only by `ORDER BY` are accumulated as well
+    order_by: &'a [OrderByExpr],
     filter_context: Option<Rc<RowContext<'a>>>,
 }
 
@@ -38,16 +41,20 @@ enum S<T1, T2> {
 impl<'a, T: GStore> Aggregator<'a, T> {
     pub fn new(
         storage: &'a T,
+        session: Session<'a>,
         fields: &'a [SelectItem],
         group_by: &'a [Expr],
         having: Option<&'a Expr>,
+        order_by: &'a [OrderByExpr],
         filter_context: Option<Rc<RowContext<'a>>>,
     ) -> Self {
         Self {
             storage,
+            session,
             fields,
             group_by,
             having,
+            order_by,
             filter_context,
         }
     }
@@ -69,7 +76,7 @@ impl<'a, T: GStore> Aggregator<'a, T> {
             .enumerate()
             .map(|(i, row)| row.map(|row| (i, row)))
             .try_fold(
-                State::new(self.storage),
+                State::new(self.storage, self.session),
                 |state, (index, project_context)| async move {
                     let filter_context = match &self.filter_context {
                         Some(filter_context) => Rc::new(RowContext::concat(
@@ -83,7 +90,9 @@ impl<'a, T: GStore> Aggregator<'a, T> {
                     let evaluated: Vec<Evaluated<'_>> = stream::iter(self.group_by.iter())
                         .then(|expr| {
                             let filter_clone = filter_context.as_ref().map(Rc::clone);
-                            async move { evaluate(self.storage, filter_clone, None, expr).await }
+                            async move {
+                                evaluate(self.storage, self.session, filter_clone, None, expr).await
+                            }
                         })
                         .try_collect::<Vec<_>>()
                         .await?;
@@ -94,18 +103,11 @@ impl<'a, T: GStore> Aggregator<'a, T> {
                         .collect::<Result<Vec<Key>>>()?;
 
                     let state = state.apply(index, group, Rc::clone(&project_context));
-                    let state = stream::iter(self.fields)
-                        .fold(Ok(state), |state, field| {
+                    let state = stream::iter(self.exprs())
+                        .fold(Ok(state), |state, expr| {
                             let filter_clone = filter_context.as_ref().map(Rc::clone);
 
-                            async move {
-                                match field {
-                                    SelectItem::Expr { expr, .. } => {
-                                        aggregate(state?, filter_clone, expr).await
-                                    }
-                                    _ => state,
-                                }
-                            }
+                            async move { aggregate(state?, filter_clone, expr).await }
                         })
                         .await?;
 
@@ -122,6 +124,7 @@ impl<'a, T: GStore> Aggregator<'a, T> {
         state: State<'a, T>,
     ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>>> {
         let storage = self.storage;
+        let session = self.session;
         let filter_context = self.filter_context.as_ref().map(Rc::clone);
         let having = self.having;
         let rows = state
@@ -149,6 +152,7 @@ impl<'a, T: GStore> Aggregator<'a, T> {
 
                             check_expr(
                                 storage,
+                                session,
                                 filter_context,
                                 aggregated.as_ref().map(Rc::clone),
                                 having,
@@ -176,13 +180,17 @@ impl<'a, T: GStore> Aggregator<'a, T> {
             return true;
         }
 
-        self.fields
-            .iter()
-            .map(|field| match field {
-                SelectItem::Expr { expr, .. } => check(expr),
-                _ => false,
-            })
-            .any(identity)
+        self.exprs().any(check)
+    }
+
+    fn exprs(&self) -> impl Iterator<Item = &'a Expr> {
+        let fields = self.fields.iter().filter_map(|field| match field {
+            SelectItem::Expr { expr, .. } => Some(expr),
+            _ => None,
+        });
+        let order_by = self.order_by.iter().map(|OrderByExpr { expr, .. }| expr);
+
+        fields.chain(order_by)
     }
 }
 
@@ -1,13 +1,14 @@
 use {
+    super::AggregateError,
     crate::{
-        ast::{Aggregate, CountArgExpr, DataType},
-        data::{Key, Value},
-        executor::{context::RowContext, evaluate::evaluate},
+        ast::{Aggregate, CountArgExpr, DataType, Expr, OrderByExpr},
+        data::{HyperLogLog, Key, Value},
+        executor::{context::RowContext, evaluate::evaluate, sort::sort_by, Session},
         result::Result,
         store::GStore,
     },
     futures::stream::{self, StreamExt, TryStreamExt},
-    im_rc::{HashMap, HashSet},
+    im_rc::{HashMap, HashSet, Vector as ImVector},
     itertools::Itertools,
     std::{cmp::Ordering, rc::Rc},
     utils::{IndexMap, Vector},
@@ -17,7 +18,8 @@ type Group = Rc<Vec<Key>>;
 type ValuesMap<'a> = HashMap<&'a Aggregate, Value>;
 type Context<'a> = Rc<RowContext<'a>>;
 
-enum AggrValue {
+#[derive(Clone)]
+enum AggrValue<'a> {
     Count {
         wildcard: bool,
         count: i64,
@@ -39,10 +41,34 @@ enum AggrValue {
         sum: Value,
         count: i64,
     },
+    First(Value),
+    Last(Value),
+    CountApproxDistinct(HyperLogLog),
+    StringAgg {
+        separator: &'a str,
+        order_by: &'a [OrderByExpr],
+        /// Non-null values with their sort keys, kept in scan order until export
+        values: ImVector<(Vec<Key>, String)>,
+    },
+    JsonAgg {
+        include_nulls: bool,
+        values: ImVector<Value>,
+    },
+    JsonObjectAgg {
+        include_nulls: bool,
+        entries: HashMap<String, Value>,
+    },
+    Percentile {
+        continuous: bool,
+        fraction: f64,
+        values: ImVector<Value>,
+    },
 }
 
-impl AggrValue {
-    fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
+impl<'a> AggrValue<'a> {
+    /// `args` holds the values of the arguments besides the main expression, the `ORDER BY`
+    /// keys of `STRING_AGG`, the value of `JSON_OBJECT_AGG` and the fraction of percentiles.
+    fn new(aggr: &'a Aggregate, value: &Value, args: Vec<Value>) -> Result<Self> {
         let value = value.clone();
 
         Ok(match aggr {
@@ -71,10 +97,71 @@ impl AggrValue {
                 sum: value,
                 count: 1,
             },
+            Aggregate::First(_) => AggrValue::First(value),
+            Aggregate::Last(_) => AggrValue::Last(value),
+            Aggregate::CountApproxDistinct { precision, .. } => {
+                let mut hll = HyperLogLog::new(*precision);
+                if !value.is_null() {
+                    hll.insert(&Key::try_from(value)?);
+                }
+
+                AggrValue::CountApproxDistinct(hll)
+            }
+            Aggregate::StringAgg {
+                separator,
+                order_by,
+                ..
+            } => {
+                let mut values = ImVector::new();
+                if !value.is_null() {
+                    values.push_back((to_sort_keys(args)?, String::from(&value)));
+                }
+
+                AggrValue::StringAgg {
+                    separator,
+                    order_by,
+                    values,
+                }
+            }
+            Aggregate::JsonAgg { include_nulls, .. } => {
+                let mut values = ImVector::new();
+                if *include_nulls || !value.is_null() {
+                    values.push_back(value);
+                }
+
+                AggrValue::JsonAgg {
+                    include_nulls: *include_nulls,
+                    values,
+                }
+            }
+            Aggregate::JsonObjectAgg { include_nulls, .. } => {
+                let mut entries = HashMap::new();
+                if let Some((key, value)) = to_entry(value, args, *include_nulls)? {
+                    entries.insert(key, value);
+                }
+
+                AggrValue::JsonObjectAgg {
+                    include_nulls: *include_nulls,
+                    entries,
+                }
+            }
+            Aggregate::PercentileCont { .. } | Aggregate::PercentileDisc { .. } => {
+                let fraction = to_fraction(args)?;
+                let mut values = ImVector::new();
+                if !value.is_null() {
+                    values.push_back(value);
+                }
+
+                AggrValue::Percentile {
+                    continuous: matches!(aggr, Aggregate::PercentileCont { .. }),
+                    fraction,
+                    values,
+                }
+            }
         })
     }
 
-    fn accumulate(&self, new_value: &Value) -> Result<Option<Self>> {
+    fn accumulate(&self, new_value: &Value, args: Vec<Value>) -> Result<Option<Self>> {
         match self {
             Self::Count { wildcard, count } => {
                 let wildcard = *wildcard;
@@ -119,6 +206,85 @@ impl AggrValue {
                 sum: sum.add(new_value)?,
                 count: count + 1,
             })),
+            Self::First(_) => Ok(None),
+            Self::Last(_) => Ok(Some(Self::Last(new_value.clone()))),
+            Self::CountApproxDistinct(hll) => {
+                if new_value.is_null() {
+                    return Ok(None);
+                }
+
+                let key = Key::try_from(new_value)?;
+                if !hll.updates(&key) {
+                    return Ok(None);
+                }
+
+                let mut hll = hll.clone();
+                hll.insert(&key);
+
+                Ok(Some(Self::CountApproxDistinct(hll)))
+            }
+            Self::StringAgg {
+                separator,
+                order_by,
+                values,
+            } => {
+                if new_value.is_null() {
+                    return Ok(None);
+                }
+
+                let mut values = values.clone();
+                values.push_back((to_sort_keys(args)?, String::from(new_value)));
+
+                Ok(Some(Self::StringAgg {
+                    separator: *separator,
+                    order_by: *order_by,
+                    values,
+                }))
+            }
+            Self::JsonAgg {
+                include_nulls,
+                values,
+            } => {
+                if !include_nulls && new_value.is_null() {
+                    return Ok(None);
+                }
+
+                let mut values = values.clone();
+                values.push_back(new_value.clone());
+
+                Ok(Some(Self::JsonAgg {
+                    include_nulls: *include_nulls,
+                    values,
+                }))
+            }
+            Self::JsonObjectAgg {
+                include_nulls,
+                entries,
+            } => match to_entry(new_value.clone(), args, *include_nulls)? {
+                Some((key, value)) => Ok(Some(Self::JsonObjectAgg {
+                    include_nulls: *include_nulls,
+                    entries: entries.update(key, value),
+                })),
+                None => Ok(None),
+            },
+            Self::Percentile {
+                continuous,
+                fraction,
+                values,
+            } => {
+                if new_value.is_null() {
+                    return Ok(None);
+                }
+
+                let mut values = values.clone();
+                values.push_back(new_value.clone());
+
+                Ok(Some(Self::Percentile {
+                    continuous: *continuous,
+                    fraction: *fraction,
+                    values,
+                }))
+            }
         }
     }
 
@@ -134,7 +300,11 @@ impl AggrValue {
 
         match self {
             Self::Count { count, .. } => Ok(Value::I64(count)),
-            Self::Sum(value) | Self::Min(value) | Self::Max(value) => Ok(value),
+            Self::Sum(value)
+            | Self::Min(value)
+            | Self::Max(value)
+            | Self::First(value)
+            | Self::Last(value) => Ok(value),
             Self::Avg { sum, count } => {
                 let sum = sum.cast(&DataType::Float)?;
 
@@ -149,24 +319,101 @@ impl AggrValue {
                 sum_square,
                 sum,
                 count,
-            } => variance(sum_square, sum, count).await?.sqrt(),
+            } => variance(sum_square, sum, count).await?.try_sqrt(),
+            Self::CountApproxDistinct(hll) => Ok(Value::I64(hll.estimate().round() as i64)),
+            Self::StringAgg { values, .. } if values.is_empty() => Ok(Value::Null),
+            Self::StringAgg {
+                separator,
+                order_by,
+                values,
+            } => {
+                let mut values = values
+                    .into_iter()
+                    .map(|(keys, value)| {
+                        (keys.into_iter().zip(order_by).collect::<Vec<_>>(), value)
+                    })
+                    .collect::<Vec<_>>();
+                values.sort_by(|(keys_a, _), (keys_b, _)| sort_by(keys_a, keys_b));
+
+                let joined = values.into_iter().map(|(_, value)| value).join(separator);
+
+                Ok(Value::Str(joined))
+            }
+            Self::JsonAgg { values, .. } if values.is_empty() => Ok(Value::Null),
+            Self::JsonAgg { values, .. } => Ok(Value::List(values.into_iter().collect())),
+            Self::JsonObjectAgg { entries, .. } if entries.is_empty() => Ok(Value::Null),
+            Self::JsonObjectAgg { entries, .. } => Ok(Value::Map(entries.into_iter().collect())),
+            Self::Percentile { values, .. } if values.is_empty() => Ok(Value::Null),
+            Self::Percentile {
+                continuous,
+                fraction,
+                values,
+            } => {
+                let mut values = values.into_iter().collect::<Vec<_>>();
+                values.sort_by(|a, b| a.evaluate_cmp(b).unwrap_or(Ordering::Equal));
+
+                if !continuous {
+                    let index = (fraction * values.len() as f64).ceil() as usize;
+
+                    return Ok(values.swap_remove(index.saturating_sub(1)));
+                }
+
+                let position = fraction * (values.len() - 1) as f64;
+                let lower = f64::try_from(&values[position.floor() as usize])?;
+                let upper = f64::try_from(&values[position.ceil() as usize])?;
+
+                Ok(Value::F64(lower + (upper - lower) * position.fract()))
+            }
         }
     }
 }
 
+fn to_fraction(args: Vec<Value>) -> Result<f64> {
+    let value = args.into_iter().next().unwrap_or(Value::Null);
+    let fraction = match &value {
+        Value::Null => None,
+        value => f64::try_from(value).ok(),
+    };
+
+    fraction
+        .filter(|fraction| (0.0..=1.0).contains(fraction))
+        .ok_or_else(|| AggregateError::InvalidPercentileFraction(String::from(&value)).into())
+}
+
+fn to_sort_keys(values: Vec<Value>) -> Result<Vec<Key>> {
+    values.into_iter().map(Key::try_from).collect()
+}
+
+/// Builds a `JSON_OBJECT_AGG` entry from the evaluated key and the first of `args`, returns
+/// `None` when the value is `NULL` and `NULL` values are not included.
+fn to_entry(key: Value, args: Vec<Value>, include_nulls: bool) -> Result<Option<(String, Value)>> {
+    if key.is_null() {
+        return Err(AggregateError::NullKeyInJsonObjectAgg.into());
+    }
+
+    let value = args.into_iter().next().unwrap_or(Value::Null);
+    if !include_nulls && value.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some((String::from(&key), value)))
+}
+
 pub struct State<'a, T: GStore> {
     storage: &'a T,
+    session: Session<'a>,
     index: usize,
     group: Group,
-    values: IndexMap<(Group, &'a Aggregate), (usize, AggrValue)>,
+    values: IndexMap<(Group, &'a Aggregate), (usize, AggrValue<'a>)>,
     groups: HashSet<Group>,
     contexts: Vector<Rc<RowContext<'a>>>,
 }
 
 impl<'a, T: GStore> State<'a, T> {
-    pub fn new(storage: &'a T) -> Self {
+    pub fn new(storage: &'a T, session: Session<'a>) -> Self {
         State {
             storage,
+            session,
             index: 0,
             group: Rc::new(vec![Key::None]),
             values: IndexMap::new(),
@@ -195,18 +442,27 @@ impl<'a, T: GStore> State<'a, T> {
         }
     }
 
-    fn update(self, aggr: &'a Aggregate, value: AggrValue) -> Self {
+    fn update(self, aggr: &'a Aggregate, value: AggrValue<'a>) -> Self {
         let key = (Rc::clone(&self.group), aggr);
         let (values, _) = self.values.insert(key, (self.index, value));
         Self { values, ..self }
     }
 
-    fn get(&self, aggr: &'a Aggregate) -> Option<&(usize, AggrValue)> {
+    fn get(&self, aggr: &'a Aggregate) -> Option<&(usize, AggrValue<'a>)> {
         let group = Rc::clone(&self.group);
 
         self.values.get(&(group, aggr))
     }
 
+    /// Returns the value aggregated so far in the current group without consuming the state,
+    /// which lets window functions read running aggregates.
+    pub async fn peek(&self, aggr: &'a Aggregate) -> Result<Value> {
+        match self.get(aggr) {
+            Some((_, aggr_value)) => aggr_value.clone().export().await,
+            None => Ok(Value::Null),
+        }
+    }
+
     pub async fn export(self) -> Result<Vec<(Option<ValuesMap<'a>>, Option<Context<'a>>)>> {
         let size = match self.values.keys().next() {
             Some((target, _)) => match self.values.keys().position(|(group, _)| group != target) {
@@ -253,6 +509,26 @@ impl<'a, T: GStore> State<'a, T> {
         filter_context: Option<Rc<RowContext<'a>>>,
         aggr: &'a Aggregate,
     ) -> Result<State<'a, T>> {
+        let arg_exprs: Vec<&Expr> = match aggr {
+            Aggregate::StringAgg { order_by, .. } => order_by
+                .iter()
+                .map(|OrderByExpr { expr, .. }| expr)
+                .collect(),
+            Aggregate::JsonObjectAgg { value, .. } => vec![value],
+            Aggregate::PercentileCont { fraction, .. }
+            | Aggregate::PercentileDisc { fraction, .. } => vec![fraction],
+            _ => Vec::new(),
+        };
+        let mut args = Vec::with_capacity(arg_exprs.len());
+        for expr in arg_exprs {
+            let context = filter_context.as_ref().map(Rc::clone);
+            let value: Value = evaluate(self.storage, self.session, context, None, expr)
+                .await?
+                .try_into()?;
+
+            args.push(value);
+        }
+
         let value = match aggr {
             Aggregate::Count(CountArgExpr::Wildcard) => Value::Null,
             Aggregate::Count(CountArgExpr::Expr(expr))
@@ -261,14 +537,24 @@ impl<'a, T: GStore> State<'a, T> {
             | Aggregate::Max(expr)
             | Aggregate::Avg(expr)
             | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => evaluate(self.storage, filter_context, None, expr)
-                .await?
-                .try_into()?,
+            | Aggregate::Stdev(expr)
+            | Aggregate::First(expr)
+            | Aggregate::Last(expr)
+            | Aggregate::CountApproxDistinct { expr, .. }
+            | Aggregate::StringAgg { expr, .. }
+            | Aggregate::JsonAgg { expr, .. }
+            | Aggregate::JsonObjectAgg { key: expr, .. }
+            | Aggregate::PercentileCont { expr, .. }
+            | Aggregate::PercentileDisc { expr, .. } => {
+                evaluate(self.storage, self.session, filter_context, None, expr)
+                    .await?
+                    .try_into()?
+            }
         };
         let aggr_value = match self.get(aggr) {
             Some((index, _)) if self.index <= *index => None,
-            Some((_, aggr_value)) => aggr_value.accumulate(&value)?,
-            None => Some(AggrValue::new(aggr, &value)?),
+            Some((_, aggr_value)) => aggr_value.accumulate(&value, args)?,
+            None => Some(AggrValue::new(aggr, &value, args)?),
         };
 
         match aggr_value {
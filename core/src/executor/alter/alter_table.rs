@@ -1,10 +1,13 @@
 use {
-    super::{validate, AlterError},
+    super::{referenced_columns, validate, AlterError},
     crate::{
-        ast::{AlterTableOperation, Expr, Function},
+        ast::{
+            AlterColumnOperation, AlterTableOperation, CheckConstraint, ColumnDef,
+            ColumnUniqueOption, Expr, ForeignKey, Function, ReferentialAction,
+        },
         data::{Schema, SchemaIndex},
         result::Result,
-        store::{GStore, GStoreMut},
+        store::{AlterTableError, DataRow, GStore, GStoreMut},
     },
 };
 
@@ -26,25 +29,67 @@ pub async fn alter_table<T: GStore + GStoreMut>(
                 .await
         }
         AlterTableOperation::AddColumn { column_def } => {
-            validate(column_def).await?;
+            validate(storage, column_def, &[]).await?;
 
             storage.add_column(table_name, column_def).await
         }
         AlterTableOperation::DropColumn {
             column_name,
             if_exists,
+            cascade,
         } => {
-            let indexes = match storage.fetch_schema(table_name).await? {
-                Some(Schema { indexes, .. }) => indexes,
+            let (indexes, checks, foreign_keys) = match storage.fetch_schema(table_name).await? {
+                Some(Schema {
+                    indexes,
+                    checks,
+                    foreign_keys,
+                    ..
+                }) => (indexes, checks, foreign_keys),
                 None => {
                     return Err(AlterError::TableNotFound(table_name.to_owned()).into());
                 }
             };
+            let referencing_foreign_keys = storage
+                .fetch_all_schemas()
+                .await?
+                .into_iter()
+                .flat_map(|schema| schema.foreign_keys)
+                .filter(|foreign_key| {
+                    foreign_key.referenced_table_name == table_name
+                        && &foreign_key.referenced_column_name == column_name
+                });
 
             let indexes = indexes
                 .iter()
                 .filter(|SchemaIndex { expr, .. }| find_column(expr, column_name))
-                .map(|SchemaIndex { name, .. }| name);
+                .map(|SchemaIndex { name, .. }| name)
+                .collect::<Vec<_>>();
+            let checks = checks
+                .iter()
+                .filter(|CheckConstraint { expr, .. }| {
+                    referenced_columns(expr).contains(&column_name.as_str())
+                })
+                .map(|CheckConstraint { name, .. }| name.to_owned());
+            let constraints = foreign_keys
+                .into_iter()
+                .filter(|foreign_key| &foreign_key.column_name == column_name)
+                .chain(referencing_foreign_keys)
+                .map(|ForeignKey { name, .. }| name);
+            let constraints = checks.chain(constraints).collect::<Vec<_>>();
+
+            // constraints cannot be dropped, so they block the column even with CASCADE
+            if (!cascade && !indexes.is_empty()) || !constraints.is_empty() {
+                let dependents = match cascade {
+                    true => constraints,
+                    false => indexes.into_iter().cloned().chain(constraints).collect(),
+                };
+
+                return Err(AlterError::DependentObjectsExist {
+                    column_name: column_name.to_owned(),
+                    dependents,
+                }
+                .into());
+            }
 
             for index_name in indexes {
                 storage.drop_index(table_name, index_name).await?;
@@ -54,6 +99,80 @@ pub async fn alter_table<T: GStore + GStoreMut>(
                 .drop_column(table_name, column_name, *if_exists)
                 .await
         }
+        AlterTableOperation::AlterColumn {
+            column_name,
+            operation,
+        } => {
+            let (column_defs, foreign_keys) = match storage.fetch_schema(table_name).await? {
+                Some(Schema {
+                    column_defs,
+                    foreign_keys,
+                    ..
+                }) => (column_defs.unwrap_or_default(), foreign_keys),
+                None => {
+                    return Err(AlterError::TableNotFound(table_name.to_owned()).into());
+                }
+            };
+
+            let i = column_defs
+                .iter()
+                .position(|ColumnDef { name, .. }| name == column_name)
+                .ok_or_else(|| AlterTableError::AlteringColumnNotFound(column_name.to_owned()))?;
+
+            match operation {
+                AlterColumnOperation::SetNotNull => {
+                    let set_null = foreign_keys.into_iter().find(|foreign_key| {
+                        &foreign_key.column_name == column_name
+                            && foreign_key.on_delete == ReferentialAction::SetNull
+                    });
+
+                    if let Some(ForeignKey { name, .. }) = set_null {
+                        return Err(AlterError::SetNullOnNotNullColumn {
+                            foreign_key: name,
+                            column: column_name.to_owned(),
+                        }
+                        .into());
+                    }
+
+                    for result in storage.scan_data(table_name).await? {
+                        let (_, data_row) = result?;
+
+                        if matches!(&data_row, DataRow::Vec(values) if values[i].is_null()) {
+                            return Err(AlterError::NullValueFound(column_name.to_owned()).into());
+                        }
+                    }
+
+                    storage
+                        .set_column_nullable(table_name, column_name, false)
+                        .await
+                }
+                AlterColumnOperation::DropNotNull => {
+                    if column_defs[i].unique == Some(ColumnUniqueOption { is_primary: true }) {
+                        return Err(AlterError::NullablePrimaryKey(column_name.to_owned()).into());
+                    }
+
+                    storage
+                        .set_column_nullable(table_name, column_name, true)
+                        .await
+                }
+                AlterColumnOperation::SetDefault(expr) => {
+                    let column_def = ColumnDef {
+                        default: Some(expr.clone()),
+                        ..column_defs[i].clone()
+                    };
+                    validate(storage, &column_def, &column_defs[..i]).await?;
+
+                    storage
+                        .set_column_default(table_name, column_name, Some(expr))
+                        .await
+                }
+                AlterColumnOperation::DropDefault => {
+                    storage
+                        .set_column_default(table_name, column_name, None)
+                        .await
+                }
+            }
+        }
     }
 }
 
@@ -65,8 +184,9 @@ fn find_column(expr: &Expr, column_name: &str) -> bool {
         Expr::Nested(expr) => find(expr),
         Expr::BinaryOp { left, right, .. } => find(left) || find(right),
         Expr::UnaryOp { expr, .. } => find(expr),
+        Expr::Tuple(exprs) => exprs.iter().any(find),
         Expr::Function(func) => match func.as_ref() {
-            Function::Cast { expr, .. } => find(expr),
+            Function::Cast { expr, .. } | Function::TryCast { expr, .. } => find(expr),
             _ => false,
         },
         _ => false,
@@ -0,0 +1,122 @@
+use {
+    crate::{
+        ast::{AlterTypeOperation, ColumnDef, DataType},
+        data::{EnumType, EnumTypeError},
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    std::borrow::Cow,
+};
+
+pub async fn create_type<T: GStore + GStoreMut>(
+    storage: &mut T,
+    name: &str,
+    variants: &[String],
+) -> Result<()> {
+    if storage.fetch_enum_type(name).await?.is_some() {
+        return Err(EnumTypeError::TypeAlreadyExists(name.to_owned()).into());
+    }
+
+    let enum_type = EnumType::new(name.to_owned(), variants.to_vec())?;
+
+    storage.insert_enum_type(enum_type).await
+}
+
+pub async fn alter_type<T: GStore + GStoreMut>(
+    storage: &mut T,
+    name: &str,
+    operation: &AlterTypeOperation,
+) -> Result<()> {
+    let mut enum_type = storage
+        .fetch_enum_type(name)
+        .await?
+        .ok_or_else(|| EnumTypeError::TypeNotFound(name.to_owned()))?;
+
+    match operation {
+        AlterTypeOperation::AddValue {
+            value,
+            if_not_exists,
+        } => enum_type.add_variant(value.to_owned(), *if_not_exists)?,
+    }
+
+    storage.insert_enum_type(enum_type).await
+}
+
+/// Drops the types, a type which a column of a table is declared with cannot be dropped.
+pub async fn drop_type<T: GStore + GStoreMut>(
+    storage: &mut T,
+    names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    let schemas = storage.fetch_all_schemas().await?;
+
+    for name in names {
+        if storage.fetch_enum_type(name).await?.is_none() {
+            match if_exists {
+                true => continue,
+                false => return Err(EnumTypeError::TypeNotFound(name.to_owned()).into()),
+            }
+        }
+
+        let data_type = DataType::UserDefined(name.to_owned());
+        let column = schemas.iter().find_map(|schema| {
+            schema
+                .column_defs
+                .iter()
+                .flatten()
+                .find(|column_def| column_def.data_type == data_type)
+                .map(|column_def| (schema, column_def))
+        });
+
+        if let Some((schema, column_def)) = column {
+            return Err(EnumTypeError::TypeInUse {
+                name: name.to_owned(),
+                table: schema.table_name.to_owned(),
+                column: column_def.name.to_owned(),
+            }
+            .into());
+        }
+
+        storage.delete_enum_type(name).await?;
+    }
+
+    Ok(())
+}
+
+/// Returns the enum type of the variants of the type created with `CREATE TYPE`, the other data
+/// types are returned as they are.
+pub async fn resolve_data_type<'a, T: GStore>(
+    storage: &T,
+    data_type: &'a DataType,
+) -> Result<Cow<'a, DataType>> {
+    let name = match data_type {
+        DataType::UserDefined(name) => name,
+        _ => return Ok(Cow::Borrowed(data_type)),
+    };
+
+    storage
+        .fetch_enum_type(name)
+        .await?
+        .map(|enum_type| Cow::Owned(DataType::Enum(enum_type.variants)))
+        .ok_or_else(|| EnumTypeError::TypeNotFound(name.to_owned()).into())
+}
+
+/// Resolves the data types of the columns, see `resolve_data_type`.
+pub async fn resolve_column_defs<T: GStore>(
+    storage: &T,
+    column_defs: Vec<ColumnDef>,
+) -> Result<Vec<ColumnDef>> {
+    let mut resolved = Vec::with_capacity(column_defs.len());
+    for column_def in column_defs {
+        let data_type = resolve_data_type(storage, &column_def.data_type)
+            .await?
+            .into_owned();
+
+        resolved.push(ColumnDef {
+            data_type,
+            ..column_def
+        });
+    }
+
+    Ok(resolved)
+}
@@ -17,6 +17,19 @@ pub enum AlterError {
     #[error("function does not exist: {0}")]
     FunctionNotFound(String),
 
+    // CREATE VIEW, DROP VIEW
+    #[error("view already exists: {0}")]
+    ViewAlreadyExists(String),
+
+    #[error("view does not exist: {0}")]
+    ViewNotFound(String),
+
+    #[error("'{0}' is not a view")]
+    NotAView(String),
+
+    #[error("'{0}' is a view, not a table")]
+    NotATable(String),
+
     // CREATE INDEX, DROP TABLE
     #[error("table does not exist: {0}")]
     TableNotFound(String),
@@ -47,4 +60,77 @@ pub enum AlterError {
 
     #[error("non-default argument should not follow the default argument")]
     NonDefaultArgumentFollowsDefaultArgument,
+
+    #[error(
+        "cannot drop column '{column_name}' because other objects depend on it: {}",
+        .dependents.join(", ")
+    )]
+    DependentObjectsExist {
+        column_name: String,
+        dependents: Vec<String>,
+    },
+
+    #[error("default value of column '{0}' refers to itself")]
+    CircularDefaultReference(String),
+
+    #[error("default value of column '{column}' refers to unavailable column '{reference}'")]
+    UnavailableDefaultReference { column: String, reference: String },
+
+    // validate check constraints
+    #[error("duplicate check constraint name: {0}")]
+    DuplicateCheckName(String),
+
+    #[error("check constraint '{check}' refers to unavailable column '{reference}'")]
+    UnavailableCheckReference { check: String, reference: String },
+
+    // validate foreign keys
+    #[error("duplicate foreign key name: {0}")]
+    DuplicateForeignKeyName(String),
+
+    #[error("foreign key '{foreign_key}' refers to unavailable column '{column}'")]
+    UnavailableForeignKeyColumn { foreign_key: String, column: String },
+
+    #[error("foreign key '{foreign_key}' references unavailable table '{table_name}'")]
+    ReferencedTableNotFound {
+        foreign_key: String,
+        table_name: String,
+    },
+
+    #[error("foreign key '{foreign_key}' references unavailable column '{table_name}.{column}'")]
+    ReferencedColumnNotFound {
+        foreign_key: String,
+        table_name: String,
+        column: String,
+    },
+
+    #[error("foreign key '{foreign_key}' references column '{table_name}.{column}' which is neither unique nor primary key")]
+    ReferencedColumnNotUnique {
+        foreign_key: String,
+        table_name: String,
+        column: String,
+    },
+
+    #[error("foreign key '{foreign_key}' cannot set not null column '{column}' to null on delete")]
+    SetNullOnNotNullColumn { foreign_key: String, column: String },
+
+    #[error("foreign key '{0}' makes a circular reference")]
+    CircularForeignKey(String),
+
+    // DROP TABLE
+    #[error("cannot drop table '{table_name}' because foreign key '{foreign_key}' references it")]
+    ReferencedTableCannotBeDropped {
+        table_name: String,
+        foreign_key: String,
+    },
+
+    // ALTER COLUMN
+    #[error("column '{0}' contains null values")]
+    NullValueFound(String),
+
+    #[error("primary key column '{0}' cannot be nullable")]
+    NullablePrimaryKey(String),
+
+    // apply schema diff
+    #[error("unsupported schema change: {0}")]
+    UnsupportedSchemaChange(String),
 }
@@ -3,6 +3,7 @@ use {
     crate::{
         ast::{ColumnDef, Expr, Function, OrderByExpr},
         data::Schema,
+        executor::validate::validate_unique_index,
         result::Result,
         store::{GStore, GStoreMut},
     },
@@ -13,26 +14,39 @@ pub async fn create_index<T: GStore + GStoreMut>(
     table_name: &str,
     index_name: &str,
     column: &OrderByExpr,
+    unique: bool,
 ) -> Result<()> {
     let expr = &column.expr;
     let Schema { column_defs, .. } = storage
         .fetch_schema(table_name)
         .await?
         .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+    let column_defs = column_defs.unwrap_or_default();
     let columns = column_defs
-        .unwrap_or_default()
-        .into_iter()
-        .map(|ColumnDef { name, .. }| name)
+        .iter()
+        .map(|ColumnDef { name, .. }| name.to_owned())
         .collect::<Vec<_>>();
 
-    let (valid, has_ident) = validate_index_expr(&columns, expr);
-    if !valid {
-        return Err(AlterError::UnsupportedIndexExpr(expr.clone()).into());
-    } else if !has_ident {
-        return Err(AlterError::IdentifierNotFound(expr.clone()).into());
+    let exprs = match expr {
+        Expr::Tuple(exprs) if unique => exprs.iter().collect(),
+        expr => vec![expr],
+    };
+    for expr in exprs {
+        let (valid, has_ident) = validate_index_expr(&columns, expr);
+        if !valid {
+            return Err(AlterError::UnsupportedIndexExpr(expr.clone()).into());
+        } else if !has_ident {
+            return Err(AlterError::IdentifierNotFound(expr.clone()).into());
+        }
+    }
+
+    if unique {
+        validate_unique_index(storage, table_name, &column_defs, index_name, expr).await?;
     }
 
-    storage.create_index(table_name, index_name, column).await
+    storage
+        .create_index(table_name, index_name, column, unique)
+        .await
 }
 
 fn validate_index_expr(columns: &[String], expr: &Expr) -> (bool, bool) {
@@ -50,7 +64,7 @@ fn validate_index_expr(columns: &[String], expr: &Expr) -> (bool, bool) {
         }
         Expr::UnaryOp { expr, .. } => validate(expr),
         Expr::Function(func) => match func.as_ref() {
-            Function::Cast { expr, .. } => validate(expr),
+            Function::Cast { expr, .. } | Function::TryCast { expr, .. } => validate(expr),
             _ => (false, false),
         },
         _ => (false, false),
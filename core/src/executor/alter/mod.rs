@@ -1,16 +1,28 @@
 mod alter_table;
+mod enum_type;
 mod error;
 mod function;
 mod index;
+mod sequence;
 mod table;
 mod validate;
+mod view;
 
-use validate::{validate, validate_arg_names, validate_column_names, validate_default_args};
+use {
+    sequence::{create_serial_sequences, drop_serial_sequences},
+    validate::{
+        referenced_columns, validate, validate_arg_names, validate_checks, validate_column_names,
+        validate_default_args, validate_foreign_keys,
+    },
+};
 
 pub use {
     alter_table::alter_table,
+    enum_type::{alter_type, create_type, drop_type, resolve_column_defs, resolve_data_type},
     error::AlterError,
     function::{delete_function, insert_function},
     index::create_index,
-    table::{create_table, drop_table},
+    sequence::{create_sequence, drop_sequence},
+    table::{create_table, drop_table, CreateTableOptions},
+    view::{create_view, drop_view},
 };
@@ -0,0 +1,134 @@
+use crate::{
+    ast::{AstLiteral, Expr, Function, SequenceOptions},
+    data::{Schema, Sequence, SequenceError, Value},
+    executor::{evaluate_stateless, Session},
+    result::Result,
+    store::{GStore, GStoreMut},
+};
+
+pub async fn create_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    name: &str,
+    options: &SequenceOptions,
+    if_not_exists: bool,
+) -> Result<()> {
+    let SequenceOptions {
+        start,
+        increment,
+        min_value,
+        max_value,
+        cycle,
+    } = options;
+
+    let sequence = Sequence::new(
+        name.to_owned(),
+        evaluate_option(start).await?,
+        evaluate_option(increment).await?,
+        evaluate_option(min_value).await?,
+        evaluate_option(max_value).await?,
+        *cycle,
+    )?;
+
+    if storage.fetch_sequence(name).await?.is_some() {
+        return match if_not_exists {
+            true => Ok(()),
+            false => Err(SequenceError::SequenceAlreadyExists(name.to_owned()).into()),
+        };
+    }
+
+    session.current_values.reset(name);
+    storage.insert_sequence(sequence).await
+}
+
+pub async fn drop_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for name in names {
+        if storage.fetch_sequence(name).await?.is_none() {
+            match if_exists {
+                true => continue,
+                false => return Err(SequenceError::SequenceNotFound(name.to_owned()).into()),
+            }
+        }
+
+        session.current_values.reset(name);
+        storage.delete_sequence(name).await?;
+    }
+
+    Ok(())
+}
+
+/// Creates the sequences of the `SERIAL` columns of a new table, which are owned by the table.
+pub async fn create_serial_sequences<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    table_name: &str,
+    names: &[String],
+) -> Result<()> {
+    for name in names {
+        if storage.fetch_sequence(name).await?.is_some() {
+            return Err(SequenceError::SequenceAlreadyExists(name.to_owned()).into());
+        }
+    }
+
+    for name in names {
+        let sequence = Sequence::new(name.to_owned(), None, None, None, None, false)?;
+
+        session.current_values.reset(name);
+        storage
+            .insert_sequence(Sequence {
+                owned_by: Some(table_name.to_owned()),
+                ..sequence
+            })
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Drops the sequences owned by the table which its columns take `NEXTVAL` of by default.
+pub async fn drop_serial_sequences<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    schema: &Schema,
+) -> Result<()> {
+    let names = schema
+        .column_defs
+        .iter()
+        .flatten()
+        .filter_map(|column_def| match column_def.default.as_ref() {
+            Some(Expr::Function(function)) => match function.as_ref() {
+                Function::Nextval(Expr::Literal(AstLiteral::QuotedString(name))) => Some(name),
+                _ => None,
+            },
+            _ => None,
+        });
+
+    for name in names {
+        let owned = matches!(
+            storage.fetch_sequence(name).await?,
+            Some(Sequence { owned_by: Some(owner), .. }) if owner == schema.table_name
+        );
+
+        if owned {
+            session.current_values.reset(name);
+            storage.delete_sequence(name).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn evaluate_option(expr: &Option<Expr>) -> Result<Option<i64>> {
+    let expr = match expr {
+        Some(expr) => expr,
+        None => return Ok(None),
+    };
+    let value: Value = evaluate_stateless(None, expr).await?.try_into()?;
+
+    i64::try_from(&value).map(Some)
+}
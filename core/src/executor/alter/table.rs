@@ -1,9 +1,12 @@
 use {
-    super::{validate, validate_column_names, AlterError},
+    super::{
+        create_serial_sequences, drop_serial_sequences, validate, validate_checks,
+        validate_column_names, validate_foreign_keys, AlterError,
+    },
     crate::{
-        ast::{ColumnDef, Query, SetExpr, TableFactor, Values},
+        ast::{CheckConstraint, ColumnDef, ForeignKey, Query, SetExpr, TableFactor, Values},
         data::{Schema, TableError},
-        executor::{evaluate_stateless, select::select},
+        executor::{evaluate_stateless, select::select, Session},
         prelude::{DataType, Value},
         result::{Error, Result},
         store::{GStore, GStoreMut},
@@ -11,83 +14,114 @@ use {
     futures::stream::TryStreamExt,
 };
 
+pub struct CreateTableOptions<'a> {
+    pub target_table_name: &'a str,
+    pub column_defs: Option<&'a [ColumnDef]>,
+    pub checks: &'a [CheckConstraint],
+    pub foreign_keys: &'a [ForeignKey],
+    /// Sequences to create for the `SERIAL` columns
+    pub sequences: &'a [String],
+    pub if_not_exists: bool,
+    pub source: &'a Option<Box<Query>>,
+    pub engine: &'a Option<String>,
+}
+
 pub async fn create_table<T: GStore + GStoreMut>(
     storage: &mut T,
-    target_table_name: &str,
-    column_defs: Option<&[ColumnDef]>,
-    if_not_exists: bool,
-    source: &Option<Box<Query>>,
-    engine: &Option<String>,
+    session: Session<'_>,
+    CreateTableOptions {
+        target_table_name,
+        column_defs,
+        checks,
+        foreign_keys,
+        sequences,
+        if_not_exists,
+        source,
+        engine,
+    }: CreateTableOptions<'_>,
 ) -> Result<()> {
     let target_columns_defs = match source.as_deref() {
-        Some(Query { body, .. }) => match body {
-            SetExpr::Select(select_query) => match &select_query.from.relation {
-                TableFactor::Table { name, .. } => {
-                    let schema = storage.fetch_schema(name).await?;
-                    let Schema {
-                        column_defs: source_column_defs,
-                        ..
-                    } = schema.ok_or_else(|| -> Error {
-                        AlterError::CtasSourceTableNotFound(name.to_owned()).into()
-                    })?;
-
-                    source_column_defs
-                }
-                TableFactor::Series { .. } => {
-                    let column_def = ColumnDef {
-                        name: "N".into(),
-                        data_type: DataType::Int,
-                        nullable: false,
-                        default: None,
-                        unique: None,
-                    };
-
-                    Some(vec![column_def])
-                }
-                _ => {
-                    return Err(Error::Table(TableError::Unreachable));
-                }
-            },
-            SetExpr::Values(Values(values_list)) => {
-                let first_len = values_list[0].len();
-                let mut column_types = vec![None; first_len];
-
-                for exprs in values_list {
-                    for (i, expr) in exprs.iter().enumerate() {
-                        if column_types[i].is_some() {
-                            continue;
-                        }
+        Some(Query { body, .. }) => {
+            let mut body = body;
+            // columns of a set operation are defined by its leftmost operand
+            while let SetExpr::SetOperation { left: operand, .. }
+            | SetExpr::Recursive { base: operand, .. } = body
+            {
+                body = operand.as_ref();
+            }
 
-                        column_types[i] = evaluate_stateless(None, expr)
-                            .await
-                            .and_then(Value::try_from)
-                            .map(|value| value.get_type())?;
+            match body {
+                SetExpr::Select(select_query) => match &select_query.from.relation {
+                    TableFactor::Table { name, .. } => {
+                        let schema = storage.fetch_schema(name).await?;
+                        let Schema {
+                            column_defs: source_column_defs,
+                            ..
+                        } = schema.ok_or_else(|| -> Error {
+                            AlterError::CtasSourceTableNotFound(name.to_owned()).into()
+                        })?;
+
+                        source_column_defs
+                    }
+                    TableFactor::Series { .. } => {
+                        let column_def = ColumnDef {
+                            name: "N".into(),
+                            data_type: DataType::Int,
+                            nullable: false,
+                            default: None,
+                            unique: None,
+                        };
+
+                        Some(vec![column_def])
+                    }
+                    _ => {
+                        return Err(Error::Table(TableError::Unreachable));
                     }
+                },
+                SetExpr::Values(Values(values_list)) => {
+                    let first_len = values_list[0].len();
+                    let mut column_types = vec![None; first_len];
+
+                    for exprs in values_list {
+                        for (i, expr) in exprs.iter().enumerate() {
+                            if column_types[i].is_some() {
+                                continue;
+                            }
+
+                            column_types[i] = evaluate_stateless(None, expr)
+                                .await
+                                .and_then(Value::try_from)
+                                .map(|value| value.get_type())?;
+                        }
 
-                    if column_types.iter().all(Option::is_some) {
-                        break;
+                        if column_types.iter().all(Option::is_some) {
+                            break;
+                        }
                     }
-                }
 
-                let column_defs = column_types
-                    .iter()
-                    .map(|column_type| match column_type {
-                        Some(column_type) => column_type.to_owned(),
-                        None => DataType::Text,
-                    })
-                    .enumerate()
-                    .map(|(i, data_type)| ColumnDef {
-                        name: format!("column{}", i + 1),
-                        data_type,
-                        nullable: true,
-                        default: None,
-                        unique: None,
-                    })
-                    .collect::<Vec<_>>();
-
-                Some(column_defs)
+                    let column_defs = column_types
+                        .iter()
+                        .map(|column_type| match column_type {
+                            Some(column_type) => column_type.to_owned(),
+                            None => DataType::Text,
+                        })
+                        .enumerate()
+                        .map(|(i, data_type)| ColumnDef {
+                            name: format!("column{}", i + 1),
+                            data_type,
+                            nullable: true,
+                            default: None,
+                            unique: None,
+                        })
+                        .collect::<Vec<_>>();
+
+                    Some(column_defs)
+                }
+                SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+                    return Err(Error::Table(TableError::Unreachable));
+                }
             }
-        },
+        }
         None if column_defs.is_some() => column_defs.map(<[ColumnDef]>::to_vec),
         None => None,
     };
@@ -95,17 +129,31 @@ pub async fn create_table<T: GStore + GStoreMut>(
     if let Some(column_defs) = target_columns_defs.as_deref() {
         validate_column_names(column_defs)?;
 
-        for column_def in column_defs {
-            validate(column_def).await?;
+        for (i, column_def) in column_defs.iter().enumerate() {
+            validate(storage, column_def, &column_defs[..i]).await?;
         }
     }
 
+    validate_checks(target_columns_defs.as_deref().unwrap_or_default(), checks)?;
+
     if storage.fetch_schema(target_table_name).await?.is_none() {
+        validate_foreign_keys(
+            storage,
+            target_table_name,
+            target_columns_defs.as_deref().unwrap_or_default(),
+            foreign_keys,
+        )
+        .await?;
+        create_serial_sequences(storage, session, target_table_name, sequences).await?;
+
         let schema = Schema {
             table_name: target_table_name.to_owned(),
             column_defs: target_columns_defs,
+            checks: checks.to_vec(),
+            foreign_keys: foreign_keys.to_vec(),
             indexes: vec![],
             engine: engine.clone(),
+            view: None,
         };
 
         storage.insert_schema(&schema).await?;
@@ -115,7 +163,7 @@ pub async fn create_table<T: GStore + GStoreMut>(
 
     match source {
         Some(query) => {
-            let rows = select(storage, query, None)
+            let rows = select(storage, session, query, None)
                 .await?
                 .map_ok(Into::into)
                 .try_collect()
@@ -132,6 +180,7 @@ pub async fn create_table<T: GStore + GStoreMut>(
 
 pub async fn drop_table<T: GStore + GStoreMut>(
     storage: &mut T,
+    session: Session<'_>,
     table_names: &[String],
     if_exists: bool,
 ) -> Result<()> {
@@ -139,7 +188,33 @@ pub async fn drop_table<T: GStore + GStoreMut>(
         let schema = storage.fetch_schema(table_name).await?;
 
         if !if_exists {
-            schema.ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+            schema
+                .as_ref()
+                .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+        }
+
+        if let Some(Schema { view: Some(_), .. }) = schema {
+            return Err(AlterError::NotATable(table_name.to_owned()).into());
+        }
+
+        let referencing_foreign_key = storage
+            .fetch_all_schemas()
+            .await?
+            .into_iter()
+            .filter(|schema| !table_names.contains(&schema.table_name))
+            .flat_map(|schema| schema.foreign_keys)
+            .find(|foreign_key| &foreign_key.referenced_table_name == table_name);
+
+        if let Some(ForeignKey { name, .. }) = referencing_foreign_key {
+            return Err(AlterError::ReferencedTableCannotBeDropped {
+                table_name: table_name.to_owned(),
+                foreign_key: name,
+            }
+            .into());
+        }
+
+        if let Some(schema) = schema {
+            drop_serial_sequences(storage, session, &schema).await?;
         }
 
         storage.delete_schema(table_name).await?;
@@ -1,13 +1,26 @@
 use {
-    super::AlterError,
+    super::{resolve_data_type, AlterError},
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, DataType, OperateFunctionArg},
+        ast::{
+            CheckConstraint, ColumnDef, ColumnUniqueOption, DataType, Expr, ForeignKey, Function,
+            OperateFunctionArg, ReferentialAction,
+        },
+        data::{Schema, TimeZone},
         executor::evaluate_stateless,
+        plan::PlanExpr,
         result::Result,
+        store::GStore,
     },
 };
 
-pub async fn validate(column_def: &ColumnDef) -> Result<()> {
+/// Validates the column definition.
+/// The default value can refer to the columns in `preceding_column_defs`, which already have
+/// their values when the default value is evaluated on insert.
+pub async fn validate<T: GStore>(
+    storage: &T,
+    column_def: &ColumnDef,
+    preceding_column_defs: &[ColumnDef],
+) -> Result<()> {
     let ColumnDef {
         data_type,
         default,
@@ -27,13 +40,224 @@ pub async fn validate(column_def: &ColumnDef) -> Result<()> {
         .into());
     }
 
+    // a type created with `CREATE TYPE` must exist
+    let data_type = resolve_data_type(storage, data_type).await?;
+
     if let Some(expr) = default {
-        evaluate_stateless(None, expr).await?;
+        let references = referenced_columns(expr);
+        let unavailable = references.iter().find(|reference| {
+            !preceding_column_defs
+                .iter()
+                .any(|column_def| &&column_def.name == reference)
+        });
+
+        match unavailable {
+            Some(reference) if reference == name => {
+                return Err(AlterError::CircularDefaultReference(name.to_owned()).into());
+            }
+            Some(reference) => {
+                return Err(AlterError::UnavailableDefaultReference {
+                    column: name.to_owned(),
+                    reference: reference.to_string(),
+                }
+                .into());
+            }
+            // sequence functions need the storage, they are evaluated on insert only
+            None if references.is_empty() && !uses_sequence(expr) => {
+                let evaluated = evaluate_stateless(None, expr).await?;
+
+                // the default of an enum column must be one of its variants
+                if let DataType::Enum(_) = data_type.as_ref() {
+                    evaluated.try_into_value(&data_type, true, TimeZone::UTC)?;
+                }
+            }
+            None => {}
+        }
     }
 
     Ok(())
 }
 
+/// Validates that the `CHECK` constraints have distinct names and refer to the columns of the table
+pub fn validate_checks(column_defs: &[ColumnDef], checks: &[CheckConstraint]) -> Result<()> {
+    for (i, CheckConstraint { name, expr }) in checks.iter().enumerate() {
+        if checks[..i].iter().any(|check| &check.name == name) {
+            return Err(AlterError::DuplicateCheckName(name.to_owned()).into());
+        }
+
+        let unavailable = referenced_columns(expr).into_iter().find(|reference| {
+            !column_defs
+                .iter()
+                .any(|column_def| &column_def.name == reference)
+        });
+
+        if let Some(reference) = unavailable {
+            return Err(AlterError::UnavailableCheckReference {
+                check: name.to_owned(),
+                reference: reference.to_owned(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the foreign keys refer to the columns of the table and reference
+/// unique or primary key columns of existing tables, without making a cycle of references
+pub async fn validate_foreign_keys<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    foreign_keys: &[ForeignKey],
+) -> Result<()> {
+    for (i, foreign_key) in foreign_keys.iter().enumerate() {
+        let ForeignKey {
+            name,
+            column_name,
+            referenced_table_name,
+            referenced_column_name,
+            on_delete,
+        } = foreign_key;
+
+        if foreign_keys[..i]
+            .iter()
+            .any(|foreign_key| &foreign_key.name == name)
+        {
+            return Err(AlterError::DuplicateForeignKeyName(name.to_owned()).into());
+        }
+
+        let column_def = column_defs
+            .iter()
+            .find(|column_def| &column_def.name == column_name)
+            .ok_or_else(|| AlterError::UnavailableForeignKeyColumn {
+                foreign_key: name.to_owned(),
+                column: column_name.to_owned(),
+            })?;
+
+        if *on_delete == ReferentialAction::SetNull && !column_def.nullable {
+            return Err(AlterError::SetNullOnNotNullColumn {
+                foreign_key: name.to_owned(),
+                column: column_name.to_owned(),
+            }
+            .into());
+        }
+
+        if referenced_table_name == table_name
+            || references_table(storage, referenced_table_name, table_name).await?
+        {
+            return Err(AlterError::CircularForeignKey(name.to_owned()).into());
+        }
+
+        let referenced_column_defs = match storage.fetch_schema(referenced_table_name).await? {
+            Some(Schema { column_defs, .. }) => column_defs.unwrap_or_default(),
+            None => {
+                return Err(AlterError::ReferencedTableNotFound {
+                    foreign_key: name.to_owned(),
+                    table_name: referenced_table_name.to_owned(),
+                }
+                .into());
+            }
+        };
+
+        let referenced_column_def = referenced_column_defs
+            .iter()
+            .find(|column_def| &column_def.name == referenced_column_name)
+            .ok_or_else(|| AlterError::ReferencedColumnNotFound {
+                foreign_key: name.to_owned(),
+                table_name: referenced_table_name.to_owned(),
+                column: referenced_column_name.to_owned(),
+            })?;
+
+        if referenced_column_def.unique.is_none() {
+            return Err(AlterError::ReferencedColumnNotUnique {
+                foreign_key: name.to_owned(),
+                table_name: referenced_table_name.to_owned(),
+                column: referenced_column_name.to_owned(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Walks the foreign keys from `table_name` and returns whether they reach `target_table_name`
+async fn references_table<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    target_table_name: &str,
+) -> Result<bool> {
+    let mut visited = vec![table_name.to_owned()];
+    let mut i = 0;
+
+    while let Some(table_name) = visited.get(i) {
+        let foreign_keys = match storage.fetch_schema(table_name).await? {
+            Some(Schema { foreign_keys, .. }) => foreign_keys,
+            None => Vec::new(),
+        };
+
+        for ForeignKey {
+            referenced_table_name,
+            ..
+        } in foreign_keys
+        {
+            if referenced_table_name == target_table_name {
+                return Ok(true);
+            }
+
+            if !visited.contains(&referenced_table_name) {
+                visited.push(referenced_table_name);
+            }
+        }
+
+        i += 1;
+    }
+
+    Ok(false)
+}
+
+pub fn referenced_columns(expr: &Expr) -> Vec<&str> {
+    match expr.into() {
+        PlanExpr::None | PlanExpr::Query(_) => Vec::new(),
+        PlanExpr::Identifier(ident) | PlanExpr::CompoundIdentifier { ident, .. } => vec![ident],
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => referenced_columns(expr),
+        PlanExpr::TwoExprs(expr, expr2) => [expr, expr2]
+            .into_iter()
+            .flat_map(referenced_columns)
+            .collect(),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => [expr, expr2, expr3]
+            .into_iter()
+            .flat_map(referenced_columns)
+            .collect(),
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().flat_map(referenced_columns).collect(),
+    }
+}
+
+fn uses_sequence(expr: &Expr) -> bool {
+    if let Expr::Function(function) = expr {
+        if matches!(
+            function.as_ref(),
+            Function::Nextval(_) | Function::Currval(_) | Function::Setval { .. }
+        ) {
+            return true;
+        }
+    }
+
+    match expr.into() {
+        PlanExpr::None
+        | PlanExpr::Query(_)
+        | PlanExpr::Identifier(_)
+        | PlanExpr::CompoundIdentifier { .. } => false,
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => uses_sequence(expr),
+        PlanExpr::TwoExprs(expr, expr2) => [expr, expr2].into_iter().any(uses_sequence),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            [expr, expr2, expr3].into_iter().any(uses_sequence)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(uses_sequence),
+    }
+}
+
 pub fn validate_column_names(column_defs: &[ColumnDef]) -> Result<()> {
     let duplicate_column_name = column_defs
         .iter()
@@ -0,0 +1,116 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::{Cte, Query, SetExpr, TableAlias, TableFactor, TableWithJoins},
+        data::{Schema, SchemaView},
+        executor::fetch::fetch_relation_columns,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    std::iter,
+};
+
+pub async fn create_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    name: &str,
+    columns: &[String],
+    query: &Query,
+    or_replace: bool,
+) -> Result<()> {
+    match storage.fetch_schema(name).await? {
+        Some(Schema { view: None, .. }) => {
+            return Err(AlterError::TableAlreadyExists(name.to_owned()).into());
+        }
+        Some(_) if !or_replace => {
+            return Err(AlterError::ViewAlreadyExists(name.to_owned()).into());
+        }
+        _ => {}
+    }
+
+    for table_name in referenced_tables(query) {
+        if storage.fetch_schema(table_name).await?.is_none() {
+            return Err(AlterError::TableNotFound(table_name.to_owned()).into());
+        }
+    }
+
+    // validates the number of the column names against the labels of the query
+    let relation = TableFactor::Derived {
+        subquery: query.clone(),
+        alias: TableAlias {
+            name: name.to_owned(),
+            columns: columns.to_vec(),
+        },
+        lateral: false,
+    };
+    fetch_relation_columns(storage, &relation).await?;
+
+    let schema = Schema {
+        table_name: name.to_owned(),
+        column_defs: None,
+        checks: Vec::new(),
+        foreign_keys: Vec::new(),
+        indexes: Vec::new(),
+        engine: None,
+        view: Some(SchemaView {
+            columns: columns.to_vec(),
+            query: query.clone(),
+        }),
+    };
+
+    storage.insert_schema(&schema).await
+}
+
+pub async fn drop_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for name in names {
+        match storage.fetch_schema(name).await? {
+            Some(Schema { view: Some(_), .. }) => storage.delete_schema(name).await?,
+            Some(_) => return Err(AlterError::NotAView(name.to_owned()).into()),
+            None if if_exists => {}
+            None => return Err(AlterError::ViewNotFound(name.to_owned()).into()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Tables and views which the query reads rows from, except the ones in subquery expressions and
+/// the common table expressions of the query itself
+fn referenced_tables(query: &Query) -> Vec<&String> {
+    let Query { with, body, .. } = query;
+
+    with.iter()
+        .flat_map(|Cte { query, .. }| referenced_tables(query))
+        .chain(set_expr_tables(body))
+        .filter(|name| !with.iter().any(|Cte { alias, .. }| alias.name == **name))
+        .collect()
+}
+
+fn set_expr_tables(body: &SetExpr) -> Vec<&String> {
+    match body {
+        SetExpr::Select(select) => {
+            let TableWithJoins { relation, joins } = &select.from;
+
+            iter::once(relation)
+                .chain(joins.iter().map(|join| &join.relation))
+                .flat_map(|table_factor| match table_factor {
+                    TableFactor::Table { name, .. } => vec![name],
+                    TableFactor::Derived { subquery, .. } => referenced_tables(subquery),
+                    TableFactor::Series { .. }
+                    | TableFactor::Unnest { .. }
+                    | TableFactor::Dictionary { .. } => Vec::new(),
+                })
+                .collect()
+        }
+        SetExpr::SetOperation { left, right, .. } => set_expr_tables(left)
+            .into_iter()
+            .chain(set_expr_tables(right))
+            .collect(),
+        // the step of a recursive query reads the rows of the query itself
+        SetExpr::Recursive { base, .. } => set_expr_tables(base),
+        SetExpr::Values(_) => Vec::new(),
+    }
+}
@@ -0,0 +1,140 @@
+use {
+    super::ExecuteError,
+    crate::{
+        ast::{ColumnDef, DataType},
+        data::{HyperLogLog, Key, Schema, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    std::cmp::Ordering,
+};
+
+/// Table into which `ANALYZE TABLE` stores the column statistics
+pub const STATISTICS_TABLE: &str = "__gluesql_statistics";
+
+const STATISTICS_DDL: &str = r#"CREATE TABLE "__gluesql_statistics" (
+    "table_name" TEXT,
+    "column_name" TEXT,
+    "row_count" INT,
+    "null_count" INT,
+    "distinct_count" INT NULL,
+    "min_value" TEXT NULL,
+    "max_value" TEXT NULL
+);"#;
+
+struct ColumnStatistics {
+    null_count: i64,
+    /// `None` for the types which cannot be compared, such as `MAP` and `LIST`
+    distinct: Option<HyperLogLog>,
+    min: Option<Value>,
+    max: Option<Value>,
+}
+
+impl ColumnStatistics {
+    fn new(data_type: &DataType) -> Self {
+        let comparable = !matches!(data_type, DataType::Map | DataType::List | DataType::Point);
+
+        Self {
+            null_count: 0,
+            distinct: comparable.then(|| HyperLogLog::new(HyperLogLog::DEFAULT_PRECISION)),
+            min: None,
+            max: None,
+        }
+    }
+
+    fn add(&mut self, value: &Value) -> Result<()> {
+        if value.is_null() {
+            self.null_count += 1;
+
+            return Ok(());
+        }
+
+        let distinct = match &mut self.distinct {
+            Some(distinct) => distinct,
+            None => return Ok(()),
+        };
+        distinct.insert(&Key::try_from(value)?);
+
+        match &self.min {
+            Some(min) if min.evaluate_cmp(value) != Some(Ordering::Greater) => {}
+            _ => self.min = Some(value.clone()),
+        }
+        match &self.max {
+            Some(max) if max.evaluate_cmp(value) != Some(Ordering::Less) => {}
+            _ => self.max = Some(value.clone()),
+        }
+
+        Ok(())
+    }
+
+    fn into_row(self, table_name: &str, column_name: &str, row_count: i64) -> DataRow {
+        let text = |value: Option<Value>| match value {
+            Some(value) => Value::Str(String::from(&value)),
+            None => Value::Null,
+        };
+
+        DataRow::Vec(vec![
+            Value::Str(table_name.to_owned()),
+            Value::Str(column_name.to_owned()),
+            Value::I64(row_count),
+            Value::I64(self.null_count),
+            match self.distinct {
+                Some(distinct) => Value::I64(distinct.estimate().round() as i64),
+                None => Value::Null,
+            },
+            text(self.min),
+            text(self.max),
+        ])
+    }
+}
+
+/// Collects the statistics of each column of the table and replaces its rows in
+/// `__gluesql_statistics`, which is created on the first run.
+pub async fn analyze<T: GStore + GStoreMut>(storage: &mut T, table_name: &str) -> Result<()> {
+    let Schema { column_defs, .. } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
+    let column_defs = column_defs.unwrap_or_default();
+
+    let mut row_count = 0;
+    let mut columns = column_defs
+        .iter()
+        .map(|ColumnDef { data_type, .. }| ColumnStatistics::new(data_type))
+        .collect::<Vec<_>>();
+    for result in storage.scan_data(table_name).await? {
+        let (_, data_row) = result?;
+        row_count += 1;
+
+        if let DataRow::Vec(values) = data_row {
+            for (column, value) in columns.iter_mut().zip(values.iter()) {
+                column.add(value)?;
+            }
+        }
+    }
+
+    let rows = column_defs
+        .iter()
+        .zip(columns)
+        .map(|(ColumnDef { name, .. }, column)| column.into_row(table_name, name, row_count))
+        .collect();
+
+    if storage.fetch_schema(STATISTICS_TABLE).await?.is_none() {
+        storage
+            .insert_schema(&Schema::from_ddl(STATISTICS_DDL)?)
+            .await?;
+    }
+
+    let target = Value::Str(table_name.to_owned());
+    let mut stale_keys = Vec::new();
+    for result in storage.scan_data(STATISTICS_TABLE).await? {
+        let (key, data_row) = result?;
+
+        if matches!(&data_row, DataRow::Vec(values) if values.first() == Some(&target)) {
+            stale_keys.push(key);
+        }
+    }
+
+    storage.delete_data(STATISTICS_TABLE, stale_keys).await?;
+    storage.append_data(STATISTICS_TABLE, rows).await
+}
@@ -0,0 +1,397 @@
+use {
+    super::{context::RowContext, evaluate::evaluate, EvaluateError, Session},
+    crate::{
+        ast::{CheckConstraint, ColumnDef, ColumnUniqueOption, ForeignKey, ReferentialAction},
+        data::{Key, Schema, Value},
+        result::Result,
+        store::{DataRow, GStore, GStoreMut},
+    },
+    serde::Serialize,
+    std::{
+        collections::{HashMap, HashSet, VecDeque},
+        fmt::Debug,
+        rc::Rc,
+    },
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq)]
+pub enum ConstraintError {
+    #[error(
+        "check constraint '{constraint_name}' is violated by row ({})",
+        .row.iter().map(String::from).collect::<Vec<_>>().join(", ")
+    )]
+    CheckFailed {
+        constraint_name: String,
+        row: Vec<Value>,
+    },
+
+    #[error(
+        "foreign key '{constraint_name}' is violated: referenced value {value:?} does not exist"
+    )]
+    ForeignKeyViolation {
+        constraint_name: String,
+        value: Value,
+    },
+
+    #[error("foreign key '{constraint_name}' is violated: value {value:?} is still referenced")]
+    ReferencedRowExists {
+        constraint_name: String,
+        value: Value,
+    },
+}
+
+/// Evaluates the `CHECK` constraints of the table against the rows to write.
+/// A row violates a constraint only when it evaluates to `FALSE`, `NULL` satisfies it.
+pub async fn validate_check_constraints<'a, T: GStore>(
+    storage: &T,
+    session: Session<'_>,
+    column_defs: &[ColumnDef],
+    checks: &[CheckConstraint],
+    rows: impl Iterator<Item = &'a [Value]>,
+) -> Result<()> {
+    if checks.is_empty() {
+        return Ok(());
+    }
+
+    let columns = column_defs
+        .iter()
+        .map(|ColumnDef { name, .. }| name.to_owned())
+        .collect::<Vec<_>>();
+
+    for values in rows {
+        for CheckConstraint { name, expr } in checks {
+            let context = RowContext::RefVecData {
+                columns: &columns,
+                values,
+            };
+            let value: Value = evaluate(storage, session, Some(Rc::new(context)), None, expr)
+                .await?
+                .try_into()?;
+
+            match value {
+                Value::Bool(true) | Value::Null => {}
+                Value::Bool(false) => {
+                    return Err(ConstraintError::CheckFailed {
+                        constraint_name: name.to_owned(),
+                        row: values.to_vec(),
+                    }
+                    .into());
+                }
+                value => {
+                    return Err(EvaluateError::BooleanTypeRequired(format!("{value:?}")).into());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the foreign key values of the rows to write exist in the referenced tables.
+/// `NULL` does not reference any row.
+pub async fn validate_foreign_keys<'a, T: GStore>(
+    storage: &T,
+    column_defs: &[ColumnDef],
+    foreign_keys: &[ForeignKey],
+    rows: impl Iterator<Item = &'a [Value]> + Clone,
+) -> Result<()> {
+    for foreign_key in foreign_keys {
+        let ForeignKey {
+            name,
+            column_name,
+            referenced_table_name,
+            referenced_column_name,
+            ..
+        } = foreign_key;
+
+        let i = match column_index(column_defs, column_name) {
+            Some(i) => i,
+            None => continue,
+        };
+        let referenced_column_defs = match storage.fetch_schema(referenced_table_name).await? {
+            Some(Schema { column_defs, .. }) => column_defs.unwrap_or_default(),
+            None => Vec::new(),
+        };
+        let j = column_index(&referenced_column_defs, referenced_column_name);
+        let is_primary = matches!(
+            j.map(|j| &referenced_column_defs[j].unique),
+            Some(Some(ColumnUniqueOption { is_primary: true }))
+        );
+
+        let values = rows
+            .clone()
+            .filter_map(|values| values.get(i))
+            .filter(|value| !value.is_null());
+        let referenced_values = match (j, is_primary) {
+            (Some(j), false) => scan_column(storage, referenced_table_name, j).await?,
+            _ => Vec::new(),
+        };
+
+        for value in values {
+            let exists = match (j, is_primary) {
+                (None, _) => false,
+                (Some(_), true) => storage
+                    .fetch_data(referenced_table_name, &Key::try_from(value)?)
+                    .await?
+                    .is_some(),
+                (Some(_), false) => referenced_values
+                    .iter()
+                    .any(|referenced| referenced.evaluate_eq(value)),
+            };
+
+            if !exists {
+                return Err(ConstraintError::ForeignKeyViolation {
+                    constraint_name: name.to_owned(),
+                    value: value.clone(),
+                }
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Foreign keys of the tables referencing `table_name`, with the names of their tables
+pub async fn fetch_referencing_foreign_keys<T: GStore>(
+    storage: &T,
+    table_name: &str,
+) -> Result<Vec<(String, ForeignKey)>> {
+    let foreign_keys = storage
+        .fetch_all_schemas()
+        .await?
+        .into_iter()
+        .flat_map(|schema| {
+            let table_name = schema.table_name;
+
+            schema
+                .foreign_keys
+                .into_iter()
+                .map(move |foreign_key| (table_name.clone(), foreign_key))
+        })
+        .filter(|(_, foreign_key)| foreign_key.referenced_table_name == table_name)
+        .collect();
+
+    Ok(foreign_keys)
+}
+
+/// Validates that the updated rows do not change the referenced values which are still in use.
+/// Referenced values cannot be updated, as `ON UPDATE` actions are not supported.
+pub async fn validate_referenced_update<'a, T: GStore>(
+    storage: &T,
+    column_defs: &[ColumnDef],
+    referencing_foreign_keys: &[(String, ForeignKey)],
+    rows: impl Iterator<Item = (&'a [Value], &'a [Value])> + Clone,
+) -> Result<()> {
+    for (table_name, foreign_key) in referencing_foreign_keys {
+        let j = match column_index(column_defs, &foreign_key.referenced_column_name) {
+            Some(j) => j,
+            None => continue,
+        };
+        let changed = rows
+            .clone()
+            .filter_map(|(old_values, new_values)| old_values.get(j).zip(new_values.get(j)))
+            .filter(|(old, new)| !old.is_null() && !old.evaluate_eq(new))
+            .map(|(old, _)| old)
+            .collect::<Vec<_>>();
+
+        if changed.is_empty() {
+            continue;
+        }
+
+        let i = match fetch_column_index(storage, table_name, &foreign_key.column_name).await? {
+            Some(i) => i,
+            None => continue,
+        };
+        let referencing_values = scan_column(storage, table_name, i).await?;
+        let referenced = changed.into_iter().find(|old| {
+            referencing_values
+                .iter()
+                .any(|value| value.evaluate_eq(old))
+        });
+
+        if let Some(value) = referenced {
+            return Err(ConstraintError::ReferencedRowExists {
+                constraint_name: foreign_key.name.to_owned(),
+                value: value.clone(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies the `ON DELETE` actions of the foreign keys referencing the deleted rows.
+/// `CASCADE` deletes the referencing rows and applies the actions on them in turn,
+/// `SET NULL` nullifies the referencing column and `RESTRICT` or `NO ACTION` fails.
+pub async fn delete_references<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    rows: Vec<(Key, Vec<Value>)>,
+) -> Result<()> {
+    let schemas = storage.fetch_all_schemas().await?;
+    let mut deleted = rows
+        .iter()
+        .map(|(key, _)| (table_name.to_owned(), key.clone()))
+        .collect::<HashSet<_>>();
+    let mut updated = HashMap::<(String, Key), Vec<Value>>::new();
+    let mut cascaded = Vec::<(String, Key)>::new();
+
+    let rows = rows
+        .into_iter()
+        .map(|(_, values)| values)
+        .collect::<Vec<_>>();
+    let mut queue = VecDeque::from([(table_name.to_owned(), rows)]);
+
+    while let Some((table_name, rows)) = queue.pop_front() {
+        let column_defs = schemas
+            .iter()
+            .find(|schema| schema.table_name == table_name)
+            .and_then(|schema| schema.column_defs.as_deref())
+            .unwrap_or_default();
+
+        for schema in schemas.iter() {
+            let referencing_table_name = &schema.table_name;
+
+            for foreign_key in schema
+                .foreign_keys
+                .iter()
+                .filter(|foreign_key| foreign_key.referenced_table_name == table_name)
+            {
+                let j = match column_index(column_defs, &foreign_key.referenced_column_name) {
+                    Some(j) => j,
+                    None => continue,
+                };
+                let i = match schema
+                    .column_defs
+                    .as_deref()
+                    .and_then(|column_defs| column_index(column_defs, &foreign_key.column_name))
+                {
+                    Some(i) => i,
+                    None => continue,
+                };
+                let referenced_values = rows
+                    .iter()
+                    .filter_map(|values| values.get(j))
+                    .filter(|value| !value.is_null())
+                    .collect::<Vec<_>>();
+
+                if referenced_values.is_empty() {
+                    continue;
+                }
+
+                let mut cascaded_rows = Vec::new();
+                for (key, values) in scan_rows(storage, referencing_table_name).await? {
+                    let id = (referencing_table_name.to_owned(), key);
+                    if deleted.contains(&id) {
+                        continue;
+                    }
+
+                    let values = updated.get(&id).cloned().unwrap_or(values);
+                    let value = match values.get(i) {
+                        Some(value) if !value.is_null() => value,
+                        _ => continue,
+                    };
+
+                    if !referenced_values
+                        .iter()
+                        .any(|referenced| referenced.evaluate_eq(value))
+                    {
+                        continue;
+                    }
+
+                    match foreign_key.on_delete {
+                        ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                            return Err(ConstraintError::ReferencedRowExists {
+                                constraint_name: foreign_key.name.to_owned(),
+                                value: value.clone(),
+                            }
+                            .into());
+                        }
+                        ReferentialAction::Cascade => {
+                            deleted.insert(id.clone());
+                            cascaded.push(id);
+                            cascaded_rows.push(values);
+                        }
+                        ReferentialAction::SetNull => {
+                            let mut values = values;
+                            values[i] = Value::Null;
+                            updated.insert(id, values);
+                        }
+                    }
+                }
+
+                if !cascaded_rows.is_empty() {
+                    queue.push_back((referencing_table_name.to_owned(), cascaded_rows));
+                }
+            }
+        }
+    }
+
+    let mut updated_rows = HashMap::<String, Vec<(Key, DataRow)>>::new();
+    for ((table_name, key), values) in updated {
+        if !deleted.contains(&(table_name.clone(), key.clone())) {
+            updated_rows
+                .entry(table_name)
+                .or_default()
+                .push((key, DataRow::Vec(values)));
+        }
+    }
+    for (table_name, rows) in updated_rows {
+        storage.insert_data(&table_name, rows).await?;
+    }
+
+    let mut deleted_keys = HashMap::<String, Vec<Key>>::new();
+    for (table_name, key) in cascaded {
+        deleted_keys.entry(table_name).or_default().push(key);
+    }
+    for (table_name, keys) in deleted_keys {
+        storage.delete_data(&table_name, keys).await?;
+    }
+
+    Ok(())
+}
+
+fn column_index(column_defs: &[ColumnDef], column_name: &str) -> Option<usize> {
+    column_defs
+        .iter()
+        .position(|column_def| column_def.name == column_name)
+}
+
+async fn fetch_column_index<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_name: &str,
+) -> Result<Option<usize>> {
+    let column_defs = match storage.fetch_schema(table_name).await? {
+        Some(Schema { column_defs, .. }) => column_defs.unwrap_or_default(),
+        None => return Ok(None),
+    };
+
+    Ok(column_index(&column_defs, column_name))
+}
+
+async fn scan_rows<T: GStore>(storage: &T, table_name: &str) -> Result<Vec<(Key, Vec<Value>)>> {
+    let mut rows = Vec::new();
+    for result in storage.scan_data(table_name).await? {
+        let (key, data_row) = result?;
+
+        if let DataRow::Vec(values) = data_row {
+            rows.push((key, values));
+        }
+    }
+
+    Ok(rows)
+}
+
+async fn scan_column<T: GStore>(storage: &T, table_name: &str, i: usize) -> Result<Vec<Value>> {
+    let values = scan_rows(storage, table_name)
+        .await?
+        .into_iter()
+        .filter_map(|(_, values)| values.into_iter().nth(i))
+        .collect();
+
+    Ok(values)
+}
@@ -1,5 +1,8 @@
 use {
-    crate::data::{Row, Value},
+    crate::{
+        ast::Window,
+        data::{Row, Value},
+    },
     std::{borrow::Cow, collections::HashMap, fmt::Debug, rc::Rc},
 };
 
@@ -19,6 +22,10 @@ pub enum RowContext<'a> {
         left: Rc<RowContext<'a>>,
         right: Rc<RowContext<'a>>,
     },
+    Window {
+        values: HashMap<&'a Window, Value>,
+        next: Rc<RowContext<'a>>,
+    },
 }
 
 impl<'a> RowContext<'a> {
@@ -52,6 +59,7 @@ impl<'a> RowContext<'a> {
                 .position(|column| column == target)
                 .and_then(|index| values.get(index)),
             Self::RefMapData(values) => values.get(target),
+            Self::Window { next, .. } => next.get_value(target),
         }
     }
 
@@ -78,6 +86,7 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_value(target_table_alias, target)
                 .or_else(|| right.get_alias_value(target_table_alias, target)),
+            Self::Window { next, .. } => next.get_alias_value(target_table_alias, target),
             _ => None,
         }
     }
@@ -94,6 +103,7 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_entries(alias)
                 .or_else(|| right.get_alias_entries(alias)),
+            Self::Window { next, .. } => next.get_alias_entries(alias),
             _ => None,
         }
     }
@@ -115,7 +125,23 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => {
                 [left.get_all_entries(), right.get_all_entries()].concat()
             }
+            Self::Window { next, .. } => next.get_all_entries(),
             _ => vec![],
         }
     }
+
+    pub fn get_window_value(&self, window: &Window) -> Option<&Value> {
+        match self {
+            Self::Window { values, next } => {
+                values.get(window).or_else(|| next.get_window_value(window))
+            }
+            Self::Data {
+                next: Some(next), ..
+            } => next.get_window_value(window),
+            Self::Bridge { left, right } => left
+                .get_window_value(window)
+                .or_else(|| right.get_window_value(window)),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,286 @@
+use {
+    super::{
+        context::RowContext, fetch::fetch_relation_columns, select::select_with_labels, Session,
+    },
+    crate::{
+        ast::{ColumnDef, Cte, DataType, IndexOperator, Query, TableAlias, TableFactor},
+        data::{
+            CustomFunction as StructCustomFunction, EnumType as StructEnumType, Key, Row, Schema,
+            Sequence as StructSequence, Value,
+        },
+        result::Result,
+        store::{
+            CustomFunction, DataRow, EnumType, GStore, Index, MetaIter, Metadata, RowIter,
+            Sequence, Store,
+        },
+    },
+    async_trait::async_trait,
+    futures::stream::TryStreamExt,
+    std::rc::Rc,
+};
+
+/// Evaluates each common table expression of the query once, in the order they are defined,
+/// then runs the query against the storage where they are read as tables holding their rows.
+pub(crate) async fn fetch_cte_rows<'a, T: GStore>(
+    storage: &'a T,
+    session: Session<'a>,
+    query: &Query,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)> {
+    let Query {
+        with,
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+
+    let mut materialized = Vec::with_capacity(with.len());
+    for Cte { alias, query } in with {
+        let cte_storage = CteStorage::new(storage, with, &materialized);
+        let (labels, rows) = select_with_labels(
+            &cte_storage,
+            session,
+            query,
+            filter_context.as_ref().map(Rc::clone),
+        )
+        .await?;
+        let rows = rows.try_collect::<Vec<_>>().await?;
+
+        materialized.push((cte_columns(alias, labels.as_deref()), rows));
+    }
+
+    let query = Query {
+        with: Vec::new(),
+        body: body.clone(),
+        order_by: order_by.clone(),
+        limit: limit.clone(),
+        offset: offset.clone(),
+    };
+    let cte_storage = CteStorage::new(storage, with, &materialized);
+    let (labels, rows) = select_with_labels(&cte_storage, session, &query, filter_context).await?;
+    let rows = rows.try_collect().await?;
+
+    Ok((labels, rows))
+}
+
+/// Columns of a derived table whose subquery has common table expressions, which are read as
+/// empty tables with their columns while the labels are fetched.
+pub(crate) async fn fetch_derived_columns<T: GStore>(
+    storage: &T,
+    subquery: &Query,
+    alias: &TableAlias,
+) -> Result<Option<Vec<String>>> {
+    let derived = |query: &Query, alias: &TableAlias| TableFactor::Derived {
+        subquery: query.clone(),
+        alias: alias.clone(),
+        lateral: false,
+    };
+    let with = &subquery.with;
+
+    let mut materialized = Vec::with_capacity(with.len());
+    for Cte { alias, query } in with {
+        let cte_storage = CteStorage::new(storage, with, &materialized);
+        let columns = fetch_relation_columns(&cte_storage, &derived(query, alias)).await?;
+
+        materialized.push((columns, Vec::new()));
+    }
+
+    let query = Query {
+        with: Vec::new(),
+        ..subquery.clone()
+    };
+    let cte_storage = CteStorage::new(storage, with, &materialized);
+
+    fetch_relation_columns(&cte_storage, &derived(&query, alias)).await
+}
+
+/// Columns of a common table expression are named by its alias first, then by the labels of its
+/// query for the rest.
+pub(crate) fn cte_columns(alias: &TableAlias, labels: Option<&[String]>) -> Option<Vec<String>> {
+    labels.map(|labels| {
+        alias
+            .columns
+            .iter()
+            .chain(labels.iter().skip(alias.columns.len()))
+            .cloned()
+            .collect()
+    })
+}
+
+pub(crate) struct CteTable<'a> {
+    pub name: &'a str,
+    pub columns: Option<&'a [String]>,
+    pub rows: &'a [Row],
+}
+
+/// Storage where the common table expressions are read as tables, the latest one is found first
+/// when the names are the same.
+/// Every other request is passed through to the underlying storage.
+pub(crate) struct CteStorage<'a> {
+    storage: &'a dyn GStore,
+    tables: Vec<CteTable<'a>>,
+}
+
+impl<'a> CteStorage<'a> {
+    fn new(
+        storage: &'a dyn GStore,
+        with: &'a [Cte],
+        materialized: &'a [(Option<Vec<String>>, Vec<Row>)],
+    ) -> Self {
+        let tables = with
+            .iter()
+            .zip(materialized)
+            .map(|(Cte { alias, .. }, (columns, rows))| CteTable {
+                name: &alias.name,
+                columns: columns.as_deref(),
+                rows,
+            })
+            .collect();
+
+        Self { storage, tables }
+    }
+
+    pub(crate) fn with_table(storage: &'a dyn GStore, table: CteTable<'a>) -> Self {
+        Self {
+            storage,
+            tables: vec![table],
+        }
+    }
+
+    fn table(&self, name: &str) -> Option<&CteTable<'a>> {
+        self.tables.iter().rev().find(|table| table.name == name)
+    }
+}
+
+#[async_trait(?Send)]
+impl Store for CteStorage<'_> {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let table = match self.table(table_name) {
+            Some(table) => table,
+            None => return self.storage.fetch_schema(table_name).await,
+        };
+
+        // only the column names are used while the common table expression is selected
+        let column_defs = table.columns.map(|columns| {
+            columns
+                .iter()
+                .map(|name| ColumnDef {
+                    name: name.to_owned(),
+                    data_type: DataType::Text,
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                })
+                .collect()
+        });
+
+        Ok(Some(Schema {
+            table_name: table.name.to_owned(),
+            column_defs,
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
+            indexes: Vec::new(),
+            engine: None,
+            view: None,
+        }))
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.storage.fetch_all_schemas().await
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let table = match self.table(table_name) {
+            Some(table) => table,
+            None => return self.storage.fetch_data(table_name, key).await,
+        };
+
+        let row = match key {
+            Key::I64(n) => usize::try_from(*n).ok().and_then(|n| table.rows.get(n)),
+            _ => None,
+        };
+
+        Ok(row.cloned().map(DataRow::from))
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let table = match self.table(table_name) {
+            Some(table) => table,
+            None => return self.storage.scan_data(table_name).await,
+        };
+
+        let rows = table
+            .rows
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(n, row)| Ok((Key::I64(n as i64), DataRow::from(row))))
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(rows.into_iter()))
+    }
+}
+
+#[async_trait(?Send)]
+impl Index for CteStorage<'_> {
+    async fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<RowIter> {
+        self.storage
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl Metadata for CteStorage<'_> {
+    async fn scan_table_meta(&self) -> Result<MetaIter> {
+        self.storage.scan_table_meta().await
+    }
+}
+
+#[async_trait(?Send)]
+impl CustomFunction for CteStorage<'_> {
+    async fn fetch_function(&self, func_name: &str) -> Result<Option<&StructCustomFunction>> {
+        self.storage.fetch_function(func_name).await
+    }
+
+    async fn fetch_all_functions(&self) -> Result<Vec<&StructCustomFunction>> {
+        self.storage.fetch_all_functions().await
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for CteStorage<'_> {
+    async fn fetch_sequence(&self, name: &str) -> Result<Option<StructSequence>> {
+        self.storage.fetch_sequence(name).await
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        self.storage.fetch_all_sequences().await
+    }
+
+    async fn next_sequence_value(&self, name: &str) -> Result<i64> {
+        self.storage.next_sequence_value(name).await
+    }
+
+    async fn set_sequence_value(&self, name: &str, value: i64) -> Result<()> {
+        self.storage.set_sequence_value(name, value).await
+    }
+}
+
+#[async_trait(?Send)]
+impl EnumType for CteStorage<'_> {
+    async fn fetch_enum_type(&self, name: &str) -> Result<Option<StructEnumType>> {
+        self.storage.fetch_enum_type(name).await
+    }
+
+    async fn fetch_all_enum_types(&self) -> Result<Vec<StructEnumType>> {
+        self.storage.fetch_all_enum_types().await
+    }
+}
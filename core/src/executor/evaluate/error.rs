@@ -1,5 +1,5 @@
 use {
-    crate::ast::{Aggregate, Expr, ToSql},
+    crate::ast::{Aggregate, DataType, Expr, ToSql, Window},
     serde::{Serialize, Serializer},
     std::fmt::Debug,
     thiserror::Error,
@@ -26,6 +26,9 @@ pub enum EvaluateError {
     #[error("function requires usize value: {0}")]
     FunctionRequiresUSizeValue(String),
 
+    #[error("function requires non-zero integer value: {0}")]
+    FunctionRequiresNonZeroIntegerValue(String),
+
     #[error("function requires float value: {0}")]
     FunctionRequiresFloatValue(String),
 
@@ -38,6 +41,21 @@ pub enum EvaluateError {
     #[error("function requires point value: {0}")]
     FunctionRequiresPointValue(String),
 
+    #[error("function requires bytea value: {0}")]
+    FunctionRequiresByteaValue(String),
+
+    #[error("unsupported encoding format: {0}")]
+    UnsupportedEncodingFormat(String),
+
+    #[error("failed to decode {format} string: {value}")]
+    FailedToDecode { format: String, value: String },
+
+    #[error("function requires uuid or string value: {0}")]
+    FunctionRequiresUuidValue(String),
+
+    #[error("function requires 16 bytes binary value: {0}")]
+    FunctionRequiresBinaryUuidValue(String),
+
     #[error("value not found: {0}")]
     ValueNotFound(String),
 
@@ -50,6 +68,12 @@ pub enum EvaluateError {
     #[error("expr requires list value")]
     ListTypeRequired,
 
+    #[error("function requires comparable values: {0}")]
+    FunctionRequiresComparableValues(String),
+
+    #[error("function requires lists of the same length: {0}")]
+    FunctionRequiresListsOfSameLength(String),
+
     #[error("map or string value required for json map conversion: {0}")]
     MapOrStringValueRequired(String),
 
@@ -62,15 +86,41 @@ pub enum EvaluateError {
     #[error("context is required for identifier evaluation: {}", .0.to_sql())]
     ContextRequiredForIdentEvaluation(Expr),
 
+    #[error("DEFAULT is only allowed as a value of INSERT or UPDATE")]
+    DefaultNotAllowed,
+
+    #[error("ROLLUP, CUBE and GROUPING SETS are only allowed in GROUP BY")]
+    GroupByModifierNotAllowed,
+
+    #[error(
+        "number of parameters does not match placeholders, expected {expected} but found {found}"
+    )]
+    ParameterCountMismatch { expected: usize, found: usize },
+
+    #[error("parameter ${0} is not bound, parameters are bound by executing a prepared statement")]
+    UnboundParameter(usize),
+
+    #[error("GROUPING arguments must be GROUP BY expressions")]
+    GroupingArgNotInGroupBy,
+
     #[error("unreachable empty aggregate value: {0:?}")]
     UnreachableEmptyAggregateValue(Aggregate),
 
+    #[error("unreachable empty window value: {0:?}")]
+    UnreachableEmptyWindowValue(Window),
+
     #[error("the divisor should not be zero")]
     DivisorShouldNotBeZero,
 
     #[error("negative substring length not allowed")]
     NegativeSubstrLenNotAllowed,
 
+    #[error("pad string must not be empty: {0}")]
+    EmptyPadString(String),
+
+    #[error("function result is too long: {0}")]
+    FunctionResultTooLong(String),
+
     #[error("subquery returns more than one row")]
     MoreThanOneRowReturned,
 
@@ -95,6 +145,20 @@ pub enum EvaluateError {
     #[error("unsupported evaluate binary arithmetic between {0} and {1}")]
     UnsupportedBinaryArithmetic(String, String),
 
+    #[error("type mismatch: {left_type} {operation} {right_type} is not supported")]
+    TypeMismatch {
+        left_type: DataType,
+        right_type: DataType,
+        operation: String,
+    },
+
+    #[error("literal arithmetic is not supported: {left} {operation} {right}")]
+    LiteralArithmeticError {
+        left: String,
+        right: String,
+        operation: String,
+    },
+
     #[error("unsupported evaluate string unary plus: {0}")]
     UnsupportedUnaryPlus(String),
 
@@ -104,9 +168,15 @@ pub enum EvaluateError {
     #[error("unsupported evaluate string unary factorial: {0}")]
     UnsupportedUnaryFactorial(String),
 
+    #[error("unsupported evaluate string unary bitwise not: {0}")]
+    UnsupportedUnaryBitwiseNot(String),
+
     #[error("unsupported custom function in subqueries")]
     UnsupportedCustomFunction,
 
+    #[error("unsupported sequence function without storage: {0}")]
+    UnsupportedSequenceFunction(String),
+
     #[error("function args.length not matching: {name}, expected: {expected_minimum} ~ {expected_maximum}, found: {found}")]
     FunctionArgsLengthNotWithinRange {
         name: String,
@@ -2,7 +2,10 @@ use {
     super::error::EvaluateError,
     crate::{
         ast::{DataType, TrimWhereField},
-        data::{value::HashMapJsonExt, Key, Literal, Value},
+        data::{
+            value::HashMapJsonExt, Key, Literal, LiteralError, NumericBinaryOperator, TimeZone,
+            Value, ValueError,
+        },
         result::{Error, Result},
     },
     std::{borrow::Cow, cmp::Ordering, collections::HashMap, ops::Range},
@@ -99,6 +102,7 @@ impl TryFrom<Evaluated<'_>> for HashMap<String, Value> {
 fn binary_op<'a, 'b, T, U>(
     l: &Evaluated<'a>,
     r: &Evaluated<'b>,
+    operator: NumericBinaryOperator,
     value_op: T,
     literal_op: U,
 ) -> Result<Evaluated<'b>>
@@ -106,7 +110,7 @@ where
     T: FnOnce(&Value, &Value) -> Result<Value>,
     U: FnOnce(&Literal<'a>, &Literal<'b>) -> Result<Literal<'b>>,
 {
-    match (l, r) {
+    let evaluated = match (l, r) {
         (Evaluated::Literal(l), Evaluated::Literal(r)) => literal_op(l, r).map(Evaluated::Literal),
         (Evaluated::Literal(l), Evaluated::Value(r)) => {
             value_op(&Value::try_from(l)?, r).map(Evaluated::from)
@@ -120,7 +124,53 @@ where
             format!("{:?}", r),
         )
         .into()),
+    };
+
+    evaluated.map_err(|error| match error {
+        Error::Value(ValueError::NonNumericMathOperation { .. })
+        | Error::Literal(LiteralError::UnsupportedBinaryArithmetic(..))
+        | Error::Evaluate(EvaluateError::UnsupportedBinaryArithmetic(..)) => {
+            match (l.get_type(), r.get_type()) {
+                (Some(left_type), Some(right_type)) => EvaluateError::TypeMismatch {
+                    left_type,
+                    right_type,
+                    operation: operator.to_string(),
+                }
+                .into(),
+                _ => match (l, r) {
+                    (Evaluated::Literal(l), Evaluated::Literal(r)) => {
+                        EvaluateError::LiteralArithmeticError {
+                            left: format!("{:?}", l),
+                            right: format!("{:?}", r),
+                            operation: operator.to_string(),
+                        }
+                        .into()
+                    }
+                    _ => error,
+                },
+            }
+        }
+        _ => error,
+    })
+}
+
+/// Runs `AND`, `OR` or `XOR` on `Value::Bool` operands, reading a NULL operand as `Value::Null`.
+fn logical_op<'a, T>(l: Evaluated<'_>, r: Evaluated<'_>, value_op: T) -> Result<Evaluated<'a>>
+where
+    T: FnOnce(&Value, &Value) -> Result<Value>,
+{
+    let l = try_into_logical_operand(l)?;
+    let r = try_into_logical_operand(r)?;
+
+    value_op(&l, &r).map(Evaluated::from)
+}
+
+fn try_into_logical_operand(v: Evaluated<'_>) -> Result<Value> {
+    if v.is_null() {
+        return Ok(Value::Null);
     }
+
+    bool::try_from(v).map(Value::Bool)
 }
 
 pub fn exceptional_int_val_to_eval<'a>(name: String, v: Value) -> Result<Evaluated<'a>> {
@@ -131,12 +181,15 @@ pub fn exceptional_int_val_to_eval<'a>(name: String, v: Value) -> Result<Evaluat
 }
 
 impl<'a> Evaluated<'a> {
-    pub fn evaluate_eq(&self, other: &Evaluated<'a>) -> bool {
+    /// A `TIMESTAMP` or a text is read in `time_zone` against a `TIMESTAMPTZ`.
+    pub fn evaluate_eq(&self, other: &Evaluated<'a>, time_zone: TimeZone) -> bool {
         match (self, other) {
             (Evaluated::Literal(a), Evaluated::Literal(b)) => a.evaluate_eq(b),
             (Evaluated::Literal(b), Evaluated::Value(a))
-            | (Evaluated::Value(a), Evaluated::Literal(b)) => a.evaluate_eq_with_literal(b),
-            (Evaluated::Value(a), Evaluated::Value(b)) => a.evaluate_eq(b),
+            | (Evaluated::Value(a), Evaluated::Literal(b)) => {
+                a.evaluate_eq_with_literal(b, time_zone)
+            }
+            (Evaluated::Value(a), Evaluated::Value(b)) => a.evaluate_eq_in(b, time_zone),
             (Evaluated::Literal(a), Evaluated::StrSlice { source, range })
             | (Evaluated::StrSlice { source, range }, Evaluated::Literal(a)) => {
                 let b = &source[range.clone()];
@@ -147,7 +200,7 @@ impl<'a> Evaluated<'a> {
             | (Evaluated::StrSlice { source, range }, Evaluated::Value(a)) => {
                 let b = &source[range.clone()];
 
-                a.evaluate_eq_with_literal(&Literal::Text(Cow::Borrowed(b)))
+                a.evaluate_eq_with_literal(&Literal::Text(Cow::Borrowed(b)), time_zone)
             }
             (
                 Evaluated::StrSlice { source, range },
@@ -159,14 +212,17 @@ impl<'a> Evaluated<'a> {
         }
     }
 
-    pub fn evaluate_cmp(&self, other: &Evaluated<'a>) -> Option<Ordering> {
+    /// A `TIMESTAMP` or a text is read in `time_zone` against a `TIMESTAMPTZ`.
+    pub fn evaluate_cmp(&self, other: &Evaluated<'a>, time_zone: TimeZone) -> Option<Ordering> {
         match (self, other) {
             (Evaluated::Literal(l), Evaluated::Literal(r)) => l.evaluate_cmp(r),
-            (Evaluated::Literal(l), Evaluated::Value(r)) => {
-                r.evaluate_cmp_with_literal(l).map(|o| o.reverse())
+            (Evaluated::Literal(l), Evaluated::Value(r)) => r
+                .evaluate_cmp_with_literal(l, time_zone)
+                .map(|o| o.reverse()),
+            (Evaluated::Value(l), Evaluated::Literal(r)) => {
+                l.evaluate_cmp_with_literal(r, time_zone)
             }
-            (Evaluated::Value(l), Evaluated::Literal(r)) => l.evaluate_cmp_with_literal(r),
-            (Evaluated::Value(l), Evaluated::Value(r)) => l.evaluate_cmp(r),
+            (Evaluated::Value(l), Evaluated::Value(r)) => l.evaluate_cmp_in(r, time_zone),
             (Evaluated::Literal(l), Evaluated::StrSlice { source, range }) => {
                 let r = Literal::Text(Cow::Borrowed(&source[range.clone()]));
 
@@ -175,7 +231,7 @@ impl<'a> Evaluated<'a> {
             (Evaluated::Value(l), Evaluated::StrSlice { source, range }) => {
                 let r = Literal::Text(Cow::Borrowed(&source[range.clone()]));
 
-                l.evaluate_cmp_with_literal(&r)
+                l.evaluate_cmp_with_literal(&r, time_zone)
             }
             (Evaluated::StrSlice { source, range }, Evaluated::Literal(l)) => {
                 let r = Literal::Text(Cow::Borrowed(&source[range.clone()]));
@@ -185,7 +241,8 @@ impl<'a> Evaluated<'a> {
             (Evaluated::StrSlice { source, range }, Evaluated::Value(r)) => {
                 let l = Literal::Text(Cow::Borrowed(&source[range.clone()]));
 
-                r.evaluate_cmp_with_literal(&l).map(|o| o.reverse())
+                r.evaluate_cmp_with_literal(&l, time_zone)
+                    .map(|o| o.reverse())
             }
             (
                 Evaluated::StrSlice {
@@ -201,23 +258,65 @@ impl<'a> Evaluated<'a> {
     }
 
     pub fn add<'b>(&'a self, other: &Evaluated<'b>) -> Result<Evaluated<'b>> {
-        binary_op(self, other, |l, r| l.add(r), |l, r| l.add(r))
+        binary_op(
+            self,
+            other,
+            NumericBinaryOperator::Add,
+            |l, r| l.add(r),
+            |l, r| l.add(r),
+        )
     }
 
     pub fn subtract<'b>(&'a self, other: &Evaluated<'b>) -> Result<Evaluated<'b>> {
-        binary_op(self, other, |l, r| l.subtract(r), |l, r| l.subtract(r))
+        binary_op(
+            self,
+            other,
+            NumericBinaryOperator::Subtract,
+            |l, r| l.subtract(r),
+            |l, r| l.subtract(r),
+        )
     }
 
     pub fn multiply<'b>(&'a self, other: &Evaluated<'b>) -> Result<Evaluated<'b>> {
-        binary_op(self, other, |l, r| l.multiply(r), |l, r| l.multiply(r))
+        binary_op(
+            self,
+            other,
+            NumericBinaryOperator::Multiply,
+            |l, r| l.multiply(r),
+            |l, r| l.multiply(r),
+        )
     }
 
     pub fn divide<'b>(&'a self, other: &Evaluated<'b>) -> Result<Evaluated<'b>> {
-        binary_op(self, other, |l, r| l.divide(r), |l, r| l.divide(r))
+        binary_op(
+            self,
+            other,
+            NumericBinaryOperator::Divide,
+            |l, r| l.divide(r),
+            |l, r| l.divide(r),
+        )
     }
 
     pub fn modulo<'b>(&'a self, other: &Evaluated<'b>) -> Result<Evaluated<'b>> {
-        binary_op(self, other, |l, r| l.modulo(r), |l, r| l.modulo(r))
+        binary_op(
+            self,
+            other,
+            NumericBinaryOperator::Modulo,
+            |l, r| l.modulo(r),
+            |l, r| l.modulo(r),
+        )
+    }
+
+    pub fn and<'b>(self, other: Evaluated<'_>) -> Result<Evaluated<'b>> {
+        logical_op(self, other, Value::and)
+    }
+
+    pub fn or<'b>(self, other: Evaluated<'_>) -> Result<Evaluated<'b>> {
+        logical_op(self, other, Value::or)
+    }
+
+    pub fn xor<'b>(self, other: Evaluated<'_>) -> Result<Evaluated<'b>> {
+        logical_op(self, other, Value::xor)
     }
 
     pub fn unary_plus(&self) -> Result<Evaluated<'a>> {
@@ -252,12 +351,52 @@ impl<'a> Evaluated<'a> {
         .map(Evaluated::from)
     }
 
-    pub fn cast(self, data_type: &DataType) -> Result<Evaluated<'a>> {
+    pub fn unary_bitwise_not(&self) -> Result<Evaluated<'a>> {
+        match self {
+            Evaluated::Literal(v) => Value::try_from(v).and_then(|v| v.unary_bitwise_not()),
+            Evaluated::Value(v) => v.unary_bitwise_not(),
+            Evaluated::StrSlice { source, range } => Err(
+                EvaluateError::UnsupportedUnaryBitwiseNot(source[range.clone()].to_owned()).into(),
+            ),
+        }
+        .map(Evaluated::from)
+    }
+
+    /// Casts to `data_type`. Casts without a more specific error, such as `DATE` to `DECIMAL`,
+    /// fail with `CastFailed` which holds the value and the target type.
+    /// A `TIMESTAMP` is cast from or to a `TIMESTAMPTZ` in `time_zone`.
+    pub fn cast(self, data_type: &DataType, time_zone: TimeZone) -> Result<Evaluated<'a>> {
+        let cast_failed = |from: Value, error: Error| match error {
+            Error::Value(
+                ValueError::ImpossibleCast
+                | ValueError::UnimplementedCast
+                | ValueError::UnimplementedLiteralCast { .. },
+            ) => ValueError::CastFailed {
+                from,
+                to: data_type.clone(),
+            }
+            .into(),
+            error => error,
+        };
+
         match self {
-            Evaluated::Literal(literal) => Value::try_cast_from_literal(data_type, &literal),
-            Evaluated::Value(value) => value.cast(data_type),
+            Evaluated::Literal(literal) => {
+                Value::try_cast_from_literal(data_type, &literal, time_zone).map_err(|error| {
+                    match Value::try_from(&literal) {
+                        Ok(from) => cast_failed(from, error),
+                        Err(_) => error,
+                    }
+                })
+            }
+            Evaluated::Value(value) => value
+                .cast_in(data_type, time_zone)
+                .map_err(|error| cast_failed(value, error)),
             Evaluated::StrSlice { source, range } => {
-                Value::Str(source[range].to_owned()).cast(data_type)
+                let value = Value::Str(source[range].to_owned());
+
+                value
+                    .cast_in(data_type, time_zone)
+                    .map_err(|error| cast_failed(value, error))
             }
         }
         .map(Evaluated::from)
@@ -415,6 +554,15 @@ impl<'a> Evaluated<'a> {
         })
     }
 
+    /// Returns `None` for `NULL`, and for literals which cannot be converted into a value.
+    fn get_type(&self) -> Option<DataType> {
+        match self {
+            Evaluated::Literal(v) => Value::try_from(v).ok()?.get_type(),
+            Evaluated::StrSlice { .. } => Some(DataType::Text),
+            Evaluated::Value(v) => v.get_type(),
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         match self {
             Evaluated::Value(v) => v.is_null(),
@@ -744,15 +892,25 @@ impl<'a> Evaluated<'a> {
         }
     }
 
-    pub fn try_into_value(self, data_type: &DataType, nullable: bool) -> Result<Value> {
+    /// A text literal without an offset is read in `time_zone` for a `TIMESTAMPTZ`.
+    pub fn try_into_value(
+        self,
+        data_type: &DataType,
+        nullable: bool,
+        time_zone: TimeZone,
+    ) -> Result<Value> {
         let value = match self {
-            Evaluated::Literal(v) => Value::try_from_literal(data_type, &v)?,
+            Evaluated::Literal(v) => Value::try_from_literal(data_type, &v, time_zone)?,
             Evaluated::Value(v) => v,
             Evaluated::StrSlice {
                 source: s,
                 range: r,
             } => Value::Str(s[r].to_owned()),
         };
+        let value = match data_type {
+            DataType::Enum(_) => value.into_column_value(data_type)?,
+            _ => value,
+        };
 
         value.validate_null(nullable)?;
 
@@ -2,7 +2,7 @@ use {
     super::{EvaluateError, Evaluated},
     crate::{
         ast::{AstLiteral, BinaryOperator, DataType, UnaryOperator},
-        data::{Literal, Value},
+        data::{Literal, TimeZone, Value},
         result::Result,
     },
     std::{borrow::Cow, cmp::Ordering},
@@ -12,16 +12,21 @@ pub fn literal(ast_literal: &AstLiteral) -> Result<Evaluated<'_>> {
     Literal::try_from(ast_literal).map(Evaluated::Literal)
 }
 
-pub fn typed_string<'a>(data_type: &'a DataType, value: Cow<'a, str>) -> Result<Evaluated<'a>> {
+pub fn typed_string<'a>(
+    data_type: &'a DataType,
+    value: Cow<'a, str>,
+    time_zone: TimeZone,
+) -> Result<Evaluated<'a>> {
     let literal = Literal::Text(value);
 
-    Value::try_from_literal(data_type, &literal).map(Evaluated::from)
+    Value::try_from_literal(data_type, &literal, time_zone).map(Evaluated::from)
 }
 
 pub fn binary_op<'a>(
     op: &BinaryOperator,
     l: Evaluated<'a>,
     r: Evaluated<'a>,
+    time_zone: TimeZone,
 ) -> Result<Evaluated<'a>> {
     macro_rules! cmp {
         ($expr: expr) => {
@@ -29,13 +34,15 @@ pub fn binary_op<'a>(
         };
     }
 
-    macro_rules! cond {
-        (l $op: tt r) => {{
-            let l: bool = l.try_into()?;
-            let r: bool = r.try_into()?;
-            let v = l $op r;
+    macro_rules! regexp {
+        ($case_insensitive: expr, $negated: expr) => {{
+            let l = Value::try_from(l)?;
+            let r = Value::try_from(r)?;
 
-            Ok(Evaluated::from(Value::Bool(v)))
+            match l.try_regexp_match(&r, $case_insensitive)? {
+                Value::Bool(v) => Ok(Evaluated::from(Value::Bool($negated ^ v))),
+                v => Ok(Evaluated::from(v)),
+            }
         }};
     }
 
@@ -46,15 +53,35 @@ pub fn binary_op<'a>(
         BinaryOperator::Divide => l.divide(&r),
         BinaryOperator::Modulo => l.modulo(&r),
         BinaryOperator::StringConcat => l.concat(r),
-        BinaryOperator::Eq => cmp!(l.evaluate_eq(&r)),
-        BinaryOperator::NotEq => cmp!(!l.evaluate_eq(&r)),
-        BinaryOperator::Lt => cmp!(l.evaluate_cmp(&r) == Some(Ordering::Less)),
-        BinaryOperator::LtEq => cmp!(l.evaluate_cmp(&r) != Some(Ordering::Greater)),
-        BinaryOperator::Gt => cmp!(l.evaluate_cmp(&r) == Some(Ordering::Greater)),
-        BinaryOperator::GtEq => cmp!(l.evaluate_cmp(&r) != Some(Ordering::Less)),
-        BinaryOperator::And => cond!(l && r),
-        BinaryOperator::Or => cond!(l || r),
-        BinaryOperator::Xor => cond!(l ^ r),
+        BinaryOperator::Eq => cmp!(l.evaluate_eq(&r, time_zone)),
+        BinaryOperator::NotEq => cmp!(!l.evaluate_eq(&r, time_zone)),
+        BinaryOperator::Lt => cmp!(l.evaluate_cmp(&r, time_zone) == Some(Ordering::Less)),
+        BinaryOperator::LtEq => cmp!(l.evaluate_cmp(&r, time_zone) != Some(Ordering::Greater)),
+        BinaryOperator::Gt => cmp!(l.evaluate_cmp(&r, time_zone) == Some(Ordering::Greater)),
+        BinaryOperator::GtEq => cmp!(l.evaluate_cmp(&r, time_zone) != Some(Ordering::Less)),
+        BinaryOperator::And => l.and(r),
+        BinaryOperator::Or => l.or(r),
+        BinaryOperator::Xor => l.xor(r),
+        BinaryOperator::RegexMatch => regexp!(false, false),
+        BinaryOperator::RegexIMatch => regexp!(true, false),
+        BinaryOperator::RegexNotMatch => regexp!(false, true),
+        BinaryOperator::RegexNotIMatch => regexp!(true, true),
+    }
+}
+
+/// Returns the result of `AND` or `OR` when the left operand alone decides it, so the right
+/// operand does not need to be evaluated.
+pub fn short_circuit<'a>(op: &BinaryOperator, l: &Evaluated<'_>) -> Option<Evaluated<'a>> {
+    let v = match l {
+        Evaluated::Literal(Literal::Boolean(v)) | Evaluated::Value(Value::Bool(v)) => *v,
+        _ => return None,
+    };
+
+    match (op, v) {
+        (BinaryOperator::And, false) | (BinaryOperator::Or, true) => {
+            Some(Evaluated::from(Value::Bool(v)))
+        }
+        _ => None,
     }
 }
 
@@ -64,6 +91,7 @@ pub fn unary_op<'a>(op: &UnaryOperator, v: Evaluated<'a>) -> Result<Evaluated<'a
         UnaryOperator::Minus => v.unary_minus(),
         UnaryOperator::Not => v.try_into().map(|v: bool| Evaluated::from(Value::Bool(!v))),
         UnaryOperator::Factorial => v.unary_factorial(),
+        UnaryOperator::BitwiseNot => v.unary_bitwise_not(),
     }
 }
 
@@ -72,9 +100,10 @@ pub fn between<'a>(
     negated: bool,
     low: Evaluated<'a>,
     high: Evaluated<'a>,
+    time_zone: TimeZone,
 ) -> Result<Evaluated<'a>> {
-    let v = low.evaluate_cmp(&target) != Some(Ordering::Greater)
-        && target.evaluate_cmp(&high) != Some(Ordering::Greater);
+    let v = low.evaluate_cmp(&target, time_zone) != Some(Ordering::Greater)
+        && target.evaluate_cmp(&high, time_zone) != Some(Ordering::Greater);
     let v = negated ^ v;
 
     Ok(Evaluated::from(Value::Bool(v)))
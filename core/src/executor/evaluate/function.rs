@@ -2,19 +2,22 @@ use {
     super::{EvaluateError, Evaluated},
     crate::{
         ast::{DataType, DateTimeField},
-        data::{Point, Value, ValueError},
+        data::{value::parse_uuid, Point, TimeZone, Value, ValueError},
+        executor::Session,
         result::Result,
+        store::GStore,
     },
+    base64::{engine::general_purpose::STANDARD as BASE64, Engine},
     md5::{Digest, Md5},
     rand::{rngs::StdRng, Rng, SeedableRng},
-    std::ops::ControlFlow,
+    std::{cmp::Ordering, ops::ControlFlow},
     uuid::Uuid,
 };
 
 macro_rules! eval_to_str {
     ($name: expr, $evaluated: expr) => {
         match $evaluated.try_into()? {
-            Value::Str(value) => value,
+            Value::Str(value) | Value::Enum { value, .. } => value,
             Value::Null => {
                 return Ok(Evaluated::from(Value::Null));
             }
@@ -55,6 +58,32 @@ macro_rules! eval_to_float {
     };
 }
 
+macro_rules! eval_to_numeric {
+    ($name: expr, $evaluated: expr) => {
+        match $evaluated.try_into()? {
+            Value::Null => {
+                return Ok(Evaluated::from(Value::Null));
+            }
+            value @ (Value::I8(_)
+            | Value::I16(_)
+            | Value::I32(_)
+            | Value::I64(_)
+            | Value::I128(_)
+            | Value::U8(_)
+            | Value::U16(_)
+            | Value::U32(_)
+            | Value::U64(_)
+            | Value::U128(_)
+            | Value::F32(_)
+            | Value::F64(_)
+            | Value::Decimal(_)) => value,
+            _ => {
+                return Err(EvaluateError::FunctionRequiresFloatValue($name).into());
+            }
+        }
+    };
+}
+
 macro_rules! eval_to_point {
     ($name: expr, $evaluated: expr) => {
         match $evaluated.try_into()? {
@@ -200,11 +229,13 @@ pub fn lpad_or_rpad<'a>(
         None => " ".to_owned(),
     };
 
-    let result = if size > string.len() {
-        let padding_size = size - string.len();
-        let repeat_count = padding_size / fill.len();
-        let plus_count = padding_size % fill.len();
-        let fill = fill.repeat(repeat_count) + &fill[0..plus_count];
+    if fill.is_empty() {
+        return Err(EvaluateError::EmptyPadString(name).into());
+    }
+
+    let length = string.chars().count();
+    let result = if size > length {
+        let fill = fill.chars().cycle().take(size - length).collect::<String>();
 
         if name == "LPAD" {
             fill + &string
@@ -212,7 +243,7 @@ pub fn lpad_or_rpad<'a>(
             string + &fill
         }
     } else {
-        string[0..size].to_owned()
+        string.chars().take(size).collect()
     };
 
     Ok(Evaluated::from(Value::Str(result)))
@@ -226,7 +257,16 @@ pub fn reverse(name: String, expr: Evaluated<'_>) -> Result<Evaluated> {
 
 pub fn repeat<'a>(name: String, expr: Evaluated<'_>, num: Evaluated<'_>) -> Result<Evaluated<'a>> {
     let expr = eval_to_str!(name, expr);
-    let num = eval_to_int!(name, num) as usize;
+    let num = usize::try_from(eval_to_int!(name, num))
+        .map_err(|_| EvaluateError::FunctionRequiresUSizeValue(name.clone()))?;
+    if expr
+        .len()
+        .checked_mul(num)
+        .map_or(true, |len| len > isize::MAX as usize)
+    {
+        return Err(EvaluateError::FunctionResultTooLong(name).into());
+    }
+
     let value = expr.repeat(num);
 
     Ok(Evaluated::from(Value::Str(value)))
@@ -270,26 +310,71 @@ pub fn md5<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
     Ok(Evaluated::from(Value::Str(result)))
 }
 
+pub fn octet_length<'a>(name: String, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    let length = match expr.try_into()? {
+        Value::Bytea(bytes) => bytes.len(),
+        Value::Str(value) => value.len(),
+        Value::Null => return Ok(Evaluated::from(Value::Null)),
+        _ => return Err(EvaluateError::FunctionRequiresByteaValue(name).into()),
+    };
+
+    Ok(Evaluated::from(Value::I64(length as i64)))
+}
+
+pub fn encode<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    format: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let bytes = match expr.try_into()? {
+        Value::Bytea(bytes) => bytes,
+        Value::Null => return Ok(Evaluated::from(Value::Null)),
+        _ => return Err(EvaluateError::FunctionRequiresByteaValue(name).into()),
+    };
+    let format = eval_to_str!(name, format);
+
+    let encoded = match format.to_lowercase().as_str() {
+        "hex" => hex::encode(bytes),
+        "base64" => BASE64.encode(bytes),
+        _ => return Err(EvaluateError::UnsupportedEncodingFormat(format).into()),
+    };
+
+    Ok(Evaluated::from(Value::Str(encoded)))
+}
+
+pub fn decode<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    format: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let value = eval_to_str!(name.clone(), expr);
+    let format = eval_to_str!(name, format);
+
+    let decoded = match format.to_lowercase().as_str() {
+        "hex" => hex::decode(&value).ok(),
+        "base64" => BASE64.decode(&value).ok(),
+        _ => return Err(EvaluateError::UnsupportedEncodingFormat(format).into()),
+    };
+
+    decoded
+        .map(|bytes| Evaluated::from(Value::Bytea(bytes)))
+        .ok_or_else(|| EvaluateError::FailedToDecode { format, value }.into())
+}
+
 // --- float ---
 
 pub fn abs<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    match n.try_into()? {
-        Value::I8(v) => Ok(Evaluated::from(Value::I8(v.abs()))),
-        Value::I32(v) => Ok(Evaluated::from(Value::I32(v.abs()))),
-        Value::I64(v) => Ok(Evaluated::from(Value::I64(v.abs()))),
-        Value::I128(v) => Ok(Evaluated::from(Value::I128(v.abs()))),
-        Value::Decimal(v) => Ok(Evaluated::from(Value::Decimal(v.abs()))),
-        Value::F32(v) => Ok(Evaluated::from(Value::F32(v.abs()))),
-        Value::F64(v) => Ok(Evaluated::from(Value::F64(v.abs()))),
-        Value::Null => Ok(Evaluated::from(Value::Null)),
-        _ => Err(EvaluateError::FunctionRequiresFloatValue(name).into()),
-    }
+    eval_to_numeric!(name, n).try_abs().map(Evaluated::from)
 }
 
-pub fn ifnull<'a>(expr: Evaluated<'a>, then: Evaluated<'a>) -> Result<Evaluated<'a>> {
-    Ok(match expr.is_null() {
-        true => then,
-        false => expr,
+pub fn nullif<'a>(
+    expr1: Evaluated<'a>,
+    expr2: Evaluated<'a>,
+    time_zone: TimeZone,
+) -> Result<Evaluated<'a>> {
+    Ok(match expr1.evaluate_eq(&expr2, time_zone) {
+        true => Evaluated::from(Value::Null),
+        false => expr1,
     })
 }
 
@@ -302,18 +387,18 @@ pub fn sign(name: String, n: Evaluated<'_>) -> Result<Evaluated> {
 }
 
 pub fn sqrt<'a>(n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::sqrt(&n.try_into()?)?))
+    Value::try_sqrt(&n.try_into()?).map(Evaluated::from)
 }
 
 pub fn power<'a>(name: String, expr: Evaluated<'_>, power: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    let expr = eval_to_float!(name, expr);
-    let power = eval_to_float!(name, power);
+    let expr = eval_to_numeric!(name, expr);
+    let power = eval_to_numeric!(name, power);
 
-    Ok(Evaluated::from(Value::F64(expr.powf(power))))
+    expr.power(&power).map(Evaluated::from)
 }
 
 pub fn ceil<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).ceil())))
+    eval_to_numeric!(name, n).try_ceil().map(Evaluated::from)
 }
 
 pub fn rand<'a>(name: String, seed: Option<Evaluated<'_>>) -> Result<Evaluated<'a>> {
@@ -325,12 +410,23 @@ pub fn rand<'a>(name: String, seed: Option<Evaluated<'_>>) -> Result<Evaluated<'
     Ok(Evaluated::from(Value::F64(seed)))
 }
 
-pub fn round<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).round())))
+pub fn round<'a>(
+    name: String,
+    n: Evaluated<'_>,
+    scale: Option<Evaluated<'_>>,
+) -> Result<Evaluated<'a>> {
+    let n = eval_to_numeric!(name, n);
+    let scale = match scale {
+        Some(scale) => i32::try_from(eval_to_int!(name, scale))
+            .map_err(|_| EvaluateError::FunctionRequiresIntegerValue(name))?,
+        None => 0,
+    };
+
+    n.try_round(scale).map(Evaluated::from)
 }
 
 pub fn floor<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).floor())))
+    eval_to_numeric!(name, n).try_floor().map(Evaluated::from)
 }
 
 pub fn radians<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
@@ -346,26 +442,26 @@ pub fn degrees<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
 }
 
 pub fn exp<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).exp())))
+    eval_to_numeric!(name, n).try_exp().map(Evaluated::from)
 }
 
 pub fn log<'a>(name: String, antilog: Evaluated<'_>, base: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    let antilog = eval_to_float!(name, antilog);
-    let base = eval_to_float!(name, base);
+    let antilog = eval_to_numeric!(name, antilog);
+    let base = eval_to_numeric!(name, base);
 
-    Ok(Evaluated::from(Value::F64(antilog.log(base))))
+    antilog.try_log(&base).map(Evaluated::from)
 }
 
 pub fn ln<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).ln())))
+    eval_to_numeric!(name, n).try_ln().map(Evaluated::from)
 }
 
 pub fn log2<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).log2())))
+    eval_to_numeric!(name, n).try_log2().map(Evaluated::from)
 }
 
 pub fn log10<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
-    Ok(Evaluated::from(Value::F64(eval_to_float!(name, n).log10())))
+    eval_to_numeric!(name, n).try_log10().map(Evaluated::from)
 }
 
 pub fn sin<'a>(name: String, n: Evaluated<'_>) -> Result<Evaluated<'a>> {
@@ -488,6 +584,181 @@ pub fn prepend<'a>(expr: Evaluated<'_>, value: Evaluated<'_>) -> Result<Evaluate
     }
 }
 
+pub fn array_remove<'a>(expr: Evaluated<'_>, value: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    let expr: Value = expr.try_into()?;
+    let value: Value = value.try_into()?;
+
+    match expr {
+        Value::List(l) => {
+            let l = l.into_iter().filter(|v| !v.evaluate_eq(&value)).collect();
+            Ok(Evaluated::Value(Value::List(l)))
+        }
+        Value::Null => Ok(Evaluated::Value(Value::Null)),
+        _ => Err(EvaluateError::ListTypeRequired.into()),
+    }
+}
+
+pub fn array_remove_nulls<'a>(expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    match expr.try_into()? {
+        Value::List(l) => {
+            let l = l.into_iter().filter(|v| !v.is_null()).collect();
+            Ok(Evaluated::Value(Value::List(l)))
+        }
+        Value::Null => Ok(Evaluated::Value(Value::Null)),
+        _ => Err(EvaluateError::ListTypeRequired.into()),
+    }
+}
+
+pub fn array_length<'a>(expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
+    match expr.try_into()? {
+        Value::List(l) => Ok(Evaluated::Value(Value::I64(l.len() as i64))),
+        Value::Null => Ok(Evaluated::Value(Value::Null)),
+        _ => Err(EvaluateError::ListTypeRequired.into()),
+    }
+}
+
+pub fn array_replace<'a>(
+    expr: Evaluated<'_>,
+    from: Evaluated<'_>,
+    to: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let expr: Value = expr.try_into()?;
+    let from: Value = from.try_into()?;
+    let to: Value = to.try_into()?;
+
+    match expr {
+        Value::List(l) => {
+            let l = l
+                .into_iter()
+                .map(|v| if v.evaluate_eq(&from) { to.clone() } else { v })
+                .collect();
+            Ok(Evaluated::Value(Value::List(l)))
+        }
+        Value::Null => Ok(Evaluated::Value(Value::Null)),
+        _ => Err(EvaluateError::ListTypeRequired.into()),
+    }
+}
+
+pub fn greatest<'a>(name: String, exprs: Vec<Evaluated<'_>>) -> Result<Evaluated<'a>> {
+    extremum(name, exprs, Ordering::Greater)
+}
+
+pub fn least<'a>(name: String, exprs: Vec<Evaluated<'_>>) -> Result<Evaluated<'a>> {
+    extremum(name, exprs, Ordering::Less)
+}
+
+/// Scalar arguments ignore NULLs, while list arguments are compared element-wise and a NULL
+/// element in any of the lists makes the output element NULL.
+fn extremum<'a>(
+    name: String,
+    exprs: Vec<Evaluated<'_>>,
+    target: Ordering,
+) -> Result<Evaluated<'a>> {
+    let values = exprs
+        .into_iter()
+        .map(Value::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    if !values.iter().any(|value| matches!(value, Value::List(_))) {
+        return pick_extremum(&name, values, target).map(Evaluated::from);
+    }
+
+    let mut lists = Vec::with_capacity(values.len());
+    for value in values {
+        match value {
+            Value::List(list) => lists.push(list),
+            Value::Null => return Ok(Evaluated::from(Value::Null)),
+            _ => return Err(EvaluateError::ListTypeRequired.into()),
+        }
+    }
+
+    let len = lists[0].len();
+    if lists.iter().any(|list| list.len() != len) {
+        return Err(EvaluateError::FunctionRequiresListsOfSameLength(name).into());
+    }
+
+    let mut lists = lists.into_iter().map(Vec::into_iter).collect::<Vec<_>>();
+    (0..len)
+        .map(|_| {
+            let items = lists
+                .iter_mut()
+                .filter_map(Iterator::next)
+                .collect::<Vec<_>>();
+
+            if items.iter().any(Value::is_null) {
+                Ok(Value::Null)
+            } else {
+                pick_extremum(&name, items, target)
+            }
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(|list| Evaluated::from(Value::List(list)))
+}
+
+fn pick_extremum(name: &str, values: Vec<Value>, target: Ordering) -> Result<Value> {
+    let values = values
+        .into_iter()
+        .filter(|value| !value.is_null())
+        .collect::<Vec<_>>();
+
+    let picked = values.iter().try_fold(&Value::Null, |picked, value| {
+        if picked.is_null() {
+            return Ok(value);
+        }
+
+        match value.evaluate_cmp(picked) {
+            Some(ordering) if ordering == target => Ok(value),
+            Some(_) => Ok(picked),
+            None => Err(EvaluateError::FunctionRequiresComparableValues(
+                name.to_owned(),
+            )),
+        }
+    })?;
+
+    widen_numeric(picked.clone(), &values)
+}
+
+/// Casts a numeric pick to the widest numeric type among the arguments, the same way
+/// arithmetic turns an integer into a float and a float into a decimal.
+/// Integers of different sizes are left as they are, as they are in arithmetic.
+fn widen_numeric(picked: Value, values: &[Value]) -> Result<Value> {
+    fn rank(value: &Value) -> Option<u8> {
+        match value {
+            Value::I8(_)
+            | Value::I16(_)
+            | Value::I32(_)
+            | Value::I64(_)
+            | Value::I128(_)
+            | Value::U8(_)
+            | Value::U16(_)
+            | Value::U32(_)
+            | Value::U64(_)
+            | Value::U128(_) => Some(0),
+            Value::F32(_) => Some(1),
+            Value::F64(_) => Some(2),
+            Value::Decimal(_) => Some(3),
+            _ => None,
+        }
+    }
+
+    let picked_rank = match rank(&picked) {
+        Some(picked_rank) => picked_rank,
+        None => return Ok(picked),
+    };
+    let widest = values.iter().filter_map(rank).max().unwrap_or(picked_rank);
+    if widest <= picked_rank {
+        return Ok(picked);
+    }
+
+    let data_type = match widest {
+        1 => DataType::Float32,
+        2 => DataType::Float,
+        _ => DataType::Decimal,
+    };
+
+    picked.cast(&data_type)
+}
+
 // --- etc ---
 
 pub fn unwrap<'a>(
@@ -510,10 +781,76 @@ pub fn unwrap<'a>(
     Ok(Evaluated::from(value.selector(&selector)?))
 }
 
+pub fn json_extract<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    path: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let value = Value::try_from(expr)?;
+    let path = eval_to_str!(name, path);
+
+    value.try_json_extract(&path).map(Evaluated::from)
+}
+
 pub fn generate_uuid<'a>() -> Evaluated<'a> {
     Evaluated::from(Value::Uuid(Uuid::new_v4().as_u128()))
 }
 
+/// MySQL swap flag, moves the time-high part of the UUID to the front and the time-low part
+/// to the back so that time-based UUIDs are stored in the order they are generated.
+fn eval_uuid_swap_flag(name: &str, swap: Option<Evaluated<'_>>) -> Result<bool> {
+    match swap.map(Value::try_from).transpose()? {
+        None | Some(Value::Null) => Ok(false),
+        Some(Value::Bool(swap)) => Ok(swap),
+        Some(Value::I64(swap)) => Ok(swap != 0),
+        Some(_) => Err(EvaluateError::FunctionRequiresIntegerValue(name.to_owned()).into()),
+    }
+}
+
+pub fn uuid_to_bin<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    swap: Option<Evaluated<'_>>,
+) -> Result<Evaluated<'a>> {
+    let swap = eval_uuid_swap_flag(&name, swap)?;
+    let uuid = match expr.try_into()? {
+        Value::Uuid(uuid) => uuid,
+        Value::Str(uuid) => parse_uuid(&uuid)?,
+        Value::Null => return Ok(Evaluated::from(Value::Null)),
+        _ => return Err(EvaluateError::FunctionRequiresUuidValue(name).into()),
+    };
+
+    let bytes = uuid.to_be_bytes();
+    let bytes = match swap {
+        true => [&bytes[6..8], &bytes[4..6], &bytes[0..4], &bytes[8..]].concat(),
+        false => bytes.to_vec(),
+    };
+
+    Ok(Evaluated::from(Value::Bytea(bytes)))
+}
+
+pub fn bin_to_uuid<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    swap: Option<Evaluated<'_>>,
+) -> Result<Evaluated<'a>> {
+    let swap = eval_uuid_swap_flag(&name, swap)?;
+    let bytes = match expr.try_into()? {
+        Value::Bytea(bytes) if bytes.len() == 16 => bytes,
+        Value::Null => return Ok(Evaluated::from(Value::Null)),
+        _ => return Err(EvaluateError::FunctionRequiresBinaryUuidValue(name).into()),
+    };
+
+    let bytes = match swap {
+        true => [&bytes[4..8], &bytes[2..4], &bytes[0..2], &bytes[8..]].concat(),
+        false => bytes,
+    };
+    let uuid = Uuid::from_slice(&bytes)
+        .map_err(|_| EvaluateError::FunctionRequiresBinaryUuidValue(name))?;
+
+    Ok(Evaluated::from(Value::Str(uuid.to_string())))
+}
+
 pub fn format<'a>(
     name: String,
     expr: Evaluated<'_>,
@@ -636,14 +973,97 @@ pub fn find_idx<'a>(
     .map(Evaluated::from)
 }
 
-pub fn cast<'a>(expr: Evaluated<'a>, data_type: &DataType) -> Result<Evaluated<'a>> {
-    expr.cast(data_type)
+pub fn split_part<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    delimiter: Evaluated<'_>,
+    index: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let string = eval_to_str!(name, expr);
+    let delimiter = eval_to_str!(name, delimiter);
+    let index = eval_to_int!(name, index);
+
+    let parts = split_str(&string, Some(delimiter.as_str()));
+    let part = match index.cmp(&0) {
+        Ordering::Greater => parts.get(index as usize - 1),
+        Ordering::Less => parts
+            .len()
+            .checked_sub(index.unsigned_abs() as usize)
+            .and_then(|i| parts.get(i)),
+        Ordering::Equal => {
+            return Err(EvaluateError::FunctionRequiresNonZeroIntegerValue(name).into());
+        }
+    };
+
+    Ok(Evaluated::from(Value::Str(
+        part.map(ToString::to_string).unwrap_or_default(),
+    )))
+}
+
+pub fn string_to_array<'a>(
+    name: String,
+    expr: Evaluated<'_>,
+    delimiter: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let string = eval_to_str!(name, expr);
+    let delimiter = match delimiter.try_into()? {
+        Value::Str(delimiter) => Some(delimiter),
+        Value::Null => None,
+        _ => return Err(EvaluateError::FunctionRequiresStringValue(name).into()),
+    };
+
+    let list = split_str(&string, delimiter.as_deref())
+        .into_iter()
+        .map(|part| Value::Str(part.to_owned()))
+        .collect();
+
+    Ok(Evaluated::from(Value::List(list)))
+}
+
+/// Splits `string` on `delimiter`, or into single characters when the delimiter is empty or
+/// missing. An empty string has no parts at all.
+fn split_str<'a>(string: &'a str, delimiter: Option<&str>) -> Vec<&'a str> {
+    if string.is_empty() {
+        return Vec::new();
+    }
+
+    match delimiter {
+        Some(delimiter) if !delimiter.is_empty() => string.split(delimiter).collect(),
+        _ => string
+            .char_indices()
+            .map(|(i, c)| &string[i..i + c.len_utf8()])
+            .collect(),
+    }
+}
+
+pub fn cast<'a>(
+    expr: Evaluated<'a>,
+    data_type: &DataType,
+    time_zone: TimeZone,
+) -> Result<Evaluated<'a>> {
+    expr.cast(data_type, time_zone)
+}
+
+pub fn try_cast<'a>(
+    expr: Evaluated<'a>,
+    data_type: &DataType,
+    time_zone: TimeZone,
+) -> Result<Evaluated<'a>> {
+    Ok(expr
+        .cast(data_type, time_zone)
+        .unwrap_or_else(|_| Evaluated::from(Value::Null)))
 }
 
 pub fn extract<'a>(field: &DateTimeField, expr: Evaluated<'_>) -> Result<Evaluated<'a>> {
     Ok(Evaluated::from(Value::try_from(expr)?.extract(field)?))
 }
 
+pub fn at_time_zone<'a>(expr: Evaluated<'_>, time_zone: &str) -> Result<Evaluated<'a>> {
+    Ok(Evaluated::from(
+        Value::try_from(expr)?.at_time_zone(time_zone)?,
+    ))
+}
+
 pub fn point<'a>(x: Evaluated<'_>, y: Evaluated<'_>) -> Result<Evaluated<'a>> {
     let x = eval_to_float!("point".to_owned(), x);
     let y = eval_to_float!("point".to_owned(), y);
@@ -671,3 +1091,53 @@ pub fn calc_distance<'a>(x: Evaluated<'_>, y: Evaluated<'_>) -> Result<Evaluated
 
     Ok(Evaluated::from(Value::F64(Point::calc_distance(&x, &y))))
 }
+
+pub async fn nextval<'a, T: GStore>(
+    storage: Option<&T>,
+    session: Option<Session<'_>>,
+    name: String,
+    sequence_name: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let sequence_name = eval_to_str!(name, sequence_name);
+    let (storage, session) = storage
+        .zip(session)
+        .ok_or(EvaluateError::UnsupportedSequenceFunction(name))?;
+    let value = storage.next_sequence_value(&sequence_name).await?;
+    session.current_values.set(&sequence_name, value);
+
+    Ok(Evaluated::from(Value::I64(value)))
+}
+
+/// Returns the value last returned by `NEXTVAL` or set by `SETVAL` in the current session,
+/// regardless of the later changes made by the other sessions.
+pub fn currval<'a>(
+    session: Option<Session<'_>>,
+    name: String,
+    sequence_name: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let sequence_name = eval_to_str!(name, sequence_name);
+    let value = session
+        .ok_or(EvaluateError::UnsupportedSequenceFunction(name))?
+        .current_values
+        .get(&sequence_name)?;
+
+    Ok(Evaluated::from(Value::I64(value)))
+}
+
+pub async fn setval<'a, T: GStore>(
+    storage: Option<&T>,
+    session: Option<Session<'_>>,
+    name: String,
+    sequence_name: Evaluated<'_>,
+    value: Evaluated<'_>,
+) -> Result<Evaluated<'a>> {
+    let sequence_name = eval_to_str!(name, sequence_name);
+    let value = eval_to_int!(name, value);
+    let (storage, session) = storage
+        .zip(session)
+        .ok_or(EvaluateError::UnsupportedSequenceFunction(name))?;
+    storage.set_sequence_value(&sequence_name, value).await?;
+    session.current_values.set(&sequence_name, value);
+
+    Ok(Evaluated::from(Value::I64(value)))
+}
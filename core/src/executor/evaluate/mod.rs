@@ -4,10 +4,10 @@ mod expr;
 mod function;
 
 use {
-    super::{context::RowContext, select::select},
+    super::{alter::resolve_data_type, context::RowContext, select::select, Session},
     crate::{
         ast::{Aggregate, Expr, Function},
-        data::{CustomFunction, Interval, Literal, Row, Value},
+        data::{CustomFunction, Interval, Literal, Row, TimeZone, Value},
         mock::MockStorage,
         result::{Error, Result},
         store::GStore,
@@ -27,11 +27,12 @@ pub use {error::EvaluateError, evaluated::Evaluated};
 #[async_recursion(?Send)]
 pub async fn evaluate<'a, 'b: 'a, 'c: 'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     context: Option<Rc<RowContext<'b>>>,
     aggregated: Option<Rc<HashMap<&'c Aggregate, Value>>>,
     expr: &'a Expr,
 ) -> Result<Evaluated<'a>> {
-    evaluate_inner(Some(storage), context, aggregated, expr).await
+    evaluate_inner(Some(storage), Some(session), context, aggregated, expr).await
 }
 
 pub async fn evaluate_stateless<'a, 'b: 'a>(
@@ -41,12 +42,25 @@ pub async fn evaluate_stateless<'a, 'b: 'a>(
     let context = context.map(Rc::new);
     let storage: Option<&MockStorage> = None;
 
-    evaluate_inner(storage, context, None, expr).await
+    evaluate_inner(storage, None, context, None, expr).await
+}
+
+/// Evaluates `expr` without a storage, but within the given `session`
+pub(crate) async fn evaluate_stateless_in<'a, 'b: 'a>(
+    session: Session<'a>,
+    context: Option<RowContext<'b>>,
+    expr: &'a Expr,
+) -> Result<Evaluated<'a>> {
+    let context = context.map(Rc::new);
+    let storage: Option<&MockStorage> = None;
+
+    evaluate_inner(storage, Some(session), context, None, expr).await
 }
 
 #[async_recursion(?Send)]
 async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
     storage: Option<&'a T>,
+    session: Option<Session<'a>>,
     context: Option<Rc<RowContext<'b>>>,
     aggregated: Option<Rc<HashMap<&'c Aggregate, Value>>>,
     expr: &'a Expr,
@@ -55,13 +69,14 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
         let context = context.as_ref().map(Rc::clone);
         let aggregated = aggregated.as_ref().map(Rc::clone);
 
-        evaluate_inner(storage, context, aggregated, expr)
+        evaluate_inner(storage, session, context, aggregated, expr)
     };
+    let time_zone = session.map_or(TimeZone::UTC, |session| session.time_zone);
 
     match expr {
         Expr::Literal(ast_literal) => expr::literal(ast_literal),
         Expr::TypedString { data_type, value } => {
-            expr::typed_string(data_type, Cow::Borrowed(value))
+            expr::typed_string(data_type, Cow::Borrowed(value), time_zone)
         }
         Expr::Identifier(ident) => {
             let context = context
@@ -87,10 +102,11 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             .map(Evaluated::from)
         }
         Expr::Subquery(query) => {
-            let storage =
-                storage.ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
+            let (storage, session) = storage
+                .zip(session)
+                .ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
 
-            let evaluations = select(storage, query, context.as_ref().map(Rc::clone))
+            let evaluations = select(storage, session, query, context.as_ref().map(Rc::clone))
                 .await?
                 .map(|row| {
                     let value = match row? {
@@ -122,9 +138,13 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
         }
         Expr::BinaryOp { op, left, right } => {
             let left = eval(left).await?;
+            if let Some(evaluated) = expr::short_circuit(op, &left) {
+                return Ok(evaluated);
+            }
+
             let right = eval(right).await?;
 
-            expr::binary_op(op, left, right)
+            expr::binary_op(op, left, right, time_zone)
         }
         Expr::UnaryOp { op, expr } => {
             let v = eval(expr).await?;
@@ -138,11 +158,18 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             Some(value) => Ok(Evaluated::from(value.clone())),
             None => Err(EvaluateError::UnreachableEmptyAggregateValue(*aggr.clone()).into()),
         },
+        Expr::Window(window) => match context
+            .as_ref()
+            .and_then(|context| context.get_window_value(window))
+        {
+            Some(value) => Ok(Evaluated::from(value.clone())),
+            None => Err(EvaluateError::UnreachableEmptyWindowValue(*window.clone()).into()),
+        },
         Expr::Function(func) => {
             let context = context.as_ref().map(Rc::clone);
             let aggregated = aggregated.as_ref().map(Rc::clone);
 
-            evaluate_function(storage, context, aggregated, func).await
+            evaluate_function(storage, session, context, aggregated, func).await
         }
         Expr::InList {
             expr,
@@ -154,7 +181,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
 
             stream::iter(list)
                 .then(eval)
-                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target)))
+                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target, time_zone)))
                 .try_next()
                 .await
                 .map(|v| v.is_some() ^ negated)
@@ -166,11 +193,12 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             subquery,
             negated,
         } => {
-            let storage =
-                storage.ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
+            let (storage, session) = storage
+                .zip(session)
+                .ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
             let target = eval(target_expr).await?;
 
-            select(storage, subquery, context)
+            select(storage, session, subquery, context)
                 .await?
                 .map(|row| {
                     let value = match row? {
@@ -185,7 +213,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
 
                     Ok(Evaluated::from(value))
                 })
-                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target)))
+                .try_filter(|evaluated| ready(evaluated.evaluate_eq(&target, time_zone)))
                 .try_next()
                 .await
                 .map(|v| v.is_some() ^ negated)
@@ -202,7 +230,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let low = eval(low).await?;
             let high = eval(high).await?;
 
-            expr::between(target, *negated, low, high)
+            expr::between(target, *negated, low, high, time_zone)
         }
         Expr::Like {
             expr,
@@ -214,10 +242,10 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let evaluated = target.like(pattern, true)?;
 
             Ok(match negated {
-                true => Evaluated::from(Value::Bool(
-                    evaluated.evaluate_eq(&Evaluated::Literal(Literal::Boolean(false))),
+                true if !evaluated.is_null() => Evaluated::from(Value::Bool(
+                    evaluated.evaluate_eq(&Evaluated::Literal(Literal::Boolean(false)), time_zone),
                 )),
-                false => evaluated,
+                _ => evaluated,
             })
         }
         Expr::ILike {
@@ -230,17 +258,31 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let evaluated = target.like(pattern, false)?;
 
             Ok(match negated {
-                true => Evaluated::from(Value::Bool(
-                    evaluated.evaluate_eq(&Evaluated::Literal(Literal::Boolean(false))),
+                true if !evaluated.is_null() => Evaluated::from(Value::Bool(
+                    evaluated.evaluate_eq(&Evaluated::Literal(Literal::Boolean(false)), time_zone),
                 )),
-                false => evaluated,
+                _ => evaluated,
             })
         }
+        Expr::SimilarTo {
+            expr,
+            negated,
+            pattern,
+        } => {
+            let target = Value::try_from(eval(expr).await?)?;
+            let pattern = Value::try_from(eval(pattern).await?)?;
+
+            Ok(Evaluated::from(match target.try_similar_to(&pattern)? {
+                Value::Bool(v) => Value::Bool(*negated ^ v),
+                v => v,
+            }))
+        }
         Expr::Exists { subquery, negated } => {
-            let storage =
-                storage.ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
+            let (storage, session) = storage
+                .zip(session)
+                .ok_or_else(|| EvaluateError::UnsupportedStatelessExpr(expr.clone()))?;
 
-            select(storage, subquery, context)
+            select(storage, session, subquery, context)
                 .await?
                 .try_next()
                 .await
@@ -271,7 +313,7 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             for (when, then) in when_then.iter() {
                 let when = eval(when).await?;
 
-                if when.evaluate_eq(&operand) {
+                if when.evaluate_eq(&operand, time_zone) {
                     return eval(then).await;
                 }
             }
@@ -286,6 +328,13 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let indexes = try_join_all(indexes.iter().map(eval)).await?;
             expr::array_index(obj, indexes)
         }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => try_join_all(exprs.iter().map(eval))
+            .await?
+            .into_iter()
+            .map(Value::try_from)
+            .collect::<Result<_>>()
+            .map(Value::List)
+            .map(Evaluated::from),
         Expr::Interval {
             expr,
             leading_field,
@@ -300,11 +349,15 @@ async fn evaluate_inner<'a, 'b: 'a, 'c: 'a, T: GStore>(
                 .map(Value::Interval)
                 .map(Evaluated::from)
         }
+        Expr::Default => Err(EvaluateError::DefaultNotAllowed.into()),
+        Expr::Parameter(index) => Err(EvaluateError::UnboundParameter(*index).into()),
+        Expr::GroupByModifier(_) => Err(EvaluateError::GroupByModifierNotAllowed.into()),
     }
 }
 
 async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
     storage: Option<&'a T>,
+    session: Option<Session<'a>>,
     context: Option<Rc<RowContext<'b>>>,
     aggregated: Option<Rc<HashMap<&'c Aggregate, Value>>>,
     func: &'b Function,
@@ -315,8 +368,9 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
         let context = context.as_ref().map(Rc::clone);
         let aggregated = aggregated.as_ref().map(Rc::clone);
 
-        evaluate_inner(storage, context, aggregated, expr)
+        evaluate_inner(storage, session, context, aggregated, expr)
     };
+    let time_zone = session.map_or(TimeZone::UTC, |session| session.time_zone);
 
     let name = func.to_string();
 
@@ -360,7 +414,7 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
                 .then(|(arg, expr)| async {
                     eval(expr)
                         .await?
-                        .try_into_value(&arg.data_type, true)
+                        .try_into_value(&arg.data_type, true, time_zone)
                         .map(|value| (arg.name.to_owned(), value))
                 })
                 .try_collect()
@@ -371,14 +425,31 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
                     Some(Rc::new(context))
                 })?;
 
-            evaluate_inner(storage, context, None, body).await
+            evaluate_inner(storage, session, context, None, body).await
         }
         Function::ConcatWs { separator, exprs } => {
             let separator = eval(separator).await?;
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
             f::concat_ws(name, separator, exprs)
         }
-        Function::IfNull { expr, then } => f::ifnull(eval(expr).await?, eval(then).await?),
+        Function::IfNull { expr, then } => match eval(expr).await? {
+            evaluated if evaluated.is_null() => eval(then).await,
+            evaluated => Ok(evaluated),
+        },
+        Function::Coalesce(exprs) => {
+            for expr in exprs {
+                let evaluated = eval(expr).await?;
+
+                if !evaluated.is_null() {
+                    return Ok(evaluated);
+                }
+            }
+
+            Ok(Evaluated::from(Value::Null))
+        }
+        Function::NullIf { expr1, expr2 } => {
+            f::nullif(eval(expr1).await?, eval(expr2).await?, time_zone)
+        }
         Function::Lower(expr) => f::lower(name, eval(expr).await?),
         Function::Initcap(expr) => f::initcap(name, eval(expr).await?),
         Function::Upper(expr) => f::upper(name, eval(expr).await?),
@@ -452,6 +523,19 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
         Function::Ascii(expr) => f::ascii(name, eval(expr).await?),
         Function::Chr(expr) => f::chr(name, eval(expr).await?),
         Function::Md5(expr) => f::md5(name, eval(expr).await?),
+        Function::OctetLength(expr) => f::octet_length(name, eval(expr).await?),
+        Function::Encode { expr, format } => {
+            let expr = eval(expr).await?;
+            let format = eval(format).await?;
+
+            f::encode(name, expr, format)
+        }
+        Function::Decode { expr, format } => {
+            let expr = eval(expr).await?;
+            let format = eval(format).await?;
+
+            f::decode(name, expr, format)
+        }
 
         // --- float ---
         Function::Abs(expr) => f::abs(name, eval(expr).await?),
@@ -471,7 +555,14 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             };
             f::rand(name, expr)
         }
-        Function::Round(expr) => f::round(name, eval(expr).await?),
+        Function::Round { expr, scale } => {
+            let expr = eval(expr).await?;
+            let scale = match scale {
+                Some(v) => Some(eval(v).await?),
+                None => None,
+            };
+            f::round(name, expr, scale)
+        }
         Function::Floor(expr) => f::floor(name, eval(expr).await?),
         Function::Radians(expr) => f::radians(name, eval(expr).await?),
         Function::Degrees(expr) => f::degrees(name, eval(expr).await?),
@@ -545,7 +636,31 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
 
             f::unwrap(name, expr, selector)
         }
+        Function::JsonExtract { expr, path } => {
+            let expr = eval(expr).await?;
+            let path = eval(path).await?;
+
+            f::json_extract(name, expr, path)
+        }
         Function::GenerateUuid() => Ok(f::generate_uuid()),
+        Function::UuidToBin { expr, swap } => {
+            let expr = eval(expr).await?;
+            let swap = match swap {
+                Some(v) => Some(eval(v).await?),
+                None => None,
+            };
+
+            f::uuid_to_bin(name, expr, swap)
+        }
+        Function::BinToUuid { expr, swap } => {
+            let expr = eval(expr).await?;
+            let swap = match swap {
+                Some(v) => Some(eval(v).await?),
+                None => None,
+            };
+
+            f::bin_to_uuid(name, expr, swap)
+        }
         Function::Now() => Ok(Evaluated::from(Value::Timestamp(Utc::now().naive_utc()))),
         Function::Format { expr, format } => {
             let expr = eval(expr).await?;
@@ -589,14 +704,45 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             };
             f::find_idx(name, from_expr, sub_expr, start)
         }
+        Function::SplitPart {
+            expr,
+            delimiter,
+            index,
+        } => {
+            let expr = eval(expr).await?;
+            let delimiter = eval(delimiter).await?;
+            let index = eval(index).await?;
+            f::split_part(name, expr, delimiter, index)
+        }
+        Function::StringToArray { expr, delimiter } => {
+            let expr = eval(expr).await?;
+            let delimiter = eval(delimiter).await?;
+            f::string_to_array(name, expr, delimiter)
+        }
         Function::Cast { expr, data_type } => {
             let expr = eval(expr).await?;
-            f::cast(expr, data_type)
+            let data_type = match storage {
+                Some(storage) => resolve_data_type(storage, data_type).await?,
+                None => Cow::Borrowed(data_type),
+            };
+            f::cast(expr, &data_type, time_zone)
+        }
+        Function::TryCast { expr, data_type } => {
+            let expr = eval(expr).await?;
+            let data_type = match storage {
+                Some(storage) => resolve_data_type(storage, data_type).await?,
+                None => Cow::Borrowed(data_type),
+            };
+            f::try_cast(expr, &data_type, time_zone)
         }
         Function::Extract { field, expr } => {
             let expr = eval(expr).await?;
             f::extract(field, expr)
         }
+        Function::AtTimeZone { expr, time_zone } => {
+            let expr = eval(expr).await?;
+            f::at_time_zone(expr, time_zone)
+        }
 
         // --- list ---
         Function::Append { expr, value } => {
@@ -609,5 +755,38 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             let value = eval(value).await?;
             f::prepend(expr, value)
         }
+        Function::ArrayRemove { expr, value } => {
+            let expr = eval(expr).await?;
+            let value = eval(value).await?;
+            f::array_remove(expr, value)
+        }
+        Function::ArrayRemoveNulls(expr) => f::array_remove_nulls(eval(expr).await?),
+        Function::ArrayLength(expr) => f::array_length(eval(expr).await?),
+        Function::ArrayReplace { expr, from, to } => {
+            let expr = eval(expr).await?;
+            let from = eval(from).await?;
+            let to = eval(to).await?;
+            f::array_replace(expr, from, to)
+        }
+        Function::Greatest(exprs) => {
+            let exprs = stream::iter(exprs).then(eval).try_collect().await?;
+            f::greatest(name, exprs)
+        }
+        Function::Least(exprs) => {
+            let exprs = stream::iter(exprs).then(eval).try_collect().await?;
+            f::least(name, exprs)
+        }
+        Function::Grouping(_) => Err(EvaluateError::GroupingArgNotInGroupBy.into()),
+        Function::Nextval(expr) => f::nextval(storage, session, name, eval(expr).await?).await,
+        Function::Currval(expr) => f::currval(session, name, eval(expr).await?),
+        Function::Setval {
+            name: sequence_name,
+            value,
+        } => {
+            let sequence_name = eval(sequence_name).await?;
+            let value = eval(value).await?;
+
+            f::setval(storage, session, name, sequence_name, value).await
+        }
     }
 }
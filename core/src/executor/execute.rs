@@ -1,26 +1,35 @@
 use {
     super::{
         alter::{
-            alter_table, create_index, create_table, delete_function, drop_table, insert_function,
+            alter_table, alter_type, create_index, create_sequence, create_table, create_type,
+            create_view, delete_function, drop_sequence, drop_table, drop_type, drop_view,
+            insert_function, resolve_column_defs, CreateTableOptions,
+        },
+        analyze::analyze,
+        constraint::{
+            delete_references, fetch_referencing_foreign_keys, validate_check_constraints,
+            validate_foreign_keys, validate_referenced_update,
         },
         fetch::{fetch, fetch_columns},
         insert::insert,
-        select::{select, select_with_labels},
-        update::Update,
-        validate::{validate_unique, ColumnValidation},
+        profile::{Profiler, QueryProfile, Stopwatch},
+        select::{select, select_with_labels, select_with_profiler},
+        update::{Update, UpdateError},
+        validate::{validate_unique, validate_unique_indexes, ColumnValidation},
+        Session,
     },
     crate::{
         ast::{
             AstLiteral, BinaryOperator, DataType, Dictionary, Expr, Query, SelectItem, SetExpr,
-            Statement, TableAlias, TableFactor, TableWithJoins, Variable,
+            Statement, TableAlias, TableFactor, TableWithJoins, ToSql, Variable,
         },
-        data::{Key, Row, Schema, Value},
+        data::{CurrentValues, Key, Row, Schema, TimeZone, Value},
         result::Result,
         store::{GStore, GStoreMut},
     },
     futures::stream::{StreamExt, TryStreamExt},
     serde::{Deserialize, Serialize},
-    std::{collections::HashMap, env::var, fmt::Debug, rc::Rc},
+    std::{collections::HashMap, env::var, fmt::Debug, iter::once, rc::Rc},
     thiserror::Error as ThisError,
 };
 
@@ -28,6 +37,12 @@ use {
 pub enum ExecuteError {
     #[error("table not found: {0}")]
     TableNotFound(String),
+
+    #[error("only SELECT queries can be executed as an iterator")]
+    QueryRequiredForIterator,
+
+    #[error("cannot modify data or schema in a read-only transaction")]
+    ReadOnlyTransaction,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -44,10 +59,16 @@ pub enum Payload {
     Update(usize),
     DropTable,
     DropFunction,
+    DropSequence,
+    DropType,
+    DropView,
     AlterTable,
+    AlterType,
     CreateIndex,
     DropIndex,
+    Analyze,
     StartTransaction,
+    SetTransaction,
     Commit,
     Rollback,
     ShowVariable(PayloadVariable),
@@ -60,19 +81,195 @@ pub enum PayloadVariable {
     Version(String),
 }
 
+/// Row modified by an `INSERT`, `UPDATE` or `DELETE` statement.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RowChange {
+    Insert(Row),
+    /// Old row and new row
+    Update(Row, Row),
+    Delete(Row),
+}
+
+/// Session options which change how statements are executed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExecuteOption {
+    /// Whether a read-only transaction is in progress, in which case statements which modify
+    /// data or schema fail with `ExecuteError::ReadOnlyTransaction`
+    pub read_only: bool,
+}
+
+/// Executes the statement in a new session, whose `CURRVAL` values are dropped afterwards.
 pub async fn execute<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
 ) -> Result<Payload> {
+    let current_values = CurrentValues::default();
+    let session = Session {
+        current_values: &current_values,
+        time_zone: TimeZone::UTC,
+    };
+
+    execute_with_option(storage, session, statement, ExecuteOption::default()).await
+}
+
+pub async fn execute_with_option<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    statement: &Statement,
+    option: ExecuteOption,
+) -> Result<Payload> {
+    execute_with_profiler(storage, session, statement, option, None, None).await
+}
+
+/// Executes the statement and returns the time spent in each stage of the query.
+/// `parse_time`, `plan_time` and `total_time` of the returned profile are left empty.
+pub async fn execute_with_profile<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    statement: &Statement,
+    option: ExecuteOption,
+) -> Result<(Payload, QueryProfile)> {
+    let profiler = Rc::new(Profiler::default());
+    let started = Stopwatch::start();
+    let payload = execute_with_profiler(
+        storage,
+        session,
+        statement,
+        option,
+        Some(Rc::clone(&profiler)),
+        None,
+    )
+    .await?;
+    let profile = profiler.export(started.elapsed());
+
+    Ok((payload, profile))
+}
+
+/// Executes the statement and returns the rows inserted, updated or deleted by it.
+pub async fn execute_with_changes<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    statement: &Statement,
+    option: ExecuteOption,
+) -> Result<(Payload, Vec<RowChange>)> {
+    let mut changes = Vec::new();
+    let payload = execute_with_profiler(
+        storage,
+        session,
+        statement,
+        option,
+        None,
+        Some(&mut changes),
+    )
+    .await?;
+
+    Ok((payload, changes))
+}
+
+/// Returns `ExecuteError::ReadOnlyTransaction` if the statement modifies data or schema while
+/// a read-only transaction is in progress.
+pub fn assert_read_only(statement: &Statement, option: ExecuteOption) -> Result<()> {
+    let read_only_statement = matches!(
+        statement,
+        Statement::Query(_)
+            | Statement::ShowColumns { .. }
+            | Statement::ShowVariable(_)
+            | Statement::ShowIndexes(_)
+            | Statement::ShowCreateTable(_)
+            | Statement::Explain { .. }
+            | Statement::StartTransaction { .. }
+            | Statement::SetTransaction { .. }
+            | Statement::Commit
+            | Statement::Rollback
+    );
+
+    if option.read_only && !read_only_statement {
+        return Err(ExecuteError::ReadOnlyTransaction.into());
+    }
+
+    Ok(())
+}
+
+async fn execute_with_profiler<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    statement: &Statement,
+    option: ExecuteOption,
+    profiler: Option<Rc<Profiler>>,
+    changes: Option<&mut Vec<RowChange>>,
+) -> Result<Payload> {
+    assert_read_only(statement, option)?;
+
     if matches!(
         statement,
-        Statement::StartTransaction | Statement::Rollback | Statement::Commit
+        Statement::StartTransaction { .. }
+            | Statement::SetTransaction { .. }
+            | Statement::Rollback
+            | Statement::Commit
     ) {
-        return execute_inner(storage, statement).await;
+        return execute_inner(storage, session, statement, profiler, changes).await;
+    }
+
+    let autocommit = storage.begin(true).await?;
+    let result = execute_inner(storage, session, statement, profiler, changes).await;
+
+    if !autocommit {
+        return result;
+    }
+
+    match result {
+        Ok(payload) => storage.commit().await.map(|_| payload),
+        Err(error) => {
+            storage.rollback().await?;
+
+            Err(error)
+        }
     }
+}
+
+/// Executes the version checking `UPDATE` and, if it updates no row, the `conflict_check`
+/// `SELECT COUNT(*)` which counts the rows matched without the version check, in the same
+/// transaction. If the count is not zero, the versions are stale and
+/// `UpdateError::OptimisticLockConflict` is returned. Neither statement has changed a row by
+/// then, so the transaction is rolled back in autocommit mode and a transaction in progress is
+/// left open as it was.
+pub(crate) async fn execute_versioned_update<T: GStore + GStoreMut>(
+    storage: &mut T,
+    session: Session<'_>,
+    statement: &Statement,
+    conflict_check: Option<&Query>,
+    option: ExecuteOption,
+) -> Result<(Payload, Vec<RowChange>)> {
+    assert_read_only(statement, option)?;
 
     let autocommit = storage.begin(true).await?;
-    let result = execute_inner(storage, statement).await;
+    let result = async {
+        let mut changes = Vec::new();
+        let payload = execute_inner(storage, session, statement, None, Some(&mut changes)).await?;
+
+        match (payload, conflict_check, statement) {
+            (Payload::Update(0), Some(conflict_check), Statement::Update { table_name, .. }) => {
+                let count = select(storage, session, conflict_check, None)
+                    .await?
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .next()
+                    .map(Row::try_into_vec)
+                    .transpose()?
+                    .and_then(|values| values.into_iter().next());
+
+                match count {
+                    Some(Value::I64(count)) if count > 0 => {
+                        Err(UpdateError::OptimisticLockConflict(table_name.to_owned()).into())
+                    }
+                    _ => Ok((Payload::Update(0), changes)),
+                }
+            }
+            (payload, ..) => Ok((payload, changes)),
+        }
+    }
+    .await;
 
     if !autocommit {
         return result;
@@ -90,7 +287,10 @@ pub async fn execute<T: GStore + GStoreMut>(
 
 async fn execute_inner<T: GStore + GStoreMut>(
     storage: &mut T,
+    session: Session<'_>,
     statement: &Statement,
+    profiler: Option<Rc<Profiler>>,
+    changes: Option<&mut Vec<RowChange>>,
 ) -> Result<Payload> {
     match statement {
         //- Modification
@@ -98,23 +298,31 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::CreateTable {
             name,
             columns,
+            checks,
+            foreign_keys,
+            sequences,
             if_not_exists,
             source,
             engine,
-            ..
-        } => create_table(
-            storage,
-            name,
-            columns.as_ref().map(Vec::as_slice),
-            *if_not_exists,
-            source,
-            engine,
-        )
-        .await
-        .map(|_| Payload::Create),
+        } => {
+            let options = CreateTableOptions {
+                target_table_name: name,
+                column_defs: columns.as_deref(),
+                checks,
+                foreign_keys,
+                sequences,
+                if_not_exists: *if_not_exists,
+                source,
+                engine,
+            };
+
+            create_table(storage, session, options)
+                .await
+                .map(|_| Payload::Create)
+        }
         Statement::DropTable {
             names, if_exists, ..
-        } => drop_table(storage, names, *if_exists)
+        } => drop_table(storage, session, names, *if_exists)
             .await
             .map(|_| Payload::DropTable),
         Statement::AlterTable { name, operation } => alter_table(storage, name, operation)
@@ -124,18 +332,38 @@ async fn execute_inner<T: GStore + GStoreMut>(
             name,
             table_name,
             column,
-        } => create_index(storage, table_name, name, column)
+            unique,
+        } => create_index(storage, table_name, name, column, *unique)
             .await
             .map(|_| Payload::CreateIndex),
         Statement::DropIndex { name, table_name } => storage
             .drop_index(table_name, name)
             .await
             .map(|_| Payload::DropIndex),
+        Statement::Analyze { table_name } => {
+            analyze(storage, table_name).await.map(|_| Payload::Analyze)
+        }
+        Statement::Explain { verbose, statement } => {
+            let plan = match verbose {
+                true => format!("{statement:#?}"),
+                false => statement.to_sql(),
+            };
+            let rows = plan
+                .lines()
+                .map(|line| vec![Value::Str(line.to_owned())])
+                .collect();
+
+            Ok(Payload::Select {
+                labels: vec!["QUERY PLAN".to_owned()],
+                rows,
+            })
+        }
         //- Transaction
-        Statement::StartTransaction => storage
+        Statement::StartTransaction { .. } => storage
             .begin(false)
             .await
             .map(|_| Payload::StartTransaction),
+        Statement::SetTransaction { .. } => Ok(Payload::SetTransaction),
         Statement::Commit => storage.commit().await.map(|_| Payload::Commit),
         Statement::Rollback => storage.rollback().await.map(|_| Payload::Rollback),
         //-- Rows
@@ -143,18 +371,38 @@ async fn execute_inner<T: GStore + GStoreMut>(
             table_name,
             columns,
             source,
-        } => insert(storage, table_name, columns, source)
-            .await
-            .map(Payload::Insert),
+            on_conflict,
+        } => insert(
+            storage,
+            session,
+            table_name,
+            columns,
+            source,
+            on_conflict,
+            changes,
+        )
+        .await
+        .map(Payload::Insert),
         Statement::Update {
             table_name,
+            alias,
             selection,
             assignments,
         } => {
-            let Schema { column_defs, .. } = storage
+            let Schema {
+                column_defs,
+                checks,
+                foreign_keys,
+                indexes,
+                ..
+            } = storage
                 .fetch_schema(table_name)
                 .await?
                 .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
+            let column_defs = match column_defs {
+                Some(column_defs) => Some(resolve_column_defs(storage, column_defs).await?),
+                None => None,
+            };
 
             let all_columns = column_defs.as_deref().map(|columns| {
                 columns
@@ -167,38 +415,108 @@ async fn execute_inner<T: GStore + GStoreMut>(
                 .map(|assignment| assignment.id.to_owned())
                 .collect();
 
-            let update = Update::new(storage, table_name, assignments, column_defs.as_deref())?;
+            let table_alias = alias.as_deref().unwrap_or(table_name);
+            let update = Update::new(
+                storage,
+                session,
+                table_alias,
+                assignments,
+                column_defs.as_deref(),
+            )?;
+            let referencing_foreign_keys =
+                fetch_referencing_foreign_keys(storage, table_name).await?;
+            let keep_old_rows = changes.is_some() || !referencing_foreign_keys.is_empty();
 
-            let rows = fetch(storage, table_name, all_columns, selection.as_ref())
-                .await?
-                .and_then(|item| {
-                    let update = &update;
-                    let (key, row) = item;
+            let rows = fetch(
+                storage,
+                session,
+                table_name,
+                table_alias,
+                all_columns,
+                selection.as_ref(),
+            )
+            .await?
+            .and_then(|item| {
+                let update = &update;
+                let (key, row) = item;
 
-                    async move {
-                        let row = update.apply(row).await?;
+                async move {
+                    let old_row = keep_old_rows.then(|| row.clone());
+                    let row = update.apply(row).await?;
 
-                        Ok((key, row))
-                    }
-                })
-                .try_collect::<Vec<(Key, Row)>>()
-                .await?;
+                    Ok((key, old_row, row))
+                }
+            })
+            .try_collect::<Vec<(Key, Option<Row>, Row)>>()
+            .await?;
 
             if let Some(column_defs) = column_defs {
                 let column_validation =
                     ColumnValidation::SpecifiedColumns(&column_defs, columns_to_update);
-                let rows = rows.iter().filter_map(|(_, row)| match row {
-                    Row::Vec { values, .. } => Some(values.as_slice()),
+                let updated_rows = rows.iter().filter_map(|(key, _, row)| match row {
+                    Row::Vec { values, .. } => Some((key, values.as_slice())),
                     Row::Map(_) => None,
                 });
 
-                validate_unique(storage, table_name, column_validation, rows).await?;
+                validate_check_constraints(
+                    storage,
+                    session,
+                    &column_defs,
+                    &checks,
+                    updated_rows.clone().map(|(_, values)| values),
+                )
+                .await?;
+                validate_foreign_keys(
+                    storage,
+                    &column_defs,
+                    &foreign_keys,
+                    updated_rows.clone().map(|(_, values)| values),
+                )
+                .await?;
+                validate_referenced_update(
+                    storage,
+                    &column_defs,
+                    &referencing_foreign_keys,
+                    rows.iter()
+                        .filter_map(|(_, old_row, row)| match (old_row, row) {
+                            (
+                                Some(Row::Vec {
+                                    values: old_values, ..
+                                }),
+                                Row::Vec { values, .. },
+                            ) => Some((old_values.as_slice(), values.as_slice())),
+                            _ => None,
+                        }),
+                )
+                .await?;
+                validate_unique(
+                    storage,
+                    table_name,
+                    column_validation,
+                    updated_rows.clone().map(|(_, values)| values),
+                )
+                .await?;
+                validate_unique_indexes(
+                    storage,
+                    table_name,
+                    &column_defs,
+                    &indexes,
+                    updated_rows.map(|(key, values)| (Some(key), values)),
+                )
+                .await?;
             }
 
             let num_rows = rows.len();
+            let mut changes = changes;
             let rows = rows
                 .into_iter()
-                .map(|(key, row)| (key, row.into()))
+                .map(|(key, old_row, row)| {
+                    if let (Some(changes), Some(old_row)) = (changes.as_mut(), old_row) {
+                        changes.push(RowChange::Update(old_row, row.clone()));
+                    }
+
+                    (key, row.into())
+                })
                 .collect();
 
             storage
@@ -208,14 +526,46 @@ async fn execute_inner<T: GStore + GStoreMut>(
         }
         Statement::Delete {
             table_name,
+            alias,
             selection,
         } => {
+            let table_alias = alias.as_deref().unwrap_or(table_name);
             let columns = fetch_columns(storage, table_name).await?.map(Rc::from);
-            let keys = fetch(storage, table_name, columns, selection.as_ref())
+            let referenced = !fetch_referencing_foreign_keys(storage, table_name)
                 .await?
-                .map_ok(|(key, _)| key)
-                .try_collect::<Vec<_>>()
-                .await?;
+                .is_empty();
+            let keep_rows = changes.is_some() || referenced;
+            let (keys, rows): (Vec<_>, Vec<_>) = fetch(
+                storage,
+                session,
+                table_name,
+                table_alias,
+                columns,
+                selection.as_ref(),
+            )
+            .await?
+            .map_ok(|(key, row)| (key, keep_rows.then_some(row)))
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .unzip();
+
+            if referenced {
+                let deleted_rows = keys
+                    .iter()
+                    .zip(&rows)
+                    .filter_map(|(key, row)| match row {
+                        Some(Row::Vec { values, .. }) => Some((key.clone(), values.clone())),
+                        _ => None,
+                    })
+                    .collect();
+
+                delete_references(storage, table_name, deleted_rows).await?;
+            }
+
+            if let Some(changes) = changes {
+                changes.extend(rows.into_iter().flatten().map(RowChange::Delete));
+            }
 
             let num_keys = keys.len();
 
@@ -226,8 +576,15 @@ async fn execute_inner<T: GStore + GStoreMut>(
         }
 
         //- Selection
-        Statement::Query(query) => {
-            let (labels, rows) = select_with_labels(storage, query, None).await?;
+        Statement::Query(query) | Statement::SelectForUpdate(query) => {
+            if matches!(statement, Statement::SelectForUpdate(_)) {
+                for table_name in locked_table_names(query) {
+                    storage.lock_for_update(table_name).await?;
+                }
+            }
+
+            let (labels, rows) =
+                select_with_profiler(storage, session, query, None, profiler).await?;
 
             match labels {
                 Some(labels) => rows
@@ -258,6 +615,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
         }
         Statement::ShowIndexes(table_name) => {
             let query = Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(crate::ast::Select {
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
@@ -285,7 +643,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                 offset: None,
             };
 
-            let (labels, rows) = select_with_labels(storage, &query, None).await?;
+            let (labels, rows) = select_with_labels(storage, session, &query, None).await?;
             let labels = labels.unwrap_or_default();
             let rows = rows
                 .map(|row| row?.try_into_vec())
@@ -298,9 +656,21 @@ async fn execute_inner<T: GStore + GStoreMut>(
 
             Ok(Payload::Select { labels, rows })
         }
+        Statement::ShowCreateTable(table_name) => {
+            let schema = storage
+                .fetch_schema(table_name)
+                .await?
+                .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()))?;
+
+            Ok(Payload::Select {
+                labels: vec!["Create Table".to_owned()],
+                rows: vec![vec![Value::Str(schema.to_ddl())]],
+            })
+        }
         Statement::ShowVariable(variable) => match variable {
             Variable::Tables => {
                 let query = Query {
+                    with: Vec::new(),
                     body: SetExpr::Select(Box::new(crate::ast::Select {
                         projection: vec![SelectItem::Expr {
                             expr: Expr::Identifier("TABLE_NAME".to_owned()),
@@ -325,7 +695,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                     offset: None,
                 };
 
-                let table_names = select(storage, &query, None)
+                let table_names = select(storage, session, &query, None)
                     .await?
                     .map(|row| row?.try_into_vec())
                     .try_collect::<Vec<Vec<Value>>>()
@@ -367,5 +737,57 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::DropFunction { if_exists, names } => delete_function(storage, names, *if_exists)
             .await
             .map(|_| Payload::DropFunction),
+        Statement::CreateSequence {
+            if_not_exists,
+            name,
+            options,
+        } => create_sequence(storage, session, name, options, *if_not_exists)
+            .await
+            .map(|_| Payload::Create),
+        Statement::DropSequence { if_exists, names } => {
+            drop_sequence(storage, session, names, *if_exists)
+                .await
+                .map(|_| Payload::DropSequence)
+        }
+        Statement::CreateType { name, variants } => create_type(storage, name, variants)
+            .await
+            .map(|_| Payload::Create),
+        Statement::AlterType { name, operation } => alter_type(storage, name, operation)
+            .await
+            .map(|_| Payload::AlterType),
+        Statement::DropType { if_exists, names } => drop_type(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropType),
+        Statement::CreateView {
+            or_replace,
+            name,
+            columns,
+            query,
+        } => create_view(storage, name, columns, query, *or_replace)
+            .await
+            .map(|_| Payload::Create),
+        Statement::DropView { if_exists, names } => drop_view(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropView),
     }
 }
+
+/// Returns the names of the tables which `SELECT ... FOR UPDATE` reads rows from, except its
+/// common table expressions.
+fn locked_table_names(query: &Query) -> Vec<&str> {
+    let TableWithJoins { relation, joins } = match &query.body {
+        SetExpr::Select(select) => &select.from,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+            return Vec::new()
+        }
+    };
+
+    once(relation)
+        .chain(joins.iter().map(|join| &join.relation))
+        .filter_map(|table_factor| match table_factor {
+            TableFactor::Table { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .filter(|name| !query.with.iter().any(|cte| cte.alias.name == *name))
+        .collect()
+}
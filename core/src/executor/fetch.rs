@@ -1,5 +1,8 @@
 use {
-    super::{context::RowContext, evaluate::evaluate_stateless, filter::check_expr},
+    super::{
+        context::RowContext, cte::fetch_derived_columns, evaluate::evaluate_stateless,
+        filter::check_expr, Session,
+    },
     crate::{
         ast::{
             ToSql,
@@ -9,8 +12,11 @@ use {
                 Values,
             },
         },
-        data::{get_alias, get_index, Key, Row, Value},
-        executor::{evaluate::evaluate, select::select},
+        data::{get_alias, get_index, unnest_column, Key, Row, TableError, Value},
+        executor::{
+            evaluate::{evaluate, EvaluateError},
+            select::select,
+        },
         result::Result,
         store::{DataRow, GStore},
     },
@@ -40,7 +46,9 @@ pub enum FetchError {
 
 pub async fn fetch<'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     table_name: &'a str,
+    table_alias: &'a str,
     columns: Option<Rc<[String]>>,
     where_clause: Option<&'a Expr>,
 ) -> Result<impl Stream<Item = Result<(Key, Row)>> + 'a> {
@@ -66,9 +74,9 @@ pub async fn fetch<'a, T: GStore>(
                     Some(expr) => expr,
                 };
 
-                let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
+                let context = RowContext::new(table_alias, Cow::Borrowed(&row), None);
 
-                check_expr(storage, Some(Rc::new(context)), None, expr)
+                check_expr(storage, session, Some(Rc::new(context)), None, expr)
                     .await
                     .map(|pass| pass.then_some((key, row)))
             }
@@ -78,15 +86,17 @@ pub async fn fetch<'a, T: GStore>(
 }
 
 #[derive(futures_enum::Stream)]
-pub enum Rows<I1, I2, I3, I4> {
+pub enum Rows<I1, I2, I3, I4, I5> {
     Derived(I1),
     Table(I2),
     Series(I3),
-    Dictionary(I4),
+    Unnest(I4),
+    Dictionary(I5),
 }
 
 pub async fn fetch_relation_rows<'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     table_factor: &'a TableFactor,
     filter_context: &Option<Rc<RowContext<'a>>>,
 ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
@@ -99,16 +109,15 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
     match table_factor {
         TableFactor::Derived { subquery, .. } => {
             let filter_context = filter_context.as_ref().map(Rc::clone);
-            let rows =
-                select(storage, subquery, filter_context)
-                    .await?
-                    .map_ok(move |row| match row {
-                        Row::Vec { values, .. } => Row::Vec {
-                            columns: Rc::clone(&columns),
-                            values,
-                        },
-                        Row::Map(values) => Row::Map(values),
-                    });
+            let rows = select(storage, session, subquery, filter_context)
+                .await?
+                .map_ok(move |row| match row {
+                    Row::Vec { values, .. } => Row::Vec {
+                        columns: Rc::clone(&columns),
+                        values,
+                    },
+                    Row::Map(values) => Row::Map(values),
+                });
 
             Ok(Rows::Derived(rows))
         }
@@ -129,7 +138,8 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                     }) => {
                         let cmp_value = match cmp_expr {
                             Some((op, expr)) => {
-                                let evaluated = evaluate(storage, None, None, expr).await?;
+                                let evaluated =
+                                    evaluate(storage, session, None, None, expr).await?;
 
                                 Some((op, evaluated.try_into()?))
                             }
@@ -151,7 +161,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                     }
                     Some(IndexItem::PrimaryKey(expr)) => {
                         let filter_context = filter_context.as_ref().map(Rc::clone);
-                        let key = evaluate(storage, filter_context, None, expr)
+                        let key = evaluate(storage, session, filter_context, None, expr)
                             .await
                             .and_then(Value::try_from)
                             .and_then(Key::try_from)?;
@@ -207,6 +217,26 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
 
             Ok(Rows::Series(stream::iter(rows)))
         }
+        TableFactor::Unnest { expr, .. } => {
+            let filter_context = filter_context.as_ref().map(Rc::clone);
+            let value: Value = evaluate(storage, session, filter_context, None, expr)
+                .await?
+                .try_into()?;
+            let values = match value {
+                Value::List(values) => values,
+                Value::Null => Vec::new(),
+                _ => return Err(EvaluateError::ListTypeRequired.into()),
+            };
+
+            let rows = values.into_iter().map(move |value| {
+                Ok(Row::Vec {
+                    columns: Rc::clone(&columns),
+                    values: vec![value],
+                })
+            });
+
+            Ok(Rows::Unnest(stream::iter(rows)))
+        }
         TableFactor::Dictionary { dict, .. } => {
             let rows = {
                 #[derive(Iterator)]
@@ -342,7 +372,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                                     Value::Str(index.name),
                                     Value::Str(index.order.to_string()),
                                     Value::Str(index.expr.to_sql_unquoted()),
-                                    Value::Bool(false),
+                                    Value::Bool(index.unique),
                                 ];
 
                                 Ok(Row::Vec {
@@ -413,6 +443,7 @@ pub async fn fetch_relation_columns<T: GStore>(
             }
         }
         TableFactor::Series { .. } => Ok(Some(vec!["N".to_owned()])),
+        TableFactor::Unnest { alias, .. } => Ok(Some(vec![unnest_column(alias)])),
         TableFactor::Dictionary { dict, .. } => Ok(Some(match dict {
             Dictionary::GlueObjects => vec![
                 "OBJECT_NAME".to_owned(),
@@ -436,6 +467,11 @@ pub async fn fetch_relation_columns<T: GStore>(
                 "UNIQUENESS".to_owned(),
             ],
         })),
+        TableFactor::Derived {
+            subquery: subquery @ Query { with, .. },
+            alias,
+            ..
+        } if !with.is_empty() => fetch_derived_columns(storage, subquery, alias).await,
         TableFactor::Derived {
             subquery: Query { body, .. },
             alias:
@@ -443,59 +479,72 @@ pub async fn fetch_relation_columns<T: GStore>(
                     columns: alias_columns,
                     name,
                 },
-        } => match body {
-            SetExpr::Select(statement) => {
-                let Select {
-                    from:
-                        TableWithJoins {
-                            relation, joins, ..
-                        },
-                    projection,
-                    ..
-                } = statement.as_ref();
-
-                let labels = fetch_labels(storage, relation, joins, projection).await?;
-                match labels {
-                    None => Ok(None),
-                    Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
-                    Some(labels) if alias_columns.len() > labels.len() => {
-                        Err(FetchError::TooManyColumnAliases(
-                            name.to_string(),
-                            labels.len(),
-                            alias_columns.len(),
+            ..
+        } => {
+            let mut body = body;
+            while let SetExpr::SetOperation { left: operand, .. }
+            | SetExpr::Recursive { base: operand, .. } = body
+            {
+                body = operand.as_ref();
+            }
+
+            match body {
+                SetExpr::Select(statement) => {
+                    let Select {
+                        from:
+                            TableWithJoins {
+                                relation, joins, ..
+                            },
+                        projection,
+                        ..
+                    } = statement.as_ref();
+
+                    let labels = fetch_labels(storage, relation, joins, projection).await?;
+                    match labels {
+                        None => Ok(None),
+                        Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
+                        Some(labels) if alias_columns.len() > labels.len() => {
+                            Err(FetchError::TooManyColumnAliases(
+                                name.to_string(),
+                                labels.len(),
+                                alias_columns.len(),
+                            )
+                            .into())
+                        }
+                        Some(labels) => Ok(Some(
+                            alias_columns
+                                .iter()
+                                .cloned()
+                                .chain(labels[alias_columns.len()..labels.len()].to_vec())
+                                .collect(),
+                        )),
+                    }
+                }
+                SetExpr::Values(Values(values_list)) => {
+                    let total_len = values_list[0].len();
+                    let alias_len = alias_columns.len();
+                    if alias_len > total_len {
+                        return Err(FetchError::TooManyColumnAliases(
+                            name.into(),
+                            total_len,
+                            alias_len,
                         )
-                        .into())
+                        .into());
                     }
-                    Some(labels) => Ok(Some(
-                        alias_columns
-                            .iter()
-                            .cloned()
-                            .chain(labels[alias_columns.len()..labels.len()].to_vec())
-                            .collect(),
-                    )),
+                    let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
+                    let labels = alias_columns
+                        .iter()
+                        .cloned()
+                        .chain(labels)
+                        .collect::<Vec<_>>();
+
+                    Ok(Some(labels))
                 }
-            }
-            SetExpr::Values(Values(values_list)) => {
-                let total_len = values_list[0].len();
-                let alias_len = alias_columns.len();
-                if alias_len > total_len {
-                    return Err(FetchError::TooManyColumnAliases(
-                        name.into(),
-                        total_len,
-                        alias_len,
-                    )
-                    .into());
+                SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+                    Err(TableError::Unreachable.into())
                 }
-                let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
-                let labels = alias_columns
-                    .iter()
-                    .cloned()
-                    .chain(labels)
-                    .collect::<Vec<_>>();
-
-                Ok(Some(labels))
             }
-        },
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 use {
-    super::{context::RowContext, evaluate::evaluate},
+    super::{context::RowContext, evaluate::evaluate, Session},
     crate::{
         ast::{Aggregate, Expr},
         data::Value,
@@ -12,6 +12,7 @@ use {
 
 pub struct Filter<'a, T: GStore> {
     storage: &'a T,
+    session: Session<'a>,
     where_clause: Option<&'a Expr>,
     context: Option<Rc<RowContext<'a>>>,
     aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
@@ -20,12 +21,14 @@ pub struct Filter<'a, T: GStore> {
 impl<'a, T: GStore> Filter<'a, T> {
     pub fn new(
         storage: &'a T,
+        session: Session<'a>,
         where_clause: Option<&'a Expr>,
         context: Option<Rc<RowContext<'a>>>,
         aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
     ) -> Self {
         Self {
             storage,
+            session,
             where_clause,
             context,
             aggregated,
@@ -44,7 +47,7 @@ impl<'a, T: GStore> Filter<'a, T> {
                 let context = Some(context);
                 let aggregated = self.aggregated.as_ref().map(Rc::clone);
 
-                check_expr(self.storage, context, aggregated, expr).await
+                check_expr(self.storage, self.session, context, aggregated, expr).await
             }
             None => Ok(true),
         }
@@ -53,11 +56,12 @@ impl<'a, T: GStore> Filter<'a, T> {
 
 pub async fn check_expr<'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     context: Option<Rc<RowContext<'a>>>,
     aggregated: Option<Rc<HashMap<&'a Aggregate, Value>>>,
     expr: &'a Expr,
 ) -> Result<bool> {
-    evaluate(storage, context, aggregated, expr)
+    evaluate(storage, session, context, aggregated, expr)
         .await
         .map(|evaluated| evaluated.try_into())?
 }
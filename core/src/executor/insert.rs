@@ -1,18 +1,37 @@
 use {
     super::{
+        alter::resolve_column_defs,
+        constraint::{validate_check_constraints, validate_foreign_keys},
         select::select,
-        validate::{validate_unique, ColumnValidation},
+        update::Update,
+        validate::{
+            filter_unique, validate_unique, validate_unique_indexes, ColumnValidation,
+            ValidateError,
+        },
+        Session,
     },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, Query, SetExpr, Values},
+        ast::{
+            Assignment, ColumnDef, ColumnUniqueOption, Expr, OnConflict, Query, SetExpr, Values,
+        },
         data::{Key, Row, Schema, Value},
-        executor::{evaluate::evaluate_stateless, limit::Limit},
+        executor::{
+            context::RowContext,
+            evaluate::{evaluate, evaluate_stateless},
+            limit::Limit,
+            RowChange,
+        },
         result::Result,
         store::{DataRow, GStore, GStoreMut},
     },
     futures::stream::{self, StreamExt, TryStreamExt},
     serde::Serialize,
-    std::{fmt::Debug, rc::Rc},
+    std::{
+        borrow::Cow,
+        collections::{HashMap, HashSet},
+        fmt::Debug,
+        rc::Rc,
+    },
     thiserror::Error as ThisError,
 };
 
@@ -38,6 +57,12 @@ pub enum InsertError {
 
     #[error("map type required: {0}")]
     MapTypeValueRequired(String),
+
+    #[error("ON CONFLICT target must be a single PRIMARY KEY or UNIQUE column: {0}")]
+    InvalidConflictTarget(String),
+
+    #[error("ON CONFLICT DO UPDATE cannot affect a row a second time: {0}")]
+    ConflictRowAffectedTwice(String),
 }
 
 enum RowsData {
@@ -45,24 +70,81 @@ enum RowsData {
     Insert(Vec<(Key, DataRow)>),
 }
 
+/// Rows changed by `ON CONFLICT DO UPDATE`, as their storage key, the stored row and the updated row
+type UpdatedRows = Vec<(Key, Row, Row)>;
+
 pub async fn insert<T: GStore + GStoreMut>(
     storage: &mut T,
+    session: Session<'_>,
     table_name: &str,
     columns: &[String],
     source: &Query,
+    on_conflict: &Option<OnConflict>,
+    changes: Option<&mut Vec<RowChange>>,
 ) -> Result<usize> {
-    let Schema { column_defs, .. } = storage
+    let schema = storage
         .fetch_schema(table_name)
         .await?
         .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
-
-    let rows = match column_defs {
-        Some(column_defs) => {
-            fetch_vec_rows(storage, table_name, column_defs, columns, source).await
+    let labels = schema
+        .column_defs
+        .iter()
+        .flatten()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Rc<[String]>>();
+
+    let (rows, updated_rows) = match (schema.column_defs.clone(), on_conflict) {
+        (Some(column_defs), _) => {
+            fetch_vec_rows(
+                storage,
+                session,
+                &schema,
+                column_defs,
+                columns,
+                source,
+                on_conflict,
+            )
+            .await
         }
-        None => fetch_map_rows(storage, source).await.map(RowsData::Append),
+        (None, Some(OnConflict::DoUpdate { target, .. })) => {
+            Err(InsertError::InvalidConflictTarget(target.join(", ")).into())
+        }
+        (None, _) => fetch_map_rows(storage, session, source)
+            .await
+            .map(|rows| (RowsData::Append(rows), Vec::new())),
     }?;
 
+    if let Some(changes) = changes {
+        let data_rows = match &rows {
+            RowsData::Append(rows) => rows.iter().collect::<Vec<_>>(),
+            RowsData::Insert(rows) => rows.iter().map(|(_, row)| row).collect(),
+        };
+        let inserted = data_rows.into_iter().map(|data_row| match data_row {
+            DataRow::Vec(values) => Row::Vec {
+                columns: Rc::clone(&labels),
+                values: values.clone(),
+            },
+            DataRow::Map(values) => Row::Map(values.clone()),
+        });
+
+        changes.extend(inserted.map(RowChange::Insert));
+        changes.extend(
+            updated_rows
+                .iter()
+                .map(|(_, old_row, row)| RowChange::Update(old_row.clone(), row.clone())),
+        );
+    }
+
+    let num_updated_rows = updated_rows.len();
+    if num_updated_rows > 0 {
+        let updated_rows = updated_rows
+            .into_iter()
+            .map(|(key, _, row)| (key, row.into()))
+            .collect();
+
+        storage.insert_data(table_name, updated_rows).await?;
+    }
+
     match rows {
         RowsData::Append(rows) => {
             let num_rows = rows.len();
@@ -70,7 +152,7 @@ pub async fn insert<T: GStore + GStoreMut>(
             storage
                 .append_data(table_name, rows)
                 .await
-                .map(|_| num_rows)
+                .map(|_| num_rows + num_updated_rows)
         }
         RowsData::Insert(rows) => {
             let num_rows = rows.len();
@@ -78,19 +160,29 @@ pub async fn insert<T: GStore + GStoreMut>(
             storage
                 .insert_data(table_name, rows)
                 .await
-                .map(|_| num_rows)
+                .map(|_| num_rows + num_updated_rows)
         }
     }
 }
 
 async fn fetch_vec_rows<T: GStore>(
     storage: &T,
-    table_name: &str,
+    session: Session<'_>,
+    schema: &Schema,
     column_defs: Vec<ColumnDef>,
     columns: &[String],
     source: &Query,
-) -> Result<RowsData> {
-    let labels = Rc::from(
+    on_conflict: &Option<OnConflict>,
+) -> Result<(RowsData, UpdatedRows)> {
+    let Schema {
+        table_name,
+        checks,
+        foreign_keys,
+        indexes,
+        ..
+    } = schema;
+    let column_defs = resolve_column_defs(storage, column_defs).await?;
+    let labels: Rc<[String]> = Rc::from(
         column_defs
             .iter()
             .map(|column_def| column_def.name.to_owned())
@@ -115,7 +207,8 @@ async fn fetch_vec_rows<T: GStore>(
                 async move {
                     Ok(Row::Vec {
                         columns: labels,
-                        values: fill_values(&column_defs, columns, values).await?,
+                        values: fill_values(storage, session, &column_defs, columns, values)
+                            .await?,
                     })
                 }
             });
@@ -124,25 +217,26 @@ async fn fetch_vec_rows<T: GStore>(
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+            let rows = select(storage, session, source, None).await?.map(|row| {
                 let values = row?.try_into_vec()?;
 
                 column_defs
                     .iter()
-                    .zip(values.iter())
-                    .try_for_each(|(column_def, value)| {
+                    .zip(values)
+                    .map(|(column_def, value)| {
                         let ColumnDef {
                             data_type,
                             nullable,
                             ..
                         } = column_def;
 
-                        value.validate_type(data_type)?;
-                        value.validate_null(*nullable)
-                    })?;
+                        let value = value.into_column_value(data_type)?;
+                        value.validate_null(*nullable)?;
 
-                Ok(values)
+                        Ok(value)
+                    })
+                    .collect::<Result<Vec<_>>>()
             });
 
             Rows::Select(rows)
@@ -151,19 +245,108 @@ async fn fetch_vec_rows<T: GStore>(
     .try_collect::<Vec<Vec<Value>>>()
     .await?;
 
-    validate_unique(
+    validate_check_constraints(
         storage,
-        table_name,
-        column_validation,
+        session,
+        &column_defs,
+        checks,
         rows.iter().map(|values| values.as_slice()),
     )
     .await?;
+    validate_foreign_keys(
+        storage,
+        &column_defs,
+        foreign_keys,
+        rows.iter().map(|values| values.as_slice()),
+    )
+    .await?;
+
+    let (rows, updated_rows) = match on_conflict {
+        Some(OnConflict::DoNothing) => (
+            filter_unique(storage, table_name, &column_defs, indexes, rows).await?,
+            Vec::new(),
+        ),
+        Some(OnConflict::DoUpdate {
+            target,
+            assignments,
+        }) => {
+            let (rows, updated_rows) = update_conflict_rows(
+                storage,
+                session,
+                table_name,
+                &column_defs,
+                target,
+                assignments,
+                rows,
+            )
+            .await?;
+
+            validate_unique(
+                storage,
+                table_name,
+                column_validation,
+                rows.iter().map(|values| values.as_slice()),
+            )
+            .await?;
+
+            let updated_values = updated_rows.iter().filter_map(|(key, _, row)| match row {
+                Row::Vec { values, .. } => Some((Some(key), values.as_slice())),
+                Row::Map(_) => None,
+            });
+            validate_check_constraints(
+                storage,
+                session,
+                &column_defs,
+                checks,
+                updated_values.clone().map(|(_, values)| values),
+            )
+            .await?;
+            validate_foreign_keys(
+                storage,
+                &column_defs,
+                foreign_keys,
+                updated_values.clone().map(|(_, values)| values),
+            )
+            .await?;
+            validate_unique_indexes(
+                storage,
+                table_name,
+                &column_defs,
+                indexes,
+                rows.iter()
+                    .map(|values| (None, values.as_slice()))
+                    .chain(updated_values),
+            )
+            .await?;
+
+            (rows, updated_rows)
+        }
+        None => {
+            validate_unique(
+                storage,
+                table_name,
+                column_validation,
+                rows.iter().map(|values| values.as_slice()),
+            )
+            .await?;
+            validate_unique_indexes(
+                storage,
+                table_name,
+                &column_defs,
+                indexes,
+                rows.iter().map(|values| (None, values.as_slice())),
+            )
+            .await?;
+
+            (rows, Vec::new())
+        }
+    };
 
     let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
         unique == &Some(ColumnUniqueOption { is_primary: true })
     });
 
-    match primary_key {
+    let rows = match primary_key {
         Some(i) => rows
             .into_iter()
             .filter_map(|values| {
@@ -173,12 +356,149 @@ async fn fetch_vec_rows<T: GStore>(
                     .map(|result| result.map(|key| (key, values.into())))
             })
             .collect::<Result<Vec<_>>>()
-            .map(RowsData::Insert),
-        None => Ok(RowsData::Append(rows.into_iter().map(Into::into).collect())),
+            .map(RowsData::Insert)?,
+        None => RowsData::Append(rows.into_iter().map(Into::into).collect()),
+    };
+
+    Ok((rows, updated_rows))
+}
+
+/// Splits the rows of `INSERT ... ON CONFLICT (target) DO UPDATE` into the rows to insert and the
+/// stored rows updated by `assignments`, where `EXCLUDED` refers to the row proposed for insertion.
+async fn update_conflict_rows<T: GStore>(
+    storage: &T,
+    session: Session<'_>,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    target: &[String],
+    assignments: &[Assignment],
+    rows: Vec<Vec<Value>>,
+) -> Result<(Vec<Vec<Value>>, UpdatedRows)> {
+    let target_index = match target {
+        [column] => column_defs
+            .iter()
+            .position(|ColumnDef { name, unique, .. }| name == column && unique.is_some()),
+        _ => None,
     }
+    .ok_or_else(|| InsertError::InvalidConflictTarget(target.join(", ")))?;
+
+    // stored rows are fetched by their primary key, other unique columns need a full scan
+    let stored_rows = match column_defs[target_index].unique {
+        Some(ColumnUniqueOption { is_primary: true }) => None,
+        _ => {
+            let mut stored_rows = HashMap::new();
+            for result in storage.scan_data(table_name).await? {
+                let (key, data_row) = result?;
+                let values = match data_row {
+                    DataRow::Vec(values) => values,
+                    DataRow::Map(_) => {
+                        return Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into());
+                    }
+                };
+
+                stored_rows.insert(conflict_key(&values, target_index)?, (key, values));
+            }
+
+            Some(stored_rows)
+        }
+    };
+
+    let labels = column_defs
+        .iter()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Rc<[String]>>();
+    let update = Update::new(storage, session, table_name, assignments, Some(column_defs))?;
+    let mut target_keys = HashSet::new();
+    let mut inserted_rows = Vec::new();
+    let mut updated_rows = Vec::new();
+
+    for values in rows {
+        let target_key = conflict_key(&values, target_index)?;
+        if target_key == Key::None {
+            inserted_rows.push(values);
+            continue;
+        } else if !target_keys.insert(target_key.clone()) {
+            let value = String::from(&values[target_index]);
+
+            return Err(InsertError::ConflictRowAffectedTwice(value).into());
+        }
+
+        let stored_row = match &stored_rows {
+            Some(stored_rows) => stored_rows.get(&target_key).cloned(),
+            None => match storage.fetch_data(table_name, &target_key).await? {
+                Some(DataRow::Vec(stored_values)) => Some((target_key, stored_values)),
+                Some(DataRow::Map(_)) => {
+                    return Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into());
+                }
+                None => None,
+            },
+        };
+
+        let (key, stored_values) = match stored_row {
+            Some(stored_row) => stored_row,
+            None => {
+                inserted_rows.push(values);
+                continue;
+            }
+        };
+
+        let stored_row = Row::Vec {
+            columns: Rc::clone(&labels),
+            values: stored_values,
+        };
+        let excluded = Row::Vec {
+            columns: Rc::clone(&labels),
+            values,
+        };
+        let excluded = RowContext::new("EXCLUDED", Cow::Borrowed(&excluded), None);
+        let row = update
+            .apply_with_context(stored_row.clone(), Some(Rc::new(excluded)))
+            .await?;
+
+        updated_rows.push((key, stored_row, row));
+    }
+
+    // a unique value which is not changed by the update cannot conflict with the other rows
+    for Assignment { id, .. } in assignments {
+        let index = match column_defs
+            .iter()
+            .position(|ColumnDef { name, .. }| name == id)
+        {
+            Some(index) => index,
+            None => continue,
+        };
+        let changed_rows =
+            updated_rows
+                .iter()
+                .filter_map(|(_, stored_row, row)| match (stored_row, row) {
+                    (Row::Vec { values: old, .. }, Row::Vec { values, .. })
+                        if old.get(index) != values.get(index) =>
+                    {
+                        Some(values.as_slice())
+                    }
+                    _ => None,
+                });
+        let column_validation =
+            ColumnValidation::SpecifiedColumns(column_defs, vec![id.to_owned()]);
+
+        validate_unique(storage, table_name, column_validation, changed_rows).await?;
+    }
+
+    Ok((inserted_rows, updated_rows))
+}
+
+fn conflict_key(values: &[Value], index: usize) -> Result<Key> {
+    values
+        .get(index)
+        .ok_or(ValidateError::ConflictOnStorageColumnIndex(index))?
+        .try_into()
 }
 
-async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<DataRow>> {
+async fn fetch_map_rows<T: GStore>(
+    storage: &T,
+    session: Session<'_>,
+    source: &Query,
+) -> Result<Vec<DataRow>> {
     #[derive(futures_enum::Stream)]
     enum Rows<I1, I2> {
         Values(I1),
@@ -203,8 +523,8 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+            let rows = select(storage, session, source, None).await?.map(|row| {
                 let row = row?;
 
                 if let Row::Vec { values, .. } = &row {
@@ -227,7 +547,9 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
     Ok(rows)
 }
 
-async fn fill_values(
+async fn fill_values<T: GStore>(
+    storage: &T,
+    session: Session<'_>,
     column_defs: &[ColumnDef],
     columns: &[String],
     values: &[Expr],
@@ -260,36 +582,50 @@ async fn fill_values(
 
     let column_name_value_list = columns.zip(values.iter()).collect::<Vec<(_, _)>>();
 
-    let values = stream::iter(column_defs)
-        .then(|column_def| {
-            let column_name_value_list = &column_name_value_list;
-
-            async move {
-                let ColumnDef {
-                    name: def_name,
-                    data_type,
-                    nullable,
-                    ..
-                } = column_def;
-
-                let value = column_name_value_list
-                    .iter()
-                    .find(|(name, _)| name == &def_name)
-                    .map(|(_, value)| value);
-
-                match (value, &column_def.default, nullable) {
-                    (Some(&expr), _, _) | (None, Some(expr), _) => evaluate_stateless(None, expr)
-                        .await?
-                        .try_into_value(data_type, *nullable),
-                    (None, None, true) => Ok(Value::Null),
-                    (None, None, false) => {
-                        Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into())
-                    }
-                }
+    let column_names = column_defs
+        .iter()
+        .map(|ColumnDef { name, .. }| name.to_owned())
+        .collect::<Vec<_>>();
+    let mut values = Vec::with_capacity(column_defs.len());
+
+    for column_def in column_defs {
+        let ColumnDef {
+            name: def_name,
+            data_type,
+            nullable,
+            default,
+            ..
+        } = column_def;
+
+        let value = column_name_value_list
+            .iter()
+            .find(|(name, _)| name == &def_name)
+            .map(|(_, value)| value)
+            .filter(|value| !matches!(value, Expr::Default));
+
+        let value = match (value, default, nullable) {
+            (Some(&expr), _, _) => evaluate(storage, session, None, None, expr)
+                .await?
+                .try_into_value(data_type, *nullable, session.time_zone)?,
+            (None, Some(expr), _) => {
+                // default values can refer to the columns defined before
+                let context = RowContext::RefVecData {
+                    columns: &column_names[..values.len()],
+                    values: &values,
+                };
+
+                evaluate(storage, session, Some(Rc::new(context)), None, expr)
+                    .await?
+                    .try_into_value(data_type, *nullable, session.time_zone)?
+            }
+            (None, None, true) => Value::Null,
+            (None, None, false) => {
+                return Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into());
             }
-        })
-        .try_collect::<Vec<Value>>()
-        .await?;
+        };
+
+        values.push(value);
+    }
 
     Ok(values)
 }
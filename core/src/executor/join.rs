@@ -5,22 +5,29 @@ use {
             Expr, Join as AstJoin, JoinConstraint, JoinExecutor as AstJoinExecutor,
             JoinOperator as AstJoinOperator, TableFactor,
         },
-        data::{get_alias, Key, Row, Value},
-        executor::{context::RowContext, evaluate::evaluate, filter::check_expr},
+        data::{get_alias, Key, Row, TimeZone, Value},
+        executor::{
+            context::RowContext, evaluate::evaluate, filter::check_expr, select::SelectError,
+            Session,
+        },
         result::Result,
         store::GStore,
     },
+    chrono::NaiveDateTime,
     futures::{
         future,
         stream::{self, empty, once, Stream, StreamExt, TryStreamExt},
     },
     itertools::Itertools,
-    std::{borrow::Cow, collections::HashMap, pin::Pin, rc::Rc},
+    rust_decimal::{prelude::ToPrimitive, Decimal},
+    std::{borrow::Cow, cell::RefCell, collections::HashMap, pin::Pin, rc::Rc},
     utils::OrStream,
 };
 
 pub struct Join<'a, T: GStore> {
     storage: &'a T,
+    session: Session<'a>,
+    relation: &'a TableFactor,
     join_clauses: &'a [AstJoin],
     filter_context: Option<Rc<RowContext<'a>>>,
 }
@@ -31,11 +38,15 @@ type Joined<'a> = Pin<Box<dyn Stream<Item = Result<JoinItem<'a>>> + 'a>>;
 impl<'a, T: GStore> Join<'a, T> {
     pub fn new(
         storage: &'a T,
+        session: Session<'a>,
+        relation: &'a TableFactor,
         join_clauses: &'a [AstJoin],
         filter_context: Option<Rc<RowContext<'a>>>,
     ) -> Self {
         Self {
             storage,
+            session,
+            relation,
             join_clauses,
             filter_context,
         }
@@ -47,12 +58,25 @@ impl<'a, T: GStore> Join<'a, T> {
     ) -> Result<Joined<'a>> {
         let init_rows: Joined = Box::pin(rows.map(|row| row.map(Rc::new)));
 
-        stream::iter(self.join_clauses)
+        stream::iter(self.join_clauses.iter().enumerate())
             .map(Ok)
-            .try_fold(init_rows, |rows, join_clause| {
+            .try_fold(init_rows, |rows, (i, join_clause)| {
                 let filter_context = self.filter_context.as_ref().map(Rc::clone);
+                let left_relations = std::iter::once(self.relation)
+                    .chain(self.join_clauses[..i].iter().map(|join| &join.relation))
+                    .collect();
 
-                async move { join(self.storage, filter_context, join_clause, rows).await }
+                async move {
+                    join(
+                        self.storage,
+                        self.session,
+                        filter_context,
+                        left_relations,
+                        join_clause,
+                        rows,
+                    )
+                    .await
+                }
             })
             .await
     }
@@ -60,7 +84,9 @@ impl<'a, T: GStore> Join<'a, T> {
 
 async fn join<'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     filter_context: Option<Rc<RowContext<'a>>>,
+    left_relations: Vec<&'a TableFactor>,
     ast_join: &'a AstJoin,
     left_rows: impl Stream<Item = Result<JoinItem<'a>>> + 'a,
 ) -> Result<Joined<'a>> {
@@ -71,15 +97,6 @@ async fn join<'a, T: GStore>(
     } = ast_join;
 
     let table_alias = get_alias(relation);
-    let join_executor = JoinExecutor::new(
-        storage,
-        relation,
-        filter_context.as_ref().map(Rc::clone),
-        join_executor,
-    )
-    .await
-    .map(Rc::new)?;
-
     let (join_operator, where_clause) = match join_operator {
         AstJoinOperator::Inner(JoinConstraint::None) => (JoinOperator::Inner, None),
         AstJoinOperator::Inner(JoinConstraint::On(where_clause)) => {
@@ -89,8 +106,29 @@ async fn join<'a, T: GStore>(
         AstJoinOperator::LeftOuter(JoinConstraint::On(where_clause)) => {
             (JoinOperator::LeftOuter, Some(where_clause))
         }
+        AstJoinOperator::FullOuter(JoinConstraint::None) => (JoinOperator::FullOuter, None),
+        AstJoinOperator::FullOuter(JoinConstraint::On(where_clause)) => {
+            (JoinOperator::FullOuter, Some(where_clause))
+        }
+        AstJoinOperator::Inner(JoinConstraint::Using(_) | JoinConstraint::Natural)
+        | AstJoinOperator::LeftOuter(JoinConstraint::Using(_) | JoinConstraint::Natural)
+        | AstJoinOperator::FullOuter(JoinConstraint::Using(_) | JoinConstraint::Natural) => {
+            return Err(SelectError::UnplannedJoinConstraint.into());
+        }
     };
 
+    let executor_context = filter_context.as_ref().map(Rc::clone);
+    let join_executor = match join_operator {
+        JoinOperator::FullOuter => {
+            JoinExecutor::full_outer(storage, session, relation, executor_context).await
+        }
+        JoinOperator::Inner | JoinOperator::LeftOuter => {
+            JoinExecutor::new(storage, session, relation, executor_context, join_executor).await
+        }
+    }
+    .map(Rc::new)?;
+    let full_outer_executor = Rc::clone(&join_executor);
+
     let columns = fetch_relation_columns(storage, relation)
         .await?
         .map(Rc::from);
@@ -124,19 +162,21 @@ async fn join<'a, T: GStore>(
             let filter_context = Some(filter_context);
 
             #[derive(futures_enum::Stream)]
-            enum Rows<I1, I2, I3> {
+            enum Rows<I1, I2, I3, I4> {
                 NestedLoop(I1),
                 Hash(I2),
                 Empty(I3),
+                FullOuter(I4),
             }
             let rows = match join_executor.as_ref() {
                 JoinExecutor::NestedLoop => {
-                    let rows = fetch_relation_rows(storage, relation, &filter_context)
+                    let rows = fetch_relation_rows(storage, session, relation, &filter_context)
                         .await?
                         .and_then(|row| future::ok(Cow::Owned(row)))
                         .try_filter_map(move |row| {
                             check_where_clause(
                                 storage,
+                                session,
                                 table_alias,
                                 filter_context.as_ref().map(Rc::clone),
                                 Some(&project_context).map(Rc::clone),
@@ -152,12 +192,14 @@ async fn join<'a, T: GStore>(
                 } => {
                     let rows = evaluate(
                         storage,
+                        session,
                         filter_context.as_ref().map(Rc::clone),
                         None,
                         value_expr,
                     )
                     .await
-                    .map(Key::try_from)?
+                    .and_then(Value::try_from)
+                    .and_then(|value| coerce_hash_key(value, session.time_zone))
                     .map(|hash_key| rows_map.get(&hash_key))?;
 
                     match rows {
@@ -171,6 +213,7 @@ async fn join<'a, T: GStore>(
                                     async {
                                         check_where_clause(
                                             storage,
+                                            session,
                                             table_alias,
                                             filter_context,
                                             project_context,
@@ -188,11 +231,41 @@ async fn join<'a, T: GStore>(
                         }
                     }
                 }
+                JoinExecutor::FullOuter { rows, matched } => {
+                    let rows = stream::iter(rows.iter().enumerate())
+                        .filter_map(|(i, row)| {
+                            let filter_context = filter_context.as_ref().map(Rc::clone);
+                            let project_context = Some(&project_context).map(Rc::clone);
+
+                            async move {
+                                let joined = check_where_clause(
+                                    storage,
+                                    session,
+                                    table_alias,
+                                    filter_context,
+                                    project_context,
+                                    where_clause,
+                                    Cow::Borrowed(row),
+                                )
+                                .await;
+
+                                if matches!(joined, Ok(Some(_))) {
+                                    matched.borrow_mut()[i] = true;
+                                }
+
+                                joined.transpose()
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .await;
+
+                    Rows::FullOuter(stream::iter(rows))
+                }
             };
 
             let rows: Joined = match join_operator {
                 JoinOperator::Inner => Box::pin(rows),
-                JoinOperator::LeftOuter => {
+                JoinOperator::LeftOuter | JoinOperator::FullOuter => {
                     let init_rows = once(async { Ok(init_context) });
 
                     Box::pin(OrStream::new(rows, init_rows))
@@ -203,13 +276,82 @@ async fn join<'a, T: GStore>(
         }
     });
 
-    Ok(Box::pin(rows.try_flatten()))
+    let rows = rows.try_flatten();
+
+    match join_operator {
+        JoinOperator::Inner | JoinOperator::LeftOuter => Ok(Box::pin(rows)),
+        JoinOperator::FullOuter => {
+            let unmatched_rows =
+                unmatched_rows(storage, left_relations, table_alias, full_outer_executor);
+            let unmatched_rows = once(unmatched_rows)
+                .map_ok(|rows| stream::iter(rows.into_iter().map(Ok)))
+                .try_flatten();
+
+            Ok(Box::pin(rows.chain(unmatched_rows)))
+        }
+    }
+}
+
+/// Right rows of `FULL OUTER JOIN` which matched none of the left rows, joined with a left row
+/// of `NULL`s. It must be awaited after all the left rows are joined.
+async fn unmatched_rows<'a, T: GStore>(
+    storage: &'a T,
+    left_relations: Vec<&'a TableFactor>,
+    table_alias: &'a str,
+    join_executor: Rc<JoinExecutor<'a>>,
+) -> Result<Vec<JoinItem<'a>>> {
+    let left_context = null_context(storage, &left_relations).await?;
+    let (rows, matched) = match join_executor.as_ref() {
+        JoinExecutor::FullOuter { rows, matched } => (rows, matched.borrow()),
+        JoinExecutor::NestedLoop | JoinExecutor::Hash { .. } => return Ok(Vec::new()),
+    };
+
+    let rows = rows
+        .iter()
+        .zip(matched.iter())
+        .filter(|(_, matched)| !**matched)
+        .map(|(row, _)| {
+            let left_context = left_context.as_ref().map(Rc::clone);
+            let context = RowContext::new(table_alias, Cow::Owned(row.clone()), left_context);
+
+            Rc::new(context)
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+/// Row context of the given relations whose values are all `NULL`
+async fn null_context<'a, T: GStore>(
+    storage: &'a T,
+    relations: &[&'a TableFactor],
+) -> Result<Option<Rc<RowContext<'a>>>> {
+    let mut context = None;
+
+    for relation in relations {
+        let row = match fetch_relation_columns(storage, relation).await? {
+            Some(columns) => Row::Vec {
+                values: vec![Value::Null; columns.len()],
+                columns: Rc::from(columns),
+            },
+            None => Row::Map(HashMap::new()),
+        };
+
+        context = Some(Rc::new(RowContext::new(
+            get_alias(relation),
+            Cow::Owned(row),
+            context,
+        )));
+    }
+
+    Ok(context)
 }
 
 #[derive(Copy, Clone)]
 enum JoinOperator {
     Inner,
     LeftOuter,
+    FullOuter,
 }
 
 enum JoinExecutor<'a> {
@@ -218,11 +360,17 @@ enum JoinExecutor<'a> {
         rows_map: HashMap<Key, Vec<Row>>,
         value_expr: &'a Expr,
     },
+    /// Right rows fetched once, and whether each of them matched any left row
+    FullOuter {
+        rows: Vec<Row>,
+        matched: RefCell<Vec<bool>>,
+    },
 }
 
 impl<'a> JoinExecutor<'a> {
     async fn new<T: GStore>(
         storage: &'a T,
+        session: Session<'a>,
         relation: &TableFactor,
         filter_context: Option<Rc<RowContext<'a>>>,
         ast_join_executor: &'a AstJoinExecutor,
@@ -236,7 +384,7 @@ impl<'a> JoinExecutor<'a> {
             } => (key_expr, value_expr, where_clause),
         };
 
-        let rows_map = fetch_relation_rows(storage, relation, &filter_context)
+        let rows_map = fetch_relation_rows(storage, session, relation, &filter_context)
             .await?
             .try_filter_map(|row| {
                 let filter_context = filter_context.as_ref().map(Rc::clone);
@@ -248,23 +396,27 @@ impl<'a> JoinExecutor<'a> {
                         filter_context,
                     ));
 
-                    let hash_key: Key = evaluate(
+                    let hash_key = evaluate(
                         storage,
+                        session,
                         Some(&filter_context).map(Rc::clone),
                         None,
                         key_expr,
                     )
-                    .await?
-                    .try_into()?;
+                    .await
+                    .and_then(Value::try_from)
+                    .and_then(|value| coerce_hash_key(value, session.time_zone))?;
 
                     if matches!(hash_key, Key::None) {
                         return Ok(None);
                     }
 
                     match where_clause {
-                        Some(expr) => check_expr(storage, Some(filter_context), None, expr)
-                            .await
-                            .map(|pass| pass.then_some((hash_key, row))),
+                        Some(expr) => {
+                            check_expr(storage, session, Some(filter_context), None, expr)
+                                .await
+                                .map(|pass| pass.then_some((hash_key, row)))
+                        }
                         None => Ok(Some((hash_key, row))),
                     }
                 }
@@ -278,10 +430,86 @@ impl<'a> JoinExecutor<'a> {
             value_expr,
         })
     }
+
+    async fn full_outer<T: GStore>(
+        storage: &'a T,
+        session: Session<'a>,
+        relation: &TableFactor,
+        filter_context: Option<Rc<RowContext<'a>>>,
+    ) -> Result<JoinExecutor<'a>> {
+        let rows = fetch_relation_rows(storage, session, relation, &filter_context)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let matched = RefCell::new(vec![false; rows.len()]);
+
+        Ok(Self::FullOuter { rows, matched })
+    }
+}
+
+/// Coerces join key values into a common key, so values of different but compatible types
+/// produce the same hash key when `evaluate_eq_in` finds them equal. Numeric types (e.g. `INT32`
+/// and `INT64`, or `DECIMAL` and `FLOAT`) share one key, and so do a `DATE`, the `TIMESTAMP` at
+/// its midnight and the `TIMESTAMPTZ` of that wall clock time in `time_zone`.
+/// Values of the other types are used as they are.
+fn coerce_hash_key(value: Value, time_zone: TimeZone) -> Result<Key> {
+    let value = match value {
+        Value::I8(v) => Value::I128(v.into()),
+        Value::I16(v) => Value::I128(v.into()),
+        Value::I32(v) => Value::I128(v.into()),
+        Value::I64(v) => Value::I128(v.into()),
+        Value::U8(v) => Value::I128(v.into()),
+        Value::U16(v) => Value::I128(v.into()),
+        Value::U32(v) => Value::I128(v.into()),
+        Value::U64(v) => Value::I128(v.into()),
+        Value::U128(v) => i128::try_from(v).map(Value::I128).unwrap_or(Value::U128(v)),
+        Value::F32(v) => float_key(v.into()),
+        Value::F64(v) => float_key(v),
+        Value::Decimal(v) => decimal_key(v),
+        Value::Date(v) => match v.and_hms_opt(0, 0, 0) {
+            Some(v) => timestamp_key(v, time_zone),
+            None => Value::Date(v),
+        },
+        Value::Timestamp(v) => timestamp_key(v, time_zone),
+        value => value,
+    };
+
+    Key::try_from(value)
+}
+
+/// A wall clock time which does not exist in `time_zone` never equals a `TIMESTAMPTZ`, so it is
+/// kept as a `TIMESTAMP`.
+fn timestamp_key(v: NaiveDateTime, time_zone: TimeZone) -> Value {
+    time_zone
+        .localize(&v)
+        .map(Value::TimestampTz)
+        .unwrap_or(Value::Timestamp(v))
+}
+
+/// Floats are compared with decimals through `Decimal::from_f64_retain`, which is used here too.
+fn float_key(v: f64) -> Value {
+    if v.fract() == 0.0 && v.abs() < i128::MAX as f64 {
+        return Value::I128(v as i128);
+    }
+
+    match Decimal::from_f64_retain(v) {
+        Some(v) => decimal_key(v),
+        None => Value::F64(v),
+    }
+}
+
+fn decimal_key(v: Decimal) -> Value {
+    let v = v.normalize();
+
+    match v.fract().is_zero() {
+        true => v.to_i128().map(Value::I128).unwrap_or(Value::Decimal(v)),
+        false => Value::Decimal(v),
+    }
 }
 
 async fn check_where_clause<'a, 'b, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     table_alias: &'a str,
     filter_context: Option<Rc<RowContext<'a>>>,
     project_context: Option<Rc<RowContext<'a>>>,
@@ -292,7 +520,7 @@ async fn check_where_clause<'a, 'b, T: GStore>(
     let filter_context = Some(Rc::new(filter_context));
 
     match where_clause {
-        Some(expr) => check_expr(storage, filter_context, None, expr).await?,
+        Some(expr) => check_expr(storage, session, filter_context, None, expr).await?,
         None => true,
     }
     .then(|| RowContext::new(table_alias, Cow::Owned(row.into_owned()), project_context))
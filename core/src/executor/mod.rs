@@ -1,6 +1,9 @@
 mod aggregate;
 mod alter;
+mod analyze;
+mod constraint;
 mod context;
+mod cte;
 mod evaluate;
 mod execute;
 mod fetch;
@@ -8,21 +11,35 @@ mod filter;
 mod insert;
 mod join;
 mod limit;
+mod profile;
+mod recursive_cte;
 mod select;
+mod session;
 mod sort;
 mod update;
 mod validate;
+mod window;
 
 pub use {
     aggregate::AggregateError,
     alter::AlterError,
+    analyze::STATISTICS_TABLE,
+    constraint::ConstraintError,
     context::RowContext,
     evaluate::{evaluate_stateless, EvaluateError},
-    execute::{execute, ExecuteError, Payload, PayloadVariable},
+    execute::{
+        assert_read_only, execute, execute_with_changes, execute_with_option, execute_with_profile,
+        ExecuteError, ExecuteOption, Payload, PayloadVariable, RowChange,
+    },
     fetch::FetchError,
     insert::InsertError,
-    select::SelectError,
+    profile::{QueryProfile, StageProfile},
+    recursive_cte::{RecursiveCteError, DEFAULT_MAX_RECURSION},
+    select::{select, SelectError},
+    session::Session,
     sort::SortError,
     update::UpdateError,
     validate::ValidateError,
 };
+
+pub(crate) use {cte::cte_columns, execute::execute_versioned_update, profile::Stopwatch};
@@ -0,0 +1,182 @@
+use {
+    futures::{
+        stream::{self, Stream, StreamExt},
+        Future,
+    },
+    std::{cell::RefCell, fmt, rc::Rc, task::Poll, time::Duration},
+};
+
+/// Measures the time elapsed since it was started.
+///
+/// `std::time::Instant` panics on `wasm32-unknown-unknown`, so no clock is read there and the
+/// elapsed time is always zero.
+pub(crate) struct Stopwatch {
+    #[cfg(not(target_arch = "wasm32"))]
+    started: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            started: std::time::Instant::now(),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn elapsed(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StageProfile {
+    pub elapsed: Duration,
+    pub rows: usize,
+}
+
+/// Timing breakdown of a single statement.
+///
+/// Stage profiles are only collected for `SELECT` queries, and the elapsed time of each stage
+/// excludes the time spent in the stages it pulls rows from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryProfile {
+    pub parse_time: Duration,
+    pub plan_time: Duration,
+    pub scan: StageProfile,
+    pub join: StageProfile,
+    pub filter: StageProfile,
+    pub aggregate: StageProfile,
+    pub project: StageProfile,
+    pub sort: StageProfile,
+    pub execute_time: Duration,
+    pub total_time: Duration,
+}
+
+impl fmt::Display for QueryProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let durations = [("parse", self.parse_time), ("plan", self.plan_time)];
+        for (name, elapsed) in durations {
+            writeln!(f, "{name:<10}{:>14}", format!("{elapsed:?}"))?;
+        }
+
+        let stages = [
+            ("scan", self.scan),
+            ("join", self.join),
+            ("filter", self.filter),
+            ("aggregate", self.aggregate),
+            ("project", self.project),
+            ("sort", self.sort),
+        ];
+        for (name, StageProfile { elapsed, rows }) in stages {
+            writeln!(f, "  {name:<8}{:>14}  rows: {rows}", format!("{elapsed:?}"))?;
+        }
+
+        let durations = [("execute", self.execute_time), ("total", self.total_time)];
+        for (name, elapsed) in durations {
+            writeln!(f, "{name:<10}{:>14}", format!("{elapsed:?}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Scan,
+    Join,
+    Filter,
+    Aggregate,
+    Project,
+    Sort,
+}
+
+/// Collects the inclusive elapsed time and the number of output rows of each select stage.
+#[derive(Default)]
+pub struct Profiler {
+    stages: RefCell<[StageProfile; 6]>,
+}
+
+impl Profiler {
+    fn record(&self, stage: Stage, elapsed: Duration, rows: usize) {
+        let mut stages = self.stages.borrow_mut();
+        let profile = &mut stages[stage as usize];
+
+        profile.elapsed += elapsed;
+        profile.rows += rows;
+    }
+
+    pub fn export(&self, execute_time: Duration) -> QueryProfile {
+        let stages = *self.stages.borrow();
+        let mut upstream = Duration::ZERO;
+        let [scan, join, filter, aggregate, project, sort] = stages.map(|stage| {
+            let elapsed = stage.elapsed.saturating_sub(upstream);
+            upstream = upstream.max(stage.elapsed);
+
+            StageProfile {
+                elapsed,
+                rows: stage.rows,
+            }
+        });
+
+        QueryProfile {
+            scan,
+            join,
+            filter,
+            aggregate,
+            project,
+            sort,
+            execute_time,
+            ..QueryProfile::default()
+        }
+    }
+}
+
+/// Adds the time spent to build the stage (e.g. collecting rows to sort) to its profile.
+pub async fn measure<F: Future>(profiler: Option<&Profiler>, stage: Stage, future: F) -> F::Output {
+    match profiler {
+        Some(profiler) => {
+            let started = Stopwatch::start();
+            let output = future.await;
+            profiler.record(stage, started.elapsed(), 0);
+
+            output
+        }
+        None => future.await,
+    }
+}
+
+/// Wraps the output rows of the stage to count them and to add the time spent polling them.
+pub fn instrument<S: Stream>(
+    profiler: Option<Rc<Profiler>>,
+    stage: Stage,
+    rows: S,
+) -> impl Stream<Item = S::Item> {
+    #[derive(futures_enum::Stream)]
+    enum Rows<S1, S2> {
+        Plain(S1),
+        Profiled(S2),
+    }
+
+    let profiler = match profiler {
+        Some(profiler) => profiler,
+        None => return Rows::Plain(rows),
+    };
+
+    let mut rows = Box::pin(rows);
+    let rows = stream::poll_fn(move |cx| {
+        let started = Stopwatch::start();
+        let poll = rows.poll_next_unpin(cx);
+        let count = usize::from(matches!(poll, Poll::Ready(Some(_))));
+        profiler.record(stage, started.elapsed(), count);
+
+        poll
+    });
+
+    Rows::Profiled(rows)
+}
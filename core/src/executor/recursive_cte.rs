@@ -0,0 +1,148 @@
+use {
+    super::{
+        context::RowContext,
+        cte::{cte_columns, CteStorage, CteTable},
+        select::fetch_rows,
+        Session,
+    },
+    crate::{
+        ast::{SetExpr, TableAlias},
+        data::{Key, Row},
+        result::Result,
+        store::GStore,
+    },
+    serde::Serialize,
+    std::{collections::HashSet, fmt::Debug, mem, rc::Rc},
+    thiserror::Error,
+};
+
+/// Times the recursive term of `WITH RECURSIVE` runs unless `PlanOption::max_recursion` is set.
+pub const DEFAULT_MAX_RECURSION: usize = 1000;
+
+#[derive(Error, Serialize, Debug, PartialEq, Eq)]
+pub enum RecursiveCteError {
+    #[error("recursive query {name} exceeded the maximum recursion of {max_recursion}")]
+    MaxRecursionExceeded { name: String, max_recursion: usize },
+
+    #[error("each UNION query must have the same number of columns")]
+    NumberOfColumnsDifferent,
+
+    #[error("UNION is not supported for schemaless rows")]
+    UnionOnMapRow,
+}
+
+/// Parts of a `WITH RECURSIVE` query, see `SetExpr::Recursive`
+pub struct RecursiveQuery<'a> {
+    pub alias: &'a TableAlias,
+    pub all: bool,
+    pub base: &'a SetExpr,
+    pub step: &'a SetExpr,
+    pub max_recursion: usize,
+}
+
+/// Seeds the working table with the rows of `base`, then runs `step` against the rows produced
+/// by the previous iteration until it produces no more rows, which has to happen within
+/// `max_recursion` iterations.
+/// The result is labeled by `base`.
+pub async fn fetch_recursive_rows<'a, T: GStore>(
+    storage: &'a T,
+    session: Session<'a>,
+    query: RecursiveQuery<'_>,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)> {
+    let RecursiveQuery {
+        alias,
+        all,
+        base,
+        step,
+        max_recursion,
+    } = query;
+    let (labels, base_rows) = fetch_rows(
+        storage,
+        session,
+        base,
+        filter_context.as_ref().map(Rc::clone),
+    )
+    .await?;
+    let columns = cte_columns(alias, labels.as_deref());
+
+    let mut distinct = Distinct::new(all);
+    let mut working_rows = distinct.filter(base_rows)?;
+    let mut rows = Vec::new();
+
+    let mut recursion = 0;
+    while !working_rows.is_empty() {
+        if recursion == max_recursion {
+            return Err(RecursiveCteError::MaxRecursionExceeded {
+                name: alias.name.to_owned(),
+                max_recursion,
+            }
+            .into());
+        }
+        recursion += 1;
+
+        let working_table = CteStorage::with_table(
+            storage,
+            CteTable {
+                name: &alias.name,
+                columns: columns.as_deref(),
+                rows: &working_rows,
+            },
+        );
+        let (_, step_rows) = fetch_rows(
+            &working_table,
+            session,
+            step,
+            filter_context.as_ref().map(Rc::clone),
+        )
+        .await?;
+
+        if let (Some(Row::Vec { values, .. }), Some(columns)) = (step_rows.first(), &columns) {
+            if values.len() != columns.len() {
+                return Err(RecursiveCteError::NumberOfColumnsDifferent.into());
+            }
+        }
+
+        let step_rows = distinct.filter(step_rows)?;
+        rows.extend(mem::replace(&mut working_rows, step_rows));
+    }
+
+    Ok((labels, rows))
+}
+
+/// Keeps track of the rows already produced by `UNION`, `UNION ALL` keeps every row.
+struct Distinct {
+    keys: Option<HashSet<Vec<Key>>>,
+}
+
+impl Distinct {
+    fn new(all: bool) -> Self {
+        Self {
+            keys: (!all).then(HashSet::new),
+        }
+    }
+
+    fn filter(&mut self, rows: Vec<Row>) -> Result<Vec<Row>> {
+        let keys = match self.keys.as_mut() {
+            Some(keys) => keys,
+            None => return Ok(rows),
+        };
+
+        let mut distinct_rows = Vec::new();
+        for row in rows {
+            let key = match &row {
+                Row::Vec { values, .. } => values
+                    .iter()
+                    .map(Key::try_from)
+                    .collect::<Result<Vec<_>>>()?,
+                Row::Map(_) => return Err(RecursiveCteError::UnionOnMapRow.into()),
+            };
+
+            if keys.insert(key) {
+                distinct_rows.push(row);
+            }
+        }
+
+        Ok(distinct_rows)
+    }
+}
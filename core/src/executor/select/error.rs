@@ -1,7 +1,16 @@
-use {serde::Serialize, std::fmt::Debug, thiserror::Error};
+use {crate::ast::SetOperator, serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum SelectError {
     #[error("VALUES lists must all be the same length")]
     NumberOfValuesDifferent,
+
+    #[error("each {0} query must have the same number of columns")]
+    NumberOfColumnsDifferent(SetOperator),
+
+    #[error("{0} is not supported for schemaless rows")]
+    SetOperationOnMapRow(SetOperator),
+
+    #[error("USING and NATURAL joins must be rewritten by the planner before they are executed")]
+    UnplannedJoinConstraint,
 }
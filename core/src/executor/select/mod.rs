@@ -1,19 +1,27 @@
 mod error;
 mod project;
+mod set_operation;
 
 pub use error::SelectError;
 
+pub(crate) use set_operation::fetch_rows;
+
 use {
-    self::project::Project,
+    self::{project::Project, set_operation::fetch_set_operation_rows},
     super::{
         aggregate::Aggregator,
         context::{AggregateContext, RowContext},
-        evaluate::evaluate_stateless,
+        cte::fetch_cte_rows,
+        evaluate::evaluate_stateless_in,
         fetch::{fetch_labels, fetch_relation_rows},
         filter::Filter,
         join::Join,
         limit::Limit,
+        profile::{instrument, measure, Profiler, Stage},
+        recursive_cte::{fetch_recursive_rows, RecursiveQuery},
         sort::Sort,
+        window::Window,
+        Session,
     },
     crate::{
         ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableWithJoins, Values},
@@ -27,7 +35,10 @@ use {
     utils::Vector,
 };
 
-async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<String>)> {
+async fn rows_with_labels(
+    session: Session<'_>,
+    exprs_list: &[Vec<Expr>],
+) -> Result<(Vec<Row>, Vec<String>)> {
     let first_len = exprs_list[0].len();
     let labels = (1..=first_len)
         .map(|i| format!("column{}", i))
@@ -45,10 +56,12 @@ async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<Str
         let mut values = Vec::with_capacity(exprs.len());
 
         for (i, expr) in exprs.iter().enumerate() {
-            let evaluated = evaluate_stateless(None, expr).await?;
+            let evaluated = evaluate_stateless_in(session, None, expr).await?;
 
             let value = match column_types[i] {
-                Some(ref data_type) => evaluated.try_into_value(data_type, true)?,
+                Some(ref data_type) => {
+                    evaluated.try_into_value(data_type, true, session.time_zone)?
+                }
                 None => {
                     let value: Value = evaluated.try_into()?;
                     column_types[i] = value.get_type();
@@ -69,19 +82,27 @@ async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<Str
     Ok((rows, labels))
 }
 
-async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<Row>> {
+async fn sort_stateless(
+    session: Session<'_>,
+    rows: Vec<Row>,
+    order_by: &[OrderByExpr],
+) -> Result<Vec<Row>> {
     let sorted = stream::iter(rows.into_iter())
         .then(|row| async move {
             stream::iter(order_by)
-                .then(|OrderByExpr { expr, asc }| {
+                .then(|order_by_expr| {
                     let row = Some(&row);
 
                     async move {
-                        evaluate_stateless(row.map(Row::as_context), expr)
-                            .await
-                            .and_then(Value::try_from)
-                            .and_then(Key::try_from)
-                            .map(|key| (key, *asc))
+                        evaluate_stateless_in(
+                            session,
+                            row.map(Row::as_context),
+                            &order_by_expr.expr,
+                        )
+                        .await
+                        .and_then(Value::try_from)
+                        .and_then(Key::try_from)
+                        .map(|key| (key, order_by_expr))
                     }
                 })
                 .try_collect::<Vec<_>>()
@@ -102,13 +123,34 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
 #[async_recursion(?Send)]
 pub async fn select_with_labels<'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     query: &'a Query,
     filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
+    select_with_profiler(storage, session, query, filter_context, None).await
+}
+
+pub async fn select_with_profiler<'a, T: GStore>(
+    storage: &'a T,
+    session: Session<'a>,
+    query: &'a Query,
+    filter_context: Option<Rc<RowContext<'a>>>,
+    profiler: Option<Rc<Profiler>>,
 ) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
     #[derive(futures_enum::Stream)]
-    enum Row<S1, S2> {
+    enum Row<S1, S2, S3> {
         Select(S2),
         Values(S1),
+        SetOperation(S3),
+    }
+
+    if !query.with.is_empty() {
+        let limit = Limit::new(None, None).await?;
+        let (labels, rows) = fetch_cte_rows(storage, session, query, filter_context).await?;
+        let rows = stream::iter(rows.into_iter().map(Ok));
+        let rows = limit.apply(rows);
+
+        return Ok((labels, Row::SetOperation(rows)));
     }
 
     let Select {
@@ -121,47 +163,109 @@ pub async fn select_with_labels<'a, T: GStore>(
         SetExpr::Select(statement) => statement.as_ref(),
         SetExpr::Values(Values(values_list)) => {
             let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
-            let (rows, labels) = rows_with_labels(values_list).await?;
-            let rows = sort_stateless(rows, &query.order_by).await?;
+            let (rows, labels) = rows_with_labels(session, values_list).await?;
+            let rows = sort_stateless(session, rows, &query.order_by).await?;
             let rows = stream::iter(rows.into_iter().map(Ok));
             let rows = limit.apply(rows);
 
             return Ok((Some(labels), Row::Values(rows)));
         }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+            let (labels, rows) =
+                fetch_set_operation_rows(storage, session, *op, *all, left, right, filter_context)
+                    .await?;
+            let rows = sort_stateless(session, rows, &query.order_by).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = limit.apply(rows);
+
+            return Ok((labels, Row::SetOperation(rows)));
+        }
+        SetExpr::Recursive {
+            alias,
+            all,
+            base,
+            step,
+            max_recursion,
+        } => {
+            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
+            let recursive_query = RecursiveQuery {
+                alias,
+                all: *all,
+                base,
+                step,
+                max_recursion: *max_recursion,
+            };
+            let (labels, rows) =
+                fetch_recursive_rows(storage, session, recursive_query, filter_context).await?;
+            let rows = sort_stateless(session, rows, &query.order_by).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = limit.apply(rows);
+
+            return Ok((labels, Row::SetOperation(rows)));
+        }
     };
 
     let TableWithJoins { relation, joins } = &table_with_joins;
-    let rows = fetch_relation_rows(storage, relation, &None)
-        .await?
-        .map(move |row| {
-            let row = row?;
-            let alias = get_alias(relation);
+    let rows = measure(
+        profiler.as_deref(),
+        Stage::Scan,
+        fetch_relation_rows(storage, session, relation, &None),
+    )
+    .await?
+    .map(move |row| {
+        let row = row?;
+        let alias = get_alias(relation);
 
-            Ok(RowContext::new(alias, Cow::Owned(row), None))
-        });
+        Ok(RowContext::new(alias, Cow::Owned(row), None))
+    });
+    let rows = instrument(profiler.as_ref().map(Rc::clone), Stage::Scan, rows);
 
-    let join = Join::new(storage, joins, filter_context.as_ref().map(Rc::clone));
+    let join = Join::new(
+        storage,
+        session,
+        relation,
+        joins,
+        filter_context.as_ref().map(Rc::clone),
+    );
     let aggregate = Aggregator::new(
         storage,
+        session,
         projection,
         group_by,
         having.as_ref(),
+        &query.order_by,
         filter_context.as_ref().map(Rc::clone),
     );
     let filter = Rc::new(Filter::new(
         storage,
+        session,
         where_clause.as_ref(),
         filter_context.as_ref().map(Rc::clone),
         None,
     ));
+    let window = Window::new(
+        storage,
+        session,
+        projection,
+        &query.order_by,
+        filter_context.as_ref().map(Rc::clone),
+    );
     let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
     let sort = Sort::new(
         storage,
+        session,
         filter_context.as_ref().map(Rc::clone),
         &query.order_by,
     );
 
-    let rows = join.apply(rows).await?;
+    let rows = measure(profiler.as_deref(), Stage::Join, join.apply(rows)).await?;
+    let rows = instrument(profiler.as_ref().map(Rc::clone), Stage::Join, rows);
     let rows = rows.try_filter_map(move |project_context| {
         let filter = Rc::clone(&filter);
 
@@ -172,14 +276,17 @@ pub async fn select_with_labels<'a, T: GStore>(
                 .map(|pass| pass.then_some(project_context))
         }
     });
+    let rows = instrument(profiler.as_ref().map(Rc::clone), Stage::Filter, rows);
 
-    let rows = aggregate.apply(rows).await?;
+    let rows = measure(profiler.as_deref(), Stage::Aggregate, aggregate.apply(rows)).await?;
+    let rows = measure(profiler.as_deref(), Stage::Aggregate, window.apply(rows)).await?;
+    let rows = instrument(profiler.as_ref().map(Rc::clone), Stage::Aggregate, rows);
 
     let labels = fetch_labels(storage, relation, joins, projection)
         .await?
         .map(Rc::from);
 
-    let project = Rc::new(Project::new(storage, filter_context, projection));
+    let project = Rc::new(Project::new(storage, session, filter_context, projection));
     let project_labels = labels.as_ref().map(Rc::clone);
     let rows = rows.and_then(move |aggregate_context| {
         let labels = project_labels.as_ref().map(Rc::clone);
@@ -195,8 +302,11 @@ pub async fn select_with_labels<'a, T: GStore>(
             Ok((aggregated, next, row))
         }
     });
+    let rows = instrument(profiler.as_ref().map(Rc::clone), Stage::Project, rows);
 
-    let rows = sort.apply(rows, get_alias(relation)).await?;
+    let sorted = sort.apply(rows, get_alias(relation));
+    let rows = measure(profiler.as_deref(), Stage::Sort, sorted).await?;
+    let rows = instrument(profiler, Stage::Sort, rows);
     let rows = limit.apply(rows);
     let labels = labels.map(|labels| labels.iter().cloned().collect());
 
@@ -205,10 +315,11 @@ pub async fn select_with_labels<'a, T: GStore>(
 
 pub async fn select<'a, T: GStore>(
     storage: &'a T,
+    session: Session<'a>,
     query: &'a Query,
     filter_context: Option<Rc<RowContext<'a>>>,
 ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
-    select_with_labels(storage, query, filter_context)
+    select_with_labels(storage, session, query, filter_context)
         .await
         .map(|(_, rows)| rows)
 }
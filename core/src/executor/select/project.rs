@@ -2,7 +2,7 @@ use {
     crate::{
         ast::{Aggregate, SelectItem},
         data::{Row, Value},
-        executor::{context::RowContext, evaluate::evaluate},
+        executor::{context::RowContext, evaluate::evaluate, Session},
         result::Result,
         store::GStore,
     },
@@ -13,6 +13,7 @@ use {
 
 pub struct Project<'a, T: GStore> {
     storage: &'a T,
+    session: Session<'a>,
     context: Option<Rc<RowContext<'a>>>,
     fields: &'a [SelectItem],
 }
@@ -20,11 +21,13 @@ pub struct Project<'a, T: GStore> {
 impl<'a, T: GStore> Project<'a, T> {
     pub fn new(
         storage: &'a T,
+        session: Session<'a>,
         context: Option<Rc<RowContext<'a>>>,
         fields: &'a [SelectItem],
     ) -> Self {
         Self {
             storage,
+            session,
             context,
             fields,
         }
@@ -58,7 +61,7 @@ impl<'a, T: GStore> Project<'a, T> {
                             Ok(context.get_alias_entries(table_alias).unwrap_or_default())
                         }
                         SelectItem::Expr { expr, label } => {
-                            evaluate(self.storage, filter_context, aggregated, expr)
+                            evaluate(self.storage, self.session, filter_context, aggregated, expr)
                                 .await
                                 .map(|evaluated| evaluated.try_into())?
                                 .map(|v| vec![(label, v)])
@@ -0,0 +1,142 @@
+use {
+    super::{select_with_labels, SelectError},
+    crate::{
+        ast::{Query, SetExpr, SetOperator},
+        data::{Key, Row},
+        executor::{context::RowContext, Session},
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::TryStreamExt,
+    std::{
+        collections::{HashMap, HashSet},
+        rc::Rc,
+    },
+};
+
+/// Runs both operands of the set operation and combines their rows.
+/// The result is labeled by the left operand.
+pub async fn fetch_set_operation_rows<'a, T: GStore>(
+    storage: &'a T,
+    session: Session<'a>,
+    op: SetOperator,
+    all: bool,
+    left: &SetExpr,
+    right: &SetExpr,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)> {
+    let (labels, left_rows) = fetch_rows(
+        storage,
+        session,
+        left,
+        filter_context.as_ref().map(Rc::clone),
+    )
+    .await?;
+    let (_, right_rows) = fetch_rows(storage, session, right, filter_context).await?;
+
+    let left_rows = left_rows
+        .into_iter()
+        .map(|row| row_key(op, &row).map(|key| (key, row)))
+        .collect::<Result<Vec<_>>>()?;
+    let right_keys = right_rows
+        .iter()
+        .map(|row| row_key(op, row))
+        .collect::<Result<Vec<_>>>()?;
+
+    if let (Some((left_key, _)), Some(right_key)) = (left_rows.first(), right_keys.first()) {
+        if left_key.len() != right_key.len() {
+            return Err(SelectError::NumberOfColumnsDifferent(op).into());
+        }
+    }
+
+    let mut counts = HashMap::<Vec<Key>, usize>::new();
+    for key in right_keys {
+        *counts.entry(key).or_default() += 1;
+    }
+
+    let mut rows = Vec::new();
+    match (op, all) {
+        (SetOperator::Except, false) => {
+            let mut emitted = HashSet::new();
+
+            for (key, row) in left_rows {
+                if !counts.contains_key(&key) && emitted.insert(key) {
+                    rows.push(row);
+                }
+            }
+        }
+        (SetOperator::Except, true) => {
+            for (key, row) in left_rows {
+                match counts.get_mut(&key) {
+                    Some(count) if *count > 0 => *count -= 1,
+                    _ => rows.push(row),
+                }
+            }
+        }
+        (SetOperator::Intersect, false) => {
+            let mut emitted = HashSet::new();
+
+            for (key, row) in left_rows {
+                if counts.contains_key(&key) && emitted.insert(key) {
+                    rows.push(row);
+                }
+            }
+        }
+        (SetOperator::Intersect, true) => {
+            for (key, row) in left_rows {
+                if let Some(count) = counts.get_mut(&key).filter(|count| **count > 0) {
+                    *count -= 1;
+                    rows.push(row);
+                }
+            }
+        }
+        (SetOperator::Union, false) => {
+            let mut emitted = HashSet::new();
+
+            for (key, row) in left_rows {
+                if emitted.insert(key) {
+                    rows.push(row);
+                }
+            }
+
+            for row in right_rows {
+                if emitted.insert(row_key(op, &row)?) {
+                    rows.push(row);
+                }
+            }
+        }
+        (SetOperator::Union, true) => {
+            rows.extend(left_rows.into_iter().map(|(_, row)| row));
+            rows.extend(right_rows);
+        }
+    }
+
+    Ok((labels, rows))
+}
+
+pub(crate) async fn fetch_rows<'a, T: GStore>(
+    storage: &'a T,
+    session: Session<'a>,
+    body: &SetExpr,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)> {
+    let query = Query {
+        with: Vec::new(),
+        body: body.clone(),
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+    };
+    let (labels, rows) = select_with_labels(storage, session, &query, filter_context).await?;
+    let rows = rows.try_collect().await?;
+
+    Ok((labels, rows))
+}
+
+/// Rows are compared by their values, so `NULL`s are regarded as the same as each other.
+fn row_key(op: SetOperator, row: &Row) -> Result<Vec<Key>> {
+    match row {
+        Row::Vec { values, .. } => values.iter().map(Key::try_from).collect(),
+        Row::Map(_) => Err(SelectError::SetOperationOnMapRow(op).into()),
+    }
+}
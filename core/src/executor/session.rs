@@ -0,0 +1,12 @@
+use crate::data::{CurrentValues, TimeZone};
+
+/// State of the session which executes a statement.
+/// It is passed down to every stage of the execution instead of being kept in the storage, so
+/// the statements of different sessions never see each other's state.
+#[derive(Clone, Copy, Debug)]
+pub struct Session<'a> {
+    /// Values returned by `NEXTVAL` or set by `SETVAL` in the session, read by `CURRVAL`
+    pub current_values: &'a CurrentValues,
+    /// Time zone in which a `TIMESTAMP` is read as a `TIMESTAMPTZ` and the other way around
+    pub time_zone: TimeZone,
+}
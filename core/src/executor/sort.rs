@@ -1,5 +1,5 @@
 use {
-    super::{context::RowContext, evaluate::evaluate},
+    super::{context::RowContext, evaluate::evaluate, Session},
     crate::{
         ast::{Aggregate, AstLiteral, Expr, OrderByExpr, UnaryOperator},
         data::{Key, Row, Value},
@@ -25,6 +25,7 @@ pub enum SortError {
 
 pub struct Sort<'a, T: GStore> {
     storage: &'a T,
+    session: Session<'a>,
     context: Option<Rc<RowContext<'a>>>,
     order_by: &'a [OrderByExpr],
 }
@@ -32,11 +33,13 @@ pub struct Sort<'a, T: GStore> {
 impl<'a, T: GStore> Sort<'a, T> {
     pub fn new(
         storage: &'a T,
+        session: Session<'a>,
         context: Option<Rc<RowContext<'a>>>,
         order_by: &'a [OrderByExpr],
     ) -> Self {
         Self {
             storage,
+            session,
             context,
             order_by,
         }
@@ -75,7 +78,8 @@ impl<'a, T: GStore> Sort<'a, T> {
                 let order_by = self.order_by;
                 let order_by = order_by
                     .iter()
-                    .map(|OrderByExpr { expr, asc }| -> Result<_> {
+                    .map(|order_by_expr| -> Result<_> {
+                        let OrderByExpr { expr, .. } = order_by_expr;
                         let big_decimal = match expr {
                             Expr::Literal(AstLiteral::Number(n)) => Some(n),
                             Expr::UnaryOp {
@@ -100,9 +104,9 @@ impl<'a, T: GStore> Sort<'a, T> {
                                     SortError::ColumnIndexOutOfRange(index).into()
                                 })?;
 
-                                Ok((SortType::Value(value.clone()), *asc))
+                                Ok((SortType::Value(value.clone()), order_by_expr))
                             }
-                            _ => Ok((SortType::Expr(expr), *asc)),
+                            _ => Ok((SortType::Expr(expr), order_by_expr)),
                         }
                     })
                     .collect::<Result<Vec<_>>>();
@@ -124,21 +128,25 @@ impl<'a, T: GStore> Sort<'a, T> {
 
                     let keys = order_by
                         .map(stream::iter)?
-                        .then(|(sort_type, asc)| {
+                        .then(|(sort_type, order_by_expr)| {
                             let context = Some(Rc::clone(&filter_context));
                             let aggregated = aggregated.as_ref().map(Rc::clone);
 
                             async move {
                                 match sort_type {
                                     SortType::Value(value) => value,
-                                    SortType::Expr(expr) => {
-                                        evaluate(self.storage, context, aggregated, expr)
-                                            .await?
-                                            .try_into()?
-                                    }
+                                    SortType::Expr(expr) => evaluate(
+                                        self.storage,
+                                        self.session,
+                                        context,
+                                        aggregated,
+                                        expr,
+                                    )
+                                    .await?
+                                    .try_into()?,
                                 }
                                 .try_into()
-                                .map(|key| (key, asc))
+                                .map(|key| (key, order_by_expr))
                             }
                         })
                         .try_collect::<Vec<_>>()
@@ -150,7 +158,7 @@ impl<'a, T: GStore> Sort<'a, T> {
                     Ok((keys, row))
                 }
             })
-            .try_collect::<Vec<(Vec<(Key, Option<bool>)>, Row)>>()
+            .try_collect::<Vec<(Vec<(Key, &OrderByExpr)>, Row)>>()
             .await
             .map(Vector::from)?
             .sort_by(|(keys_a, ..), (keys_b, ..)| sort_by(keys_a, keys_b))
@@ -161,18 +169,25 @@ impl<'a, T: GStore> Sort<'a, T> {
     }
 }
 
-pub fn sort_by(keys_a: &[(Key, Option<bool>)], keys_b: &[(Key, Option<bool>)]) -> Ordering {
-    let pairs = keys_a
-        .iter()
-        .map(|(a, _)| a)
-        .zip(keys_b.iter())
-        .map(|(a, (b, asc))| (a, b, asc.unwrap_or(true)));
-
-    for (key_a, key_b, asc) in pairs {
-        match (key_a.cmp(key_b), asc) {
-            (Ordering::Equal, _) => continue,
-            (ord, true) => return ord,
-            (ord, false) => return ord.reverse(),
+/// Compares two lists of sort keys, NULLs come last in ascending order and first in descending
+/// order unless `NULLS FIRST` or `NULLS LAST` is given.
+pub fn sort_by(keys_a: &[(Key, &OrderByExpr)], keys_b: &[(Key, &OrderByExpr)]) -> Ordering {
+    for ((key_a, _), (key_b, order_by_expr)) in keys_a.iter().zip(keys_b) {
+        let asc = order_by_expr.asc.unwrap_or(true);
+        let nulls_first = order_by_expr.nulls_first.unwrap_or(!asc);
+
+        let ord = match (key_a, key_b) {
+            (Key::None, Key::None) => Ordering::Equal,
+            (Key::None, _) if nulls_first => Ordering::Less,
+            (Key::None, _) => Ordering::Greater,
+            (_, Key::None) if nulls_first => Ordering::Greater,
+            (_, Key::None) => Ordering::Less,
+            _ if asc => key_a.cmp(key_b),
+            _ => key_a.cmp(key_b).reverse(),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
         }
     }
 
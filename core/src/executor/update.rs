@@ -2,9 +2,10 @@ use {
     super::{
         context::RowContext,
         evaluate::{evaluate, Evaluated},
+        Session,
     },
     crate::{
-        ast::{Assignment, ColumnDef, ColumnUniqueOption},
+        ast::{Assignment, ColumnDef, ColumnUniqueOption, Expr},
         data::{Row, Value},
         result::{Error, Result},
         store::GStore,
@@ -26,11 +27,18 @@ pub enum UpdateError {
 
     #[error("conflict on schema, row data does not fit to schema")]
     ConflictOnSchema,
+
+    #[error("only UPDATE statements can be executed with a version check")]
+    UpdateStatementRequired,
+
+    #[error("optimistic lock conflict: rows of {0} were updated concurrently")]
+    OptimisticLockConflict(String),
 }
 
 pub struct Update<'a, T: GStore> {
     storage: &'a T,
-    table_name: &'a str,
+    session: Session<'a>,
+    table_alias: &'a str,
     fields: &'a [Assignment],
     column_defs: Option<&'a [ColumnDef]>,
 }
@@ -38,7 +46,8 @@ pub struct Update<'a, T: GStore> {
 impl<'a, T: GStore> Update<'a, T> {
     pub fn new(
         storage: &'a T,
-        table_name: &'a str,
+        session: Session<'a>,
+        table_alias: &'a str,
         fields: &'a [Assignment],
         column_defs: Option<&'a [ColumnDef]>,
     ) -> Result<Self> {
@@ -58,14 +67,25 @@ impl<'a, T: GStore> Update<'a, T> {
 
         Ok(Self {
             storage,
-            table_name,
+            session,
+            table_alias,
             fields,
             column_defs,
         })
     }
 
     pub async fn apply(&self, row: Row) -> Result<Row> {
-        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), None);
+        self.apply_with_context(row, None).await
+    }
+
+    /// Columns which are not found in `row` are looked up in `next`,
+    /// e.g. `EXCLUDED` of `INSERT ... ON CONFLICT DO UPDATE`
+    pub async fn apply_with_context(
+        &self,
+        row: Row,
+        next: Option<Rc<RowContext<'_>>>,
+    ) -> Result<Row> {
+        let context = RowContext::new(self.table_alias, Cow::Borrowed(&row), next);
         let context = Some(Rc::new(context));
 
         let assignments = stream::iter(self.fields.iter())
@@ -77,28 +97,46 @@ impl<'a, T: GStore> Update<'a, T> {
                 let context = context.as_ref().map(Rc::clone);
 
                 async move {
-                    let evaluated = evaluate(self.storage, context, None, value_expr).await?;
-                    let value = match self.column_defs {
-                        Some(column_defs) => {
-                            let ColumnDef {
-                                data_type,
-                                nullable,
-                                ..
-                            } = column_defs
+                    let column_def = self
+                        .column_defs
+                        .map(|column_defs| {
+                            column_defs
                                 .iter()
                                 .find(|column_def| id == &column_def.name)
-                                .ok_or(UpdateError::ConflictOnSchema)?;
-
+                                .ok_or(UpdateError::ConflictOnSchema)
+                        })
+                        .transpose()?;
+
+                    let evaluated = match (value_expr, column_def) {
+                        (
+                            Expr::Default,
+                            Some(ColumnDef {
+                                default: Some(default),
+                                ..
+                            }),
+                        ) => evaluate(self.storage, self.session, context, None, default).await?,
+                        (Expr::Default, Some(ColumnDef { default: None, .. })) => {
+                            Evaluated::from(Value::Null)
+                        }
+                        _ => {
+                            evaluate(self.storage, self.session, context, None, value_expr).await?
+                        }
+                    };
+                    let value = match column_def {
+                        Some(ColumnDef {
+                            data_type,
+                            nullable,
+                            ..
+                        }) => {
                             let value = match evaluated {
-                                Evaluated::Literal(v) => Value::try_from_literal(data_type, &v)?,
-                                Evaluated::Value(v) => {
-                                    v.validate_type(data_type)?;
-                                    v
+                                Evaluated::Literal(v) => {
+                                    Value::try_from_literal(data_type, &v, self.session.time_zone)?
                                 }
+                                Evaluated::Value(v) => v.into_column_value(data_type)?,
                                 Evaluated::StrSlice {
                                     source: s,
                                     range: r,
-                                } => Value::Str(s[r].to_owned()),
+                                } => Value::Str(s[r].to_owned()).into_column_value(data_type)?,
                             };
 
                             value.validate_null(*nullable)?;
@@ -1,7 +1,8 @@
 use {
+    super::{context::RowContext, evaluate::evaluate_stateless},
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption},
-        data::{Key, Value},
+        ast::{ColumnDef, ColumnUniqueOption, Expr},
+        data::{Key, SchemaIndex, Value},
         result::Result,
         store::{DataRow, Store},
     },
@@ -25,6 +26,9 @@ pub enum ValidateError {
 
     #[error("duplicate entry '{0:?}' for primary_key field")]
     DuplicateEntryOnPrimaryKeyField(Key),
+
+    #[error("duplicate entry '{}' for unique index '{index}'", String::from(value))]
+    UniqueConstraintViolation { index: String, value: Value },
 }
 
 pub enum ColumnValidation<'column_def> {
@@ -81,6 +85,168 @@ impl UniqueConstraint {
     }
 }
 
+/// Values of the unique indexes of the rows seen so far.
+/// A value containing `NULL` never conflicts with the other values.
+struct UniqueIndexes<'a> {
+    columns: &'a [String],
+    indexes: Vec<(&'a str, &'a Expr, HashSet<Vec<Key>>)>,
+}
+
+impl<'a> UniqueIndexes<'a> {
+    fn new(columns: &'a [String], indexes: impl Iterator<Item = (&'a str, &'a Expr)>) -> Self {
+        let indexes = indexes
+            .map(|(name, expr)| (name, expr, HashSet::new()))
+            .collect();
+
+        Self { columns, indexes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+
+    async fn values(&self, values: &[Value]) -> Result<Vec<(Value, Option<Vec<Key>>)>> {
+        let mut index_values = Vec::with_capacity(self.indexes.len());
+        for (_, expr, _) in self.indexes.iter() {
+            let context = RowContext::RefVecData {
+                columns: self.columns,
+                values,
+            };
+            let value: Value = evaluate_stateless(Some(context), expr).await?.try_into()?;
+            let items = match &value {
+                Value::List(items) => items.as_slice(),
+                value => std::slice::from_ref(value),
+            };
+            let key = match items.iter().any(Value::is_null) {
+                true => None,
+                false => Some(items.iter().map(Key::try_from).collect::<Result<_>>()?),
+            };
+
+            index_values.push((value, key));
+        }
+
+        Ok(index_values)
+    }
+
+    /// Returns the name of the index which already has the value and the value
+    fn conflict<'b>(
+        &self,
+        index_values: &'b [(Value, Option<Vec<Key>>)],
+    ) -> Option<(&'a str, &'b Value)> {
+        self.indexes.iter().zip(index_values).find_map(
+            |((name, _, keys), (value, key))| match key {
+                Some(key) if keys.contains(key) => Some((*name, value)),
+                _ => None,
+            },
+        )
+    }
+
+    fn insert(&mut self, index_values: Vec<(Value, Option<Vec<Key>>)>) {
+        for ((_, _, keys), (_, key)) in self.indexes.iter_mut().zip(index_values) {
+            if let Some(key) = key {
+                keys.insert(key);
+            }
+        }
+    }
+
+    async fn add(&mut self, values: &[Value]) -> Result<()> {
+        let index_values = self.values(values).await?;
+        if let Some((index, value)) = self.conflict(&index_values) {
+            return Err(ValidateError::UniqueConstraintViolation {
+                index: index.to_owned(),
+                value: value.clone(),
+            }
+            .into());
+        }
+
+        self.insert(index_values);
+
+        Ok(())
+    }
+
+    /// Adds the stored rows except the rows replaced by the given keys
+    async fn add_stored<T: Store>(
+        &mut self,
+        storage: &T,
+        table_name: &str,
+        replaced: &HashSet<Key>,
+    ) -> Result<()> {
+        for result in storage.scan_data(table_name).await? {
+            let (key, data_row) = result?;
+            if replaced.contains(&key) {
+                continue;
+            }
+
+            match data_row {
+                DataRow::Vec(values) => self.add(&values).await?,
+                DataRow::Map(_) => {
+                    return Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn unique_schema_indexes(indexes: &[SchemaIndex]) -> impl Iterator<Item = (&str, &Expr)> {
+    indexes
+        .iter()
+        .filter(|SchemaIndex { unique, .. }| *unique)
+        .map(|SchemaIndex { name, expr, .. }| (name.as_str(), expr))
+}
+
+fn column_names(column_defs: &[ColumnDef]) -> Vec<String> {
+    column_defs
+        .iter()
+        .map(|ColumnDef { name, .. }| name.to_owned())
+        .collect()
+}
+
+/// Checks that the rows to write do not duplicate a value of the unique indexes, neither among
+/// themselves nor with the stored rows. A row with a key replaces the stored row of the key.
+pub async fn validate_unique_indexes<'a, T: Store>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    indexes: &[SchemaIndex],
+    rows: impl Iterator<Item = (Option<&'a Key>, &'a [Value])>,
+) -> Result<()> {
+    let columns = column_names(column_defs);
+    let mut unique_indexes = UniqueIndexes::new(&columns, unique_schema_indexes(indexes));
+    if unique_indexes.is_empty() {
+        return Ok(());
+    }
+
+    let mut replaced = HashSet::new();
+    for (key, values) in rows {
+        if let Some(key) = key {
+            replaced.insert(key.clone());
+        }
+
+        unique_indexes.add(values).await?;
+    }
+
+    unique_indexes
+        .add_stored(storage, table_name, &replaced)
+        .await
+}
+
+/// Checks that the stored rows have no duplicate value of the unique index to create
+pub async fn validate_unique_index<T: Store>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    index_name: &str,
+    expr: &Expr,
+) -> Result<()> {
+    let columns = column_names(column_defs);
+
+    UniqueIndexes::new(&columns, std::iter::once((index_name, expr)))
+        .add_stored(storage, table_name, &HashSet::new())
+        .await
+}
+
 pub async fn validate_unique<T: Store>(
     storage: &T,
     table_name: &str,
@@ -165,6 +331,105 @@ pub async fn validate_unique<T: Store>(
     }
 }
 
+/// Drops the rows which conflict on a unique or primary key column, either with the stored rows
+/// or with the rows kept before them. Used by `INSERT ... ON CONFLICT DO NOTHING`.
+pub async fn filter_unique<T: Store>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    indexes: &[SchemaIndex],
+    rows: Vec<Vec<Value>>,
+) -> Result<Vec<Vec<Value>>> {
+    let primary_key_index = column_defs.iter().position(|ColumnDef { unique, .. }| {
+        unique == &Some(ColumnUniqueOption { is_primary: true })
+    });
+    let unique_indexes = column_defs
+        .iter()
+        .enumerate()
+        .filter(|(_, ColumnDef { unique, .. })| {
+            unique == &Some(ColumnUniqueOption { is_primary: false })
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let columns = column_names(column_defs);
+    let mut schema_indexes = UniqueIndexes::new(&columns, unique_schema_indexes(indexes));
+
+    let mut unique_keys = vec![HashSet::<Key>::new(); unique_indexes.len()];
+    if !unique_indexes.is_empty() || !schema_indexes.is_empty() {
+        for result in storage.scan_data(table_name).await? {
+            let (_, data_row) = result?;
+            let values = match data_row {
+                DataRow::Vec(values) => values,
+                DataRow::Map(_) => {
+                    return Err(ValidateError::ConflictOnUnexpectedSchemalessRowFound.into());
+                }
+            };
+
+            for (keys, i) in unique_keys.iter_mut().zip(&unique_indexes) {
+                keys.insert(get_key(&values, *i)?);
+            }
+
+            let index_values = schema_indexes.values(&values).await?;
+            schema_indexes.insert(index_values);
+        }
+    }
+
+    let mut primary_keys = HashSet::new();
+    let mut filtered = Vec::with_capacity(rows.len());
+    for values in rows {
+        let primary_key = match primary_key_index {
+            Some(i) => {
+                let key = get_key(&values, i)?;
+                if primary_keys.contains(&key)
+                    || storage.fetch_data(table_name, &key).await?.is_some()
+                {
+                    continue;
+                }
+
+                Some(key)
+            }
+            None => None,
+        };
+
+        let keys = unique_indexes
+            .iter()
+            .map(|i| get_key(&values, *i))
+            .collect::<Result<Vec<_>>>()?;
+        let conflict = keys
+            .iter()
+            .zip(&unique_keys)
+            .any(|(key, unique_keys)| key != &Key::None && unique_keys.contains(key));
+        if conflict {
+            continue;
+        }
+
+        let index_values = schema_indexes.values(&values).await?;
+        if schema_indexes.conflict(&index_values).is_some() {
+            continue;
+        }
+
+        if let Some(primary_key) = primary_key {
+            primary_keys.insert(primary_key);
+        }
+        for (key, unique_keys) in keys.into_iter().zip(unique_keys.iter_mut()) {
+            unique_keys.insert(key);
+        }
+        schema_indexes.insert(index_values);
+        filtered.push(values);
+    }
+
+    Ok(filtered)
+}
+
+fn get_key(values: &[Value], index: usize) -> Result<Key> {
+    let value = values
+        .get(index)
+        .ok_or(ValidateError::ConflictOnStorageColumnIndex(index))?;
+
+    Key::try_from(value)
+}
+
 fn create_unique_constraints<'a>(
     unique_columns: Vec<(usize, String)>,
     row_iter: impl Iterator<Item = &'a [Value]> + Clone,
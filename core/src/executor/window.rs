@@ -0,0 +1,286 @@
+use {
+    super::{
+        aggregate::State,
+        context::{AggregateContext, RowContext},
+        evaluate::{evaluate, EvaluateError},
+        sort::sort_by,
+        Session,
+    },
+    crate::{
+        ast::{self, Expr, OrderByExpr, SelectItem, WindowFunction},
+        data::{Key, Value},
+        plan::PlanExpr,
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, Stream, TryStreamExt},
+    std::{cmp::Ordering, collections::HashMap, rc::Rc},
+};
+
+pub struct Window<'a, T: GStore> {
+    storage: &'a T,
+    session: Session<'a>,
+    fields: &'a [SelectItem],
+    order_by: &'a [OrderByExpr],
+    filter_context: Option<Rc<RowContext<'a>>>,
+}
+
+impl<'a, T: GStore> Window<'a, T> {
+    pub fn new(
+        storage: &'a T,
+        session: Session<'a>,
+        fields: &'a [SelectItem],
+        order_by: &'a [OrderByExpr],
+        filter_context: Option<Rc<RowContext<'a>>>,
+    ) -> Self {
+        Self {
+            storage,
+            session,
+            fields,
+            order_by,
+            filter_context,
+        }
+    }
+
+    pub async fn apply(
+        &self,
+        rows: impl Stream<Item = Result<AggregateContext<'a>>> + 'a,
+    ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>> + 'a> {
+        #[derive(futures_enum::Stream)]
+        enum S<S1, S2> {
+            NonWindow(S1),
+            Window(S2),
+        }
+
+        let fields = self.fields.iter().filter_map(|field| match field {
+            SelectItem::Expr { expr, .. } => Some(expr),
+            _ => None,
+        });
+        let order_by = self.order_by.iter().map(|OrderByExpr { expr, .. }| expr);
+        let windows =
+            fields
+                .chain(order_by)
+                .flat_map(get_windows)
+                .fold(Vec::new(), |mut windows, window| {
+                    if !windows.contains(&window) {
+                        windows.push(window);
+                    }
+
+                    windows
+                });
+
+        if windows.is_empty() {
+            return Ok(S::NonWindow(rows));
+        }
+
+        let rows = rows.try_collect::<Vec<_>>().await?;
+        let mut values = vec![HashMap::new(); rows.len()];
+
+        for window in windows {
+            let window_values = self.evaluate(window, &rows).await?;
+
+            for (values, value) in values.iter_mut().zip(window_values) {
+                values.insert(window, value);
+            }
+        }
+
+        let rows = rows.into_iter().zip(values).map(
+            move |(AggregateContext { aggregated, next }, values)| {
+                let next = Rc::new(RowContext::Window { values, next });
+
+                Ok(AggregateContext { aggregated, next })
+            },
+        );
+
+        Ok(S::Window(stream::iter(rows)))
+    }
+
+    /// Rows are split into partitions by the PARTITION BY of the window, and each partition is
+    /// sorted by the ORDER BY of the window. Rows in a partition whose sort keys are all equal
+    /// are peers. Aggregates cover every row of the partition up to the last peer of the current
+    /// row, so a window without ORDER BY aggregates the whole partition.
+    async fn evaluate(
+        &self,
+        window: &'a ast::Window,
+        rows: &[AggregateContext<'a>],
+    ) -> Result<Vec<Value>> {
+        let contexts = rows
+            .iter()
+            .map(|AggregateContext { next, .. }| match &self.filter_context {
+                Some(filter_context) => Rc::new(RowContext::concat(
+                    Rc::clone(next),
+                    Rc::clone(filter_context),
+                )),
+                None => Rc::clone(next),
+            })
+            .collect::<Vec<_>>();
+
+        let mut partitions: HashMap<Vec<Key>, Vec<usize>> = HashMap::new();
+        let mut keys = Vec::with_capacity(rows.len());
+        for (index, context) in contexts.iter().enumerate() {
+            let mut partition_key = Vec::with_capacity(window.partition_by.len());
+            for expr in &window.partition_by {
+                let value = self.evaluate_value(context, expr).await?;
+
+                partition_key.push(Key::try_from(value)?);
+            }
+
+            partitions.entry(partition_key).or_default().push(index);
+
+            let mut row_keys = Vec::with_capacity(window.order_by.len());
+            for order_by_expr in &window.order_by {
+                let value = self.evaluate_value(context, &order_by_expr.expr).await?;
+
+                row_keys.push((Key::try_from(value)?, order_by_expr));
+            }
+
+            keys.push(row_keys);
+        }
+
+        let mut values = vec![Value::Null; rows.len()];
+
+        for mut indexes in partitions.into_values() {
+            indexes.sort_by(|a, b| sort_by(&keys[*a], &keys[*b]));
+
+            let mut peers: Vec<Vec<usize>> = Vec::new();
+            for index in indexes.iter().copied() {
+                match peers.last_mut() {
+                    Some(group) if sort_by(&keys[group[0]], &keys[index]) == Ordering::Equal => {
+                        group.push(index);
+                    }
+                    _ => peers.push(vec![index]),
+                }
+            }
+
+            match &window.function {
+                WindowFunction::Aggregate(aggregate) => {
+                    let mut state = State::new(self.storage, self.session);
+                    let mut position = 0;
+
+                    for group in &peers {
+                        for index in group.iter().copied() {
+                            let context = Some(Rc::clone(&contexts[index]));
+
+                            state = state
+                                .apply(position, Vec::new(), Rc::clone(&rows[index].next))
+                                .accumulate(context, aggregate)
+                                .await?;
+                            position += 1;
+                        }
+
+                        let value = state.peek(aggregate).await?;
+                        for index in group.iter().copied() {
+                            values[index] = value.clone();
+                        }
+                    }
+                }
+                WindowFunction::RowNumber => {
+                    for (number, index) in indexes.iter().copied().enumerate() {
+                        values[index] = Value::I64(number as i64 + 1);
+                    }
+                }
+                WindowFunction::Rank => {
+                    let mut rank = 1;
+
+                    for group in &peers {
+                        for index in group.iter().copied() {
+                            values[index] = Value::I64(rank);
+                        }
+
+                        rank += group.len() as i64;
+                    }
+                }
+                WindowFunction::DenseRank => {
+                    for (rank, group) in peers.iter().enumerate() {
+                        for index in group.iter().copied() {
+                            values[index] = Value::I64(rank as i64 + 1);
+                        }
+                    }
+                }
+                WindowFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                }
+                | WindowFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                } => {
+                    let lag = matches!(window.function, WindowFunction::Lag { .. });
+                    let name = if lag { "LAG" } else { "LEAD" };
+
+                    for (position, index) in indexes.iter().copied().enumerate() {
+                        let context = &contexts[index];
+                        let offset = match offset {
+                            Some(offset) => match self.evaluate_value(context, offset).await? {
+                                Value::I64(offset) => usize::try_from(offset).map_err(|_| {
+                                    EvaluateError::FunctionRequiresUSizeValue(name.to_owned())
+                                })?,
+                                Value::Null => continue,
+                                _ => {
+                                    return Err(EvaluateError::FunctionRequiresIntegerValue(
+                                        name.to_owned(),
+                                    )
+                                    .into());
+                                }
+                            },
+                            None => 1,
+                        };
+                        let target = if lag {
+                            position.checked_sub(offset)
+                        } else {
+                            position.checked_add(offset)
+                        }
+                        .and_then(|target| indexes.get(target));
+
+                        values[index] = match (target, default) {
+                            (Some(target), _) => {
+                                self.evaluate_value(&contexts[*target], expr).await?
+                            }
+                            (None, Some(default)) => self.evaluate_value(context, default).await?,
+                            (None, None) => Value::Null,
+                        };
+                    }
+                }
+            }
+        }
+
+        Ok(values)
+    }
+
+    async fn evaluate_value(&self, context: &Rc<RowContext<'a>>, expr: &'a Expr) -> Result<Value> {
+        evaluate(
+            self.storage,
+            self.session,
+            Some(Rc::clone(context)),
+            None,
+            expr,
+        )
+        .await?
+        .try_into()
+    }
+}
+
+/// Windows of a subquery are left to the select of the subquery.
+fn get_windows(expr: &Expr) -> Vec<&ast::Window> {
+    if let Expr::Window(window) = expr {
+        return vec![window.as_ref()];
+    }
+
+    match PlanExpr::from(expr) {
+        PlanExpr::None
+        | PlanExpr::Identifier(_)
+        | PlanExpr::CompoundIdentifier { .. }
+        | PlanExpr::Query(_) => Vec::new(),
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => get_windows(expr),
+        PlanExpr::TwoExprs(expr, expr2) => {
+            [expr, expr2].into_iter().flat_map(get_windows).collect()
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => [expr, expr2, expr3]
+            .into_iter()
+            .flat_map(get_windows)
+            .collect(),
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().flat_map(get_windows).collect(),
+    }
+}
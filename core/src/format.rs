@@ -0,0 +1,352 @@
+use {
+    crate::{
+        data::Value,
+        executor::{Payload, PayloadVariable},
+        result::Result,
+    },
+    serde::Serialize,
+    serde_json::{Map as JsonMap, Value as JsonValue},
+    std::{
+        collections::{BTreeSet, HashSet},
+        fmt::Debug,
+    },
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum FormatError {
+    #[error("failed to serialize result to json: {0}")]
+    JsonSerializationFailed(String),
+}
+
+/// Serializes the payload of a statement, e.g. to return query results from a web server.
+pub trait ResultFormatter {
+    fn format(&self, payload: &Payload) -> Result<Vec<u8>>;
+}
+
+/// Formats rows as a JSON array of objects keyed by column labels.
+/// Duplicate labels are numbered, e.g. `id`, `id_1`, `id_2`, so no column is dropped.
+pub struct JsonFormatter;
+
+/// Formats rows as RFC 4180 CSV with a header record.
+pub struct CsvFormatter;
+
+/// Formats rows as a bordered text table.
+pub struct TableFormatter {
+    pub style: TableStyle,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableStyle {
+    Ascii,
+    Unicode,
+}
+
+impl ResultFormatter for JsonFormatter {
+    fn format(&self, payload: &Payload) -> Result<Vec<u8>> {
+        let (labels, rows) = tabulate(payload);
+        let labels = unique_labels(labels);
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                labels
+                    .iter()
+                    .cloned()
+                    .zip(row)
+                    .map(|(label, value)| JsonValue::try_from(value).map(|value| (label, value)))
+                    .collect::<Result<JsonMap<_, _>>>()
+                    .map(JsonValue::Object)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        serde_json::to_vec(&JsonValue::Array(rows))
+            .map_err(|e| FormatError::JsonSerializationFailed(e.to_string()).into())
+    }
+}
+
+fn unique_labels(labels: Vec<String>) -> Vec<String> {
+    let mut used = labels.iter().cloned().collect::<HashSet<_>>();
+    let mut seen = HashSet::new();
+
+    labels
+        .into_iter()
+        .map(|label| {
+            if seen.insert(label.clone()) {
+                return label;
+            }
+
+            let unique = (1..)
+                .map(|n| format!("{label}_{n}"))
+                .find(|candidate| !used.contains(candidate))
+                .unwrap_or_default();
+            used.insert(unique.clone());
+
+            unique
+        })
+        .collect()
+}
+
+impl ResultFormatter for CsvFormatter {
+    fn format(&self, payload: &Payload) -> Result<Vec<u8>> {
+        fn escape(field: &str) -> String {
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        }
+
+        let (labels, rows) = tabulate(payload);
+        if labels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let header = labels.iter().map(|label| escape(label)).collect::<Vec<_>>();
+        let records = rows.iter().map(|row| {
+            row.iter()
+                .map(|value| match value {
+                    Value::Null => String::new(),
+                    _ => escape(&String::from(value)),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let csv = std::iter::once(header)
+            .chain(records)
+            .map(|record| format!("{}\r\n", record.join(",")))
+            .collect::<String>();
+
+        Ok(csv.into_bytes())
+    }
+}
+
+impl ResultFormatter for TableFormatter {
+    fn format(&self, payload: &Payload) -> Result<Vec<u8>> {
+        let (labels, rows) = tabulate(payload);
+        if labels.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = rows
+            .iter()
+            .map(|row| row.iter().map(String::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let widths = labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .chain([label.chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let (top, middle, bottom, vertical) = match self.style {
+            TableStyle::Ascii => (
+                ['+', '+', '+', '-'],
+                ['+', '+', '+', '-'],
+                ['+', '+', '+', '-'],
+                '|',
+            ),
+            TableStyle::Unicode => (
+                ['┌', '┬', '┐', '─'],
+                ['├', '┼', '┤', '─'],
+                ['└', '┴', '┘', '─'],
+                '│',
+            ),
+        };
+
+        let border = |[left, cross, right, line]: [char; 4]| {
+            let cells = widths
+                .iter()
+                .map(|width| line.to_string().repeat(width + 2))
+                .collect::<Vec<_>>()
+                .join(&cross.to_string());
+
+            format!("{left}{cells}{right}\n")
+        };
+        let record = |cells: &[String]| {
+            let cells = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| {
+                    let padding = " ".repeat(width - cell.chars().count());
+
+                    format!(" {cell}{padding} ")
+                })
+                .collect::<Vec<_>>()
+                .join(&vertical.to_string());
+
+            format!("{vertical}{cells}{vertical}\n")
+        };
+
+        let mut table = border(top);
+        table += &record(&labels);
+        table += &border(middle);
+        for row in rows.iter() {
+            table += &record(row);
+        }
+        table += &border(bottom);
+
+        Ok(table.into_bytes())
+    }
+}
+
+/// Converts the payload into column labels and rows; statements which return no rows, such as
+/// `CREATE TABLE`, produce no labels.
+fn tabulate(payload: &Payload) -> (Vec<String>, Vec<Vec<Value>>) {
+    let single_column = |label: &str, values: Vec<Value>| -> (Vec<String>, Vec<Vec<Value>>) {
+        let rows = values.into_iter().map(|value| vec![value]).collect();
+
+        (vec![label.to_owned()], rows)
+    };
+
+    match payload {
+        Payload::Select { labels, rows } => (labels.clone(), rows.clone()),
+        Payload::SelectMap(rows) => {
+            let labels = rows
+                .iter()
+                .flat_map(|row| row.keys().cloned())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+            let rows: Vec<Vec<Value>> = rows
+                .iter()
+                .map(|row| {
+                    labels
+                        .iter()
+                        .map(|label| row.get(label).cloned().unwrap_or(Value::Null))
+                        .collect()
+                })
+                .collect();
+
+            (labels, rows)
+        }
+        Payload::ShowColumns(columns) => {
+            let labels = vec!["Field".to_owned(), "Type".to_owned()];
+            let rows: Vec<Vec<Value>> = columns
+                .iter()
+                .map(|(field, data_type)| {
+                    vec![
+                        Value::Str(field.to_owned()),
+                        Value::Str(data_type.to_string()),
+                    ]
+                })
+                .collect();
+
+            (labels, rows)
+        }
+        Payload::ShowVariable(PayloadVariable::Tables(names)) => {
+            single_column("tables", names.iter().cloned().map(Value::Str).collect())
+        }
+        Payload::ShowVariable(PayloadVariable::Functions(names)) => {
+            single_column("functions", names.iter().cloned().map(Value::Str).collect())
+        }
+        Payload::ShowVariable(PayloadVariable::Version(version)) => {
+            single_column("version", vec![Value::Str(version.to_owned())])
+        }
+        Payload::Insert(n) | Payload::Delete(n) | Payload::Update(n) => {
+            single_column("affected", vec![Value::I64(*n as i64)])
+        }
+        Payload::Create
+        | Payload::DropTable
+        | Payload::DropFunction
+        | Payload::DropSequence
+        | Payload::DropType
+        | Payload::DropView
+        | Payload::AlterTable
+        | Payload::AlterType
+        | Payload::CreateIndex
+        | Payload::DropIndex
+        | Payload::Analyze
+        | Payload::StartTransaction
+        | Payload::SetTransaction
+        | Payload::Commit
+        | Payload::Rollback => (Vec::new(), Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{CsvFormatter, JsonFormatter, ResultFormatter, TableFormatter, TableStyle},
+        crate::{data::Value, executor::Payload},
+    };
+
+    fn payload() -> Payload {
+        Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: vec![
+                vec![Value::I64(1), Value::Str("Glue".to_owned())],
+                vec![Value::I64(2), Value::Str("Hello, \"SQL\"".to_owned())],
+                vec![Value::I64(3), Value::Null],
+            ],
+        }
+    }
+
+    fn format(formatter: &dyn ResultFormatter, payload: &Payload) -> String {
+        String::from_utf8(formatter.format(payload).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn json() {
+        let actual = format(&JsonFormatter, &payload());
+        let expected =
+            r#"[{"id":1,"name":"Glue"},{"id":2,"name":"Hello, \"SQL\""},{"id":3,"name":null}]"#;
+        assert_eq!(actual, expected);
+
+        let actual = format(&JsonFormatter, &Payload::Insert(3));
+        assert_eq!(actual, r#"[{"affected":3}]"#);
+
+        let payload = Payload::Select {
+            labels: vec!["id".to_owned(), "id".to_owned(), "id_1".to_owned()],
+            rows: vec![vec![Value::I64(1), Value::I64(2), Value::I64(3)]],
+        };
+        let actual: serde_json::Value =
+            serde_json::from_slice(&JsonFormatter.format(&payload).unwrap()).unwrap();
+        assert_eq!(actual, serde_json::json!([{"id": 1, "id_1": 3, "id_2": 2}]));
+    }
+
+    #[test]
+    fn csv() {
+        let actual = format(&CsvFormatter, &payload());
+        let expected = "id,name\r\n1,Glue\r\n2,\"Hello, \"\"SQL\"\"\"\r\n3,\r\n";
+        assert_eq!(actual, expected);
+
+        let actual = format(&CsvFormatter, &Payload::Create);
+        assert_eq!(actual, "");
+    }
+
+    #[test]
+    fn table() {
+        let formatter = TableFormatter {
+            style: TableStyle::Ascii,
+        };
+        let actual = format(&formatter, &payload());
+        let expected = "
++----+--------------+
+| id | name         |
++----+--------------+
+| 1  | Glue         |
+| 2  | Hello, \"SQL\" |
+| 3  | NULL         |
++----+--------------+
+";
+        assert_eq!(actual, expected.trim_start());
+
+        let formatter = TableFormatter {
+            style: TableStyle::Unicode,
+        };
+        let actual = format(&formatter, &Payload::Delete(1));
+        let expected = "
+┌──────────┐
+│ affected │
+├──────────┤
+│ 1        │
+└──────────┘
+";
+        assert_eq!(actual, expected.trim_start());
+    }
+}
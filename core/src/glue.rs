@@ -1,40 +1,436 @@
 use {
     crate::{
-        ast::Statement,
-        executor::{execute, Payload},
-        parse_sql::parse,
-        plan::plan,
-        result::Result,
+        ast::{
+            Aggregate, Assignment, AstLiteral, BinaryOperator, CountArgExpr, Expr, Query, Select,
+            SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Values,
+        },
+        data::{
+            schemas_from_toml, CurrentValues, Row, Schema, SchemaChange, SchemaParseError,
+            TimeZone, Value,
+        },
+        executor::{
+            assert_read_only, execute_versioned_update, execute_with_changes, execute_with_option,
+            execute_with_profile, select, EvaluateError, ExecuteError, ExecuteOption, InsertError,
+            Payload, QueryProfile, RowChange, Session, Stopwatch, UpdateError,
+        },
+        format::ResultFormatter,
+        parse_sql::{parse, tokenize},
+        plan::{plan_with_option, PlanOption},
+        plan_cache::PlanCache,
+        prepared::{bind_parameters, number_parameters, PreparedStatement},
+        result::{Error, Result},
+        stats::Stats,
         store::{GStore, GStoreMut},
-        translate::translate,
+        translate::{translate, translate_tokens},
     },
     futures::{
-        stream::{self, StreamExt},
+        channel::mpsc::{unbounded, UnboundedSender},
+        executor::block_on_stream,
+        future::LocalBoxFuture,
+        stream::{self, Stream, StreamExt},
         TryStreamExt,
     },
+    std::{collections::HashMap, fs, iter, path::Path},
 };
 
 pub struct Glue<T: GStore + GStoreMut> {
     pub storage: T,
+    pub plan_option: PlanOption,
+    watchers: HashMap<String, Vec<UnboundedSender<RowChange>>>,
+    /// Row changes of the ongoing transaction, published on commit
+    pending_changes: Option<Vec<(String, RowChange)>>,
+    /// Query whose rows are borrowed by the iterator returned from `execute_iter`
+    iterated_query: Option<Query>,
+    stats: Stats,
+    /// Whether the ongoing transaction is read-only
+    read_only: bool,
+    plan_cache: PlanCache,
+    /// Session time zone, which is used to read a `TIMESTAMP` as a `TIMESTAMPTZ` and the other
+    /// way around
+    time_zone: TimeZone,
+    /// Values returned by `NEXTVAL` or set by `SETVAL` in this session, read by `CURRVAL`
+    current_values: CurrentValues,
 }
 
 impl<T: GStore + GStoreMut> Glue<T> {
     pub fn new(storage: T) -> Self {
-        Self { storage }
+        Self {
+            storage,
+            plan_option: PlanOption::default(),
+            watchers: HashMap::new(),
+            pending_changes: None,
+            iterated_query: None,
+            stats: Stats::default(),
+            read_only: false,
+            plan_cache: PlanCache::default(),
+            time_zone: TimeZone::UTC,
+            current_values: CurrentValues::default(),
+        }
+    }
+
+    /// Sets the session time zone, `UTC` unless it is changed.
+    /// Accepts an IANA time zone name such as `Asia/Seoul` or a UTC offset such as `+09:00`.
+    pub fn set_time_zone(&mut self, name: &str) -> Result<()> {
+        self.time_zone = TimeZone::parse(name)?;
+
+        Ok(())
     }
 
     pub async fn plan<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Statement>> {
-        let parsed = parse(sql)?;
+        let (statements, cached) = self.plan_cache.translate(sql.as_ref())?;
+        if cached {
+            self.stats.plan_cache_hits += 1;
+        }
+
         let storage = &self.storage;
-        stream::iter(parsed)
-            .map(|p| translate(&p))
-            .then(|statement| async move { plan(storage, statement?).await })
+        let option = self.plan_option;
+        stream::iter(statements)
+            .then(|statement| async move { plan_with_option(storage, statement, option).await })
             .try_collect()
             .await
     }
 
     pub async fn execute_stmt(&mut self, statement: &Statement) -> Result<Payload> {
-        execute(&mut self.storage, statement).await
+        let started = Stopwatch::start();
+        let result = self.execute_and_publish(statement).await;
+        self.stats.record(result.as_ref(), started.elapsed());
+
+        result
+    }
+
+    /// Returns `ExecuteError::ReadOnlyTransaction` if the statement modifies data or schema while
+    /// a read-only transaction is in progress.
+    pub fn assert_read_only(&self, statement: &Statement) -> Result<()> {
+        assert_read_only(statement, self.execute_option())
+    }
+
+    fn execute_option(&self) -> ExecuteOption {
+        ExecuteOption {
+            read_only: self.read_only,
+        }
+    }
+
+    /// Runs `f` in a read-only transaction, which is committed if `f` succeeds and rolled back
+    /// otherwise.
+    ///
+    /// ```ignore
+    /// let payloads = glue
+    ///     .with_readonly(|glue| Box::pin(glue.execute("SELECT * FROM Item")))
+    ///     .await?;
+    /// ```
+    pub async fn with_readonly<R, F>(&mut self, f: F) -> Result<R>
+    where
+        F: for<'a> FnOnce(&'a mut Self) -> LocalBoxFuture<'a, Result<R>>,
+    {
+        self.execute_stmt(&Statement::StartTransaction { read_only: true })
+            .await?;
+
+        match f(self).await {
+            Ok(value) => {
+                self.execute_stmt(&Statement::Commit).await?;
+
+                Ok(value)
+            }
+            Err(error) => {
+                self.execute_stmt(&Statement::Rollback).await?;
+
+                Err(error)
+            }
+        }
+    }
+
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats.reset();
+    }
+
+    async fn execute_and_publish(&mut self, statement: &Statement) -> Result<Payload> {
+        let table_name = match statement {
+            Statement::Insert { table_name, .. }
+            | Statement::Update { table_name, .. }
+            | Statement::Delete { table_name, .. }
+                if self.watchers.contains_key(table_name) =>
+            {
+                table_name
+            }
+            _ => {
+                let option = self.execute_option();
+                let session = Session {
+                    current_values: &self.current_values,
+                    time_zone: self.time_zone,
+                };
+                let payload =
+                    execute_with_option(&mut self.storage, session, statement, option).await?;
+                match (&payload, statement) {
+                    (Payload::StartTransaction, Statement::StartTransaction { read_only }) => {
+                        self.pending_changes = Some(Vec::new());
+                        self.read_only = *read_only;
+                    }
+                    (Payload::SetTransaction, Statement::SetTransaction { read_only }) => {
+                        // SET TRANSACTION has no effect outside of a transaction
+                        if self.pending_changes.is_some() {
+                            self.read_only = *read_only;
+                        }
+                    }
+                    (Payload::Commit, _) => {
+                        let changes = self.pending_changes.take().unwrap_or_default();
+                        self.publish(changes);
+                        self.read_only = false;
+                    }
+                    (Payload::Rollback, _) => {
+                        self.pending_changes = None;
+                        self.read_only = false;
+                    }
+                    _ => {}
+                }
+
+                return Ok(payload);
+            }
+        };
+
+        let option = self.execute_option();
+        let session = Session {
+            current_values: &self.current_values,
+            time_zone: self.time_zone,
+        };
+        let (payload, changes) =
+            execute_with_changes(&mut self.storage, session, statement, option).await?;
+        let changes = changes
+            .into_iter()
+            .map(|change| (table_name.to_owned(), change));
+        match self.pending_changes.as_mut() {
+            Some(pending_changes) => pending_changes.extend(changes),
+            None => self.publish(changes),
+        }
+
+        Ok(payload)
+    }
+
+    /// Inserts the rows, e.g. structs deriving `IntoRow`, with a single `INSERT` statement.
+    /// Every row has to have the same columns as the first one.
+    pub async fn insert_rows<R: Into<Row>>(
+        &mut self,
+        table_name: &str,
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Payload> {
+        let mut columns: Option<Vec<String>> = None;
+        let mut values_list = Vec::new();
+        for row in rows {
+            let (row_columns, values) = match row.into() {
+                Row::Vec { columns, values } => (columns.to_vec(), values),
+                Row::Map(values) => (Vec::new(), vec![Value::Map(values)]),
+            };
+
+            match &columns {
+                Some(columns) if columns != &row_columns => {
+                    return Err(InsertError::ColumnAndValuesNotMatched.into());
+                }
+                Some(_) => {}
+                None => columns = Some(row_columns),
+            }
+
+            let values = values
+                .into_iter()
+                .map(Expr::try_from)
+                .collect::<Result<Vec<_>>>()?;
+            values_list.push(values);
+        }
+
+        if values_list.is_empty() {
+            return Ok(Payload::Insert(0));
+        }
+
+        let statement = Statement::Insert {
+            table_name: table_name.to_owned(),
+            columns: columns.unwrap_or_default(),
+            source: Query {
+                with: Vec::new(),
+                body: SetExpr::Values(Values(values_list)),
+                order_by: Vec::new(),
+                limit: None,
+                offset: None,
+            },
+            on_conflict: None,
+        };
+
+        self.execute_stmt(&statement).await
+    }
+
+    /// Executes an `UPDATE` which checks the version of the rows, e.g.
+    /// `UPDATE Item SET name = 'glue' WHERE id = 1 AND version = 3`.
+    ///
+    /// The version column is increased by one unless the statement assigns it. If no row is
+    /// updated but some rows match the condition without the version check, another transaction
+    /// has updated them in the meantime and `UpdateError::OptimisticLockConflict` is returned.
+    /// The matching rows are counted with a `SELECT COUNT(*)` in the same transaction as the
+    /// `UPDATE`. No row is changed on a conflict, and a transaction in progress stays open so it
+    /// can be retried or rolled back.
+    pub async fn update_versioned<Sql: AsRef<str>>(
+        &mut self,
+        sql: Sql,
+        version_column: &str,
+    ) -> Result<Payload> {
+        let mut statements = self.plan(sql).await?;
+        if statements.len() != 1 {
+            return Err(Error::Parser(format!(
+                "expected a single statement to update, but found {}",
+                statements.len()
+            )));
+        }
+
+        let (table_name, alias, mut assignments, selection) = match statements.remove(0) {
+            Statement::Update {
+                table_name,
+                alias,
+                assignments,
+                selection,
+            } => (table_name, alias, assignments, selection),
+            _ => return Err(UpdateError::UpdateStatementRequired.into()),
+        };
+
+        if assignments
+            .iter()
+            .all(|assignment| assignment.id != version_column)
+        {
+            assignments.push(Assignment {
+                id: version_column.to_owned(),
+                value: Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(version_column.to_owned())),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(1.into()))),
+                },
+            });
+        }
+
+        let conflict_check = selection
+            .clone()
+            .and_then(|selection| without_version_check(selection, version_column))
+            .map(|selection| Query {
+                with: Vec::new(),
+                body: SetExpr::Select(Box::new(Select {
+                    projection: vec![SelectItem::Expr {
+                        expr: Expr::Aggregate(Box::new(Aggregate::Count(CountArgExpr::Wildcard))),
+                        label: "COUNT(*)".to_owned(),
+                    }],
+                    from: TableWithJoins {
+                        relation: TableFactor::Table {
+                            name: table_name.to_owned(),
+                            alias: alias.clone().map(|name| TableAlias {
+                                name,
+                                columns: Vec::new(),
+                            }),
+                            index: None,
+                        },
+                        joins: Vec::new(),
+                    },
+                    selection: Some(selection),
+                    group_by: Vec::new(),
+                    having: None,
+                })),
+                order_by: Vec::new(),
+                limit: None,
+                offset: None,
+            });
+        let statement = Statement::Update {
+            table_name: table_name.to_owned(),
+            alias,
+            assignments,
+            selection,
+        };
+
+        let option = self.execute_option();
+        let session = Session {
+            current_values: &self.current_values,
+            time_zone: self.time_zone,
+        };
+        let started = Stopwatch::start();
+        let result = execute_versioned_update(
+            &mut self.storage,
+            session,
+            &statement,
+            conflict_check.as_ref(),
+            option,
+        )
+        .await;
+        self.stats.record(
+            result.as_ref().map(|(payload, _)| payload),
+            started.elapsed(),
+        );
+
+        let (payload, changes) = result?;
+        let changes = changes
+            .into_iter()
+            .map(|change| (table_name.to_owned(), change));
+        match self.pending_changes.as_mut() {
+            Some(pending_changes) => pending_changes.extend(changes),
+            None => self.publish(changes),
+        }
+
+        Ok(payload)
+    }
+
+    /// Executes a single `SELECT` query and returns an iterator which reads its rows lazily.
+    /// Advancing the iterator blocks the current thread until the next row is fetched.
+    /// The rows are not counted in `stats`.
+    pub async fn execute_iter<Sql: AsRef<str>>(
+        &mut self,
+        sql: Sql,
+    ) -> Result<impl Iterator<Item = Result<Row>> + '_> {
+        let mut statements = self.plan(sql).await?;
+        if statements.len() != 1 {
+            return Err(Error::Parser(format!(
+                "expected a single statement to iterate, but found {}",
+                statements.len()
+            )));
+        }
+
+        let query = match statements.remove(0) {
+            Statement::Query(query) => query,
+            _ => return Err(ExecuteError::QueryRequiredForIterator.into()),
+        };
+        let Self {
+            storage,
+            iterated_query,
+            time_zone,
+            current_values,
+            ..
+        } = self;
+        let query = iterated_query.insert(query);
+        let session = Session {
+            current_values,
+            time_zone: *time_zone,
+        };
+        let rows = select(storage, session, query, None).await?;
+        let mut rows = block_on_stream(Box::pin(rows));
+
+        Ok(iter::from_fn(move || rows.next()))
+    }
+
+    /// Returns a stream of the rows inserted, updated or deleted in the table through this `Glue`.
+    /// Changes made in a transaction are delivered once the transaction is committed.
+    pub fn watch_table(&mut self, table_name: &str) -> impl Stream<Item = RowChange> + Unpin {
+        let (sender, receiver) = unbounded();
+        self.watchers
+            .entry(table_name.to_owned())
+            .or_default()
+            .push(sender);
+
+        receiver
+    }
+
+    fn publish(&mut self, changes: impl IntoIterator<Item = (String, RowChange)>) {
+        for (table_name, change) in changes {
+            if let Some(senders) = self.watchers.get_mut(&table_name) {
+                senders.retain(|sender| sender.unbounded_send(change.clone()).is_ok());
+
+                if senders.is_empty() {
+                    self.watchers.remove(&table_name);
+                }
+            }
+        }
     }
 
     pub async fn execute<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Payload>> {
@@ -47,4 +443,209 @@ impl<T: GStore + GStoreMut> Glue<T> {
 
         Ok(payloads)
     }
+
+    /// Parses and plans a single statement whose parameters are bound by `execute_prepared`.
+    /// Parameters are written as `$1`, `$2`, ... or as `?`, which are numbered in order.
+    ///
+    /// ```ignore
+    /// let prepared = glue.prepare("SELECT * FROM Item WHERE id = $1").await?;
+    /// let payload = glue.execute_prepared(&prepared, &[Value::I64(42)]).await?;
+    /// ```
+    pub async fn prepare<Sql: AsRef<str>>(&self, sql: Sql) -> Result<PreparedStatement> {
+        let (tokens, num_parameters) = number_parameters(tokenize(sql.as_ref())?);
+        let mut statements = translate_tokens(tokens)?;
+        if statements.len() != 1 {
+            return Err(Error::Parser(format!(
+                "expected a single statement to prepare, but found {}",
+                statements.len()
+            )));
+        }
+
+        let statement = statements.remove(0);
+        let statement = plan_with_option(&self.storage, statement, self.plan_option).await?;
+
+        Ok(PreparedStatement {
+            statement,
+            num_parameters,
+        })
+    }
+
+    /// Executes the prepared statement with `params` bound to its parameters in order.
+    /// Each parameter of a copy of the statement is replaced by the literal of its value, so the
+    /// values are implicitly cast in the same way as literals.
+    pub async fn execute_prepared(
+        &mut self,
+        prepared: &PreparedStatement,
+        params: &[Value],
+    ) -> Result<Payload> {
+        if params.len() != prepared.num_parameters {
+            return Err(EvaluateError::ParameterCountMismatch {
+                expected: prepared.num_parameters,
+                found: params.len(),
+            }
+            .into());
+        }
+
+        let statement = bind_parameters(prepared.statement.clone(), params)?;
+
+        self.execute_stmt(&statement).await
+    }
+
+    /// Returns the `CREATE TABLE` and `CREATE INDEX` statements of the table.
+    pub async fn export_schema(&self, table_name: &str) -> Result<String> {
+        self.storage
+            .fetch_schema(table_name)
+            .await?
+            .map(|schema| schema.to_ddl())
+            .ok_or_else(|| ExecuteError::TableNotFound(table_name.to_owned()).into())
+    }
+
+    /// Creates the table from the statements generated by `export_schema`.
+    pub async fn import_schema(&mut self, ddl: &str) -> Result<()> {
+        Schema::from_ddl(ddl)?;
+        self.execute(ddl).await?;
+
+        Ok(())
+    }
+
+    /// Creates the tables defined in the TOML schema file.
+    pub async fn schema_from_toml<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let toml = fs::read_to_string(path)
+            .map_err(|e| SchemaParseError::CannotReadFile(e.to_string()))?;
+
+        for schema in schemas_from_toml(&toml)? {
+            let Schema {
+                table_name,
+                column_defs,
+                checks,
+                foreign_keys,
+                engine,
+                ..
+            } = schema;
+            let statement = Statement::CreateTable {
+                if_not_exists: false,
+                name: table_name,
+                columns: column_defs,
+                checks,
+                foreign_keys,
+                sequences: Vec::new(),
+                source: None,
+                engine,
+            };
+
+            self.execute_stmt(&statement).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Applies the changes computed by `diff_schemas`.
+    /// Nothing is applied if any of the changes is not supported.
+    pub async fn apply_diff(&mut self, diff: &[SchemaChange]) -> Result<()> {
+        let statements = diff
+            .iter()
+            .map(SchemaChange::to_statement)
+            .collect::<Result<Vec<_>>>()?;
+
+        for statement in statements.iter() {
+            self.execute_stmt(statement).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Executes the statements and serializes each payload with the given formatter.
+    pub async fn execute_formatted<Sql: AsRef<str>>(
+        &mut self,
+        sql: Sql,
+        formatter: &dyn ResultFormatter,
+    ) -> Result<Vec<u8>> {
+        let payloads = self.execute(sql).await?;
+        let mut output = Vec::new();
+        for payload in payloads.iter() {
+            output.extend(formatter.format(payload)?);
+        }
+
+        Ok(output)
+    }
+
+    /// Executes a single statement and returns its timing breakdown along with the payload.
+    /// Row changes made by the statement are not delivered to `watch_table` streams.
+    pub async fn execute_with_profile<Sql: AsRef<str>>(
+        &mut self,
+        sql: Sql,
+    ) -> Result<(Payload, QueryProfile)> {
+        let started = Stopwatch::start();
+        let mut parsed = parse(sql)?;
+        if parsed.len() != 1 {
+            return Err(Error::Parser(format!(
+                "expected a single statement to profile, but found {}",
+                parsed.len()
+            )));
+        }
+        let parsed = parsed.remove(0);
+        let parse_time = started.elapsed();
+
+        let planned = Stopwatch::start();
+        let statement = translate(&parsed)?;
+        let statement = plan_with_option(&self.storage, statement, self.plan_option).await?;
+        let plan_time = planned.elapsed();
+
+        let option = self.execute_option();
+        let session = Session {
+            current_values: &self.current_values,
+            time_zone: self.time_zone,
+        };
+        let executed = Stopwatch::start();
+        let result = execute_with_profile(&mut self.storage, session, &statement, option).await;
+        self.stats.record(
+            result.as_ref().map(|(payload, _)| payload),
+            executed.elapsed(),
+        );
+
+        let (payload, profile) = result?;
+        let profile = QueryProfile {
+            parse_time,
+            plan_time,
+            total_time: started.elapsed(),
+            ..profile
+        };
+
+        Ok((payload, profile))
+    }
+}
+
+/// Removes the `<version_column> = ...` conditions joined by `AND` from the `WHERE` clause.
+fn without_version_check(expr: Expr, version_column: &str) -> Option<Expr> {
+    let is_version_column = |expr: &Expr| match expr {
+        Expr::Identifier(ident) | Expr::CompoundIdentifier { ident, .. } => ident == version_column,
+        _ => false,
+    };
+
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => match (
+            without_version_check(*left, version_column),
+            without_version_check(*right, version_column),
+        ) {
+            (Some(left), Some(right)) => Some(Expr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            }),
+            (left, right) => left.or(right),
+        },
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } if is_version_column(&left) || is_version_column(&right) => None,
+        Expr::Nested(expr) => without_version_check(*expr, version_column)
+            .map(Box::new)
+            .map(Expr::Nested),
+        expr => Some(expr),
+    }
 }
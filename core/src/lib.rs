@@ -5,14 +5,19 @@ pub use {chrono, sqlparser};
 
 mod glue;
 mod mock;
+mod plan_cache;
+mod prepared;
 mod result;
 
 pub mod ast;
 pub mod ast_builder;
 pub mod data;
 pub mod executor;
+pub mod format;
 pub mod parse_sql;
 pub mod plan;
+pub mod query_builder;
+pub mod stats;
 pub mod store;
 pub mod translate;
 
@@ -24,6 +29,7 @@ pub mod prelude {
         glue::Glue,
         parse_sql::parse,
         plan::plan,
+        prepared::PreparedStatement,
         result::{Error, Result},
         translate::translate,
     };
@@ -3,8 +3,8 @@ use {
         data::{Key, Schema},
         result::{Error, Result},
         store::{
-            AlterTable, CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata,
-            RowIter, Store, StoreMut, Transaction,
+            AlterTable, CustomFunction, CustomFunctionMut, DataRow, EnumType, EnumTypeMut, Index,
+            IndexMut, Metadata, RowIter, Sequence, SequenceMut, Store, StoreMut, Transaction,
         },
     },
     async_trait::async_trait,
@@ -41,6 +41,18 @@ impl CustomFunction for MockStorage {}
 #[async_trait(?Send)]
 impl CustomFunctionMut for MockStorage {}
 
+#[async_trait(?Send)]
+impl Sequence for MockStorage {}
+
+#[async_trait(?Send)]
+impl SequenceMut for MockStorage {}
+
+#[async_trait(?Send)]
+impl EnumType for MockStorage {}
+
+#[async_trait(?Send)]
+impl EnumTypeMut for MockStorage {}
+
 #[async_trait(?Send)]
 impl Store for MockStorage {
     async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
@@ -168,7 +180,9 @@ mod tests {
                     value: "true".to_owned(),
                 },
                 asc: None,
+                nulls_first: None,
             },
+            false,
         ))
         .is_err());
         assert!(block_on(storage.drop_index("Foo", "idx_col")).is_err());
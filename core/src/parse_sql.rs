@@ -7,8 +7,8 @@ use {
             SelectItem as SqlSelectItem, Statement as SqlStatement,
         },
         dialect::PostgreSqlDialect,
-        parser::Parser,
-        tokenizer::Tokenizer,
+        parser::{Parser, ParserError},
+        tokenizer::{Token, Tokenizer},
     },
 };
 
@@ -18,6 +18,20 @@ pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
     Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
 }
 
+/// Tokenizes the SQL, reporting errors in the same way as `parse`.
+pub(crate) fn tokenize(sql: &str) -> Result<Vec<Token>> {
+    Tokenizer::new(&DIALECT, sql)
+        .tokenize()
+        .map_err(|e| Error::Parser(format!("{:#?}", ParserError::from(e))))
+}
+
+pub(crate) fn parse_tokens(tokens: Vec<Token>) -> Result<Vec<SqlStatement>> {
+    Parser::new(&DIALECT)
+        .with_tokens(tokens)
+        .parse_statements()
+        .map_err(|e| Error::Parser(format!("{:#?}", e)))
+}
+
 macro_rules! generate_parse_fn {
     ($fn_name: ident, $output_type: ty) => {
         pub fn $fn_name<Sql: AsRef<str>>(sql_expr: Sql) -> Result<$output_type> {
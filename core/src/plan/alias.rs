@@ -0,0 +1,390 @@
+use {
+    super::{context::Context, planner::Planner},
+    crate::{
+        ast::{
+            Expr, GroupByModifier, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
+            WindowFunction,
+        },
+        data::Schema,
+    },
+    std::{collections::HashMap, mem, rc::Rc},
+};
+
+/// Resolves SELECT aliases referenced by GROUP BY, HAVING and ORDER BY.
+/// Column names of the source tables take precedence over aliases.
+pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
+    let planner = AliasPlanner { schema_map };
+
+    match statement {
+        Statement::Query(query) => {
+            let query = planner.query(None, query);
+
+            Statement::Query(query)
+        }
+        _ => statement,
+    }
+}
+
+struct AliasPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+}
+
+impl<'a> Planner<'a> for AliasPlanner<'a> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let (body, order_by) = match body {
+            SetExpr::Select(select) => {
+                let (select, order_by) = self.select(outer_context, *select, order_by);
+
+                (SetExpr::Select(Box::new(select)), order_by)
+            }
+            SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+                (body, order_by)
+            }
+        };
+
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, name: &str) -> Option<&'a Schema> {
+        self.schema_map.get(name)
+    }
+}
+
+impl<'a> AliasPlanner<'a> {
+    fn select(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        select: Select,
+        order_by: Vec<OrderByExpr>,
+    ) -> (Select, Vec<OrderByExpr>) {
+        let Select {
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let context = self.update_context(None, &from.relation);
+        let context = from.joins.iter().fold(context, |context, join| {
+            self.update_context(context, &join.relation)
+        });
+
+        let aliases = projection
+            .iter()
+            .filter_map(|item| match item {
+                SelectItem::Expr { expr, label } => Some((label.as_str(), expr)),
+                _ => None,
+            })
+            .fold(HashMap::new(), |mut aliases, (label, expr)| {
+                aliases.entry(label).or_insert(expr);
+
+                aliases
+            });
+        let resolve = |expr| resolve_alias(&aliases, context.as_deref(), expr);
+
+        let group_by = group_by.into_iter().map(resolve).collect();
+        let having = having.map(resolve);
+        let order_by = order_by
+            .into_iter()
+            .map(
+                |OrderByExpr {
+                     expr,
+                     asc,
+                     nulls_first,
+                 }| OrderByExpr {
+                    expr: resolve(expr),
+                    asc,
+                    nulls_first,
+                },
+            )
+            .collect();
+
+        let outer_context = Context::concat(context, outer_context);
+        let selection = selection.map(|expr| self.subquery_expr(outer_context, expr));
+
+        let select = Select {
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        };
+
+        (select, order_by)
+    }
+}
+
+/// Subqueries are left as they are, since the aliases of the outer query are not visible there.
+fn resolve_alias(aliases: &HashMap<&str, &Expr>, context: Option<&Context>, expr: Expr) -> Expr {
+    let resolve = |expr| Box::new(resolve_alias(aliases, context, expr));
+    let resolve_mut = |expr: &mut Expr| {
+        let taken = mem::replace(expr, Expr::Default);
+        *expr = resolve_alias(aliases, context, taken);
+    };
+
+    match expr {
+        Expr::Identifier(ident) => {
+            let is_column = context
+                .map(|context| context.contains_column(&ident))
+                .unwrap_or(false);
+
+            match aliases.get(ident.as_str()) {
+                Some(aliased) if !is_column => (*aliased).clone(),
+                _ => Expr::Identifier(ident),
+            }
+        }
+        Expr::Nested(expr) => Expr::Nested(resolve(*expr)),
+        Expr::IsNull(expr) => Expr::IsNull(resolve(*expr)),
+        Expr::IsNotNull(expr) => Expr::IsNotNull(resolve(*expr)),
+        Expr::UnaryOp { op, expr } => Expr::UnaryOp {
+            op,
+            expr: resolve(*expr),
+        },
+        Expr::BinaryOp { left, op, right } => Expr::BinaryOp {
+            left: resolve(*left),
+            op,
+            right: resolve(*right),
+        },
+        Expr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => Expr::Between {
+            expr: resolve(*expr),
+            negated,
+            low: resolve(*low),
+            high: resolve(*high),
+        },
+        Expr::InList {
+            expr,
+            list,
+            negated,
+        } => Expr::InList {
+            expr: resolve(*expr),
+            list: list.into_iter().map(|expr| *resolve(expr)).collect(),
+            negated,
+        },
+        Expr::Tuple(exprs) => Expr::Tuple(exprs.into_iter().map(|expr| *resolve(expr)).collect()),
+        Expr::Array { elem } => Expr::Array {
+            elem: elem.into_iter().map(|expr| *resolve(expr)).collect(),
+        },
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => Expr::Case {
+            operand: operand.map(|expr| resolve(*expr)),
+            when_then: when_then
+                .into_iter()
+                .map(|(when, then)| (*resolve(when), *resolve(then)))
+                .collect(),
+            else_result: else_result.map(|expr| resolve(*expr)),
+        },
+        Expr::GroupByModifier(modifier) => {
+            let resolve = |elems: Vec<Vec<Expr>>| {
+                elems
+                    .into_iter()
+                    .map(|exprs| exprs.into_iter().map(|expr| *resolve(expr)).collect())
+                    .collect()
+            };
+            let modifier = match *modifier {
+                GroupByModifier::Rollup(elems) => GroupByModifier::Rollup(resolve(elems)),
+                GroupByModifier::Cube(elems) => GroupByModifier::Cube(resolve(elems)),
+                GroupByModifier::GroupingSets(sets) => GroupByModifier::GroupingSets(resolve(sets)),
+            };
+
+            Expr::GroupByModifier(Box::new(modifier))
+        }
+        Expr::Like {
+            expr,
+            negated,
+            pattern,
+        } => Expr::Like {
+            expr: resolve(*expr),
+            negated,
+            pattern: resolve(*pattern),
+        },
+        Expr::ILike {
+            expr,
+            negated,
+            pattern,
+        } => Expr::ILike {
+            expr: resolve(*expr),
+            negated,
+            pattern: resolve(*pattern),
+        },
+        Expr::SimilarTo {
+            expr,
+            negated,
+            pattern,
+        } => Expr::SimilarTo {
+            expr: resolve(*expr),
+            negated,
+            pattern: resolve(*pattern),
+        },
+        Expr::InSubquery {
+            expr,
+            subquery,
+            negated,
+        } => Expr::InSubquery {
+            expr: resolve(*expr),
+            subquery,
+            negated,
+        },
+        Expr::ArrayIndex { obj, indexes } => Expr::ArrayIndex {
+            obj: resolve(*obj),
+            indexes: indexes.into_iter().map(|expr| *resolve(expr)).collect(),
+        },
+        Expr::Interval {
+            expr,
+            leading_field,
+            last_field,
+        } => Expr::Interval {
+            expr: resolve(*expr),
+            leading_field,
+            last_field,
+        },
+        Expr::Function(mut function) => {
+            function.as_exprs_mut().for_each(resolve_mut);
+
+            Expr::Function(function)
+        }
+        Expr::Aggregate(mut aggregate) => {
+            aggregate.as_exprs_mut().for_each(resolve_mut);
+
+            Expr::Aggregate(aggregate)
+        }
+        Expr::Window(mut window) => {
+            match &mut window.function {
+                WindowFunction::Aggregate(aggregate) => {
+                    aggregate.as_exprs_mut().for_each(resolve_mut);
+                }
+                WindowFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                }
+                | WindowFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                } => {
+                    resolve_mut(expr);
+                    offset
+                        .iter_mut()
+                        .chain(default.iter_mut())
+                        .for_each(resolve_mut);
+                }
+                WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {}
+            }
+
+            window.partition_by.iter_mut().for_each(resolve_mut);
+            window
+                .order_by
+                .iter_mut()
+                .for_each(|OrderByExpr { expr, .. }| resolve_mut(expr));
+
+            Expr::Window(window)
+        }
+        Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Exists { .. }
+        | Expr::Subquery(_)
+        | Expr::Default
+        | Expr::Parameter(_) => expr,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan as plan_alias,
+        crate::{
+            ast::Statement,
+            mock::{run, MockStorage},
+            parse_sql::parse,
+            plan::fetch_schema_map,
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn translate_sql(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).expect(sql)
+    }
+
+    fn plan(storage: &MockStorage, sql: &str) -> Statement {
+        let statement = translate_sql(sql);
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        plan_alias(&schema_map, statement)
+    }
+
+    #[test]
+    fn resolve_alias() {
+        let storage = run("
+            CREATE TABLE Item (
+                id INTEGER,
+                price INTEGER,
+                qty INTEGER
+            );
+        ");
+
+        let sql = "SELECT price * qty AS total FROM Item ORDER BY total";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql("SELECT price * qty AS total FROM Item ORDER BY price * qty");
+        assert_eq!(actual, expected, "order by alias:\n{sql}");
+
+        let sql = "SELECT price * qty AS total FROM Item ORDER BY total + 1 DESC";
+        let actual = plan(&storage, sql);
+        let expected =
+            translate_sql("SELECT price * qty AS total FROM Item ORDER BY price * qty + 1 DESC");
+        assert_eq!(actual, expected, "order by alias in expression:\n{sql}");
+
+        let sql =
+            "SELECT id % 2 AS parity, COUNT(*) AS cnt FROM Item GROUP BY parity HAVING cnt > 1";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql(
+            "SELECT id % 2 AS parity, COUNT(*) AS cnt FROM Item GROUP BY id % 2 HAVING COUNT(*) > 1",
+        );
+        assert_eq!(actual, expected, "group by and having alias:\n{sql}");
+
+        let sql = "SELECT price - qty AS total FROM Item ORDER BY ABS(total), CAST(total AS TEXT)";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql(
+            "SELECT price - qty AS total FROM Item ORDER BY ABS(price - qty), CAST(price - qty AS TEXT)",
+        );
+        assert_eq!(actual, expected, "order by alias in function:\n{sql}");
+
+        let sql = "SELECT id AS code, price * qty AS total FROM Item GROUP BY code HAVING SUM(total) > 10";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql(
+            "SELECT id AS code, price * qty AS total FROM Item GROUP BY id HAVING SUM(price * qty) > 10",
+        );
+        assert_eq!(actual, expected, "having alias in aggregate:\n{sql}");
+
+        let sql = "SELECT qty AS price FROM Item ORDER BY price";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql(sql);
+        assert_eq!(actual, expected, "column name takes precedence:\n{sql}");
+    }
+}
@@ -0,0 +1,340 @@
+use {
+    super::{join_constraint::get_labels, plan_with_option, PlanOption},
+    crate::{
+        ast::{
+            Assignment, ColumnDef, Cte, DataType, Expr, Join, JoinConstraint, JoinOperator,
+            OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins, Values,
+        },
+        data::{Key, Schema, TableError},
+        executor::cte_columns,
+        result::{Error, Result},
+        store::{DataRow, RowIter, Store},
+    },
+    async_recursion::async_recursion,
+    async_trait::async_trait,
+    std::{iter, mem},
+};
+
+/// Plans the query of each common table expression in the statement as a query of its own,
+/// where the recursive ones run at most `option.max_recursion` times.
+/// Returns the schemas of the common table expressions, which the rest of the statement is
+/// planned with instead of the tables or views of the same names.
+#[async_recursion(?Send)]
+pub async fn plan<T: Store>(
+    storage: &T,
+    mut statement: Statement,
+    option: PlanOption,
+) -> Result<(Statement, Vec<Schema>)> {
+    let mut withs = Vec::new();
+    let mut n = 0;
+    visit_statement(&mut statement, &mut |query| {
+        if !query.with.is_empty() {
+            withs.push((n, mem::take(&mut query.with)));
+        }
+
+        n += 1;
+    });
+
+    let mut schemas = Vec::new();
+    for (_, with) in withs.iter_mut() {
+        for Cte { alias, query } in with.iter_mut() {
+            // the recursive term reads the common table expression itself
+            let storage = CteSchemas::new(
+                storage,
+                schemas
+                    .iter()
+                    .cloned()
+                    .chain(iter::once(cte_schema(alias, query)))
+                    .collect(),
+            );
+            *query =
+                match plan_with_option(&storage, Statement::Query(query.clone()), option).await? {
+                    Statement::Query(query) => query,
+                    _ => return Err(Error::Table(TableError::Unreachable)),
+                };
+            if let SetExpr::Recursive { max_recursion, .. } = &mut query.body {
+                *max_recursion = option.max_recursion;
+            }
+
+            schemas.push(cte_schema(alias, query));
+        }
+    }
+
+    let mut withs = withs.into_iter().peekable();
+    let mut n = 0;
+    visit_statement(&mut statement, &mut |query| {
+        if let Some((_, with)) = withs.next_if(|(i, _)| *i == n) {
+            query.with = with;
+        }
+
+        n += 1;
+    });
+
+    Ok((statement, schemas))
+}
+
+/// Only the column names are known before the query is executed.
+fn cte_schema(alias: &TableAlias, query: &Query) -> Schema {
+    let column_defs = cte_columns(alias, get_labels(&query.body).as_deref()).map(|columns| {
+        columns
+            .into_iter()
+            .map(|name| ColumnDef {
+                name,
+                data_type: DataType::Text,
+                nullable: true,
+                default: None,
+                unique: None,
+            })
+            .collect()
+    });
+
+    Schema {
+        table_name: alias.name.to_owned(),
+        column_defs,
+        checks: Vec::new(),
+        foreign_keys: Vec::new(),
+        indexes: Vec::new(),
+        engine: None,
+        view: None,
+    }
+}
+
+/// Storage seen by the planner, where the common table expressions are found as tables which
+/// have the columns of their queries, the latest one first when the names are the same.
+/// Every other request is passed through to the underlying storage.
+pub struct CteSchemas<'a> {
+    storage: &'a dyn Store,
+    schemas: Vec<Schema>,
+}
+
+impl<'a> CteSchemas<'a> {
+    pub fn new(storage: &'a dyn Store, schemas: Vec<Schema>) -> Self {
+        Self { storage, schemas }
+    }
+}
+
+#[async_trait(?Send)]
+impl Store for CteSchemas<'_> {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let schema = self
+            .schemas
+            .iter()
+            .rev()
+            .find(|schema| schema.table_name == table_name);
+
+        match schema {
+            Some(schema) => Ok(Some(schema.clone())),
+            None => self.storage.fetch_schema(table_name).await,
+        }
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        self.storage.fetch_all_schemas().await
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        self.storage.fetch_data(table_name, key).await
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        self.storage.scan_data(table_name).await
+    }
+}
+
+/// Visits every query of the statement before its subqueries, except the queries of the common
+/// table expressions.
+fn visit_statement(statement: &mut Statement, visit: &mut dyn FnMut(&mut Query)) {
+    match statement {
+        Statement::Query(query) | Statement::Insert { source: query, .. } => {
+            visit_query(query, visit);
+        }
+        Statement::CreateTable {
+            source: Some(query),
+            ..
+        } => {
+            visit_query(query, visit);
+        }
+        Statement::Update {
+            assignments,
+            selection,
+            ..
+        } => {
+            for Assignment { value, .. } in assignments {
+                visit_expr(value, visit);
+            }
+
+            if let Some(expr) = selection {
+                visit_expr(expr, visit);
+            }
+        }
+        Statement::Delete {
+            selection: Some(expr),
+            ..
+        } => {
+            visit_expr(expr, visit);
+        }
+        _ => {}
+    }
+}
+
+fn visit_query(query: &mut Query, visit: &mut dyn FnMut(&mut Query)) {
+    visit(query);
+
+    let Query {
+        body,
+        order_by,
+        limit,
+        offset,
+        ..
+    } = query;
+
+    visit_set_expr(body, visit);
+
+    for OrderByExpr { expr, .. } in order_by {
+        visit_expr(expr, visit);
+    }
+
+    for expr in limit.iter_mut().chain(offset.iter_mut()) {
+        visit_expr(expr, visit);
+    }
+}
+
+fn visit_set_expr(body: &mut SetExpr, visit: &mut dyn FnMut(&mut Query)) {
+    match body {
+        SetExpr::Select(select) => visit_select(select, visit),
+        SetExpr::SetOperation { left, right, .. }
+        | SetExpr::Recursive {
+            base: left,
+            step: right,
+            ..
+        } => {
+            visit_set_expr(left, visit);
+            visit_set_expr(right, visit);
+        }
+        SetExpr::Values(Values(rows)) => {
+            for expr in rows.iter_mut().flatten() {
+                visit_expr(expr, visit);
+            }
+        }
+    }
+}
+
+fn visit_select(select: &mut Select, visit: &mut dyn FnMut(&mut Query)) {
+    let Select {
+        projection,
+        from: TableWithJoins { relation, joins },
+        selection,
+        group_by,
+        having,
+    } = select;
+
+    visit_table_factor(relation, visit);
+
+    for Join {
+        relation,
+        join_operator,
+        ..
+    } in joins
+    {
+        visit_table_factor(relation, visit);
+
+        if let JoinOperator::Inner(JoinConstraint::On(expr))
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) = join_operator
+        {
+            visit_expr(expr, visit);
+        }
+    }
+
+    for item in projection.iter_mut() {
+        if let SelectItem::Expr { expr, .. } = item {
+            visit_expr(expr, visit);
+        }
+    }
+
+    for expr in selection.iter_mut().chain(group_by).chain(having) {
+        visit_expr(expr, visit);
+    }
+}
+
+fn visit_table_factor(table_factor: &mut TableFactor, visit: &mut dyn FnMut(&mut Query)) {
+    match table_factor {
+        TableFactor::Derived { subquery, .. } => visit_query(subquery, visit),
+        TableFactor::Series { size: expr, .. } | TableFactor::Unnest { expr, .. } => {
+            visit_expr(expr, visit)
+        }
+        TableFactor::Table { .. } | TableFactor::Dictionary { .. } => {}
+    }
+}
+
+fn visit_expr(expr: &mut Expr, visit: &mut dyn FnMut(&mut Query)) {
+    match expr {
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => visit_expr(expr, visit),
+        Expr::InSubquery { expr, subquery, .. } => {
+            visit_expr(expr, visit);
+            visit_query(subquery, visit);
+        }
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => visit_query(subquery, visit),
+        Expr::InList { expr, list, .. } => {
+            visit_expr(expr, visit);
+            list.iter_mut().for_each(|expr| visit_expr(expr, visit));
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            visit_expr(expr, visit);
+            visit_expr(low, visit);
+            visit_expr(high, visit);
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            visit_expr(expr, visit);
+            visit_expr(pattern, visit);
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            visit_expr(left, visit);
+            visit_expr(right, visit);
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            operand.iter_mut().for_each(|expr| visit_expr(expr, visit));
+            when_then.iter_mut().for_each(|(when, then)| {
+                visit_expr(when, visit);
+                visit_expr(then, visit);
+            });
+            else_result
+                .iter_mut()
+                .for_each(|expr| visit_expr(expr, visit));
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            visit_expr(obj, visit);
+            indexes.iter_mut().for_each(|expr| visit_expr(expr, visit));
+        }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => {
+            exprs.iter_mut().for_each(|expr| visit_expr(expr, visit));
+        }
+        Expr::Function(function) => function
+            .as_exprs_mut()
+            .for_each(|expr| visit_expr(expr, visit)),
+        Expr::Aggregate(aggregate) => aggregate
+            .as_exprs_mut()
+            .for_each(|expr| visit_expr(expr, visit)),
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Window(_)
+        | Expr::Default
+        | Expr::Parameter(_)
+        | Expr::GroupByModifier(_) => {}
+    }
+}
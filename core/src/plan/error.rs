@@ -4,6 +4,24 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error as ThisError};
 pub enum PlanError {
     /// Error that that omits when user projects common column name from multiple tables in `JOIN`
     /// situation.
-    #[error("column reference {0} is ambiguous, please specify the table name")]
-    ColumnReferenceAmbiguous(String),
+    #[error(
+        "column reference {column_name} is ambiguous, present in tables {}, please specify the table name",
+        .tables.join(" and ")
+    )]
+    ColumnReferenceAmbiguous {
+        column_name: String,
+        tables: Vec<String>,
+    },
+
+    #[error("column '{0}' in USING clause is not found in both sides of the join")]
+    UsingColumnNotFound(String),
+
+    #[error("columns of '{0}' must be known to be joined by NATURAL JOIN")]
+    UnknownNaturalJoinColumns(String),
+
+    #[error("modifying data through a view is not supported: {0}")]
+    ViewNotUpdatable(String),
+
+    #[error("views are nested deeper than {0} levels, a view may read itself")]
+    ViewDepthExceeded(usize),
 }
@@ -40,12 +40,18 @@ pub fn check_expr(context: Option<Rc<Context<'_>>>, expr: &Expr) -> bool {
 
 fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
     let Query {
+        with,
         body,
         order_by,
         limit,
         offset,
     } = query;
 
+    // common table expressions are read as tables which the context does not know
+    if !with.is_empty() {
+        return false;
+    }
+
     let body = match body {
         SetExpr::Select(select) => check_select(context.as_ref().map(Rc::clone), select),
         SetExpr::Values(Values(rows)) => rows
@@ -53,6 +59,7 @@ fn check_query(context: Option<Rc<Context<'_>>>, query: &Query) -> bool {
             .flatten()
             .map(|expr| check_expr(context.as_ref().map(Rc::clone), expr))
             .all(identity),
+        SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => false,
     };
 
     if !body {
@@ -116,11 +123,13 @@ fn check_select(context: Option<Rc<Context<'_>>>, select: &Select) -> bool {
 
             match join_operator {
                 JoinOperator::Inner(JoinConstraint::On(expr))
-                | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => {
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) => {
                     check_expr(context.as_ref().map(Rc::clone), expr)
                 }
-                JoinOperator::Inner(JoinConstraint::None)
-                | JoinOperator::LeftOuter(JoinConstraint::None) => true,
+                JoinOperator::Inner(_)
+                | JoinOperator::LeftOuter(_)
+                | JoinOperator::FullOuter(_) => true,
             }
         })
         .all(identity)
@@ -144,6 +153,7 @@ fn check_table_factor(context: Option<Rc<Context<'_>>>, table_factor: &TableFact
             .unwrap_or_else(|| name),
         TableFactor::Derived { alias, .. }
         | TableFactor::Series { alias, .. }
+        | TableFactor::Unnest { alias, .. }
         | TableFactor::Dictionary { alias, .. } => &alias.name,
     };
 
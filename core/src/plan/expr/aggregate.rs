@@ -1,4 +1,7 @@
-use crate::ast::{Aggregate, CountArgExpr, Expr};
+use {
+    crate::ast::{Aggregate, CountArgExpr, Expr, OrderByExpr},
+    std::iter::once,
+};
 
 impl Aggregate {
     pub fn as_expr(&self) -> Option<&Expr> {
@@ -10,9 +13,43 @@ impl Aggregate {
             | Aggregate::Min(expr)
             | Aggregate::Avg(expr)
             | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => Some(expr),
+            | Aggregate::Stdev(expr)
+            | Aggregate::First(expr)
+            | Aggregate::Last(expr)
+            | Aggregate::CountApproxDistinct { expr, .. }
+            | Aggregate::StringAgg { expr, .. }
+            | Aggregate::JsonAgg { expr, .. }
+            | Aggregate::JsonObjectAgg { key: expr, .. }
+            | Aggregate::PercentileCont { expr, .. }
+            | Aggregate::PercentileDisc { expr, .. } => Some(expr),
         }
     }
+
+    /// Every expression of the aggregate, including the ones `as_expr` leaves out
+    pub fn as_exprs_mut(&mut self) -> impl Iterator<Item = &mut Expr> {
+        let exprs: Vec<&mut Expr> = match self {
+            Aggregate::Count(CountArgExpr::Wildcard) => Vec::new(),
+            Aggregate::StringAgg { expr, order_by, .. } => once(expr)
+                .chain(order_by.iter_mut().map(|OrderByExpr { expr, .. }| expr))
+                .collect(),
+            Aggregate::JsonObjectAgg { key, value, .. } => vec![key, value],
+            Aggregate::PercentileCont { fraction, expr }
+            | Aggregate::PercentileDisc { fraction, expr } => vec![fraction, expr],
+            Aggregate::Count(CountArgExpr::Expr(expr))
+            | Aggregate::Sum(expr)
+            | Aggregate::Max(expr)
+            | Aggregate::Min(expr)
+            | Aggregate::Avg(expr)
+            | Aggregate::Variance(expr)
+            | Aggregate::Stdev(expr)
+            | Aggregate::First(expr)
+            | Aggregate::Last(expr)
+            | Aggregate::CountApproxDistinct { expr, .. }
+            | Aggregate::JsonAgg { expr, .. } => vec![expr],
+        };
+
+        exprs.into_iter()
+    }
 }
 
 #[cfg(test)]
@@ -60,5 +97,44 @@ mod tests {
         let actual = parse("VARIANCE(id)");
         let expected = Expr::Identifier("id".to_owned());
         assert_eq!(actual.as_expr(), Some(&expected));
+
+        let actual = parse("FIRST(id)");
+        let expected = Expr::Identifier("id".to_owned());
+        assert_eq!(actual.as_expr(), Some(&expected));
+
+        let actual = parse("LAST(id)");
+        let expected = Expr::Identifier("id".to_owned());
+        assert_eq!(actual.as_expr(), Some(&expected));
+
+        let actual = parse("STRING_AGG(name, ',')");
+        let expected = Expr::Identifier("name".to_owned());
+        assert_eq!(actual.as_expr(), Some(&expected));
+    }
+
+    #[test]
+    fn as_exprs_mut() {
+        let ident = |name: &str| Expr::Identifier(name.to_owned());
+
+        assert_eq!(parse("COUNT(*)").as_exprs_mut().count(), 0);
+
+        let mut aggregate = parse("SUM(id)");
+        let actual = aggregate
+            .as_exprs_mut()
+            .map(|expr| expr.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![ident("id")]);
+
+        let mut aggregate = parse("JSON_OBJECT_AGG(id, name)");
+        let actual = aggregate
+            .as_exprs_mut()
+            .map(|expr| expr.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, vec![ident("id"), ident("name")]);
+
+        let mut aggregate = parse("SUM(id)");
+        aggregate
+            .as_exprs_mut()
+            .for_each(|expr| *expr = ident("num"));
+        assert_eq!(aggregate, parse("SUM(num)"));
     }
 }
@@ -3,8 +3,10 @@ use {
     std::iter::{empty, once},
 };
 
-impl Function {
-    pub fn as_exprs(&self) -> impl Iterator<Item = &Expr> {
+/// Expands to an iterator over the argument expressions of a function, `$iter` is either `iter`
+/// or `iter_mut`.
+macro_rules! exprs {
+    ($function: expr, $iter: ident) => {{
         #[derive(iter_enum::Iterator)]
         enum Exprs<I0, I1, I2, I3, I4, I5> {
             Empty(I0),
@@ -15,7 +17,7 @@ impl Function {
             VariableArgsWithSingle(I5),
         }
 
-        match self {
+        match $function {
             Self::Now() | Function::Pi() | Function::GenerateUuid() | Self::Rand(None) => {
                 Exprs::Empty(empty())
             }
@@ -32,7 +34,7 @@ impl Function {
             | Self::Degrees(expr)
             | Self::Ceil(expr)
             | Self::Rand(Some(expr))
-            | Self::Round(expr)
+            | Self::Round { expr, scale: None }
             | Self::Floor(expr)
             | Self::Exp(expr)
             | Self::Ln(expr)
@@ -44,6 +46,9 @@ impl Function {
             | Self::Ascii(expr)
             | Self::Chr(expr)
             | Self::Md5(expr)
+            | Self::OctetLength(expr)
+            | Self::UuidToBin { expr, swap: None }
+            | Self::BinToUuid { expr, swap: None }
             | Self::Ltrim { expr, chars: None }
             | Self::Rtrim { expr, chars: None }
             | Self::Trim {
@@ -53,9 +58,15 @@ impl Function {
             }
             | Self::Reverse(expr)
             | Self::Cast { expr, .. }
+            | Self::TryCast { expr, .. }
             | Self::Extract { expr, .. }
+            | Self::AtTimeZone { expr, .. }
             | Self::GetX(expr)
-            | Self::GetY(expr) => Exprs::Single([expr].into_iter()),
+            | Self::GetY(expr)
+            | Self::ArrayRemoveNulls(expr)
+            | Self::ArrayLength(expr)
+            | Self::Nextval(expr)
+            | Self::Currval(expr) => Exprs::Single([expr].into_iter()),
             Self::Left { expr, size: expr2 }
             | Self::Right { expr, size: expr2 }
             | Self::Lpad {
@@ -74,8 +85,8 @@ impl Function {
                 ..
             }
             | Self::Log {
-                antilog: expr,
-                base: expr2,
+                base: expr,
+                antilog: expr2,
             }
             | Self::Div {
                 dividend: expr,
@@ -125,10 +136,20 @@ impl Function {
                 count: None,
             }
             | Self::IfNull { expr, then: expr2 }
+            | Self::NullIf { expr1: expr, expr2 }
             | Self::Unwrap {
                 expr,
                 selector: expr2,
             }
+            | Self::JsonExtract { expr, path: expr2 }
+            | Self::Encode {
+                expr,
+                format: expr2,
+            }
+            | Self::Decode {
+                expr,
+                format: expr2,
+            }
             | Self::Position {
                 from_expr: expr2,
                 sub_expr: expr,
@@ -138,12 +159,33 @@ impl Function {
                 sub_expr: expr2,
                 start: None,
             }
+            | Self::Round {
+                expr,
+                scale: Some(expr2),
+            }
+            | Self::UuidToBin {
+                expr,
+                swap: Some(expr2),
+            }
+            | Self::BinToUuid {
+                expr,
+                swap: Some(expr2),
+            }
+            | Self::StringToArray {
+                expr,
+                delimiter: expr2,
+            }
             | Self::Append { expr, value: expr2 }
             | Self::Prepend { expr, value: expr2 }
+            | Self::ArrayRemove { expr, value: expr2 }
             | Self::Point { x: expr, y: expr2 }
             | Self::CalcDistance {
                 geometry1: expr,
                 geometry2: expr2,
+            }
+            | Self::Setval {
+                name: expr,
+                value: expr2,
             } => Exprs::Double([expr, expr2].into_iter()),
             Self::Lpad {
                 expr,
@@ -164,13 +206,37 @@ impl Function {
                 from_expr: expr,
                 sub_expr: expr2,
                 start: Some(expr3),
+            }
+            | Self::SplitPart {
+                expr,
+                delimiter: expr2,
+                index: expr3,
+            }
+            | Self::ArrayReplace {
+                expr,
+                from: expr2,
+                to: expr3,
             } => Exprs::Triple([expr, expr2, expr3].into_iter()),
-            Self::Custom { name: _, exprs } => Exprs::VariableArgs(exprs.iter()),
-            Self::Concat(exprs) => Exprs::VariableArgs(exprs.iter()),
+            Self::Custom { name: _, exprs } => Exprs::VariableArgs(exprs.$iter()),
+            Self::Concat(exprs)
+            | Self::Coalesce(exprs)
+            | Self::Greatest(exprs)
+            | Self::Least(exprs)
+            | Self::Grouping(exprs) => Exprs::VariableArgs(exprs.$iter()),
             Self::ConcatWs { separator, exprs } => {
-                Exprs::VariableArgsWithSingle(once(separator).chain(exprs.iter()))
+                Exprs::VariableArgsWithSingle(once(separator).chain(exprs.$iter()))
             }
         }
+    }};
+}
+
+impl Function {
+    pub fn as_exprs(&self) -> impl Iterator<Item = &Expr> {
+        exprs!(self, iter)
+    }
+
+    pub fn as_exprs_mut(&mut self) -> impl Iterator<Item = &mut Expr> {
+        exprs!(self, iter_mut)
     }
 }
 
@@ -223,6 +289,7 @@ mod tests {
         test("CEIL(1.23)", &["1.23"]);
         test("Rand(1.23)", &["1.23"]);
         test("ROUND(1.23)", &["1.23"]);
+        test("ROUND(1.23)", &["1.23"]);
         test("FLOOR(1.23)", &["1.23"]);
         test("EXP(1.23)", &["1.23"]);
         test("LN(col + 1)", &["col + 1"]);
@@ -234,6 +301,7 @@ mod tests {
         test(r#"TRIM("  rust  ")"#, &[r#""  rust  ""#]);
         test(r#"REVERSE("abcde")"#, &[r#""abcde""#]);
         test(r#"CAST(1 AS BOOLEAN)"#, &["1"]);
+        test("created AT TIME ZONE 'UTC'", &["created"]);
 
         test(r#"ABS(1)"#, &["1"]);
         test(r#"ABS(-1)"#, &["-1"]);
@@ -248,18 +316,24 @@ mod tests {
         test(r#"SIGN(-2)"#, &["-2"]);
         test(r#"SIGN(3.0)"#, &["3.0"]);
         test(r#"SIGN(-3.0)"#, &["-3.0"]);
+        test("ARRAY_REMOVE_NULLS(list)", &["list"]);
+        test("ARRAY_LENGTH(list)", &["list"]);
+        test("OCTET_LENGTH(bytes)", &["bytes"]);
+        test("UUID_TO_BIN(id)", &["id"]);
+        test("BIN_TO_UUID(bin)", &["bin"]);
 
         // Double
         test(r#"LEFT("hello", 2)"#, &[r#""hello""#, "2"]);
         test(r#"RIGHT("hello", 2)"#, &[r#""hello""#, "2"]);
         test(r#"FIND_IDX("Calzone", "zone")"#, &[r#"Calzone"#, r#"zone"#]);
+        test(r#"STRING_TO_ARRAY(csv, ',')"#, &["csv", "','"]);
         test(r#"LPAD(value, 5)"#, &["value", "5"]);
         test(r#"RPAD(value, 5)"#, &["value", "5"]);
         test(
             r#"TRIM(LEADING "_" FROM "__hello")"#,
             &[r#""__hello""#, r#""_""#],
         );
-        test("LOG(rate, 2)", &["rate", "2"]);
+        test("LOG(2, rate)", &["2", "rate"]);
         test("DIV(6, 2)", &["6", "2"]);
         test("MOD(6, 2)", &["6", "2"]);
         test("GCD(6, 2)", &["6", "2"]);
@@ -269,7 +343,14 @@ mod tests {
         test(r#"RTRIM(name, "xyz")"#, &["name", r#""xyz""#]);
         test("REPEAT(col || col2, 3)", &["col || col2", "3"]);
         test("REPEAT(column, 2)", &["column", "2"]);
+        test("UUID_TO_BIN(id, 1)", &["id", "1"]);
+        test("ENCODE(bytes, 'hex')", &["bytes", "'hex'"]);
+        test("DECODE(text, 'base64')", &["text", "'base64'"]);
+        test("BIN_TO_UUID(bin, 1)", &["bin", "1"]);
         test(r#"UNWRAP(field, "foo.1")"#, &["field", r#""foo.1""#]);
+        test(r#"JSON_EXTRACT(doc, '$.a')"#, &["doc", "'$.a'"]);
+        test("ARRAY_REMOVE(list, 2)", &["list", "2"]);
+        test("ARRAY_PREPEND(2, list)", &["list", "2"]);
 
         // Triple
         test(
@@ -284,6 +365,7 @@ mod tests {
             r#"SUBSTR('   >++++("<   ', 3, 11)"#,
             &[r#"'   >++++("<   '"#, "3", "11"],
         );
+        test("ARRAY_REPLACE(list, 2, 99)", &["list", "2", "99"]);
 
         //VariableArgs
         test(r#"CONCAT("abc")"#, &[r#""abc""#]);
@@ -302,8 +384,14 @@ mod tests {
             &[r#""gluesql""#, r#"" ""#, r#""is""#, r#"" ""#, r#""cool""#],
         );
 
+        test(r#"COALESCE(a, b, 'c')"#, &["a", "b", "'c'"]);
+        test(r#"GREATEST(a, b)"#, &["a", "b"]);
+        test(r#"LEAST(1, 2, 3)"#, &["1", "2", "3"]);
+        test(r#"GROUPING(a, b)"#, &["a", "b"]);
+
         test(r#"POSITION("men" IN "ramen")"#, &[r#""men""#, r#""ramen""#]);
         test(r#"POSITION("men" IN ramen)"#, &[r#""men""#, "ramen"]);
+        test(r#"STRPOS(ramen, "men")"#, &[r#""men""#, "ramen"]);
 
         //TypedStringVariableArgs
         test(
@@ -311,4 +399,21 @@ mod tests {
             &[r#"",""#, r#""gluesql""#, r#""is""#, r#""cool""#],
         );
     }
+
+    #[test]
+    fn as_exprs_mut() {
+        let mut function = match expr("SUBSTR(name, 1, 2)") {
+            Expr::Function(function) => *function,
+            _ => unreachable!("only for function tests"),
+        };
+
+        for expr in function.as_exprs_mut() {
+            *expr = Expr::Nested(Box::new(expr.clone()));
+        }
+
+        assert_eq!(
+            Expr::Function(Box::new(function)),
+            expr("SUBSTR((name), (1), (2))")
+        );
+    }
 }
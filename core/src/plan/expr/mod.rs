@@ -2,7 +2,7 @@ mod aggregate;
 mod function;
 
 use {
-    crate::ast::{Expr, Query},
+    crate::ast::{Aggregate, Expr, GroupByModifier, OrderByExpr, Query, WindowFunction},
     std::iter::once,
 };
 
@@ -22,7 +22,9 @@ pub enum PlanExpr<'a> {
 impl<'a> From<&'a Expr> for PlanExpr<'a> {
     fn from(expr: &'a Expr) -> Self {
         match expr {
-            Expr::Literal(_) | Expr::TypedString { .. } => PlanExpr::None,
+            Expr::Literal(_) | Expr::TypedString { .. } | Expr::Default | Expr::Parameter(_) => {
+                PlanExpr::None
+            }
             Expr::Identifier(ident) => PlanExpr::Identifier(ident),
             Expr::CompoundIdentifier { alias, ident } => {
                 PlanExpr::CompoundIdentifier { alias, ident }
@@ -32,14 +34,55 @@ impl<'a> From<&'a Expr> for PlanExpr<'a> {
             | Expr::IsNull(expr)
             | Expr::IsNotNull(expr)
             | Expr::Interval { expr, .. } => PlanExpr::Expr(expr),
-            Expr::Aggregate(aggregate) => match aggregate.as_expr() {
-                Some(expr) => PlanExpr::Expr(expr),
-                None => PlanExpr::None,
+            Expr::Aggregate(aggregate) => match aggregate.as_ref() {
+                Aggregate::StringAgg { expr, order_by, .. } if !order_by.is_empty() => {
+                    let exprs = once(expr)
+                        .chain(order_by.iter().map(|OrderByExpr { expr, .. }| expr))
+                        .collect();
+
+                    PlanExpr::MultiExprs(exprs)
+                }
+                Aggregate::JsonObjectAgg { key, value, .. } => PlanExpr::TwoExprs(key, value),
+                Aggregate::PercentileCont { fraction, expr }
+                | Aggregate::PercentileDisc { fraction, expr } => {
+                    PlanExpr::TwoExprs(fraction, expr)
+                }
+                aggregate => match aggregate.as_expr() {
+                    Some(expr) => PlanExpr::Expr(expr),
+                    None => PlanExpr::None,
+                },
             },
-            Expr::BinaryOp { left, right, .. } => PlanExpr::TwoExprs(left, right),
-            Expr::Like { expr, pattern, .. } | Expr::ILike { expr, pattern, .. } => {
-                PlanExpr::TwoExprs(expr, pattern)
+            Expr::Window(window) => {
+                let function = match &window.function {
+                    WindowFunction::Aggregate(aggregate) => {
+                        aggregate.as_expr().into_iter().collect()
+                    }
+                    WindowFunction::RowNumber
+                    | WindowFunction::Rank
+                    | WindowFunction::DenseRank => Vec::new(),
+                    WindowFunction::Lag {
+                        expr,
+                        offset,
+                        default,
+                    }
+                    | WindowFunction::Lead {
+                        expr,
+                        offset,
+                        default,
+                    } => once(expr).chain(offset).chain(default).collect(),
+                };
+                let exprs = function
+                    .into_iter()
+                    .chain(window.partition_by.iter())
+                    .chain(window.order_by.iter().map(|OrderByExpr { expr, .. }| expr))
+                    .collect();
+
+                PlanExpr::MultiExprs(exprs)
             }
+            Expr::BinaryOp { left, right, .. } => PlanExpr::TwoExprs(left, right),
+            Expr::Like { expr, pattern, .. }
+            | Expr::ILike { expr, pattern, .. }
+            | Expr::SimilarTo { expr, pattern, .. } => PlanExpr::TwoExprs(expr, pattern),
             Expr::Between {
                 expr, low, high, ..
             } => PlanExpr::ThreeExprs(expr, low, high),
@@ -69,6 +112,16 @@ impl<'a> From<&'a Expr> for PlanExpr<'a> {
                 let exprs = indexes.iter().chain(once(obj.as_ref())).collect();
                 PlanExpr::MultiExprs(exprs)
             }
+            Expr::Tuple(exprs) | Expr::Array { elem: exprs } => {
+                PlanExpr::MultiExprs(exprs.iter().collect())
+            }
+            Expr::GroupByModifier(modifier) => match modifier.as_ref() {
+                GroupByModifier::Rollup(elems)
+                | GroupByModifier::Cube(elems)
+                | GroupByModifier::GroupingSets(elems) => {
+                    PlanExpr::MultiExprs(elems.iter().flatten().collect())
+                }
+            },
             Expr::Function(function) => PlanExpr::MultiExprs(function.as_exprs().collect()),
             Expr::Subquery(subquery) | Expr::Exists { subquery, .. } => PlanExpr::Query(subquery),
             Expr::InSubquery {
@@ -171,6 +224,12 @@ mod tests {
         let expected = PlanExpr::TwoExprs(&target, &pattern);
         test!(actual, expected);
 
+        let actual = expr("JSON_OBJECT_AGG(id, name)");
+        let key = expr("id");
+        let value = expr("name");
+        let expected = PlanExpr::TwoExprs(&key, &value);
+        test!(actual, expected);
+
         let actual = expr("name ILIKE '_foo%'");
         let target = expr("name");
         let pattern = expr("'_foo%'");
@@ -217,6 +276,11 @@ mod tests {
         let expected = PlanExpr::MultiExprs(expected.iter().collect());
         test!(actual, expected);
 
+        let actual = expr("LISTAGG(name, ',') WITHIN GROUP (ORDER BY id)");
+        let expected = ["name", "id"].into_iter().map(expr).collect::<Vec<_>>();
+        let expected = PlanExpr::MultiExprs(expected.iter().collect());
+        test!(actual, expected);
+
         let actual = expr("CAST(0 AS BOOLEAN)");
         let expected = ["0"].into_iter().map(expr).collect::<Vec<_>>();
         let expected = PlanExpr::MultiExprs(expected.iter().collect());
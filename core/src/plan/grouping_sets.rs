@@ -0,0 +1,424 @@
+use {
+    super::{context::Context, planner::Planner},
+    crate::{
+        ast::{
+            AstLiteral, Expr, Function, GroupByModifier, Join, OrderByExpr, Query, Select,
+            SelectItem, SetExpr, SetOperator, Statement, TableFactor, TableWithJoins,
+        },
+        data::Schema,
+    },
+    itertools::Itertools,
+    std::{collections::HashMap, rc::Rc},
+};
+
+/// Expands `ROLLUP`, `CUBE` and `GROUPING SETS` into a `SELECT` for each grouping set, and
+/// combines them with `UNION ALL`. Expressions grouped out of a set are replaced with `NULL`, and
+/// `GROUPING(...)` with a constant.
+pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
+    let planner = GroupingSetsPlanner { schema_map };
+
+    match statement {
+        Statement::Query(query) => {
+            let query = planner.query(None, query);
+
+            Statement::Query(query)
+        }
+        _ => statement,
+    }
+}
+
+struct GroupingSetsPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+}
+
+impl<'a> Planner<'a> for GroupingSetsPlanner<'a> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        // ORDER BY of the combined result is evaluated on the output rows, which are labeled
+        let order_by = match &body {
+            SetExpr::Select(select) if grouping_sets(&select.group_by).len() > 1 => {
+                relabel(&select.projection, order_by)
+            }
+            _ => order_by,
+        };
+        let body = self.set_expr(outer_context, body);
+
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, name: &str) -> Option<&'a Schema> {
+        self.schema_map.get(name)
+    }
+}
+
+impl<'a> GroupingSetsPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, body: SetExpr) -> SetExpr {
+        match body {
+            SetExpr::Select(select) => self.select(outer_context, *select),
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(outer_context.as_ref().map(Rc::clone), *left)),
+                right: Box::new(self.set_expr(outer_context, *right)),
+            },
+            SetExpr::Recursive {
+                alias,
+                all,
+                base,
+                step,
+                max_recursion,
+            } => SetExpr::Recursive {
+                alias,
+                all,
+                base: Box::new(self.set_expr(outer_context.as_ref().map(Rc::clone), *base)),
+                step: Box::new(self.set_expr(outer_context, *step)),
+                max_recursion,
+            },
+            SetExpr::Values(_) => body,
+        }
+    }
+
+    fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> SetExpr {
+        let Select {
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let context = self.update_context(None, &from.relation);
+        let context = from.joins.iter().fold(context, |context, join| {
+            self.update_context(context, &join.relation)
+        });
+        let outer_context = Context::concat(context, outer_context);
+        let selection = selection.map(|expr| self.subquery_expr(outer_context, expr));
+        let from = self.table_with_joins(from);
+
+        if group_by.is_empty() {
+            return SetExpr::Select(Box::new(Select {
+                projection,
+                from,
+                selection,
+                group_by,
+                having,
+            }));
+        }
+
+        let sets = grouping_sets(&group_by);
+        let exprs = sets.iter().flatten().unique().collect::<Vec<_>>();
+
+        sets.iter()
+            .map(|set| {
+                let replace = |mut expr| {
+                    replace_grouped_out(&mut expr, &exprs, set);
+
+                    expr
+                };
+                let projection = projection
+                    .iter()
+                    .cloned()
+                    .map(|item| match item {
+                        SelectItem::Expr { expr, label } => SelectItem::Expr {
+                            expr: replace(expr),
+                            label,
+                        },
+                        item => item,
+                    })
+                    .collect();
+
+                SetExpr::Select(Box::new(Select {
+                    projection,
+                    from: from.clone(),
+                    selection: selection.clone(),
+                    group_by: set.clone(),
+                    having: having.clone().map(replace),
+                }))
+            })
+            .reduce(|left, right| SetExpr::SetOperation {
+                op: SetOperator::Union,
+                all: true,
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+            .expect("grouping sets are never empty")
+    }
+
+    fn table_with_joins(&self, table_with_joins: TableWithJoins) -> TableWithJoins {
+        let TableWithJoins { relation, joins } = table_with_joins;
+        let relation = self.table_factor(relation);
+        let joins = joins
+            .into_iter()
+            .map(|join| Join {
+                relation: self.table_factor(join.relation),
+                ..join
+            })
+            .collect();
+
+        TableWithJoins { relation, joins }
+    }
+
+    fn table_factor(&self, table_factor: TableFactor) -> TableFactor {
+        match table_factor {
+            TableFactor::Derived {
+                subquery,
+                alias,
+                lateral,
+            } => TableFactor::Derived {
+                subquery: self.query(None, subquery),
+                alias,
+                lateral,
+            },
+            TableFactor::Table { .. }
+            | TableFactor::Series { .. }
+            | TableFactor::Unnest { .. }
+            | TableFactor::Dictionary { .. } => table_factor,
+        }
+    }
+}
+
+/// Lists the grouping sets of `GROUP BY`, which is the cross product of its items.
+fn grouping_sets(group_by: &[Expr]) -> Vec<Vec<Expr>> {
+    group_by.iter().fold(vec![Vec::new()], |sets, expr| {
+        let item_sets = match expr {
+            Expr::GroupByModifier(modifier) => match modifier.as_ref() {
+                GroupByModifier::Rollup(elems) => (0..=elems.len())
+                    .rev()
+                    .map(|len| elems[..len].concat())
+                    .collect(),
+                GroupByModifier::Cube(elems) => (0..1_u64 << elems.len())
+                    .rev()
+                    .map(|mask| {
+                        elems
+                            .iter()
+                            .rev()
+                            .enumerate()
+                            .filter(|(i, _)| mask & (1 << i) != 0)
+                            .rev()
+                            .flat_map(|(_, exprs)| exprs)
+                            .cloned()
+                            .collect()
+                    })
+                    .collect(),
+                GroupByModifier::GroupingSets(sets) if sets.is_empty() => vec![Vec::new()],
+                GroupByModifier::GroupingSets(sets) => sets.clone(),
+            },
+            expr => vec![vec![expr.clone()]],
+        };
+
+        sets.iter()
+            .flat_map(|set| {
+                item_sets
+                    .iter()
+                    .map(move |item_set| set.iter().chain(item_set).cloned().collect())
+            })
+            .collect()
+    })
+}
+
+/// Replaces the expressions in `exprs` which are not grouped by `set` with `NULL`, and
+/// `GROUPING(...)` with a bit mask whose bits are set for the grouped out arguments.
+/// Aggregates and subqueries are left as they are.
+fn replace_grouped_out(expr: &mut Expr, exprs: &[&Expr], set: &[Expr]) {
+    let replaced = match &*expr {
+        grouped if exprs.contains(&grouped) && !set.contains(grouped) => {
+            Some(Expr::Literal(AstLiteral::Null))
+        }
+        Expr::Function(function) => match function.as_ref() {
+            Function::Grouping(args) => args
+                .iter()
+                .try_fold(0_i64, |bits, arg| {
+                    exprs
+                        .contains(&arg)
+                        .then(|| (bits << 1) | i64::from(!set.contains(arg)))
+                })
+                .map(|bits| Expr::Literal(AstLiteral::Number(bits.into()))),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if let Some(replaced) = replaced {
+        *expr = replaced;
+
+        return;
+    }
+
+    let replace = |expr: &mut Expr| replace_grouped_out(expr, exprs, set);
+
+    match expr {
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. }
+        | Expr::InSubquery { expr, .. } => replace(expr),
+        Expr::InList { expr, list, .. } => {
+            replace(expr);
+            list.iter_mut().for_each(replace);
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            replace(expr);
+            replace(low);
+            replace(high);
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            replace(expr);
+            replace(pattern);
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            replace(left);
+            replace(right);
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            operand.iter_mut().for_each(|expr| replace(expr));
+            when_then.iter_mut().for_each(|(when, then)| {
+                replace(when);
+                replace(then);
+            });
+            else_result.iter_mut().for_each(|expr| replace(expr));
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            replace(obj);
+            indexes.iter_mut().for_each(replace);
+        }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => exprs.iter_mut().for_each(replace),
+        Expr::Function(function) => function.as_exprs_mut().for_each(replace),
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Aggregate(_)
+        | Expr::Window(_)
+        | Expr::Exists { .. }
+        | Expr::Subquery(_)
+        | Expr::Default
+        | Expr::Parameter(_)
+        | Expr::GroupByModifier(_) => {}
+    }
+}
+
+/// Replaces the `ORDER BY` expressions which are also selected with their labels.
+fn relabel(projection: &[SelectItem], order_by: Vec<OrderByExpr>) -> Vec<OrderByExpr> {
+    order_by
+        .into_iter()
+        .map(|order_by_expr| {
+            let label = projection.iter().find_map(|item| match item {
+                SelectItem::Expr { expr, label } if expr == &order_by_expr.expr => Some(label),
+                _ => None,
+            });
+
+            match label {
+                Some(label) => OrderByExpr {
+                    expr: Expr::Identifier(label.to_owned()),
+                    ..order_by_expr
+                },
+                None => order_by_expr,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan as plan_grouping_sets,
+        crate::{
+            ast::{Statement, ToSql},
+            mock::{run, MockStorage},
+            parse_sql::parse,
+            plan::fetch_schema_map,
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn plan(storage: &MockStorage, sql: &str) -> String {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let statement = translate(&parsed).expect(sql);
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        match plan_grouping_sets(&schema_map, statement) {
+            Statement::Query(query) => query.to_sql(),
+            _ => unreachable!("only for queries"),
+        }
+    }
+
+    #[test]
+    fn expand_grouping_sets() {
+        let storage = run("
+            CREATE TABLE Sales (
+                region TEXT,
+                city TEXT,
+                amount INTEGER
+            );
+        ");
+
+        let sql = "SELECT region, SUM(amount) AS total FROM Sales GROUP BY ROLLUP(region) ORDER BY region";
+        let actual = plan(&storage, sql);
+        let expected = [
+            r#"SELECT "region" AS "region", SUM("amount") AS "total" FROM "Sales" GROUP BY "region""#,
+            r#"UNION ALL"#,
+            r#"SELECT NULL AS "region", SUM("amount") AS "total" FROM "Sales""#,
+            r#"ORDER BY "region""#,
+        ]
+        .join(" ");
+        assert_eq!(actual, expected, "rollup:\n{sql}");
+
+        let sql = "SELECT region, city, GROUPING(region, city) AS g FROM Sales GROUP BY CUBE(region, city)";
+        let actual = plan(&storage, sql);
+        let expected = [
+            r#"SELECT "region" AS "region", "city" AS "city", 0 AS "g" FROM "Sales" GROUP BY "region", "city""#,
+            r#"UNION ALL"#,
+            r#"SELECT "region" AS "region", NULL AS "city", 1 AS "g" FROM "Sales" GROUP BY "region""#,
+            r#"UNION ALL"#,
+            r#"SELECT NULL AS "region", "city" AS "city", 2 AS "g" FROM "Sales" GROUP BY "city""#,
+            r#"UNION ALL"#,
+            r#"SELECT NULL AS "region", NULL AS "city", 3 AS "g" FROM "Sales""#,
+        ]
+        .join(" ");
+        assert_eq!(actual, expected, "cube:\n{sql}");
+
+        let sql = "SELECT region, COUNT(*) AS cnt FROM Sales GROUP BY region, GROUPING SETS ((city), ()) HAVING COUNT(*) > 1";
+        let actual = plan(&storage, sql);
+        let expected = [
+            r#"SELECT "region" AS "region", COUNT(*) AS "cnt" FROM "Sales" GROUP BY "region", "city" HAVING COUNT(*) > 1"#,
+            r#"UNION ALL"#,
+            r#"SELECT "region" AS "region", COUNT(*) AS "cnt" FROM "Sales" GROUP BY "region" HAVING COUNT(*) > 1"#,
+        ]
+        .join(" ");
+        assert_eq!(actual, expected, "grouping sets:\n{sql}");
+
+        let sql = "SELECT region, GROUPING(region) AS g FROM Sales GROUP BY region";
+        let actual = plan(&storage, sql);
+        let expected =
+            r#"SELECT "region" AS "region", 0 AS "g" FROM "Sales" GROUP BY "region""#.to_owned();
+        assert_eq!(actual, expected, "plain group by:\n{sql}");
+    }
+}
@@ -32,7 +32,7 @@ impl Indexes {
         self.0
             .iter()
             .find(|SchemaIndex { expr, order, .. }| {
-                if expr != &target.expr {
+                if expr != &target.expr || target.nulls_first.is_some() {
                     return false;
                 }
 
@@ -50,6 +50,7 @@ impl Indexes {
 
 fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Query> {
     let Query {
+        with,
         body,
         order_by,
         limit,
@@ -58,8 +59,9 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
 
     let select = match body {
         SetExpr::Select(select) => select,
-        SetExpr::Values(_) => {
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
             return Ok(Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -73,6 +75,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         TableFactor::Table { name, .. } => name,
         TableFactor::Derived { .. } => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -82,6 +85,10 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         TableFactor::Series {
             alias: TableAlias { name, .. },
             ..
+        }
+        | TableFactor::Unnest {
+            alias: TableAlias { name, .. },
+            ..
         } => name,
         TableFactor::Dictionary {
             alias: TableAlias { name, .. },
@@ -93,6 +100,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         Some(Schema { indexes, .. }) => Indexes(indexes.clone()),
         None => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -126,6 +134,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
                 TableFactor::Table { name, alias, .. } => (name, alias),
                 TableFactor::Derived { .. }
                 | TableFactor::Series { .. }
+                | TableFactor::Unnest { .. }
                 | TableFactor::Dictionary { .. } => {
                     return Err(Error::Table(TableError::Unreachable));
                 }
@@ -145,6 +154,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             Ok(Query {
+                with,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vector::from(order_by).pop().0.into(),
                 limit,
@@ -155,6 +165,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             let select = plan_select(schema_map, &indexes, *select)?;
             let body = SetExpr::Select(Box::new(select));
             let query = Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -211,6 +222,7 @@ fn plan_select(
                 TableFactor::Table { name, alias, .. } => (name, alias),
                 TableFactor::Derived { .. }
                 | TableFactor::Series { .. }
+                | TableFactor::Unnest { .. }
                 | TableFactor::Dictionary { .. } => {
                     return Err(Error::Table(TableError::Unreachable));
                 }
@@ -443,7 +455,7 @@ fn is_stateless(expr: &Expr) -> bool {
         | Expr::UnaryOp { expr, .. }
         | Expr::Nested(expr) => is_stateless(expr.as_ref()),
         Expr::Function(func) => match func.as_ref() {
-            Function::Cast { expr, .. } => is_stateless(expr),
+            Function::Cast { expr, .. } | Function::TryCast { expr, .. } => is_stateless(expr),
             _ => false,
         },
         Expr::BinaryOp { left, right, .. } => {
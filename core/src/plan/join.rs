@@ -1,9 +1,12 @@
 use {
-    super::{context::Context, evaluable::check_expr as check_evaluable, planner::Planner},
+    super::{
+        context::Context, evaluable::check_expr as check_evaluable, optimizer::scanned_table,
+        planner::Planner,
+    },
     crate::{
         ast::{
             BinaryOperator, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select,
-            SetExpr, Statement, TableWithJoins,
+            SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
         },
         data::Schema,
     },
@@ -31,6 +34,7 @@ struct JoinPlanner<'a> {
 impl<'a> Planner<'a> for JoinPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
         let Query {
+            with,
             body,
             order_by,
             limit,
@@ -43,10 +47,11 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 
                 SetExpr::Select(Box::new(select))
             }
-            SetExpr::Values(_) => body,
+            SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => body,
         };
 
         Query {
+            with,
             body,
             order_by,
             limit,
@@ -60,6 +65,34 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 }
 
 impl<'a> JoinPlanner<'a> {
+    /// Plans the scans which the optimizer pushes `WHERE` predicates down into like the tables
+    /// they scan
+    fn relation_context(
+        &self,
+        next: Option<Rc<Context<'a>>>,
+        relation: &TableFactor,
+    ) -> Option<Rc<Context<'a>>> {
+        let table = match relation {
+            TableFactor::Derived {
+                subquery,
+                alias: TableAlias { name, columns },
+                lateral: false,
+            } if columns.is_empty() => {
+                scanned_table(subquery).map(|(table_name, _)| TableFactor::Table {
+                    name: table_name.to_owned(),
+                    alias: Some(TableAlias {
+                        name: name.to_owned(),
+                        columns: Vec::new(),
+                    }),
+                    index: None,
+                })
+            }
+            _ => None,
+        };
+
+        self.update_context(next, table.as_ref().unwrap_or(relation))
+    }
+
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
         let Select {
             projection,
@@ -87,7 +120,7 @@ impl<'a> JoinPlanner<'a> {
         table_with_joins: TableWithJoins,
     ) -> (Option<Rc<Context<'a>>>, TableWithJoins) {
         let TableWithJoins { relation, joins } = table_with_joins;
-        let init_context = self.update_context(None, &relation);
+        let init_context = self.relation_context(None, &relation);
         let (context, joins) =
             joins
                 .into_iter()
@@ -116,8 +149,13 @@ impl<'a> JoinPlanner<'a> {
             join_executor,
         } = join;
 
-        if matches!(join_executor, JoinExecutor::Hash { .. }) {
-            let context = self.update_context(inner_context, &relation);
+        let lateral = matches!(
+            relation,
+            TableFactor::Derived { lateral: true, .. } | TableFactor::Unnest { .. }
+        );
+
+        if lateral || matches!(join_executor, JoinExecutor::Hash { .. }) {
+            let context = self.relation_context(inner_context, &relation);
             let join = Join {
                 relation,
                 join_operator,
@@ -135,9 +173,14 @@ impl<'a> JoinPlanner<'a> {
         let (join_op, expr) = match join_operator {
             JoinOperator::Inner(JoinConstraint::On(expr)) => (JoinOp::Inner, expr),
             JoinOperator::LeftOuter(JoinConstraint::On(expr)) => (JoinOp::LeftOuter, expr),
-            JoinOperator::Inner(JoinConstraint::None)
-            | JoinOperator::LeftOuter(JoinConstraint::None) => {
-                let context = self.update_context(inner_context, &relation);
+            JoinOperator::Inner(
+                JoinConstraint::None | JoinConstraint::Using(_) | JoinConstraint::Natural,
+            )
+            | JoinOperator::LeftOuter(
+                JoinConstraint::None | JoinConstraint::Using(_) | JoinConstraint::Natural,
+            )
+            | JoinOperator::FullOuter(_) => {
+                let context = self.relation_context(inner_context, &relation);
                 let join = Join {
                     relation,
                     join_operator,
@@ -148,7 +191,7 @@ impl<'a> JoinPlanner<'a> {
             }
         };
 
-        let current_context = self.update_context(None, &relation);
+        let current_context = self.relation_context(None, &relation);
         let (join_executor, expr) = self.join_expr(
             outer_context,
             inner_context.as_ref().map(Rc::clone),
@@ -163,7 +206,7 @@ impl<'a> JoinPlanner<'a> {
             (JoinOp::LeftOuter, None) => JoinOperator::LeftOuter(JoinConstraint::None),
         };
 
-        let context = self.update_context(inner_context, &relation);
+        let context = self.relation_context(inner_context, &relation);
         let join = Join {
             relation,
             join_operator,
@@ -0,0 +1,494 @@
+use {
+    super::PlanError,
+    crate::{
+        ast::{
+            BinaryOperator, Expr, Function, Join, JoinConstraint, JoinOperator, OrderByExpr, Query,
+            Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            Values,
+        },
+        data::{get_alias, unnest_column, Schema},
+        result::Result,
+    },
+    std::collections::HashMap,
+};
+
+type SchemaMap = HashMap<String, Schema>;
+
+/// Rewrites `JOIN ... USING (...)` and `NATURAL JOIN` to `JOIN ... ON`.
+/// The unqualified references to the joined columns are resolved to the column of the left
+/// relation, or to the non-`NULL` one of both sides after `FULL OUTER JOIN`, and `SELECT *`
+/// returns each joined column once, ahead of the other columns.
+pub fn plan(schema_map: &SchemaMap, mut statement: Statement) -> Result<Statement> {
+    match &mut statement {
+        Statement::Query(query) | Statement::Insert { source: query, .. } => {
+            plan_query(schema_map, query)?;
+        }
+        Statement::CreateTable {
+            source: Some(query),
+            ..
+        } => {
+            plan_query(schema_map, query)?;
+        }
+        _ => {}
+    }
+
+    Ok(statement)
+}
+
+/// Column which both sides of a `USING` or `NATURAL` join share
+struct JoinedColumn {
+    name: String,
+    expr: Expr,
+    /// Relations whose column is merged into this one
+    aliases: Vec<String>,
+}
+
+struct Relation {
+    alias: String,
+    columns: Option<Vec<String>>,
+}
+
+impl Relation {
+    fn new(schema_map: &SchemaMap, table_factor: &TableFactor) -> Self {
+        Self {
+            alias: get_alias(table_factor).to_owned(),
+            columns: get_columns(schema_map, table_factor),
+        }
+    }
+
+    /// Relations whose columns are unknown, such as schemaless tables, may have any column
+    fn may_contain(&self, column: &str) -> bool {
+        self.columns
+            .as_ref()
+            .map(|columns| columns.iter().any(|name| name == column))
+            .unwrap_or(true)
+    }
+}
+
+fn plan_query(schema_map: &SchemaMap, query: &mut Query) -> Result<()> {
+    let Query { body, order_by, .. } = query;
+    let joined = plan_set_expr(schema_map, body)?;
+
+    for OrderByExpr { expr, .. } in order_by {
+        plan_expr(schema_map, &joined, expr)?;
+    }
+
+    Ok(())
+}
+
+fn plan_set_expr(schema_map: &SchemaMap, body: &mut SetExpr) -> Result<Vec<JoinedColumn>> {
+    match body {
+        SetExpr::Select(select) => plan_select(schema_map, select),
+        SetExpr::SetOperation { left, right, .. } => {
+            plan_set_expr(schema_map, left)?;
+            plan_set_expr(schema_map, right)?;
+
+            Ok(Vec::new())
+        }
+        SetExpr::Recursive { base, step, .. } => {
+            plan_set_expr(schema_map, base)?;
+            plan_set_expr(schema_map, step)?;
+
+            Ok(Vec::new())
+        }
+        SetExpr::Values(_) => Ok(Vec::new()),
+    }
+}
+
+fn plan_select(schema_map: &SchemaMap, select: &mut Select) -> Result<Vec<JoinedColumn>> {
+    let Select {
+        projection,
+        from: TableWithJoins { relation, joins },
+        selection,
+        group_by,
+        having,
+    } = select;
+
+    plan_table_factor(schema_map, relation)?;
+
+    let mut relations = vec![Relation::new(schema_map, relation)];
+    let mut joined = Vec::new();
+
+    for Join {
+        relation,
+        join_operator,
+        ..
+    } in joins
+    {
+        plan_table_factor(schema_map, relation)?;
+
+        let right = Relation::new(schema_map, relation);
+        let (join_constraint, full_outer) = match join_operator {
+            JoinOperator::Inner(join_constraint) | JoinOperator::LeftOuter(join_constraint) => {
+                (join_constraint, false)
+            }
+            JoinOperator::FullOuter(join_constraint) => (join_constraint, true),
+        };
+        let columns = match join_constraint {
+            JoinConstraint::On(expr) => {
+                plan_expr(schema_map, &joined, expr)?;
+
+                None
+            }
+            JoinConstraint::None => None,
+            JoinConstraint::Using(columns) => Some(columns.clone()),
+            JoinConstraint::Natural => Some(natural_columns(&relations, &joined, &right)?),
+        };
+
+        if let Some(columns) = columns {
+            *join_constraint = join_on(&relations, &mut joined, &right, columns, full_outer)?
+                .map(JoinConstraint::On)
+                .unwrap_or(JoinConstraint::None);
+        }
+
+        relations.push(right);
+    }
+
+    if !joined.is_empty() {
+        if let Some(items) = expand_wildcard(&relations, &joined) {
+            *projection = std::mem::take(projection)
+                .into_iter()
+                .flat_map(|item| match item {
+                    SelectItem::Wildcard => items.clone(),
+                    item => vec![item],
+                })
+                .collect();
+        }
+    }
+
+    for item in projection.iter_mut() {
+        if let SelectItem::Expr { expr, .. } = item {
+            plan_expr(schema_map, &joined, expr)?;
+        }
+    }
+
+    for expr in selection.iter_mut().chain(group_by).chain(having) {
+        plan_expr(schema_map, &joined, expr)?;
+    }
+
+    Ok(joined)
+}
+
+fn plan_table_factor(schema_map: &SchemaMap, table_factor: &mut TableFactor) -> Result<()> {
+    match table_factor {
+        TableFactor::Derived { subquery, .. } => plan_query(schema_map, subquery),
+        TableFactor::Table { .. }
+        | TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
+        | TableFactor::Dictionary { .. } => Ok(()),
+    }
+}
+
+/// Columns of `right` which the relations on the left also have, in the order of `right`
+fn natural_columns(
+    relations: &[Relation],
+    joined: &[JoinedColumn],
+    right: &Relation,
+) -> Result<Vec<String>> {
+    let columns = right
+        .columns
+        .as_ref()
+        .ok_or_else(|| PlanError::UnknownNaturalJoinColumns(right.alias.to_owned()))?;
+
+    let columns = columns
+        .iter()
+        .filter(|column| {
+            joined.iter().any(|joined| &&joined.name == column)
+                || relations.iter().any(|relation| {
+                    relation
+                        .columns
+                        .as_ref()
+                        .map(|columns| columns.contains(*column))
+                        .unwrap_or(false)
+                })
+        })
+        .cloned()
+        .collect();
+
+    Ok(columns)
+}
+
+/// Builds the join condition which compares each of `columns` on both sides, and merges the
+/// columns into `joined`. `NATURAL JOIN` without any common column has no condition.
+/// A column merged by `FULL OUTER JOIN` takes the value of the right side when the left is `NULL`.
+fn join_on(
+    relations: &[Relation],
+    joined: &mut Vec<JoinedColumn>,
+    right: &Relation,
+    columns: Vec<String>,
+    full_outer: bool,
+) -> Result<Option<Expr>> {
+    let mut condition = None;
+
+    for name in columns {
+        let index = match joined.iter().position(|joined| joined.name == name) {
+            Some(index) => index,
+            None => {
+                let relation = relations
+                    .iter()
+                    .find(|relation| relation.may_contain(&name))
+                    .ok_or_else(|| PlanError::UsingColumnNotFound(name.to_owned()))?;
+
+                joined.push(JoinedColumn {
+                    name: name.to_owned(),
+                    expr: Expr::CompoundIdentifier {
+                        alias: relation.alias.to_owned(),
+                        ident: name.to_owned(),
+                    },
+                    aliases: vec![relation.alias.to_owned()],
+                });
+
+                joined.len() - 1
+            }
+        };
+
+        if !right.may_contain(&name) {
+            return Err(PlanError::UsingColumnNotFound(name).into());
+        }
+
+        let column = &mut joined[index];
+        let right_expr = Expr::CompoundIdentifier {
+            alias: right.alias.to_owned(),
+            ident: name,
+        };
+        let expr = Expr::BinaryOp {
+            left: Box::new(column.expr.clone()),
+            op: BinaryOperator::Eq,
+            right: Box::new(right_expr.clone()),
+        };
+        column.aliases.push(right.alias.to_owned());
+
+        if full_outer {
+            let exprs = vec![column.expr.clone(), right_expr];
+
+            column.expr = Expr::Function(Box::new(Function::Coalesce(exprs)));
+        }
+
+        condition = Some(match condition {
+            Some(condition) => Expr::BinaryOp {
+                left: Box::new(condition),
+                op: BinaryOperator::And,
+                right: Box::new(expr),
+            },
+            None => expr,
+        });
+    }
+
+    Ok(condition)
+}
+
+/// Items which replace `SELECT *`, or `None` when the columns of a relation are unknown
+fn expand_wildcard(relations: &[Relation], joined: &[JoinedColumn]) -> Option<Vec<SelectItem>> {
+    let joined_items = joined.iter().map(|JoinedColumn { name, expr, .. }| {
+        Some(SelectItem::Expr {
+            expr: expr.clone(),
+            label: name.to_owned(),
+        })
+    });
+    let other_items = relations.iter().flat_map(|Relation { alias, columns }| {
+        let columns = match columns {
+            Some(columns) => columns,
+            None => return vec![None],
+        };
+
+        columns
+            .iter()
+            .filter(|column| {
+                !joined
+                    .iter()
+                    .any(|joined| &&joined.name == column && joined.aliases.contains(alias))
+            })
+            .map(|column| {
+                Some(SelectItem::Expr {
+                    expr: Expr::CompoundIdentifier {
+                        alias: alias.to_owned(),
+                        ident: column.to_owned(),
+                    },
+                    label: column.to_owned(),
+                })
+            })
+            .collect()
+    });
+
+    joined_items.chain(other_items).collect()
+}
+
+/// Resolves the unqualified references to the joined columns, and plans the subqueries.
+fn plan_expr(schema_map: &SchemaMap, joined: &[JoinedColumn], expr: &mut Expr) -> Result<()> {
+    if let Expr::Identifier(ident) = expr {
+        if let Some(column) = joined.iter().find(|column| &column.name == ident) {
+            *expr = column.expr.clone();
+        }
+
+        return Ok(());
+    }
+
+    let plan = |expr: &mut Expr| plan_expr(schema_map, joined, expr);
+
+    match expr {
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => plan(expr),
+        Expr::InSubquery { expr, subquery, .. } => {
+            plan(expr)?;
+            plan_query(schema_map, subquery)
+        }
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => {
+            plan_query(schema_map, subquery)
+        }
+        Expr::InList { expr, list, .. } => {
+            plan(expr)?;
+            list.iter_mut().try_for_each(plan)
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            plan(expr)?;
+            plan(low)?;
+            plan(high)
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            plan(expr)?;
+            plan(pattern)
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            plan(left)?;
+            plan(right)
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            operand.iter_mut().try_for_each(|expr| plan(expr))?;
+            when_then.iter_mut().try_for_each(|(when, then)| {
+                plan(when)?;
+                plan(then)
+            })?;
+            else_result.iter_mut().try_for_each(|expr| plan(expr))
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            plan(obj)?;
+            indexes.iter_mut().try_for_each(plan)
+        }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => exprs.iter_mut().try_for_each(plan),
+        Expr::Function(function) => function.as_exprs_mut().try_for_each(plan),
+        Expr::Aggregate(aggregate) => aggregate.as_exprs_mut().try_for_each(plan),
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Window(_)
+        | Expr::Default
+        | Expr::Parameter(_)
+        | Expr::GroupByModifier(_) => Ok(()),
+    }
+}
+
+fn get_columns(schema_map: &SchemaMap, table_factor: &TableFactor) -> Option<Vec<String>> {
+    match table_factor {
+        TableFactor::Table { name, .. } => {
+            schema_map
+                .get(name)?
+                .column_defs
+                .as_ref()
+                .map(|column_defs| {
+                    column_defs
+                        .iter()
+                        .map(|column_def| column_def.name.to_owned())
+                        .collect()
+                })
+        }
+        TableFactor::Derived {
+            alias: TableAlias { columns, .. },
+            ..
+        } if !columns.is_empty() => Some(columns.clone()),
+        TableFactor::Derived { subquery, .. } => get_labels(&subquery.body),
+        TableFactor::Series { .. } => Some(vec!["N".to_owned()]),
+        TableFactor::Unnest { alias, .. } => Some(vec![unnest_column(alias)]),
+        TableFactor::Dictionary { .. } => None,
+    }
+}
+
+pub(super) fn get_labels(body: &SetExpr) -> Option<Vec<String>> {
+    match body {
+        SetExpr::Select(select) => select
+            .projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::Expr { label, .. } => Some(label.to_owned()),
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+            })
+            .collect(),
+        SetExpr::Values(Values(rows)) => rows
+            .first()
+            .map(|row| (1..=row.len()).map(|i| format!("column{i}")).collect()),
+        SetExpr::SetOperation { left, .. } => get_labels(left),
+        SetExpr::Recursive { base, .. } => get_labels(base),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan as plan_join_constraint,
+        crate::{
+            ast::Statement,
+            mock::{run, MockStorage},
+            parse_sql::parse,
+            plan::fetch_schema_map,
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn translate_sql(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).expect(sql)
+    }
+
+    fn plan(storage: &MockStorage, sql: &str) -> Statement {
+        let statement = translate_sql(sql);
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        plan_join_constraint(&schema_map, statement).expect(sql)
+    }
+
+    #[test]
+    fn join_constraint() {
+        let storage = run("
+            CREATE TABLE Player (id INTEGER, name TEXT, created_at INTEGER);
+            CREATE TABLE Item (id INTEGER, player_id INTEGER, created_at INTEGER);
+        ");
+
+        let sql = "SELECT id FROM Player JOIN Item USING (id) WHERE id > 1 ORDER BY id";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql(
+            "SELECT Player.id FROM Player JOIN Item ON Player.id = Item.id
+            WHERE Player.id > 1 ORDER BY Player.id",
+        );
+        assert_eq!(actual, expected, "using:\n{sql}");
+
+        let sql = "SELECT * FROM Player NATURAL LEFT JOIN Item";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql(
+            "SELECT Player.id, Player.created_at, Player.name, Item.player_id
+            FROM Player
+            LEFT JOIN Item ON Player.id = Item.id AND Player.created_at = Item.created_at",
+        );
+        assert_eq!(actual, expected, "natural join:\n{sql}");
+
+        let sql = "SELECT * FROM Player AS P NATURAL JOIN (SELECT 1 AS num) AS N";
+        let actual = plan(&storage, sql);
+        let expected = translate_sql("SELECT * FROM Player AS P JOIN (SELECT 1 AS num) AS N");
+        assert_eq!(
+            actual, expected,
+            "natural join without common columns:\n{sql}"
+        );
+    }
+}
@@ -1,27 +1,121 @@
+mod alias;
 mod context;
+mod cte;
 mod error;
 mod evaluable;
 mod expr;
+mod grouping_sets;
 mod index;
 mod join;
+mod join_constraint;
+mod optimizer;
 mod planner;
 mod primary_key;
 mod schema;
+mod statistics;
 mod validate;
+mod view;
 
-use crate::{ast::Statement, result::Result, store::Store};
+use {
+    self::cte::{plan as plan_cte, CteSchemas},
+    crate::{ast::Statement, executor::DEFAULT_MAX_RECURSION, result::Result, store::Store},
+};
 
 pub use {
-    self::validate::validate, error::*, index::plan as plan_index, join::plan as plan_join,
-    primary_key::plan as plan_primary_key, schema::fetch_schema_map,
+    self::validate::{disambiguate, validate},
+    alias::plan as plan_alias,
+    error::*,
+    grouping_sets::plan as plan_grouping_sets,
+    index::plan as plan_index,
+    join::plan as plan_join,
+    join_constraint::plan as plan_join_constraint,
+    optimizer::{CostBasedOptimizer, QueryOptimizer},
+    primary_key::plan as plan_primary_key,
+    schema::fetch_schema_map,
+    statistics::{fetch_statistics, ColumnStatistics, StatisticsMap, TableStatistics},
+    view::plan as plan_view,
 };
 
+pub(crate) use expr::PlanExpr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlanOption {
+    /// Resolves ambiguous column references to the first table which has the column,
+    /// instead of returning `PlanError::ColumnReferenceAmbiguous` (MySQL behaviour)
+    pub resolve_ambiguous_column: bool,
+    /// Levels of views defined on other views which are expanded before
+    /// `PlanError::ViewDepthExceeded` is returned
+    pub max_view_depth: usize,
+    /// Times the recursive term of `WITH RECURSIVE` runs before
+    /// `RecursiveCteError::MaxRecursionExceeded` is returned
+    pub max_recursion: usize,
+}
+
+impl Default for PlanOption {
+    fn default() -> Self {
+        Self {
+            resolve_ambiguous_column: false,
+            max_view_depth: 16,
+            max_recursion: DEFAULT_MAX_RECURSION,
+        }
+    }
+}
+
 pub async fn plan<T: Store>(storage: &T, statement: Statement) -> Result<Statement> {
+    plan_with_option(storage, statement, PlanOption::default()).await
+}
+
+pub async fn plan_with_option<T: Store>(
+    storage: &T,
+    statement: Statement,
+    option: PlanOption,
+) -> Result<Statement> {
+    // EXPLAIN shows the statement as it is planned for the execution
+    if let Statement::Explain { verbose, statement } = statement {
+        let statement = plan_statement(storage, *statement, option).await?;
+
+        return Ok(Statement::Explain {
+            verbose,
+            statement: Box::new(statement),
+        });
+    }
+
+    plan_statement(storage, statement, option).await
+}
+
+async fn plan_statement<T: Store>(
+    storage: &T,
+    statement: Statement,
+    option: PlanOption,
+) -> Result<Statement> {
+    // FOR UPDATE only affects the execution, so the query is planned as a plain SELECT
+    let (statement, for_update) = match statement {
+        Statement::SelectForUpdate(query) => (Statement::Query(query), true),
+        statement => (statement, false),
+    };
+
+    let statement = plan_view(storage, statement, option.max_view_depth).await?;
+    let (statement, cte_schemas) = plan_cte(storage, statement, option).await?;
+    let storage = &CteSchemas::new(storage, cte_schemas);
     let schema_map = fetch_schema_map(storage, &statement).await?;
-    validate(&schema_map, &statement)?;
+    let statement = plan_join_constraint(&schema_map, statement)?;
+    let statement = if option.resolve_ambiguous_column {
+        disambiguate(&schema_map, statement)
+    } else {
+        validate(&schema_map, &statement)?;
+
+        statement
+    };
+    let statement = plan_alias(&schema_map, statement);
     let statement = plan_primary_key(&schema_map, statement);
+    let statistics = fetch_statistics(storage, &schema_map).await?;
+    let statement = CostBasedOptimizer::new(&schema_map, &statistics).optimize(statement);
     let statement = plan_index(&schema_map, statement)?;
     let statement = plan_join(&schema_map, statement);
+    let statement = plan_grouping_sets(&schema_map, statement);
 
-    Ok(statement)
+    Ok(match statement {
+        Statement::Query(query) if for_update => Statement::SelectForUpdate(query),
+        statement => statement,
+    })
 }
@@ -0,0 +1,736 @@
+use {
+    super::{
+        expr::PlanExpr,
+        statistics::{StatisticsMap, TableStatistics},
+    },
+    crate::{
+        ast::{
+            BinaryOperator, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select,
+            SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+        },
+        data::Schema,
+    },
+    std::{
+        cmp::Ordering,
+        collections::{BTreeSet, HashMap},
+        iter::once,
+    },
+};
+
+type SchemaMap = HashMap<String, Schema>;
+
+/// Rewrites a statement into an equivalent one which is cheaper to execute.
+pub trait QueryOptimizer {
+    fn optimize(&self, statement: Statement) -> Statement;
+}
+
+/// Optimizes the `SELECT` queries over the tables analyzed by `ANALYZE TABLE`.
+///
+/// - A subquery in `FROM` which only selects every column of a table is replaced with the table.
+/// - `WHERE` predicates on a single table are pushed down into the scan of the table, unless the
+///   table has indexes, which are left to the index planner.
+/// - Inner joins start from the table with the fewest estimated rows, and then join the smallest
+///   table connected to the joined ones. Join conditions are moved to the first join which has
+///   all their tables.
+///
+/// Queries which read a table without statistics are left as they are.
+pub struct CostBasedOptimizer<'a> {
+    schema_map: &'a SchemaMap,
+    statistics: &'a StatisticsMap,
+}
+
+struct Relation<'a> {
+    alias: String,
+    columns: Vec<&'a str>,
+    statistics: &'a TableStatistics,
+    /// Whether the `WHERE` predicates on the relation can filter it before it is joined
+    filterable: bool,
+}
+
+impl QueryOptimizer for CostBasedOptimizer<'_> {
+    fn optimize(&self, statement: Statement) -> Statement {
+        match statement {
+            Statement::Query(query) => Statement::Query(self.query(query)),
+            _ => statement,
+        }
+    }
+}
+
+impl<'a> CostBasedOptimizer<'a> {
+    pub fn new(schema_map: &'a SchemaMap, statistics: &'a StatisticsMap) -> Self {
+        Self {
+            schema_map,
+            statistics,
+        }
+    }
+
+    fn query(&self, query: Query) -> Query {
+        Query {
+            body: self.set_expr(query.body),
+            ..query
+        }
+    }
+
+    fn set_expr(&self, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => SetExpr::Select(Box::new(self.select(*select))),
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(*left)),
+                right: Box::new(self.set_expr(*right)),
+            },
+            SetExpr::Values(_) | SetExpr::Recursive { .. } => set_expr,
+        }
+    }
+
+    fn table_factor(&self, table_factor: TableFactor) -> TableFactor {
+        let (subquery, alias, lateral) = match table_factor {
+            TableFactor::Derived {
+                subquery,
+                alias,
+                lateral,
+            } => (subquery, alias, lateral),
+            _ => return table_factor,
+        };
+
+        let table = match scanned_table(&subquery) {
+            Some((name, None))
+                if alias.columns.is_empty() && self.statistics.contains_key(name) =>
+            {
+                Some(name.to_owned())
+            }
+            _ => None,
+        };
+
+        match table {
+            Some(name) => TableFactor::Table {
+                name,
+                alias: Some(alias),
+                index: None,
+            },
+            None => TableFactor::Derived {
+                subquery: self.query(subquery),
+                alias,
+                lateral,
+            },
+        }
+    }
+
+    fn select(&self, select: Select) -> Select {
+        let Select {
+            projection,
+            from: TableWithJoins { relation, joins },
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let relation = self.table_factor(relation);
+        let joins = joins
+            .into_iter()
+            .map(|join| Join {
+                relation: self.table_factor(join.relation),
+                ..join
+            })
+            .collect::<Vec<_>>();
+
+        let relations = match self.relations(&relation, &joins) {
+            Some(relations) => relations,
+            None => {
+                return Select {
+                    projection,
+                    from: TableWithJoins { relation, joins },
+                    selection,
+                    group_by,
+                    having,
+                }
+            }
+        };
+
+        let inner_joins = joins.iter().all(|join| match &join.join_operator {
+            JoinOperator::Inner(JoinConstraint::On(expr)) => {
+                resolve(&relations, expr, &mut BTreeSet::new())
+            }
+            JoinOperator::Inner(JoinConstraint::None) => true,
+            _ => false,
+        });
+        let wildcard = projection
+            .iter()
+            .any(|item| matches!(item, SelectItem::Wildcard));
+        let ambiguous = projection
+            .iter()
+            .filter_map(|item| match item {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => None,
+            })
+            .chain(&selection)
+            .chain(&group_by)
+            .chain(&having)
+            .any(|expr| is_ambiguous(&relations, expr));
+        let reorder = inner_joins && !wildcard && !ambiguous;
+
+        let mut conjuncts = Vec::new();
+        let mut join_relations = Vec::new();
+        let mut join_operators = Vec::new();
+        for Join {
+            relation,
+            join_operator,
+            join_executor,
+        } in joins
+        {
+            join_relations.push(relation);
+
+            match join_operator {
+                JoinOperator::Inner(JoinConstraint::On(expr)) if reorder => {
+                    split_conjuncts(expr, &mut conjuncts);
+                }
+                join_operator => join_operators.push((join_operator, join_executor)),
+            }
+        }
+
+        let original_selection = selection.clone();
+        if let Some(expr) = selection {
+            split_conjuncts(expr, &mut conjuncts);
+        }
+
+        let mut pushed = vec![Vec::new(); relations.len()];
+        let mut join_predicates = Vec::new();
+        let mut remainder = Vec::new();
+        for expr in conjuncts {
+            let mut found = BTreeSet::new();
+            let resolved = resolve(&relations, &expr, &mut found);
+            let single = match found.len() {
+                1 => found.iter().next().copied(),
+                _ => None,
+            };
+
+            match single {
+                Some(i) if resolved && relations[i].filterable => pushed[i].push(expr),
+                _ if resolved && reorder => join_predicates.push((found, expr)),
+                _ => remainder.push(expr),
+            }
+        }
+
+        if !reorder && pushed.iter().all(Vec::is_empty) {
+            let joins = join_relations
+                .into_iter()
+                .zip(join_operators)
+                .map(|(relation, (join_operator, join_executor))| Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                })
+                .collect();
+
+            return Select {
+                projection,
+                from: TableWithJoins { relation, joins },
+                selection: original_selection,
+                group_by,
+                having,
+            };
+        }
+
+        let estimates = relations
+            .iter()
+            .enumerate()
+            .map(|(i, relation)| {
+                let local_predicates = join_predicates
+                    .iter()
+                    .filter(|(found, _)| found.len() == 1 && found.contains(&i))
+                    .map(|(_, expr)| expr);
+
+                pushed[i]
+                    .iter()
+                    .chain(local_predicates)
+                    .fold(relation.statistics.row_count as f64, |rows, expr| {
+                        rows * relation.statistics.selectivity(expr)
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        let mut table_factors = once(relation)
+            .chain(join_relations)
+            .zip(pushed)
+            .zip(&relations)
+            .map(
+                |((table_factor, pushed), relation)| match conjunction(pushed) {
+                    Some(selection) => filtered_scan(table_factor, &relation.alias, selection),
+                    None => table_factor,
+                },
+            )
+            .enumerate()
+            .collect::<Vec<_>>();
+        let selection = conjunction(remainder);
+
+        if !reorder {
+            let (_, relation) = table_factors.remove(0);
+            let joins = table_factors
+                .into_iter()
+                .zip(join_operators)
+                .map(|((_, relation), (join_operator, join_executor))| Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                })
+                .collect();
+
+            return Select {
+                projection,
+                from: TableWithJoins { relation, joins },
+                selection,
+                group_by,
+                having,
+            };
+        }
+
+        let order = join_order(&estimates, &join_predicates);
+        table_factors.sort_by_key(|(i, _)| order.iter().position(|j| j == i));
+
+        let (first, relation) = table_factors.remove(0);
+        let mut joined = BTreeSet::from([first]);
+        let mut joins = Vec::new();
+        for (i, relation) in table_factors {
+            joined.insert(i);
+
+            let (predicates, rest): (Vec<_>, Vec<_>) = join_predicates
+                .into_iter()
+                .partition(|(found, _)| found.is_subset(&joined));
+            join_predicates = rest;
+
+            let predicates = predicates.into_iter().map(|(_, expr)| expr).collect();
+            let join_constraint = match conjunction(predicates) {
+                Some(expr) => JoinConstraint::On(expr),
+                None => JoinConstraint::None,
+            };
+
+            joins.push(Join {
+                relation,
+                join_operator: JoinOperator::Inner(join_constraint),
+                join_executor: JoinExecutor::NestedLoop,
+            });
+        }
+
+        Select {
+            projection,
+            from: TableWithJoins { relation, joins },
+            selection,
+            group_by,
+            having,
+        }
+    }
+
+    /// Returns the relations of the select, if all of them are analyzed tables
+    fn relations(&self, relation: &TableFactor, joins: &[Join]) -> Option<Vec<Relation<'a>>> {
+        if joins.is_empty() {
+            return None;
+        }
+
+        let full_outer = joins
+            .iter()
+            .any(|join| matches!(join.join_operator, JoinOperator::FullOuter(_)));
+
+        once((relation, true))
+            .chain(joins.iter().map(|join| {
+                let inner = matches!(join.join_operator, JoinOperator::Inner(_));
+
+                (&join.relation, inner)
+            }))
+            .map(|(table_factor, inner)| {
+                let (name, alias) = match table_factor {
+                    TableFactor::Table {
+                        name,
+                        alias,
+                        index: None,
+                    } => (name, alias),
+                    _ => return None,
+                };
+
+                let schema = self.schema_map.get(name)?;
+                let statistics = self.statistics.get(name)?;
+                let columns = schema
+                    .column_defs
+                    .as_ref()?
+                    .iter()
+                    .map(|column_def| column_def.name.as_str())
+                    .collect();
+
+                Some(Relation {
+                    alias: alias
+                        .as_ref()
+                        .map(|TableAlias { name, .. }| name.to_owned())
+                        .unwrap_or_else(|| name.to_owned()),
+                    columns,
+                    statistics,
+                    filterable: inner && !full_outer && schema.indexes.is_empty(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the table and the `WHERE` clause of `SELECT * FROM table WHERE ...`, which is also
+/// the form of the scans the optimizer pushes predicates down into
+pub(super) fn scanned_table(query: &Query) -> Option<(&str, Option<&Expr>)> {
+    let select = match query {
+        Query {
+            with,
+            body: SetExpr::Select(select),
+            order_by,
+            limit: None,
+            offset: None,
+        } if with.is_empty() && order_by.is_empty() => select,
+        _ => return None,
+    };
+
+    match select.as_ref() {
+        Select {
+            projection,
+            from:
+                TableWithJoins {
+                    relation:
+                        TableFactor::Table {
+                            name, index: None, ..
+                        },
+                    joins,
+                },
+            selection,
+            group_by,
+            having: None,
+        } if matches!(projection.as_slice(), [SelectItem::Wildcard])
+            && joins.is_empty()
+            && group_by.is_empty() =>
+        {
+            Some((name.as_str(), selection.as_ref()))
+        }
+        _ => None,
+    }
+}
+
+fn filtered_scan(table_factor: TableFactor, alias: &str, selection: Expr) -> TableFactor {
+    let select = Select {
+        projection: vec![SelectItem::Wildcard],
+        from: TableWithJoins {
+            relation: table_factor,
+            joins: Vec::new(),
+        },
+        selection: Some(selection),
+        group_by: Vec::new(),
+        having: None,
+    };
+
+    TableFactor::Derived {
+        subquery: Query {
+            with: Vec::new(),
+            body: SetExpr::Select(Box::new(select)),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        },
+        alias: TableAlias {
+            name: alias.to_owned(),
+            columns: Vec::new(),
+        },
+        lateral: false,
+    }
+}
+
+/// Starts from the relation with the fewest estimated rows, and then repeatedly joins the
+/// smallest relation which a predicate connects to the joined ones, or the smallest one if
+/// no predicate does.
+fn join_order(estimates: &[f64], predicates: &[(BTreeSet<usize>, Expr)]) -> Vec<usize> {
+    let mut order = Vec::new();
+    let mut remaining = (0..estimates.len()).collect::<Vec<_>>();
+
+    while !remaining.is_empty() {
+        let connected = remaining
+            .iter()
+            .copied()
+            .filter(|i| {
+                predicates
+                    .iter()
+                    .any(|(found, _)| found.contains(i) && found.iter().any(|j| order.contains(j)))
+            })
+            .collect::<Vec<_>>();
+        let candidates = match connected.is_empty() {
+            true => &remaining,
+            false => &connected,
+        };
+
+        let smallest = candidates.iter().copied().min_by(|a, b| {
+            estimates[*a]
+                .partial_cmp(&estimates[*b])
+                .unwrap_or(Ordering::Equal)
+        });
+        let smallest = match smallest {
+            Some(smallest) => smallest,
+            None => break,
+        };
+
+        remaining.retain(|i| *i != smallest);
+        order.push(smallest);
+    }
+
+    order
+}
+
+/// Collects the indexes of the relations which the expression refers to.
+/// Returns `false` if it refers to a column out of the relations, an ambiguous column or a
+/// subquery.
+fn resolve(relations: &[Relation], expr: &Expr, found: &mut BTreeSet<usize>) -> bool {
+    match expr.into() {
+        PlanExpr::None => true,
+        PlanExpr::Identifier(ident) => {
+            let mut matched = relations
+                .iter()
+                .enumerate()
+                .filter(|(_, relation)| relation.columns.contains(&ident));
+
+            match (matched.next(), matched.next()) {
+                (Some((i, _)), None) => {
+                    found.insert(i);
+
+                    true
+                }
+                _ => false,
+            }
+        }
+        PlanExpr::CompoundIdentifier { alias, ident } => {
+            let matched = relations
+                .iter()
+                .position(|relation| relation.alias == alias && relation.columns.contains(&ident));
+
+            match matched {
+                Some(i) => {
+                    found.insert(i);
+
+                    true
+                }
+                None => false,
+            }
+        }
+        PlanExpr::Expr(expr) => resolve(relations, expr, found),
+        PlanExpr::TwoExprs(expr, expr2) => {
+            resolve(relations, expr, found) && resolve(relations, expr2, found)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            resolve(relations, expr, found)
+                && resolve(relations, expr2, found)
+                && resolve(relations, expr3, found)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.iter().all(|expr| resolve(relations, expr, found)),
+        PlanExpr::Query(_) | PlanExpr::QueryAndExpr { .. } => false,
+    }
+}
+
+/// Whether the expression refers to a column without its table name, which more than one
+/// relation has. Which relation it reads from depends on the join order.
+fn is_ambiguous(relations: &[Relation], expr: &Expr) -> bool {
+    match expr.into() {
+        PlanExpr::Identifier(ident) => {
+            relations
+                .iter()
+                .filter(|relation| relation.columns.contains(&ident))
+                .count()
+                > 1
+        }
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => is_ambiguous(relations, expr),
+        PlanExpr::TwoExprs(expr, expr2) => {
+            is_ambiguous(relations, expr) || is_ambiguous(relations, expr2)
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            is_ambiguous(relations, expr)
+                || is_ambiguous(relations, expr2)
+                || is_ambiguous(relations, expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.iter().any(|expr| is_ambiguous(relations, expr)),
+        PlanExpr::None | PlanExpr::CompoundIdentifier { .. } | PlanExpr::Query(_) => false,
+    }
+}
+
+fn split_conjuncts(expr: Expr, conjuncts: &mut Vec<Expr>) {
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_conjuncts(*left, conjuncts);
+            split_conjuncts(*right, conjuncts);
+        }
+        expr => conjuncts.push(expr),
+    }
+}
+
+fn conjunction(exprs: Vec<Expr>) -> Option<Expr> {
+    exprs.into_iter().reduce(|left, right| Expr::BinaryOp {
+        left: Box::new(left),
+        op: BinaryOperator::And,
+        right: Box::new(right),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{CostBasedOptimizer, QueryOptimizer},
+        crate::{
+            ast::Statement,
+            mock::{run, MockStorage},
+            parse_sql::parse,
+            plan::{
+                fetch_schema_map,
+                statistics::{ColumnStatistics, StatisticsMap, TableStatistics},
+            },
+            translate::translate,
+        },
+        futures::executor::block_on,
+    };
+
+    fn translate_sql(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).unwrap()
+    }
+
+    fn optimize(storage: &MockStorage, statistics: &StatisticsMap, sql: &str) -> Statement {
+        let statement = translate_sql(sql);
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        CostBasedOptimizer::new(&schema_map, statistics).optimize(statement)
+    }
+
+    fn table(row_count: i64, columns: &[(&str, i64)]) -> TableStatistics {
+        let columns = columns
+            .iter()
+            .map(|(name, distinct_count)| {
+                let column = ColumnStatistics {
+                    distinct_count: Some(*distinct_count),
+                    min_value: Some("1".to_owned()),
+                    max_value: Some(row_count.to_string()),
+                    ..ColumnStatistics::default()
+                };
+
+                (name.to_string(), column)
+            })
+            .collect();
+
+        TableStatistics { row_count, columns }
+    }
+
+    #[test]
+    fn cost_based_optimizer() {
+        let storage = run("
+            CREATE TABLE Player (id INTEGER, name TEXT);
+            CREATE TABLE Item (id INTEGER, player_id INTEGER, amount INTEGER);
+            CREATE TABLE Empty (id INTEGER);
+        ");
+        let statistics = [
+            ("Player", table(100, &[("id", 100), ("name", 100)])),
+            (
+                "Item",
+                table(1000, &[("id", 1000), ("player_id", 100), ("amount", 1000)]),
+            ),
+        ]
+        .into_iter()
+        .map(|(name, table)| (name.to_owned(), table))
+        .collect();
+
+        let sql = "
+            SELECT Player.name, Item.id
+            FROM Item
+            JOIN Player ON Player.id = Item.player_id
+            WHERE Player.name = 'Mike'
+        ";
+        let expected = "
+            SELECT Player.name, Item.id
+            FROM (SELECT * FROM Player WHERE Player.name = 'Mike') AS Player
+            JOIN Item ON Player.id = Item.player_id
+        ";
+        assert_eq!(
+            optimize(&storage, &statistics, sql),
+            translate_sql(expected),
+            "filter pushed down and joined first:\n{sql}"
+        );
+
+        let sql = "
+            SELECT Player.name, Item.id
+            FROM Item
+            LEFT JOIN Player ON Player.id = Item.player_id
+            WHERE Item.amount > 900 AND Player.name = 'Mike'
+        ";
+        let expected = "
+            SELECT Player.name, Item.id
+            FROM (SELECT * FROM Item WHERE Item.amount > 900) AS Item
+            LEFT JOIN Player ON Player.id = Item.player_id
+            WHERE Player.name = 'Mike'
+        ";
+        assert_eq!(
+            optimize(&storage, &statistics, sql),
+            translate_sql(expected),
+            "outer joined table is not filtered before the join:\n{sql}"
+        );
+
+        let sql = "
+            SELECT *
+            FROM Item
+            JOIN Player ON Player.id = Item.player_id
+            WHERE Player.name = 'Mike'
+        ";
+        let expected = "
+            SELECT *
+            FROM Item
+            JOIN (SELECT * FROM Player WHERE Player.name = 'Mike') AS Player
+                ON Player.id = Item.player_id
+        ";
+        assert_eq!(
+            optimize(&storage, &statistics, sql),
+            translate_sql(expected),
+            "wildcard keeps the join order:\n{sql}"
+        );
+
+        let sql = "
+            SELECT Player.name, Item.id
+            FROM Player
+            JOIN Item
+            WHERE Item.player_id = Player.id AND Item.id = 1
+        ";
+        let expected = "
+            SELECT Player.name, Item.id
+            FROM (SELECT * FROM Item WHERE Item.id = 1) AS Item
+            JOIN Player ON Item.player_id = Player.id
+        ";
+        assert_eq!(
+            optimize(&storage, &statistics, sql),
+            translate_sql(expected),
+            "join condition moved from WHERE:\n{sql}"
+        );
+
+        let sql = "SELECT * FROM (SELECT * FROM Player) AS P";
+        let expected = "SELECT * FROM Player AS P";
+        assert_eq!(
+            optimize(&storage, &statistics, sql),
+            translate_sql(expected),
+            "redundant projection:\n{sql}"
+        );
+
+        let sql = "
+            SELECT Player.name
+            FROM Item
+            JOIN Empty ON Empty.id = Item.id
+            JOIN Player ON Player.id = Item.player_id
+            WHERE Player.name = 'Mike'
+        ";
+        assert_eq!(
+            optimize(&storage, &statistics, sql),
+            translate_sql(sql),
+            "table without statistics:\n{sql}"
+        );
+    }
+}
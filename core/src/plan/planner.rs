@@ -17,7 +17,8 @@ pub trait Planner<'a> {
             Expr::Identifier(_)
             | Expr::CompoundIdentifier { .. }
             | Expr::Literal(_)
-            | Expr::TypedString { .. } => expr,
+            | Expr::TypedString { .. }
+            | Expr::Parameter(_) => expr,
             Expr::IsNull(expr) => Expr::IsNull(Box::new(self.subquery_expr(outer_context, *expr))),
             Expr::IsNotNull(expr) => {
                 Expr::IsNotNull(Box::new(self.subquery_expr(outer_context, *expr)))
@@ -109,6 +110,22 @@ pub trait Planner<'a> {
                     pattern,
                 }
             }
+            Expr::SimilarTo {
+                expr,
+                negated,
+                pattern,
+            } => {
+                let expr =
+                    Box::new(self.subquery_expr(outer_context.as_ref().map(Rc::clone), *expr));
+                let pattern =
+                    Box::new(self.subquery_expr(outer_context.as_ref().map(Rc::clone), *pattern));
+
+                Expr::SimilarTo {
+                    expr,
+                    negated,
+                    pattern,
+                }
+            }
             Expr::BinaryOp { left, op, right } => Expr::BinaryOp {
                 left: Box::new(self.subquery_expr(outer_context.as_ref().map(Rc::clone), *left)),
                 op,
@@ -153,6 +170,18 @@ pub trait Planner<'a> {
                 let obj = Box::new(self.subquery_expr(outer_context, *obj));
                 Expr::ArrayIndex { obj, indexes }
             }
+            Expr::Tuple(exprs) => Expr::Tuple(
+                exprs
+                    .into_iter()
+                    .map(|expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr))
+                    .collect(),
+            ),
+            Expr::Array { elem } => Expr::Array {
+                elem: elem
+                    .into_iter()
+                    .map(|expr| self.subquery_expr(outer_context.as_ref().map(Rc::clone), expr))
+                    .collect(),
+            },
             Expr::Interval {
                 expr,
                 leading_field,
@@ -167,13 +196,19 @@ pub trait Planner<'a> {
                     expr: self.subquery_expr(outer_context, expr),
                     data_type,
                 })),
+                Function::TryCast { expr, data_type } => {
+                    Expr::Function(Box::new(Function::TryCast {
+                        expr: self.subquery_expr(outer_context, expr),
+                        data_type,
+                    }))
+                }
                 Function::Extract { field, expr } => Expr::Function(Box::new(Function::Extract {
                     field,
                     expr: self.subquery_expr(outer_context, expr),
                 })),
                 _ => Expr::Function(func),
             },
-            Expr::Aggregate(_) => expr,
+            Expr::Aggregate(_) | Expr::Window(_) | Expr::Default | Expr::GroupByModifier(_) => expr,
         }
     }
 
@@ -190,6 +225,7 @@ pub trait Planner<'a> {
             }
             TableFactor::Derived { .. }
             | TableFactor::Series { .. }
+            | TableFactor::Unnest { .. }
             | TableFactor::Dictionary { .. } => return next,
         };
 
@@ -35,7 +35,9 @@ impl<'a> Planner<'a> for PrimaryKeyPlanner<'a> {
 
                 SetExpr::Select(Box::new(select))
             }
-            SetExpr::Values(_) => query.body,
+            SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+                query.body
+            }
         };
 
         Query { body, ..query }
@@ -241,6 +243,7 @@ mod tests {
 
     fn select(select: Select) -> Statement {
         Statement::Query(Query {
+            with: Vec::new(),
             body: SetExpr::Select(Box::new(select)),
             limit: None,
             offset: None,
@@ -448,6 +451,7 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(Select {
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
@@ -502,6 +506,7 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(Select {
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("name".to_owned()),
@@ -553,6 +558,7 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: Vec::new(),
                 body: SetExpr::Select(Box::new(Select {
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("id".to_owned()),
@@ -600,6 +606,7 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = Statement::Delete {
             table_name: "Player".to_owned(),
+            alias: None,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("id".to_owned())),
                 op: BinaryOperator::Eq,
@@ -611,6 +618,7 @@ mod tests {
         let sql = "VALUES (1), (2);";
         let actual = plan(&storage, sql);
         let expected = Statement::Query(Query {
+            with: Vec::new(),
             body: SetExpr::Values(Values(vec![
                 vec![Expr::Literal(AstLiteral::Number(1.into()))],
                 vec![Expr::Literal(AstLiteral::Number(2.into()))],
@@ -73,7 +73,9 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
 
     let schema_list = match body {
         SetExpr::Select(select) => scan_select(storage, select).await?,
-        SetExpr::Values(_) => HashMap::new(),
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+            HashMap::new()
+        }
     };
 
     let schema_list = match (limit, offset) {
@@ -155,13 +157,15 @@ async fn scan_join<T: Store>(storage: &T, join: &Join) -> Result<HashMap<String,
     let schema_list = scan_table_factor(storage, relation).await?;
     let schema_list = match join_operator {
         JoinOperator::Inner(JoinConstraint::On(expr))
-        | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
             .await?
             .into_iter()
             .chain(schema_list)
             .collect(),
-        JoinOperator::Inner(JoinConstraint::None)
-        | JoinOperator::LeftOuter(JoinConstraint::None) => schema_list,
+        JoinOperator::Inner(_) | JoinOperator::LeftOuter(_) | JoinOperator::FullOuter(_) => {
+            schema_list
+        }
     };
 
     Ok(schema_list)
@@ -182,7 +186,9 @@ async fn scan_table_factor<T: Store>(
             Ok(schema_list)
         }
         TableFactor::Derived { subquery, .. } => scan_query(storage, subquery).await,
-        TableFactor::Series { .. } | TableFactor::Dictionary { .. } => Ok(HashMap::new()),
+        TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
+        | TableFactor::Dictionary { .. } => Ok(HashMap::new()),
     }
 }
 
@@ -0,0 +1,281 @@
+use {
+    crate::{
+        ast::{AstLiteral, BinaryOperator, Expr, UnaryOperator},
+        data::{Schema, Value},
+        executor::STATISTICS_TABLE,
+        result::Result,
+        store::{DataRow, Store},
+    },
+    bigdecimal::ToPrimitive,
+    std::collections::HashMap,
+};
+
+/// Selectivity of `column = value` when the number of distinct values is unknown
+const EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a comparison like `column > value` which cannot be estimated from the range
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of the other predicates
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+
+pub type StatisticsMap = HashMap<String, TableStatistics>;
+
+/// Statistics of a table collected by `ANALYZE TABLE`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableStatistics {
+    pub row_count: i64,
+    pub columns: HashMap<String, ColumnStatistics>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnStatistics {
+    pub null_count: i64,
+    pub distinct_count: Option<i64>,
+    pub min_value: Option<String>,
+    pub max_value: Option<String>,
+}
+
+/// Reads the statistics of the tables in `schema_map` from `__gluesql_statistics`.
+/// Tables which have never been analyzed have no entry.
+pub async fn fetch_statistics<T: Store>(
+    storage: &T,
+    schema_map: &HashMap<String, Schema>,
+) -> Result<StatisticsMap> {
+    let mut statistics = StatisticsMap::new();
+    if schema_map.is_empty() || storage.fetch_schema(STATISTICS_TABLE).await?.is_none() {
+        return Ok(statistics);
+    }
+
+    for result in storage.scan_data(STATISTICS_TABLE).await? {
+        let values = match result? {
+            (_, DataRow::Vec(values)) => values,
+            (_, DataRow::Map(_)) => continue,
+        };
+
+        let [table_name, column_name, row_count, null_count, distinct_count, min, max] =
+            match <[Value; 7]>::try_from(values) {
+                Ok(values) => values,
+                Err(_) => continue,
+            };
+        let (table_name, column_name, row_count, null_count) =
+            match (table_name, column_name, row_count, null_count) {
+                (
+                    Value::Str(table_name),
+                    Value::Str(column_name),
+                    Value::I64(row_count),
+                    Value::I64(null_count),
+                ) => (table_name, column_name, row_count, null_count),
+                _ => continue,
+            };
+
+        if !schema_map.contains_key(&table_name) {
+            continue;
+        }
+
+        let text = |value: Value| match value {
+            Value::Str(text) => Some(text),
+            _ => None,
+        };
+        let column = ColumnStatistics {
+            null_count,
+            distinct_count: match distinct_count {
+                Value::I64(distinct_count) => Some(distinct_count),
+                _ => None,
+            },
+            min_value: text(min),
+            max_value: text(max),
+        };
+
+        let table = statistics.entry(table_name).or_default();
+        table.row_count = row_count;
+        table.columns.insert(column_name, column);
+    }
+
+    Ok(statistics)
+}
+
+impl TableStatistics {
+    /// Estimates the fraction of the rows of the table which satisfy the predicate
+    pub fn selectivity(&self, expr: &Expr) -> f64 {
+        let selectivity = match expr {
+            Expr::Nested(expr) => self.selectivity(expr),
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr,
+            } => 1.0 - self.selectivity(expr),
+            Expr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => self.selectivity(left) * self.selectivity(right),
+            Expr::BinaryOp {
+                left,
+                op: BinaryOperator::Or,
+                right,
+            } => {
+                let (left, right) = (self.selectivity(left), self.selectivity(right));
+
+                left + right - left * right
+            }
+            Expr::BinaryOp { left, op, right } => match (self.column(left), self.column(right)) {
+                (Some(column), None) => self.compare(column, op, right),
+                (None, Some(column)) => match op {
+                    BinaryOperator::Gt => self.compare(column, &BinaryOperator::Lt, left),
+                    BinaryOperator::GtEq => self.compare(column, &BinaryOperator::LtEq, left),
+                    BinaryOperator::Lt => self.compare(column, &BinaryOperator::Gt, left),
+                    BinaryOperator::LtEq => self.compare(column, &BinaryOperator::GtEq, left),
+                    _ => self.compare(column, op, left),
+                },
+                _ => DEFAULT_SELECTIVITY,
+            },
+            Expr::IsNull(expr) => match self.column(expr) {
+                Some(column) => self.null_fraction(column),
+                None => DEFAULT_SELECTIVITY,
+            },
+            Expr::IsNotNull(expr) => match self.column(expr) {
+                Some(column) => 1.0 - self.null_fraction(column),
+                None => DEFAULT_SELECTIVITY,
+            },
+            Expr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let selectivity = match self.column(expr) {
+                    Some(column) => list.len() as f64 * self.equal(column),
+                    None => DEFAULT_SELECTIVITY,
+                };
+
+                match negated {
+                    true => 1.0 - selectivity,
+                    false => selectivity,
+                }
+            }
+            Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                let selectivity = match self.column(expr) {
+                    Some(column) => {
+                        self.compare(column, &BinaryOperator::GtEq, low)
+                            * self.compare(column, &BinaryOperator::LtEq, high)
+                    }
+                    None => DEFAULT_SELECTIVITY,
+                };
+
+                match negated {
+                    true => 1.0 - selectivity,
+                    false => selectivity,
+                }
+            }
+            _ => DEFAULT_SELECTIVITY,
+        };
+
+        selectivity.clamp(0.0, 1.0)
+    }
+
+    fn column(&self, expr: &Expr) -> Option<&ColumnStatistics> {
+        match expr {
+            Expr::Identifier(ident) | Expr::CompoundIdentifier { ident, .. } => {
+                self.columns.get(ident)
+            }
+            _ => None,
+        }
+    }
+
+    fn null_fraction(&self, column: &ColumnStatistics) -> f64 {
+        match self.row_count {
+            0 => 0.0,
+            row_count => column.null_count as f64 / row_count as f64,
+        }
+    }
+
+    fn equal(&self, column: &ColumnStatistics) -> f64 {
+        match column.distinct_count {
+            Some(distinct_count) if distinct_count > 0 => {
+                (1.0 - self.null_fraction(column)) / distinct_count as f64
+            }
+            Some(_) => 0.0,
+            None => EQ_SELECTIVITY,
+        }
+    }
+
+    fn compare(&self, column: &ColumnStatistics, op: &BinaryOperator, value: &Expr) -> f64 {
+        let number = |text: &Option<String>| text.as_deref()?.parse::<f64>().ok();
+        let range = match (number(&column.min_value), number(&column.max_value), value) {
+            (Some(min), Some(max), Expr::Literal(AstLiteral::Number(value))) if min < max => {
+                value.to_f64().map(|value| (value - min) / (max - min))
+            }
+            _ => None,
+        };
+        let not_null = 1.0 - self.null_fraction(column);
+
+        match (op, range) {
+            (BinaryOperator::Eq, _) => self.equal(column),
+            (BinaryOperator::NotEq, _) => not_null - self.equal(column),
+            (BinaryOperator::Lt | BinaryOperator::LtEq, Some(below)) => {
+                not_null * below.clamp(0.0, 1.0)
+            }
+            (BinaryOperator::Gt | BinaryOperator::GtEq, Some(below)) => {
+                not_null * (1.0 - below).clamp(0.0, 1.0)
+            }
+            (
+                BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq,
+                None,
+            ) => RANGE_SELECTIVITY,
+            _ => DEFAULT_SELECTIVITY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{ColumnStatistics, TableStatistics},
+        crate::{parse_sql::parse_expr, translate::translate_expr},
+    };
+
+    #[test]
+    fn selectivity() {
+        let column = |null_count, distinct_count| ColumnStatistics {
+            null_count,
+            distinct_count: Some(distinct_count),
+            min_value: Some("0".to_owned()),
+            max_value: Some("100".to_owned()),
+        };
+        let statistics = TableStatistics {
+            row_count: 100,
+            columns: [
+                ("id".to_owned(), column(0, 100)),
+                ("rate".to_owned(), column(50, 10)),
+            ]
+            .into(),
+        };
+
+        let test = |sql: &str, expected: f64| {
+            let expr = translate_expr(&parse_expr(sql).unwrap()).unwrap();
+            let actual = statistics.selectivity(&expr);
+
+            assert!((actual - expected).abs() < 1e-9, "{sql}: {actual}");
+        };
+
+        test("id = 1", 0.01);
+        test("Item.id = 1", 0.01);
+        test("1 = id", 0.01);
+        test("rate = 1", 0.05);
+        test("rate IS NULL", 0.5);
+        test("id > 75", 0.25);
+        test("75 < id", 0.25);
+        test("rate <= 20", 0.1);
+        test("id IN (1, 2, 3)", 0.03);
+        test("id = 1 OR id = 2", 0.0199);
+        test("id = 1 AND rate IS NULL", 0.005);
+        test("NOT id > 75", 0.75);
+        test("id > 'a'", 1.0 / 3.0);
+        test("name = 'a'", 0.5);
+    }
+}
@@ -1,7 +1,10 @@
 use {
-    super::PlanError,
+    super::{expr::PlanExpr, PlanError},
     crate::{
-        ast::{Expr, Join, Query, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins},
+        ast::{
+            Expr, Join, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableAlias,
+            TableFactor, TableWithJoins,
+        },
         data::Schema,
         result::Result,
     },
@@ -9,42 +12,220 @@ use {
 };
 
 type SchemaMap = HashMap<String, Schema>;
-/// Validate user select column should not be ambiguous
+/// Validate unqualified column references of the query should not be ambiguous.
+///
+/// The projection, `WHERE`, `GROUP BY`, `HAVING` and `ORDER BY` of the top-level `SELECT` are
+/// checked, while `JOIN ... ON` constraints and subqueries are not. `ORDER BY` may refer to the
+/// label of a projected column even when the name is shared by the joined tables.
 pub fn validate(schema_map: &SchemaMap, statement: &Statement) -> Result<()> {
-    let query = match statement {
-        Statement::Query(query) => Some(query),
-        Statement::Insert { source, .. } => Some(source),
-        Statement::CreateTable { source, .. } => source.as_deref(),
-        _ => None,
+    let query = match get_query(statement) {
+        Some(query) => query,
+        None => return Ok(()),
     };
 
-    if let Some(query) = query {
-        if let Query {
-            body: SetExpr::Select(select),
-            ..
-        } = query
-        {
-            for select_item in &select.projection {
-                if let SelectItem::Expr {
+    let select = match &query.body {
+        SetExpr::Select(select) => select,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+            return Ok(())
+        }
+    };
+
+    let context = match contextualize_query(schema_map, query) {
+        Some(context) => context,
+        None => return Ok(()),
+    };
+
+    let Select {
+        projection,
+        selection,
+        group_by,
+        having,
+        ..
+    } = select.as_ref();
+
+    let mut idents = Vec::new();
+    let exprs = projection
+        .iter()
+        .filter_map(|select_item| match select_item {
+            SelectItem::Expr { expr, .. } => Some(expr),
+            _ => None,
+        })
+        .chain(selection)
+        .chain(group_by)
+        .chain(having);
+    for expr in exprs {
+        collect_identifiers(expr, &mut idents);
+    }
+
+    let labels = projection
+        .iter()
+        .filter_map(|select_item| match select_item {
+            SelectItem::Expr { label, .. } => Some(label.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for OrderByExpr { expr, .. } in &query.order_by {
+        match expr {
+            Expr::Identifier(ident) if labels.contains(&ident.as_str()) => {}
+            expr => collect_identifiers(expr, &mut idents),
+        }
+    }
+
+    idents
+        .into_iter()
+        .try_for_each(|ident| context.validate_duplicated(ident))
+}
+
+/// Collects the unqualified column references of the expression, except the ones in subqueries.
+fn collect_identifiers<'a>(expr: &'a Expr, idents: &mut Vec<&'a str>) {
+    match PlanExpr::from(expr) {
+        PlanExpr::Identifier(ident) => idents.push(ident),
+        PlanExpr::Expr(expr) | PlanExpr::QueryAndExpr { expr, .. } => {
+            collect_identifiers(expr, idents)
+        }
+        PlanExpr::TwoExprs(expr, expr2) => {
+            collect_identifiers(expr, idents);
+            collect_identifiers(expr2, idents);
+        }
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            collect_identifiers(expr, idents);
+            collect_identifiers(expr2, idents);
+            collect_identifiers(expr3, idents);
+        }
+        PlanExpr::MultiExprs(exprs) => {
+            for expr in exprs {
+                collect_identifiers(expr, idents);
+            }
+        }
+        PlanExpr::None | PlanExpr::CompoundIdentifier { .. } | PlanExpr::Query(_) => {}
+    }
+}
+
+/// Resolve ambiguous column references to the column of the first table which has it,
+/// instead of returning an error (MySQL behaviour)
+pub fn disambiguate(schema_map: &SchemaMap, statement: Statement) -> Statement {
+    let tables_by_column = |query: &Query| -> HashMap<String, String> {
+        let select = match &query.body {
+            SetExpr::Select(select) => select,
+            SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => {
+                return HashMap::new()
+            }
+        };
+        let context = match contextualize_query(schema_map, query) {
+            Some(context) => context,
+            None => return HashMap::new(),
+        };
+
+        select
+            .projection
+            .iter()
+            .filter_map(|select_item| match select_item {
+                SelectItem::Expr {
                     expr: Expr::Identifier(ident),
                     ..
-                } = select_item
-                {
-                    if let Some(context) = contextualize_query(schema_map, query) {
-                        context.validate_duplicated(ident)?;
-                    }
+                } => {
+                    let tables = context.get_tables(ident);
+
+                    (tables.len() > 1).then(|| (ident.to_owned(), tables[0].to_owned()))
                 }
-            }
+                _ => None,
+            })
+            .collect()
+    };
+
+    let disambiguate_query = |query: Query| -> Query {
+        let tables_by_column = tables_by_column(&query);
+
+        if tables_by_column.is_empty() {
+            return query;
         }
+
+        let body = match query.body {
+            SetExpr::Select(select) => {
+                let projection = select
+                    .projection
+                    .into_iter()
+                    .map(|select_item| match select_item {
+                        SelectItem::Expr {
+                            expr: Expr::Identifier(ident),
+                            label,
+                        } => {
+                            let expr = match tables_by_column.get(&ident) {
+                                Some(table) => Expr::CompoundIdentifier {
+                                    alias: table.to_owned(),
+                                    ident,
+                                },
+                                None => Expr::Identifier(ident),
+                            };
+
+                            SelectItem::Expr { expr, label }
+                        }
+                        _ => select_item,
+                    })
+                    .collect();
+
+                SetExpr::Select(Box::new(Select {
+                    projection,
+                    ..*select
+                }))
+            }
+            body @ (SetExpr::Values(_)
+            | SetExpr::SetOperation { .. }
+            | SetExpr::Recursive { .. }) => body,
+        };
+
+        Query { body, ..query }
+    };
+
+    match statement {
+        Statement::Query(query) => Statement::Query(disambiguate_query(query)),
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            on_conflict,
+        } => Statement::Insert {
+            table_name,
+            columns,
+            source: disambiguate_query(source),
+            on_conflict,
+        },
+        Statement::CreateTable {
+            if_not_exists,
+            name,
+            columns,
+            checks,
+            foreign_keys,
+            sequences,
+            source: Some(source),
+            engine,
+        } => Statement::CreateTable {
+            if_not_exists,
+            name,
+            columns,
+            checks,
+            foreign_keys,
+            sequences,
+            source: Some(Box::new(disambiguate_query(*source))),
+            engine,
+        },
+        _ => statement,
     }
+}
 
-    Ok(())
+fn get_query(statement: &Statement) -> Option<&Query> {
+    match statement {
+        Statement::Query(query) => Some(query),
+        Statement::Insert { source, .. } => Some(source),
+        Statement::CreateTable { source, .. } => source.as_deref(),
+        _ => None,
+    }
 }
 
 enum Context<'a> {
     Data {
+        table: &'a str,
         labels: Option<Vec<&'a str>>,
-        next: Option<Rc<Context<'a>>>,
     },
     Bridge {
         left: Rc<Context<'a>>,
@@ -53,8 +234,8 @@ enum Context<'a> {
 }
 
 impl<'a> Context<'a> {
-    fn new(labels: Option<Vec<&'a str>>, next: Option<Rc<Context<'a>>>) -> Self {
-        Self::Data { labels, next }
+    fn new(table: &'a str, labels: Option<Vec<&'a str>>) -> Self {
+        Self::Data { table, labels }
     }
 
     fn concat(left: Option<Rc<Context<'a>>>, right: Option<Rc<Context<'a>>>) -> Option<Rc<Self>> {
@@ -65,38 +246,45 @@ impl<'a> Context<'a> {
         }
     }
 
-    fn validate_duplicated(&self, column_name: &str) -> Result<()> {
-        fn validate(context: &Context, column_name: &str) -> Result<bool> {
-            let (left, right) = match context {
-                Context::Data { labels, next, .. } => {
-                    let current = labels
-                        .as_ref()
-                        .map(|labels| labels.iter().any(|label| *label == column_name))
-                        .unwrap_or(false);
-
-                    let next = next
-                        .as_ref()
-                        .map(|next| validate(next, column_name))
-                        .unwrap_or(Ok(false))?;
-
-                    (current, next)
-                }
-                Context::Bridge { left, right } => {
-                    let left = validate(left, column_name)?;
-                    let right = validate(right, column_name)?;
+    fn get_labels(&self) -> Option<Vec<&'a str>> {
+        match self {
+            Context::Data { labels, .. } => labels.clone(),
+            Context::Bridge { left, right } => match (left.get_labels(), right.get_labels()) {
+                (Some(left), Some(right)) => Some([left, right].concat()),
+                _ => None,
+            },
+        }
+    }
 
-                    (left, right)
-                }
-            };
+    /// Returns names of the tables which contain the given column, in FROM clause order
+    fn get_tables(&self, column_name: &str) -> Vec<&'a str> {
+        match self {
+            Context::Data { table, labels } => {
+                let found = labels
+                    .as_ref()
+                    .map(|labels| labels.iter().any(|label| *label == column_name))
+                    .unwrap_or(false);
 
-            if left && right {
-                Err(PlanError::ColumnReferenceAmbiguous(column_name.to_owned()).into())
-            } else {
-                Ok(left || right)
+                found.then_some(*table).into_iter().collect()
+            }
+            Context::Bridge { left, right } => {
+                [left.get_tables(column_name), right.get_tables(column_name)].concat()
             }
         }
+    }
 
-        validate(self, column_name).map(|_| ())
+    fn validate_duplicated(&self, column_name: &str) -> Result<()> {
+        let tables = self.get_tables(column_name);
+
+        if tables.len() > 1 {
+            return Err(PlanError::ColumnReferenceAmbiguous {
+                column_name: column_name.to_owned(),
+                tables: tables.into_iter().map(ToOwned::to_owned).collect(),
+            }
+            .into());
+        }
+
+        Ok(())
     }
 }
 
@@ -122,7 +310,7 @@ fn contextualize_query<'a>(schema_map: &'a SchemaMap, query: &'a Query) -> Optio
 
             Context::concat(by_table, by_joins)
         }
-        SetExpr::Values(_) => None,
+        SetExpr::Values(_) | SetExpr::SetOperation { .. } | SetExpr::Recursive { .. } => None,
     }
 }
 
@@ -131,12 +319,24 @@ fn contextualize_table_factor<'a>(
     table_factor: &'a TableFactor,
 ) -> Option<Rc<Context<'a>>> {
     match table_factor {
-        TableFactor::Table { name, .. } => {
+        TableFactor::Table { name, alias, .. } => {
+            let table = alias
+                .as_ref()
+                .map(|TableAlias { name, .. }| name)
+                .unwrap_or(name);
             let schema = schema_map.get(name);
-            schema.map(|schema| Rc::from(Context::new(get_labels(schema), None)))
+
+            schema.map(|schema| Context::new(table, get_labels(schema)))
         }
-        TableFactor::Derived { subquery, .. } => contextualize_query(schema_map, subquery),
-        TableFactor::Series { .. } | TableFactor::Dictionary { .. } => None,
+        TableFactor::Derived {
+            subquery,
+            alias: TableAlias { name, .. },
+            ..
+        } => contextualize_query(schema_map, subquery)
+            .map(|context| Context::new(name, context.get_labels())),
+        TableFactor::Series { .. }
+        | TableFactor::Unnest { .. }
+        | TableFactor::Dictionary { .. } => None,
     }
     .map(Rc::from)
 }
@@ -145,13 +345,20 @@ fn contextualize_table_factor<'a>(
 mod tests {
     use {
         crate::{
+            ast::Statement,
             mock::run,
-            plan::{fetch_schema_map, validate},
+            plan::{disambiguate, fetch_schema_map, validate},
             prelude::{parse, translate},
         },
         futures::executor::block_on,
     };
 
+    fn translate_sql(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).expect(sql)
+    }
+
     #[test]
     fn validate_test() {
         let storage = run("
@@ -165,6 +372,26 @@ mod tests {
             ("SELECT * FROM (SELECT * FROM Users) AS Sub", true),
             ("SELECT * FROM SERIES(3)", true),
             ("SELECT id FROM Users A JOIN Users B on A.id = B.id", false),
+            (
+                "SELECT A.id FROM Users A JOIN Users B ON A.id = B.id WHERE id > 1",
+                false,
+            ),
+            (
+                "SELECT A.name, COUNT(*) FROM Users A JOIN Users B ON A.id = B.id GROUP BY name",
+                false,
+            ),
+            (
+                "SELECT A.id FROM Users A JOIN Users B ON A.id = B.id ORDER BY ABS(id)",
+                false,
+            ),
+            (
+                "SELECT A.id AS id FROM Users A JOIN Users B ON A.id = B.id ORDER BY id",
+                true,
+            ),
+            (
+                "SELECT A.id FROM Users A JOIN Users B ON A.id = B.id WHERE A.name = 'a'",
+                true,
+            ),
             (
                 "INSERT INTO Users SELECT id FROM Users A JOIN Users B on A.id = B.id",
                 false,
@@ -176,12 +403,43 @@ mod tests {
         ];
 
         for (sql, expected) in cases {
-            let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
-            let statement = translate(&parsed).unwrap();
+            let statement = translate_sql(sql);
             let schema_map = block_on(fetch_schema_map(&storage, &statement)).unwrap();
             let actual = validate(&schema_map, &statement).is_ok();
 
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn disambiguate_test() {
+        let storage = run("
+            CREATE TABLE Users (id INTEGER, name TEXT);
+            CREATE TABLE Testers (id INTEGER, nickname TEXT);
+        ");
+
+        let cases = [
+            (
+                "SELECT id FROM Users A JOIN Users B on A.id = B.id",
+                "SELECT A.id AS id FROM Users A JOIN Users B on A.id = B.id",
+            ),
+            (
+                "SELECT id, nickname FROM Testers JOIN Users ON Users.id = Testers.id",
+                "SELECT Testers.id AS id, nickname FROM Testers JOIN Users ON Users.id = Testers.id",
+            ),
+            (
+                "SELECT name FROM Users JOIN Testers ON Users.id = Testers.id",
+                "SELECT name FROM Users JOIN Testers ON Users.id = Testers.id",
+            ),
+        ];
+
+        for (sql, expected) in cases {
+            let statement = translate_sql(sql);
+            let schema_map = block_on(fetch_schema_map(&storage, &statement)).unwrap();
+            let actual = disambiguate(&schema_map, statement);
+            let expected = translate_sql(expected);
+
+            assert_eq!(actual, expected, "{sql}");
+        }
+    }
 }
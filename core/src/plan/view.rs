@@ -0,0 +1,298 @@
+use {
+    super::PlanError,
+    crate::{
+        ast::{
+            Assignment, Cte, Expr, Join, JoinConstraint, JoinOperator, OrderByExpr, Query, Select,
+            SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+        },
+        data::{Schema, SchemaView},
+        result::Result,
+        store::Store,
+    },
+    std::collections::{HashMap, HashSet},
+};
+
+/// Expands the views which the statement reads into subqueries of their queries.
+/// The views which those queries read are expanded in turn, up to `max_depth` levels, so a view
+/// which reads itself through other views fails with `PlanError::ViewDepthExceeded`.
+pub async fn plan<T: Store>(
+    storage: &T,
+    mut statement: Statement,
+    max_depth: usize,
+) -> Result<Statement> {
+    if let Statement::Insert { table_name, .. }
+    | Statement::Update { table_name, .. }
+    | Statement::Delete { table_name, .. } = &statement
+    {
+        if let Some(Schema { view: Some(_), .. }) = storage.fetch_schema(table_name).await? {
+            return Err(PlanError::ViewNotUpdatable(table_name.to_owned()).into());
+        }
+    }
+
+    let mut depth = 0;
+
+    loop {
+        let mut names = HashSet::new();
+        plan_statement(&mut statement, &mut |table_factor| {
+            if let TableFactor::Table { name, .. } = table_factor {
+                names.insert(name.to_owned());
+            }
+        });
+
+        let mut views = HashMap::new();
+        for name in names {
+            if let Some(Schema {
+                view: Some(view), ..
+            }) = storage.fetch_schema(&name).await?
+            {
+                views.insert(name, view);
+            }
+        }
+
+        if views.is_empty() {
+            return Ok(statement);
+        } else if depth == max_depth {
+            return Err(PlanError::ViewDepthExceeded(max_depth).into());
+        }
+
+        plan_statement(&mut statement, &mut |table_factor| {
+            expand_view(&views, table_factor)
+        });
+        depth += 1;
+    }
+}
+
+/// Replaces a reference to a view with the subquery of the view, aliased by the name of the view
+/// unless the reference has its own alias.
+fn expand_view(views: &HashMap<String, SchemaView>, table_factor: &mut TableFactor) {
+    let (name, alias) = match table_factor {
+        TableFactor::Table { name, alias, .. } => (name, alias),
+        _ => return,
+    };
+    let SchemaView { columns, query } = match views.get(name) {
+        Some(view) => view,
+        None => return,
+    };
+
+    let alias = match alias.take() {
+        Some(TableAlias {
+            name,
+            columns: mut alias_columns,
+        }) => {
+            alias_columns.extend(columns.iter().skip(alias_columns.len()).cloned());
+
+            TableAlias {
+                name,
+                columns: alias_columns,
+            }
+        }
+        None => TableAlias {
+            name: name.to_owned(),
+            columns: columns.clone(),
+        },
+    };
+
+    *table_factor = TableFactor::Derived {
+        subquery: query.clone(),
+        alias,
+        lateral: false,
+    };
+}
+
+fn plan_statement(statement: &mut Statement, visit: &mut dyn FnMut(&mut TableFactor)) {
+    match statement {
+        Statement::Query(query) | Statement::Insert { source: query, .. } => {
+            plan_query(query, visit);
+        }
+        Statement::CreateTable {
+            source: Some(query),
+            ..
+        } => {
+            plan_query(query, visit);
+        }
+        Statement::Update {
+            assignments,
+            selection,
+            ..
+        } => {
+            for Assignment { value, .. } in assignments {
+                plan_expr(value, visit);
+            }
+
+            if let Some(expr) = selection {
+                plan_expr(expr, visit);
+            }
+        }
+        Statement::Delete {
+            selection: Some(expr),
+            ..
+        } => {
+            plan_expr(expr, visit);
+        }
+        _ => {}
+    }
+}
+
+/// The common table expressions of the query are not views, even when views of the same names
+/// exist.
+fn plan_query(query: &mut Query, visit: &mut dyn FnMut(&mut TableFactor)) {
+    let Query {
+        with,
+        body,
+        order_by,
+        ..
+    } = query;
+
+    let names = with
+        .iter()
+        .map(|Cte { alias, .. }| alias.name.to_owned())
+        .collect::<Vec<_>>();
+    let visit: &mut dyn FnMut(&mut TableFactor) = &mut |table_factor| match table_factor {
+        TableFactor::Table { name, .. } if names.contains(name) => {}
+        _ => visit(table_factor),
+    };
+
+    for Cte { query, .. } in with {
+        plan_query(query, visit);
+    }
+
+    plan_set_expr(body, visit);
+
+    for OrderByExpr { expr, .. } in order_by {
+        plan_expr(expr, visit);
+    }
+}
+
+fn plan_set_expr(body: &mut SetExpr, visit: &mut dyn FnMut(&mut TableFactor)) {
+    match body {
+        SetExpr::Select(select) => plan_select(select, visit),
+        SetExpr::SetOperation { left, right, .. } => {
+            plan_set_expr(left, visit);
+            plan_set_expr(right, visit);
+        }
+        SetExpr::Recursive { base, step, .. } => {
+            plan_set_expr(base, visit);
+            plan_set_expr(step, visit);
+        }
+        SetExpr::Values(_) => {}
+    }
+}
+
+fn plan_select(select: &mut Select, visit: &mut dyn FnMut(&mut TableFactor)) {
+    let Select {
+        projection,
+        from: TableWithJoins { relation, joins },
+        selection,
+        group_by,
+        having,
+    } = select;
+
+    plan_table_factor(relation, visit);
+
+    for Join {
+        relation,
+        join_operator,
+        ..
+    } in joins
+    {
+        plan_table_factor(relation, visit);
+
+        if let JoinOperator::Inner(JoinConstraint::On(expr))
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) = join_operator
+        {
+            plan_expr(expr, visit);
+        }
+    }
+
+    for item in projection.iter_mut() {
+        if let SelectItem::Expr { expr, .. } = item {
+            plan_expr(expr, visit);
+        }
+    }
+
+    for expr in selection.iter_mut().chain(group_by).chain(having) {
+        plan_expr(expr, visit);
+    }
+}
+
+/// Visits the relations of a subquery before the subquery itself, so a view is expanded once
+/// per pass even when its query reads other views.
+fn plan_table_factor(table_factor: &mut TableFactor, visit: &mut dyn FnMut(&mut TableFactor)) {
+    if let TableFactor::Derived { subquery, .. } = table_factor {
+        plan_query(subquery, visit);
+    }
+
+    visit(table_factor);
+}
+
+fn plan_expr(expr: &mut Expr, visit: &mut dyn FnMut(&mut TableFactor)) {
+    match expr {
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => plan_expr(expr, visit),
+        Expr::InSubquery { expr, subquery, .. } => {
+            plan_expr(expr, visit);
+            plan_query(subquery, visit);
+        }
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => plan_query(subquery, visit),
+        Expr::InList { expr, list, .. } => {
+            plan_expr(expr, visit);
+            list.iter_mut().for_each(|expr| plan_expr(expr, visit));
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            plan_expr(expr, visit);
+            plan_expr(low, visit);
+            plan_expr(high, visit);
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            plan_expr(expr, visit);
+            plan_expr(pattern, visit);
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            plan_expr(left, visit);
+            plan_expr(right, visit);
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            operand.iter_mut().for_each(|expr| plan_expr(expr, visit));
+            when_then.iter_mut().for_each(|(when, then)| {
+                plan_expr(when, visit);
+                plan_expr(then, visit);
+            });
+            else_result
+                .iter_mut()
+                .for_each(|expr| plan_expr(expr, visit));
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            plan_expr(obj, visit);
+            indexes.iter_mut().for_each(|expr| plan_expr(expr, visit));
+        }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => {
+            exprs.iter_mut().for_each(|expr| plan_expr(expr, visit));
+        }
+        Expr::Function(function) => function
+            .as_exprs_mut()
+            .for_each(|expr| plan_expr(expr, visit)),
+        Expr::Aggregate(aggregate) => aggregate
+            .as_exprs_mut()
+            .for_each(|expr| plan_expr(expr, visit)),
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Window(_)
+        | Expr::Default
+        | Expr::Parameter(_)
+        | Expr::GroupByModifier(_) => {}
+    }
+}
@@ -0,0 +1,457 @@
+use {
+    crate::{
+        ast::{
+            Assignment, AstLiteral, Cte, Expr, Join, JoinConstraint, JoinOperator, OrderByExpr,
+            Query, SelectItem, SetExpr, Statement, TableFactor, Values,
+        },
+        parse_sql::tokenize,
+        result::Result,
+        translate::translate_tokens,
+    },
+    bigdecimal::{BigDecimal, ToPrimitive},
+    sqlparser::tokenizer::Token,
+    std::{collections::HashMap, str::FromStr},
+};
+
+/// Number of fingerprints kept before the cache is cleared
+const CAPACITY: usize = 1024;
+
+/// The `i`-th number literal of a query is replaced with `NUMBER_MARKER + i`, and the `i`-th
+/// string literal with `STRING_MARKER` followed by `i`, to find where each literal goes in the
+/// translated statements.
+const NUMBER_MARKER: u64 = 1 << 62;
+const STRING_MARKER: &str = "\u{0}literal:";
+
+/// Caches translated statements keyed on the fingerprint of SQL, which replaces literal values
+/// with placeholders. Structurally identical queries share the cached statements, so they are
+/// translated once and re-executed with their own literals injected.
+///
+/// Only `SELECT`, `INSERT`, `UPDATE` and `DELETE` statements are cached.
+#[derive(Default)]
+pub struct PlanCache {
+    /// `None` for the fingerprints whose statements cannot be cached
+    templates: HashMap<String, Option<Template>>,
+}
+
+struct Template {
+    statements: Vec<Statement>,
+    /// Index of the literal of the query which goes to each literal node of the statements,
+    /// in the traversal order
+    slots: Vec<Option<usize>>,
+    num_literals: usize,
+}
+
+impl PlanCache {
+    /// Translates the SQL, and returns whether the statements were taken from the cache.
+    pub fn translate(&mut self, sql: &str) -> Result<(Vec<Statement>, bool)> {
+        let tokens = tokenize(sql)?;
+        let (fingerprint, literals) = match normalize(&tokens) {
+            Some(normalized) => normalized,
+            None => return translate_tokens(tokens).map(|statements| (statements, false)),
+        };
+
+        match self.templates.get(&fingerprint) {
+            Some(Some(template)) => {
+                if let Some(statements) = template.instantiate(&literals) {
+                    return Ok((statements, true));
+                }
+            }
+            Some(None) => return translate_tokens(tokens).map(|statements| (statements, false)),
+            None => {}
+        }
+
+        let probe = probe(&tokens);
+        let statements = translate_tokens(tokens)?;
+        let template = Template::new(probe, literals.len())
+            .filter(|template| template.instantiate(&literals).as_ref() == Some(&statements));
+
+        if self.templates.len() >= CAPACITY {
+            self.templates.clear();
+        }
+        self.templates.insert(fingerprint, template);
+
+        Ok((statements, false))
+    }
+}
+
+impl Template {
+    /// Translates the probe, whose literals are replaced with markers.
+    /// Returns `None` unless every literal of the query is found in the translated statements.
+    fn new(probe: Vec<Token>, num_literals: usize) -> Option<Self> {
+        let mut statements = translate_tokens(probe).ok()?;
+        let mut nodes = Vec::new();
+        for statement in statements.iter_mut() {
+            if !statement_literals(statement, &mut nodes) {
+                return None;
+            }
+        }
+
+        let slots = nodes
+            .into_iter()
+            .map(|literal| marker_index(literal))
+            .collect::<Vec<_>>();
+        let mut found = slots.iter().flatten().copied().collect::<Vec<_>>();
+        found.sort_unstable();
+        if !found.into_iter().eq(0..num_literals) {
+            return None;
+        }
+
+        Some(Self {
+            statements,
+            slots,
+            num_literals,
+        })
+    }
+
+    fn instantiate(&self, literals: &[AstLiteral]) -> Option<Vec<Statement>> {
+        if literals.len() != self.num_literals {
+            return None;
+        }
+
+        let mut statements = self.statements.clone();
+        let mut nodes = Vec::new();
+        for statement in statements.iter_mut() {
+            statement_literals(statement, &mut nodes);
+        }
+
+        for (node, slot) in nodes.into_iter().zip(self.slots.iter()) {
+            if let Some(i) = slot {
+                *node = literals.get(*i)?.clone();
+            }
+        }
+
+        Some(statements)
+    }
+}
+
+/// Returns the fingerprint of the tokens and their literals.
+fn normalize(tokens: &[Token]) -> Option<(String, Vec<AstLiteral>)> {
+    let mut fingerprint = Vec::new();
+    let mut literals = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Whitespace(_) => {}
+            Token::Number(n, _) => {
+                let n = BigDecimal::from_str(n).ok()?;
+
+                fingerprint.push("\u{0}n".to_owned());
+                literals.push(AstLiteral::Number(n));
+            }
+            Token::SingleQuotedString(s) => {
+                fingerprint.push("\u{0}s".to_owned());
+                literals.push(AstLiteral::QuotedString(s.to_owned()));
+            }
+            _ => fingerprint.push(token.to_string()),
+        }
+    }
+
+    Some((fingerprint.join(" "), literals))
+}
+
+fn probe(tokens: &[Token]) -> Vec<Token> {
+    let mut i = 0;
+    let mut next_index = || {
+        i += 1;
+
+        i - 1
+    };
+
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Number(_, long) => {
+                Token::Number((NUMBER_MARKER + next_index() as u64).to_string(), *long)
+            }
+            Token::SingleQuotedString(_) => {
+                Token::SingleQuotedString(format!("{STRING_MARKER}{}", next_index()))
+            }
+            _ => token.clone(),
+        })
+        .collect()
+}
+
+fn marker_index(literal: &AstLiteral) -> Option<usize> {
+    match literal {
+        AstLiteral::Number(n) => {
+            let i = n.to_u64()?.checked_sub(NUMBER_MARKER)?;
+
+            (n == &BigDecimal::from(NUMBER_MARKER + i)).then_some(i as usize)
+        }
+        AstLiteral::QuotedString(s) => s.strip_prefix(STRING_MARKER)?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Collects the literal nodes of the statement in the order they are written in SQL.
+/// Returns `false` if the statement is not cacheable.
+fn statement_literals<'a>(
+    statement: &'a mut Statement,
+    literals: &mut Vec<&'a mut AstLiteral>,
+) -> bool {
+    match statement {
+        Statement::Query(query) | Statement::SelectForUpdate(query) => {
+            query_literals(query, literals);
+        }
+        Statement::Insert { source, .. } => query_literals(source, literals),
+        Statement::Update {
+            assignments,
+            selection,
+            ..
+        } => {
+            for Assignment { value, .. } in assignments {
+                expr_literals(value, literals);
+            }
+
+            if let Some(selection) = selection {
+                expr_literals(selection, literals);
+            }
+        }
+        Statement::Delete {
+            selection: Some(selection),
+            ..
+        } => expr_literals(selection, literals),
+        Statement::Delete { .. } => {}
+        _ => return false,
+    }
+
+    true
+}
+
+fn query_literals<'a>(query: &'a mut Query, literals: &mut Vec<&'a mut AstLiteral>) {
+    let Query {
+        with,
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+
+    for Cte { query, .. } in with {
+        query_literals(query, literals);
+    }
+
+    set_expr_literals(body, literals);
+
+    for OrderByExpr { expr, .. } in order_by {
+        expr_literals(expr, literals);
+    }
+
+    for expr in limit.iter_mut().chain(offset.iter_mut()) {
+        expr_literals(expr, literals);
+    }
+}
+
+fn set_expr_literals<'a>(set_expr: &'a mut SetExpr, literals: &mut Vec<&'a mut AstLiteral>) {
+    match set_expr {
+        SetExpr::Select(select) => {
+            for item in select.projection.iter_mut() {
+                if let SelectItem::Expr { expr, .. } = item {
+                    expr_literals(expr, literals);
+                }
+            }
+
+            table_factor_literals(&mut select.from.relation, literals);
+            for Join {
+                relation,
+                join_operator,
+                ..
+            } in select.from.joins.iter_mut()
+            {
+                table_factor_literals(relation, literals);
+
+                if let JoinOperator::Inner(JoinConstraint::On(expr))
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) = join_operator
+                {
+                    expr_literals(expr, literals);
+                }
+            }
+
+            let exprs = select
+                .selection
+                .iter_mut()
+                .chain(select.group_by.iter_mut())
+                .chain(select.having.iter_mut());
+            for expr in exprs {
+                expr_literals(expr, literals);
+            }
+        }
+        SetExpr::Values(Values(rows)) => {
+            for expr in rows.iter_mut().flatten() {
+                expr_literals(expr, literals);
+            }
+        }
+        SetExpr::SetOperation { left, right, .. }
+        | SetExpr::Recursive {
+            base: left,
+            step: right,
+            ..
+        } => {
+            set_expr_literals(left, literals);
+            set_expr_literals(right, literals);
+        }
+    }
+}
+
+fn table_factor_literals<'a>(
+    table_factor: &'a mut TableFactor,
+    literals: &mut Vec<&'a mut AstLiteral>,
+) {
+    match table_factor {
+        TableFactor::Derived { subquery, .. } => query_literals(subquery, literals),
+        TableFactor::Series { size: expr, .. } | TableFactor::Unnest { expr, .. } => {
+            expr_literals(expr, literals)
+        }
+        TableFactor::Table { .. } | TableFactor::Dictionary { .. } => {}
+    }
+}
+
+/// Literals in functions, aggregates and windows are not collected, which makes the statements
+/// which have them uncacheable.
+fn expr_literals<'a>(expr: &'a mut Expr, literals: &mut Vec<&'a mut AstLiteral>) {
+    match expr {
+        Expr::Literal(literal) => literals.push(literal),
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => expr_literals(expr, literals),
+        Expr::InList { expr, list, .. } => {
+            expr_literals(expr, literals);
+
+            for expr in list {
+                expr_literals(expr, literals);
+            }
+        }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => {
+            for expr in exprs {
+                expr_literals(expr, literals);
+            }
+        }
+        Expr::InSubquery { expr, subquery, .. } => {
+            expr_literals(expr, literals);
+            query_literals(subquery, literals);
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            expr_literals(expr, literals);
+            expr_literals(low, literals);
+            expr_literals(high, literals);
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            expr_literals(expr, literals);
+            expr_literals(pattern, literals);
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            expr_literals(left, literals);
+            expr_literals(right, literals);
+        }
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => {
+            query_literals(subquery, literals);
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            if let Some(operand) = operand {
+                expr_literals(operand, literals);
+            }
+
+            for (when, then) in when_then {
+                expr_literals(when, literals);
+                expr_literals(then, literals);
+            }
+
+            if let Some(else_result) = else_result {
+                expr_literals(else_result, literals);
+            }
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            expr_literals(obj, literals);
+
+            for expr in indexes {
+                expr_literals(expr, literals);
+            }
+        }
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::TypedString { .. }
+        | Expr::Function(_)
+        | Expr::Aggregate(_)
+        | Expr::Window(_)
+        | Expr::Default
+        | Expr::Parameter(_)
+        | Expr::GroupByModifier(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::PlanCache,
+        crate::{ast::Statement, parse_sql::parse, translate::translate},
+    };
+
+    fn translate_sql(sql: &str) -> Vec<Statement> {
+        parse(sql)
+            .unwrap()
+            .iter()
+            .map(|parsed| translate(parsed).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn translate_cached() {
+        let mut cache = PlanCache::default();
+
+        let sql = "SELECT * FROM Item WHERE id = 1 AND name = 'glue' LIMIT 3;";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), false)));
+
+        let sql = "SELECT * FROM Item WHERE id = 5 AND name = 'sql' LIMIT 10;";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), true)));
+
+        let sql = "SELECT  *  FROM Item  WHERE id = 7 AND name = 'x' LIMIT 1; -- comment";
+        assert_eq!(
+            cache.translate(sql),
+            Ok((translate_sql(sql), true)),
+            "whitespaces and comments are ignored"
+        );
+
+        let sql = "UPDATE Item SET name = 'a' WHERE id IN (1, 2);";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), false)));
+        let sql = "UPDATE Item SET name = 'b' WHERE id IN (3, 4);";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), true)));
+    }
+
+    #[test]
+    fn translate_uncacheable() {
+        let mut cache = PlanCache::default();
+
+        let sql = "SELECT 1 FROM Item;";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), false)));
+        let sql = "SELECT 2 FROM Item;";
+        assert_eq!(
+            cache.translate(sql),
+            Ok((translate_sql(sql), false)),
+            "labels depend on literals"
+        );
+
+        let sql = "SELECT * FROM Item WHERE name = UPPER('a');";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), false)));
+        let sql = "SELECT * FROM Item WHERE name = UPPER('b');";
+        assert_eq!(
+            cache.translate(sql),
+            Ok((translate_sql(sql), false)),
+            "literals in functions"
+        );
+
+        let sql = "CREATE TABLE Item (id INTEGER DEFAULT 1);";
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), false)));
+        assert_eq!(cache.translate(sql), Ok((translate_sql(sql), false)));
+    }
+}
@@ -0,0 +1,442 @@
+use {
+    crate::{
+        ast::{
+            Aggregate, Assignment, AstLiteral, Cte, Expr, GroupByModifier, IndexItem, Join,
+            JoinConstraint, JoinOperator, OnConflict, OrderByExpr, Query, Select, SelectItem,
+            SetExpr, Statement, TableFactor, TableWithJoins, Values, WindowFunction,
+        },
+        data::{Value, ValueError},
+        executor::EvaluateError,
+        result::Result,
+    },
+    bigdecimal::BigDecimal,
+    sqlparser::tokenizer::Token,
+    std::str::FromStr,
+};
+
+/// Statement parsed and planned once by `Glue::prepare`, which is executed by
+/// `Glue::execute_prepared` with the values bound to its `$1`, `$2`, ... or `?` parameters.
+///
+/// The statement is not planned again, so it has to be prepared again after the schema of the
+/// tables it reads is changed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedStatement {
+    pub(crate) statement: Statement,
+    pub(crate) num_parameters: usize,
+}
+
+impl PreparedStatement {
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// Number of the values to bind, the largest number of the parameters
+    pub fn num_parameters(&self) -> usize {
+        self.num_parameters
+    }
+}
+
+/// Numbers each `?` placeholder in the order they are written, `?1`, `?2`, ..., and returns the
+/// tokens with the number of the parameters.
+pub(crate) fn number_parameters(tokens: Vec<Token>) -> (Vec<Token>, usize) {
+    let mut num_question_marks = 0;
+    let mut num_parameters = 0;
+
+    let tokens = tokens
+        .into_iter()
+        .map(|token| match token {
+            Token::Placeholder(placeholder) => {
+                let placeholder = match placeholder.as_str() {
+                    "?" => {
+                        num_question_marks += 1;
+
+                        format!("?{num_question_marks}")
+                    }
+                    _ => placeholder,
+                };
+
+                let index = placeholder
+                    .get(1..)
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .unwrap_or_default();
+                num_parameters = num_parameters.max(index);
+
+                Token::Placeholder(placeholder)
+            }
+            token => token,
+        })
+        .collect();
+
+    (tokens, num_parameters)
+}
+
+/// Returns the statement with each parameter replaced by the literal of the value bound to it,
+/// so the values are implicitly cast in the same way as the literals they stand for.
+pub(crate) fn bind_parameters(mut statement: Statement, params: &[Value]) -> Result<Statement> {
+    let mut bind = |expr: &mut Expr| -> Result<()> {
+        if let Expr::Parameter(index) = *expr {
+            let value = params
+                .get(index - 1)
+                .ok_or(EvaluateError::UnboundParameter(index))?;
+
+            // `Expr::try_from` converts a decimal through `f64`, which loses its precision
+            *expr = match value {
+                Value::Decimal(v) => BigDecimal::from_str(&v.to_string())
+                    .map(|n| Expr::Literal(AstLiteral::Number(n)))
+                    .map_err(|_| ValueError::ValueToExprConversionFailure)?,
+                value => Expr::try_from(value.clone())?,
+            };
+        }
+
+        Ok(())
+    };
+
+    bind_statement(&mut statement, &mut bind)?;
+
+    Ok(statement)
+}
+
+fn bind_statement<F>(statement: &mut Statement, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    match statement {
+        Statement::Query(query) | Statement::SelectForUpdate(query) => bind_query(query, bind),
+        Statement::Insert {
+            source,
+            on_conflict,
+            ..
+        } => {
+            bind_query(source, bind)?;
+
+            if let Some(OnConflict::DoUpdate { assignments, .. }) = on_conflict {
+                for Assignment { value, .. } in assignments {
+                    bind_expr(value, bind)?;
+                }
+            }
+
+            Ok(())
+        }
+        Statement::Update {
+            assignments,
+            selection,
+            ..
+        } => {
+            for Assignment { value, .. } in assignments {
+                bind_expr(value, bind)?;
+            }
+
+            selection
+                .iter_mut()
+                .try_for_each(|expr| bind_expr(expr, bind))
+        }
+        Statement::Delete { selection, .. } => selection
+            .iter_mut()
+            .try_for_each(|expr| bind_expr(expr, bind)),
+        Statement::CreateTable {
+            source: Some(source),
+            ..
+        } => bind_query(source, bind),
+        _ => Ok(()),
+    }
+}
+
+fn bind_query<F>(query: &mut Query, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    let Query {
+        with,
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+
+    for Cte { query, .. } in with {
+        bind_query(query, bind)?;
+    }
+
+    bind_set_expr(body, bind)?;
+
+    for OrderByExpr { expr, .. } in order_by {
+        bind_expr(expr, bind)?;
+    }
+
+    limit
+        .iter_mut()
+        .chain(offset.iter_mut())
+        .try_for_each(|expr| bind_expr(expr, bind))
+}
+
+fn bind_set_expr<F>(set_expr: &mut SetExpr, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    match set_expr {
+        SetExpr::Select(select) => bind_select(select, bind),
+        SetExpr::Values(Values(rows)) => rows
+            .iter_mut()
+            .flatten()
+            .try_for_each(|expr| bind_expr(expr, bind)),
+        SetExpr::SetOperation { left, right, .. }
+        | SetExpr::Recursive {
+            base: left,
+            step: right,
+            ..
+        } => {
+            bind_set_expr(left, bind)?;
+            bind_set_expr(right, bind)
+        }
+    }
+}
+
+fn bind_select<F>(select: &mut Select, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    let Select {
+        projection,
+        from: TableWithJoins { relation, joins },
+        selection,
+        group_by,
+        having,
+    } = select;
+
+    for item in projection.iter_mut() {
+        if let SelectItem::Expr { expr, .. } = item {
+            bind_expr(expr, bind)?;
+        }
+    }
+
+    bind_table_factor(relation, bind)?;
+
+    for Join {
+        relation,
+        join_operator,
+        ..
+    } in joins
+    {
+        bind_table_factor(relation, bind)?;
+
+        if let JoinOperator::Inner(JoinConstraint::On(expr))
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) = join_operator
+        {
+            bind_expr(expr, bind)?;
+        }
+    }
+
+    selection
+        .iter_mut()
+        .chain(group_by)
+        .chain(having)
+        .try_for_each(|expr| bind_expr(expr, bind))
+}
+
+fn bind_table_factor<F>(table_factor: &mut TableFactor, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    match table_factor {
+        TableFactor::Table {
+            index: Some(IndexItem::PrimaryKey(expr)),
+            ..
+        }
+        | TableFactor::Table {
+            index:
+                Some(IndexItem::NonClustered {
+                    cmp_expr: Some((_, expr)),
+                    ..
+                }),
+            ..
+        }
+        | TableFactor::Series { size: expr, .. }
+        | TableFactor::Unnest { expr, .. } => bind_expr(expr, bind),
+        TableFactor::Derived { subquery, .. } => bind_query(subquery, bind),
+        TableFactor::Table { .. } | TableFactor::Dictionary { .. } => Ok(()),
+    }
+}
+
+fn bind_expr<F>(expr: &mut Expr, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    match expr {
+        Expr::Parameter(_) => bind(expr),
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => bind_expr(expr, bind),
+        Expr::InSubquery { expr, subquery, .. } => {
+            bind_expr(expr, bind)?;
+            bind_query(subquery, bind)
+        }
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => bind_query(subquery, bind),
+        Expr::InList { expr, list, .. } => {
+            bind_expr(expr, bind)?;
+            list.iter_mut().try_for_each(|expr| bind_expr(expr, bind))
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            bind_expr(expr, bind)?;
+            bind_expr(low, bind)?;
+            bind_expr(high, bind)
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            bind_expr(expr, bind)?;
+            bind_expr(pattern, bind)
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            bind_expr(left, bind)?;
+            bind_expr(right, bind)
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            for expr in operand.iter_mut() {
+                bind_expr(expr, bind)?;
+            }
+
+            for (when, then) in when_then {
+                bind_expr(when, bind)?;
+                bind_expr(then, bind)?;
+            }
+
+            else_result
+                .iter_mut()
+                .try_for_each(|expr| bind_expr(expr, bind))
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            bind_expr(obj, bind)?;
+            indexes
+                .iter_mut()
+                .try_for_each(|expr| bind_expr(expr, bind))
+        }
+        Expr::Tuple(exprs) | Expr::Array { elem: exprs } => {
+            exprs.iter_mut().try_for_each(|expr| bind_expr(expr, bind))
+        }
+        Expr::Function(function) => function
+            .as_exprs_mut()
+            .try_for_each(|expr| bind_expr(expr, bind)),
+        Expr::Aggregate(aggregate) => bind_aggregate(aggregate, bind),
+        Expr::Window(window) => {
+            match &mut window.function {
+                WindowFunction::Aggregate(aggregate) => bind_aggregate(aggregate, bind)?,
+                WindowFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                }
+                | WindowFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                } => {
+                    bind_expr(expr, bind)?;
+
+                    for expr in offset.iter_mut().chain(default.iter_mut()) {
+                        bind_expr(expr, bind)?;
+                    }
+                }
+                WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {}
+            }
+
+            for expr in window.partition_by.iter_mut() {
+                bind_expr(expr, bind)?;
+            }
+
+            window
+                .order_by
+                .iter_mut()
+                .try_for_each(|OrderByExpr { expr, .. }| bind_expr(expr, bind))
+        }
+        Expr::GroupByModifier(modifier) => match modifier.as_mut() {
+            GroupByModifier::Rollup(sets)
+            | GroupByModifier::Cube(sets)
+            | GroupByModifier::GroupingSets(sets) => sets
+                .iter_mut()
+                .flatten()
+                .try_for_each(|expr| bind_expr(expr, bind)),
+        },
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Default => Ok(()),
+    }
+}
+
+fn bind_aggregate<F>(aggregate: &mut Aggregate, bind: &mut F) -> Result<()>
+where
+    F: FnMut(&mut Expr) -> Result<()>,
+{
+    aggregate
+        .as_exprs_mut()
+        .try_for_each(|expr| bind_expr(expr, bind))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{bind_parameters, number_parameters},
+        crate::{
+            ast::Statement,
+            data::Value,
+            executor::EvaluateError,
+            parse_sql::{parse, tokenize},
+            translate::translate,
+        },
+        rust_decimal::Decimal,
+        sqlparser::tokenizer::{Token, Whitespace},
+    };
+
+    fn translate_sql(sql: &str) -> Statement {
+        translate(&parse(sql).unwrap()[0]).unwrap()
+    }
+
+    #[test]
+    fn number_question_marks() {
+        let tokens = tokenize("? ?").unwrap();
+        let expected = vec![
+            Token::Placeholder("?1".to_owned()),
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("?2".to_owned()),
+        ];
+        assert_eq!(number_parameters(tokens), (expected, 2));
+
+        let tokens = tokenize("$3 '?'").unwrap();
+        let (_, num_parameters) = number_parameters(tokens);
+        assert_eq!(num_parameters, 3);
+    }
+
+    #[test]
+    fn bind() {
+        let statement = translate_sql(
+            "SELECT id, ABS($2) FROM Item WHERE id IN (SELECT $1) AND price > $3 LIMIT $1",
+        );
+        let params = [
+            Value::I64(3),
+            Value::Str("a".to_owned()),
+            Value::Decimal(Decimal::new(105, 1)),
+        ];
+        assert_eq!(
+            bind_parameters(statement, &params),
+            Ok(translate_sql(
+                "SELECT id, ABS('a') FROM Item WHERE id IN (SELECT 3) AND price > 10.5 LIMIT 3"
+            ))
+        );
+
+        let statement = translate_sql("UPDATE Item SET id = $1 WHERE id = $2");
+        assert_eq!(
+            bind_parameters(statement, &[Value::I64(1)]),
+            Err(EvaluateError::UnboundParameter(2).into())
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use {
+    crate::{
+        ast::{Expr, Statement, ToSql},
+        data::{Schema, Value},
+        executor::Payload,
+        parse_sql::parse_query,
+        plan::plan_with_option,
+        prelude::Glue,
+        result::Result,
+        store::{GStore, GStoreMut},
+        translate::translate_query,
+    },
+    serde::Serialize,
+    std::fmt::Debug,
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum QueryBuilderError {
+    #[error("table is not specified")]
+    TableNotSpecified,
+
+    #[error("table not found: {0}")]
+    TableNotFound(String),
+
+    #[error("column '{column}' not found in table '{table}'")]
+    ColumnNotFound { table: String, column: String },
+
+    #[error(
+        "number of parameters does not match placeholders, expected {expected} but found {found}"
+    )]
+    ParameterCountMismatch { expected: usize, found: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Builds a `SELECT` statement from chained method calls.
+///
+/// Column names are checked against the schema of the table when the query is built, and the
+/// generated SQL goes through the parser like any other query.
+#[derive(Clone, Debug, Default)]
+pub struct QueryBuilder {
+    columns: Vec<String>,
+    table_name: Option<String>,
+    conditions: Vec<(String, Vec<Value>)>,
+    order_by: Vec<(String, SortOrder)>,
+    limit: Option<usize>,
+}
+
+impl QueryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn select(mut self, columns: &[&str]) -> Self {
+        self.columns
+            .extend(columns.iter().map(|column| (*column).to_owned()));
+        self
+    }
+
+    pub fn from(mut self, table_name: &str) -> Self {
+        self.table_name = Some(table_name.to_owned());
+        self
+    }
+
+    /// Adds a condition whose `?` placeholders are bound to `params` in order.
+    /// Multiple conditions are combined with `AND`.
+    pub fn where_(mut self, condition: &str, params: &[Value]) -> Self {
+        self.conditions
+            .push((condition.to_owned(), params.to_vec()));
+        self
+    }
+
+    pub fn order_by(mut self, column: &str, order: SortOrder) -> Self {
+        self.order_by.push((column.to_owned(), order));
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub async fn build<T: GStore>(&self, storage: &T) -> Result<String> {
+        let table_name = self
+            .table_name
+            .as_ref()
+            .ok_or(QueryBuilderError::TableNotSpecified)?;
+        let Schema { column_defs, .. } = storage
+            .fetch_schema(table_name)
+            .await?
+            .ok_or_else(|| QueryBuilderError::TableNotFound(table_name.to_owned()))?;
+
+        if let Some(column_defs) = column_defs {
+            let columns = self
+                .columns
+                .iter()
+                .chain(self.order_by.iter().map(|(column, _)| column));
+
+            for column in columns {
+                if !column_defs
+                    .iter()
+                    .any(|column_def| &column_def.name == column)
+                {
+                    return Err(QueryBuilderError::ColumnNotFound {
+                        table: table_name.to_owned(),
+                        column: column.to_owned(),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        let projection = match self.columns.is_empty() {
+            true => "*".to_owned(),
+            false => self
+                .columns
+                .iter()
+                .map(|column| format!(r#""{column}""#))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        let mut sql = format!(r#"SELECT {projection} FROM "{table_name}""#);
+
+        if !self.conditions.is_empty() {
+            let conditions = self
+                .conditions
+                .iter()
+                .map(|(condition, params)| bind(condition, params).map(|c| format!("({c})")))
+                .collect::<Result<Vec<_>>>()?
+                .join(" AND ");
+
+            sql += &format!(" WHERE {conditions}");
+        }
+
+        if !self.order_by.is_empty() {
+            let order_by = self
+                .order_by
+                .iter()
+                .map(|(column, order)| {
+                    let order = match order {
+                        SortOrder::Asc => "ASC",
+                        SortOrder::Desc => "DESC",
+                    };
+
+                    format!(r#""{column}" {order}"#)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            sql += &format!(" ORDER BY {order_by}");
+        }
+
+        if let Some(limit) = self.limit {
+            sql += &format!(" LIMIT {limit}");
+        }
+
+        Ok(sql)
+    }
+
+    pub async fn execute<T: GStore + GStoreMut>(&self, glue: &mut Glue<T>) -> Result<Payload> {
+        let sql = self.build(&glue.storage).await?;
+        let query = parse_query(sql).and_then(|query| translate_query(&query))?;
+        let statement =
+            plan_with_option(&glue.storage, Statement::Query(query), glue.plan_option).await?;
+
+        glue.execute_stmt(&statement).await
+    }
+}
+
+/// Replaces `?` placeholders outside of quoted strings and identifiers with SQL literals.
+fn bind(condition: &str, params: &[Value]) -> Result<String> {
+    let mut bound = String::with_capacity(condition.len());
+    let mut params_iter = params.iter();
+    let mut quote = None;
+    let mut num_placeholders = 0;
+
+    for c in condition.chars() {
+        match (c, quote) {
+            ('?', None) => {
+                num_placeholders += 1;
+
+                match params_iter.next() {
+                    Some(param) => bound += &Expr::try_from(param.clone())?.to_sql(),
+                    None => continue,
+                }
+            }
+            ('\'' | '"', None) => {
+                quote = Some(c);
+                bound.push(c);
+            }
+            (_, Some(q)) if c == q => {
+                quote = None;
+                bound.push(c);
+            }
+            _ => bound.push(c),
+        }
+    }
+
+    if num_placeholders != params.len() {
+        return Err(QueryBuilderError::ParameterCountMismatch {
+            expected: num_placeholders,
+            found: params.len(),
+        }
+        .into());
+    }
+
+    Ok(bound)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{bind, QueryBuilderError},
+        crate::data::Value,
+    };
+
+    #[test]
+    fn bind_params() {
+        let actual = bind(
+            "id > ? AND name = ?",
+            &[Value::I32(5), Value::Str("Glue".to_owned())],
+        );
+        assert_eq!(actual, Ok("id > 5 AND name = 'Glue'".to_owned()));
+
+        let actual = bind("name = '?' AND id = ?", &[Value::I64(1)]);
+        assert_eq!(actual, Ok("name = '?' AND id = 1".to_owned()));
+
+        let actual = bind("id = ?", &[]);
+        assert_eq!(
+            actual,
+            Err(QueryBuilderError::ParameterCountMismatch {
+                expected: 1,
+                found: 0
+            }
+            .into())
+        );
+    }
+}
@@ -3,14 +3,16 @@ use {serde::Serialize, std::fmt::Debug, thiserror::Error as ThisError};
 pub use crate::{
     ast_builder::AstBuilderError,
     data::{
-        IntervalError, KeyError, LiteralError, RowError, SchemaParseError, StringExtError,
-        TableError, ValueError,
+        EnumTypeError, IntervalError, KeyError, LiteralError, RowError, SchemaParseError,
+        SequenceError, StringExtError, TableError, ValueError,
     },
     executor::{
-        AggregateError, AlterError, EvaluateError, ExecuteError, FetchError, InsertError,
-        SelectError, SortError, UpdateError, ValidateError,
+        AggregateError, AlterError, ConstraintError, EvaluateError, ExecuteError, FetchError,
+        InsertError, RecursiveCteError, SelectError, SortError, UpdateError, ValidateError,
     },
+    format::FormatError,
     plan::PlanError,
+    query_builder::QueryBuilderError,
     store::{AlterTableError, IndexError},
     translate::TranslateError,
 };
@@ -41,6 +43,8 @@ pub enum Error {
     Fetch(#[from] FetchError),
     #[error("select: {0}")]
     Select(#[from] SelectError),
+    #[error("recursive-cte: {0}")]
+    RecursiveCte(#[from] RecursiveCteError),
     #[error("evaluate: {0}")]
     Evaluate(#[from] EvaluateError),
     #[error("aggregate: {0}")]
@@ -55,6 +59,8 @@ pub enum Error {
     Table(#[from] TableError),
     #[error("validate: {0}")]
     Validate(#[from] ValidateError),
+    #[error("constraint: {0}")]
+    Constraint(#[from] ConstraintError),
     #[error("row: {0}")]
     Row(#[from] RowError),
     #[error("key: {0}")]
@@ -71,6 +77,14 @@ pub enum Error {
     Plan(#[from] PlanError),
     #[error("schema-parse: {0}")]
     Schema(#[from] SchemaParseError),
+    #[error("sequence: {0}")]
+    Sequence(#[from] SequenceError),
+    #[error("enum-type: {0}")]
+    EnumType(#[from] EnumTypeError),
+    #[error("format: {0}")]
+    Format(#[from] FormatError),
+    #[error("query-builder: {0}")]
+    QueryBuilder(#[from] QueryBuilderError),
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
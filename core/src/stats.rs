@@ -0,0 +1,78 @@
+use {
+    crate::{executor::Payload, result::Error},
+    std::time::Duration,
+};
+
+/// Statistics accumulated over the statements executed through a `Glue`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of rows returned by queries, rows which the storage scanned but filtered out are
+    /// not counted
+    pub rows_returned: u64,
+    /// Number of rows inserted, updated or deleted
+    pub rows_written: u64,
+    pub queries_executed: u64,
+    /// Number of statements which returned an error
+    pub errors: u64,
+    pub total_execution_time: Duration,
+    /// Number of queries whose statements were taken from the plan cache instead of being parsed
+    pub plan_cache_hits: u64,
+}
+
+impl Stats {
+    pub fn record(&mut self, result: Result<&Payload, &Error>, elapsed: Duration) {
+        self.queries_executed += 1;
+        self.total_execution_time += elapsed;
+
+        match result {
+            Ok(Payload::Select { rows, .. }) => self.rows_returned += rows.len() as u64,
+            Ok(Payload::SelectMap(rows)) => self.rows_returned += rows.len() as u64,
+            Ok(Payload::Insert(n) | Payload::Update(n) | Payload::Delete(n)) => {
+                self.rows_written += *n as u64;
+            }
+            Ok(_) => {}
+            Err(_) => self.errors += 1,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Stats,
+        crate::{data::Value, executor::Payload, result::Error},
+        std::time::Duration,
+    };
+
+    #[test]
+    fn record() {
+        let mut stats = Stats::default();
+        let select = Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(1)], vec![Value::I64(2)]],
+        };
+        let error = Error::StorageMsg("error".to_owned());
+
+        stats.record(Ok(&select), Duration::from_millis(2));
+        stats.record(Ok(&Payload::Insert(3)), Duration::from_millis(1));
+        stats.record(Ok(&Payload::Create), Duration::from_millis(1));
+        stats.record(Err(&error), Duration::from_millis(1));
+
+        let expected = Stats {
+            rows_returned: 2,
+            rows_written: 3,
+            queries_executed: 4,
+            errors: 1,
+            total_execution_time: Duration::from_millis(5),
+            plan_cache_hits: 0,
+        };
+        assert_eq!(stats, expected);
+
+        stats.reset();
+        assert_eq!(stats, Stats::default());
+    }
+}
@@ -1,6 +1,6 @@
 use {
     crate::{
-        ast::ColumnDef,
+        ast::{ColumnDef, Expr},
         result::{Error, Result},
     },
     async_trait::async_trait,
@@ -26,6 +26,9 @@ pub enum AlterTableError {
     #[error("Dropping column not found: {0}")]
     DroppingColumnNotFound(String),
 
+    #[error("Altering column not found: {0}")]
+    AlteringColumnNotFound(String),
+
     #[error("Schemaless table does not support ALTER TABLE: {0}")]
     SchemalessTableFound(String),
 }
@@ -65,4 +68,26 @@ pub trait AlterTable {
 
         Err(Error::StorageMsg(msg))
     }
+
+    async fn set_column_nullable(
+        &mut self,
+        _table_name: &str,
+        _column_name: &str,
+        _nullable: bool,
+    ) -> Result<()> {
+        let msg = "[Storage] AlterTable::set_column_nullable is not supported".to_owned();
+
+        Err(Error::StorageMsg(msg))
+    }
+
+    async fn set_column_default(
+        &mut self,
+        _table_name: &str,
+        _column_name: &str,
+        _default: Option<&Expr>,
+    ) -> Result<()> {
+        let msg = "[Storage] AlterTable::set_column_default is not supported".to_owned();
+
+        Err(Error::StorageMsg(msg))
+    }
 }
@@ -0,0 +1,40 @@
+use {
+    crate::{
+        data::EnumType as StructEnumType,
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+};
+
+/// By implementing `EnumType` trait, you can use the types created with `CREATE TYPE`.
+#[async_trait(?Send)]
+pub trait EnumType {
+    async fn fetch_enum_type(&self, _name: &str) -> Result<Option<StructEnumType>> {
+        Err(Error::StorageMsg(
+            "[Storage] EnumType is not supported".to_owned(),
+        ))
+    }
+
+    async fn fetch_all_enum_types(&self) -> Result<Vec<StructEnumType>> {
+        Err(Error::StorageMsg(
+            "[Storage] EnumType is not supported".to_owned(),
+        ))
+    }
+}
+
+/// By implementing `EnumTypeMut` trait, you can run `CREATE TYPE`, `ALTER TYPE` and `DROP TYPE`.
+#[async_trait(?Send)]
+pub trait EnumTypeMut {
+    /// Inserts the enum type, or replaces the enum type of the same name.
+    async fn insert_enum_type(&mut self, _enum_type: StructEnumType) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] EnumType is not supported".to_owned(),
+        ))
+    }
+
+    async fn delete_enum_type(&mut self, _name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] EnumType is not supported".to_owned(),
+        ))
+    }
+}
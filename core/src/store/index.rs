@@ -60,6 +60,7 @@ pub trait IndexMut {
         _table_name: &str,
         _index_name: &str,
         _column: &OrderByExpr,
+        _unique: bool,
     ) -> Result<()> {
         let msg = "[Storage] Index::create_index is not supported".to_owned();
 
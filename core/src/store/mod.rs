@@ -1,28 +1,51 @@
 mod alter_table;
 mod data_row;
+mod enum_type;
 mod function;
 mod index;
 mod metadata;
+mod sequence;
 mod transaction;
 
-pub trait GStore: Store + Index + Metadata + CustomFunction {}
-impl<S: Store + Index + Metadata + CustomFunction> GStore for S {}
+pub trait GStore: Store + Index + Metadata + CustomFunction + Sequence + EnumType {}
+impl<S: Store + Index + Metadata + CustomFunction + Sequence + EnumType> GStore for S {}
 
 pub trait GStoreMut:
-    StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut
+    StoreMut
+    + IndexMut
+    + AlterTable
+    + Transaction
+    + CustomFunction
+    + CustomFunctionMut
+    + Sequence
+    + SequenceMut
+    + EnumType
+    + EnumTypeMut
 {
 }
-impl<S: StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut>
-    GStoreMut for S
+impl<
+        S: StoreMut
+            + IndexMut
+            + AlterTable
+            + Transaction
+            + CustomFunction
+            + CustomFunctionMut
+            + Sequence
+            + SequenceMut
+            + EnumType
+            + EnumTypeMut,
+    > GStoreMut for S
 {
 }
 
 pub use {
     alter_table::{AlterTable, AlterTableError},
     data_row::DataRow,
+    enum_type::{EnumType, EnumTypeMut},
     function::{CustomFunction, CustomFunctionMut},
     index::{Index, IndexError, IndexMut},
     metadata::{MetaIter, Metadata},
+    sequence::{Sequence, SequenceMut},
     transaction::Transaction,
 };
 
@@ -0,0 +1,56 @@
+use {
+    crate::{
+        data::Sequence as StructSequence,
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+};
+
+/// By implementing `Sequence` trait, you can run `NEXTVAL`, `CURRVAL` and `SETVAL`.
+///
+/// `NEXTVAL` and `SETVAL` change the sequence while an expression is evaluated, so the storage
+/// updates the sequence through a shared reference. The change is not undone by `ROLLBACK`.
+#[async_trait(?Send)]
+pub trait Sequence {
+    async fn fetch_sequence(&self, _name: &str) -> Result<Option<StructSequence>> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+
+    /// Advances the sequence with `StructSequence::next_value` and returns the new value.
+    async fn next_sequence_value(&self, _name: &str) -> Result<i64> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+
+    /// Sets the last value of the sequence with `StructSequence::set_value`.
+    async fn set_sequence_value(&self, _name: &str, _value: i64) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+}
+
+/// By implementing `SequenceMut` trait, you can run `CREATE SEQUENCE` and `DROP SEQUENCE`.
+#[async_trait(?Send)]
+pub trait SequenceMut {
+    async fn insert_sequence(&mut self, _sequence: StructSequence) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+
+    async fn delete_sequence(&mut self, _name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+}
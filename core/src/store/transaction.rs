@@ -22,4 +22,10 @@ pub trait Transaction {
     async fn commit(&mut self) -> Result<()> {
         Ok(())
     }
+
+    /// Called by `SELECT ... FOR UPDATE` to keep other transactions from modifying the rows of
+    /// the table until the current transaction ends.
+    async fn lock_for_update(&mut self, _table_name: &str) -> Result<()> {
+        Ok(())
+    }
 }
@@ -38,6 +38,19 @@ pub fn translate_datetime_field(sql_datetime_field: &SqlDateTimeField) -> Result
     })
 }
 
+/// Field name given as a string, e.g. `DATE_PART('year', ...)`
+pub fn translate_datetime_field_name(name: &str) -> Result<DateTimeField> {
+    Ok(match name.to_uppercase().as_str() {
+        "YEAR" => DateTimeField::Year,
+        "MONTH" => DateTimeField::Month,
+        "DAY" => DateTimeField::Day,
+        "HOUR" => DateTimeField::Hour,
+        "MINUTE" => DateTimeField::Minute,
+        "SECOND" => DateTimeField::Second,
+        _ => return Err(TranslateError::UnsupportedDateTimeField(name.to_owned()).into()),
+    })
+}
+
 pub fn translate_trim_where_field(sql_trim_where_field: &SqlTrimWhereField) -> TrimWhereField {
     use TrimWhereField::*;
     match sql_trim_where_field {
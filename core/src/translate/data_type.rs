@@ -12,16 +12,21 @@ pub fn translate_data_type(sql_data_type: &SqlDataType) -> Result<DataType> {
         SqlDataType::Boolean => Ok(DataType::Boolean),
         SqlDataType::Int(None) | SqlDataType::Integer(None) => Ok(DataType::Int),
         SqlDataType::Float(_) => Ok(DataType::Float),
+        SqlDataType::Real => Ok(DataType::Float32),
         SqlDataType::Text => Ok(DataType::Text),
         SqlDataType::Bytea => Ok(DataType::Bytea),
         SqlDataType::Date => Ok(DataType::Date),
         SqlDataType::Timestamp(None, SqlTimezoneInfo::None) => Ok(DataType::Timestamp),
+        SqlDataType::Timestamp(None, SqlTimezoneInfo::WithTimeZone | SqlTimezoneInfo::Tz) => {
+            Ok(DataType::TimestampTz)
+        }
         SqlDataType::Time(None, SqlTimezoneInfo::None) => Ok(DataType::Time),
         SqlDataType::Interval => Ok(DataType::Interval),
         SqlDataType::Uuid => Ok(DataType::Uuid),
+        SqlDataType::Enum(variants) => Ok(DataType::Enum(variants.clone())),
         SqlDataType::Decimal(SqlExactNumberInfo::None) => Ok(DataType::Decimal),
-        SqlDataType::Custom(name, _idents) => {
-            let name = name.0.get(0).map(|v| v.value.to_uppercase());
+        SqlDataType::Custom(object_name, _idents) => {
+            let name = object_name.0.get(0).map(|v| v.value.to_uppercase());
 
             match name.as_deref() {
                 Some("MAP") => Ok(DataType::Map),
@@ -30,16 +35,20 @@ pub fn translate_data_type(sql_data_type: &SqlDataType) -> Result<DataType> {
                 Some("INT16") => Ok(DataType::Int16),
                 Some("INT32") => Ok(DataType::Int32),
                 Some("INT128") => Ok(DataType::Int128),
-                Some("UINT8") => Ok(DataType::Uint8),
-                Some("UINT16") => Ok(DataType::Uint16),
-                Some("UINT32") => Ok(DataType::Uint32),
+                Some("UINT8") | Some("UINT1") => Ok(DataType::Uint8),
+                Some("UINT16") | Some("UINT2") => Ok(DataType::Uint16),
+                Some("UINT32") | Some("UINT4") => Ok(DataType::Uint32),
                 Some("UINT64") => Ok(DataType::Uint64),
                 Some("UINT128") => Ok(DataType::Uint128),
                 Some("POINT") => Ok(DataType::Point),
                 Some("INET") => Ok(DataType::Inet),
-                Some("FLOAT32") => Ok(DataType::Float32),
+                Some("FLOAT32") | Some("FLOAT4") => Ok(DataType::Float32),
 
-                _ => Err(TranslateError::UnsupportedDataType(sql_data_type.to_string()).into()),
+                // the other names refer to the types created with `CREATE TYPE`
+                _ => match object_name.0.as_slice() {
+                    [name] => Ok(DataType::UserDefined(name.value.to_owned())),
+                    _ => Err(TranslateError::UnsupportedDataType(sql_data_type.to_string()).into()),
+                },
             }
         }
         _ => Err(TranslateError::UnsupportedDataType(sql_data_type.to_string()).into()),
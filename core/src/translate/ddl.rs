@@ -3,14 +3,23 @@ use {
         data_type::translate_data_type, expr::translate_expr, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{AlterTableOperation, ColumnDef, ColumnUniqueOption, OperateFunctionArg},
+        ast::{
+            AlterColumnOperation, AlterTableOperation, AstLiteral, CheckConstraint, ColumnDef,
+            ColumnUniqueOption, Expr, ForeignKey, Function, OperateFunctionArg, ReferentialAction,
+            SequenceOptions,
+        },
         result::Result,
     },
     sqlparser::ast::{
+        AlterColumnOperation as SqlAlterColumnOperation,
         AlterTableOperation as SqlAlterTableOperation, ColumnDef as SqlColumnDef,
         ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef,
-        OperateFunctionArg as SqlOperateFunctionArg,
+        DataType as SqlDataType, Ident as SqlIdent, MinMaxValue as SqlMinMaxValue,
+        ObjectName as SqlObjectName, OperateFunctionArg as SqlOperateFunctionArg,
+        ReferentialAction as SqlReferentialAction, SequenceOptions as SqlSequenceOptions,
+        TableConstraint as SqlTableConstraint,
     },
+    std::iter,
 };
 
 pub fn translate_alter_table_operation(
@@ -18,6 +27,20 @@ pub fn translate_alter_table_operation(
 ) -> Result<AlterTableOperation> {
     match sql_alter_table_operation {
         SqlAlterTableOperation::AddColumn { column_def, .. } => {
+            let constraint =
+                column_def
+                    .options
+                    .iter()
+                    .find(|SqlColumnOptionDef { option, .. }| {
+                        matches!(
+                            option,
+                            SqlColumnOption::Check(_) | SqlColumnOption::ForeignKey { .. }
+                        )
+                    });
+            if let Some(SqlColumnOptionDef { option, .. }) = constraint {
+                return Err(TranslateError::UnsupportedColumnOption(option.to_string()).into());
+            }
+
             Ok(AlterTableOperation::AddColumn {
                 column_def: translate_column_def(column_def)?,
             })
@@ -25,10 +48,11 @@ pub fn translate_alter_table_operation(
         SqlAlterTableOperation::DropColumn {
             column_name,
             if_exists,
-            ..
+            cascade,
         } => Ok(AlterTableOperation::DropColumn {
             column_name: column_name.value.to_owned(),
             if_exists: *if_exists,
+            cascade: *cascade,
         }),
         SqlAlterTableOperation::RenameColumn {
             old_column_name,
@@ -42,6 +66,27 @@ pub fn translate_alter_table_operation(
                 table_name: translate_object_name(table_name)?,
             })
         }
+        SqlAlterTableOperation::AlterColumn { column_name, op } => {
+            let operation = match op {
+                SqlAlterColumnOperation::SetNotNull => AlterColumnOperation::SetNotNull,
+                SqlAlterColumnOperation::DropNotNull => AlterColumnOperation::DropNotNull,
+                SqlAlterColumnOperation::SetDefault { value } => {
+                    AlterColumnOperation::SetDefault(translate_expr(value)?)
+                }
+                SqlAlterColumnOperation::DropDefault => AlterColumnOperation::DropDefault,
+                _ => {
+                    return Err(TranslateError::UnsupportedAlterTableOperation(
+                        sql_alter_table_operation.to_string(),
+                    )
+                    .into());
+                }
+            };
+
+            Ok(AlterTableOperation::AlterColumn {
+                column_name: column_name.value.to_owned(),
+                operation,
+            })
+        }
         _ => Err(TranslateError::UnsupportedAlterTableOperation(
             sql_alter_table_operation.to_string(),
         )
@@ -76,6 +121,11 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
 
                     Ok((nullable, default, unique))
                 }
+                // translated into the constraints of the table by translate_checks and
+                // translate_foreign_keys
+                SqlColumnOption::Check(_) | SqlColumnOption::ForeignKey { .. } => {
+                    Ok((nullable, default, unique))
+                }
                 _ => Err(TranslateError::UnsupportedColumnOption(option.to_string()).into()),
             }
         },
@@ -90,6 +140,214 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
     })
 }
 
+/// Translates the columns of `CREATE TABLE` with the names of the sequences to create for them.
+/// A `SERIAL` or `BIGSERIAL` column is an `INTEGER NOT NULL` column whose default value is
+/// `NEXTVAL` of a sequence named `{table}_{column}_seq`.
+pub fn translate_column_defs(
+    table_name: &str,
+    columns: &[SqlColumnDef],
+) -> Result<(Vec<ColumnDef>, Vec<String>)> {
+    let mut column_defs = Vec::with_capacity(columns.len());
+    let mut sequences = Vec::new();
+
+    for sql_column_def in columns {
+        if !is_serial(&sql_column_def.data_type) {
+            column_defs.push(translate_column_def(sql_column_def)?);
+            continue;
+        }
+
+        let column_def = translate_column_def(&SqlColumnDef {
+            data_type: SqlDataType::Integer(None),
+            ..sql_column_def.clone()
+        })?;
+        if column_def.default.is_some() {
+            return Err(TranslateError::DefaultOnSerialColumn(column_def.name).into());
+        }
+
+        let sequence_name = format!("{table_name}_{}_seq", column_def.name);
+        let default = Expr::Function(Box::new(Function::Nextval(Expr::Literal(
+            AstLiteral::QuotedString(sequence_name.clone()),
+        ))));
+
+        column_defs.push(ColumnDef {
+            nullable: false,
+            default: Some(default),
+            ..column_def
+        });
+        sequences.push(sequence_name);
+    }
+
+    Ok((column_defs, sequences))
+}
+
+fn is_serial(data_type: &SqlDataType) -> bool {
+    match data_type {
+        SqlDataType::Custom(name, _) => {
+            let name = name.0.get(0).map(|v| v.value.to_uppercase());
+
+            matches!(name.as_deref(), Some("SERIAL" | "BIGSERIAL"))
+        }
+        _ => false,
+    }
+}
+
+/// Translates the `CHECK` constraints of the columns and of the table.
+/// Unnamed constraints are named `{table}_{column}_check` and `{table}_check`,
+/// followed by a number if the name is already taken.
+pub fn translate_checks(
+    table_name: &str,
+    columns: &[SqlColumnDef],
+    constraints: &[SqlTableConstraint],
+) -> Result<Vec<CheckConstraint>> {
+    let column_checks = columns.iter().flat_map(|column_def| {
+        column_def
+            .options
+            .iter()
+            .filter_map(move |option_def| match &option_def.option {
+                SqlColumnOption::Check(expr) => {
+                    Some((&option_def.name, Some(&column_def.name.value), expr))
+                }
+                _ => None,
+            })
+    });
+    let table_checks = constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            SqlTableConstraint::Check { name, expr } => Some((name, None, expr.as_ref())),
+            _ => None,
+        });
+
+    let mut checks: Vec<CheckConstraint> = Vec::new();
+    for (name, column_name, expr) in column_checks.chain(table_checks) {
+        let name = match name {
+            Some(name) => name.value.to_owned(),
+            None => {
+                let name = match column_name {
+                    Some(column_name) => format!("{table_name}_{column_name}_check"),
+                    None => format!("{table_name}_check"),
+                };
+                let taken = |name: &str| checks.iter().any(|check| check.name == name);
+
+                iter::once(name.clone())
+                    .chain((1..).map(|i| format!("{name}{i}")))
+                    .find(|name| !taken(name))
+                    .unwrap_or(name)
+            }
+        };
+
+        checks.push(CheckConstraint {
+            name,
+            expr: translate_expr(expr)?,
+        });
+    }
+
+    Ok(checks)
+}
+
+/// Translates the `FOREIGN KEY` constraints of the columns and of the table.
+/// Unnamed constraints are named `{table}_{column}_fkey`.
+pub fn translate_foreign_keys(
+    table_name: &str,
+    columns: &[SqlColumnDef],
+    constraints: &[SqlTableConstraint],
+) -> Result<Vec<ForeignKey>> {
+    let column_foreign_keys = columns.iter().flat_map(|column_def| {
+        column_def
+            .options
+            .iter()
+            .filter_map(move |option_def| match &option_def.option {
+                SqlColumnOption::ForeignKey {
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                } => Some(translate_foreign_key(
+                    table_name,
+                    &option_def.name,
+                    std::slice::from_ref(&column_def.name),
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                )),
+                _ => None,
+            })
+    });
+    let table_foreign_keys = constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            SqlTableConstraint::ForeignKey {
+                name,
+                columns,
+                foreign_table,
+                referred_columns,
+                on_delete,
+                on_update,
+            } => Some(translate_foreign_key(
+                table_name,
+                name,
+                columns,
+                foreign_table,
+                referred_columns,
+                on_delete,
+                on_update,
+            )),
+            _ => None,
+        });
+
+    column_foreign_keys.chain(table_foreign_keys).collect()
+}
+
+fn translate_foreign_key(
+    table_name: &str,
+    name: &Option<SqlIdent>,
+    columns: &[SqlIdent],
+    foreign_table: &SqlObjectName,
+    referred_columns: &[SqlIdent],
+    on_delete: &Option<SqlReferentialAction>,
+    on_update: &Option<SqlReferentialAction>,
+) -> Result<ForeignKey> {
+    let (column_name, referenced_column_name) = match (columns, referred_columns) {
+        ([column], [referred_column]) => (&column.value, &referred_column.value),
+        _ => return Err(TranslateError::CompositeForeignKeyNotSupported.into()),
+    };
+
+    let on_delete = match on_delete {
+        None | Some(SqlReferentialAction::NoAction) => ReferentialAction::NoAction,
+        Some(SqlReferentialAction::Restrict) => ReferentialAction::Restrict,
+        Some(SqlReferentialAction::Cascade) => ReferentialAction::Cascade,
+        Some(SqlReferentialAction::SetNull) => ReferentialAction::SetNull,
+        Some(action @ SqlReferentialAction::SetDefault) => {
+            return Err(TranslateError::UnsupportedReferentialAction(format!(
+                "ON DELETE {action}"
+            ))
+            .into());
+        }
+    };
+
+    // updating a referenced value is always restricted
+    match on_update {
+        None | Some(SqlReferentialAction::NoAction | SqlReferentialAction::Restrict) => {}
+        Some(action) => {
+            return Err(TranslateError::UnsupportedReferentialAction(format!(
+                "ON UPDATE {action}"
+            ))
+            .into());
+        }
+    }
+
+    Ok(ForeignKey {
+        name: name
+            .as_ref()
+            .map(|name| name.value.to_owned())
+            .unwrap_or_else(|| format!("{table_name}_{column_name}_fkey")),
+        column_name: column_name.to_owned(),
+        referenced_table_name: translate_object_name(foreign_table)?,
+        referenced_column_name: referenced_column_name.to_owned(),
+        on_delete,
+    })
+}
+
 pub fn translate_operate_function_arg(arg: &SqlOperateFunctionArg) -> Result<OperateFunctionArg> {
     let name = arg
         .name
@@ -104,3 +362,47 @@ pub fn translate_operate_function_arg(arg: &SqlOperateFunctionArg) -> Result<Ope
         default,
     })
 }
+
+pub fn translate_sequence_options(
+    sql_sequence_options: &[SqlSequenceOptions],
+) -> Result<SequenceOptions> {
+    let min_max_value = |value: &SqlMinMaxValue| match value {
+        SqlMinMaxValue::Some(expr) => translate_expr(expr).map(Some),
+        SqlMinMaxValue::Empty | SqlMinMaxValue::None => Ok(None),
+    };
+
+    sql_sequence_options.iter().try_fold(
+        SequenceOptions::default(),
+        |options, sql_option| -> Result<_> {
+            let options = match sql_option {
+                SqlSequenceOptions::StartWith(expr, _) => SequenceOptions {
+                    start: Some(translate_expr(expr)?),
+                    ..options
+                },
+                SqlSequenceOptions::IncrementBy(expr, _) => SequenceOptions {
+                    increment: Some(translate_expr(expr)?),
+                    ..options
+                },
+                SqlSequenceOptions::MinValue(value) => SequenceOptions {
+                    min_value: min_max_value(value)?,
+                    ..options
+                },
+                SqlSequenceOptions::MaxValue(value) => SequenceOptions {
+                    max_value: min_max_value(value)?,
+                    ..options
+                },
+                SqlSequenceOptions::Cycle(no_cycle) => SequenceOptions {
+                    cycle: !no_cycle,
+                    ..options
+                },
+                SqlSequenceOptions::Cache(expr) => {
+                    return Err(
+                        TranslateError::UnsupportedSequenceOption(format!("CACHE {expr}")).into(),
+                    );
+                }
+            };
+
+            Ok(options)
+        },
+    )
+}
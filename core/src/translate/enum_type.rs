@@ -0,0 +1,259 @@
+use {
+    crate::{
+        ast::{AlterTypeOperation, Statement},
+        result::{Error, Result},
+    },
+    sqlparser::{
+        parser::ParserError,
+        tokenizer::{Token, Word},
+    },
+};
+
+/// Returns whether the tokens of a single statement begin with `CREATE TYPE`, `ALTER TYPE` or
+/// `DROP TYPE`, which the SQL parser does not support.
+pub(crate) fn is_type_statement(tokens: &[Token]) -> bool {
+    let mut tokens = tokens
+        .iter()
+        .filter(|token| !matches!(token, Token::Whitespace(_)));
+
+    match (tokens.next(), tokens.next()) {
+        (Some(first), Some(second)) => {
+            ["CREATE", "ALTER", "DROP"]
+                .iter()
+                .any(|keyword| is_keyword(first, keyword))
+                && is_keyword(second, "TYPE")
+        }
+        _ => false,
+    }
+}
+
+/// Translates the tokens of a single `CREATE TYPE`, `ALTER TYPE` or `DROP TYPE` statement.
+pub(crate) fn translate_type_statement(tokens: Vec<Token>) -> Result<Statement> {
+    let mut tokens = TypeTokens::new(tokens);
+
+    let statement = if tokens.parse_keyword("CREATE") {
+        tokens.expect_keyword("TYPE")?;
+        let name = tokens.parse_name()?;
+        tokens.expect_keyword("AS")?;
+        tokens.expect_keyword("ENUM")?;
+        tokens.expect_token(Token::LParen)?;
+        let variants = match tokens.consume_token(&Token::RParen) {
+            true => Vec::new(),
+            false => {
+                let variants = tokens.parse_comma_separated(TypeTokens::parse_string)?;
+                tokens.expect_token(Token::RParen)?;
+
+                variants
+            }
+        };
+
+        Statement::CreateType { name, variants }
+    } else if tokens.parse_keyword("ALTER") {
+        tokens.expect_keyword("TYPE")?;
+        let name = tokens.parse_name()?;
+        tokens.expect_keyword("ADD")?;
+        tokens.expect_keyword("VALUE")?;
+        let if_not_exists = tokens.parse_keywords(&["IF", "NOT", "EXISTS"]);
+        let value = tokens.parse_string()?;
+
+        Statement::AlterType {
+            name,
+            operation: AlterTypeOperation::AddValue {
+                value,
+                if_not_exists,
+            },
+        }
+    } else {
+        tokens.expect_keyword("DROP")?;
+        tokens.expect_keyword("TYPE")?;
+        let if_exists = tokens.parse_keywords(&["IF", "EXISTS"]);
+        let names = tokens.parse_comma_separated(TypeTokens::parse_name)?;
+
+        Statement::DropType { if_exists, names }
+    };
+
+    match tokens.peek() {
+        None => Ok(statement),
+        Some(_) => Err(tokens.expected("end of statement")),
+    }
+}
+
+fn is_keyword(token: &Token, keyword: &str) -> bool {
+    matches!(
+        token,
+        Token::Word(Word { value, quote_style: None, .. }) if value.eq_ignore_ascii_case(keyword)
+    )
+}
+
+struct TypeTokens {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl TypeTokens {
+    fn new(tokens: Vec<Token>) -> Self {
+        let tokens = tokens
+            .into_iter()
+            .filter(|token| !matches!(token, Token::Whitespace(_) | Token::EOF))
+            .collect();
+
+        Self { tokens, index: 0 }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index)
+    }
+
+    fn parse_keyword(&mut self, keyword: &str) -> bool {
+        self.parse_keywords(&[keyword])
+    }
+
+    fn parse_keywords(&mut self, keywords: &[&str]) -> bool {
+        let found = keywords.iter().enumerate().all(|(i, keyword)| {
+            self.tokens
+                .get(self.index + i)
+                .map(|token| is_keyword(token, keyword))
+                .unwrap_or(false)
+        });
+
+        if found {
+            self.index += keywords.len();
+        }
+
+        found
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        match self.parse_keyword(keyword) {
+            true => Ok(()),
+            false => Err(self.expected(keyword)),
+        }
+    }
+
+    fn consume_token(&mut self, expected: &Token) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.index += 1;
+        }
+
+        found
+    }
+
+    fn expect_token(&mut self, expected: Token) -> Result<()> {
+        match self.consume_token(&expected) {
+            true => Ok(()),
+            false => Err(self.expected(&expected.to_string())),
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token::Word(Word { value, .. })) => {
+                let name = value.to_owned();
+                self.index += 1;
+
+                Ok(name)
+            }
+            _ => Err(self.expected("type name")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token::SingleQuotedString(value)) => {
+                let value = value.to_owned();
+                self.index += 1;
+
+                Ok(value)
+            }
+            _ => Err(self.expected("literal string")),
+        }
+    }
+
+    fn parse_comma_separated<T>(
+        &mut self,
+        mut parse: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut values = vec![parse(self)?];
+        while self.consume_token(&Token::Comma) {
+            values.push(parse(self)?);
+        }
+
+        Ok(values)
+    }
+
+    /// Reports the error in the same way as the SQL parser does.
+    fn expected(&self, expected: &str) -> Error {
+        let found = self
+            .peek()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| Token::EOF.to_string());
+        let error = ParserError::ParserError(format!("Expected {expected}, found: {found}"));
+
+        Error::Parser(format!("{:#?}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{is_type_statement, translate_type_statement},
+        crate::{
+            ast::{AlterTypeOperation, Statement},
+            parse_sql::tokenize,
+            result::Result,
+        },
+    };
+
+    fn translate(sql: &str) -> Result<Statement> {
+        translate_type_statement(tokenize(sql).unwrap())
+    }
+
+    #[test]
+    fn type_statement() {
+        assert!(is_type_statement(
+            &tokenize("CREATE TYPE mood AS ENUM ('ok')").unwrap()
+        ));
+        assert!(is_type_statement(&tokenize("  drop type mood").unwrap()));
+        assert!(!is_type_statement(
+            &tokenize("CREATE TABLE Foo (id INT)").unwrap()
+        ));
+        assert!(!is_type_statement(&tokenize(r#"CREATE "TYPE""#).unwrap()));
+
+        assert_eq!(
+            translate("CREATE TYPE mood AS ENUM ('sad', 'ok')"),
+            Ok(Statement::CreateType {
+                name: "mood".to_owned(),
+                variants: vec!["sad".to_owned(), "ok".to_owned()],
+            })
+        );
+        assert_eq!(
+            translate("CREATE TYPE mood AS ENUM ()"),
+            Ok(Statement::CreateType {
+                name: "mood".to_owned(),
+                variants: Vec::new(),
+            })
+        );
+        assert_eq!(
+            translate("ALTER TYPE mood ADD VALUE IF NOT EXISTS 'happy'"),
+            Ok(Statement::AlterType {
+                name: "mood".to_owned(),
+                operation: AlterTypeOperation::AddValue {
+                    value: "happy".to_owned(),
+                    if_not_exists: true,
+                },
+            })
+        );
+        assert_eq!(
+            translate("DROP TYPE IF EXISTS mood, size"),
+            Ok(Statement::DropType {
+                if_exists: true,
+                names: vec!["mood".to_owned(), "size".to_owned()],
+            })
+        );
+
+        assert!(translate("CREATE TYPE mood AS ENUM ('sad'").is_err());
+        assert!(translate("ALTER TYPE mood RENAME TO feeling").is_err());
+        assert!(translate("DROP TYPE mood CASCADE").is_err());
+    }
+}
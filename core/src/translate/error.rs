@@ -11,6 +11,12 @@ pub enum TranslateError {
     #[error("unimplemented - composite index is not supported")]
     CompositeIndexNotSupported,
 
+    #[error("unimplemented - composite foreign key is not supported")]
+    CompositeForeignKeyNotSupported,
+
+    #[error("unsupported referential action: {0}")]
+    UnsupportedReferentialAction(String),
+
     #[error("unimplemented - join on update not supported")]
     JoinOnUpdateNotSupported,
 
@@ -60,6 +66,21 @@ pub enum TranslateError {
     #[error("qualified wildcard is not supported - COUNT({0})")]
     QualifiedWildcardInCountNotSupported(String),
 
+    #[error("COUNT_APPROX_DISTINCT precision must be an integer between 4 and 16, found: {0}")]
+    InvalidApproxDistinctPrecision(String),
+
+    #[error("string aggregate separator must be a string literal, found: {0}")]
+    InvalidStringAggSeparator(String),
+
+    #[error("include_nulls argument must be a boolean literal, found: {0}")]
+    InvalidIncludeNullsArg(String),
+
+    #[error("unsupported window function: {0}")]
+    UnsupportedWindowFunction(String),
+
+    #[error("unsupported window spec: {0}")]
+    UnsupportedWindowSpec(String),
+
     #[error("order by - NULLS (FIRST | LAST) is not supported")]
     OrderByNullsFirstOrLastNotSupported,
 
@@ -69,6 +90,12 @@ pub enum TranslateError {
     #[error("unsupported SHOW VARIABLE statement: {0}")]
     UnsupportedShowVariableStatement(String),
 
+    #[error("unsupported ON clause of INSERT: {0}")]
+    UnsupportedOnInsert(String),
+
+    #[error("ON CONFLICT DO UPDATE requires a conflict target column")]
+    LackOfConflictTarget,
+
     #[error("unsupported statement: {0}")]
     UnsupportedStatement(String),
 
@@ -96,18 +123,24 @@ pub enum TranslateError {
     #[error("unsupported query set expr: {0}")]
     UnsupportedQuerySetExpr(String),
 
+    #[error("unsupported lock type: {0}")]
+    UnsupportedLockType(String),
+
     #[error("unsupported query table factor: {0}")]
     UnsupportedQueryTableFactor(String),
 
-    #[error("unsupported join constraint: {0}")]
-    UnsupportedJoinConstraint(String),
-
     #[error("unsupported join operator: {0}")]
     UnsupportedJoinOperator(String),
 
     #[error("unsupported column option: {0}")]
     UnsupportedColumnOption(String),
 
+    #[error("SERIAL column cannot have a default value: {0}")]
+    DefaultOnSerialColumn(String),
+
+    #[error("unsupported sequence option: {0}")]
+    UnsupportedSequenceOption(String),
+
     #[error("unsupported alter table operation: {0}")]
     UnsupportedAlterTableOperation(String),
 
@@ -117,6 +150,9 @@ pub enum TranslateError {
     #[error("Every derived table must have its own alias")]
     LackOfAlias,
 
+    #[error("common table expression name specified more than once: {0}")]
+    DuplicateCteName(String),
+
     #[error("Series should have size")]
     LackOfArgs,
 
@@ -131,4 +167,7 @@ pub enum TranslateError {
 
     #[error("cannot drop primary index")]
     CannotDropPrimary,
+
+    #[error("unsupported parameter: {0}, parameters are numbered from $1 in prepared statements")]
+    UnsupportedParameter(String),
 }
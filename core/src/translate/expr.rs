@@ -3,8 +3,9 @@ use {
         ast_literal::{translate_ast_literal, translate_datetime_field},
         data_type::translate_data_type,
         function::{
-            translate_cast, translate_ceil, translate_extract, translate_floor, translate_function,
-            translate_position,
+            translate_at_time_zone, translate_cast, translate_ceil, translate_extract,
+            translate_floor, translate_function, translate_list_agg, translate_position,
+            translate_try_cast,
         },
         operator::{translate_binary_operator, translate_unary_operator},
         translate_idents, translate_query, TranslateError,
@@ -16,6 +17,7 @@ use {
     },
     sqlparser::ast::{
         DateTimeField as SqlDateTimeField, Expr as SqlExpr, OrderByExpr as SqlOrderByExpr,
+        Value as SqlValue,
     },
 };
 
@@ -29,6 +31,11 @@ use {
 /// In `GlueSQL`, if an argument is received wrapped in `( )` in the sql statement, the standard is set to translate in the form of `Expr::Function(Box<Function::Cast>)` rather than `Expr::Cast`.
 pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
     match sql_expr {
+        SqlExpr::Identifier(ident)
+            if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("DEFAULT") =>
+        {
+            Ok(Expr::Default)
+        }
         SqlExpr::Identifier(ident) => Ok(Expr::Identifier(ident.value.clone())),
         SqlExpr::CompoundIdentifier(idents) => (idents.len() == 2)
             .then(|| Expr::CompoundIdentifier {
@@ -89,6 +96,16 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
             negated: *negated,
             pattern: translate_expr(pattern).map(Box::new)?,
         }),
+        SqlExpr::SimilarTo {
+            expr,
+            negated,
+            pattern,
+            escape_char: None,
+        } => Ok(Expr::SimilarTo {
+            expr: translate_expr(expr).map(Box::new)?,
+            negated: *negated,
+            pattern: translate_expr(pattern).map(Box::new)?,
+        }),
         SqlExpr::BinaryOp { left, op, right } => Ok(Expr::BinaryOp {
             left: translate_expr(left).map(Box::new)?,
             op: translate_binary_operator(op)?,
@@ -99,7 +116,12 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
             expr: translate_expr(expr).map(Box::new)?,
         }),
         SqlExpr::Extract { field, expr } => translate_extract(field, expr),
+        SqlExpr::AtTimeZone {
+            timestamp,
+            time_zone,
+        } => translate_at_time_zone(timestamp, time_zone),
         SqlExpr::Nested(expr) => translate_expr(expr).map(Box::new).map(Expr::Nested),
+        SqlExpr::Value(SqlValue::Placeholder(placeholder)) => translate_parameter(placeholder),
         SqlExpr::Value(value) => translate_ast_literal(value).map(Expr::Literal),
         SqlExpr::TypedString { data_type, value } => Ok(Expr::TypedString {
             data_type: translate_data_type(data_type)?,
@@ -159,6 +181,18 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
             obj: translate_expr(obj).map(Box::new)?,
             indexes: indexes.iter().map(translate_expr).collect::<Result<_>>()?,
         }),
+        SqlExpr::Tuple(exprs) => exprs
+            .iter()
+            .map(translate_expr)
+            .collect::<Result<_>>()
+            .map(Expr::Tuple),
+        SqlExpr::Array(array) => Ok(Expr::Array {
+            elem: array
+                .elem
+                .iter()
+                .map(translate_expr)
+                .collect::<Result<_>>()?,
+        }),
         SqlExpr::Position { expr, r#in } => translate_position(expr, r#in),
         SqlExpr::Interval {
             value,
@@ -177,11 +211,24 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
                 .transpose()?,
         }),
         SqlExpr::Cast { expr, data_type } => translate_cast(expr, data_type),
+        SqlExpr::TryCast { expr, data_type } => translate_try_cast(expr, data_type),
+        SqlExpr::ListAgg(list_agg) => translate_list_agg(list_agg),
 
         _ => Err(TranslateError::UnsupportedExpr(sql_expr.to_string()).into()),
     }
 }
 
+/// Translates `$n`, or `?n` which `Glue::prepare` numbers each `?` placeholder to.
+fn translate_parameter(placeholder: &str) -> Result<Expr> {
+    placeholder
+        .strip_prefix('$')
+        .or_else(|| placeholder.strip_prefix('?'))
+        .and_then(|index| index.parse::<usize>().ok())
+        .filter(|index| *index > 0)
+        .map(Expr::Parameter)
+        .ok_or_else(|| TranslateError::UnsupportedParameter(placeholder.to_owned()).into())
+}
+
 pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<OrderByExpr> {
     let SqlOrderByExpr {
         expr,
@@ -189,12 +236,9 @@ pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<Ord
         nulls_first,
     } = sql_order_by_expr;
 
-    if matches!(nulls_first, Some(_)) {
-        return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
-    }
-
     Ok(OrderByExpr {
         expr: translate_expr(expr)?,
         asc: *asc,
+        nulls_first: *nulls_first,
     })
 }
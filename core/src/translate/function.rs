@@ -1,17 +1,21 @@
 use {
     super::{
-        ast_literal::{translate_datetime_field, translate_trim_where_field},
-        expr::translate_expr,
+        ast_literal::{
+            translate_datetime_field, translate_datetime_field_name, translate_trim_where_field,
+        },
+        expr::{translate_expr, translate_order_by_expr},
         translate_data_type, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{Aggregate, CountArgExpr, Expr, Function},
+        ast::{Aggregate, CountArgExpr, Expr, Function, Window, WindowFunction},
+        data::HyperLogLog,
         result::Result,
     },
     sqlparser::ast::{
         DataType, DateTimeField as SqlDateTimeField, Expr as SqlExpr, Function as SqlFunction,
         FunctionArg as SqlFunctionArg, FunctionArgExpr as SqlFunctionArgExpr,
-        TrimWhereField as SqlTrimWhereField,
+        ListAgg as SqlListAgg, TrimWhereField as SqlTrimWhereField, Value as SqlValue,
+        WindowSpec as SqlWindowSpec,
     },
 };
 
@@ -55,18 +59,67 @@ pub fn translate_position(sub_expr: &SqlExpr, from_expr: &SqlExpr) -> Result<Exp
     })))
 }
 
+pub fn translate_list_agg(list_agg: &SqlListAgg) -> Result<Expr> {
+    let SqlListAgg {
+        distinct,
+        expr,
+        separator,
+        on_overflow,
+        within_group,
+    } = list_agg;
+
+    if *distinct || on_overflow.is_some() {
+        return Err(TranslateError::UnsupportedExpr(list_agg.to_string()).into());
+    }
+
+    let expr = translate_expr(expr)?;
+    let separator = separator
+        .as_ref()
+        .map(|separator| translate_separator(separator))
+        .transpose()?
+        .unwrap_or_default();
+    let order_by = within_group
+        .iter()
+        .map(translate_order_by_expr)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Expr::Aggregate(Box::new(Aggregate::StringAgg {
+        expr,
+        separator,
+        order_by,
+    })))
+}
+
 pub fn translate_cast(expr: &SqlExpr, data_type: &DataType) -> Result<Expr> {
     let expr = translate_expr(expr)?;
     let data_type = translate_data_type(data_type)?;
     Ok(Expr::Function(Box::new(Function::Cast { expr, data_type })))
 }
 
+pub fn translate_try_cast(expr: &SqlExpr, data_type: &DataType) -> Result<Expr> {
+    let expr = translate_expr(expr)?;
+    let data_type = translate_data_type(data_type)?;
+    Ok(Expr::Function(Box::new(Function::TryCast {
+        expr,
+        data_type,
+    })))
+}
+
 pub fn translate_extract(field: &SqlDateTimeField, expr: &SqlExpr) -> Result<Expr> {
     let field = translate_datetime_field(field)?;
     let expr = translate_expr(expr)?;
     Ok(Expr::Function(Box::new(Function::Extract { field, expr })))
 }
 
+pub fn translate_at_time_zone(timestamp: &SqlExpr, time_zone: &str) -> Result<Expr> {
+    let expr = translate_expr(timestamp)?;
+    let time_zone = time_zone.to_owned();
+    Ok(Expr::Function(Box::new(Function::AtTimeZone {
+        expr,
+        time_zone,
+    })))
+}
+
 fn check_len(name: String, found: usize, expected: usize) -> Result<()> {
     if found == expected {
         Ok(())
@@ -144,6 +197,43 @@ fn translate_aggregate_one_arg<T: FnOnce(Expr) -> Aggregate>(
         .map(Expr::Aggregate)
 }
 
+fn translate_precision(arg: &SqlExpr) -> Result<u8> {
+    let precision = match arg {
+        SqlExpr::Value(SqlValue::Number(n, _)) => n.parse::<u8>().ok(),
+        _ => None,
+    };
+
+    precision
+        .filter(|precision| {
+            (HyperLogLog::MIN_PRECISION..=HyperLogLog::MAX_PRECISION).contains(precision)
+        })
+        .ok_or_else(|| TranslateError::InvalidApproxDistinctPrecision(arg.to_string()).into())
+}
+
+fn translate_separator(arg: &SqlExpr) -> Result<String> {
+    match arg {
+        SqlExpr::Value(SqlValue::SingleQuotedString(separator)) => Ok(separator.to_owned()),
+        _ => Err(TranslateError::InvalidStringAggSeparator(arg.to_string()).into()),
+    }
+}
+
+fn translate_include_nulls(arg: &SqlExpr) -> Result<bool> {
+    match arg {
+        SqlExpr::Value(SqlValue::Boolean(include_nulls)) => Ok(*include_nulls),
+        _ => Err(TranslateError::InvalidIncludeNullsArg(arg.to_string()).into()),
+    }
+}
+
+fn translate_string_agg(expr: &SqlExpr, separator: String) -> Result<Expr> {
+    let expr = translate_expr(expr)?;
+
+    Ok(Expr::Aggregate(Box::new(Aggregate::StringAgg {
+        expr,
+        separator,
+        order_by: Vec::new(),
+    })))
+}
+
 fn translate_function_trim<T: FnOnce(Expr, Option<Expr>) -> Function>(
     func: T,
     args: Vec<&SqlExpr>,
@@ -163,6 +253,17 @@ fn translate_function_trim<T: FnOnce(Expr, Option<Expr>) -> Function>(
     Ok(Expr::Function(Box::new(result)))
 }
 
+fn translate_function_args(args: &[SqlFunctionArg]) -> Result<Vec<&SqlFunctionArgExpr>> {
+    args.iter()
+        .map(|arg| match arg {
+            SqlFunctionArg::Named { .. } => {
+                Err(TranslateError::NamedFunctionArgNotSupported.into())
+            }
+            SqlFunctionArg::Unnamed(arg_expr) => Ok(arg_expr),
+        })
+        .collect()
+}
+
 pub fn translate_function_arg_exprs(
     function_arg_exprs: Vec<&SqlFunctionArgExpr>,
 ) -> Result<Vec<&SqlExpr>> {
@@ -177,20 +278,97 @@ pub fn translate_function_arg_exprs(
         .collect::<Result<Vec<_>>>()
 }
 
-pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
-    let SqlFunction { name, args, .. } = sql_function;
-    let name = translate_object_name(name)?.to_uppercase();
+fn translate_window(sql_function: &SqlFunction, window_spec: &SqlWindowSpec) -> Result<Expr> {
+    let SqlWindowSpec {
+        partition_by,
+        order_by,
+        window_frame,
+    } = window_spec;
 
-    let function_arg_exprs = args
-        .iter()
-        .map(|arg| match arg {
-            SqlFunctionArg::Named { .. } => {
-                Err(TranslateError::NamedFunctionArgNotSupported.into())
+    if window_frame.is_some() {
+        return Err(TranslateError::UnsupportedWindowSpec(window_spec.to_string()).into());
+    }
+
+    let sql_function = SqlFunction {
+        over: None,
+        ..sql_function.clone()
+    };
+    let name = translate_object_name(&sql_function.name)?.to_uppercase();
+    let function = match name.as_str() {
+        "ROW_NUMBER" => {
+            check_len(name, sql_function.args.len(), 0)?;
+
+            WindowFunction::RowNumber
+        }
+        "RANK" => {
+            check_len(name, sql_function.args.len(), 0)?;
+
+            WindowFunction::Rank
+        }
+        "DENSE_RANK" => {
+            check_len(name, sql_function.args.len(), 0)?;
+
+            WindowFunction::DenseRank
+        }
+        "LAG" | "LEAD" => {
+            let args = translate_function_args(&sql_function.args)?;
+            let args = translate_function_arg_exprs(args)?;
+            check_len_range(name.clone(), args.len(), 1, 3)?;
+
+            let expr = translate_expr(args[0])?;
+            let offset = args.get(1).map(|arg| translate_expr(arg)).transpose()?;
+            let default = args.get(2).map(|arg| translate_expr(arg)).transpose()?;
+
+            match name.as_str() {
+                "LAG" => WindowFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                },
+                _ => WindowFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                },
             }
-            SqlFunctionArg::Unnamed(arg_expr) => Ok(arg_expr),
-        })
+        }
+        _ => match translate_function(&sql_function)? {
+            Expr::Aggregate(aggregate) => WindowFunction::Aggregate(*aggregate),
+            _ => {
+                return Err(
+                    TranslateError::UnsupportedWindowFunction(sql_function.to_string()).into(),
+                );
+            }
+        },
+    };
+    let partition_by = partition_by
+        .iter()
+        .map(translate_expr)
+        .collect::<Result<Vec<_>>>()?;
+    let order_by = order_by
+        .iter()
+        .map(translate_order_by_expr)
         .collect::<Result<Vec<_>>>()?;
 
+    Ok(Expr::Window(Box::new(Window {
+        function,
+        partition_by,
+        order_by,
+    })))
+}
+
+pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
+    let SqlFunction {
+        name, args, over, ..
+    } = sql_function;
+
+    if let Some(window_spec) = over {
+        return translate_window(sql_function, window_spec);
+    }
+
+    let name = translate_object_name(name)?.to_uppercase();
+    let function_arg_exprs = translate_function_args(args)?;
+
     if name.as_str() == "COUNT" {
         check_len(name, args.len(), 1)?;
 
@@ -217,6 +395,89 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         "AVG" => translate_aggregate_one_arg(Aggregate::Avg, args, name),
         "VARIANCE" => translate_aggregate_one_arg(Aggregate::Variance, args, name),
         "STDEV" => translate_aggregate_one_arg(Aggregate::Stdev, args, name),
+        "FIRST" => translate_aggregate_one_arg(Aggregate::First, args, name),
+        "LAST" => translate_aggregate_one_arg(Aggregate::Last, args, name),
+        "COUNT_APPROX_DISTINCT" => {
+            check_len_range(name, args.len(), 1, 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let precision = match args.get(1) {
+                Some(arg) => translate_precision(arg)?,
+                None => HyperLogLog::DEFAULT_PRECISION,
+            };
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::CountApproxDistinct {
+                expr,
+                precision,
+            })))
+        }
+        "STRING_AGG" => {
+            check_len(name, args.len(), 2)?;
+
+            let separator = translate_separator(args[1])?;
+
+            translate_string_agg(args[0], separator)
+        }
+        "GROUP_CONCAT" => {
+            check_len_range(name, args.len(), 1, 2)?;
+
+            let separator = args
+                .get(1)
+                .map(|arg| translate_separator(arg))
+                .transpose()?
+                .unwrap_or_else(|| ",".to_owned());
+
+            translate_string_agg(args[0], separator)
+        }
+        "JSON_AGG" => {
+            check_len_range(name, args.len(), 1, 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let include_nulls = args
+                .get(1)
+                .map(|arg| translate_include_nulls(arg))
+                .transpose()?
+                .unwrap_or(false);
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::JsonAgg {
+                expr,
+                include_nulls,
+            })))
+        }
+        "JSON_OBJECT_AGG" => {
+            check_len_range(name, args.len(), 2, 3)?;
+
+            let key = translate_expr(args[0])?;
+            let value = translate_expr(args[1])?;
+            let include_nulls = args
+                .get(2)
+                .map(|arg| translate_include_nulls(arg))
+                .transpose()?
+                .unwrap_or(false);
+
+            Ok(Expr::Aggregate(Box::new(Aggregate::JsonObjectAgg {
+                key,
+                value,
+                include_nulls,
+            })))
+        }
+        "PERCENTILE_CONT" | "PERCENTILE_DISC" => {
+            check_len(name.clone(), args.len(), 2)?;
+
+            let fraction = translate_expr(args[0])?;
+            let expr = translate_expr(args[1])?;
+            let aggregate = match name.as_str() {
+                "PERCENTILE_CONT" => Aggregate::PercentileCont { fraction, expr },
+                _ => Aggregate::PercentileDisc { fraction, expr },
+            };
+
+            Ok(Expr::Aggregate(Box::new(aggregate)))
+        }
+        "ROW" => args
+            .into_iter()
+            .map(translate_expr)
+            .collect::<Result<_>>()
+            .map(Expr::Tuple),
         "CONCAT" => {
             let exprs = args
                 .into_iter()
@@ -252,6 +513,56 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
                 start,
             })))
         }
+        "SPLIT_PART" => {
+            check_len(name, args.len(), 3)?;
+
+            let expr = translate_expr(args[0])?;
+            let delimiter = translate_expr(args[1])?;
+            let index = translate_expr(args[2])?;
+
+            Ok(Expr::Function(Box::new(Function::SplitPart {
+                expr,
+                delimiter,
+                index,
+            })))
+        }
+        "STRING_TO_ARRAY" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let delimiter = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::StringToArray {
+                expr,
+                delimiter,
+            })))
+        }
+        "STRPOS" => {
+            check_len(name, args.len(), 2)?;
+
+            let from_expr = translate_expr(args[0])?;
+            let sub_expr = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Position {
+                from_expr,
+                sub_expr,
+            })))
+        }
+        "LOCATE" => {
+            check_len_range(name, args.len(), 2, 3)?;
+
+            let sub_expr = translate_expr(args[0])?;
+            let from_expr = translate_expr(args[1])?;
+            let start = (args.len() > 2)
+                .then(|| translate_expr(args[2]))
+                .transpose()?;
+
+            Ok(Expr::Function(Box::new(Function::FindIdx {
+                from_expr,
+                sub_expr,
+                start,
+            })))
+        }
         "LOWER" => translate_function_one_arg(Function::Lower, args, name),
         "INITCAP" => translate_function_one_arg(Function::Initcap, args, name),
         "UPPER" => translate_function_one_arg(Function::Upper, args, name),
@@ -269,6 +580,21 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             let then = translate_expr(args[1])?;
             Ok(Expr::Function(Box::new(Function::IfNull { expr, then })))
         }
+        "COALESCE" => {
+            check_len_min(name, args.len(), 1)?;
+            let exprs = args
+                .into_iter()
+                .map(translate_expr)
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Expr::Function(Box::new(Function::Coalesce(exprs))))
+        }
+        "NULLIF" => {
+            check_len(name, args.len(), 2)?;
+            let expr1 = translate_expr(args[0])?;
+            let expr2 = translate_expr(args[1])?;
+            Ok(Expr::Function(Box::new(Function::NullIf { expr1, expr2 })))
+        }
         "RIGHT" => {
             check_len(name, args.len(), 2)?;
 
@@ -285,7 +611,7 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
                 .map(Box::new)
                 .map(Expr::Function)
         }
-        "POWER" => {
+        "POWER" | "POW" => {
             check_len(name, args.len(), 2)?;
 
             let expr = translate_expr(args[0])?;
@@ -336,14 +662,24 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             };
             Ok(Expr::Function(Box::new(Function::Rand(v))))
         }
-        "ROUND" => translate_function_one_arg(Function::Round, args, name),
+        "ROUND" => {
+            check_len_range(name, args.len(), 1, 2)?;
+            let expr = translate_expr(args[0])?;
+            let scale = if args.len() == 1 {
+                None
+            } else {
+                Some(translate_expr(args[1])?)
+            };
+
+            Ok(Expr::Function(Box::new(Function::Round { expr, scale })))
+        }
         "EXP" => translate_function_one_arg(Function::Exp, args, name),
         "LN" => translate_function_one_arg(Function::Ln, args, name),
         "LOG" => {
             check_len(name, args.len(), 2)?;
 
-            let antilog = translate_expr(args[0])?;
-            let base = translate_expr(args[1])?;
+            let base = translate_expr(args[0])?;
+            let antilog = translate_expr(args[1])?;
 
             Ok(Expr::Function(Box::new(Function::Log { antilog, base })))
         }
@@ -358,7 +694,7 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         "RADIANS" => translate_function_one_arg(Function::Radians, args, name),
         "DEGREES" => translate_function_one_arg(Function::Degrees, args, name),
         "PI" => translate_function_zero_arg(Function::Pi(), args, name),
-        "NOW" => translate_function_zero_arg(Function::Now(), args, name),
+        "NOW" | "CURRENT_TIMESTAMP" => translate_function_zero_arg(Function::Now(), args, name),
         "GCD" => {
             check_len(name, args.len(), 2)?;
 
@@ -438,9 +774,44 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
                 selector,
             })))
         }
+        "JSON_EXTRACT" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let path = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::JsonExtract {
+                expr,
+                path,
+            })))
+        }
         "ABS" => translate_function_one_arg(Function::Abs, args, name),
         "SIGN" => translate_function_one_arg(Function::Sign, args, name),
-        "GENERATE_UUID" => translate_function_zero_arg(Function::GenerateUuid(), args, name),
+        "GENERATE_UUID" | "GEN_RANDOM_UUID" | "UUID" => {
+            translate_function_zero_arg(Function::GenerateUuid(), args, name)
+        }
+        "UUID_TO_BIN" => {
+            check_len_range(name, args.len(), 1, 2)?;
+            let expr = translate_expr(args[0])?;
+            let swap = if args.len() == 1 {
+                None
+            } else {
+                Some(translate_expr(args[1])?)
+            };
+
+            Ok(Expr::Function(Box::new(Function::UuidToBin { expr, swap })))
+        }
+        "BIN_TO_UUID" => {
+            check_len_range(name, args.len(), 1, 2)?;
+            let expr = translate_expr(args[0])?;
+            let swap = if args.len() == 1 {
+                None
+            } else {
+                Some(translate_expr(args[1])?)
+            };
+
+            Ok(Expr::Function(Box::new(Function::BinToUuid { expr, swap })))
+        }
         "FORMAT" => {
             check_len(name, args.len(), 2)?;
 
@@ -495,7 +866,39 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             let expr = translate_expr(args[0])?;
             Ok(Expr::Function(Box::new(Function::Md5(expr))))
         }
-        "APPEND" => {
+        "OCTET_LENGTH" => translate_function_one_arg(Function::OctetLength, args, name),
+        "DATE_PART" => {
+            check_len(name, args.len(), 2)?;
+
+            let field = match args[0] {
+                SqlExpr::Value(SqlValue::SingleQuotedString(field)) => {
+                    translate_datetime_field_name(field)?
+                }
+                field => {
+                    return Err(TranslateError::UnsupportedDateTimeField(field.to_string()).into())
+                }
+            };
+            let expr = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Extract { field, expr })))
+        }
+        "ENCODE" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let format = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Encode { expr, format })))
+        }
+        "DECODE" => {
+            check_len(name, args.len(), 2)?;
+
+            let expr = translate_expr(args[0])?;
+            let format = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Decode { expr, format })))
+        }
+        "APPEND" | "ARRAY_APPEND" => {
             check_len(name, args.len(), 2)?;
             let expr = translate_expr(args[0])?;
             let value = translate_expr(args[1])?;
@@ -509,6 +912,58 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
 
             Ok(Expr::Function(Box::new(Function::Prepend { expr, value })))
         }
+        "ARRAY_PREPEND" => {
+            check_len(name, args.len(), 2)?;
+            let value = translate_expr(args[0])?;
+            let expr = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Prepend { expr, value })))
+        }
+        "ARRAY_REMOVE" => {
+            check_len(name, args.len(), 2)?;
+            let expr = translate_expr(args[0])?;
+            let value = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::ArrayRemove {
+                expr,
+                value,
+            })))
+        }
+        "ARRAY_REMOVE_NULLS" => translate_function_one_arg(Function::ArrayRemoveNulls, args, name),
+        "ARRAY_LENGTH" => translate_function_one_arg(Function::ArrayLength, args, name),
+        "ARRAY_REPLACE" => {
+            check_len(name, args.len(), 3)?;
+            let expr = translate_expr(args[0])?;
+            let from = translate_expr(args[1])?;
+            let to = translate_expr(args[2])?;
+
+            Ok(Expr::Function(Box::new(Function::ArrayReplace {
+                expr,
+                from,
+                to,
+            })))
+        }
+        "GROUPING" => {
+            check_len_min(name, args.len(), 1)?;
+            let exprs = args
+                .into_iter()
+                .map(translate_expr)
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Expr::Function(Box::new(Function::Grouping(exprs))))
+        }
+        "GREATEST" | "LEAST" => {
+            check_len_min(name.clone(), args.len(), 1)?;
+            let exprs = args
+                .into_iter()
+                .map(translate_expr)
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Expr::Function(Box::new(match name.as_str() {
+                "GREATEST" => Function::Greatest(exprs),
+                _ => Function::Least(exprs),
+            })))
+        }
         "POINT" => {
             check_len(name, args.len(), 2)?;
             let x = translate_expr(args[0])?;
@@ -537,6 +992,15 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
                 geometry2,
             })))
         }
+        "NEXTVAL" => translate_function_one_arg(Function::Nextval, args, name),
+        "CURRVAL" => translate_function_one_arg(Function::Currval, args, name),
+        "SETVAL" => {
+            check_len(name, args.len(), 2)?;
+
+            let name = translate_expr(args[0])?;
+            let value = translate_expr(args[1])?;
+            Ok(Expr::Function(Box::new(Function::Setval { name, value })))
+        }
         _ => {
             let exprs = args
                 .into_iter()
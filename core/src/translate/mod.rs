@@ -1,6 +1,7 @@
 mod ast_literal;
 mod data_type;
 mod ddl;
+mod enum_type;
 mod error;
 mod expr;
 mod function;
@@ -17,71 +18,140 @@ pub use self::{
 
 use {
     crate::{
-        ast::{Assignment, Statement, Variable},
+        ast::{Assignment, Expr, OnConflict, OrderByExpr, Statement, Variable},
+        parse_sql::{parse_tokens, tokenize},
         result::Result,
     },
-    ddl::translate_alter_table_operation,
-    sqlparser::ast::{
-        Assignment as SqlAssignment, Ident as SqlIdent, ObjectName as SqlObjectName,
-        ObjectType as SqlObjectType, Statement as SqlStatement, TableFactor, TableWithJoins,
+    ddl::{
+        translate_alter_table_operation, translate_checks, translate_column_defs,
+        translate_foreign_keys, translate_sequence_options,
+    },
+    enum_type::{is_type_statement, translate_type_statement},
+    sqlparser::{
+        ast::{
+            Assignment as SqlAssignment, DoUpdate as SqlDoUpdate, Ident as SqlIdent, LockType,
+            ObjectName as SqlObjectName, ObjectType as SqlObjectType, OnConflict as SqlOnConflict,
+            OnConflictAction, OnInsert, ShowCreateObject, Statement as SqlStatement, TableFactor,
+            TableWithJoins, TransactionAccessMode, TransactionMode,
+        },
+        tokenizer::Token,
     },
 };
 
+/// Parses and translates the SQL, which may have `CREATE TYPE`, `ALTER TYPE` and `DROP TYPE`
+/// statements that `parse` does not support.
+pub fn translate_sql<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<Statement>> {
+    translate_tokens(tokenize(sql.as_ref())?)
+}
+
+pub(crate) fn translate_tokens(tokens: Vec<Token>) -> Result<Vec<Statement>> {
+    if !tokens
+        .split(|token| token == &Token::SemiColon)
+        .any(is_type_statement)
+    {
+        return parse_tokens(tokens)?.iter().map(translate).collect();
+    }
+
+    let mut translated = Vec::new();
+    for tokens in statements_of(tokens) {
+        if is_type_statement(&tokens) {
+            translated.push(translate_type_statement(tokens)?);
+        } else {
+            for parsed in parse_tokens(tokens)? {
+                translated.push(translate(&parsed)?);
+            }
+        }
+    }
+
+    Ok(translated)
+}
+
+fn statements_of(tokens: Vec<Token>) -> Vec<Vec<Token>> {
+    let mut statements = vec![Vec::new()];
+    for token in tokens {
+        match token {
+            Token::SemiColon => statements.push(Vec::new()),
+            token => statements.last_mut().unwrap().push(token),
+        }
+    }
+
+    statements
+}
+
 pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
     match sql_statement {
-        SqlStatement::Query(query) => translate_query(query).map(Statement::Query),
+        SqlStatement::Query(query) => match &query.lock {
+            None => translate_query(query).map(Statement::Query),
+            Some(LockType::Update) => translate_query(query).map(Statement::SelectForUpdate),
+            Some(LockType::Share) => {
+                Err(TranslateError::UnsupportedLockType(sql_statement.to_string()).into())
+            }
+        },
         SqlStatement::Insert {
             table_name,
             columns,
             source,
+            on,
             ..
         } => Ok(Statement::Insert {
             table_name: translate_object_name(table_name)?,
             columns: translate_idents(columns),
             source: translate_query(source)?,
+            on_conflict: on.as_ref().map(translate_on_insert).transpose()?,
         }),
         SqlStatement::Update {
             table,
             assignments,
             selection,
             ..
-        } => Ok(Statement::Update {
-            table_name: translate_table_with_join(table)?,
-            assignments: assignments
+        } => {
+            let (table_name, alias) = translate_table_with_join(table)?;
+            let assignments = assignments
                 .iter()
-                .map(translate_assignment)
-                .collect::<Result<_>>()?,
-            selection: selection.as_ref().map(translate_expr).transpose()?,
-        }),
+                .map(|assignment| {
+                    translate_table_assignment(assignment, &table_name, alias.as_deref())
+                })
+                .collect::<Result<_>>()?;
+
+            Ok(Statement::Update {
+                table_name,
+                alias,
+                assignments,
+                selection: selection.as_ref().map(translate_expr).transpose()?,
+            })
+        }
         SqlStatement::Delete {
-            table_name: TableFactor::Table {
-                name: table_name, ..
-            },
+            table_name: TableFactor::Table { name, alias, .. },
             selection,
             ..
         } => Ok(Statement::Delete {
-            table_name: translate_object_name(table_name)?,
+            table_name: translate_object_name(name)?,
+            alias: alias.as_ref().map(|alias| alias.name.value.to_owned()),
             selection: selection.as_ref().map(translate_expr).transpose()?,
         }),
         SqlStatement::CreateTable {
             if_not_exists,
             name,
             columns,
+            constraints,
             query,
             engine,
             ..
         } => {
-            let columns = columns
-                .iter()
-                .map(translate_column_def)
-                .collect::<Result<Vec<_>>>()?;
+            let name = translate_object_name(name)?;
+            let checks = translate_checks(&name, columns, constraints)?;
+            let foreign_keys = translate_foreign_keys(&name, columns, constraints)?;
+            let (columns, sequences) = translate_column_defs(&name, columns)?;
 
             let columns = (!columns.is_empty()).then_some(columns);
 
             Ok(Statement::CreateTable {
                 if_not_exists: *if_not_exists,
-                name: translate_object_name(name)?,
+                name,
                 columns,
+                checks,
+                foreign_keys,
+                sequences,
                 source: match query {
                     Some(v) => Some(translate_query(v).map(Box::new)?),
                     None => None,
@@ -107,6 +177,56 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .map(translate_object_name)
                 .collect::<Result<Vec<_>>>()?,
         }),
+        SqlStatement::Drop {
+            object_type: SqlObjectType::Sequence,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropSequence {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
+        }),
+        SqlStatement::CreateSequence {
+            if_not_exists,
+            name,
+            sequence_options,
+            ..
+        } => Ok(Statement::CreateSequence {
+            if_not_exists: *if_not_exists,
+            name: translate_object_name(name)?,
+            options: translate_sequence_options(sequence_options)?,
+        }),
+        SqlStatement::CreateView {
+            or_replace,
+            materialized: false,
+            name,
+            columns,
+            query,
+            ..
+        } => Ok(Statement::CreateView {
+            or_replace: *or_replace,
+            name: translate_object_name(name)?,
+            columns: columns
+                .iter()
+                .map(|column| column.value.to_owned())
+                .collect(),
+            query: translate_query(query).map(Box::new)?,
+        }),
+        SqlStatement::Drop {
+            object_type: SqlObjectType::View,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropView {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
+        }),
         SqlStatement::DropFunction {
             if_exists,
             func_desc,
@@ -122,9 +242,10 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             name,
             table_name,
             columns,
+            unique,
             ..
         } => {
-            if columns.len() > 1 {
+            if columns.len() > 1 && !unique {
                 return Err(TranslateError::CompositeIndexNotSupported.into());
             }
 
@@ -134,10 +255,29 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 return Err(TranslateError::ReservedIndexName(name).into());
             };
 
+            if columns.iter().any(|column| column.nulls_first.is_some()) {
+                return Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into());
+            }
+
+            let column = match columns.as_slice() {
+                [column] => translate_order_by_expr(column)?,
+                columns => OrderByExpr {
+                    expr: Expr::Tuple(
+                        columns
+                            .iter()
+                            .map(|column| translate_expr(&column.expr))
+                            .collect::<Result<_>>()?,
+                    ),
+                    asc: None,
+                    nulls_first: None,
+                },
+            };
+
             Ok(Statement::CreateIndex {
                 name,
                 table_name: translate_object_name(table_name)?,
-                column: translate_order_by_expr(&columns[0])?,
+                column,
+                unique: *unique,
             })
         }
         SqlStatement::Drop {
@@ -163,7 +303,12 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
 
             Ok(Statement::DropIndex { name, table_name })
         }
-        SqlStatement::StartTransaction { .. } => Ok(Statement::StartTransaction),
+        SqlStatement::StartTransaction { modes } => Ok(Statement::StartTransaction {
+            read_only: translate_read_only(modes).unwrap_or(false),
+        }),
+        SqlStatement::SetTransaction { modes, .. } => translate_read_only(modes)
+            .map(|read_only| Statement::SetTransaction { read_only })
+            .ok_or_else(|| TranslateError::UnsupportedStatement(sql_statement.to_string()).into()),
         SqlStatement::Commit { .. } => Ok(Statement::Commit),
         SqlStatement::Rollback { .. } => Ok(Statement::Rollback),
         SqlStatement::ShowTables {
@@ -199,6 +344,22 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
         SqlStatement::ShowColumns { table_name, .. } => Ok(Statement::ShowColumns {
             table_name: translate_object_name(table_name)?,
         }),
+        SqlStatement::ShowCreate {
+            obj_type: ShowCreateObject::Table,
+            obj_name,
+        } => Ok(Statement::ShowCreateTable(translate_object_name(obj_name)?)),
+        SqlStatement::Analyze { table_name, .. } => Ok(Statement::Analyze {
+            table_name: translate_object_name(table_name)?,
+        }),
+        SqlStatement::Explain {
+            analyze: false,
+            verbose,
+            statement,
+            ..
+        } => Ok(Statement::Explain {
+            verbose: *verbose,
+            statement: Box::new(translate(statement)?),
+        }),
         SqlStatement::CreateFunction {
             or_replace,
             name,
@@ -230,6 +391,47 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
     }
 }
 
+/// Returns whether the transaction access mode is `READ ONLY`, or `None` if it is not specified.
+fn translate_read_only(modes: &[TransactionMode]) -> Option<bool> {
+    modes.iter().rev().find_map(|mode| match mode {
+        TransactionMode::AccessMode(TransactionAccessMode::ReadOnly) => Some(true),
+        TransactionMode::AccessMode(TransactionAccessMode::ReadWrite) => Some(false),
+        TransactionMode::IsolationLevel(_) => None,
+    })
+}
+
+/// Translates `ON CONFLICT DO NOTHING`, which skips the rows violating any unique or primary key
+/// constraint regardless of the conflict target, and `ON CONFLICT (target) DO UPDATE SET ...`
+fn translate_on_insert(on: &OnInsert) -> Result<OnConflict> {
+    match on {
+        OnInsert::OnConflict(SqlOnConflict {
+            action: OnConflictAction::DoNothing,
+            ..
+        }) => Ok(OnConflict::DoNothing),
+        OnInsert::OnConflict(SqlOnConflict {
+            conflict_target,
+            action:
+                OnConflictAction::DoUpdate(SqlDoUpdate {
+                    assignments,
+                    selection: None,
+                }),
+        }) => {
+            if conflict_target.is_empty() {
+                return Err(TranslateError::LackOfConflictTarget.into());
+            }
+
+            Ok(OnConflict::DoUpdate {
+                target: translate_idents(conflict_target),
+                assignments: assignments
+                    .iter()
+                    .map(translate_assignment)
+                    .collect::<Result<_>>()?,
+            })
+        }
+        _ => Err(TranslateError::UnsupportedOnInsert(on.to_string()).into()),
+    }
+}
+
 pub fn translate_assignment(sql_assignment: &SqlAssignment) -> Result<Assignment> {
     let SqlAssignment { id, value } = sql_assignment;
 
@@ -249,12 +451,36 @@ pub fn translate_assignment(sql_assignment: &SqlAssignment) -> Result<Assignment
     })
 }
 
-fn translate_table_with_join(table: &TableWithJoins) -> Result<String> {
+/// Translates the assignment of UPDATE, which may qualify its column with the table name or
+/// the table alias (e.g. `UPDATE Foo AS F SET F.id = 1`)
+fn translate_table_assignment(
+    sql_assignment: &SqlAssignment,
+    table_name: &str,
+    alias: Option<&str>,
+) -> Result<Assignment> {
+    let SqlAssignment { id, value } = sql_assignment;
+
+    match id.as_slice() {
+        [qualifier, column] if qualifier.value == alias.unwrap_or(table_name) => {
+            translate_assignment(&SqlAssignment {
+                id: vec![column.clone()],
+                value: value.clone(),
+            })
+        }
+        _ => translate_assignment(sql_assignment),
+    }
+}
+
+fn translate_table_with_join(table: &TableWithJoins) -> Result<(String, Option<String>)> {
     if !table.joins.is_empty() {
         return Err(TranslateError::JoinOnUpdateNotSupported.into());
     }
     match &table.relation {
-        TableFactor::Table { name, .. } => translate_object_name(name),
+        TableFactor::Table { name, alias, .. } => {
+            let alias = alias.as_ref().map(|alias| alias.name.value.to_owned());
+
+            Ok((translate_object_name(name)?, alias))
+        }
         t => Err(TranslateError::UnsupportedTableFactor(t.to_string()).into()),
     }
 }
@@ -5,30 +5,104 @@ use {
     },
     crate::{
         ast::{
-            AstLiteral, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query,
-            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, Values,
+            AstLiteral, Cte, Dictionary, Expr, GroupByModifier, Join, JoinConstraint, JoinExecutor,
+            JoinOperator, Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+            TableWithJoins, Values,
         },
+        executor::DEFAULT_MAX_RECURSION,
         result::Result,
     },
     sqlparser::ast::{
         Expr as SqlExpr, FunctionArg as SqlFunctionArg, Join as SqlJoin,
         JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator, Query as SqlQuery,
         Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
-        TableAlias as SqlTableAlias, TableFactor as SqlTableFactor,
-        TableWithJoins as SqlTableWithJoins,
+        SetOperator as SqlSetOperator, SetQuantifier, TableAlias as SqlTableAlias,
+        TableFactor as SqlTableFactor, TableWithJoins as SqlTableWithJoins, With as SqlWith,
     },
 };
 
+fn translate_with(sql_with: &SqlWith) -> Result<Vec<Cte>> {
+    let SqlWith {
+        recursive,
+        cte_tables,
+    } = sql_with;
+
+    cte_tables
+        .iter()
+        .enumerate()
+        .map(|(i, cte)| {
+            let name = &cte.alias.name.value;
+            if cte_tables[..i]
+                .iter()
+                .any(|prev| &prev.alias.name.value == name)
+            {
+                return Err(TranslateError::DuplicateCteName(name.to_owned()).into());
+            }
+
+            let alias = TableAlias {
+                name: name.to_owned(),
+                columns: translate_idents(&cte.alias.columns),
+            };
+            let query = match (*recursive, cte.query.body.as_ref()) {
+                (
+                    true,
+                    SqlSetExpr::SetOperation {
+                        op: SqlSetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    },
+                ) => {
+                    translate_recursive_cte(&cte.query, alias.clone(), set_quantifier, left, right)?
+                }
+                _ => translate_query(&cte.query)?,
+            };
+
+            Ok(Cte { alias, query })
+        })
+        .collect()
+}
+
+/// The recursive term refers to the common table expression itself as a plain table, which is
+/// filled with the working rows on execution.
+fn translate_recursive_cte(
+    sql_query: &SqlQuery,
+    alias: TableAlias,
+    set_quantifier: &SetQuantifier,
+    left: &SqlSetExpr,
+    right: &SqlSetExpr,
+) -> Result<Query> {
+    let body = SetExpr::Recursive {
+        alias,
+        all: matches!(set_quantifier, SetQuantifier::All),
+        base: translate_set_expr(left).map(Box::new)?,
+        step: translate_set_expr(right).map(Box::new)?,
+        max_recursion: DEFAULT_MAX_RECURSION,
+    };
+
+    translate_query_with_body(sql_query, body)
+}
+
 pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
+    let body = translate_set_expr(&sql_query.body)?;
+
+    translate_query_with_body(sql_query, body)
+}
+
+fn translate_query_with_body(sql_query: &SqlQuery, body: SetExpr) -> Result<Query> {
     let SqlQuery {
-        body,
+        with,
         order_by,
         limit,
         offset,
         ..
     } = sql_query;
 
-    let body = translate_set_expr(body)?;
+    let with = match with {
+        Some(with) => translate_with(with)?,
+        None => Vec::new(),
+    };
+
     let order_by = order_by
         .iter()
         .map(translate_order_by_expr)
@@ -41,6 +115,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         .transpose()?;
 
     Ok(Query {
+        with,
         body,
         order_by,
         limit,
@@ -57,6 +132,29 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SqlSetOperator::Except => SetOperator::Except,
+                SqlSetOperator::Intersect => SetOperator::Intersect,
+                SqlSetOperator::Union => {
+                    return Err(
+                        TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into(),
+                    );
+                }
+            };
+
+            Ok(SetExpr::SetOperation {
+                op,
+                all: matches!(set_quantifier, SetQuantifier::All),
+                left: translate_set_expr(left).map(Box::new)?,
+                right: translate_set_expr(right).map(Box::new)?,
+            })
+        }
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
@@ -72,7 +170,12 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         ..
     } = sql_select;
 
-    if from.len() > 1 {
+    let lateral_only = from
+        .iter()
+        .skip(1)
+        .all(|SqlTableWithJoins { relation, .. }| is_lateral(relation));
+
+    if !lateral_only {
         return Err(TranslateError::TooManyTables.into());
     }
 
@@ -80,8 +183,18 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         return Err(TranslateError::SelectDistinctNotSupported.into());
     }
 
-    let from = match from.get(0) {
-        Some(sql_table_with_joins) => translate_table_with_joins(sql_table_with_joins)?,
+    let from = match from.split_first() {
+        Some((sql_table_with_joins, laterals)) => {
+            let TableWithJoins { relation, joins } =
+                translate_table_with_joins(sql_table_with_joins)?;
+            let lateral_joins = laterals.iter().map(translate_lateral_joins);
+            let joins = std::iter::once(Ok(joins))
+                .chain(lateral_joins)
+                .collect::<Result<Vec<_>>>()?
+                .concat();
+
+            TableWithJoins { relation, joins }
+        }
         None => TableWithJoins {
             relation: TableFactor::Series {
                 alias: TableAlias {
@@ -101,11 +214,32 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
             .collect::<Result<_>>()?,
         from,
         selection: selection.as_ref().map(translate_expr).transpose()?,
-        group_by: group_by.iter().map(translate_expr).collect::<Result<_>>()?,
+        group_by: group_by
+            .iter()
+            .map(translate_group_by_expr)
+            .collect::<Result<_>>()?,
         having: having.as_ref().map(translate_expr).transpose()?,
     })
 }
 
+fn translate_group_by_expr(sql_expr: &SqlExpr) -> Result<Expr> {
+    let translate_elems = |elems: &[Vec<SqlExpr>]| -> Result<Vec<Vec<Expr>>> {
+        elems
+            .iter()
+            .map(|exprs| exprs.iter().map(translate_expr).collect())
+            .collect()
+    };
+
+    let modifier = match sql_expr {
+        SqlExpr::Rollup(elems) => GroupByModifier::Rollup(translate_elems(elems)?),
+        SqlExpr::Cube(elems) => GroupByModifier::Cube(translate_elems(elems)?),
+        SqlExpr::GroupingSets(sets) => GroupByModifier::GroupingSets(translate_elems(sets)?),
+        _ => return translate_expr(sql_expr),
+    };
+
+    Ok(Expr::GroupByModifier(Box::new(modifier)))
+}
+
 pub fn translate_select_item(sql_select_item: &SqlSelectItem) -> Result<SelectItem> {
     match sql_select_item {
         SqlSelectItem::UnnamedExpr(expr) => {
@@ -144,6 +278,35 @@ fn translate_table_with_joins(sql_table_with_joins: &SqlTableWithJoins) -> Resul
     })
 }
 
+/// `LATERAL` subqueries and `UNNEST` are executed for each row of the tables on their left.
+fn is_lateral(sql_table_factor: &SqlTableFactor) -> bool {
+    match sql_table_factor {
+        SqlTableFactor::Derived { lateral, .. } => *lateral,
+        SqlTableFactor::Table {
+            name,
+            args: Some(_),
+            ..
+        } => name.to_string().to_uppercase() == "UNNEST",
+        _ => false,
+    }
+}
+
+/// `FROM a, LATERAL (...) AS b` is translated the same as `FROM a JOIN LATERAL (...) AS b`,
+/// so the lateral subquery is executed for each row of the tables on its left.
+/// `FROM a, UNNEST(...) AS b` is translated the same way.
+fn translate_lateral_joins(sql_table_with_joins: &SqlTableWithJoins) -> Result<Vec<Join>> {
+    let SqlTableWithJoins { relation, joins } = sql_table_with_joins;
+    let join = Join {
+        relation: translate_table_factor(relation)?,
+        join_operator: JoinOperator::Inner(JoinConstraint::None),
+        join_executor: JoinExecutor::NestedLoop,
+    };
+
+    std::iter::once(Ok(join))
+        .chain(joins.iter().map(translate_join))
+        .collect()
+}
+
 fn translate_table_alias(alias: &Option<SqlTableAlias>) -> Option<TableAlias> {
     alias
         .as_ref()
@@ -175,14 +338,18 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
         SqlTableFactor::Table {
             name, alias, args, ..
         } => {
-            let object_name = translate_object_name(name)?.to_uppercase();
             let alias = translate_table_alias(alias);
+            let object_name = translate_object_name(name)?.to_uppercase();
 
             match (object_name.as_str(), args) {
                 ("SERIES", Some(args)) => Ok(TableFactor::Series {
                     alias: alias_or_name(alias, object_name),
                     size: translate_table_args(args)?,
                 }),
+                ("UNNEST", Some(args)) => Ok(TableFactor::Unnest {
+                    alias: alias_or_name(alias, object_name),
+                    expr: translate_table_args(args)?,
+                }),
                 ("GLUE_OBJECTS", _) => Ok(TableFactor::Dictionary {
                     dict: Dictionary::GlueObjects,
                     alias: alias_or_name(alias, object_name),
@@ -209,7 +376,9 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
             }
         }
         SqlTableFactor::Derived {
-            subquery, alias, ..
+            lateral,
+            subquery,
+            alias,
         } => {
             if let Some(alias) = alias {
                 Ok(TableFactor::Derived {
@@ -218,6 +387,7 @@ fn translate_table_factor(sql_table_factor: &SqlTableFactor) -> Result<TableFact
                         name: alias.name.value.to_owned(),
                         columns: translate_idents(&alias.columns),
                     },
+                    lateral: *lateral,
                 })
             } else {
                 Err(TranslateError::LackOfAlias.into())
@@ -243,12 +413,8 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
     let translate_constraint = |sql_join_constraint: &SqlJoinConstraint| match sql_join_constraint {
         SqlJoinConstraint::On(expr) => translate_expr(expr).map(JoinConstraint::On),
         SqlJoinConstraint::None => Ok(JoinConstraint::None),
-        SqlJoinConstraint::Using(_) => {
-            Err(TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into())
-        }
-        SqlJoinConstraint::Natural => {
-            Err(TranslateError::UnsupportedJoinConstraint("NATURAL".to_owned()).into())
-        }
+        SqlJoinConstraint::Using(idents) => Ok(JoinConstraint::Using(translate_idents(idents))),
+        SqlJoinConstraint::Natural => Ok(JoinConstraint::Natural),
     };
 
     let join_operator = match sql_join_operator {
@@ -258,6 +424,9 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
         SqlJoinOperator::LeftOuter(sql_join_constraint) => {
             translate_constraint(sql_join_constraint).map(JoinOperator::LeftOuter)
         }
+        SqlJoinOperator::FullOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::FullOuter)
+        }
         _ => {
             Err(TranslateError::UnsupportedJoinOperator(format!("{:?}", sql_join_operator)).into())
         }
@@ -0,0 +1,185 @@
+//! Derive macros which map rows of GlueSQL query results to Rust structs.
+//!
+//! The generated code refers to the `gluesql_core` crate, so it has to be a dependency of the
+//! crate using the macros.
+
+use {
+    proc_macro::TokenStream,
+    proc_macro2::TokenStream as TokenStream2,
+    quote::quote,
+    syn::{
+        parse_macro_input, Data, DeriveInput, Error, Field, Fields, Ident, Lit, Meta, NestedMeta,
+        Result,
+    },
+};
+
+/// Implements `TryFrom<Row>` and `FromValue` for a struct with named fields.
+///
+/// Each field is read from the column of the same name, which can be changed with
+/// `#[column(name = "...")]`. `Option` fields accept `NULL`, and fields of other structs deriving
+/// `FromRow` are read from map values.
+#[proc_macro_derive(FromRow, attributes(column))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_from_row(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Implements `From<Self> for Row` and `IntoValue` for a struct with named fields.
+///
+/// The row has a column for each field, named by the field or by `#[column(name = "...")]`.
+#[proc_macro_derive(IntoRow, attributes(column))]
+pub fn derive_into_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_into_row(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_from_row(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = named_fields(&input)?
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let column = column_name(field)?;
+
+            Ok(quote! {
+                #ident: ::gluesql_core::data::FromValue::from_value(
+                    row.get_value(#column).ok_or_else(|| {
+                        ::gluesql_core::data::RowError::ColumnNotFound(#column.to_owned())
+                    })?,
+                )?
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::std::convert::TryFrom<::gluesql_core::data::Row>
+            for #name #ty_generics #where_clause
+        {
+            type Error = ::gluesql_core::prelude::Error;
+
+            fn try_from(
+                row: ::gluesql_core::data::Row,
+            ) -> ::gluesql_core::prelude::Result<Self> {
+                Ok(Self {
+                    #(#fields,)*
+                })
+            }
+        }
+
+        impl #impl_generics ::gluesql_core::data::FromValue for #name #ty_generics #where_clause {
+            fn from_value(
+                value: &::gluesql_core::data::Value,
+            ) -> ::gluesql_core::prelude::Result<Self> {
+                let values = <::std::collections::HashMap<
+                    ::std::string::String,
+                    ::gluesql_core::data::Value,
+                > as ::gluesql_core::data::FromValue>::from_value(value)?;
+
+                <Self as ::std::convert::TryFrom<::gluesql_core::data::Row>>::try_from(
+                    ::gluesql_core::data::Row::Map(values),
+                )
+            }
+        }
+    })
+}
+
+fn expand_into_row(input: DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = named_fields(&input)?;
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let columns = fields.iter().map(column_name).collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #impl_generics ::std::convert::From<#name #ty_generics>
+            for ::gluesql_core::data::Row #where_clause
+        {
+            fn from(value: #name #ty_generics) -> Self {
+                ::gluesql_core::data::Row::Vec {
+                    columns: ::std::rc::Rc::from(vec![#(#columns.to_owned()),*]),
+                    values: vec![
+                        #(::gluesql_core::data::IntoValue::into_value(value.#idents)),*
+                    ],
+                }
+            }
+        }
+
+        impl #impl_generics ::gluesql_core::data::IntoValue for #name #ty_generics #where_clause {
+            fn into_value(self) -> ::gluesql_core::data::Value {
+                ::gluesql_core::data::Value::Map(::std::collections::HashMap::from([
+                    #((
+                        #columns.to_owned(),
+                        ::gluesql_core::data::IntoValue::into_value(self.#idents),
+                    )),*
+                ]))
+            }
+        }
+    })
+}
+
+fn named_fields(input: &DeriveInput) -> Result<Vec<&Field>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().collect()),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                "only structs with named fields are supported",
+            )),
+        },
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "only structs with named fields are supported",
+        )),
+    }
+}
+
+/// Reads `#[column(name = "...")]`, or falls back to the field name.
+fn column_name(field: &Field) -> Result<String> {
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("column"))
+    {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    r#"expected #[column(name = "...")]"#,
+                ))
+            }
+        };
+
+        for nested in list.nested.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("name") =>
+                {
+                    match &name_value.lit {
+                        Lit::Str(name) => return Ok(name.value()),
+                        lit => return Err(Error::new_spanned(lit, "column name must be a string")),
+                    }
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        nested,
+                        r#"expected #[column(name = "...")]"#,
+                    ))
+                }
+            }
+        }
+    }
+
+    field
+        .ident
+        .as_ref()
+        .map(Ident::to_string)
+        .ok_or_else(|| Error::new_spanned(field, "only named fields are supported"))
+}
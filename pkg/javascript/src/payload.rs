@@ -95,7 +95,9 @@ fn convert_payload(payload: Payload) -> Json {
         Payload::AlterTable => json!({ "type": "ALTER TABLE" }),
         Payload::CreateIndex => json!({ "type": "CREATE INDEX" }),
         Payload::DropIndex => json!({ "type": "DROP INDEX" }),
+        Payload::Analyze => json!({ "type": "ANALYZE" }),
         Payload::StartTransaction => json!({ "type": "BEGIN" }),
+        Payload::SetTransaction => json!({ "type": "SET TRANSACTION" }),
         Payload::Commit => json!({ "type": "COMMIT" }),
         Payload::Rollback => json!({ "type": "ROLLBACK" }),
         Payload::ShowVariable(PayloadVariable::Version(version)) => {
@@ -111,6 +113,10 @@ fn convert_payload(payload: Payload) -> Json {
             })
         }
         Payload::DropFunction => json!({ "type": "DROP FUNCTION" }),
+        Payload::DropSequence => json!({ "type": "DROP SEQUENCE" }),
+        Payload::DropType => json!({ "type": "DROP TYPE" }),
+        Payload::AlterType => json!({ "type": "ALTER TYPE" }),
+        Payload::DropView => json!({ "type": "DROP VIEW" }),
         Payload::ShowVariable(PayloadVariable::Functions(function_names)) => {
             json!({
                 "type": "SHOW FUNCTIONS",
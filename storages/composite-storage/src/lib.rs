@@ -92,3 +92,7 @@ impl gluesql_core::store::IndexMut for CompositeStorage {}
 impl Metadata for CompositeStorage {}
 impl gluesql_core::store::CustomFunction for CompositeStorage {}
 impl gluesql_core::store::CustomFunctionMut for CompositeStorage {}
+impl gluesql_core::store::Sequence for CompositeStorage {}
+impl gluesql_core::store::SequenceMut for CompositeStorage {}
+impl gluesql_core::store::EnumType for CompositeStorage {}
+impl gluesql_core::store::EnumTypeMut for CompositeStorage {}
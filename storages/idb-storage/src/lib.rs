@@ -443,3 +443,7 @@ impl gluesql_core::store::Transaction for IdbStorage {}
 impl Metadata for IdbStorage {}
 impl gluesql_core::store::CustomFunction for IdbStorage {}
 impl gluesql_core::store::CustomFunctionMut for IdbStorage {}
+impl gluesql_core::store::Sequence for IdbStorage {}
+impl gluesql_core::store::SequenceMut for IdbStorage {}
+impl gluesql_core::store::EnumType for IdbStorage {}
+impl gluesql_core::store::EnumTypeMut for IdbStorage {}
@@ -1,7 +1,13 @@
 use {
     super::JsonStorage,
-    gluesql_core::store::{CustomFunction, CustomFunctionMut},
+    gluesql_core::store::{
+        CustomFunction, CustomFunctionMut, EnumType, EnumTypeMut, Sequence, SequenceMut,
+    },
 };
 
 impl CustomFunction for JsonStorage {}
 impl CustomFunctionMut for JsonStorage {}
+impl Sequence for JsonStorage {}
+impl SequenceMut for JsonStorage {}
+impl EnumType for JsonStorage {}
+impl EnumTypeMut for JsonStorage {}
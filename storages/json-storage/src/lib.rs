@@ -51,7 +51,7 @@ impl JsonStorage {
         }
 
         let schema_path = self.schema_path(table_name);
-        let column_defs = match schema_path.exists() {
+        let (column_defs, checks, foreign_keys, view) = match schema_path.exists() {
             true => {
                 let mut file = File::open(&schema_path).map_storage_err()?;
                 let mut ddl = String::new();
@@ -64,16 +64,24 @@ impl JsonStorage {
                     ));
                 }
 
-                schema.column_defs
+                (
+                    schema.column_defs,
+                    schema.checks,
+                    schema.foreign_keys,
+                    schema.view,
+                )
             }
-            false => None,
+            false => (None, Vec::new(), Vec::new(), None),
         };
 
         Ok(Some(Schema {
             table_name: table_name.to_owned(),
             column_defs,
+            checks,
+            foreign_keys,
             indexes: vec![],
             engine: None,
+            view,
         }))
     }
 
@@ -23,7 +23,7 @@ impl StoreMut for JsonStorage {
         let data_path = self.jsonl_path(schema.table_name.as_str());
         File::create(data_path).map_storage_err()?;
 
-        if schema.column_defs.is_some() {
+        if schema.column_defs.is_some() || schema.view.is_some() {
             let schema_path = self.schema_path(schema.table_name.as_str());
             let ddl = schema.to_ddl();
             let mut file = File::create(schema_path).map_storage_err()?;
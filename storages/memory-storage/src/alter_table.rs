@@ -2,8 +2,8 @@ use {
     super::MemoryStorage,
     async_trait::async_trait,
     gluesql_core::{
-        ast::ColumnDef,
-        data::Value,
+        ast::{ColumnDef, Expr},
+        data::{TimeZone, Value},
         error::{AlterTableError, Error, Result},
         store::{AlterTable, DataRow},
     },
@@ -89,7 +89,7 @@ impl AlterTable for MemoryStorage {
             (Some(expr), _) => {
                 let evaluated = gluesql_core::executor::evaluate_stateless(None, expr).await?;
 
-                evaluated.try_into_value(data_type, *nullable)?
+                evaluated.try_into_value(data_type, *nullable, TimeZone::UTC)?
             }
             (None, true) => Value::Null,
             (None, false) => {
@@ -165,4 +165,46 @@ impl AlterTable for MemoryStorage {
 
         Ok(())
     }
+
+    async fn set_column_nullable(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        nullable: bool,
+    ) -> Result<()> {
+        self.column_def_mut(table_name, column_name)?.nullable = nullable;
+
+        Ok(())
+    }
+
+    async fn set_column_default(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        default: Option<&Expr>,
+    ) -> Result<()> {
+        self.column_def_mut(table_name, column_name)?.default = default.cloned();
+
+        Ok(())
+    }
+}
+
+impl MemoryStorage {
+    fn column_def_mut(&mut self, table_name: &str, column_name: &str) -> Result<&mut ColumnDef> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        let column_def = item
+            .schema
+            .column_defs
+            .as_mut()
+            .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()))?
+            .iter_mut()
+            .find(|column_def| column_def.name == column_name)
+            .ok_or_else(|| AlterTableError::AlteringColumnNotFound(column_name.to_owned()))?;
+
+        Ok(column_def)
+    }
 }
@@ -1,26 +1,68 @@
 use {
-    super::MemoryStorage,
+    super::{Item, MemoryStorage},
     async_trait::async_trait,
     gluesql_core::{
-        ast::{IndexOperator, OrderByExpr},
-        data::Value,
-        error::{Error, Result},
-        store::{Index, IndexMut, RowIter},
+        ast::{Expr, IndexOperator, OrderByExpr},
+        chrono::Utc,
+        data::{Key, SchemaIndex, SchemaIndexOrd, Value},
+        error::{IndexError, Result},
+        executor::evaluate_stateless,
+        store::{DataRow, Index, IndexMut, RowIter},
+    },
+    std::{
+        collections::{BTreeMap, BTreeSet},
+        iter::empty,
+        ops::Bound,
     },
 };
 
+/// Entries of an ordered index, from the evaluated index value to the keys of the rows
+pub type IndexEntries = BTreeMap<Key, BTreeSet<Key>>;
+
 #[async_trait(?Send)]
 impl Index for MemoryStorage {
     async fn scan_indexed_data(
         &self,
-        _table_name: &str,
-        _index_name: &str,
-        _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+        let item = match self.items.get(table_name) {
+            Some(item) => item,
+            None => return Ok(Box::new(empty())),
+        };
+        let entries = item
+            .indexes
+            .get(index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        let range = match cmp_value {
+            None => (Bound::Unbounded, Bound::Unbounded),
+            Some((op, value)) => {
+                let key = index_key(value)?;
+
+                match op {
+                    IndexOperator::Eq => (Bound::Included(key.clone()), Bound::Included(key)),
+                    IndexOperator::Gt => (Bound::Excluded(key), Bound::Unbounded),
+                    IndexOperator::GtEq => (Bound::Included(key), Bound::Unbounded),
+                    IndexOperator::Lt => (Bound::Unbounded, Bound::Excluded(key)),
+                    IndexOperator::LtEq => (Bound::Unbounded, Bound::Included(key)),
+                }
+            }
+        };
+
+        let entries = entries.range(range);
+        let keys = match asc {
+            Some(true) | None => entries.flat_map(|(_, keys)| keys).collect::<Vec<_>>(),
+            Some(false) => entries.rev().flat_map(|(_, keys)| keys).collect(),
+        };
+        let rows = keys
+            .into_iter()
+            .filter_map(|key| item.rows.get(key).map(|row| Ok((key.clone(), row.clone()))))
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(rows.into_iter()))
     }
 }
 
@@ -28,18 +70,151 @@ impl Index for MemoryStorage {
 impl IndexMut for MemoryStorage {
     async fn create_index(
         &mut self,
-        _table_name: &str,
-        _index_name: &str,
-        _column: &OrderByExpr,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+        unique: bool,
     ) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if item
+            .schema
+            .indexes
+            .iter()
+            .any(|index| index.name == index_name)
+        {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let columns = item.columns();
+        let mut entries = IndexEntries::new();
+        for (key, row) in item.rows.iter() {
+            let value = evaluate_index_key(&column.expr, columns.as_deref(), row).await?;
+
+            entries.entry(value).or_default().insert(key.clone());
+        }
+
+        item.schema.indexes.push(SchemaIndex {
+            name: index_name.to_owned(),
+            expr: column.expr.clone(),
+            order: SchemaIndexOrd::Both,
+            created: Utc::now().naive_utc(),
+            unique,
+        });
+        item.indexes.insert(index_name.to_owned(), entries);
+
+        Ok(())
+    }
+
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let item = self
+            .items
+            .get_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        let i = item
+            .schema
+            .indexes
+            .iter()
+            .position(|index| index.name == index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        item.schema.indexes.remove(i);
+        item.indexes.remove(index_name);
+
+        Ok(())
+    }
+}
+
+impl Item {
+    /// Inserts or replaces the row, keeping every index of the table in sync
+    pub(crate) async fn insert_row(&mut self, key: Key, row: DataRow) -> Result<()> {
+        let index_keys = self.index_keys(&row).await?;
+        self.delete_row(&key).await?;
+
+        for (index_name, value) in index_keys {
+            self.indexes
+                .entry(index_name)
+                .or_default()
+                .entry(value)
+                .or_default()
+                .insert(key.clone());
+        }
+        self.rows.insert(key, row);
+
+        Ok(())
+    }
+
+    /// Deletes the row and its index entries
+    pub(crate) async fn delete_row(&mut self, key: &Key) -> Result<()> {
+        let index_keys = match self.rows.get(key) {
+            Some(row) => self.index_keys(row).await?,
+            None => return Ok(()),
+        };
+        self.rows.remove(key);
+
+        for (index_name, value) in index_keys {
+            let entries = match self.indexes.get_mut(&index_name) {
+                Some(entries) => entries,
+                None => continue,
+            };
+
+            if let Some(keys) = entries.get_mut(&value) {
+                keys.remove(key);
+
+                if keys.is_empty() {
+                    entries.remove(&value);
+                }
+            }
+        }
+
+        Ok(())
     }
 
-    async fn drop_index(&mut self, _table_name: &str, _index_name: &str) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+    async fn index_keys(&self, row: &DataRow) -> Result<Vec<(String, Key)>> {
+        if self.schema.indexes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let columns = self.columns();
+        let mut index_keys = Vec::with_capacity(self.schema.indexes.len());
+        for SchemaIndex { name, expr, .. } in self.schema.indexes.iter() {
+            let value = evaluate_index_key(expr, columns.as_deref(), row).await?;
+
+            index_keys.push((name.to_owned(), value));
+        }
+
+        Ok(index_keys)
+    }
+
+    fn columns(&self) -> Option<Vec<String>> {
+        self.schema.column_defs.as_ref().map(|column_defs| {
+            column_defs
+                .iter()
+                .map(|column_def| column_def.name.to_owned())
+                .collect()
+        })
+    }
+}
+
+async fn evaluate_index_key(
+    index_expr: &Expr,
+    columns: Option<&[String]>,
+    row: &DataRow,
+) -> Result<Key> {
+    let context = Some(row.as_context(columns));
+    let value: Value = evaluate_stateless(context, index_expr).await?.try_into()?;
+
+    index_key(value)
+}
+
+/// Composite index values are lists, which are compared by their bytes
+fn index_key(value: Value) -> Result<Key> {
+    match value {
+        Value::List(_) => value.to_cmp_be_bytes().map(Key::Bytea),
+        value => Key::try_from(value),
     }
 }
@@ -3,15 +3,23 @@
 mod alter_table;
 mod index;
 mod metadata;
+mod sequence;
 mod transaction;
 
+pub use {index::IndexEntries, sequence::Sequences};
+
 use {
     async_trait::async_trait,
     gluesql_core::{
         chrono::Utc,
-        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        data::{
+            CustomFunction as StructCustomFunction, EnumType as StructEnumType, Key, Schema, Value,
+        },
         error::Result,
-        store::{CustomFunction, CustomFunctionMut, DataRow, RowIter, Store, StoreMut},
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, EnumType, EnumTypeMut, RowIter, Store,
+            StoreMut,
+        },
     },
     serde::{Deserialize, Serialize},
     std::{
@@ -24,6 +32,8 @@ use {
 pub struct Item {
     pub schema: Schema,
     pub rows: BTreeMap<Key, DataRow>,
+    #[serde(default)]
+    pub indexes: HashMap<String, IndexEntries>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -32,6 +42,10 @@ pub struct MemoryStorage {
     pub items: HashMap<String, Item>,
     pub metadata: HashMap<String, HashMap<String, Value>>,
     pub functions: HashMap<String, StructCustomFunction>,
+    #[serde(default)]
+    pub sequences: Sequences,
+    #[serde(default)]
+    pub enum_types: HashMap<String, StructEnumType>,
 }
 
 #[async_trait(?Send)]
@@ -57,6 +71,35 @@ impl CustomFunctionMut for MemoryStorage {
     }
 }
 
+#[async_trait(?Send)]
+impl EnumType for MemoryStorage {
+    async fn fetch_enum_type(&self, name: &str) -> Result<Option<StructEnumType>> {
+        Ok(self.enum_types.get(name).cloned())
+    }
+
+    async fn fetch_all_enum_types(&self) -> Result<Vec<StructEnumType>> {
+        let mut enum_types = self.enum_types.values().cloned().collect::<Vec<_>>();
+        enum_types.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(enum_types)
+    }
+}
+
+#[async_trait(?Send)]
+impl EnumTypeMut for MemoryStorage {
+    async fn insert_enum_type(&mut self, enum_type: StructEnumType) -> Result<()> {
+        self.enum_types.insert(enum_type.name.to_owned(), enum_type);
+
+        Ok(())
+    }
+
+    async fn delete_enum_type(&mut self, name: &str) -> Result<()> {
+        self.enum_types.remove(name);
+
+        Ok(())
+    }
+}
+
 #[async_trait(?Send)]
 impl Store for MemoryStorage {
     async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
@@ -109,6 +152,7 @@ impl StoreMut for MemoryStorage {
         let item = Item {
             schema: schema.clone(),
             rows: BTreeMap::new(),
+            indexes: HashMap::new(),
         };
         self.items.insert(table_name, item);
 
@@ -127,7 +171,7 @@ impl StoreMut for MemoryStorage {
             for row in rows {
                 self.id_counter += 1;
 
-                item.rows.insert(Key::I64(self.id_counter), row);
+                item.insert_row(Key::I64(self.id_counter), row).await?;
             }
         }
 
@@ -137,7 +181,7 @@ impl StoreMut for MemoryStorage {
     async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
         if let Some(item) = self.items.get_mut(table_name) {
             for (key, row) in rows {
-                item.rows.insert(key, row);
+                item.insert_row(key, row).await?;
             }
         }
 
@@ -147,7 +191,7 @@ impl StoreMut for MemoryStorage {
     async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
         if let Some(item) = self.items.get_mut(table_name) {
             for key in keys {
-                item.rows.remove(&key);
+                item.delete_row(&key).await?;
             }
         }
 
@@ -0,0 +1,80 @@
+use {
+    crate::MemoryStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Sequence as StructSequence, SequenceError},
+        error::Result,
+        store::{Sequence, SequenceMut},
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        collections::HashMap,
+        sync::{Mutex, MutexGuard, PoisonError},
+    },
+};
+
+/// Sequences are updated by `NEXTVAL` and `SETVAL` through a shared reference to the storage.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Sequences(Mutex<HashMap<String, StructSequence>>);
+
+impl Sequences {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, StructSequence>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for Sequences {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.lock().clone()))
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for MemoryStorage {
+    async fn fetch_sequence(&self, name: &str) -> Result<Option<StructSequence>> {
+        Ok(self.sequences.lock().get(name).cloned())
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let mut sequences = self.sequences.lock().values().cloned().collect::<Vec<_>>();
+        sequences.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(sequences)
+    }
+
+    async fn next_sequence_value(&self, name: &str) -> Result<i64> {
+        self.sequences
+            .lock()
+            .get_mut(name)
+            .ok_or_else(|| SequenceError::SequenceNotFound(name.to_owned()))?
+            .next_value()
+            .map_err(Into::into)
+    }
+
+    async fn set_sequence_value(&self, name: &str, value: i64) -> Result<()> {
+        self.sequences
+            .lock()
+            .get_mut(name)
+            .ok_or_else(|| SequenceError::SequenceNotFound(name.to_owned()))?
+            .set_value(value)
+            .map_err(Into::into)
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for MemoryStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        self.sequences
+            .lock()
+            .insert(sequence.name.to_owned(), sequence);
+
+        Ok(())
+    }
+
+    async fn delete_sequence(&mut self, name: &str) -> Result<()> {
+        self.sequences.lock().remove(name);
+
+        Ok(())
+    }
+}
@@ -23,12 +23,24 @@ impl Tester<MemoryStorage> for MemoryTester {
 
 generate_store_tests!(tokio::test, MemoryTester);
 
+generate_index_tests!(tokio::test, MemoryTester);
+
 generate_alter_table_tests!(tokio::test, MemoryTester);
 
+generate_alter_table_index_tests!(tokio::test, MemoryTester);
+
 generate_metadata_table_tests!(tokio::test, MemoryTester);
 
+generate_metadata_index_tests!(tokio::test, MemoryTester);
+
 generate_custom_function_tests!(tokio::test, MemoryTester);
 
+generate_sequence_tests!(tokio::test, MemoryTester);
+
+generate_enum_type_tests!(tokio::test, MemoryTester);
+
+generate_view_tests!(tokio::test, MemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -44,8 +56,10 @@ macro_rules! test {
 #[tokio::test]
 async fn memory_storage_index() {
     use gluesql_core::{
-        prelude::{Error, Glue, Result},
-        store::{Index, Store},
+        ast::IndexOperator,
+        data::{Key, Value},
+        prelude::{Glue, Payload, Result},
+        store::{DataRow, Index, Store},
     };
 
     let storage = MemoryStorage::default();
@@ -65,23 +79,66 @@ async fn memory_storage_index() {
         storage
             .scan_indexed_data("Idx", "hello", None, None)
             .await
-            .map(|_| ()),
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned()
-        ))
+            .unwrap()
+            .collect::<Result<Vec<_>>>(),
+        Ok(vec![]),
     );
 
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE Idx (id INTEGER);");
-    test!(
-        glue "CREATE INDEX idx_id ON Idx (id);",
-        Err(Error::StorageMsg("[MemoryStorage] index is not supported".to_owned()))
+    exec!(glue "INSERT INTO Idx VALUES (3), (1), (2), (1);");
+    test!(glue "CREATE INDEX idx_id ON Idx (id);", Ok(vec![Payload::CreateIndex]));
+
+    let row = |key, id| (Key::I64(key), DataRow::Vec(vec![Value::I64(id)]));
+
+    assert_eq!(
+        glue.storage
+            .scan_indexed_data("Idx", "idx_id", None, None)
+            .await
+            .unwrap()
+            .collect::<Result<Vec<_>>>(),
+        Ok(vec![row(2, 1), row(4, 1), row(3, 2), row(1, 3)]),
     );
-    test!(
-        glue "DROP INDEX Idx.idx_id;",
-        Err(Error::StorageMsg("[MemoryStorage] index is not supported".to_owned()))
+    assert_eq!(
+        glue.storage
+            .scan_indexed_data(
+                "Idx",
+                "idx_id",
+                Some(false),
+                Some((&IndexOperator::Gt, Value::I64(1)))
+            )
+            .await
+            .unwrap()
+            .collect::<Result<Vec<_>>>(),
+        Ok(vec![row(1, 3), row(3, 2)]),
+    );
+    assert_eq!(
+        glue.storage
+            .scan_indexed_data(
+                "Idx",
+                "idx_id",
+                None,
+                Some((&IndexOperator::Eq, Value::I64(1)))
+            )
+            .await
+            .unwrap()
+            .collect::<Result<Vec<_>>>(),
+        Ok(vec![row(2, 1), row(4, 1)]),
     );
+
+    exec!(glue "DELETE FROM Idx WHERE id = 1;");
+    assert_eq!(
+        glue.storage
+            .scan_indexed_data("Idx", "idx_id", None, None)
+            .await
+            .unwrap()
+            .collect::<Result<Vec<_>>>(),
+        Ok(vec![row(3, 2), row(1, 3)]),
+    );
+
+    test!(glue "DROP INDEX Idx.idx_id;", Ok(vec![Payload::DropIndex]));
+    assert_eq!(glue.storage.items["Idx"].indexes.len(), 0);
 }
 
 #[tokio::test]
@@ -1,7 +1,11 @@
 use {
     super::SharedMemoryStorage,
     async_trait::async_trait,
-    gluesql_core::{ast::ColumnDef, error::Result, store::AlterTable},
+    gluesql_core::{
+        ast::{ColumnDef, Expr},
+        error::Result,
+        store::AlterTable,
+    },
     std::sync::Arc,
 };
 
@@ -48,4 +52,32 @@ impl AlterTable for SharedMemoryStorage {
             .drop_column(table_name, column_name, if_exists)
             .await
     }
+
+    async fn set_column_nullable(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        nullable: bool,
+    ) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database
+            .set_column_nullable(table_name, column_name, nullable)
+            .await
+    }
+
+    async fn set_column_default(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        default: Option<&Expr>,
+    ) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database
+            .set_column_default(table_name, column_name, default)
+            .await
+    }
 }
@@ -0,0 +1,44 @@
+use {
+    super::SharedMemoryStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        data::EnumType as StructEnumType,
+        error::Result,
+        store::{EnumType, EnumTypeMut},
+    },
+    std::sync::Arc,
+};
+
+#[async_trait(?Send)]
+impl EnumType for SharedMemoryStorage {
+    async fn fetch_enum_type(&self, name: &str) -> Result<Option<StructEnumType>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_enum_type(name).await
+    }
+
+    async fn fetch_all_enum_types(&self) -> Result<Vec<StructEnumType>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_all_enum_types().await
+    }
+}
+
+#[async_trait(?Send)]
+impl EnumTypeMut for SharedMemoryStorage {
+    async fn insert_enum_type(&mut self, enum_type: StructEnumType) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_enum_type(enum_type).await
+    }
+
+    async fn delete_enum_type(&mut self, name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_enum_type(name).await
+    }
+}
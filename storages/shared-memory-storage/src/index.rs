@@ -4,23 +4,27 @@ use {
     gluesql_core::{
         ast::{IndexOperator, OrderByExpr},
         data::Value,
-        error::{Error, Result},
+        error::Result,
         store::{Index, IndexMut, RowIter},
     },
+    std::sync::Arc,
 };
 
 #[async_trait(?Send)]
 impl Index for SharedMemoryStorage {
     async fn scan_indexed_data(
         &self,
-        _table_name: &str,
-        _index_name: &str,
-        _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database
+            .scan_indexed_data(table_name, index_name, asc, cmp_value)
+            .await
     }
 }
 
@@ -28,18 +32,23 @@ impl Index for SharedMemoryStorage {
 impl IndexMut for SharedMemoryStorage {
     async fn create_index(
         &mut self,
-        _table_name: &str,
-        _index_name: &str,
-        _column: &OrderByExpr,
+        table_name: &str,
+        index_name: &str,
+        column: &OrderByExpr,
+        unique: bool,
     ) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database
+            .create_index(table_name, index_name, column, unique)
+            .await
     }
 
-    async fn drop_index(&mut self, _table_name: &str, _index_name: &str) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.drop_index(table_name, index_name).await
     }
 }
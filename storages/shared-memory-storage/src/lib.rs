@@ -1,7 +1,9 @@
 #![deny(clippy::str_to_string)]
 
 mod alter_table;
+mod enum_type;
 mod index;
+mod sequence;
 mod transaction;
 
 use {
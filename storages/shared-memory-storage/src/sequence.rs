@@ -0,0 +1,58 @@
+use {
+    super::SharedMemoryStorage,
+    async_trait::async_trait,
+    gluesql_core::{
+        data::Sequence as StructSequence,
+        error::Result,
+        store::{Sequence, SequenceMut},
+    },
+    std::sync::Arc,
+};
+
+#[async_trait(?Send)]
+impl Sequence for SharedMemoryStorage {
+    async fn fetch_sequence(&self, name: &str) -> Result<Option<StructSequence>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_sequence(name).await
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_all_sequences().await
+    }
+
+    async fn next_sequence_value(&self, name: &str) -> Result<i64> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.next_sequence_value(name).await
+    }
+
+    async fn set_sequence_value(&self, name: &str, value: i64) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.set_sequence_value(name, value).await
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for SharedMemoryStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_sequence(sequence).await
+    }
+
+    async fn delete_sequence(&mut self, name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_sequence(name).await
+    }
+}
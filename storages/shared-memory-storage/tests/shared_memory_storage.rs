@@ -23,8 +23,18 @@ impl Tester<SharedMemoryStorage> for SharedMemoryTester {
 
 generate_store_tests!(tokio::test, SharedMemoryTester);
 
+generate_index_tests!(tokio::test, SharedMemoryTester);
+
 generate_alter_table_tests!(tokio::test, SharedMemoryTester);
 
+generate_alter_table_index_tests!(tokio::test, SharedMemoryTester);
+
+generate_sequence_tests!(tokio::test, SharedMemoryTester);
+
+generate_enum_type_tests!(tokio::test, SharedMemoryTester);
+
+generate_view_tests!(tokio::test, SharedMemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -40,8 +50,8 @@ macro_rules! test {
 #[tokio::test]
 async fn shared_memory_storage_index() {
     use gluesql_core::{
-        error::{Error, Result},
-        prelude::Glue,
+        error::Result,
+        prelude::{Glue, Payload},
         store::{Index, Store},
     };
 
@@ -62,23 +72,29 @@ async fn shared_memory_storage_index() {
         storage
             .scan_indexed_data("Idx", "hello", None, None)
             .await
-            .map(|_| ()),
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned()
-        ))
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .as_ref()
+            .map(Vec::len),
+        Ok(0),
     );
 
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE Idx (id INTEGER);");
-    test!(
-        glue "CREATE INDEX idx_id ON Idx (id);",
-        Err(Error::StorageMsg("[Shared MemoryStorage] index is not supported".to_owned()))
-    );
-    test!(
-        glue "DROP INDEX Idx.idx_id;",
-        Err(Error::StorageMsg("[Shared MemoryStorage] index is not supported".to_owned()))
+    exec!(glue "INSERT INTO Idx VALUES (2), (1);");
+    test!(glue "CREATE INDEX idx_id ON Idx (id);", Ok(vec![Payload::CreateIndex]));
+    assert_eq!(
+        glue.storage
+            .scan_indexed_data("Idx", "idx_id", None, None)
+            .await
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .as_ref()
+            .map(Vec::len),
+        Ok(2),
     );
+    test!(glue "DROP INDEX Idx.idx_id;", Ok(vec![Payload::DropIndex]));
 }
 
 #[tokio::test]
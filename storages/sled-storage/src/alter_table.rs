@@ -9,14 +9,14 @@ use {
     async_io::block_on,
     async_trait::async_trait,
     gluesql_core::{
-        ast::ColumnDef,
-        data::{schema::Schema, Value},
+        ast::{ColumnDef, Expr},
+        data::{schema::Schema, TimeZone, Value},
         error::{AlterTableError, Error, Result},
         executor::evaluate_stateless,
         store::{AlterTable, DataRow},
     },
-    sled::transaction::ConflictableTransactionError,
-    std::{iter::once, str},
+    sled::transaction::{ConflictableTransactionError, TransactionError},
+    std::{iter::once, result::Result as StdResult, str},
     utils::Vector,
 };
 
@@ -49,8 +49,11 @@ impl AlterTable for SledStorage {
             let (old_snapshot, old_schema) = schema_snapshot.delete(txid);
             let Schema {
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
                 ..
             } = old_schema
                 .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
@@ -59,8 +62,11 @@ impl AlterTable for SledStorage {
             let new_schema = Schema {
                 table_name: new_table_name.to_owned(),
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
             };
 
             bincode::serialize(&old_snapshot)
@@ -157,8 +163,11 @@ impl AlterTable for SledStorage {
 
             let Schema {
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
                 ..
             } = snapshot
                 .get(txid, None)
@@ -205,8 +214,11 @@ impl AlterTable for SledStorage {
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs: Some(column_defs),
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
             };
             let (snapshot, _) = snapshot.update(txid, schema);
             let value = bincode::serialize(&snapshot)
@@ -257,8 +269,11 @@ impl AlterTable for SledStorage {
             let Schema {
                 table_name,
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
                 ..
             } = schema_snapshot
                 .get(txid, None)
@@ -292,7 +307,7 @@ impl AlterTable for SledStorage {
                         .map_err(ConflictableTransactionError::Abort)?;
 
                     evaluated
-                        .try_into_value(data_type, *nullable)
+                        .try_into_value(data_type, *nullable, TimeZone::UTC)
                         .map_err(ConflictableTransactionError::Abort)?
                 }
                 (None, true) => Value::Null,
@@ -354,8 +369,11 @@ impl AlterTable for SledStorage {
             let schema = Schema {
                 table_name,
                 column_defs: Some(column_defs),
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -409,8 +427,11 @@ impl AlterTable for SledStorage {
             let Schema {
                 table_name,
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
                 ..
             } = schema_snapshot
                 .get(txid, None)
@@ -492,8 +513,11 @@ impl AlterTable for SledStorage {
             let schema = Schema {
                 table_name,
                 column_defs: Some(column_defs),
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
             let schema_value = bincode::serialize(&schema_snapshot)
@@ -514,4 +538,108 @@ impl AlterTable for SledStorage {
 
         Ok(())
     }
+
+    async fn set_column_nullable(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        nullable: bool,
+    ) -> Result<()> {
+        let tx_result = self.alter_column_def(table_name, column_name, |column_def| ColumnDef {
+            nullable,
+            ..column_def
+        });
+
+        if self.check_retry(tx_result)? {
+            self.set_column_nullable(table_name, column_name, nullable)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn set_column_default(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+        default: Option<&Expr>,
+    ) -> Result<()> {
+        let tx_result = self.alter_column_def(table_name, column_name, |column_def| ColumnDef {
+            default: default.cloned(),
+            ..column_def
+        });
+
+        if self.check_retry(tx_result)? {
+            self.set_column_default(table_name, column_name, default)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SledStorage {
+    /// Replaces the definition of the column with the one returned by `alter`
+    fn alter_column_def(
+        &self,
+        table_name: &str,
+        column_name: &str,
+        alter: impl Fn(ColumnDef) -> ColumnDef,
+    ) -> StdResult<TxPayload, TransactionError<Error>> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        self.tree.transaction(move |tree| {
+            let (txid, autocommit) = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, autocommit } => (txid, autocommit),
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let (schema_key, snapshot) = fetch_schema(tree, table_name)?;
+            let snapshot = snapshot
+                .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let schema = snapshot
+                .get(txid, None)
+                .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let column_defs = schema
+                .column_defs
+                .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let i = column_defs
+                .iter()
+                .position(|column_def| column_def.name == column_name)
+                .ok_or_else(|| {
+                    AlterTableError::AlteringColumnNotFound(column_name.to_owned()).into()
+                })
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let column_def = alter(column_defs[i].clone());
+            let column_defs = Vector::from(column_defs).update(i, column_def).into();
+
+            let schema = Schema {
+                column_defs: Some(column_defs),
+                ..schema
+            };
+            let (snapshot, _) = snapshot.update(txid, schema);
+            let value = bincode::serialize(&snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+            tree.insert(schema_key.as_bytes(), value)?;
+
+            if !autocommit {
+                let temp_key = key::temp_schema(txid, table_name);
+
+                tree.insert(temp_key, schema_key.as_bytes())?;
+            }
+
+            Ok(TxPayload::Success)
+        })
+    }
 }
@@ -0,0 +1,62 @@
+use {
+    super::{err_into, SledStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::EnumType as StructEnumType,
+        error::Result,
+        store::{EnumType, EnumTypeMut},
+    },
+};
+
+impl SledStorage {
+    // enum types are stored out of the transaction snapshots like sequences,
+    // `CREATE TYPE`, `ALTER TYPE` and `DROP TYPE` are not undone by `ROLLBACK`
+    const ENUM_TYPE_PREFIX: &str = "enum_type/";
+}
+
+#[async_trait(?Send)]
+impl EnumType for SledStorage {
+    async fn fetch_enum_type(&self, name: &str) -> Result<Option<StructEnumType>> {
+        let key = format!("{}{}", SledStorage::ENUM_TYPE_PREFIX, name);
+
+        self.tree
+            .get(key.as_bytes())
+            .map_err(err_into)?
+            .map(|v| bincode::deserialize(&v))
+            .transpose()
+            .map_err(err_into)
+    }
+
+    async fn fetch_all_enum_types(&self) -> Result<Vec<StructEnumType>> {
+        self.tree
+            .scan_prefix(SledStorage::ENUM_TYPE_PREFIX)
+            .map(|item| {
+                let (_, value) = item.map_err(err_into)?;
+
+                bincode::deserialize(&value).map_err(err_into)
+            })
+            .collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl EnumTypeMut for SledStorage {
+    async fn insert_enum_type(&mut self, enum_type: StructEnumType) -> Result<()> {
+        let key = format!("{}{}", SledStorage::ENUM_TYPE_PREFIX, enum_type.name);
+        let value = bincode::serialize(&enum_type).map_err(err_into)?;
+
+        self.tree
+            .insert(key.as_bytes(), value)
+            .map(|_| ())
+            .map_err(err_into)
+    }
+
+    async fn delete_enum_type(&mut self, name: &str) -> Result<()> {
+        let key = format!("{}{}", SledStorage::ENUM_TYPE_PREFIX, name);
+
+        self.tree
+            .remove(key.as_bytes())
+            .map(|_| ())
+            .map_err(err_into)
+    }
+}
@@ -44,6 +44,7 @@ impl IndexMut for SledStorage {
         table_name: &str,
         index_name: &str,
         column: &OrderByExpr,
+        unique: bool,
     ) -> Result<()> {
         let rows = self
             .scan_data(table_name)
@@ -70,8 +71,11 @@ impl IndexMut for SledStorage {
             let (schema_snapshot, schema) = schema_snapshot.delete(txid);
             let Schema {
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -87,6 +91,7 @@ impl IndexMut for SledStorage {
                 expr: index_expr.clone(),
                 order: SchemaIndexOrd::Both,
                 created: Utc::now().naive_utc(),
+                unique,
             };
 
             let indexes = indexes
@@ -97,8 +102,11 @@ impl IndexMut for SledStorage {
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -130,7 +138,8 @@ impl IndexMut for SledStorage {
         });
 
         if self.check_retry(tx_result)? {
-            self.create_index(table_name, index_name, column).await?;
+            self.create_index(table_name, index_name, column, unique)
+                .await?;
         }
 
         Ok(())
@@ -160,8 +169,11 @@ impl IndexMut for SledStorage {
             let (schema_snapshot, schema) = schema_snapshot.delete(txid);
             let Schema {
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
                 ..
             } = schema
                 .ok_or_else(|| IndexError::ConflictTableNotFound(table_name.to_owned()).into())
@@ -182,8 +194,11 @@ impl IndexMut for SledStorage {
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs,
+                checks,
+                foreign_keys,
                 indexes,
                 engine,
+                view,
             };
 
             let index_sync = IndexSync::from_schema(tree, txid, &schema);
@@ -1,6 +1,7 @@
 #![deny(clippy::str_to_string)]
 
 mod alter_table;
+mod enum_type;
 mod error;
 mod gc;
 mod index;
@@ -8,6 +9,7 @@ mod index_mut;
 mod index_sync;
 mod key;
 mod lock;
+mod sequence;
 mod snapshot;
 mod store;
 mod store_mut;
@@ -0,0 +1,99 @@
+use {
+    super::{err_into, tx_err_into, SledStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Sequence as StructSequence, SequenceError},
+        error::{Error, Result},
+        store::{Sequence, SequenceMut},
+    },
+    sled::{transaction::ConflictableTransactionError, Db},
+};
+
+impl SledStorage {
+    // sequences are stored out of the transaction snapshots,
+    // a change of a sequence is visible at once and is not undone by `ROLLBACK`
+    const SEQUENCE_PREFIX: &str = "sequence/";
+}
+
+#[async_trait(?Send)]
+impl Sequence for SledStorage {
+    async fn fetch_sequence(&self, name: &str) -> Result<Option<StructSequence>> {
+        let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, name);
+
+        self.tree
+            .get(key.as_bytes())
+            .map_err(err_into)?
+            .map(|v| bincode::deserialize(&v))
+            .transpose()
+            .map_err(err_into)
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        self.tree
+            .scan_prefix(SledStorage::SEQUENCE_PREFIX)
+            .map(|item| {
+                let (_, value) = item.map_err(err_into)?;
+
+                bincode::deserialize(&value).map_err(err_into)
+            })
+            .collect()
+    }
+
+    async fn next_sequence_value(&self, name: &str) -> Result<i64> {
+        update_sequence(&self.tree, name, StructSequence::next_value)
+    }
+
+    async fn set_sequence_value(&self, name: &str, value: i64) -> Result<()> {
+        update_sequence(&self.tree, name, |sequence| sequence.set_value(value))
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for SledStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, sequence.name);
+        let value = bincode::serialize(&sequence).map_err(err_into)?;
+
+        self.tree
+            .insert(key.as_bytes(), value)
+            .map(|_| ())
+            .map_err(err_into)
+    }
+
+    async fn delete_sequence(&mut self, name: &str) -> Result<()> {
+        let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, name);
+
+        self.tree
+            .remove(key.as_bytes())
+            .map(|_| ())
+            .map_err(err_into)
+    }
+}
+
+/// Reads, updates and writes back the sequence in a single sled transaction, so concurrent
+/// `NEXTVAL` calls never return the same value.
+fn update_sequence<T>(
+    tree: &Db,
+    name: &str,
+    update: impl Fn(&mut StructSequence) -> std::result::Result<T, SequenceError>,
+) -> Result<T> {
+    let key = format!("{}{}", SledStorage::SEQUENCE_PREFIX, name);
+
+    tree.transaction(|tree| {
+        let mut sequence: StructSequence = tree
+            .get(key.as_bytes())?
+            .ok_or_else(|| -> Error { SequenceError::SequenceNotFound(name.to_owned()).into() })
+            .and_then(|v| bincode::deserialize(&v).map_err(err_into))
+            .map_err(ConflictableTransactionError::Abort)?;
+        let value =
+            update(&mut sequence).map_err(|e| ConflictableTransactionError::Abort(e.into()))?;
+        let sequence = bincode::serialize(&sequence)
+            .map_err(err_into)
+            .map_err(ConflictableTransactionError::Abort)?;
+
+        tree.insert(key.as_bytes(), sequence)?;
+
+        Ok(value)
+    })
+    .map_err(tx_err_into)
+}
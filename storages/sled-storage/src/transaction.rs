@@ -1,7 +1,7 @@
 use {
     super::{
         err_into, key,
-        lock::{self, Lock},
+        lock::{self, Lock, LockAcquired},
         tx_err_into, SledStorage, Snapshot, State,
     },
     async_trait::async_trait,
@@ -117,6 +117,30 @@ impl Transaction for SledStorage {
 
         gc_result
     }
+
+    /// Acquires the database lock which writes take, so other transactions cannot modify any
+    /// rows until the current transaction ends.
+    async fn lock_for_update(&mut self, table_name: &str) -> Result<()> {
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+
+        let tx_result = self.tree.transaction(move |tree| {
+            let payload = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { .. } => TxPayload::Success,
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    TxPayload::RollbackAndRetry(lock_txid)
+                }
+            };
+
+            Ok(payload)
+        });
+
+        if self.check_retry(tx_result)? {
+            self.lock_for_update(table_name).await?;
+        }
+
+        Ok(())
+    }
 }
 
 impl SledStorage {
@@ -250,6 +250,37 @@ async fn sled_transaction_data_mut() {
     );
 }
 
+#[tokio::test]
+async fn sled_transaction_select_for_update() {
+    let path = &format!("{}/transaction_select_for_update", PATH_PREFIX);
+    fs::remove_dir_all(path).unwrap_or(());
+
+    let storage1 = SledStorage::new(path).unwrap();
+    let storage2 = storage1.clone();
+    let mut glue1 = Glue::new(storage1);
+    let mut glue2 = Glue::new(storage2);
+
+    exec!(glue1 "CREATE TABLE Sample (id INTEGER);");
+    exec!(glue1 "INSERT INTO Sample VALUES (1);");
+
+    exec!(glue1 "BEGIN;");
+    test!(
+        glue1 "SELECT * FROM Sample FOR UPDATE;",
+        Ok(select!(id I64; 1))
+    );
+    test!(
+        glue2 "SELECT * FROM Sample;",
+        Ok(select!(id I64; 1))
+    );
+    test!(
+        glue2 "UPDATE Sample SET id = 2;",
+        Err(Error::StorageMsg("database is locked".to_owned()))
+    );
+
+    exec!(glue1 "COMMIT;");
+    test!(glue2 "UPDATE Sample SET id = 2;", Ok(Payload::Update(1)));
+}
+
 #[tokio::test]
 async fn sled_transaction_index_mut() {
     use ast::IndexOperator::Eq;
@@ -0,0 +1,26 @@
+use {gluesql_core::error::Error, thiserror::Error};
+
+pub trait ResultExt<T, E: ToString> {
+    fn map_storage_err(self) -> Result<T, Error>;
+}
+
+impl<T, E: ToString> ResultExt<T, E> for std::result::Result<T, E> {
+    fn map_storage_err(self) -> Result<T, Error> {
+        self.map_err(|e| e.to_string()).map_err(Error::StorageMsg)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SqliteStorageError {
+    #[error("table does not exist: {0}")]
+    TableDoesNotExist(String),
+
+    #[error("creating or dropping tables is not supported, use the SQLite database directly")]
+    SchemaChangeNotSupported,
+
+    #[error("schemaless rows are not supported")]
+    SchemalessRowNotSupported,
+
+    #[error("rows are addressed by rowid, but the key was not an integer")]
+    InvalidRowId,
+}
@@ -0,0 +1,146 @@
+#![deny(clippy::str_to_string)]
+
+pub mod error;
+mod store;
+mod store_mut;
+mod value;
+
+use {
+    error::ResultExt,
+    gluesql_core::{
+        ast::{ColumnDef, DataType},
+        data::Schema,
+        error::{Error, Result},
+        store::{
+            AlterTable, CustomFunction, CustomFunctionMut, EnumType, EnumTypeMut, Index, IndexMut,
+            Metadata, Sequence, SequenceMut, Transaction,
+        },
+    },
+    rusqlite::Connection,
+    std::path::Path,
+};
+
+pub use error::SqliteStorageError;
+
+/// Storage which exposes the tables of an existing SQLite database.
+///
+/// Schemas are discovered from `PRAGMA table_info`, and rows are addressed by their `rowid`.
+/// Tables are created and dropped in SQLite itself, not through this storage.
+pub struct SqliteStorage {
+    pub connection: Connection,
+    pub engine: Option<String>,
+}
+
+impl SqliteStorage {
+    pub fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            engine: None,
+        }
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Connection::open(path).map(Self::new).map_storage_err()
+    }
+
+    /// Sets the engine reported by the discovered schemas, so `CompositeStorage` can route
+    /// queries on the SQLite tables to this storage.
+    pub fn with_engine<T: Into<String>>(self, engine: T) -> Self {
+        Self {
+            engine: Some(engine.into()),
+            ..self
+        }
+    }
+
+    fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        let sql = format!("PRAGMA table_info({})", quote(table_name));
+        let column_defs = self
+            .connection
+            .prepare(&sql)
+            .map_storage_err()?
+            .query_map([], |row| {
+                let name: String = row.get("name")?;
+                let declared_type: String = row.get("type")?;
+                let not_null: bool = row.get("notnull")?;
+
+                Ok(ColumnDef {
+                    name,
+                    data_type: data_type(&declared_type),
+                    nullable: !not_null,
+                    default: None,
+                    unique: None,
+                })
+            })
+            .map_storage_err()?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_storage_err()?;
+
+        if column_defs.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Schema {
+            table_name: table_name.to_owned(),
+            column_defs: Some(column_defs),
+            checks: Vec::new(),
+            foreign_keys: Vec::new(),
+            indexes: vec![],
+            engine: self.engine.clone(),
+            view: None,
+        }))
+    }
+
+    fn fetch_column_defs(&self, table_name: &str) -> Result<Vec<ColumnDef>> {
+        self.fetch_schema(table_name)?
+            .and_then(|schema| schema.column_defs)
+            .ok_or_else(|| {
+                Error::StorageMsg(
+                    SqliteStorageError::TableDoesNotExist(table_name.to_owned()).to_string(),
+                )
+            })
+    }
+}
+
+/// Maps a declared SQLite column type into a data type, following SQLite's type affinity rules.
+fn data_type(declared_type: &str) -> DataType {
+    let declared_type = declared_type.to_uppercase();
+    let contains_any = |names: &[&str]| names.iter().any(|name| declared_type.contains(name));
+
+    if contains_any(&["INT"]) {
+        DataType::Int
+    } else if contains_any(&["CHAR", "CLOB", "TEXT"]) || declared_type.is_empty() {
+        DataType::Text
+    } else if contains_any(&["BLOB"]) {
+        DataType::Bytea
+    } else if contains_any(&["REAL", "FLOA", "DOUB"]) {
+        DataType::Float
+    } else if contains_any(&["BOOL"]) {
+        DataType::Boolean
+    } else {
+        DataType::Decimal
+    }
+}
+
+fn quote(identifier: &str) -> String {
+    format!(r#""{}""#, identifier.replace('"', r#""""#))
+}
+
+fn column_list(column_defs: &[ColumnDef]) -> String {
+    column_defs
+        .iter()
+        .map(|column_def| quote(&column_def.name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl AlterTable for SqliteStorage {}
+impl Index for SqliteStorage {}
+impl IndexMut for SqliteStorage {}
+impl Metadata for SqliteStorage {}
+impl Transaction for SqliteStorage {}
+impl CustomFunction for SqliteStorage {}
+impl CustomFunctionMut for SqliteStorage {}
+impl Sequence for SqliteStorage {}
+impl SequenceMut for SqliteStorage {}
+impl EnumType for SqliteStorage {}
+impl EnumTypeMut for SqliteStorage {}
@@ -0,0 +1,75 @@
+use {
+    crate::{column_list, error::ResultExt, quote, value::read_row, SqliteStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::Result,
+        store::{DataRow, RowIter, Store},
+    },
+};
+
+#[async_trait(?Send)]
+impl Store for SqliteStorage {
+    async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
+        self.fetch_schema(table_name)
+    }
+
+    async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
+        let sql = "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name";
+        let table_names = self
+            .connection
+            .prepare(sql)
+            .map_storage_err()?
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_storage_err()?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_storage_err()?;
+
+        table_names
+            .iter()
+            .filter_map(|table_name| self.fetch_schema(table_name).transpose())
+            .collect()
+    }
+
+    async fn fetch_data(&self, table_name: &str, key: &Key) -> Result<Option<DataRow>> {
+        let rowid = match key {
+            Key::I64(rowid) => *rowid,
+            _ => return Ok(None),
+        };
+
+        let column_defs = self.fetch_column_defs(table_name)?;
+        let sql = format!(
+            "SELECT {} FROM {} WHERE rowid = ?",
+            column_list(&column_defs),
+            quote(table_name)
+        );
+        let mut statement = self.connection.prepare(&sql).map_storage_err()?;
+        let mut rows = statement.query([rowid]).map_storage_err()?;
+
+        rows.next()
+            .map_storage_err()?
+            .map(|row| read_row(row, &column_defs, 0))
+            .transpose()
+    }
+
+    async fn scan_data(&self, table_name: &str) -> Result<RowIter> {
+        let column_defs = self.fetch_column_defs(table_name)?;
+        let sql = format!(
+            "SELECT rowid, {} FROM {} ORDER BY rowid",
+            column_list(&column_defs),
+            quote(table_name)
+        );
+        let mut statement = self.connection.prepare(&sql).map_storage_err()?;
+        let mut rows = statement.query([]).map_storage_err()?;
+
+        let mut data_rows = Vec::new();
+        while let Some(row) = rows.next().map_storage_err()? {
+            let rowid: i64 = row.get(0).map_storage_err()?;
+            let data_row = read_row(row, &column_defs, 1)?;
+
+            data_rows.push(Ok((Key::I64(rowid), data_row)));
+        }
+
+        Ok(Box::new(data_rows.into_iter()))
+    }
+}
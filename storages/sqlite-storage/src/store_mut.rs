@@ -0,0 +1,111 @@
+use {
+    crate::{
+        column_list, error::ResultExt, quote, value::into_sql_values, SqliteStorage,
+        SqliteStorageError,
+    },
+    async_trait::async_trait,
+    gluesql_core::{
+        data::{Key, Schema},
+        error::{Error, Result},
+        store::{DataRow, StoreMut},
+    },
+    rusqlite::{params_from_iter, types::Value as SqlValue},
+};
+
+#[async_trait(?Send)]
+impl StoreMut for SqliteStorage {
+    async fn insert_schema(&mut self, _schema: &Schema) -> Result<()> {
+        Err(Error::StorageMsg(
+            SqliteStorageError::SchemaChangeNotSupported.to_string(),
+        ))
+    }
+
+    async fn delete_schema(&mut self, _table_name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            SqliteStorageError::SchemaChangeNotSupported.to_string(),
+        ))
+    }
+
+    async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        let column_defs = self.fetch_column_defs(table_name)?;
+        let sql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            quote(table_name),
+            column_list(&column_defs),
+            placeholders(column_defs.len())
+        );
+        let mut statement = self.connection.prepare(&sql).map_storage_err()?;
+
+        for row in rows {
+            let values = into_sql_values(row)?;
+
+            statement
+                .execute(params_from_iter(values))
+                .map_storage_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
+        let column_defs = self.fetch_column_defs(table_name)?;
+        let assignments = column_defs
+            .iter()
+            .map(|column_def| format!("{} = ?", quote(&column_def.name)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let update_sql = format!(
+            "UPDATE {} SET {assignments} WHERE rowid = ?",
+            quote(table_name)
+        );
+        let insert_sql = format!(
+            "INSERT INTO {} (rowid, {}) VALUES ({})",
+            quote(table_name),
+            column_list(&column_defs),
+            placeholders(column_defs.len() + 1)
+        );
+        let mut update = self.connection.prepare(&update_sql).map_storage_err()?;
+        let mut insert = self.connection.prepare(&insert_sql).map_storage_err()?;
+
+        for (key, row) in rows {
+            let rowid = get_rowid(&key)?;
+            let mut values = into_sql_values(row)?;
+            values.push(SqlValue::Integer(rowid));
+
+            let updated = update
+                .execute(params_from_iter(&values))
+                .map_storage_err()?;
+
+            if updated == 0 {
+                values.rotate_right(1);
+                insert.execute(params_from_iter(values)).map_storage_err()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
+        let sql = format!("DELETE FROM {} WHERE rowid = ?", quote(table_name));
+        let mut statement = self.connection.prepare(&sql).map_storage_err()?;
+
+        for key in keys {
+            statement.execute([get_rowid(&key)?]).map_storage_err()?;
+        }
+
+        Ok(())
+    }
+}
+
+fn get_rowid(key: &Key) -> Result<i64> {
+    match key {
+        Key::I64(rowid) => Ok(*rowid),
+        _ => Err(Error::StorageMsg(
+            SqliteStorageError::InvalidRowId.to_string(),
+        )),
+    }
+}
+
+fn placeholders(len: usize) -> String {
+    vec!["?"; len].join(", ")
+}
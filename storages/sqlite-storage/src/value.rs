@@ -0,0 +1,73 @@
+use {
+    crate::{error::ResultExt, SqliteStorageError},
+    gluesql_core::{
+        ast::ColumnDef,
+        data::Value,
+        error::{Error, Result},
+        store::DataRow,
+    },
+    rusqlite::{
+        types::{Value as SqlValue, ValueRef},
+        Row,
+    },
+};
+
+/// Reads the columns of `row` starting at `offset`, casting each value into its column type.
+pub fn read_row(row: &Row, column_defs: &[ColumnDef], offset: usize) -> Result<DataRow> {
+    column_defs
+        .iter()
+        .enumerate()
+        .map(|(index, column_def)| {
+            let value = match row.get_ref(index + offset).map_storage_err()? {
+                ValueRef::Null => Value::Null,
+                ValueRef::Integer(v) => Value::I64(v),
+                ValueRef::Real(v) => Value::F64(v),
+                ValueRef::Text(v) => Value::Str(String::from_utf8(v.to_vec()).map_storage_err()?),
+                ValueRef::Blob(v) => Value::Bytea(v.to_vec()),
+            };
+
+            match value.get_type() {
+                Some(data_type) if data_type != column_def.data_type => {
+                    value.cast(&column_def.data_type)
+                }
+                Some(_) | None => Ok(value),
+            }
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(DataRow::Vec)
+}
+
+pub fn into_sql_values(row: DataRow) -> Result<Vec<SqlValue>> {
+    let values = match row {
+        DataRow::Vec(values) => values,
+        DataRow::Map(_) => {
+            return Err(Error::StorageMsg(
+                SqliteStorageError::SchemalessRowNotSupported.to_string(),
+            ))
+        }
+    };
+
+    values.into_iter().map(into_sql_value).collect()
+}
+
+fn into_sql_value(value: Value) -> Result<SqlValue> {
+    Ok(match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(v) => SqlValue::Integer(i64::from(v)),
+        Value::I8(v) => SqlValue::Integer(i64::from(v)),
+        Value::I16(v) => SqlValue::Integer(i64::from(v)),
+        Value::I32(v) => SqlValue::Integer(i64::from(v)),
+        Value::I64(v) => SqlValue::Integer(v),
+        Value::U8(v) => SqlValue::Integer(i64::from(v)),
+        Value::U16(v) => SqlValue::Integer(i64::from(v)),
+        Value::U32(v) => SqlValue::Integer(i64::from(v)),
+        Value::I128(_) | Value::U64(_) | Value::U128(_) => {
+            SqlValue::Integer(i64::try_from(&value)?)
+        }
+        Value::F32(v) => SqlValue::Real(f64::from(v)),
+        Value::F64(v) => SqlValue::Real(v),
+        Value::Str(v) => SqlValue::Text(v),
+        Value::Bytea(v) => SqlValue::Blob(v),
+        _ => SqlValue::Text(String::from(&value)),
+    })
+}
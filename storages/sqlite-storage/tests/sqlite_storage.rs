@@ -0,0 +1,126 @@
+use {
+    composite_storage::CompositeStorage,
+    gluesql_core::prelude::{Error, Glue, Payload, Value::*},
+    gluesql_sqlite_storage::{SqliteStorage, SqliteStorageError},
+    memory_storage::MemoryStorage,
+    rusqlite::Connection,
+    test_suite::{concat_with, concat_with_null, row, select, select_with_null, stringify_label},
+};
+
+fn connection() -> Connection {
+    let connection = Connection::open_in_memory().unwrap();
+    connection
+        .execute_batch(
+            "
+            CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT NOT NULL, price REAL);
+            INSERT INTO Item (id, name, price) VALUES (1, 'glue', 1.5), (2, 'sql', NULL);
+            ",
+        )
+        .unwrap();
+
+    connection
+}
+
+#[tokio::test]
+async fn sqlite_storage() {
+    let mut glue = Glue::new(SqliteStorage::new(connection()));
+
+    let cases = vec![
+        (
+            glue.execute("SELECT id, name, price FROM Item").await,
+            Ok(select_with_null!(
+                id     | name                  | price;
+                I64(1)   Str("glue".to_owned())  F64(1.5);
+                I64(2)   Str("sql".to_owned())   Null
+            )),
+        ),
+        (
+            glue.execute("INSERT INTO Item (id, name) VALUES (3, 'rust')")
+                .await,
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            glue.execute("UPDATE Item SET price = 3.0 WHERE id = 2")
+                .await,
+            Ok(Payload::Update(1)),
+        ),
+        (
+            glue.execute("DELETE FROM Item WHERE id = 1").await,
+            Ok(Payload::Delete(1)),
+        ),
+        (
+            glue.execute("SELECT id, name, price FROM Item").await,
+            Ok(select_with_null!(
+                id     | name                  | price;
+                I64(2)   Str("sql".to_owned())   F64(3.0);
+                I64(3)   Str("rust".to_owned())  Null
+            )),
+        ),
+        (
+            glue.execute("CREATE TABLE Foo (id INTEGER)").await,
+            Err(Error::StorageMsg(
+                SqliteStorageError::SchemaChangeNotSupported.to_string(),
+            )),
+        ),
+        (
+            glue.execute("CREATE VIEW Cheap AS SELECT id FROM Item WHERE price < 2.0")
+                .await,
+            Err(Error::StorageMsg(
+                SqliteStorageError::SchemaChangeNotSupported.to_string(),
+            )),
+        ),
+    ];
+
+    for (actual, expected) in cases {
+        assert_eq!(actual.map(|mut payloads| payloads.remove(0)), expected);
+    }
+
+    let names = glue
+        .storage
+        .connection
+        .prepare("SELECT name FROM Item ORDER BY id")
+        .unwrap()
+        .query_map([], |row| row.get::<_, String>(0))
+        .unwrap()
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(names, vec!["sql".to_owned(), "rust".to_owned()]);
+}
+
+#[tokio::test]
+async fn join_with_memory_storage() {
+    let mut storage = CompositeStorage::new();
+    storage.push("MEMORY", MemoryStorage::default());
+    storage.push(
+        "SQLITE",
+        SqliteStorage::new(connection()).with_engine("SQLITE"),
+    );
+    storage.set_default("MEMORY");
+
+    let mut glue = Glue::new(storage);
+
+    glue.execute("CREATE TABLE Orders (item_id INTEGER, quantity INTEGER);")
+        .await
+        .unwrap();
+    glue.execute("INSERT INTO Orders VALUES (1, 10), (2, 20), (2, 30);")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        glue.execute(
+            "SELECT Item.name, Orders.quantity FROM Orders JOIN Item ON Orders.item_id = Item.id;"
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap(),
+        select!(
+            name              | quantity
+            Str               | I64;
+            "glue".to_owned()   10;
+            "sql".to_owned()    20;
+            "sql".to_owned()    30
+        )
+    );
+}
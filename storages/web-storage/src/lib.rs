@@ -209,3 +209,7 @@ impl gluesql_core::store::Transaction for WebStorage {}
 impl Metadata for WebStorage {}
 impl gluesql_core::store::CustomFunction for WebStorage {}
 impl gluesql_core::store::CustomFunctionMut for WebStorage {}
+impl gluesql_core::store::Sequence for WebStorage {}
+impl gluesql_core::store::SequenceMut for WebStorage {}
+impl gluesql_core::store::EnumType for WebStorage {}
+impl gluesql_core::store::EnumTypeMut for WebStorage {}
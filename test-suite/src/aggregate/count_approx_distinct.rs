@@ -0,0 +1,59 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(count_approx_distinct, async move {
+    run!(
+        "
+    CREATE TABLE Item (
+        id INTEGER,
+        category TEXT,
+        age INTEGER NULL,
+    );
+    "
+    );
+    run!(
+        "
+    INSERT INTO Item (id, category, age) VALUES
+        (1, 'fruit',   11),
+        (2, 'fruit',   90),
+        (3, 'meat',  NULL),
+        (4, 'fruit',   11),
+        (5, 'drink', NULL);
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT COUNT_APPROX_DISTINCT(category) FROM Item",
+            Ok(select!("COUNT_APPROX_DISTINCT(category)" I64; 3)),
+        ),
+        (
+            "SELECT COUNT_APPROX_DISTINCT(age, 8) FROM Item",
+            Ok(select!("COUNT_APPROX_DISTINCT(age, 8)" I64; 2)),
+        ),
+        (
+            "SELECT category, COUNT_APPROX_DISTINCT(age) FROM Item GROUP BY category",
+            Ok(select!(
+                category               | "COUNT_APPROX_DISTINCT(age)"
+                Str                    | I64;
+                "fruit".to_owned()       2;
+                "meat".to_owned()        0;
+                "drink".to_owned()       0
+            )),
+        ),
+        (
+            "SELECT COUNT_APPROX_DISTINCT(id, 20) FROM Item",
+            Err(TranslateError::InvalidApproxDistinctPrecision("20".to_owned()).into()),
+        ),
+        (
+            "SELECT COUNT_APPROX_DISTINCT(id, category) FROM Item",
+            Err(TranslateError::InvalidApproxDistinctPrecision("category".to_owned()).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
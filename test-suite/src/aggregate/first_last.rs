@@ -0,0 +1,81 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(first_last, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            city TEXT,
+            quantity INTEGER NULL,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, city, quantity) VALUES
+            (1,   'Seoul',   10),
+            (2,   'Dhaka',    0),
+            (3,   'Seoul', NULL),
+            (4, 'Seattle',   24),
+            (5,   'Dhaka',   30);
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT FIRST(id), LAST(id) FROM Item",
+            select!(
+                "FIRST(id)" | "LAST(id)"
+                I64         | I64;
+                1             5
+            ),
+        ),
+        (
+            "SELECT city, FIRST(quantity) AS f, LAST(quantity) AS l FROM Item GROUP BY city",
+            select_with_null!(
+                city                       | f       | l;
+                Str("Seoul".to_owned())      I64(10)   Null;
+                Str("Dhaka".to_owned())      I64(0)    I64(30);
+                Str("Seattle".to_owned())    I64(24)   I64(24)
+            ),
+        ),
+        (
+            "
+            SELECT FIRST(city) AS f, LAST(city) AS l
+            FROM (SELECT * FROM Item ORDER BY quantity DESC) AS Sorted
+            WHERE quantity IS NOT NULL
+            ",
+            select!(
+                f                  | l
+                Str                | Str;
+                "Dhaka".to_owned()   "Dhaka".to_owned()
+            ),
+        ),
+        (
+            "SELECT id, LAST(city) OVER (ORDER BY id DESC) AS l FROM Item WHERE id < 3",
+            select!(
+                id  | l
+                I64 | Str;
+                1     "Seoul".to_owned();
+                2     "Dhaka".to_owned()
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT FIRST(id, city) FROM Item",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "FIRST".to_owned(),
+            expected: 1,
+            found: 2,
+        }
+        .into())
+    );
+});
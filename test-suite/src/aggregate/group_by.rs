@@ -88,6 +88,23 @@ test_case!(group_by, async move {
                 21                2            "Seoul".to_owned()
             ),
         ),
+        (
+            "SELECT city AS location, COUNT(*) AS cnt FROM Item GROUP BY location HAVING cnt > 1",
+            select!(
+                location | cnt
+                Str      | I64;
+                "Seoul".to_owned() 2
+            ),
+        ),
+        (
+            "SELECT id % 2 AS parity, COUNT(*) AS cnt FROM Item GROUP BY parity ORDER BY parity",
+            select!(
+                parity | cnt
+                I64    | I64;
+                0        2;
+                1        4
+            ),
+        ),
     ];
 
     for (sql, expected) in test_cases {
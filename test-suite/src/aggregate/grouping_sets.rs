@@ -0,0 +1,141 @@
+use {
+    crate::*,
+    gluesql_core::{error::EvaluateError, prelude::Value::*},
+};
+
+test_case!(grouping_sets, async move {
+    run!(
+        "
+        CREATE TABLE Sales (
+            region TEXT,
+            city TEXT,
+            product TEXT,
+            amount INTEGER,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Sales (region, city, product, amount) VALUES
+            ('East', 'Boston', 'Apple', 10),
+            ('East', 'Boston',  'Pear', 20),
+            ('East',    'NYC', 'Apple', 30),
+            ('West',     'LA', 'Apple', 40),
+            ('West',     'LA',  'Pear', 50);
+    "
+    );
+
+    let s = |v: &str| Str(v.to_owned());
+
+    let test_cases = [
+        (
+            "
+            SELECT region, city, product, SUM(amount) AS total
+            FROM Sales
+            GROUP BY ROLLUP(region, city, product)
+            ORDER BY region, city, product
+            ",
+            select_with_null!(
+                region    | city        | product    | total;
+                s("East")   s("Boston")   s("Apple")   I64(10);
+                s("East")   s("Boston")   s("Pear")    I64(20);
+                s("East")   s("Boston")   Null         I64(30);
+                s("East")   s("NYC")      s("Apple")   I64(30);
+                s("East")   s("NYC")      Null         I64(30);
+                s("East")   Null          Null         I64(60);
+                s("West")   s("LA")       s("Apple")   I64(40);
+                s("West")   s("LA")       s("Pear")    I64(50);
+                s("West")   s("LA")       Null         I64(90);
+                s("West")   Null          Null         I64(90);
+                Null        Null          Null         I64(150)
+            ),
+        ),
+        (
+            "
+            SELECT region, city, GROUPING(region, city) AS g, COUNT(*) AS cnt
+            FROM Sales
+            GROUP BY CUBE(region, city)
+            ORDER BY g, region, city
+            ",
+            select_with_null!(
+                region    | city        | g      | cnt;
+                s("East")   s("Boston")   I64(0)   I64(2);
+                s("East")   s("NYC")      I64(0)   I64(1);
+                s("West")   s("LA")       I64(0)   I64(2);
+                s("East")   Null          I64(1)   I64(3);
+                s("West")   Null          I64(1)   I64(2);
+                Null        s("Boston")   I64(2)   I64(2);
+                Null        s("LA")       I64(2)   I64(2);
+                Null        s("NYC")      I64(2)   I64(1);
+                Null        Null          I64(3)   I64(5)
+            ),
+        ),
+        (
+            "
+            SELECT region, product, SUM(amount) AS total
+            FROM Sales
+            GROUP BY GROUPING SETS ((region), (product))
+            ORDER BY region, product
+            ",
+            select_with_null!(
+                region    | product    | total;
+                s("East")   Null         I64(60);
+                s("West")   Null         I64(90);
+                Null        s("Apple")   I64(80);
+                Null        s("Pear")    I64(70)
+            ),
+        ),
+        (
+            "
+            SELECT region, SUM(amount) AS total
+            FROM Sales
+            GROUP BY ROLLUP(region)
+            HAVING SUM(amount) > 60
+            ORDER BY region
+            ",
+            select_with_null!(
+                region    | total;
+                s("West")   I64(90);
+                Null        I64(150)
+            ),
+        ),
+        (
+            "
+            SELECT
+                CASE WHEN GROUPING(region) = 1 THEN 'All' ELSE region END AS label,
+                COALESCE(UPPER(region), 'TOTAL') AS upper,
+                SUM(amount) AS total
+            FROM Sales
+            GROUP BY ROLLUP(region)
+            ORDER BY total
+            ",
+            select_with_null!(
+                label    | upper       | total;
+                s("East")  s("EAST")     I64(60);
+                s("West")  s("WEST")     I64(90);
+                s("All")   s("TOTAL")    I64(150)
+            ),
+        ),
+        (
+            "SELECT region, GROUPING(region) AS g FROM Sales GROUP BY region ORDER BY region",
+            select_with_null!(
+                region    | g;
+                s("East")   I64(0);
+                s("West")   I64(0)
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT GROUPING(region) FROM Sales",
+        Err(EvaluateError::GroupingArgNotInGroupBy.into())
+    );
+    test!(
+        "SELECT region, GROUPING(city) FROM Sales GROUP BY ROLLUP(region)",
+        Err(EvaluateError::GroupingArgNotInGroupBy.into())
+    );
+});
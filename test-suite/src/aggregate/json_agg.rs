@@ -0,0 +1,97 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AggregateError, TranslateError},
+        prelude::Value::{self, *},
+    },
+};
+
+test_case!(json_agg, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            city TEXT,
+            name TEXT NULL,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, city, name) VALUES
+            (1,   'Seoul',   'kim'),
+            (2,   'Dhaka', 'rahim'),
+            (3,   'Seoul',    NULL),
+            (4, 'Seattle',   'ann'),
+            (5,   'Dhaka', 'karim');
+    "
+    );
+
+    let l = |s: &str| Value::parse_json_list(s).unwrap();
+    let m = |s: &str| Value::parse_json_map(s).unwrap();
+    let s = |v: &str| Str(v.to_owned());
+
+    test!(
+        "SELECT JSON_AGG(name) AS a, JSON_AGG(name, TRUE) AS b FROM Item",
+        Ok(select_with_null!(
+            a                                        | b;
+            l(r#"["kim", "rahim", "ann", "karim"]"#)   l(r#"["kim", "rahim", null, "ann", "karim"]"#)
+        ))
+    );
+    test!(
+        "SELECT city, JSON_AGG(id) AS ids FROM Item GROUP BY city",
+        Ok(select_with_null!(
+            city         | ids;
+            s("Seoul")     l("[1, 3]");
+            s("Dhaka")     l("[2, 5]");
+            s("Seattle")   l("[4]")
+        ))
+    );
+    test!(
+        "SELECT JSON_AGG(id) AS ids FROM (SELECT * FROM Item ORDER BY id DESC) AS Sorted",
+        Ok(select_with_null!(ids; l("[5, 4, 3, 2, 1]")))
+    );
+    test!(
+        "SELECT city, JSON_AGG(name) AS names FROM Item WHERE id = 3 GROUP BY city",
+        Ok(select_with_null!(
+            city       | names;
+            s("Seoul")   Null
+        ))
+    );
+    test!(
+        "SELECT CAST(JSON_AGG(id) AS TEXT) AS ids FROM Item WHERE city = 'Seoul'",
+        Ok(select_with_null!(ids; s("[1,3]")))
+    );
+    test!(
+        "SELECT JSON_OBJECT_AGG(name, id) AS obj FROM Item WHERE name IS NOT NULL",
+        Ok(select_with_null!(
+            obj;
+            m(r#"{"kim": 1, "rahim": 2, "ann": 4, "karim": 5}"#)
+        ))
+    );
+    test!(
+        "SELECT JSON_OBJECT_AGG(city, name) AS a, JSON_OBJECT_AGG(city, name, TRUE) AS b FROM Item",
+        Ok(select_with_null!(
+            a                                                          | b;
+            m(r#"{"Seoul": "kim", "Dhaka": "karim", "Seattle": "ann"}"#)   m(r#"{"Seoul": null, "Dhaka": "karim", "Seattle": "ann"}"#)
+        ))
+    );
+    test!(
+        "SELECT JSON_OBJECT_AGG(name, id) AS obj FROM Item",
+        Err(AggregateError::NullKeyInJsonObjectAgg.into())
+    );
+    test!(
+        "SELECT JSON_AGG(name, 1) FROM Item",
+        Err(TranslateError::InvalidIncludeNullsArg("1".to_owned()).into())
+    );
+    test!(
+        "SELECT JSON_OBJECT_AGG(name) FROM Item",
+        Err(TranslateError::FunctionArgsLengthNotWithinRange {
+            name: "JSON_OBJECT_AGG".to_owned(),
+            expected_minimum: 2,
+            expected_maximum: 3,
+            found: 1,
+        }
+        .into())
+    );
+});
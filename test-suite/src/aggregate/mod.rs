@@ -1,9 +1,15 @@
 pub mod avg;
 pub mod count;
+pub mod count_approx_distinct;
 pub mod error;
+pub mod first_last;
 pub mod group_by;
+pub mod grouping_sets;
+pub mod json_agg;
 pub mod max;
 pub mod min;
+pub mod percentile;
 pub mod stdev;
+pub mod string_agg;
 pub mod sum;
 pub mod variance;
@@ -0,0 +1,102 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AggregateError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(percentile, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            city TEXT,
+            price INTEGER NULL,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, city, price) VALUES
+            (1,   'Seoul',   10),
+            (2,   'Seoul',   40),
+            (3,   'Seoul',   20),
+            (4,   'Seoul', NULL),
+            (5,   'Dhaka',   30),
+            (6,   'Dhaka',   50),
+            (7,   'Dhaka',   40),
+            (8, 'Seattle',   15),
+            (9,   'Dhaka',   60);
+    "
+    );
+
+    let test_cases = [
+        (
+            "
+            SELECT city, PERCENTILE_CONT(0.5, price) AS cont, PERCENTILE_DISC(0.5, price) AS disc
+            FROM Item
+            GROUP BY city
+            ",
+            select!(
+                city                 | cont | disc
+                Str                  | F64  | I64;
+                "Seoul".to_owned()     20.0   20;
+                "Dhaka".to_owned()     45.0   40;
+                "Seattle".to_owned()   15.0   15
+            ),
+        ),
+        (
+            "SELECT PERCENTILE_CONT(0.25, price) AS cont, PERCENTILE_DISC(0.25, price) AS disc FROM Item",
+            select!(
+                cont  | disc
+                F64   | I64;
+                18.75   15
+            ),
+        ),
+        (
+            "
+            SELECT
+                PERCENTILE_CONT(0, price) AS a,
+                PERCENTILE_CONT(1, price) AS b,
+                PERCENTILE_DISC(0, price) AS c,
+                PERCENTILE_DISC(1, price) AS d
+            FROM Item
+            ",
+            select!(
+                a    | b    | c   | d
+                F64  | F64  | I64 | I64;
+                10.0   60.0   10    60
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT PERCENTILE_CONT(0.5, price) AS a, PERCENTILE_DISC(0.5, price) AS b FROM Item WHERE id = 4",
+        Ok(select_with_null!(
+            a    | b;
+            Null   Null
+        ))
+    );
+    test!(
+        "SELECT PERCENTILE_CONT(1.5, price) FROM Item",
+        Err(AggregateError::InvalidPercentileFraction("1.5".to_owned()).into())
+    );
+    test!(
+        "SELECT PERCENTILE_DISC('half', price) FROM Item",
+        Err(AggregateError::InvalidPercentileFraction("half".to_owned()).into())
+    );
+    test!(
+        "SELECT PERCENTILE_DISC(price) FROM Item",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "PERCENTILE_DISC".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into())
+    );
+});
@@ -0,0 +1,128 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(string_agg, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            city TEXT,
+            name TEXT NULL,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, city, name) VALUES
+            (1,   'Seoul',   'kim'),
+            (2,   'Dhaka', 'rahim'),
+            (3,   'Seoul',    NULL),
+            (4, 'Seattle',   'ann'),
+            (5,   'Dhaka', 'karim'),
+            (6,   'Seoul',   'lee');
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT STRING_AGG(name, ', ') AS names FROM Item",
+            select!(
+                names
+                Str;
+                "kim, rahim, ann, karim, lee".to_owned()
+            ),
+        ),
+        (
+            "SELECT city, STRING_AGG(name, '/') AS names FROM Item GROUP BY city",
+            select!(
+                city                    | names
+                Str                     | Str;
+                "Seoul".to_owned()        "kim/lee".to_owned();
+                "Dhaka".to_owned()        "rahim/karim".to_owned();
+                "Seattle".to_owned()      "ann".to_owned()
+            ),
+        ),
+        (
+            "SELECT city, STRING_AGG(name, ',') AS names FROM Item WHERE id = 3 GROUP BY city",
+            select_with_null!(
+                city                    | names;
+                Str("Seoul".to_owned())   Null
+            ),
+        ),
+        (
+            "
+            SELECT city, LISTAGG(name, ',') WITHIN GROUP (ORDER BY name DESC) AS names
+            FROM Item
+            GROUP BY city
+            ",
+            select!(
+                city                    | names
+                Str                     | Str;
+                "Seoul".to_owned()        "lee,kim".to_owned();
+                "Dhaka".to_owned()        "rahim,karim".to_owned();
+                "Seattle".to_owned()      "ann".to_owned()
+            ),
+        ),
+        (
+            "
+            SELECT city, LISTAGG(name, ',') WITHIN GROUP (ORDER BY id DESC) AS names
+            FROM Item
+            GROUP BY city
+            ORDER BY city
+            ",
+            select!(
+                city                    | names
+                Str                     | Str;
+                "Dhaka".to_owned()        "karim,rahim".to_owned();
+                "Seattle".to_owned()      "ann".to_owned();
+                "Seoul".to_owned()        "lee,kim".to_owned()
+            ),
+        ),
+        (
+            "SELECT LISTAGG(city) WITHIN GROUP (ORDER BY city, id DESC) AS cities FROM Item",
+            select!(
+                cities
+                Str;
+                "DhakaDhakaSeattleSeoulSeoulSeoul".to_owned()
+            ),
+        ),
+        (
+            "SELECT GROUP_CONCAT(id) AS ids, GROUP_CONCAT(name, ' ') AS names FROM Item",
+            select!(
+                ids                        | names
+                Str                        | Str;
+                "1,2,3,4,5,6".to_owned()     "kim rahim ann karim lee".to_owned()
+            ),
+        ),
+        (
+            "SELECT id, STRING_AGG(name, ',') OVER (ORDER BY id) AS names FROM Item WHERE id < 4",
+            select!(
+                id  | names
+                I64 | Str;
+                1     "kim".to_owned();
+                2     "kim,rahim".to_owned();
+                3     "kim,rahim".to_owned()
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT STRING_AGG(name) FROM Item",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "STRING_AGG".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into())
+    );
+    test!(
+        "SELECT STRING_AGG(name, city) FROM Item",
+        Err(TranslateError::InvalidStringAggSeparator("city".to_owned()).into())
+    );
+});
@@ -3,7 +3,10 @@ use {
     gluesql_core::{
         ast::*,
         data::Value::*,
-        error::{AlterError, AlterTableError, EvaluateError, TranslateError},
+        error::{
+            AlterError, AlterTableError, EnumTypeError, EvaluateError, InsertError, TranslateError,
+            ValueError,
+        },
         prelude::Payload,
     },
 };
@@ -107,7 +110,7 @@ test_case!(alter_table_add_drop, async move {
         ),
         (
             "ALTER TABLE Foo ADD COLUMN something SOMEWHAT",
-            Err(TranslateError::UnsupportedDataType("SOMEWHAT".to_owned()).into()),
+            Err(EnumTypeError::TypeNotFound("SOMEWHAT".to_owned()).into()),
         ),
         (
             "ALTER TABLE Foo ADD COLUMN something FLOAT UNIQUE",
@@ -169,3 +172,153 @@ test_case!(alter_table_add_drop, async move {
         test!(sql, expected);
     }
 });
+
+test_case!(alter_table_alter_column, async move {
+    let test_cases = [
+        (
+            "CREATE TABLE Qux (id INTEGER PRIMARY KEY, num INTEGER, tag TEXT NOT NULL DEFAULT 'none');",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO Qux VALUES (1, 10, 'a'), (2, NULL, 'b');",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "INSERT INTO Qux VALUES (3, 30, NULL);",
+            Err(ValueError::NullValueOnNotNullField.into()),
+        ),
+        (
+            "INSERT INTO Qux (id, num) VALUES (3, 30);",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "ALTER TABLE Qux ALTER COLUMN num SET NOT NULL",
+            Err(AlterError::NullValueFound("num".to_owned()).into()),
+        ),
+        (
+            "ALTER TABLE Qux ALTER COLUMN hello SET NOT NULL",
+            Err(AlterTableError::AlteringColumnNotFound("hello".to_owned()).into()),
+        ),
+        (
+            "ALTER TABLE Qux ALTER COLUMN num SET DATA TYPE TEXT",
+            Err(TranslateError::UnsupportedAlterTableOperation(
+                "ALTER COLUMN num SET DATA TYPE TEXT".to_owned(),
+            )
+            .into()),
+        ),
+        ("UPDATE Qux SET num = 20 WHERE id = 2", Ok(Payload::Update(1))),
+        (
+            "ALTER TABLE Qux ALTER COLUMN num SET NOT NULL",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "INSERT INTO Qux VALUES (4, NULL, 'd');",
+            Err(ValueError::NullValueOnNotNullField.into()),
+        ),
+        (
+            "UPDATE Qux SET num = NULL WHERE id = 1",
+            Err(ValueError::NullValueOnNotNullField.into()),
+        ),
+        (
+            "SELECT COLUMN_NAME, NULLABLE FROM GLUE_TABLE_COLUMNS WHERE TABLE_NAME = 'Qux'",
+            Ok(select!(
+                COLUMN_NAME       | NULLABLE;
+                Str               | Bool;
+                "id".to_owned()     false;
+                "num".to_owned()    false;
+                "tag".to_owned()    false
+            )),
+        ),
+        (
+            "ALTER TABLE Qux ALTER COLUMN tag DROP NOT NULL",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "ALTER TABLE Qux ALTER COLUMN id DROP NOT NULL",
+            Err(AlterError::NullablePrimaryKey("id".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Qux VALUES (4, 40, NULL);",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT id, num, tag FROM Qux",
+            Ok(select_with_null!(
+                id     | num     | tag;
+                I64(1)   I64(10)   Str("a".to_owned());
+                I64(2)   I64(20)   Str("b".to_owned());
+                I64(3)   I64(30)   Str("none".to_owned());
+                I64(4)   I64(40)   Null
+            )),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
+
+test_case!(alter_table_alter_default, async move {
+    let test_cases = [
+        (
+            "CREATE TABLE Quux (id INTEGER, num INTEGER NOT NULL, created TIMESTAMP NULL);",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO Quux (id) VALUES (1);",
+            Err(InsertError::LackOfRequiredColumn("num".to_owned()).into()),
+        ),
+        (
+            "ALTER TABLE Quux ALTER COLUMN num SET DEFAULT 0",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "ALTER TABLE Quux ALTER COLUMN created SET DEFAULT NOW()",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "ALTER TABLE Quux ALTER COLUMN id SET DEFAULT num + 1",
+            Err(AlterError::UnavailableDefaultReference {
+                column: "id".to_owned(),
+                reference: "num".to_owned(),
+            }
+            .into()),
+        ),
+        ("INSERT INTO Quux (id) VALUES (1);", Ok(Payload::Insert(1))),
+        (
+            "SELECT id, num, created IS NOT NULL AS has_created FROM Quux",
+            Ok(select!(
+                id  | num | has_created;
+                I64 | I64 | Bool;
+                1     0     true
+            )),
+        ),
+        (
+            "ALTER TABLE Quux ALTER COLUMN num DROP DEFAULT",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "ALTER TABLE Quux ALTER COLUMN created DROP DEFAULT",
+            Ok(Payload::AlterTable),
+        ),
+        (
+            "INSERT INTO Quux (id) VALUES (2);",
+            Err(InsertError::LackOfRequiredColumn("num".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Quux (id, num) VALUES (2, 5);",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT id, num, created FROM Quux WHERE id = 2",
+            Ok(select_with_null!(
+                id     | num    | created;
+                I64(2)   I64(5)   Null
+            )),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
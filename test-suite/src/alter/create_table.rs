@@ -2,7 +2,7 @@ use {
     crate::*,
     gluesql_core::{
         data::value::Value::{Null, Str, I64},
-        error::{AlterError, EvaluateError, TranslateError},
+        error::{AlterError, EvaluateError},
         prelude::Payload,
     },
 };
@@ -52,16 +52,12 @@ test_case!(create_table, async move {
             Ok(Payload::Insert(1)),
         ),
         (
-            "CREATE TABLE Gluery (id SOMEWHAT);",
-            Err(TranslateError::UnsupportedDataType("SOMEWHAT".to_owned()).into()),
-        ),
-        (
-            "CREATE TABLE Gluery (id GLOBE);",
-            Err(TranslateError::UnsupportedDataType("GLOBE".to_owned()).into()),
-        ),
-        (
-            "CREATE TABLE Gluery (id INTEGER CHECK (true));",
-            Err(TranslateError::UnsupportedColumnOption("CHECK (true)".to_owned()).into()),
+            "CREATE TABLE Gluery (id INTEGER CHECK (num > 0));",
+            Err(AlterError::UnavailableCheckReference {
+                check: "Gluery_id_check".to_owned(),
+                reference: "num".to_owned(),
+            }
+            .into()),
         ),
         (
             "
@@ -157,7 +157,22 @@ CREATE TABLE Test (
         Err(AlterError::TableNotFound("Noname".to_owned()).into())
     );
 
-    run!("ALTER TABLE Test DROP COLUMN id");
+    test!(
+        "ALTER TABLE Test DROP COLUMN id",
+        Err(AlterError::DependentObjectsExist {
+            column_name: "id".to_owned(),
+            dependents: vec![
+                "idx_id".to_owned(),
+                "idx_typed_string".to_owned(),
+                "idx_binary_op".to_owned(),
+                "idx_unary_op".to_owned(),
+                "idx_cast".to_owned(),
+            ],
+        }
+        .into())
+    );
+
+    run!("ALTER TABLE Test DROP COLUMN id CASCADE");
 
     test_idx!(
         Ok(select!(
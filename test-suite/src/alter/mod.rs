@@ -2,8 +2,12 @@ mod alter_table;
 mod create_table;
 mod drop_indexed;
 mod drop_table;
+mod schema_diff;
 
-pub use alter_table::{alter_table_add_drop, alter_table_rename};
+pub use alter_table::{
+    alter_table_add_drop, alter_table_alter_column, alter_table_alter_default, alter_table_rename,
+};
 pub use create_table::create_table;
 pub use drop_indexed::{drop_indexed_column, drop_indexed_table};
 pub use drop_table::drop_table;
+pub use schema_diff::apply_diff;
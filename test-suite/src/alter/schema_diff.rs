@@ -0,0 +1,78 @@
+use {
+    crate::*,
+    gluesql_core::{
+        data::{diff_schemas, Schema, SchemaChange},
+        error::AlterError,
+        prelude::Value::*,
+    },
+};
+
+test_case!(apply_diff, async move {
+    run!("CREATE TABLE Foo (id INTEGER, name TEXT, rate FLOAT NULL);");
+    run!("INSERT INTO Foo VALUES (1, 'a', 0.5), (2, 'b', 1.5);");
+    run!("CREATE INDEX idx_rate ON Foo (rate);");
+
+    let target = Schema::from_ddl(
+        r#"
+        CREATE TABLE Foo (id INTEGER, title TEXT, num INTEGER NULL);
+        CREATE INDEX "idx_id" ON "Foo" ("id");
+        "#,
+    )
+    .unwrap();
+    let diff = diff_schemas(&schema!("Foo"), &target);
+    assert_eq!(
+        diff,
+        vec![
+            SchemaChange::DropIndex {
+                table_name: "Foo".to_owned(),
+                name: "idx_rate".to_owned(),
+            },
+            SchemaChange::RenameColumn {
+                table_name: "Foo".to_owned(),
+                old_column_name: "name".to_owned(),
+                new_column_name: "title".to_owned(),
+            },
+            SchemaChange::DropColumn {
+                table_name: "Foo".to_owned(),
+                column_name: "rate".to_owned(),
+            },
+            SchemaChange::AddColumn {
+                table_name: "Foo".to_owned(),
+                column_def: target.column_defs.as_ref().unwrap()[2].clone(),
+            },
+            SchemaChange::AddIndex {
+                table_name: "Foo".to_owned(),
+                name: "idx_id".to_owned(),
+                expr: target.indexes[0].expr.clone(),
+                unique: false,
+            },
+        ]
+    );
+
+    get_glue!().apply_diff(&diff).await.unwrap();
+
+    test!(
+        "SELECT * FROM Foo",
+        Ok(select_with_null!(
+            id     | title               | num;
+            I64(1)   Str("a".to_owned())   Null;
+            I64(2)   Str("b".to_owned())   Null
+        ))
+    );
+    assert_eq!(diff_schemas(&schema!("Foo"), &target), Vec::new());
+
+    let target = Schema::from_ddl(
+        "CREATE TABLE Foo (id INTEGER PRIMARY KEY, title TEXT, num INTEGER NULL, flag BOOLEAN NULL);",
+    )
+    .unwrap();
+    let diff = diff_schemas(&schema!("Foo"), &target);
+    let unsupported = SchemaChange::ModifyColumnType {
+        table_name: "Foo".to_owned(),
+        column_def: target.column_defs.as_ref().unwrap()[0].clone(),
+    };
+    assert_eq!(
+        get_glue!().apply_diff(&diff).await,
+        Err(AlterError::UnsupportedSchemaChange(format!("{unsupported:?}")).into())
+    );
+    test!("SELECT id FROM Foo", Ok(select!(id I64; 1; 2)));
+});
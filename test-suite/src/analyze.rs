@@ -0,0 +1,50 @@
+use {
+    crate::*,
+    gluesql_core::{
+        executor::ExecuteError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(analyze, async move {
+    run!("CREATE TABLE Item (id INTEGER, name TEXT NULL, rate FLOAT NULL);");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 'apple', 1.5),
+            (2, NULL, 3.5),
+            (3, 'banana', NULL),
+            (3, 'apple', 2.5);
+    "
+    );
+
+    test!("ANALYZE TABLE Item;", Ok(Payload::Analyze));
+    test!(
+        "SELECT * FROM __gluesql_statistics;",
+        Ok(select!(
+            table_name        | column_name       | row_count | null_count | distinct_count | min_value         | max_value
+            Str               | Str               | I64       | I64        | I64            | Str               | Str;
+            "Item".to_owned()   "id".to_owned()     4           0            3                "1".to_owned()      "3".to_owned();
+            "Item".to_owned()   "name".to_owned()   4           1            2                "apple".to_owned()  "banana".to_owned();
+            "Item".to_owned()   "rate".to_owned()   4           1            3                "1.5".to_owned()    "3.5".to_owned()
+        ))
+    );
+
+    run!("DELETE FROM Item WHERE id = 3;");
+    test!("ANALYZE TABLE Item;", Ok(Payload::Analyze));
+    test!(
+        "SELECT column_name, row_count, null_count FROM __gluesql_statistics;",
+        Ok(select!(
+            column_name       | row_count | null_count
+            Str               | I64       | I64;
+            "id".to_owned()     2           0;
+            "name".to_owned()   2           1;
+            "rate".to_owned()   2           0
+        ))
+    );
+
+    test!(
+        "ANALYZE TABLE Nothing;",
+        Err(ExecuteError::TableNotFound("Nothing".to_owned()).into())
+    );
+});
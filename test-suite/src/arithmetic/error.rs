@@ -1,8 +1,8 @@
 use {
     crate::*,
-    bigdecimal::BigDecimal,
     gluesql_core::{
-        data::{Literal, NumericBinaryOperator},
+        ast::DataType,
+        data::Literal,
         error::{EvaluateError, LiteralError, UpdateError, ValueError},
         prelude::Value,
     },
@@ -33,46 +33,46 @@ test_case!(error, async move {
     let test_cases = [
         (
             "SELECT * FROM Arith WHERE name + id < 1",
-            ValueError::NonNumericMathOperation {
-                lhs: Value::Str("A".to_owned()),
-                operator: NumericBinaryOperator::Add,
-                rhs: Value::I64(1),
+            EvaluateError::TypeMismatch {
+                left_type: DataType::Text,
+                right_type: DataType::Int,
+                operation: "+".to_owned(),
             }
             .into(),
         ),
         (
             "SELECT * FROM Arith WHERE name - id < 1",
-            ValueError::NonNumericMathOperation {
-                lhs: Value::Str("A".to_owned()),
-                operator: NumericBinaryOperator::Subtract,
-                rhs: Value::I64(1),
+            EvaluateError::TypeMismatch {
+                left_type: DataType::Text,
+                right_type: DataType::Int,
+                operation: "-".to_owned(),
             }
             .into(),
         ),
         (
             "SELECT * FROM Arith WHERE name * id < 1",
-            ValueError::NonNumericMathOperation {
-                lhs: Value::Str("A".to_owned()),
-                operator: NumericBinaryOperator::Multiply,
-                rhs: Value::I64(1),
+            EvaluateError::TypeMismatch {
+                left_type: DataType::Text,
+                right_type: DataType::Int,
+                operation: "*".to_owned(),
             }
             .into(),
         ),
         (
             "SELECT * FROM Arith WHERE name / id < 1",
-            ValueError::NonNumericMathOperation {
-                lhs: Value::Str("A".to_owned()),
-                operator: NumericBinaryOperator::Divide,
-                rhs: Value::I64(1),
+            EvaluateError::TypeMismatch {
+                left_type: DataType::Text,
+                right_type: DataType::Int,
+                operation: "/".to_owned(),
             }
             .into(),
         ),
         (
             "SELECT * FROM Arith WHERE name % id < 1",
-            ValueError::NonNumericMathOperation {
-                lhs: Value::Str("A".to_owned()),
-                operator: NumericBinaryOperator::Modulo,
-                rhs: Value::I64(1),
+            EvaluateError::TypeMismatch {
+                left_type: DataType::Text,
+                right_type: DataType::Int,
+                operation: "%".to_owned(),
             }
             .into(),
         ),
@@ -82,10 +82,20 @@ test_case!(error, async move {
         ),
         (
             "SELECT * FROM Arith WHERE TRUE + 1 = 1",
-            LiteralError::UnsupportedBinaryArithmetic(
-                format!("{:?}", Literal::Boolean(true)),
-                format!("{:?}", Literal::Number(Cow::Owned(BigDecimal::from(1)))),
-            )
+            EvaluateError::TypeMismatch {
+                left_type: DataType::Boolean,
+                right_type: DataType::Int,
+                operation: "+".to_owned(),
+            }
+            .into(),
+        ),
+        (
+            "SELECT * FROM Arith WHERE id = NULL + 'abc'",
+            EvaluateError::LiteralArithmeticError {
+                left: format!("{:?}", Literal::Null),
+                right: format!("{:?}", Literal::Text(Cow::Owned("abc".to_owned()))),
+                operation: "+".to_owned(),
+            }
             .into(),
         ),
         (
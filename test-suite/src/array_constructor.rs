@@ -0,0 +1,123 @@
+use {
+    crate::*,
+    gluesql_core::{error::EvaluateError, prelude::Value::*},
+};
+
+test_case!(array_constructor, async move {
+    run!(
+        "
+        CREATE TABLE ArrayItem (
+            id INTEGER,
+            items LIST NULL
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO ArrayItem VALUES
+            (1, ARRAY[1, 2, 3]),
+            (2, ARRAY[]),
+            (3, NULL);
+    "
+    );
+
+    test!(
+        "SELECT items FROM ArrayItem WHERE id = 1",
+        Ok(select!(
+            items
+            List;
+            vec![I64(1), I64(2), I64(3)]
+        ))
+    );
+    test!(
+        "SELECT ARRAY[id, id * 10, NULL] AS arr FROM ArrayItem WHERE id = 2",
+        Ok(select!(
+            arr
+            List;
+            vec![I64(2), I64(20), Null]
+        ))
+    );
+    test!(
+        "SELECT
+            items[1] AS first,
+            items[3] AS last,
+            items[0] AS zero,
+            items[4] AS missing
+        FROM ArrayItem WHERE id = 1",
+        Ok(select_with_null!(
+            first  | last   | zero | missing;
+            I64(1)   I64(3)   Null   Null
+        ))
+    );
+    test!(
+        "SELECT id, ARRAY_LENGTH(items) AS len FROM ArrayItem",
+        Ok(select_with_null!(
+            id     | len;
+            I64(1)   I64(3);
+            I64(2)   I64(0);
+            I64(3)   Null
+        ))
+    );
+    test!(
+        "SELECT
+            ARRAY_APPEND(items, 4) AS appended,
+            ARRAY_PREPEND(0, items) AS prepended
+        FROM ArrayItem WHERE id = 1",
+        Ok(select!(
+            appended                                 | prepended
+            List                                     | List;
+            vec![I64(1), I64(2), I64(3), I64(4)]       vec![I64(0), I64(1), I64(2), I64(3)]
+        ))
+    );
+    test!(
+        "SELECT id FROM ArrayItem WHERE items = ARRAY[1, 2, 3]",
+        Ok(select!(id I64; 1))
+    );
+    test!(
+        "SELECT id FROM ArrayItem WHERE items = ARRAY[1, 2]",
+        Ok(select!(id))
+    );
+    test!(
+        "SELECT id FROM ArrayItem WHERE id < 3 AND items <> ARRAY[1, 2, 3]",
+        Ok(select!(id I64; 2))
+    );
+    test!(
+        "SELECT ARRAY_LENGTH(id) AS len FROM ArrayItem",
+        Err(EvaluateError::ListTypeRequired.into())
+    );
+
+    test!(
+        "SELECT * FROM UNNEST(ARRAY['a', 'b'])",
+        Ok(select!(
+            UNNEST
+            Str;
+            "a".to_owned();
+            "b".to_owned()
+        ))
+    );
+    test!(
+        "SELECT ArrayItem.id, u.x FROM ArrayItem, UNNEST(items) AS u(x)",
+        Ok(select!(
+            id  | x
+            I64 | I64;
+            1     1;
+            1     2;
+            1     3
+        ))
+    );
+    test!(
+        "SELECT id, x FROM ArrayItem LEFT JOIN UNNEST(items) AS u(x) ON TRUE",
+        Ok(select_with_null!(
+            id     | x;
+            I64(1)   I64(1);
+            I64(1)   I64(2);
+            I64(1)   I64(3);
+            I64(2)   Null;
+            I64(3)   Null
+        ))
+    );
+    test!(
+        "SELECT * FROM UNNEST(1)",
+        Err(EvaluateError::ListTypeRequired.into())
+    );
+});
@@ -0,0 +1,123 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{FetchError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(cte, async move {
+    run!(
+        "
+        CREATE TABLE Employee (
+            id INTEGER,
+            name TEXT,
+            dept_id INTEGER,
+            salary INTEGER
+        );
+    "
+    );
+    run!(
+        "
+        CREATE TABLE Dept (
+            id INTEGER,
+            name TEXT
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Employee VALUES
+            (1, 'Alice', 1, 100),
+            (2, 'Bob', 1, 80),
+            (3, 'Carol', 2, 120),
+            (4, 'Dave', 2, 60),
+            (5, 'Eve', 3, 90);
+    "
+    );
+    run!("INSERT INTO Dept VALUES (1, 'Eng'), (2, 'Sales'), (3, 'Ops');");
+
+    let test_cases = [
+        (
+            "
+            WITH Rich AS (SELECT id, name FROM Employee WHERE salary >= 100)
+            SELECT name FROM Rich
+            ",
+            select!(
+                name
+                Str;
+                "Alice".to_owned();
+                "Carol".to_owned()
+            ),
+        ),
+        (
+            "
+            WITH
+                Rich AS (SELECT name, dept_id FROM Employee WHERE salary >= 90),
+                RichEng AS (SELECT name FROM Rich WHERE dept_id = 1)
+            SELECT name FROM RichEng
+            ",
+            select!(name Str; "Alice".to_owned()),
+        ),
+        (
+            "
+            WITH DeptTotal AS (
+                SELECT dept_id, SUM(salary) AS total FROM Employee GROUP BY dept_id
+            )
+            SELECT Dept.name, DeptTotal.total
+            FROM Dept
+            JOIN DeptTotal ON Dept.id = DeptTotal.dept_id
+            ",
+            select!(
+                name               | total
+                Str                | I64;
+                "Eng".to_owned()     180;
+                "Sales".to_owned()   180;
+                "Ops".to_owned()     90
+            ),
+        ),
+        (
+            "
+            WITH Named (n) AS (SELECT name FROM Dept WHERE id = 3)
+            SELECT d.n FROM Named AS d
+            ",
+            select!(n Str; "Ops".to_owned()),
+        ),
+        (
+            "
+            WITH Eng AS (SELECT id FROM Employee WHERE dept_id = 1)
+            SELECT name FROM Employee WHERE id IN (SELECT id FROM Eng)
+            ",
+            select!(
+                name
+                Str;
+                "Alice".to_owned();
+                "Bob".to_owned()
+            ),
+        ),
+        (
+            "
+            WITH Dept AS (SELECT 1 AS id, 'Virtual' AS name)
+            SELECT name FROM Dept
+            ",
+            select!(name Str; "Virtual".to_owned()),
+        ),
+        (
+            "WITH RECURSIVE Numbers AS (SELECT 1 AS n) SELECT n FROM Numbers",
+            select!(n I64; 1),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT * FROM Rich",
+        Err(FetchError::TableNotFound("Rich".to_owned()).into())
+    );
+    test!(
+        "WITH A AS (SELECT 1 AS n), A AS (SELECT 2 AS n) SELECT n FROM A",
+        Err(TranslateError::DuplicateCteName("A".to_owned()).into())
+    );
+});
@@ -45,6 +45,38 @@ test_case!(bytea, async move {
             r#"INSERT INTO Bytea VALUES (X'123')"#,
             Err(LiteralError::FailedToDecodeHexString("123".to_owned()).into()),
         ),
+        (
+            "INSERT INTO Bytea VALUES (X'00FF00'), (X'0000')",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "SELECT * FROM Bytea ORDER BY bytes",
+            Ok(select!(
+                bytes
+                Bytea;
+                bytea("0000");
+                bytea("00ff00");
+                bytea("123456");
+                bytea("936DA0");
+                bytea("ab0123")
+            )),
+        ),
+        (
+            "SELECT bytes || X'00FF' AS bytes FROM Bytea WHERE bytes = X'0000'",
+            Ok(select!(bytes Bytea; bytea("000000ff"))),
+        ),
+        (
+            "SELECT X'00' || X'FF' AS bytes",
+            Ok(select!(bytes Bytea; bytea("00ff"))),
+        ),
+        (
+            "SELECT CAST('ab' AS BYTEA) AS bytes",
+            Ok(select!(bytes Bytea; b"ab".to_vec())),
+        ),
+        (
+            "SELECT CAST(name AS BYTEA) AS bytes FROM (SELECT 'zz' AS name) AS Sub",
+            Ok(select!(bytes Bytea; b"zz".to_vec())),
+        ),
     ];
 
     for (sql, expected) in test_cases {
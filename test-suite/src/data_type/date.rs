@@ -1,6 +1,10 @@
 use {
     crate::*,
-    gluesql_core::{error::ValueError, prelude::Value::*},
+    gluesql_core::{
+        ast::DataType,
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
 };
 
 test_case!(date, async move {
@@ -111,6 +115,30 @@ INSERT INTO DateLog VALUES
         ))
     );
 
+    test!(
+        "SELECT
+            DATE '2024-01-31' + INTERVAL '1' MONTH AS leap,
+            INTERVAL '1' MONTH + DATE '2023-01-31' AS month_end,
+            DATE '2023-11-15' + INTERVAL '1' MONTH AS december,
+            DATE '1970-01-01' - INTERVAL '1' DAY AS before_epoch,
+            DATE '1970-01-01' - DATE '1969-12-01' AS date_sub",
+        Ok(select!(
+            leap                   | month_end              | december               | before_epoch             | date_sub
+            Timestamp              | Timestamp              | Timestamp              | Timestamp                | Interval;
+            timestamp(2024, 2, 29)   timestamp(2023, 2, 28)   timestamp(2023, 12, 15)  timestamp(1969, 12, 31)    days(31)
+        ))
+    );
+
+    test!(
+        "SELECT date1 + date2 FROM DateLog",
+        Err(EvaluateError::TypeMismatch {
+            left_type: DataType::Date,
+            right_type: DataType::Date,
+            operation: "+".to_owned(),
+        }
+        .into())
+    );
+
     test!(
         "INSERT INTO DateLog VALUES (1, '12345-678', '2021-05-01')",
         Err(ValueError::FailedToParseDate("12345-678".to_owned()).into())
@@ -0,0 +1,88 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::ValueError,
+        prelude::{Payload, Value::*},
+    },
+    std::sync::Arc,
+};
+
+test_case!(enum_type, async move {
+    let moods = || vec!["happy".to_owned(), "sad".to_owned(), "ok".to_owned()];
+    let mood = |value: &str| Enum {
+        value: value.to_owned(),
+        variants: Arc::new(moods()),
+    };
+
+    run!("CREATE TABLE Person (id INTEGER, mood ENUM('happy', 'sad', 'ok') NULL);");
+    run!("INSERT INTO Person VALUES (1, 'happy'), (2, 'sad'), (3, NULL), (4, 'ok');");
+
+    test!(
+        "SHOW COLUMNS FROM Person",
+        Ok(Payload::ShowColumns(vec![
+            ("id".to_owned(), DataType::Int),
+            ("mood".to_owned(), DataType::Enum(moods())),
+        ]))
+    );
+    test!(
+        "INSERT INTO Person VALUES (5, 'angry')",
+        Err(ValueError::InvalidEnumVariant {
+            value: "angry".to_owned(),
+            variants: moods(),
+        }
+        .into())
+    );
+    test!(
+        "INSERT INTO Person VALUES (5, UPPER('ok'))",
+        Err(ValueError::InvalidEnumVariant {
+            value: "OK".to_owned(),
+            variants: moods(),
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Person SET mood = 'meh' WHERE id = 4",
+        Err(ValueError::InvalidEnumVariant {
+            value: "meh".to_owned(),
+            variants: moods(),
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Person SET mood = LOWER('SAD') WHERE id = 4",
+        Ok(Payload::Update(1))
+    );
+
+    test!(
+        "SELECT id, mood FROM Person WHERE mood IN ('happy', 'sad')",
+        Ok(select_with_null!(
+            id     | mood;
+            I64(1)   mood("happy");
+            I64(2)   mood("sad");
+            I64(4)   mood("sad")
+        ))
+    );
+    test!(
+        "SELECT id FROM Person WHERE mood > 'ok' ORDER BY mood, id",
+        Ok(select!(id I64; 2; 4))
+    );
+    test!(
+        "SELECT CAST('ok' AS ENUM('happy', 'ok')) AS mood",
+        Ok(select_with_null!(
+            mood;
+            Enum {
+                value: "ok".to_owned(),
+                variants: Arc::new(vec!["happy".to_owned(), "ok".to_owned()]),
+            }
+        ))
+    );
+    test!(
+        "SELECT CAST('angry' AS ENUM('happy', 'ok')) AS mood",
+        Err(ValueError::InvalidEnumVariant {
+            value: "angry".to_owned(),
+            variants: vec!["happy".to_owned(), "ok".to_owned()],
+        }
+        .into())
+    );
+});
@@ -46,6 +46,22 @@ test_case!(float32, async move {
 
             )),
         ),
+        (
+            "CREATE TABLE real_line (x REAL, y FLOAT4)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO real_line VALUES (1.5, 2.5)",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT x, y, x + CAST(0.25 AS FLOAT) AS widened FROM real_line",
+            Ok(select!(
+                x       | y       | widened
+                F32     | F32     | F64;
+                1.5_f32   2.5_f32   1.75
+            )),
+        ),
     ];
 
     for (sql, expected) in test_cases {
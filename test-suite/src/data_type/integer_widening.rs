@@ -0,0 +1,48 @@
+use {crate::*, gluesql_core::prelude::Value::*};
+
+test_case!(integer_widening, async move {
+    run!(
+        "CREATE TABLE Item (
+            u1 UINT1,
+            u2 UINT2,
+            u4 UINT4,
+            u8 UINT64,
+            i1 INT8,
+            i8 INTEGER
+        );"
+    );
+    run!("INSERT INTO Item VALUES (255, 65535, 4294967295, 18446744073709551615, -1, -1);");
+
+    test!(
+        "SELECT u1, u2, u4 FROM Item",
+        Ok(select!(
+            u1  | u2    | u4
+            U8  | U16   | U32;
+            255   65535   4294967295
+        ))
+    );
+    test!(
+        "SELECT i1 - u1 AS a, u1 + i1 AS b FROM Item",
+        Ok(select!(
+            a      | b
+            I16    | I16;
+            (-256)   254
+        ))
+    );
+    test!(
+        "SELECT u4 * i1 AS a, u2 + i1 AS b, u1 + i8 AS c FROM Item",
+        Ok(select!(
+            a               | b     | c
+            I64             | I32   | I64;
+            (-4294967295)     65534   254
+        ))
+    );
+    test!(
+        "SELECT i8 - u8 AS a, u8 + i8 AS b FROM Item",
+        Ok(select!(
+            a                         | b
+            I128                      | I128;
+            (-18446744073709551616)     18446744073709551614
+        ))
+    );
+});
@@ -54,7 +54,7 @@ INSERT INTO ListType VALUES
     );
 
     test!(
-        "SELECT id, items[1] AS second FROM ListType",
+        "SELECT id, items[2] AS second FROM ListType",
         Ok(select_with_null!(
             id     | second;
             I64(1)   I64(2);
@@ -65,9 +65,9 @@ INSERT INTO ListType VALUES
 
     test! {
         name: "select index expr without alias",
-        sql: "SELECT id, items[1] FROM ListType",
+        sql: "SELECT id, items[2] FROM ListType",
         expected: Ok(select_with_null!(
-            id     | "items[1]";
+            id     | "items[2]";
             I64(1)   I64(2);
             I64(2)   s("world");
             I64(3)   I64(10)
@@ -94,9 +94,9 @@ INSERT INTO ListType2 VALUES
     test!(
         "SELECT
             id,
-            items['0'] AS foo,
-            items['1'] AS bar,
-            items['3']['0'] AS hundred
+            items['1'] AS foo,
+            items['2'] AS bar,
+            items['4']['1'] AS hundred
         FROM ListType2",
         Ok(select_with_null!(
             id     | foo        | bar        | hundred;
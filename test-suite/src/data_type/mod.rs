@@ -1,6 +1,7 @@
 pub mod bytea;
 pub mod date;
 pub mod decimal;
+pub mod enum_type;
 pub mod float32;
 pub mod inet;
 pub mod int128;
@@ -8,6 +9,7 @@ pub mod int16;
 pub mod int32;
 pub mod int64;
 pub mod int8;
+pub mod integer_widening;
 pub mod interval;
 pub mod list;
 pub mod map;
@@ -15,6 +17,7 @@ pub mod point;
 pub mod sql_types;
 pub mod time;
 pub mod timestamp;
+pub mod timestamptz;
 pub mod uint128;
 pub mod uint16;
 pub mod uint32;
@@ -0,0 +1,165 @@
+use {
+    crate::*,
+    gluesql_core::{error::ValueError, prelude::Value::*},
+};
+
+test_case!(timestamptz, async move {
+    run!(
+        "
+CREATE TABLE TimestampTzLog (
+    id INTEGER,
+    t TIMESTAMPTZ,
+    naive TIMESTAMP,
+)"
+    );
+
+    run!(
+        "
+INSERT INTO TimestampTzLog VALUES
+    (1, '2024-01-01T00:00:00+09:00', '2024-01-01 00:00:00'),
+    (2, '2023-12-31 15:00:00Z',      '2023-12-31 15:00:00'),
+    (3, '2024-01-01 09:00:00-05:00', '2024-01-01 14:00:00');
+"
+    );
+
+    macro_rules! t {
+        ($timestamp: expr) => {
+            $timestamp.parse().unwrap()
+        };
+    }
+
+    test!(
+        "SELECT id, CAST(t AS TEXT) AS t FROM TimestampTzLog",
+        Ok(select!(
+            id  | t
+            I64 | Str;
+            1     "2024-01-01 00:00:00+09:00".to_owned();
+            2     "2023-12-31 15:00:00+00:00".to_owned();
+            3     "2024-01-01 09:00:00-05:00".to_owned()
+        ))
+    );
+
+    test!(
+        "SELECT id FROM TimestampTzLog WHERE t = '2023-12-31T15:00:00Z'",
+        Ok(select!(id I64; 1; 2))
+    );
+
+    test!(
+        "SELECT id FROM TimestampTzLog WHERE t > TIMESTAMPTZ '2024-01-01T08:00:00+09:00'",
+        Ok(select!(id I64; 3))
+    );
+
+    test!(
+        "SELECT id FROM TimestampTzLog ORDER BY t DESC, id",
+        Ok(select!(id I64; 3; 1; 2))
+    );
+
+    test!(
+        "SELECT id, t - TIMESTAMPTZ '2024-01-01T00:00:00Z' AS diff FROM TimestampTzLog",
+        Ok(select!(
+            id  | diff
+            I64 | Interval;
+            1     gluesql_core::data::Interval::seconds(-32_400);
+            2     gluesql_core::data::Interval::seconds(-32_400);
+            3     gluesql_core::data::Interval::seconds(50_400)
+        ))
+    );
+
+    test!(
+        "SELECT
+            id,
+            CAST(t + INTERVAL '1' MONTH AS TEXT) AS add,
+            CAST(t - INTERVAL '1' HOUR AS TEXT) AS sub
+        FROM TimestampTzLog",
+        Ok(select!(
+            id  | add                                      | sub
+            I64 | Str                                      | Str;
+            1     "2024-02-01 00:00:00+09:00".to_owned()     "2023-12-31 23:00:00+09:00".to_owned();
+            2     "2024-01-31 15:00:00+00:00".to_owned()     "2023-12-31 14:00:00+00:00".to_owned();
+            3     "2024-02-01 09:00:00-05:00".to_owned()     "2024-01-01 08:00:00-05:00".to_owned()
+        ))
+    );
+
+    test!(
+        "SELECT id, t AT TIME ZONE 'Asia/Seoul' AS seoul FROM TimestampTzLog",
+        Ok(select!(
+            id  | seoul
+            I64 | Timestamp;
+            1     t!("2024-01-01T00:00:00");
+            2     t!("2024-01-01T00:00:00");
+            3     t!("2024-01-01T23:00:00")
+        ))
+    );
+
+    // America/New_York moves from -05:00 to -04:00 at 2024-03-10 02:00 local time
+    // and back at 2024-11-03 02:00 local time
+    test!(
+        "SELECT
+            TIMESTAMPTZ '2024-03-10T06:30:00Z' AT TIME ZONE 'America/New_York' AS est,
+            TIMESTAMPTZ '2024-03-10T07:30:00Z' AT TIME ZONE 'America/New_York' AS edt",
+        Ok(select!(
+            est                       | edt
+            Timestamp                 | Timestamp;
+            t!("2024-03-10T01:30:00")   t!("2024-03-10T03:30:00")
+        ))
+    );
+    test!(
+        "SELECT CAST(TIMESTAMP '2024-11-03 01:30:00' AT TIME ZONE 'America/New_York' AS TEXT) AS t",
+        Ok(select!(t Str; "2024-11-03 01:30:00-04:00".to_owned()))
+    );
+    test!(
+        "SELECT TIMESTAMP '2024-03-10 02:30:00' AT TIME ZONE 'America/New_York' AS t",
+        Err(ValueError::NonexistentLocalTime {
+            timestamp: "2024-03-10 02:30:00".to_owned(),
+            time_zone: "America/New_York".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "SELECT t AT TIME ZONE 'Mars/Olympus' AS t FROM TimestampTzLog",
+        Err(ValueError::FailedToParseTimeZone("Mars/Olympus".to_owned()).into())
+    );
+    test!(
+        "SELECT id AT TIME ZONE 'UTC' AS t FROM TimestampTzLog",
+        Err(ValueError::AtTimeZoneOnNonTimestamp(I64(1)).into())
+    );
+
+    // TIMESTAMP is read in the session time zone when it meets TIMESTAMPTZ
+    test!(
+        "SELECT id FROM TimestampTzLog WHERE t = naive",
+        Ok(select!(id I64; 2; 3))
+    );
+
+    get_glue!().set_time_zone("Asia/Seoul").unwrap();
+
+    test!(
+        "SELECT id FROM TimestampTzLog WHERE t = naive",
+        Ok(select!(id I64; 1))
+    );
+    test!(
+        "SELECT
+            id,
+            CAST(CAST(naive AS TIMESTAMPTZ) AS TEXT) AS tz,
+            CAST(t AS TIMESTAMP) AS naive
+        FROM TimestampTzLog",
+        Ok(select!(
+            id  | tz                                       | naive
+            I64 | Str                                      | Timestamp;
+            1     "2024-01-01 00:00:00+09:00".to_owned()     t!("2024-01-01T00:00:00");
+            2     "2023-12-31 15:00:00+09:00".to_owned()     t!("2024-01-01T00:00:00");
+            3     "2024-01-01 14:00:00+09:00".to_owned()     t!("2024-01-01T23:00:00")
+        ))
+    );
+    run!("INSERT INTO TimestampTzLog VALUES (4, '2024-06-01 12:00:00', '2024-06-01 12:00:00');");
+    test!(
+        "SELECT CAST(t AS TEXT) AS t FROM TimestampTzLog WHERE id = 4",
+        Ok(select!(t Str; "2024-06-01 12:00:00+09:00".to_owned()))
+    );
+
+    get_glue!().set_time_zone("UTC").unwrap();
+
+    test!(
+        "INSERT INTO TimestampTzLog VALUES (5, '12345-678', '2024-01-01')",
+        Err(ValueError::FailedToParseTimestamp("12345-678".to_owned()).into())
+    );
+});
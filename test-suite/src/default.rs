@@ -2,7 +2,7 @@ use {
     crate::*,
     chrono::NaiveDate,
     gluesql_core::{
-        error::EvaluateError,
+        error::{AlterError, EvaluateError},
         prelude::{Payload, Value::*},
     },
 };
@@ -92,4 +92,113 @@ test_case!(default, async move {
             1     d(2020, 1, 1)   2     true   true    false   false
         ))
     );
+
+    run!(
+        "
+        CREATE TABLE TestKeyword (
+            id INTEGER NULL,
+            num INTEGER DEFAULT 10,
+            name TEXT NULL,
+            flag BOOLEAN NOT NULL DEFAULT true
+        )"
+    );
+
+    let test_cases = [
+        (
+            "INSERT INTO TestKeyword VALUES (1, DEFAULT, 'a', DEFAULT)",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO TestKeyword (id, num, name) VALUES (2, 20, DEFAULT)",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT * FROM TestKeyword",
+            Ok(select_with_null!(
+                id     | num     | name          | flag;
+                I64(1)   I64(10)   Str("a".to_owned())   Bool(true);
+                I64(2)   I64(20)   Null                  Bool(true)
+            )),
+        ),
+        (
+            "UPDATE TestKeyword SET num = DEFAULT, name = DEFAULT, flag = false WHERE id = 1",
+            Ok(Payload::Update(1)),
+        ),
+        (
+            "UPDATE TestKeyword SET flag = DEFAULT WHERE id = 1",
+            Ok(Payload::Update(1)),
+        ),
+        (
+            "SELECT * FROM TestKeyword WHERE id = 1",
+            Ok(select_with_null!(
+                id     | num     | name | flag;
+                I64(1)   I64(10)   Null   Bool(true)
+            )),
+        ),
+        (
+            "INSERT INTO TestKeyword (num) VALUES (DEFAULT)",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO TestKeyword (id, num) VALUES (DEFAULT, 3)",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT DEFAULT FROM TestKeyword",
+            Err(EvaluateError::DefaultNotAllowed.into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+
+    let test_cases = [
+        (
+            "CREATE TABLE TestReference (
+                name TEXT,
+                lower_name TEXT DEFAULT LOWER(name),
+                created TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                uuid UUID DEFAULT GEN_RANDOM_UUID()
+            )",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO TestReference (name) VALUES ('GlueSQL')",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO TestReference (name, lower_name) VALUES ('Glue', 'sql')",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT name, lower_name FROM TestReference",
+            Ok(select!(
+                name                     | lower_name;
+                Str                      | Str;
+                "GlueSQL".to_owned()       "gluesql".to_owned();
+                "Glue".to_owned()          "sql".to_owned()
+            )),
+        ),
+        (
+            "SELECT COUNT(*) FROM TestReference WHERE created IS NOT NULL AND uuid IS NOT NULL",
+            Ok(select!("COUNT(*)"; I64; 2)),
+        ),
+        (
+            "CREATE TABLE TestForward (a INTEGER DEFAULT b + 1, b INTEGER)",
+            Err(AlterError::UnavailableDefaultReference {
+                column: "a".to_owned(),
+                reference: "b".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "CREATE TABLE TestCircular (a INTEGER DEFAULT a + 1)",
+            Err(AlterError::CircularDefaultReference("a".to_owned()).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
 });
@@ -50,10 +50,25 @@ test_case!(delete, async move {
         ))
     };
 
+    test! {
+        name: "delete using table alias",
+        sql: "DELETE FROM Foo AS F WHERE F.score > 500",
+        expected: Ok(Payload::Delete(1))
+    };
+
+    test! {
+        sql: "SELECT * FROM Foo",
+        expected: Ok(select!(
+            id  | score | flag
+            I64 | I64   | Bool;
+            1     100     true
+        ))
+    };
+
     test! {
         name: "delete all",
         sql: "DELETE FROM Foo;",
-        expected: Ok(Payload::Delete(2))
+        expected: Ok(Payload::Delete(1))
     };
 
     test! {
@@ -0,0 +1,158 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{EnumTypeError, ValueError},
+        prelude::{Payload, Value::*},
+    },
+    std::sync::Arc,
+};
+
+test_case!(enum_type, async move {
+    let moods = |variants: &[&str]| {
+        variants
+            .iter()
+            .map(|&variant| variant.to_owned())
+            .collect::<Vec<_>>()
+    };
+
+    test!(
+        "CREATE TYPE mood AS ENUM ('happy', 'sad', 'ok')",
+        Ok(Payload::Create)
+    );
+    test!(
+        "CREATE TYPE mood AS ENUM ('happy')",
+        Err(EnumTypeError::TypeAlreadyExists("mood".to_owned()).into())
+    );
+    test!(
+        "CREATE TYPE nothing AS ENUM ()",
+        Err(EnumTypeError::EmptyVariants("nothing".to_owned()).into())
+    );
+    test!(
+        "CREATE TYPE twice AS ENUM ('ok', 'ok')",
+        Err(EnumTypeError::VariantAlreadyExists {
+            name: "twice".to_owned(),
+            variant: "ok".to_owned(),
+        }
+        .into())
+    );
+
+    test!(
+        "CREATE TABLE Gluery (id SOMEWHAT)",
+        Err(EnumTypeError::TypeNotFound("SOMEWHAT".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Gluery (id INTEGER, feeling mood DEFAULT 'meh')",
+        Err(ValueError::InvalidEnumVariant {
+            value: "meh".to_owned(),
+            variants: moods(&["happy", "sad", "ok"]),
+        }
+        .into())
+    );
+
+    run!("CREATE TABLE Person (id INTEGER, feeling mood NULL)");
+    test!(
+        "SHOW COLUMNS FROM Person",
+        Ok(Payload::ShowColumns(vec![
+            ("id".to_owned(), DataType::Int),
+            (
+                "feeling".to_owned(),
+                DataType::UserDefined("mood".to_owned())
+            ),
+        ]))
+    );
+    test!(
+        "INSERT INTO Person VALUES (1, 'happy'), (2, 'sad'), (3, NULL)",
+        Ok(Payload::Insert(3))
+    );
+    test!(
+        "INSERT INTO Person VALUES (4, 'angry')",
+        Err(ValueError::InvalidEnumVariant {
+            value: "angry".to_owned(),
+            variants: moods(&["happy", "sad", "ok"]),
+        }
+        .into())
+    );
+
+    test!("ALTER TYPE mood ADD VALUE 'angry'", Ok(Payload::AlterType));
+    test!(
+        "ALTER TYPE mood ADD VALUE 'angry'",
+        Err(EnumTypeError::VariantAlreadyExists {
+            name: "mood".to_owned(),
+            variant: "angry".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "ALTER TYPE mood ADD VALUE IF NOT EXISTS 'angry'",
+        Ok(Payload::AlterType)
+    );
+    test!(
+        "ALTER TYPE weather ADD VALUE 'small'",
+        Err(EnumTypeError::TypeNotFound("weather".to_owned()).into())
+    );
+    test!(
+        "INSERT INTO Person VALUES (4, 'angry')",
+        Ok(Payload::Insert(1))
+    );
+    test!(
+        "UPDATE Person SET feeling = 'meh' WHERE id = 1",
+        Err(ValueError::InvalidEnumVariant {
+            value: "meh".to_owned(),
+            variants: moods(&["happy", "sad", "ok", "angry"]),
+        }
+        .into())
+    );
+
+    test!(
+        "SELECT id FROM Person WHERE feeling IN ('sad', 'angry') ORDER BY id",
+        Ok(select!(id I64; 2; 4))
+    );
+    test!(
+        "SELECT id FROM Person WHERE feeling NOT IN ('happy') ORDER BY id",
+        Ok(select!(id I64; 2; 4))
+    );
+    test!(
+        "SELECT id FROM Person WHERE feeling IN (SELECT feeling FROM Person WHERE id > 3)",
+        Ok(select!(id I64; 4))
+    );
+    test!(
+        "SELECT CAST('ok' AS mood) AS feeling",
+        Ok(select_with_null!(
+            feeling;
+            Enum {
+                value: "ok".to_owned(),
+                variants: Arc::new(moods(&["happy", "sad", "ok", "angry"])),
+            }
+        ))
+    );
+    test!(
+        "SELECT CAST('meh' AS mood) AS feeling",
+        Err(ValueError::InvalidEnumVariant {
+            value: "meh".to_owned(),
+            variants: moods(&["happy", "sad", "ok", "angry"]),
+        }
+        .into())
+    );
+    test!(
+        "SELECT CAST('ok' AS weather) AS feeling",
+        Err(EnumTypeError::TypeNotFound("weather".to_owned()).into())
+    );
+
+    test!(
+        "DROP TYPE mood",
+        Err(EnumTypeError::TypeInUse {
+            name: "mood".to_owned(),
+            table: "Person".to_owned(),
+            column: "feeling".to_owned(),
+        }
+        .into())
+    );
+    run!("DROP TABLE Person");
+    test!("DROP TYPE mood", Ok(Payload::DropType));
+    test!(
+        "DROP TYPE mood",
+        Err(EnumTypeError::TypeNotFound("mood".to_owned()).into())
+    );
+    test!("DROP TYPE IF EXISTS mood", Ok(Payload::DropType));
+});
@@ -0,0 +1,43 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(explain, async move {
+    run!("CREATE TABLE ExplainItem (id INTEGER PRIMARY KEY, name TEXT);");
+    run!("INSERT INTO ExplainItem VALUES (1, 'glue');");
+
+    test!(
+        "EXPLAIN SELECT * FROM ExplainItem",
+        Ok(select!(
+            "QUERY PLAN"
+            Str;
+            r#"SELECT * FROM "ExplainItem";"#.to_owned()
+        ))
+    );
+
+    let rows = match run!("EXPLAIN VERBOSE SELECT * FROM ExplainItem WHERE id = 1") {
+        Payload::Select { labels, rows } => {
+            assert_eq!(labels, vec!["QUERY PLAN".to_owned()]);
+
+            rows
+        }
+        payload => panic!("unexpected payload: {payload:?}"),
+    };
+    assert_eq!(rows[0], vec![Str("Query(".to_owned())]);
+    assert!(
+        rows.iter()
+            .any(|row| matches!(&row[..], [Str(line)] if line.trim() == "PrimaryKey(")),
+        "the primary key planned for the WHERE clause is shown"
+    );
+
+    run!("EXPLAIN UPDATE ExplainItem SET name = 'sql'");
+    test!(
+        "SELECT name FROM ExplainItem",
+        Ok(select!(
+            name
+            Str;
+            "glue".to_owned()
+        ))
+    );
+});
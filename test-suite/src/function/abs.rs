@@ -1,7 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{EvaluateError, TranslateError},
+        error::{EvaluateError, TranslateError, ValueError},
         prelude::{Payload, Value::*},
     },
 };
@@ -57,6 +57,10 @@ test_case!(abs, async move {
                 0         1         2.into()
             )),
         ),
+        (
+            "SELECT ABS(CAST(-128 AS INT8)) AS ABS",
+            Err(ValueError::AbsOverflow(I8(i8::MIN)).into()),
+        ),
         (
             "SELECT ABS('string') AS ABS FROM SingleItem",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("ABS")).into()),
@@ -0,0 +1,92 @@
+use {
+    crate::*,
+    gluesql_core::{error::EvaluateError, prelude::Value::*},
+};
+
+test_case!(array_remove_replace, async move {
+    run!(
+        "
+        CREATE TABLE ArrayItem (
+            id INTEGER,
+            items LIST NULL,
+            element INTEGER
+        );
+    "
+    );
+    run!(
+        r#"
+        INSERT INTO ArrayItem VALUES
+            (1, '[1, 2, 3, 2]', 2),
+            (2, '["a", null, "b", null]', 3),
+            (3, NULL, 1);
+    "#
+    );
+
+    test!(
+        "SELECT ARRAY_REMOVE(items, element) AS removed FROM ArrayItem WHERE id = 1",
+        Ok(select!(
+            removed
+            List;
+            vec![I64(1), I64(3)]
+        ))
+    );
+    test!(
+        "SELECT ARRAY_REMOVE(items, 'b') AS removed FROM ArrayItem WHERE id = 2",
+        Ok(select!(
+            removed
+            List;
+            vec![Str("a".to_owned()), Null, Null]
+        ))
+    );
+    test!(
+        "SELECT ARRAY_REMOVE(items, NULL) AS removed FROM ArrayItem WHERE id = 2",
+        Ok(select!(
+            removed
+            List;
+            vec![Str("a".to_owned()), Null, Str("b".to_owned()), Null]
+        ))
+    );
+    test!(
+        "SELECT ARRAY_REMOVE_NULLS(items) AS removed FROM ArrayItem WHERE id = 2",
+        Ok(select!(
+            removed
+            List;
+            vec![Str("a".to_owned()), Str("b".to_owned())]
+        ))
+    );
+    test!(
+        "SELECT ARRAY_REPLACE(items, element, 99) AS replaced FROM ArrayItem WHERE id = 1",
+        Ok(select!(
+            replaced
+            List;
+            vec![I64(1), I64(99), I64(3), I64(99)]
+        ))
+    );
+    test!(
+        "SELECT ARRAY_REPLACE(items, 'a', 'z') AS replaced FROM ArrayItem WHERE id = 2",
+        Ok(select!(
+            replaced
+            List;
+            vec![Str("z".to_owned()), Null, Str("b".to_owned()), Null]
+        ))
+    );
+    test!(
+        "SELECT
+            ARRAY_REMOVE(items, 1) AS removed,
+            ARRAY_REMOVE_NULLS(items) AS removed_nulls,
+            ARRAY_REPLACE(items, 1, 2) AS replaced
+        FROM ArrayItem WHERE id = 3",
+        Ok(select_with_null!(
+            removed | removed_nulls | replaced;
+            Null      Null            Null
+        ))
+    );
+    test!(
+        "SELECT ARRAY_REMOVE(element, 1) AS removed FROM ArrayItem",
+        Err(EvaluateError::ListTypeRequired.into())
+    );
+    test!(
+        "SELECT ARRAY_REPLACE(element, 1, 2) AS replaced FROM ArrayItem",
+        Err(EvaluateError::ListTypeRequired.into())
+    );
+});
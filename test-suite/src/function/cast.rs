@@ -182,7 +182,11 @@ test_case!(cast_literal, async move {
         ),
         (
             "SELECT CAST(mytext AS Decimal) AS cast FROM test",
-            Err(ValueError::ImpossibleCast.into()),
+            Err(ValueError::CastFailed {
+                from: Str("foobar".to_owned()),
+                to: DataType::Decimal,
+            }
+            .into()),
         ),
         (
             "SELECT CAST(myint8 AS Decimal) AS cast FROM test",
@@ -216,7 +220,11 @@ test_case!(cast_literal, async move {
 
         (
             "SELECT CAST(mydate AS Decimal) AS cast FROM test",
-            Err(ValueError::ImpossibleCast.into()),
+            Err(ValueError::CastFailed {
+                from: Date(NaiveDate::from_ymd_opt(2001, 9, 11).unwrap()),
+                to: DataType::Decimal,
+            }
+            .into()),
         ),
         (
             "SELECT CAST(1 AS TEXT) AS cast FROM Item",
@@ -236,9 +244,9 @@ test_case!(cast_literal, async move {
         ),
         (
             "SELECT CAST(NULL AS INTERVAL) FROM Item",
-            Err(ValueError::UnimplementedLiteralCast {
-                data_type: gluesql_core::ast::DataType::Interval,
-                literal: format!("{:?}", gluesql_core::data::Literal::Null),
+            Err(ValueError::CastFailed {
+                from: Null,
+                to: DataType::Interval,
             }
             .into()),
         ),
@@ -373,7 +381,11 @@ test_case!(cast_value, async move {
         ),
         (
             "SELECT CAST(number AS BOOLEAN) FROM Item",
-            Err(ValueError::ImpossibleCast.into()),
+            Err(ValueError::CastFailed {
+                from: Str("1".to_owned()),
+                to: DataType::Boolean,
+            }
+            .into()),
         ),
         (
             "
@@ -17,8 +17,8 @@ test_case!(ceil, async move {
             ;",
             Ok(select!(
                 "ceil1" | "ceil2" | "ceil3" | "ceil4";
-                F64     | F64     | F64     | F64;
-                1.0       0.0       10.0      7.0
+                F64     | F64     | I64     | F64;
+                1.0       0.0       10        7.0
             )),
         ),
         (
@@ -0,0 +1,110 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{TranslateError, ValueError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(coalesce_nullif, async move {
+    run!(
+        "
+        CREATE TABLE Player (
+            id INTEGER,
+            nickname TEXT NULL,
+            name TEXT NULL,
+            score INTEGER NULL
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Player VALUES
+            (1, 'glue', 'Alice', 10),
+            (2, NULL, 'Bob', 0),
+            (3, NULL, NULL, NULL);
+    "
+    );
+
+    test!(
+        "SELECT id, COALESCE(nickname, name, 'unknown') AS label FROM Player",
+        Ok(select!(
+            id  | label
+            I64 | Str;
+            1     "glue".to_owned();
+            2     "Bob".to_owned();
+            3     "unknown".to_owned()
+        ))
+    );
+    test!(
+        "SELECT COALESCE(score) AS a, COALESCE(NULL, score, 1.5) AS b FROM Player",
+        Ok(select_with_null!(
+            a       | b;
+            I64(10)   I64(10);
+            I64(0)    I64(0);
+            Null      F64(1.5)
+        ))
+    );
+    test!(
+        "SELECT COALESCE(NULL, NULL) AS a",
+        Ok(select_with_null!(a; Null))
+    );
+
+    // arguments after the first non-null one are never evaluated
+    test!(
+        "SELECT COALESCE(id, id / 0) AS a FROM Player WHERE id = 1",
+        Ok(select!(a I64; 1))
+    );
+    test!(
+        "SELECT COALESCE(nickname, (SELECT name FROM Player)) AS a FROM Player WHERE id = 1",
+        Ok(select!(a Str; "glue".to_owned()))
+    );
+    test!(
+        "SELECT IFNULL(id, id / 0) AS a FROM Player WHERE id = 1",
+        Ok(select!(a I64; 1))
+    );
+    test!(
+        "SELECT COALESCE(score, id / 0) AS a FROM Player WHERE id = 3",
+        Err(ValueError::DivisorShouldNotBeZero.into())
+    );
+
+    test!(
+        "SELECT id, NULLIF(score, 0) AS score, NULLIF(name, 'Alice') AS name FROM Player",
+        Ok(select_with_null!(
+            id     | score     | name;
+            I64(1)   I64(10)     Null;
+            I64(2)   Null        Str("Bob".to_owned());
+            I64(3)   Null        Null
+        ))
+    );
+    test!(
+        "SELECT NULLIF(1, NULL) AS a, NULLIF(NULL, 1) AS b, NULLIF('a', 'a') AS c",
+        Ok(select_with_null!(
+            a      | b    | c;
+            I64(1)   Null   Null
+        ))
+    );
+    test!(
+        "SELECT 10 / NULLIF(score, 0) AS ratio FROM Player WHERE id = 2",
+        Ok(select_with_null!(ratio; Null))
+    );
+
+    test!(
+        "SELECT COALESCE() AS a",
+        Err(TranslateError::FunctionArgsLengthNotMatchingMin {
+            name: "COALESCE".to_owned(),
+            expected_minimum: 1,
+            found: 0,
+        }
+        .into())
+    );
+    test!(
+        "SELECT NULLIF(1) AS a",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "NULLIF".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into())
+    );
+});
@@ -0,0 +1,77 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(encode_decode, async move {
+    let bytea = |v| hex::decode(v).unwrap();
+
+    let test_cases = [
+        (
+            "CREATE TABLE EncodeDecode (bytes BYTEA NULL)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO EncodeDecode VALUES (X'00FF7F80'), (X''), (NULL)",
+            Ok(Payload::Insert(3)),
+        ),
+        (
+            "SELECT ENCODE(bytes, 'hex') AS hex, ENCODE(bytes, 'BASE64') AS base64 FROM EncodeDecode",
+            Ok(select_with_null!(
+                hex                           | base64;
+                Str("00ff7f80".to_owned())      Str("AP9/gA==".to_owned());
+                Str("".to_owned())              Str("".to_owned());
+                Null                            Null
+            )),
+        ),
+        (
+            "SELECT DECODE('00ff7f80', 'hex') AS a, DECODE('AP9/gA==', 'base64') AS b",
+            Ok(select!(
+                a                  | b
+                Bytea              | Bytea;
+                bytea("00ff7f80")    bytea("00ff7f80")
+            )),
+        ),
+        (
+            "SELECT DECODE(ENCODE(bytes, 'base64'), 'base64') = bytes AS round_trip
+            FROM EncodeDecode WHERE bytes IS NOT NULL",
+            Ok(select!(round_trip Bool; true; true)),
+        ),
+        (
+            "SELECT ENCODE(bytes, 'escape') FROM EncodeDecode",
+            Err(EvaluateError::UnsupportedEncodingFormat("escape".to_owned()).into()),
+        ),
+        (
+            "SELECT ENCODE('abc', 'hex') AS hex",
+            Err(EvaluateError::FunctionRequiresByteaValue("ENCODE".to_owned()).into()),
+        ),
+        (
+            "SELECT DECODE('xyz', 'hex') AS bytes",
+            Err(EvaluateError::FailedToDecode {
+                format: "hex".to_owned(),
+                value: "xyz".to_owned(),
+            }
+            .into()),
+        ),
+        (
+            "SELECT DECODE(1, 'hex') AS bytes",
+            Err(EvaluateError::FunctionRequiresStringValue("DECODE".to_owned()).into()),
+        ),
+        (
+            "SELECT ENCODE(bytes) FROM EncodeDecode",
+            Err(TranslateError::FunctionArgsLengthNotMatching {
+                name: "ENCODE".to_owned(),
+                expected: 2,
+                found: 1,
+            }
+            .into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
@@ -1,6 +1,9 @@
 use {
     crate::*,
-    gluesql_core::{error::EvaluateError, prelude::Value::*},
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
 };
 
 test_case!(log2, async move {
@@ -24,6 +27,14 @@ test_case!(log2, async move {
                 f64::from(32).log2()
             )),
         ),
+        (
+            "SELECT LOG2(-1) AS log2;",
+            Err(ValueError::DomainError {
+                function: "LOG2".to_owned(),
+                value: I64(-1),
+            }
+            .into()),
+        ),
         (
             "SELECT LOG2('string') AS log2;",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("LOG2")).into()),
@@ -60,6 +71,14 @@ test_case!(log10, async move {
                 f64::from(10).log10()
             )),
         ),
+        (
+            "SELECT LOG10(-0.5) AS log10",
+            Err(ValueError::DomainError {
+                function: "LOG10".to_owned(),
+                value: F64(-0.5),
+            }
+            .into()),
+        ),
         (
             "SELECT LOG10('string') AS log10",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("LOG10")).into()),
@@ -96,6 +115,14 @@ test_case!(ln, async move {
                 f64::from(10).ln()
             )),
         ),
+        (
+            "SELECT LN(0) AS ln",
+            Err(ValueError::DomainError {
+                function: "LN".to_owned(),
+                value: I64(0),
+            }
+            .into()),
+        ),
         (
             "SELECT LN('string') AS log10",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("LN")).into()),
@@ -112,23 +139,40 @@ test_case!(log, async move {
     let test_cases = [
         (
             "SELECT
-                LOG(64.0, 2.0) as log_1,
-                LOG(0.04, 10.0) as log_2
+                LOG(2.0, 64.0) as log_1,
+                LOG(10.0, 0.04) as log_2,
+                LOG(3, 81) as log_3
             ;",
             Ok(select!(
-                log_1               | log_2;
-                F64                 | F64;
-                64.0_f64.log(2.0)     0.04_f64.log(10.0)
+                log_1               | log_2               | log_3;
+                F64                 | F64                 | F64;
+                6.0                   0.04_f64.log10()      81.0_f64.log(3.0)
             )),
         ),
         (
-            "SELECT LOG(10, 10) as log_with_int",
+            "SELECT LOG(2, 8) as log_with_int",
             Ok(select!(
                 log_with_int
                 F64;
-                f64::from(10).log(10.0)
+                3.0
             )),
         ),
+        (
+            "SELECT LOG(2, 0) AS log",
+            Err(ValueError::DomainError {
+                function: "LOG".to_owned(),
+                value: I64(0),
+            }
+            .into()),
+        ),
+        (
+            "SELECT LOG(1, 8) AS log",
+            Err(ValueError::DomainError {
+                function: "LOG".to_owned(),
+                value: I64(1),
+            }
+            .into()),
+        ),
         (
             "SELECT LOG('string', 10) AS log",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("LOG")).into()),
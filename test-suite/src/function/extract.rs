@@ -66,6 +66,26 @@ test_case!(extract, async move {
             "SELECT EXTRACT(SECOND FROM INTERVAL '8' SECOND) as extract",
             Ok(select!("extract" I64; 8)),
         ),
+        (
+            "SELECT DATE_PART('year', DATE '2021-10-06') AS date_part",
+            Ok(select!(date_part I64; 2021)),
+        ),
+        (
+            "SELECT DATE_PART('Minute', TIMESTAMP '2016-12-31 13:30:15') AS date_part",
+            Ok(select!(date_part I64; 30)),
+        ),
+        (
+            "SELECT DATE_PART('second', TIME '17:12:28') AS date_part",
+            Ok(select!(date_part I64; 28)),
+        ),
+        (
+            "SELECT EXTRACT(YEAR FROM NULL) AS extract",
+            Ok(select_with_null!(extract; Null)),
+        ),
+        (
+            "SELECT DATE_PART('week', DATE '2021-10-06')",
+            Err(TranslateError::UnsupportedDateTimeField("week".to_owned()).into()),
+        ),
         ("CREATE TABLE Item (number TEXT)", Ok(Payload::Create)),
         ("INSERT INTO Item VALUES ('1')", Ok(Payload::Insert(1))),
         (
@@ -18,8 +18,8 @@ test_case!(floor, async move {
             ;"#,
             Ok(select!(
                 floor1 | floor2              | floor3 | floor4
-                F64    | F64                 | F64    | F64;
-                0.0      f64::from(-1)   10.0     6.0
+                F64    | F64                 | I64    | F64;
+                0.0      f64::from(-1)         10       6.0
             )),
         ),
         (
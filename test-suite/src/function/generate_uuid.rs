@@ -33,4 +33,8 @@ test_case!(generate_uuid, async move {
         &[DataType::Uuid],
         "SELECT GENERATE_UUID() as uuid FROM SingleItem"
     );
+    type_match!(&[DataType::Uuid], "SELECT UUID() as uuid FROM SingleItem");
+
+    run!("INSERT INTO SingleItem VALUES (UUID()), (UUID()), (GENERATE_UUID())");
+    count!(4, "SELECT id FROM SingleItem GROUP BY id");
 });
@@ -0,0 +1,119 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+    rust_decimal::Decimal,
+};
+
+test_case!(greatest_least, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            num INTEGER NULL,
+            rate FLOAT,
+            name TEXT,
+            left_items LIST,
+            right_items LIST
+        );
+    "
+    );
+    run!(
+        r#"
+        INSERT INTO Item VALUES
+            (1, 5, 2.5, 'glue', '[1, 5, 3]', '[4, 2, 6]'),
+            (2, NULL, 7.5, 'sql', '[1, null, 3]', '[2, 2, null]');
+    "#
+    );
+
+    test!(
+        "SELECT GREATEST(id, 3, num) AS g, LEAST(id, 3, num) AS l FROM Item",
+        Ok(select_with_null!(
+            g      | l;
+            I64(5)   I64(1);
+            I64(3)   I64(2)
+        ))
+    );
+    test!(
+        "SELECT GREATEST(id, rate) AS g, LEAST(id, rate) AS l FROM Item",
+        Ok(select!(
+            g      | l
+            F64    | F64;
+            2.5      1.0;
+            7.5      2.0
+        ))
+    );
+    test!(
+        "SELECT
+            GREATEST(1, CAST(2.5 AS FLOAT32)) AS f,
+            LEAST(3, 2.5, CAST(4 AS DECIMAL)) AS d,
+            GREATEST(CAST(1 AS INT8), 2) AS i
+        ",
+        Ok(select!(
+            f      | d                  | i
+            F32    | Decimal            | I64;
+            2.5      Decimal::new(25, 1)  2
+        ))
+    );
+    test!(
+        "SELECT GREATEST(num) AS g, LEAST(rate) AS l FROM Item",
+        Ok(select_with_null!(
+            g      | l;
+            I64(5)   F64(2.5);
+            Null     F64(7.5)
+        ))
+    );
+    test!(
+        "SELECT LEAST(NULL, id, NULL) AS l FROM Item",
+        Ok(select!(l I64; 1; 2))
+    );
+    test!(
+        "SELECT GREATEST(name, 'hello') AS g FROM Item",
+        Ok(select!(
+            g
+            Str;
+            "hello".to_owned();
+            "sql".to_owned()
+        ))
+    );
+    test!(
+        "SELECT GREATEST(NULL, NULL) AS g FROM Item WHERE id = 1",
+        Ok(select_with_null!(g; Null))
+    );
+    test!(
+        "SELECT GREATEST(left_items, right_items) AS g, LEAST(left_items, right_items) AS l FROM Item",
+        Ok(select!(
+            g                                  | l
+            List                               | List;
+            vec![I64(4), I64(5), I64(6)]         vec![I64(1), I64(2), I64(3)];
+            vec![I64(2), Null, Null]             vec![I64(1), Null, Null]
+        ))
+    );
+    test!(
+        "SELECT GREATEST(left_items, NULL) AS g FROM Item WHERE id = 1",
+        Ok(select_with_null!(g; Null))
+    );
+    test!(
+        "SELECT GREATEST(left_items, CAST('[1, 2]' AS LIST)) AS g FROM Item",
+        Err(EvaluateError::FunctionRequiresListsOfSameLength("GREATEST".to_owned()).into())
+    );
+    test!(
+        "SELECT LEAST(left_items, 1) AS l FROM Item",
+        Err(EvaluateError::ListTypeRequired.into())
+    );
+    test!(
+        "SELECT GREATEST(name, 1) AS g FROM Item",
+        Err(EvaluateError::FunctionRequiresComparableValues("GREATEST".to_owned()).into())
+    );
+    test!(
+        "SELECT LEAST() AS l FROM Item",
+        Err(TranslateError::FunctionArgsLengthNotMatchingMin {
+            name: "LEAST".to_owned(),
+            expected_minimum: 1,
+            found: 0,
+        }
+        .into())
+    );
+});
@@ -0,0 +1,81 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(json_extract, async move {
+    run!(
+        "
+        CREATE TABLE Doc (
+            id INTEGER,
+            body MAP NULL,
+            raw TEXT
+        );
+    "
+    );
+    run!(
+        r#"
+        INSERT INTO Doc VALUES
+            (1, '{"a": {"b": [10, {"c": "glue"}]}, "d": null}', '[1, [2, 3]]'),
+            (2, NULL, '{"name": "sql"}');
+    "#
+    );
+
+    test!(
+        "SELECT JSON_EXTRACT(body, '$.a.b[0]') AS v FROM Doc WHERE id = 1",
+        Ok(select!(v I64; 10))
+    );
+    test!(
+        "SELECT JSON_EXTRACT(body, '$.a.b[1].c') AS v FROM Doc WHERE id = 1",
+        Ok(select!(v Str; "glue".to_owned()))
+    );
+    test!(
+        r#"SELECT JSON_EXTRACT(body, '$["a"].b[1]') AS v FROM Doc WHERE id = 1"#,
+        Ok(select!(
+            v
+            Map;
+            [("c".to_owned(), Str("glue".to_owned()))].into_iter().collect()
+        ))
+    );
+    test!(
+        "SELECT JSON_EXTRACT(raw, '$[1][0]') AS v FROM Doc WHERE id = 1",
+        Ok(select!(v I64; 2))
+    );
+    test!(
+        "SELECT JSON_EXTRACT(raw, '$.name') AS v FROM Doc WHERE id = 2",
+        Ok(select!(v Str; "sql".to_owned()))
+    );
+    test!(
+        "SELECT
+            JSON_EXTRACT(body, '$.d') AS null_value,
+            JSON_EXTRACT(body, '$.a.x') AS missing
+        FROM Doc WHERE id = 1",
+        Ok(select_with_null!(null_value | missing; Null Null))
+    );
+    test!(
+        "SELECT
+            JSON_EXTRACT(body, '$.a') AS null_doc,
+            JSON_EXTRACT(raw, NULL) AS null_path
+        FROM Doc WHERE id = 2",
+        Ok(select_with_null!(null_doc | null_path; Null Null))
+    );
+    test!(
+        "SELECT JSON_EXTRACT(body, 'a.b') AS v FROM Doc WHERE id = 1",
+        Err(ValueError::InvalidJsonPath("a.b".to_owned()).into())
+    );
+    test!(
+        "SELECT JSON_EXTRACT(body, '$.a[') AS v FROM Doc WHERE id = 1",
+        Err(ValueError::InvalidJsonPath("$.a[".to_owned()).into())
+    );
+    test!(
+        "SELECT JSON_EXTRACT(id, '$.a') AS v FROM Doc WHERE id = 1",
+        Err(ValueError::JsonExtractOnNonJson(I64(1)).into())
+    );
+    test!(
+        "SELECT JSON_EXTRACT(body, 1) AS v FROM Doc WHERE id = 1",
+        Err(EvaluateError::FunctionRequiresStringValue("JSON_EXTRACT".to_owned()).into())
+    );
+});
@@ -58,6 +58,26 @@ test_case!(lpad_rpad, async move {
                 "hel".to_owned()          "hel".to_owned()
             )),
         ),
+        (
+            "SELECT LPAD('한글', 5, '가나') AS a, RPAD('Σοφία', 3) AS b, LPAD('ab', 0) AS c",
+            Ok(select!(
+                a                        | b                 | c
+                Str                      | Str               | Str;
+                "가나가한글".to_owned()    "Σοφ".to_owned()    String::new()
+            )),
+        ),
+        (
+            "SELECT OCTET_LENGTH(RPAD('a', 10000, 'bc')) AS len",
+            Ok(select!(len I64; 10000)),
+        ),
+        (
+            "SELECT LPAD(name, 10, '') FROM Item",
+            Err(EvaluateError::EmptyPadString("LPAD".to_owned()).into()),
+        ),
+        (
+            "SELECT RPAD(name, 3, '') FROM Item",
+            Err(EvaluateError::EmptyPadString("RPAD".to_owned()).into()),
+        ),
         (
             "SELECT LPAD(name, 10, 'ab') AS lpad FROM NullName",
             Ok(select_with_null!(lpad; Null)),
@@ -1,13 +1,16 @@
 pub mod abs;
 pub mod append;
+pub mod array_remove_replace;
 pub mod ascii;
 pub mod cast;
 pub mod ceil;
 pub mod chr;
+pub mod coalesce_nullif;
 pub mod concat;
 pub mod concat_ws;
 pub mod degrees;
 pub mod div_mod;
+pub mod encode_decode;
 pub mod exp_log;
 pub mod extract;
 pub mod find_idx;
@@ -16,14 +19,17 @@ pub mod format;
 pub mod gcd_lcm;
 pub mod generate_uuid;
 pub mod geometry;
+pub mod greatest_least;
 pub mod ifnull;
 pub mod initcap;
+pub mod json_extract;
 pub mod left_right;
 pub mod lpad_rpad;
 pub mod ltrim_rtrim;
 pub mod math_function;
 pub mod md5;
 pub mod now;
+pub mod octet_length;
 pub mod pi;
 pub mod position;
 pub mod prepend;
@@ -33,8 +39,12 @@ pub mod repeat;
 pub mod reverse;
 pub mod round;
 pub mod sign;
+pub mod split_part;
 pub mod sqrt_power;
+pub mod strpos_locate;
 pub mod substr;
 pub mod to_date;
 pub mod trim;
+pub mod try_cast;
 pub mod upper_lower;
+pub mod uuid_to_bin;
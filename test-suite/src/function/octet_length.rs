@@ -0,0 +1,37 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::EvaluateError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(octet_length, async move {
+    let test_cases = [
+        (
+            "CREATE TABLE OctetLength (bytes BYTEA NULL, name TEXT)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO OctetLength VALUES (X'0000FF', 'Glue'), (NULL, '한글')",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "SELECT OCTET_LENGTH(bytes) AS bytes, OCTET_LENGTH(name) AS name FROM OctetLength",
+            Ok(select_with_null!(
+                bytes  | name;
+                I64(3)   I64(4);
+                Null     I64(6)
+            )),
+        ),
+        ("SELECT OCTET_LENGTH(X'') AS len", Ok(select!(len I64; 0))),
+        (
+            "SELECT OCTET_LENGTH(1) AS len",
+            Err(EvaluateError::FunctionRequiresByteaValue("OCTET_LENGTH".to_owned()).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
@@ -43,6 +43,22 @@ test_case!(repeat, async move {
             "SELECT REPEAT(1, 1) AS test FROM Item",
             Err(EvaluateError::FunctionRequiresStringValue("REPEAT".to_owned()).into()),
         ),
+        (
+            "SELECT REPEAT('한글', 3) AS test",
+            Ok(select!(test Value::Str; "한글한글한글".to_owned())),
+        ),
+        (
+            "SELECT REPEAT(name, 0) AS test FROM Item",
+            Ok(select!(test Value::Str; String::new())),
+        ),
+        (
+            "SELECT REPEAT(name, -1) AS test FROM Item",
+            Err(EvaluateError::FunctionRequiresUSizeValue("REPEAT".to_owned()).into()),
+        ),
+        (
+            "SELECT REPEAT(name, 9223372036854775807) AS test FROM Item",
+            Err(EvaluateError::FunctionResultTooLong("REPEAT".to_owned()).into()),
+        ),
         (
             "SELECT REPEAT(name, null) AS test FROM Item",
             Ok(select_with_null!(test; Value::Null)),
@@ -24,6 +24,10 @@ test_case!(reverse, async move {
                 "teem s'teL".to_owned()
             )),
         ),
+        (
+            "SELECT REVERSE('한글 Ünï') AS test",
+            Ok(select!(test Value::Str; "ïnÜ 글한".to_owned())),
+        ),
         (
             "SELECT REVERSE(1) AS test FROM Item",
             Err(EvaluateError::FunctionRequiresStringValue("REVERSE".to_owned()).into()),
@@ -1,9 +1,10 @@
 use {
     crate::*,
     gluesql_core::{
-        error::{EvaluateError, TranslateError},
+        error::{EvaluateError, TranslateError, ValueError},
         prelude::Value::*,
     },
+    rust_decimal::Decimal,
 };
 
 test_case!(round, async move {
@@ -17,10 +18,39 @@ test_case!(round, async move {
             ;",
             Ok(select!(
                 round1 | round2          | round3 | round4
-                F64    | F64             | F64    | F64;
-                0.0      f64::from(-1)   10.0     7.0
+                F64    | F64             | I64    | F64;
+                0.0      f64::from(-1)   10       7.0
             )),
         ),
+        (
+            "SELECT
+                ROUND(6.87421, 2) AS round1,
+                ROUND(1250, -2) AS round2,
+                ROUND(-2.5, 0) AS round3,
+                ROUND(CAST(1.2345 AS DECIMAL), 3) AS round4
+            ;",
+            Ok(select!(
+                round1 | round2 | round3 | round4
+                F64    | I64    | F64    | Decimal;
+                6.87     1300     -3.0     Decimal::new(1235, 3)
+            )),
+        ),
+        (
+            "SELECT ROUND(1.5, NULL) AS round",
+            Ok(select_with_null!(round; Null)),
+        ),
+        (
+            "SELECT ROUND(1.5, 'a') AS round",
+            Err(EvaluateError::FunctionRequiresIntegerValue(String::from("ROUND")).into()),
+        ),
+        (
+            "SELECT ROUND(CAST(125 AS INT8), -1) AS round",
+            Err(ValueError::RoundOverflow {
+                value: I8(125),
+                scale: -1,
+            }
+            .into()),
+        ),
         (
             "SELECT ROUND('string') AS round",
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("ROUND")).into()),
@@ -38,11 +68,12 @@ test_case!(round, async move {
             Err(EvaluateError::FunctionRequiresFloatValue(String::from("ROUND")).into()),
         ),
         (
-            "SELECT ROUND('string', 'string2') AS round",
-            Err(TranslateError::FunctionArgsLengthNotMatching {
+            "SELECT ROUND(1.5, 1, 1) AS round",
+            Err(TranslateError::FunctionArgsLengthNotWithinRange {
                 name: "ROUND".to_owned(),
-                expected: 1,
-                found: 2,
+                expected_minimum: 1,
+                expected_maximum: 2,
+                found: 3,
             }
             .into()),
         ),
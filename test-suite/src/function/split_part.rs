@@ -0,0 +1,116 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(split_part, async move {
+    run!("CREATE TABLE Item (id INTEGER, path TEXT NULL)");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 'usr/local/bin'),
+            (2, 'home'),
+            (3, NULL);
+    "
+    );
+
+    test!(
+        "SELECT SPLIT_PART(path, '/', 1) AS a, SPLIT_PART(path, '/', 3) AS b FROM Item",
+        Ok(select_with_null!(
+            a                      | b;
+            Str("usr".to_owned())    Str("bin".to_owned());
+            Str("home".to_owned())   Str(String::new());
+            Null                     Null
+        ))
+    );
+    test!(
+        "SELECT SPLIT_PART(path, '/', -1) AS tail, SPLIT_PART(path, '/', -4) AS missing FROM Item WHERE id = 1",
+        Ok(select!(
+            tail             | missing
+            Str              | Str;
+            "bin".to_owned()   String::new()
+        ))
+    );
+    test!(
+        "SELECT
+            SPLIT_PART('가,나,다', ',', 2) AS a,
+            SPLIT_PART('abc', '', 3) AS b,
+            SPLIT_PART('a::b', '::', 2) AS c,
+            SPLIT_PART('', ',', 1) AS d
+        ",
+        Ok(select!(
+            a              | b              | c              | d
+            Str            | Str            | Str            | Str;
+            "나".to_owned()  "c".to_owned()   "b".to_owned()   String::new()
+        ))
+    );
+    test!(
+        "SELECT SPLIT_PART('a,b', NULL, 1) AS a, SPLIT_PART('a,b', ',', NULL) AS b",
+        Ok(select_with_null!(
+            a    | b;
+            Null   Null
+        ))
+    );
+    test!(
+        "SELECT SPLIT_PART('a,b', ',', 0) AS a",
+        Err(EvaluateError::FunctionRequiresNonZeroIntegerValue("SPLIT_PART".to_owned()).into())
+    );
+    test!(
+        "SELECT SPLIT_PART('a,b', ',', '1') AS a",
+        Err(EvaluateError::FunctionRequiresIntegerValue("SPLIT_PART".to_owned()).into())
+    );
+    test!(
+        "SELECT SPLIT_PART(1, ',', 1) AS a",
+        Err(EvaluateError::FunctionRequiresStringValue("SPLIT_PART".to_owned()).into())
+    );
+    test!(
+        "SELECT SPLIT_PART('a,b', ',') AS a",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "SPLIT_PART".to_owned(),
+            expected: 3,
+            found: 2,
+        }
+        .into())
+    );
+
+    test!(
+        "SELECT STRING_TO_ARRAY(path, '/') AS parts FROM Item",
+        Ok(select_with_null!(
+            parts;
+            List(vec![
+                Str("usr".to_owned()),
+                Str("local".to_owned()),
+                Str("bin".to_owned())
+            ]);
+            List(vec![Str("home".to_owned())]);
+            Null
+        ))
+    );
+    test!(
+        "SELECT
+            STRING_TO_ARRAY('한글', '') AS a,
+            STRING_TO_ARRAY('ab', NULL) AS b,
+            STRING_TO_ARRAY('', ',') AS c,
+            STRING_TO_ARRAY('a,,b', ',') AS d
+        ",
+        Ok(select!(
+            a    | b    | c    | d
+            List | List | List | List;
+            vec![Str("한".to_owned()), Str("글".to_owned())]
+            vec![Str("a".to_owned()), Str("b".to_owned())]
+            vec![]
+            vec![Str("a".to_owned()), Str(String::new()), Str("b".to_owned())]
+        ))
+    );
+    test!(
+        "SELECT STRING_TO_ARRAY(NULL, ',') AS a",
+        Ok(select_with_null!(a; Null))
+    );
+    test!(
+        "SELECT STRING_TO_ARRAY('a,b', 1) AS a",
+        Err(EvaluateError::FunctionRequiresStringValue("STRING_TO_ARRAY".to_owned()).into())
+    );
+});
@@ -1,9 +1,11 @@
 use {
     crate::*,
     gluesql_core::{
+        data::NumericBinaryOperator,
         error::{EvaluateError, ValueError},
         prelude::Value::*,
     },
+    rust_decimal::prelude::Decimal,
 };
 
 test_case!(sqrt, async move {
@@ -39,6 +41,18 @@ test_case!(sqrt, async move {
             "SELECT SQRT('string') AS sqrt",
             Err(ValueError::SqrtOnNonNumeric(Str("string".to_owned())).into()),
         ),
+        (
+            "SELECT SQRT(-4) AS sqrt",
+            Err(ValueError::NegativeSqrt(I64(-4)).into()),
+        ),
+        (
+            "SELECT SQRT(CAST(4 AS DECIMAL)) AS sqrt",
+            Ok(select!(
+                sqrt
+                Decimal;
+                Decimal::TWO
+            )),
+        ),
         (
             "SELECT SQRT(NULL) AS sqrt",
             Ok(select_with_null!(sqrt; Null)),
@@ -70,10 +84,39 @@ test_case!(power, async move {
             ;",
             Ok(select!(
                 power_with_zero | power_to_zero;
-                F64             | F64;
-                0.0               1.0
+                I64             | I64;
+                0                 1
+            )),
+        ),
+        (
+            "SELECT
+                POW(2, 10) AS pow,
+                POWER(CAST(2 AS INT8), 10) AS widened,
+                POWER(2, -1) AS negative
+            ;",
+            Ok(select!(
+                pow  | widened | negative;
+                I64  | I16     | F64;
+                1024   1024      0.5
             )),
         ),
+        (
+            "SELECT POWER(CAST(1.5 AS DECIMAL), 2) AS power_decimal",
+            Ok(select!(
+                power_decimal
+                Decimal;
+                Decimal::new(225, 2)
+            )),
+        ),
+        (
+            "SELECT POWER(2, 200) AS power",
+            Err(ValueError::BinaryOperationOverflow {
+                lhs: I64(2),
+                rhs: I64(200),
+                operator: NumericBinaryOperator::Power,
+            }
+            .into()),
+        ),
         (
             "SELECT POWER(32,3.0) as power_with_float",
             Ok(select!(
@@ -0,0 +1,74 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(strpos_locate, async move {
+    run!("CREATE TABLE Food (name TEXT NULL)");
+    run!("INSERT INTO Food VALUES ('cheese burger'), ('김치찌개'), (NULL)");
+
+    test!(
+        "SELECT STRPOS(name, 'e') AS s, LOCATE('e', name) AS l FROM Food",
+        Ok(select_with_null!(
+            s      | l;
+            I64(3)   I64(3);
+            I64(0)   I64(0);
+            Null     Null
+        ))
+    );
+    test!(
+        "SELECT LOCATE('e', name, 4) AS l FROM Food",
+        Ok(select_with_null!(
+            l;
+            I64(4);
+            I64(0);
+            Null
+        ))
+    );
+    test!(
+        "SELECT
+            POSITION('찌개' IN '김치찌개') AS p,
+            STRPOS('김치찌개', '치') AS s,
+            LOCATE('개', '김치찌개', 3) AS l,
+            LOCATE('김', '김치찌개', 10) AS o
+        ",
+        Ok(select!(
+            p   | s   | l   | o
+            I64 | I64 | I64 | I64;
+            3     2     4     0
+        ))
+    );
+    test!(
+        "SELECT STRPOS('cheese', NULL) AS s, LOCATE(NULL, 'cheese') AS l",
+        Ok(select_with_null!(
+            s    | l;
+            Null   Null
+        ))
+    );
+    test!(
+        "SELECT STRPOS('cheese') AS s",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "STRPOS".to_owned(),
+            expected: 2,
+            found: 1,
+        }
+        .into())
+    );
+    test!(
+        "SELECT LOCATE('e', 'cheese', 1, 2) AS l",
+        Err(TranslateError::FunctionArgsLengthNotWithinRange {
+            name: "LOCATE".to_owned(),
+            expected_minimum: 2,
+            expected_maximum: 3,
+            found: 4,
+        }
+        .into())
+    );
+    test!(
+        "SELECT LOCATE(1, 'cheese') AS l",
+        Err(EvaluateError::FunctionRequiresStringValue("FIND_IDX".to_owned()).into())
+    );
+});
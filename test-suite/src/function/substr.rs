@@ -1,6 +1,7 @@
 use {
     crate::*,
     gluesql_core::{
+        ast::DataType,
         error::EvaluateError,
         prelude::{Payload, Value::*},
     },
@@ -248,10 +249,11 @@ test_case!(substr, async move {
         ),
         (
             r#"SELECT SUBSTR('123', 2, 3) - '3' AS test FROM SingleItem"#,
-            Err(EvaluateError::UnsupportedBinaryArithmetic(
-                "StrSlice { source: \"123\", range: 1..3 }".to_owned(),
-                "Literal(Text(\"3\"))".to_owned(),
-            )
+            Err(EvaluateError::TypeMismatch {
+                left_type: DataType::Text,
+                right_type: DataType::Text,
+                operation: "-".to_owned(),
+            }
             .into()),
         ),
         (
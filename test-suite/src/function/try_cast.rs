@@ -0,0 +1,107 @@
+use {
+    crate::*,
+    gluesql_core::{error::ValueError, prelude::Value::*},
+};
+
+test_case!(try_cast, async move {
+    run!(
+        "
+        CREATE TABLE Raw (
+            id INTEGER,
+            num INTEGER,
+            raw TEXT,
+            dt DATE,
+            items LIST,
+            attrs MAP
+        );
+    "
+    );
+    run!(
+        r#"
+        INSERT INTO Raw VALUES
+            (1, 300, '42', '2023-01-01', '[1, 2]', '{"a": 1}'),
+            (2, -1, 'forty-two', '2023-01-02', '[]', '{}');
+    "#
+    );
+
+    test!(
+        "SELECT id, TRY_CAST(raw AS INTEGER) AS n FROM Raw",
+        Ok(select_with_null!(
+            id     | n;
+            I64(1)   I64(42);
+            I64(2)   Null
+        ))
+    );
+    test!(
+        "SELECT TRY_CAST('1' AS INTEGER) AS a, TRY_CAST(NULL AS INTEGER) AS b",
+        Ok(select_with_null!(
+            a      | b;
+            I64(1)   Null
+        ))
+    );
+
+    // text literals which can not be parsed as the target type
+    test!(
+        "SELECT
+            TRY_CAST('abc' AS INTEGER) AS a,
+            TRY_CAST('abc' AS FLOAT) AS b,
+            TRY_CAST('abc' AS DECIMAL) AS c,
+            TRY_CAST('abc' AS BOOLEAN) AS d,
+            TRY_CAST('abc' AS DATE) AS e,
+            TRY_CAST('abc' AS TIMESTAMP) AS f,
+            TRY_CAST('abc' AS TIME) AS g,
+            TRY_CAST('abc' AS UUID) AS h,
+            TRY_CAST('abc' AS INET) AS i,
+            TRY_CAST('abc' AS INTERVAL) AS j
+        ",
+        Ok(select_with_null!(
+            a    | b    | c    | d    | e    | f    | g    | h    | i    | j;
+            Null   Null   Null   Null   Null   Null   Null   Null   Null   Null
+        ))
+    );
+
+    // literals out of range or of an incompatible type
+    test!(
+        "SELECT
+            TRY_CAST(300 AS INT8) AS a,
+            TRY_CAST(-1 AS UINT8) AS b,
+            TRY_CAST(1.5 AS INTEGER) AS c,
+            TRY_CAST(TRUE AS DATE) AS d
+        ",
+        Ok(select_with_null!(
+            a    | b    | c    | d;
+            Null   Null   Null   Null
+        ))
+    );
+
+    // stored values which can not be cast
+    test!(
+        "SELECT
+            id,
+            TRY_CAST(num AS INT8) AS small,
+            TRY_CAST(num AS UINT8) AS tiny,
+            TRY_CAST(dt AS DECIMAL) AS amount,
+            TRY_CAST(items AS INTEGER) AS from_list,
+            TRY_CAST(attrs AS INTEGER) AS from_map
+        FROM Raw",
+        Ok(select_with_null!(
+            id     | small  | tiny     | amount | from_list | from_map;
+            I64(1)   Null     Null       Null     Null        Null;
+            I64(2)   I8(-1)   Null       Null     Null        Null
+        ))
+    );
+
+    // errors raised while evaluating the argument are not swallowed
+    test!(
+        "SELECT TRY_CAST(id / 0 AS TEXT) AS t FROM Raw",
+        Err(ValueError::DivisorShouldNotBeZero.into())
+    );
+    test!(
+        "SELECT CAST(dt AS DECIMAL) AS amount FROM Raw WHERE id = 1",
+        Err(ValueError::CastFailed {
+            from: Date(chrono::NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()),
+            to: gluesql_core::ast::DataType::Decimal,
+        }
+        .into())
+    );
+});
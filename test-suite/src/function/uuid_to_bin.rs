@@ -0,0 +1,95 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError, ValueError},
+        prelude::{Payload, Value::*},
+    },
+    uuid::Uuid as UUID,
+};
+
+test_case!(uuid_to_bin, async move {
+    let parse_uuid = |v| UUID::parse_str(v).unwrap().as_u128();
+    let decode = |v| hex::decode(v).unwrap();
+
+    let test_cases = [
+        (
+            "CREATE TABLE UuidBin (id UUID, bin BYTEA)",
+            Ok(Payload::Create),
+        ),
+        (
+            "INSERT INTO UuidBin VALUES
+                ('6ccd780c-baba-1026-9564-5b8c656024db', UUID_TO_BIN('6ccd780c-baba-1026-9564-5b8c656024db')),
+                ('0e2b8e5e-9c4e-11ed-a8fc-0242ac120002', UUID_TO_BIN('0e2b8e5e-9c4e-11ed-a8fc-0242ac120002', 1))",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "SELECT bin FROM UuidBin",
+            Ok(select!(
+                bin
+                Bytea;
+                decode("6ccd780cbaba102695645b8c656024db");
+                decode("11ed9c4e0e2b8e5ea8fc0242ac120002")
+            )),
+        ),
+        (
+            "SELECT UUID_TO_BIN(id) AS bin, UUID_TO_BIN(id, TRUE) AS swapped FROM UuidBin WHERE id = '6ccd780c-baba-1026-9564-5b8c656024db'",
+            Ok(select!(
+                bin                                        | swapped
+                Bytea                                      | Bytea;
+                decode("6ccd780cbaba102695645b8c656024db")   decode("1026baba6ccd780c95645b8c656024db")
+            )),
+        ),
+        (
+            "SELECT BIN_TO_UUID(bin) AS a, BIN_TO_UUID(bin, 1) AS b FROM UuidBin",
+            Ok(select!(
+                a                                                | b
+                Str                                              | Str;
+                "6ccd780c-baba-1026-9564-5b8c656024db".to_owned()  "baba1026-780c-6ccd-9564-5b8c656024db".to_owned();
+                "11ed9c4e-0e2b-8e5e-a8fc-0242ac120002".to_owned()  "0e2b8e5e-9c4e-11ed-a8fc-0242ac120002".to_owned()
+            )),
+        ),
+        (
+            "SELECT id FROM UuidBin ORDER BY id",
+            Ok(select!(
+                id
+                Uuid;
+                parse_uuid("0e2b8e5e-9c4e-11ed-a8fc-0242ac120002");
+                parse_uuid("6ccd780c-baba-1026-9564-5b8c656024db")
+            )),
+        ),
+        (
+            "SELECT UUID_TO_BIN(NULL) AS a, BIN_TO_UUID(NULL) AS b",
+            Ok(select_with_null!(a | b; Null Null)),
+        ),
+        (
+            "SELECT UUID_TO_BIN(1) FROM UuidBin",
+            Err(EvaluateError::FunctionRequiresUuidValue("UUID_TO_BIN".to_owned()).into()),
+        ),
+        (
+            "SELECT UUID_TO_BIN('NOT_UUID') FROM UuidBin",
+            Err(ValueError::FailedToParseUUID("NOT_UUID".to_owned()).into()),
+        ),
+        (
+            "SELECT BIN_TO_UUID(X'1234') FROM UuidBin",
+            Err(EvaluateError::FunctionRequiresBinaryUuidValue("BIN_TO_UUID".to_owned()).into()),
+        ),
+        (
+            "SELECT BIN_TO_UUID(bin, 'yes') FROM UuidBin",
+            Err(EvaluateError::FunctionRequiresIntegerValue("BIN_TO_UUID".to_owned()).into()),
+        ),
+        (
+            "SELECT UUID_TO_BIN(id, 1, 2) FROM UuidBin",
+            Err(TranslateError::FunctionArgsLengthNotWithinRange {
+                name: "UUID_TO_BIN".to_owned(),
+                expected_minimum: 1,
+                expected_maximum: 2,
+                found: 3,
+            }
+            .into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
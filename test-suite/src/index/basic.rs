@@ -289,6 +289,11 @@ CREATE TABLE Test (
         Err(TranslateError::CompositeIndexNotSupported.into())
     );
 
+    test!(
+        "CREATE INDEX idx_nulls ON Test (num NULLS FIRST)",
+        Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into())
+    );
+
     test!(
         "DROP INDEX Test.idx_id, Test.idx_id2",
         Err(TranslateError::TooManyParamsInDropIndex.into())
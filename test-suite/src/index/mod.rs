@@ -4,7 +4,9 @@ mod expr;
 mod nested;
 mod null;
 mod order_by;
+mod range;
 mod showindexes;
+mod unique;
 mod value;
 
 pub use {
@@ -14,6 +16,8 @@ pub use {
     nested::nested,
     null::null,
     order_by::{order_by, order_by_multi},
+    range::range,
     showindexes::showindexes,
+    unique::unique,
     value::value,
 };
@@ -0,0 +1,80 @@
+use {
+    crate::*,
+    gluesql_core::{ast::IndexOperator::*, prelude::*},
+    Value::*,
+};
+
+test_case!(range, async move {
+    run!("CREATE TABLE IdxRange (id INTEGER, name TEXT)");
+    run!(
+        "
+        INSERT INTO IdxRange VALUES
+            (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e'),
+            (6, 'f'), (7, 'g'), (8, 'h'), (9, 'i'), (10, 'j');
+    "
+    );
+
+    let sql = "SELECT id FROM IdxRange WHERE id > 7";
+    let (_, profile) = get_glue!().execute_with_profile(sql).await.unwrap();
+    assert_eq!(profile.scan.rows, 10, "full scan without the index");
+
+    test!(
+        "CREATE INDEX idx_id ON IdxRange (id)",
+        Ok(Payload::CreateIndex)
+    );
+
+    test_idx!(Ok(select!(id I64; 8; 9; 10)), idx!(idx_id, Gt, "7"), sql);
+    let (_, profile) = get_glue!().execute_with_profile(sql).await.unwrap();
+    assert_eq!(
+        profile.scan.rows, 3,
+        "index scan visits the matching rows only"
+    );
+
+    let sql = "SELECT id FROM IdxRange WHERE id <= 2";
+    test_idx!(Ok(select!(id I64; 1; 2)), idx!(idx_id, LtEq, "2"), sql);
+    let (_, profile) = get_glue!().execute_with_profile(sql).await.unwrap();
+    assert_eq!(profile.scan.rows, 2);
+
+    run!("INSERT INTO IdxRange VALUES (11, 'k'), (0, 'z')");
+    test_idx!(
+        Ok(select!(id I64; 8; 9; 10; 11)),
+        idx!(idx_id, Gt, "7"),
+        "SELECT id FROM IdxRange WHERE id > 7"
+    );
+    test_idx!(
+        Ok(select!(id I64; 0; 1)),
+        idx!(idx_id, Lt, "2"),
+        "SELECT id FROM IdxRange WHERE id < 2"
+    );
+
+    run!("UPDATE IdxRange SET id = id + 100 WHERE id = 8");
+    test_idx!(
+        Ok(select!(id I64; 9; 10; 11; 108)),
+        idx!(idx_id, GtEq, "9"),
+        "SELECT id FROM IdxRange WHERE id >= 9"
+    );
+    test_idx!(
+        Ok(select!(id | name I64 | Str; 108 "h".to_owned())),
+        idx!(idx_id, Eq, "108"),
+        "SELECT id, name FROM IdxRange WHERE id = 108"
+    );
+    test_idx!(
+        Ok(select!(id | name)),
+        idx!(idx_id, Eq, "8"),
+        "SELECT id, name FROM IdxRange WHERE id = 8"
+    );
+
+    run!("DELETE FROM IdxRange WHERE id > 9");
+    test_idx!(
+        Ok(select!(id I64; 9)),
+        idx!(idx_id, Gt, "7"),
+        "SELECT id FROM IdxRange WHERE id > 7"
+    );
+
+    run!("DROP INDEX IdxRange.idx_id");
+    test_idx!(
+        Ok(select!(id I64; 9)),
+        idx!(),
+        "SELECT id FROM IdxRange WHERE id > 7"
+    );
+});
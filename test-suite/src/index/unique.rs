@@ -0,0 +1,116 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{TranslateError, ValidateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(unique, async move {
+    run!("CREATE TABLE Account (id INTEGER, email TEXT, team TEXT, num INTEGER)");
+    run!(
+        "
+        INSERT INTO Account VALUES
+            (1, 'a@x', 'red', 1),
+            (2, 'b@x', 'red', 2),
+            (3, 'c@x', 'blue', 1);
+    "
+    );
+
+    test!(
+        "CREATE UNIQUE INDEX idx_team ON Account (team)",
+        Err(ValidateError::UniqueConstraintViolation {
+            index: "idx_team".to_owned(),
+            value: Str("red".to_owned()),
+        }
+        .into())
+    );
+    test!(
+        "CREATE UNIQUE INDEX idx_email ON Account (email)",
+        Ok(Payload::CreateIndex)
+    );
+    test!(
+        "CREATE UNIQUE INDEX idx_team_num ON Account (team, num)",
+        Ok(Payload::CreateIndex)
+    );
+    test!(
+        "CREATE INDEX idx_com ON Account (team, num)",
+        Err(TranslateError::CompositeIndexNotSupported.into())
+    );
+
+    test!(
+        "INSERT INTO Account VALUES (4, 'a@x', 'blue', 2)",
+        Err(ValidateError::UniqueConstraintViolation {
+            index: "idx_email".to_owned(),
+            value: Str("a@x".to_owned()),
+        }
+        .into())
+    );
+    test!(
+        "INSERT INTO Account VALUES (4, 'd@x', 'green', 1), (5, 'e@x', 'green', 1)",
+        Err(ValidateError::UniqueConstraintViolation {
+            index: "idx_team_num".to_owned(),
+            value: List(vec![Str("green".to_owned()), I64(1)]),
+        }
+        .into())
+    );
+    test!(
+        "INSERT INTO Account VALUES (4, 'd@x', 'red', 3), (5, NULL, 'red', NULL), (6, NULL, 'red', NULL)",
+        Ok(Payload::Insert(3))
+    );
+
+    test!(
+        "UPDATE Account SET email = 'b@x' WHERE id = 1",
+        Err(ValidateError::UniqueConstraintViolation {
+            index: "idx_email".to_owned(),
+            value: Str("b@x".to_owned()),
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Account SET num = num + 1 WHERE team = 'red' AND num IS NOT NULL",
+        Ok(Payload::Update(3))
+    );
+    test!(
+        "UPDATE Account SET team = 'red', num = 2 WHERE id = 3",
+        Err(ValidateError::UniqueConstraintViolation {
+            index: "idx_team_num".to_owned(),
+            value: List(vec![Str("red".to_owned()), I64(2)]),
+        }
+        .into())
+    );
+
+    test!(
+        "INSERT INTO Account VALUES (7, 'a@x', 'green', 1), (8, 'f@x', 'blue', 1), (9, 'g@x', 'blue', 5) ON CONFLICT DO NOTHING",
+        Ok(Payload::Insert(1))
+    );
+    test!(
+        "SELECT id, email, team, num FROM Account ORDER BY id",
+        Ok(select_with_null!(
+            id     | email                | team                    | num;
+            I64(1)   Str("a@x".to_owned())  Str("red".to_owned())    I64(2);
+            I64(2)   Str("b@x".to_owned())  Str("red".to_owned())    I64(3);
+            I64(3)   Str("c@x".to_owned())  Str("blue".to_owned())   I64(1);
+            I64(4)   Str("d@x".to_owned())  Str("red".to_owned())    I64(4);
+            I64(5)   Null                   Str("red".to_owned())    Null;
+            I64(6)   Null                   Str("red".to_owned())    Null;
+            I64(9)   Str("g@x".to_owned())  Str("blue".to_owned())   I64(5)
+        ))
+    );
+
+    test!(
+        "SHOW INDEXES FROM Account",
+        Ok(select!(
+            TABLE_NAME           | INDEX_NAME                | ORDER             | EXPRESSION               | UNIQUENESS;
+            Str                  | Str                       | Str               | Str                      | Bool;
+            "Account".to_owned()   "idx_email".to_owned()      "BOTH".to_owned()   "email".to_owned()         true;
+            "Account".to_owned()   "idx_team_num".to_owned()   "BOTH".to_owned()   "(team, num)".to_owned()   true
+        ))
+    );
+
+    run!("DROP INDEX Account.idx_email");
+    test!(
+        "INSERT INTO Account VALUES (10, 'a@x', 'green', 1)",
+        Ok(Payload::Insert(1))
+    );
+});
@@ -0,0 +1,59 @@
+use {
+    crate::*,
+    gluesql_core::{
+        data::Row,
+        error::ExecuteError,
+        prelude::{Error, Value::*},
+    },
+};
+
+test_case!(execute_iter, async move {
+    run!("CREATE TABLE Item (id INTEGER, name TEXT);");
+    run!("INSERT INTO Item VALUES (1, 'a'), (2, 'b'), (3, 'c');");
+
+    let glue = get_glue!();
+
+    let rows = glue
+        .execute_iter("SELECT id, name FROM Item WHERE id > 1")
+        .await
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        rows.iter()
+            .map(|row| row.get_value("name"))
+            .collect::<Vec<_>>(),
+        vec![Some(&Str("b".to_owned())), Some(&Str("c".to_owned()))]
+    );
+
+    let ids = glue
+        .execute_iter("SELECT id FROM Item")
+        .await
+        .unwrap()
+        .map(|row| row.and_then(Row::try_into_vec))
+        .take(2)
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(ids, Ok(vec![vec![I64(1)], vec![I64(2)]]));
+
+    let mut rows = glue
+        .execute_iter("SELECT id / (id - 2) FROM Item")
+        .await
+        .unwrap();
+    assert_eq!(
+        rows.next().map(|row| row.and_then(Row::try_into_vec)),
+        Some(Ok(vec![I64(-1)]))
+    );
+    assert!(
+        matches!(rows.next(), Some(Err(_))),
+        "error is propagated per row"
+    );
+
+    assert_eq!(
+        glue.execute_iter("DELETE FROM Item").await.err(),
+        Some(ExecuteError::QueryRequiredForIterator.into())
+    );
+    assert!(matches!(
+        glue.execute_iter("SELECT 1; SELECT 2;").await.err(),
+        Some(Error::Parser(_))
+    ));
+});
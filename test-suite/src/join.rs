@@ -262,8 +262,8 @@ test_case!(project, async move {
 
     let error_cases = [
         (
-            "SELECT * FROM TableA JOIN TableA USING (id);",
-            TranslateError::UnsupportedJoinConstraint("USING".to_owned()).into(),
+            "SELECT * FROM Users JOIN Testers USING (name);",
+            PlanError::UsingColumnNotFound("name".to_owned()).into(),
         ),
         (
             "SELECT * FROM TableA CROSS JOIN TableA as A;",
@@ -271,20 +271,36 @@ test_case!(project, async move {
         ),
         (
             "SELECT id FROM Users JOIN Testers ON Users.id = Testers.id;",
-            PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
+            PlanError::ColumnReferenceAmbiguous {
+                column_name: "id".to_owned(),
+                tables: vec!["Users".to_owned(), "Testers".to_owned()],
+            }
+            .into(),
         ),
         (
             // Ambiguous column should return error even with identical table join
             "SELECT id FROM Users A JOIN Users B on A.id = B.id",
-            PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
+            PlanError::ColumnReferenceAmbiguous {
+                column_name: "id".to_owned(),
+                tables: vec!["A".to_owned(), "B".to_owned()],
+            }
+            .into(),
         ),
         (
             "INSERT INTO Users SELECT id FROM Users A JOIN Users B on A.id = B.id",
-            PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
+            PlanError::ColumnReferenceAmbiguous {
+                column_name: "id".to_owned(),
+                tables: vec!["A".to_owned(), "B".to_owned()],
+            }
+            .into(),
         ),
         (
             "CREATE TABLE Ids AS SELECT id FROM Users A JOIN Users B on A.id = B.id",
-            PlanError::ColumnReferenceAmbiguous("id".to_owned()).into(),
+            PlanError::ColumnReferenceAmbiguous {
+                column_name: "id".to_owned(),
+                tables: vec!["A".to_owned(), "B".to_owned()],
+            }
+            .into(),
         ),
         (
             "SELECT * FROM ProjectUser, ProjectItem",
@@ -296,3 +312,305 @@ test_case!(project, async move {
         test!(sql, Err(error));
     }
 });
+
+test_case!(type_coercion, async move {
+    run!("CREATE TABLE NarrowKey (id INT32, name TEXT);");
+    run!("CREATE TABLE WideKey (id INT64, amount INTEGER);");
+    run!("CREATE TABLE FloatKey (id FLOAT, label TEXT);");
+    run!("INSERT INTO NarrowKey VALUES (1, 'a'), (2, 'b'), (3, 'c');");
+    run!("INSERT INTO WideKey VALUES (1, 10), (3, 30), (4, 40);");
+    run!("INSERT INTO FloatKey VALUES (1.0, 'one'), (2.5, 'two and a half'), (3.0, 'three');");
+    run!("INSERT INTO FloatKey VALUES (1.5, 'one and a half');");
+    run!("CREATE TABLE DecimalKey (id DECIMAL, note TEXT);");
+    run!("INSERT INTO DecimalKey VALUES (1.5, 'x'), (2.50, 'y'), (3, 'z');");
+    run!("CREATE TABLE DateKey (day DATE);");
+    run!("CREATE TABLE TimestampKey (at TIMESTAMP);");
+    run!("CREATE TABLE TimestampTzKey (at TIMESTAMPTZ);");
+    run!("INSERT INTO DateKey VALUES ('2023-01-01');");
+    run!("INSERT INTO TimestampKey VALUES ('2023-01-01 00:00:00');");
+    run!("INSERT INTO TimestampTzKey VALUES ('2023-01-01T00:00:00Z'), ('2023-01-01T00:00:00+09:00');");
+
+    let date = |v: &str| Date(v.parse().unwrap());
+    let timestamp = |v: &str| Timestamp(v.parse().unwrap());
+    let timestamptz = |v: &str| TimestampTz(v.parse().unwrap());
+
+    let test_cases = [
+        (
+            "SELECT n.id, w.amount FROM NarrowKey n JOIN WideKey w ON n.id = w.id",
+            select!(
+                id  | amount
+                I32 | I64;
+                1     10;
+                3     30
+            ),
+        ),
+        (
+            "SELECT n.name, f.label FROM NarrowKey n JOIN FloatKey f ON n.id = f.id",
+            select!(
+                name              | label
+                Str               | Str;
+                "a".to_owned()      "one".to_owned();
+                "c".to_owned()      "three".to_owned()
+            ),
+        ),
+        (
+            "SELECT w.id, n.name FROM WideKey w LEFT JOIN NarrowKey n ON w.id = n.id",
+            select_with_null!(
+                id     | name;
+                I64(1)   Str("a".to_owned());
+                I64(3)   Str("c".to_owned());
+                I64(4)   Null
+            ),
+        ),
+        (
+            "SELECT d.note, f.label FROM DecimalKey d JOIN FloatKey f ON d.id = f.id",
+            select!(
+                note             | label
+                Str              | Str;
+                "x".to_owned()     "one and a half".to_owned();
+                "y".to_owned()     "two and a half".to_owned();
+                "z".to_owned()     "three".to_owned()
+            ),
+        ),
+        (
+            "SELECT d.note, n.name FROM DecimalKey d JOIN NarrowKey n ON d.id = n.id",
+            select!(
+                note             | name
+                Str              | Str;
+                "z".to_owned()     "c".to_owned()
+            ),
+        ),
+        (
+            "SELECT d.day, t.at FROM DateKey d JOIN TimestampKey t ON d.day = t.at",
+            select_with_null!(
+                day                      | at;
+                date("2023-01-01")         timestamp("2023-01-01T00:00:00")
+            ),
+        ),
+        (
+            "SELECT d.day, t.at FROM DateKey d JOIN TimestampTzKey t ON d.day = t.at",
+            select_with_null!(
+                day                      | at;
+                date("2023-01-01")         timestamptz("2023-01-01T00:00:00Z")
+            ),
+        ),
+        (
+            "SELECT s.at, t.at FROM TimestampKey s JOIN TimestampTzKey t ON s.at = t.at",
+            select_with_null!(
+                at                                 | at;
+                timestamp("2023-01-01T00:00:00")     timestamptz("2023-01-01T00:00:00Z")
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    // a date or a timestamp is the wall clock time of the session time zone against a timestamptz
+    get_glue!().set_time_zone("Asia/Seoul").unwrap();
+    test!(
+        "SELECT d.day, t.at FROM DateKey d JOIN TimestampTzKey t ON d.day = t.at",
+        Ok(select_with_null!(
+            day                      | at;
+            date("2023-01-01")         timestamptz("2023-01-01T00:00:00+09:00")
+        ))
+    );
+    test!(
+        "SELECT s.at, t.at FROM TimestampKey s JOIN TimestampTzKey t ON s.at = t.at",
+        Ok(select_with_null!(
+            at                                 | at;
+            timestamp("2023-01-01T00:00:00")     timestamptz("2023-01-01T00:00:00+09:00")
+        ))
+    );
+    get_glue!().set_time_zone("UTC").unwrap();
+});
+
+test_case!(using, async move {
+    run!("CREATE TABLE Player (player_id INTEGER, name TEXT, created_at INTEGER);");
+    run!("CREATE TABLE Item (item_id INTEGER, player_id INTEGER, created_at INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'Taehoon', 10), (2, 'Mike', 20), (3, 'Jorno', 30);");
+    run!("INSERT INTO Item VALUES (101, 1, 10), (102, 1, 11), (103, 2, 20), (104, 4, 40);");
+
+    let s = |v: &str| Str(v.to_owned());
+
+    let test_cases = [
+        (
+            "SELECT player_id, name, item_id FROM Player JOIN Item USING (player_id) ORDER BY item_id",
+            select!(
+                player_id | name                  | item_id
+                I64       | Str                   | I64;
+                1           "Taehoon".to_owned()    101;
+                1           "Taehoon".to_owned()    102;
+                2           "Mike".to_owned()       103
+            ),
+        ),
+        (
+            "SELECT * FROM Player JOIN Item USING (player_id) ORDER BY item_id",
+            select!(
+                player_id | name                  | created_at | item_id | created_at
+                I64       | Str                   | I64        | I64     | I64;
+                1           "Taehoon".to_owned()    10           101       10;
+                1           "Taehoon".to_owned()    10           102       11;
+                2           "Mike".to_owned()       20           103       20
+            ),
+        ),
+        (
+            "SELECT player_id, created_at, item_id FROM Player JOIN Item USING (player_id, created_at)",
+            select!(
+                player_id | created_at | item_id
+                I64       | I64        | I64;
+                1           10           101;
+                2           20           103
+            ),
+        ),
+        (
+            "SELECT * FROM Player NATURAL JOIN Item",
+            select!(
+                player_id | created_at | name                  | item_id
+                I64       | I64        | Str                   | I64;
+                1           10           "Taehoon".to_owned()    101;
+                2           20           "Mike".to_owned()       103
+            ),
+        ),
+        (
+            "SELECT player_id, name, item_id FROM Player NATURAL LEFT JOIN Item ORDER BY player_id",
+            select_with_null!(
+                player_id | name         | item_id;
+                I64(1)      s("Taehoon")   I64(101);
+                I64(2)      s("Mike")      I64(103);
+                I64(3)      s("Jorno")     Null
+            ),
+        ),
+        (
+            "SELECT player_id, item_id FROM Player LEFT JOIN Item USING (player_id) WHERE player_id = 3",
+            select_with_null!(
+                player_id | item_id;
+                I64(3)      Null
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT * FROM Player JOIN Item USING (item_id)",
+        Err(PlanError::UsingColumnNotFound("item_id".to_owned()).into())
+    );
+});
+
+test_case!(full_outer, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Item (id INTEGER, player_id INTEGER, amount INTEGER);");
+    run!("CREATE TABLE Owner (id INTEGER, pet TEXT);");
+    run!("CREATE TABLE Empty (id INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'Taehoon'), (2, 'Mike'), (3, 'Jorno');");
+    run!("INSERT INTO Item VALUES (101, 1, 10), (102, 1, 20), (103, 4, 30);");
+    run!("INSERT INTO Owner VALUES (2, 'Cat'), (5, 'Dog');");
+
+    let s = |v: &str| Str(v.to_owned());
+
+    let test_cases = [
+        (
+            "SELECT Player.name, Item.id FROM Player FULL OUTER JOIN Item ON Player.id = Item.player_id",
+            select_with_null!(
+                name         | id;
+                s("Taehoon")   I64(101);
+                s("Taehoon")   I64(102);
+                s("Mike")      Null;
+                s("Jorno")     Null;
+                Null           I64(103)
+            ),
+        ),
+        (
+            "SELECT Player.id, Item.id FROM Player FULL JOIN Item ON Player.id = Item.id",
+            select_with_null!(
+                id     | id;
+                I64(1)   Null;
+                I64(2)   Null;
+                I64(3)   Null;
+                Null     I64(101);
+                Null     I64(102);
+                Null     I64(103)
+            ),
+        ),
+        (
+            "SELECT Player.id, Item.id FROM Player FULL JOIN Item ON Player.id = Item.id - 100",
+            select_with_null!(
+                id     | id;
+                I64(1)   I64(101);
+                I64(2)   I64(102);
+                I64(3)   I64(103)
+            ),
+        ),
+        (
+            "SELECT Player.name, Item.id
+            FROM Player
+            FULL JOIN Item ON Player.id = Item.player_id AND Item.amount > Player.id * 15",
+            select_with_null!(
+                name         | id;
+                s("Taehoon")   I64(102);
+                s("Mike")      Null;
+                s("Jorno")     Null;
+                Null           I64(101);
+                Null           I64(103)
+            ),
+        ),
+        (
+            "SELECT Player.name, Item.id
+            FROM Player
+            FULL JOIN Item ON Player.id = Item.player_id
+            WHERE Player.id IS NULL",
+            select_with_null!(
+                name | id;
+                Null   I64(103)
+            ),
+        ),
+        (
+            "SELECT Empty.id, Player.id FROM Empty FULL JOIN Player ON Empty.id = Player.id",
+            select_with_null!(
+                id   | id;
+                Null   I64(1);
+                Null   I64(2);
+                Null   I64(3)
+            ),
+        ),
+        (
+            "SELECT Player.name, Owner.pet, Item.id
+            FROM Player
+            JOIN Owner ON Player.id = Owner.id
+            FULL JOIN Item ON Item.player_id = Player.id",
+            select_with_null!(
+                name      | pet      | id;
+                s("Mike")   s("Cat")   Null;
+                Null        Null       I64(101);
+                Null        Null       I64(102);
+                Null        Null       I64(103)
+            ),
+        ),
+        (
+            "SELECT * FROM Player NATURAL FULL JOIN Owner",
+            select_with_null!(
+                id     | name         | pet;
+                I64(1)   s("Taehoon")   Null;
+                I64(2)   s("Mike")      s("Cat");
+                I64(3)   s("Jorno")     Null;
+                I64(5)   Null           s("Dog")
+            ),
+        ),
+        (
+            "SELECT id, pet FROM Player FULL JOIN Owner USING (id) WHERE id > 2",
+            select_with_null!(
+                id     | pet;
+                I64(3)   Null;
+                I64(5)   s("Dog")
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+});
@@ -0,0 +1,118 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(lateral_join, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Item (id INTEGER, player_id INTEGER, amount INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'Taehoon'), (2, 'Mike'), (3, 'Jorno');");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (101, 1, 10),
+            (102, 1, 30),
+            (103, 2, 20),
+            (104, 1, 20);
+    "
+    );
+
+    let s = |v: &str| Str(v.to_owned());
+
+    let test_cases = [
+        (
+            "
+            SELECT Player.name, Top.amount
+            FROM Player, LATERAL (
+                SELECT amount FROM Item
+                WHERE Item.player_id = Player.id
+                ORDER BY amount DESC
+                LIMIT 2
+            ) AS Top
+            ",
+            Ok(select!(
+                name          | amount
+                Str           | I64;
+                "Taehoon".to_owned()  30;
+                "Taehoon".to_owned()  20;
+                "Mike".to_owned()     20
+            )),
+        ),
+        (
+            "
+            SELECT Player.name, Top.amount
+            FROM Player
+            JOIN LATERAL (
+                SELECT amount FROM Item
+                WHERE Item.player_id = Player.id AND Item.amount > Player.id * 10
+            ) AS Top ON TRUE
+            ",
+            Ok(select!(
+                name          | amount
+                Str           | I64;
+                "Taehoon".to_owned()  30;
+                "Taehoon".to_owned()  20
+            )),
+        ),
+        (
+            "
+            SELECT Player.name, Smallest.amount
+            FROM Player
+            LEFT JOIN LATERAL (
+                SELECT amount FROM Item
+                WHERE Item.player_id = Player.id
+                ORDER BY amount
+                LIMIT 1
+            ) AS Smallest ON TRUE
+            ",
+            Ok(select_with_null!(
+                name          | amount;
+                s("Taehoon")    I64(10);
+                s("Mike")       I64(20);
+                s("Jorno")      Null
+            )),
+        ),
+        (
+            "
+            SELECT Player.id, A.base, B.next
+            FROM Player,
+                LATERAL (SELECT Player.id * 100 AS base) AS A,
+                LATERAL (SELECT A.base + 1 AS next) AS B
+            ",
+            Ok(select!(
+                id  | base | next
+                I64 | I64  | I64;
+                1     100    101;
+                2     200    201;
+                3     300    301
+            )),
+        ),
+        (
+            "
+            SELECT Player.name, Top.amount
+            FROM Player, LATERAL (
+                SELECT amount FROM Item WHERE Item.player_id = Player.id
+            ) AS Top
+            JOIN Item ON Item.amount = Top.amount AND Item.id = 103
+            ",
+            Ok(select!(
+                name          | amount
+                Str           | I64;
+                "Taehoon".to_owned()  20;
+                "Mike".to_owned()     20
+            )),
+        ),
+        (
+            "
+            SELECT * FROM Player, (
+                SELECT amount FROM Item WHERE Item.player_id = Player.id
+            ) AS Top
+            ",
+            Err(TranslateError::TooManyTables.into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
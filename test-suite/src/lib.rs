@@ -2,37 +2,61 @@
 
 pub mod aggregate;
 pub mod alter;
+pub mod analyze;
 pub mod arithmetic;
+pub mod array_constructor;
 pub mod ast_builder;
 pub mod basic;
 pub mod case;
 pub mod column_alias;
 pub mod concat;
+pub mod cte;
 pub mod custom_function;
 pub mod data_type;
 pub mod default;
 pub mod delete;
 pub mod dictionary;
 pub mod dictionary_index;
+pub mod enum_type;
+pub mod explain;
 pub mod filter;
 pub mod function;
 pub mod index;
 pub mod inline_view;
 pub mod insert;
+pub mod iterator;
 pub mod join;
+pub mod lateral_join;
 pub mod like_ilike;
 pub mod limit;
+pub mod locking;
+pub mod logical_operator;
 pub mod metadata;
 pub mod migrate;
 pub mod nested_select;
 pub mod nullable;
+pub mod on_conflict;
+pub mod optimizer;
 pub mod order_by;
 pub mod ordering;
+pub mod prepared;
 pub mod primary_key;
+pub mod profile;
 pub mod project;
+pub mod query_builder;
+pub mod recursive_cte;
+pub mod regexp;
+pub mod row_constructor;
+pub mod row_mapping;
+pub mod schema_export;
 pub mod schemaless;
+pub mod sequence;
 pub mod series;
+pub mod set_operation;
 pub mod show_columns;
+pub mod show_create_table;
+pub mod similar_to;
+pub mod stats;
 pub mod synthesize;
 pub mod transaction;
 pub mod type_match;
@@ -40,6 +64,9 @@ pub mod unary_operator;
 pub mod update;
 pub mod validate;
 pub mod values;
+pub mod view;
+pub mod watch;
+pub mod window;
 
 pub mod tester;
 
@@ -68,14 +95,28 @@ macro_rules! generate_store_tests {
         }
         glue!(update, update::update);
         glue!(insert, insert::insert);
+        glue!(on_conflict_do_nothing, on_conflict::do_nothing);
+        glue!(on_conflict_do_update, on_conflict::do_update);
         glue!(delete, delete::delete);
         glue!(basic, basic::basic);
         glue!(aggregate_avg, aggregate::avg::avg);
         glue!(aggregate_count, aggregate::count::count);
+        glue!(
+            aggregate_count_approx_distinct,
+            aggregate::count_approx_distinct::count_approx_distinct
+        );
+        glue!(aggregate_first_last, aggregate::first_last::first_last);
         glue!(aggregate_group_by, aggregate::group_by::group_by);
+        glue!(
+            aggregate_grouping_sets,
+            aggregate::grouping_sets::grouping_sets
+        );
+        glue!(aggregate_json_agg, aggregate::json_agg::json_agg);
         glue!(aggregate_max, aggregate::max::max);
         glue!(aggregate_min, aggregate::min::min);
+        glue!(aggregate_percentile, aggregate::percentile::percentile);
         glue!(aggregate_stdev, aggregate::stdev::stdev);
+        glue!(aggregate_string_agg, aggregate::string_agg::string_agg);
         glue!(aggregate_sum, aggregate::sum::sum);
         glue!(aggregate_variance, aggregate::variance::variance);
         glue!(aggregate_error, aggregate::error::error);
@@ -89,8 +130,14 @@ macro_rules! generate_store_tests {
         glue!(default, default::default);
         glue!(limit, limit::limit);
         glue!(like_ilike, like_ilike::like_ilike);
+        glue!(regexp, regexp::regexp);
+        glue!(similar_to, similar_to::similar_to);
+        glue!(logical_operator, logical_operator::logical_operator);
         glue!(filter, filter::filter);
         glue!(inline_view, inline_view::inline_view);
+        glue!(lateral_join, lateral_join::lateral_join);
+        glue!(cte, cte::cte);
+        glue!(recursive_cte, recursive_cte::recursive_cte);
         glue!(values, values::values);
         glue!(unary_operator, unary_operator::unary_operator);
         glue!(function_upper_lower, function::upper_lower::upper_lower);
@@ -105,9 +152,14 @@ macro_rules! generate_store_tests {
         glue!(function_ltrim_rtrim, function::ltrim_rtrim::ltrim_rtrim);
         glue!(function_cast_literal, function::cast::cast_literal);
         glue!(function_cast_value, function::cast::cast_value);
+        glue!(function_try_cast, function::try_cast::try_cast);
         glue!(function_concat, function::concat::concat);
         glue!(function_concat_ws, function::concat_ws::concat_ws);
         glue!(function_ifnull, function::ifnull::ifnull);
+        glue!(
+            function_coalesce_nullif,
+            function::coalesce_nullif::coalesce_nullif
+        );
         glue!(function_math_function_asin, function::math_function::asin);
         glue!(function_math_function_acos, function::math_function::acos);
         glue!(function_math_function_atan, function::math_function::atan);
@@ -131,8 +183,18 @@ macro_rules! generate_store_tests {
         glue!(function_ascii, function::ascii::ascii);
         glue!(function_chr, function::chr::chr);
         glue!(function_mod, function::md5::md5);
+        glue!(function_octet_length, function::octet_length::octet_length);
+        glue!(
+            function_encode_decode,
+            function::encode_decode::encode_decode
+        );
         glue!(function_position, function::position::position);
         glue!(function_find_idx, function::find_idx::find_idx);
+        glue!(
+            function_strpos_locate,
+            function::strpos_locate::strpos_locate
+        );
+        glue!(function_split_part, function::split_part::split_part);
         glue!(function_geometry_get_x, function::geometry::get_x);
         glue!(function_geometry_get_y, function::geometry::get_y);
         glue!(
@@ -141,6 +203,9 @@ macro_rules! generate_store_tests {
         );
         glue!(join, join::join);
         glue!(join_project, join::project);
+        glue!(join_type_coercion, join::type_coercion);
+        glue!(join_using, join::using);
+        glue!(join_full_outer, join::full_outer);
         glue!(migrate, migrate::migrate);
         glue!(nested_select, nested_select::nested_select);
         glue!(primary_key, primary_key::primary_key);
@@ -152,6 +217,9 @@ macro_rules! generate_store_tests {
         glue!(order_by, order_by::order_by);
         glue!(sql_types, data_type::sql_types::sql_types);
         glue!(show_columns, show_columns::show_columns);
+        glue!(show_create_table, show_create_table::show_create_table);
+        glue!(analyze, analyze::analyze);
+        glue!(explain, explain::explain);
         glue!(int8, data_type::int8::int8);
         glue!(int16, data_type::int16::int16);
         glue!(int32, data_type::int32::int32);
@@ -163,18 +231,26 @@ macro_rules! generate_store_tests {
         glue!(uint64, data_type::uint64::uint64);
         glue!(uint32, data_type::uint32::uint32);
         glue!(uint128, data_type::uint128::uint128);
+        glue!(
+            integer_widening,
+            data_type::integer_widening::integer_widening
+        );
         glue!(date, data_type::date::date);
         glue!(timestamp, data_type::timestamp::timestamp);
+        glue!(timestamptz, data_type::timestamptz::timestamptz);
         glue!(time, data_type::time::time);
         glue!(interval, data_type::interval::interval);
         glue!(list, data_type::list::list);
         glue!(map, data_type::map::map);
         glue!(bytea, data_type::bytea::bytea);
         glue!(inet, data_type::inet::inet);
+        glue!(enum_type, data_type::enum_type::enum_type);
         glue!(point, data_type::point::point);
         glue!(synthesize, synthesize::synthesize);
         glue!(validate_unique, validate::unique::unique);
         glue!(validate_types, validate::types::types);
+        glue!(validate_check, validate::check::check);
+        glue!(validate_foreign_key, validate::foreign_key::foreign_key);
         glue!(function_extract, function::extract::extract);
         glue!(function_radians, function::radians::radians);
         glue!(function_degrees, function::degrees::degrees);
@@ -189,11 +265,39 @@ macro_rules! generate_store_tests {
             function_generate_uuid,
             function::generate_uuid::generate_uuid
         );
+        glue!(function_uuid_to_bin, function::uuid_to_bin::uuid_to_bin);
         glue!(type_match, type_match::type_match);
         glue!(dictionary, dictionary::dictionary);
         glue!(function_append, function::append::append);
         glue!(function_prepend, function::prepend::prepend);
+        glue!(
+            function_array_remove_replace,
+            function::array_remove_replace::array_remove_replace
+        );
+        glue!(
+            function_greatest_least,
+            function::greatest_least::greatest_least
+        );
+        glue!(function_json_extract, function::json_extract::json_extract);
         glue!(column_alias, column_alias::column_alias);
+        glue!(window_aggregate, window::aggregate::aggregate);
+        glue!(window_order_by, window::order_by::order_by);
+        glue!(window_partition_by, window::partition_by::partition_by);
+        glue!(window_lag_lead, window::lag_lead::lag_lead);
+        glue!(profile, profile::profile);
+        glue!(optimizer, optimizer::optimizer);
+        glue!(query_builder, query_builder::query_builder);
+        glue!(schema_export, schema_export::schema_export);
+        glue!(watch_table, watch::watch_table);
+        glue!(execute_iter, iterator::execute_iter);
+        glue!(row_mapping, row_mapping::row_mapping);
+        glue!(prepared, prepared::prepared);
+        glue!(row_constructor, row_constructor::row_constructor);
+        glue!(array_constructor, array_constructor::array_constructor);
+        glue!(stats, stats::stats);
+        glue!(locking, locking::locking);
+        glue!(set_operation_except, set_operation::except);
+        glue!(set_operation_intersect, set_operation::intersect);
 
         // ast-builder
         glue!(ast_builder_basic, ast_builder::basic::basic);
@@ -269,6 +373,8 @@ macro_rules! generate_alter_table_tests {
 
         glue!(alter_table_rename, alter::alter_table_rename);
         glue!(alter_table_add_drop, alter::alter_table_add_drop);
+        glue!(alter_table_alter_column, alter::alter_table_alter_column);
+        glue!(alter_table_alter_default, alter::alter_table_alter_default);
     };
 }
 
@@ -285,6 +391,46 @@ macro_rules! generate_custom_function_tests {
     };
 }
 
+#[macro_export]
+macro_rules! generate_sequence_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(sequence, sequence::sequence);
+        glue!(sequence_serial, sequence::serial);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_enum_type_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(enum_type_create_alter_drop, enum_type::enum_type);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_view_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(view, view::view);
+    };
+}
+
 #[macro_export]
 macro_rules! generate_index_tests {
     ($test: meta, $storage: ident) => {
@@ -302,6 +448,8 @@ macro_rules! generate_index_tests {
         glue!(index_value, index::value);
         glue!(index_order_by, index::order_by);
         glue!(index_order_by_multi, index::order_by_multi);
+        glue!(index_range, index::range);
+        glue!(index_unique, index::unique);
         glue!(showindexes, index::showindexes);
         glue!(dictionary_index, dictionary_index::ditionary_index);
     };
@@ -322,6 +470,9 @@ macro_rules! generate_transaction_tests {
             transaction::create_drop_table
         );
         glue!(transaction_dictionary, transaction::dictionary);
+        glue!(transaction_watch, transaction::watch);
+        glue!(transaction_read_only, transaction::read_only);
+        glue!(transaction_locking, transaction::locking);
     };
 }
 
@@ -336,6 +487,7 @@ macro_rules! generate_alter_table_index_tests {
 
         glue!(alter_table_drop_indexed_table, alter::drop_indexed_table);
         glue!(alter_table_drop_indexed_column, alter::drop_indexed_column);
+        glue!(alter_table_apply_diff, alter::apply_diff);
     };
 }
 
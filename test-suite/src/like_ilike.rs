@@ -4,7 +4,7 @@ use {
     gluesql_core::{
         data::Literal,
         error::{LiteralError, ValueError},
-        prelude::Value::{self, Bool},
+        prelude::Value::{self, Bool, Null},
     },
     std::{borrow::Cow, str::FromStr},
 };
@@ -22,6 +22,40 @@ test_case!(like_ilike, async move {
         ",
         expected: Ok(select!(column1 Bool; true; true; true; true; true))
     };
+    test! {
+        name: "NULL operands make LIKE and ILIKE return NULL",
+        sql: "SELECT NULL LIKE '%' AS a, NULL ILIKE 'a%' AS b, NULL NOT LIKE '%' AS c, '' LIKE NULL AS d",
+        expected: Ok(select_with_null!(
+            a    | b    | c    | d;
+            Null   Null   Null   Null
+        ))
+    };
+    test! {
+        name: "multi-byte characters are matched one character at a time",
+        sql: "
+            VALUES
+                ('ΣΟΦΊΑ' ILIKE 'σοφ%'),
+                ('Ünïcödé' ILIKE '_NÏ%DÉ'),
+                ('한글' LIKE '_글'),
+                ('한글' NOT LIKE '_'),
+                ('ÄPFEL' NOT LIKE 'äp%');
+        ",
+        expected: Ok(select!(column1 Bool; true; true; true; true; true))
+    };
+    test! {
+        name: "backslash escapes wildcards",
+        sql: r"
+            VALUES
+                ('a_c' LIKE 'a\_c'),
+                ('abc' NOT LIKE 'a\_c'),
+                ('100%' LIKE '100\%'),
+                ('1000' NOT LIKE '100\%'),
+                ('A\B' ILIKE 'a\\b'),
+                ('line1
+line2' LIKE 'line1%2');
+        ",
+        expected: Ok(select!(column1 Bool; true; true; true; true; true; true))
+    };
 
     run!(
         "
@@ -73,6 +107,8 @@ test_case!(like_ilike, async move {
         (5, "SELECT name FROM Item WHERE 'ABC' LIKE '_B_'"),
         (5, "SELECT name FROM Item WHERE 'abc' ILIKE '_B_'"),
         (5, "SELECT name FROM Item WHERE 'ABC' ILIKE '_B_'"),
+        (0, "SELECT name FROM Item WHERE name NOT LIKE NULL"),
+        (0, "SELECT name FROM Item WHERE NULL NOT ILIKE '%'"),
     ];
 
     for (num, sql) in test_cases {
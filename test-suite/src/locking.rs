@@ -0,0 +1,71 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{TranslateError, UpdateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(locking, async move {
+    run!("CREATE TABLE Account (id INTEGER, balance INTEGER, version INTEGER);");
+    run!("INSERT INTO Account VALUES (1, 100, 1), (2, 200, 1);");
+
+    test!(
+        "SELECT id, balance FROM Account WHERE id = 1 FOR UPDATE;",
+        Ok(select!(
+            id  | balance
+            I64 | I64;
+            1     100
+        ))
+    );
+    test!(
+        "SELECT * FROM Account FOR SHARE;",
+        Err(
+            TranslateError::UnsupportedLockType("SELECT * FROM Account FOR SHARE".to_owned())
+                .into()
+        )
+    );
+
+    let payload = get_glue!()
+        .update_versioned(
+            "UPDATE Account SET balance = 150 WHERE id = 1 AND version = 1;",
+            "version",
+        )
+        .await;
+    assert_eq!(payload, Ok(Payload::Update(1)), "version matched");
+
+    let payload = get_glue!()
+        .update_versioned(
+            "UPDATE Account SET balance = 120 WHERE id = 1 AND version = 1;",
+            "version",
+        )
+        .await;
+    assert_eq!(
+        payload,
+        Err(UpdateError::OptimisticLockConflict("Account".to_owned()).into()),
+        "stale version"
+    );
+
+    let payload = get_glue!()
+        .update_versioned(
+            "UPDATE Account SET balance = 120 WHERE id = 3 AND version = 1;",
+            "version",
+        )
+        .await;
+    assert_eq!(payload, Ok(Payload::Update(0)), "no matching row");
+
+    let payload = get_glue!()
+        .update_versioned("DELETE FROM Account WHERE id = 1;", "version")
+        .await;
+    assert_eq!(payload, Err(UpdateError::UpdateStatementRequired.into()));
+
+    test!(
+        "SELECT * FROM Account;",
+        Ok(select!(
+            id  | balance | version
+            I64 | I64     | I64;
+            1     150       2;
+            2     200       1
+        ))
+    );
+});
@@ -0,0 +1,98 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, ValueError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(logical_operator, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            flag BOOLEAN NULL
+        );
+    "
+    );
+    run!("INSERT INTO Item VALUES (1, TRUE), (2, FALSE), (3, NULL);");
+
+    test!(
+        "SELECT
+            id,
+            flag AND TRUE AS a,
+            flag AND FALSE AS b,
+            flag OR TRUE AS c,
+            flag OR FALSE AS d,
+            flag XOR TRUE AS x
+        FROM Item",
+        Ok(select_with_null!(
+            id     | a             | b            | c           | d             | x;
+            I64(1)   Bool(true)      Bool(false)    Bool(true)    Bool(true)      Bool(false);
+            I64(2)   Bool(false)     Bool(false)    Bool(true)    Bool(false)     Bool(true);
+            I64(3)   Null            Bool(false)    Bool(true)    Null            Null
+        ))
+    );
+    test!(
+        "SELECT FALSE AND flag AS a, TRUE OR flag AS b FROM Item WHERE id = 3",
+        Ok(select!(
+            a    | b
+            Bool | Bool;
+            false  true
+        ))
+    );
+    test!(
+        "SELECT id FROM Item WHERE flag OR id = 3",
+        Ok(select!(id I64; 1; 3))
+    );
+
+    // the right operand is not evaluated once the left operand decides the result
+    test!(
+        "SELECT id FROM Item WHERE id > 0 OR id / 0 = 1",
+        Ok(select!(id I64; 1; 2; 3))
+    );
+    test!(
+        "SELECT id FROM Item WHERE id < 0 AND (SELECT id FROM Item) = 1",
+        Ok(select!(id))
+    );
+    test!(
+        "SELECT id FROM Item WHERE id > 0 AND id / 0 = 1",
+        Err(ValueError::DivisorShouldNotBeZero.into())
+    );
+
+    run!("CREATE TABLE Pair (lhs BOOLEAN NULL, rhs BOOLEAN NULL);");
+    run!(
+        "
+        INSERT INTO Pair VALUES
+            (TRUE, TRUE), (TRUE, FALSE), (TRUE, NULL),
+            (FALSE, TRUE), (FALSE, FALSE), (FALSE, NULL),
+            (NULL, TRUE), (NULL, FALSE), (NULL, NULL);
+    "
+    );
+    test!(
+        "SELECT lhs AND rhs AS a, lhs OR rhs AS o, lhs XOR rhs AS x FROM Pair",
+        Ok(select_with_null!(
+            a             | o             | x;
+            Bool(true)      Bool(true)      Bool(false);
+            Bool(false)     Bool(true)      Bool(true);
+            Null            Bool(true)      Null;
+            Bool(false)     Bool(true)      Bool(true);
+            Bool(false)     Bool(false)     Bool(false);
+            Bool(false)     Null            Null;
+            Null            Bool(true)      Null;
+            Bool(false)     Null            Null;
+            Null            Null            Null
+        ))
+    );
+    test!(
+        "SELECT NULL AND TRUE AS a, NULL OR TRUE AS o, NULL XOR NULL AS x",
+        Ok(select_with_null!(
+            a    | o          | x;
+            Null   Bool(true)   Null
+        ))
+    );
+    test!(
+        "SELECT id AND TRUE AS a FROM Item WHERE id = 1",
+        Err(EvaluateError::BooleanTypeRequired("I64(1)".to_owned()).into())
+    );
+});
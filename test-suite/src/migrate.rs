@@ -43,10 +43,6 @@ test_case!(migrate, async move {
             "SELECT * FROM Test WHERE Here.User.id = 1",
             TranslateError::UnsupportedExpr("Here.User.id".to_owned()).into(),
         ),
-        (
-            "SELECT * FROM Test NATURAL JOIN Test",
-            TranslateError::UnsupportedJoinConstraint("NATURAL".to_owned()).into(),
-        ),
         (
             "SELECT 1 ^ 2 FROM Test;",
             TranslateError::UnsupportedBinaryOperator("^".to_owned()).into(),
@@ -0,0 +1,127 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{InsertError, TranslateError, UpdateError, ValidateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(do_nothing, async move {
+    run!("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT UNIQUE, rate INTEGER);");
+    run!("CREATE TABLE Log (id INTEGER, name TEXT);");
+    run!("INSERT INTO Item VALUES (1, 'apple', 10);");
+
+    let test_cases = [
+        (
+            "INSERT INTO Item VALUES (1, 'banana', 20), (2, 'banana', 30) ON CONFLICT DO NOTHING",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO Item VALUES (3, 'apple', 40), (4, 'cherry', 50), (4, 'durian', 60) ON CONFLICT DO NOTHING",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO Item VALUES (5, 'durian', 70) ON CONFLICT (id) DO NOTHING",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT id, name, rate FROM Item",
+            Ok(select!(
+                id  | name                | rate
+                I64 | Str                 | I64;
+                1     "apple".to_owned()    10;
+                2     "banana".to_owned()   30;
+                4     "cherry".to_owned()   50;
+                5     "durian".to_owned()   70
+            )),
+        ),
+        (
+            "INSERT INTO Log VALUES (1, 'apple'), (1, 'apple') ON CONFLICT DO NOTHING",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "INSERT INTO Item SELECT id + 4, name, 0 FROM Log ON CONFLICT DO NOTHING",
+            Ok(Payload::Insert(0)),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
+
+test_case!(do_update, async move {
+    run!(
+        "CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT UNIQUE, rate INTEGER, hits INTEGER);"
+    );
+    run!("INSERT INTO Item VALUES (1, 'apple', 10, 0), (2, 'banana', 20, 0);");
+
+    let test_cases = [
+        (
+            "INSERT INTO Item VALUES (1, 'apple', 15, 0), (3, 'cherry', 30, 0)
+            ON CONFLICT (id) DO UPDATE SET rate = EXCLUDED.rate, hits = hits + 1",
+            Ok(Payload::Insert(2)),
+        ),
+        (
+            "INSERT INTO Item VALUES (4, 'banana', 25, 0)
+            ON CONFLICT (name) DO UPDATE SET rate = Item.rate + EXCLUDED.rate",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "INSERT INTO Item VALUES (3, 'cherry', 35, 0)
+            ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, rate = EXCLUDED.rate",
+            Ok(Payload::Insert(1)),
+        ),
+        (
+            "SELECT id, name, rate, hits FROM Item",
+            Ok(select!(
+                id  | name                 | rate | hits
+                I64 | Str                  | I64  | I64;
+                1     "apple".to_owned()     15     1;
+                2     "banana".to_owned()    45     0;
+                3     "cherry".to_owned()    35     0
+            )),
+        ),
+        (
+            "INSERT INTO Item VALUES (3, 'apple', 0, 0)
+            ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name",
+            Err(ValidateError::DuplicateEntryOnUniqueField(
+                Str("apple".to_owned()),
+                "name".to_owned(),
+            )
+            .into()),
+        ),
+        (
+            "INSERT INTO Item VALUES (5, 'egg', 1, 0), (5, 'fig', 2, 0)
+            ON CONFLICT (id) DO UPDATE SET rate = EXCLUDED.rate",
+            Err(InsertError::ConflictRowAffectedTwice("5".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Item VALUES (1, 'apple', 0, 0) ON CONFLICT (rate) DO UPDATE SET hits = 0",
+            Err(InsertError::InvalidConflictTarget("rate".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Item VALUES (1, 'apple', 0, 0)
+            ON CONFLICT (name) DO UPDATE SET id = EXCLUDED.id",
+            Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into()),
+        ),
+        (
+            "INSERT INTO Item VALUES (1, 'apple', 0, 0) ON CONFLICT DO UPDATE SET hits = 0",
+            Err(TranslateError::LackOfConflictTarget.into()),
+        ),
+        (
+            "SELECT id, rate FROM Item",
+            Ok(select!(
+                id  | rate
+                I64 | I64;
+                1     15;
+                2     45;
+                3     35
+            )),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, expected);
+    }
+});
@@ -0,0 +1,81 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(optimizer, async move {
+    run!("CREATE TABLE Player (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Item (id INTEGER, player_id INTEGER, amount INTEGER);");
+    run!("INSERT INTO Player VALUES (1, 'Taehoon'), (2, 'Mike'), (3, 'Jorno'), (4, 'Bruno');");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (101, 1, 10), (102, 2, 20), (103, 2, 30), (104, 3, 40),
+            (105, 4, 50), (106, 1, 60), (107, 3, 70), (108, 4, 80);
+    "
+    );
+
+    let inner_join = "
+        SELECT Player.name, Item.amount
+        FROM Item
+        JOIN Player ON Player.id = Item.player_id
+        WHERE Player.name = 'Mike'
+    ";
+    let left_join = "
+        SELECT Item.id, Player.name
+        FROM Item
+        LEFT JOIN Player ON Player.id = Item.player_id
+        WHERE Item.amount > 60
+    ";
+    let inner_join_rows = || {
+        select!(
+            name               | amount
+            Str                | I64;
+            "Mike".to_owned()    20;
+            "Mike".to_owned()    30
+        )
+    };
+    let left_join_rows = || {
+        select!(
+            id  | name
+            I64 | Str;
+            107   "Jorno".to_owned();
+            108   "Bruno".to_owned()
+        )
+    };
+
+    let glue = get_glue!();
+    let (payload, profile) = glue.execute_with_profile(inner_join).await.unwrap();
+    assert_eq!(payload, inner_join_rows(), "{inner_join}");
+    assert_eq!(
+        profile.scan.rows, 8,
+        "every Item is scanned without statistics"
+    );
+
+    let (payload, profile) = glue.execute_with_profile(left_join).await.unwrap();
+    assert_eq!(payload, left_join_rows(), "{left_join}");
+    assert_eq!(
+        profile.scan.rows, 8,
+        "every Item is scanned without statistics"
+    );
+
+    test!("ANALYZE TABLE Player;", Ok(Payload::Analyze));
+    test!("ANALYZE TABLE Item;", Ok(Payload::Analyze));
+
+    let glue = get_glue!();
+    let (payload, profile) = glue.execute_with_profile(inner_join).await.unwrap();
+    assert_eq!(payload, inner_join_rows(), "{inner_join}");
+    assert_eq!(
+        profile.scan.rows, 1,
+        "Player is filtered by name before it is joined with Item"
+    );
+    assert_eq!(profile.join.rows, 2, "join rows");
+    assert_eq!(profile.filter.rows, 2, "filter rows");
+
+    let (payload, profile) = glue.execute_with_profile(left_join).await.unwrap();
+    assert_eq!(payload, left_join_rows(), "{left_join}");
+    assert_eq!(
+        profile.scan.rows, 2,
+        "Item is filtered by amount before it is joined with Player"
+    );
+});
@@ -1,9 +1,6 @@
 use {
     crate::*,
-    gluesql_core::{
-        error::{SortError, TranslateError},
-        prelude::Value::*,
-    },
+    gluesql_core::{error::SortError, prelude::Value::*},
 };
 
 test_case!(order_by, async move {
@@ -149,9 +146,74 @@ CREATE TABLE Test (
         ))
     );
 
+    test! {
+        name: "ORDER BY CASE expression",
+        sql: "SELECT id, num FROM Test ORDER BY CASE WHEN num > 5 THEN 0 ELSE 1 END, id DESC",
+        expected: Ok(select!(
+            id  | num
+            I64 | I64;
+            4     7;
+            1     9;
+            3     4;
+            1     2
+        ))
+    };
+    test! {
+        name: "ORDER BY function of a column which is not projected",
+        sql: "SELECT id FROM Test ORDER BY ABS(num - 5)",
+        expected: Ok(select!(
+            id
+            I64;
+            3;
+            4;
+            1;
+            1
+        ))
+    };
+    test! {
+        name: "ORDER BY aggregate which is not projected",
+        sql: "SELECT id FROM Test GROUP BY id ORDER BY SUM(num) DESC",
+        expected: Ok(select!(
+            id
+            I64;
+            1;
+            4;
+            3
+        ))
+    };
+    test! {
+        name: "ORDER BY expression of aliases",
+        sql: "SELECT id AS C1, num AS C2 FROM Test ORDER BY C1 * 10 + C2 DESC",
+        expected: Ok(select!(
+            C1  | C2
+            I64 | I64;
+            4     7;
+            3     4;
+            1     9;
+            1     2
+        ))
+    };
     test!(
-        "SELECT * FROM Test ORDER BY id NULLS FIRST",
-        Err(TranslateError::OrderByNullsFirstOrLastNotSupported.into())
+        "SELECT id, num FROM Test ORDER BY name NULLS FIRST",
+        Ok(select!(
+            id  | num
+            I64 | I64;
+            1     9;
+            1     2;
+            4     7;
+            3     4
+        ))
+    );
+    test!(
+        "SELECT id, num FROM Test ORDER BY rate DESC NULLS LAST, num DESC",
+        Ok(select!(
+            id  | num
+            I64 | I64;
+            1     2;
+            3     4;
+            4     7;
+            1     9
+        ))
     );
     test! {
         name: "ORDER BY aliases",
@@ -41,6 +41,13 @@ test_case!(ordering, async move {
         (1, "SELECT * FROM Operator WHERE name < 'Az';"),
         (5, "SELECT * FROM Operator WHERE name < 'zz';"),
         (5, "SELECT * FROM Operator WHERE 'aa' < 'zz';"),
+        (5, "SELECT * FROM Operator WHERE '2023-9-1' < '2023-10-01';"),
+        (
+            5,
+            "SELECT * FROM Operator WHERE '2023-10-01' <= '2023-10-01 00:00:00';",
+        ),
+        (5, "SELECT * FROM Operator WHERE '1.5' < 2;"),
+        (0, "SELECT * FROM Operator WHERE 10 < '9.5';"),
         (4, "SELECT * FROM Operator WHERE 'Romeo' >= name;"),
         (
             1,
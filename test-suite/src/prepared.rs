@@ -0,0 +1,108 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(prepared, async move {
+    run!("CREATE TABLE Item (id INTEGER, price FLOAT, created DATE, name TEXT)");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 1.5, '2023-01-01', 'a'),
+            (2, 3.0, '2023-02-01', 'b'),
+            (3, 4.5, '2023-03-01', 'c'),
+            (4, 6.0, '2023-04-01', 'd');
+    "
+    );
+
+    let glue = get_glue!();
+
+    let prepared = glue
+        .prepare("SELECT id FROM Item WHERE id IN (?, ?, ?) ORDER BY id")
+        .await
+        .unwrap();
+    assert_eq!(prepared.num_parameters(), 3);
+    assert_eq!(
+        glue.execute_prepared(&prepared, &[I64(1), I64(3), I64(9)])
+            .await,
+        Ok(select!(id I64; 1; 3))
+    );
+    assert_eq!(
+        glue.execute_prepared(&prepared, &[I64(2), Null, I64(4)])
+            .await,
+        Ok(select!(id I64; 2; 4))
+    );
+    assert_eq!(
+        glue.execute_prepared(&prepared, &[I64(1)]).await,
+        Err(EvaluateError::ParameterCountMismatch {
+            expected: 3,
+            found: 1,
+        }
+        .into())
+    );
+
+    let prepared = glue
+        .prepare("SELECT id FROM Item ORDER BY id LIMIT $1 OFFSET $2")
+        .await
+        .unwrap();
+    assert_eq!(
+        glue.execute_prepared(&prepared, &[I64(2), I64(1)]).await,
+        Ok(select!(id I64; 2; 3))
+    );
+
+    // parameters are cast like literals: integers to FLOAT, strings to DATE
+    let prepared = glue
+        .prepare("INSERT INTO Item VALUES ($1, $2, $3, $4)")
+        .await
+        .unwrap();
+    assert_eq!(
+        glue.execute_prepared(
+            &prepared,
+            &[
+                I32(5),
+                I64(7),
+                Str("2023-05-01".to_owned()),
+                Str("e".to_owned())
+            ]
+        )
+        .await,
+        Ok(Payload::Insert(1))
+    );
+
+    let prepared = glue
+        .prepare("SELECT id, price FROM Item WHERE created > $1 AND price >= $2 * 2")
+        .await
+        .unwrap();
+    assert_eq!(
+        glue.execute_prepared(&prepared, &[Str("2023-03-15".to_owned()), I64(3)])
+            .await,
+        Ok(select!(
+            id  | price;
+            I64 | F64;
+            4     6.0;
+            5     7.0
+        ))
+    );
+
+    let prepared = glue
+        .prepare("SELECT $1 + id AS n FROM Item WHERE name = $2")
+        .await
+        .unwrap();
+    assert_eq!(
+        glue.execute_prepared(&prepared, &[I64(10), Str("c".to_owned())])
+            .await,
+        Ok(select!(n I64; 13))
+    );
+
+    assert_eq!(
+        glue.execute("SELECT $1 AS n").await,
+        Err(EvaluateError::UnboundParameter(1).into())
+    );
+    assert_eq!(
+        glue.execute("SELECT ? AS n").await,
+        Err(TranslateError::UnsupportedParameter("?".to_owned()).into())
+    );
+});
@@ -0,0 +1,47 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(profile, async move {
+    run!("CREATE TABLE Item (id INTEGER, category TEXT);");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 'fruit'),
+            (2, 'meat'),
+            (3, 'fruit'),
+            (4, 'meat');
+    "
+    );
+
+    let glue = get_glue!();
+
+    let sql = "SELECT id FROM Item WHERE id > 1 ORDER BY id DESC";
+    let (payload, profile) = glue.execute_with_profile(sql).await.unwrap();
+    assert_eq!(payload, select!(id I64; 4; 3; 2), "{sql}");
+    assert_eq!(profile.scan.rows, 4, "scan rows");
+    assert_eq!(profile.join.rows, 4, "join rows");
+    assert_eq!(profile.filter.rows, 3, "filter rows");
+    assert_eq!(profile.aggregate.rows, 3, "aggregate rows");
+    assert_eq!(profile.project.rows, 3, "project rows");
+    assert_eq!(profile.sort.rows, 3, "sort rows");
+    assert!(profile.total_time >= profile.execute_time, "total time");
+    assert!(profile.to_string().contains("rows: 3"), "display");
+
+    let sql = "SELECT category, COUNT(*) FROM Item GROUP BY category";
+    let (_, profile) = glue.execute_with_profile(sql).await.unwrap();
+    assert_eq!(profile.filter.rows, 4, "filter rows with group by");
+    assert_eq!(profile.aggregate.rows, 2, "aggregate rows with group by");
+
+    let sql = "DELETE FROM Item WHERE id = 1";
+    let (payload, profile) = glue.execute_with_profile(sql).await.unwrap();
+    assert_eq!(payload, Payload::Delete(1), "{sql}");
+    assert_eq!(profile.scan.rows, 0, "no stages for delete");
+
+    let sql = "SELECT 1; SELECT 2;";
+    assert!(
+        glue.execute_with_profile(sql).await.is_err(),
+        "multiple statements"
+    );
+});
@@ -0,0 +1,84 @@
+use {
+    crate::*,
+    gluesql_core::{
+        prelude::Value::*,
+        query_builder::{QueryBuilder, QueryBuilderError, SortOrder},
+    },
+};
+
+test_case!(query_builder, async move {
+    run!("CREATE TABLE Item (id INTEGER, name TEXT, price INTEGER);");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 'Apple', 300),
+            (2, 'Banana', 100),
+            (3, 'Cherry', 500),
+            (4, 'Durian', 900);
+    "
+    );
+
+    let glue = get_glue!();
+
+    let query = QueryBuilder::new()
+        .select(&["id", "name"])
+        .from("Item")
+        .where_("price > ?", &[I32(200)])
+        .where_("name <> ?", &[Str("Durian".to_owned())])
+        .order_by("price", SortOrder::Desc)
+        .limit(10);
+    assert_eq!(
+        query.build(&glue.storage).await,
+        Ok(r#"SELECT "id", "name" FROM "Item" WHERE (price > 200) AND (name <> 'Durian') ORDER BY "price" DESC LIMIT 10"#.to_owned()),
+        "build"
+    );
+    assert_eq!(
+        query.execute(glue).await,
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "Cherry".to_owned();
+            1     "Apple".to_owned()
+        )),
+        "execute"
+    );
+
+    let query = QueryBuilder::new()
+        .from("Item")
+        .order_by("id", SortOrder::Asc)
+        .limit(1);
+    assert_eq!(
+        query.execute(glue).await,
+        Ok(select!(
+            id  | name               | price
+            I64 | Str                | I64;
+            1     "Apple".to_owned()   300
+        )),
+        "select all columns"
+    );
+
+    let query = QueryBuilder::new().select(&["id", "color"]).from("Item");
+    assert_eq!(
+        query.build(&glue.storage).await,
+        Err(QueryBuilderError::ColumnNotFound {
+            table: "Item".to_owned(),
+            column: "color".to_owned(),
+        }
+        .into()),
+        "unknown column"
+    );
+
+    let query = QueryBuilder::new().select(&["id"]).from("Nothing");
+    assert_eq!(
+        query.build(&glue.storage).await,
+        Err(QueryBuilderError::TableNotFound("Nothing".to_owned()).into()),
+        "unknown table"
+    );
+
+    let query = QueryBuilder::new().select(&["id"]);
+    assert_eq!(
+        query.build(&glue.storage).await,
+        Err(QueryBuilderError::TableNotSpecified.into()),
+        "table not specified"
+    );
+});
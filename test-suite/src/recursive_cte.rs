@@ -0,0 +1,116 @@
+use {
+    crate::*,
+    gluesql_core::{error::RecursiveCteError, executor::DEFAULT_MAX_RECURSION, prelude::Value::*},
+};
+
+test_case!(recursive_cte, async move {
+    run!(
+        "
+        CREATE TABLE Employee (
+            id INTEGER,
+            name TEXT,
+            manager_id INTEGER NULL
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Employee VALUES
+            (1, 'Alice', NULL),
+            (2, 'Bob', 1),
+            (3, 'Carol', 1),
+            (4, 'Dave', 2),
+            (5, 'Eve', 4),
+            (6, 'Frank', NULL);
+    "
+    );
+    run!("CREATE TABLE Edge (src INTEGER, dst INTEGER);");
+    run!("INSERT INTO Edge VALUES (1, 2), (2, 3), (3, 1), (3, 4);");
+
+    test!(
+        "
+        WITH RECURSIVE Report (id, name, depth) AS (
+            SELECT id, name, 0 FROM Employee WHERE id = 1
+            UNION ALL
+            SELECT e.id, e.name, r.depth + 1
+            FROM Employee AS e
+            JOIN Report AS r ON e.manager_id = r.id
+        )
+        SELECT name, depth FROM Report ORDER BY depth, id
+        ",
+        Ok(select!(
+            name               | depth
+            Str                | I64;
+            "Alice".to_owned()   0;
+            "Bob".to_owned()     1;
+            "Carol".to_owned()   1;
+            "Dave".to_owned()    2;
+            "Eve".to_owned()     3
+        ))
+    );
+    test!(
+        "
+        WITH RECURSIVE Fib (n, a, b) AS (
+            SELECT 1, 0, 1
+            UNION ALL
+            SELECT n + 1, b, a + b FROM Fib WHERE n < 10
+        )
+        SELECT a FROM Fib
+        ",
+        Ok(select!(a I64; 0; 1; 1; 2; 3; 5; 8; 13; 21; 34))
+    );
+    test!(
+        "
+        WITH RECURSIVE Reach (node) AS (
+            SELECT 1
+            UNION
+            SELECT Edge.dst FROM Edge JOIN Reach ON Edge.src = Reach.node
+        )
+        SELECT node FROM Reach
+        ",
+        Ok(select!(node I64; 1; 2; 3; 4))
+    );
+    test!(
+        "
+        WITH RECURSIVE
+            Root AS (SELECT id FROM Employee WHERE manager_id IS NULL),
+            Countdown (n) AS (SELECT 3 UNION ALL SELECT n - 1 FROM Countdown WHERE n > 1)
+        SELECT Root.id, Countdown.n FROM Root JOIN Countdown ON Root.id = Countdown.n
+        ",
+        Ok(select!(
+            id  | n
+            I64 | I64;
+            1     1
+        ))
+    );
+
+    get_glue!().plan_option.max_recursion = 5;
+    test!(
+        "
+        WITH RECURSIVE Reach (node) AS (
+            SELECT 1
+            UNION ALL
+            SELECT Edge.dst FROM Edge JOIN Reach ON Edge.src = Reach.node
+        )
+        SELECT node FROM Reach
+        ",
+        Err(RecursiveCteError::MaxRecursionExceeded {
+            name: "Reach".to_owned(),
+            max_recursion: 5,
+        }
+        .into())
+    );
+    get_glue!().plan_option.max_recursion = DEFAULT_MAX_RECURSION;
+
+    test!(
+        "
+        WITH RECURSIVE Numbers (n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1, n FROM Numbers WHERE n < 3
+        )
+        SELECT n FROM Numbers
+        ",
+        Err(RecursiveCteError::NumberOfColumnsDifferent.into())
+    );
+});
@@ -0,0 +1,74 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::ValueError,
+        prelude::{Error, Value::*},
+    },
+};
+
+test_case!(regexp, async move {
+    test! {
+        name: "basic usage - ~, ~*, !~ and !~*",
+        sql: "
+            VALUES
+                ('GlueSQL' ~ '^Glue'),
+                ('GlueSQL' ~* 'sql$'),
+                ('GlueSQL' !~ '[0-9]'),
+                ('GlueSQL' !~* '^glue');
+        ",
+        expected: Ok(select!(column1 Bool; true; true; true; false))
+    };
+
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            name TEXT
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, name) VALUES
+            (1,    'Amelia'),
+            (2,      'Doll'),
+            (3, 'Gascoigne'),
+            (4,   'Gehrman'),
+            (5,     'Maria');
+    "
+    );
+
+    let test_cases = [
+        (2, "SELECT name FROM Item WHERE name ~ '^G'"),
+        (0, "SELECT name FROM Item WHERE name ~ '^g'"),
+        (2, "SELECT name FROM Item WHERE name ~* '^g'"),
+        (1, "SELECT name FROM Item WHERE name ~ 'l{2}'"),
+        (3, "SELECT name FROM Item WHERE name !~ '^G'"),
+        (1, "SELECT name FROM Item WHERE name !~* 'A'"),
+        (4, "SELECT name FROM Item WHERE name ~ LOWER('O|IA$')"),
+    ];
+
+    for (num, sql) in test_cases {
+        count!(num, sql);
+    }
+
+    test!(
+        "SELECT NULL ~ 'a' AS l, name ~* NULL AS r FROM Item WHERE id = 1",
+        Ok(select_with_null!(l | r; Null Null))
+    );
+
+    test!(
+        "SELECT name FROM Item WHERE id = 1 AND name ~ 10",
+        Err(ValueError::RegexpOnNonString {
+            base: Str("Amelia".to_owned()),
+            pattern: I64(10),
+            case_insensitive: false,
+        }
+        .into())
+    );
+
+    assert!(matches!(
+        run("SELECT name FROM Item WHERE name ~ '(unclosed'", glue, None).await,
+        Err(Error::Value(ValueError::InvalidRegexPattern(_)))
+    ));
+});
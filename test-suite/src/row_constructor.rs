@@ -0,0 +1,94 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(row_constructor, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            num INTEGER,
+            name TEXT
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 10, 'a'),
+            (2, 20, 'b'),
+            (2, 25, 'c'),
+            (3, 30, 'd');
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT name FROM Item WHERE (id, num) = (2, 25)",
+            select!(name Str; "c".to_owned()),
+        ),
+        (
+            "SELECT name FROM Item WHERE (id, name) <> (2, 'b')",
+            select!(
+                name
+                Str;
+                "a".to_owned();
+                "c".to_owned();
+                "d".to_owned()
+            ),
+        ),
+        (
+            "SELECT name FROM Item WHERE (id, num) > (2, 20)",
+            select!(
+                name
+                Str;
+                "c".to_owned();
+                "d".to_owned()
+            ),
+        ),
+        (
+            "SELECT name FROM Item WHERE ROW(id, num) <= ROW(2, 20)",
+            select!(
+                name
+                Str;
+                "a".to_owned();
+                "b".to_owned()
+            ),
+        ),
+        (
+            "SELECT name FROM Item WHERE (id, num) IN ((1, 10), (3, 30))",
+            select!(
+                name
+                Str;
+                "a".to_owned();
+                "d".to_owned()
+            ),
+        ),
+        (
+            "SELECT name FROM Item WHERE (id, num) NOT IN ((1, 10), (3, 30))",
+            select!(
+                name
+                Str;
+                "b".to_owned();
+                "c".to_owned()
+            ),
+        ),
+        (
+            "SELECT (id, name) AS pair FROM Item WHERE id = 1",
+            select!(pair List; vec![I64(1), Str("a".to_owned())]),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT name FROM Item WHERE (id, num) = (2, NULL)",
+        Ok(Payload::Select {
+            labels: vec!["name".to_owned()],
+            rows: vec![],
+        })
+    );
+});
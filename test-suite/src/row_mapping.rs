@@ -0,0 +1,86 @@
+use {
+    crate::*,
+    gluesql_core::{
+        data::{FromRow, IntoRow, Row, RowError, ValueError},
+        prelude::{Payload, Result, Value::Str},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, FromRow, IntoRow)]
+struct Item {
+    id: i64,
+    #[column(name = "item_name")]
+    name: String,
+    rate: Option<f64>,
+    detail: Detail,
+}
+
+#[derive(Clone, Debug, PartialEq, FromRow, IntoRow)]
+struct Detail {
+    color: String,
+    tags: Vec<String>,
+}
+
+test_case!(row_mapping, async move {
+    run!("CREATE TABLE Item (id INTEGER, item_name TEXT, rate FLOAT NULL, detail MAP);");
+
+    let glue = get_glue!();
+
+    let items = vec![
+        Item {
+            id: 1,
+            name: "Glue".to_owned(),
+            rate: Some(0.5),
+            detail: Detail {
+                color: "red".to_owned(),
+                tags: vec!["a".to_owned(), "b".to_owned()],
+            },
+        },
+        Item {
+            id: 2,
+            name: "SQL".to_owned(),
+            rate: None,
+            detail: Detail {
+                color: "blue".to_owned(),
+                tags: Vec::new(),
+            },
+        },
+    ];
+    assert_eq!(
+        glue.insert_rows("Item", items.clone()).await,
+        Ok(Payload::Insert(2))
+    );
+
+    let actual = glue
+        .execute_iter("SELECT * FROM Item ORDER BY id")
+        .await
+        .unwrap()
+        .map(|row| row.and_then(Item::try_from))
+        .collect::<Result<Vec<_>>>();
+    assert_eq!(actual, Ok(items));
+
+    let actual = glue
+        .execute_iter("SELECT id, item_name, rate FROM Item")
+        .await
+        .unwrap()
+        .map(|row| row.and_then(Item::try_from))
+        .collect::<Result<Vec<_>>>();
+    assert_eq!(
+        actual,
+        Err(RowError::ColumnNotFound("detail".to_owned()).into())
+    );
+
+    let actual = glue
+        .execute_iter("SELECT id, NULL AS item_name, rate, detail FROM Item")
+        .await
+        .unwrap()
+        .map(|row| row.and_then(Item::try_from))
+        .collect::<Result<Vec<_>>>();
+    assert_eq!(actual, Err(ValueError::ImpossibleCast.into()));
+
+    let row = Row::from(Detail {
+        color: "green".to_owned(),
+        tags: Vec::new(),
+    });
+    assert_eq!(row.get_value("color"), Some(&Str("green".to_owned())));
+});
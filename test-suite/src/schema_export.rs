@@ -0,0 +1,42 @@
+use {
+    crate::*,
+    gluesql_core::{data::SchemaParseError, error::ExecuteError, prelude::Value::*},
+};
+
+test_case!(schema_export, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT DEFAULT 'glue',
+            rate FLOAT NULL
+        );
+    "
+    );
+    run!("INSERT INTO Item VALUES (1, 'a', 0.5);");
+
+    let ddl = get_glue!().export_schema("Item").await.unwrap();
+    assert_eq!(
+        ddl,
+        r#"CREATE TABLE "Item" ("id" INT NOT NULL PRIMARY KEY, "name" TEXT NULL DEFAULT 'glue', "rate" FLOAT NULL);"#
+    );
+    assert_eq!(
+        get_glue!().export_schema("Nothing").await,
+        Err(ExecuteError::TableNotFound("Nothing".to_owned()).into())
+    );
+
+    run!("DROP TABLE Item;");
+    get_glue!().import_schema(&ddl).await.unwrap();
+    assert_eq!(get_glue!().export_schema("Item").await.unwrap(), ddl);
+
+    run!("INSERT INTO Item (id, rate) VALUES (2, 1.5);");
+    test!(
+        "SELECT id, name, rate FROM Item",
+        Ok(select!(id | name | rate; I64 | Str | F64; 2 "glue".to_owned() 1.5))
+    );
+
+    assert_eq!(
+        get_glue!().import_schema("SELECT * FROM Item;").await,
+        Err(SchemaParseError::CannotParseDDL.into())
+    );
+});
@@ -0,0 +1,138 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{SequenceError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(sequence, async move {
+    run!("CREATE SEQUENCE seq");
+    test!(
+        "CREATE SEQUENCE seq",
+        Err(SequenceError::SequenceAlreadyExists("seq".to_owned()).into())
+    );
+    test!("CREATE SEQUENCE IF NOT EXISTS seq", Ok(Payload::Create));
+
+    test!(
+        "SELECT CURRVAL('seq') AS n",
+        Err(SequenceError::CurrentValueNotDefined("seq".to_owned()).into())
+    );
+    test!("SELECT NEXTVAL('seq') AS n", Ok(select!(n I64; 1)));
+    test!("SELECT NEXTVAL('seq') AS n", Ok(select!(n I64; 2)));
+    test!("SELECT CURRVAL('seq') AS n", Ok(select!(n I64; 2)));
+    test!("SELECT SETVAL('seq', 10) AS n", Ok(select!(n I64; 10)));
+    test!("SELECT NEXTVAL('seq') AS n", Ok(select!(n I64; 11)));
+
+    run!("CREATE SEQUENCE cycled INCREMENT BY 2 MINVALUE 1 MAXVALUE 4 CYCLE");
+    test!("SELECT NEXTVAL('cycled') AS n", Ok(select!(n I64; 1)));
+    test!("SELECT NEXTVAL('cycled') AS n", Ok(select!(n I64; 3)));
+    test!("SELECT NEXTVAL('cycled') AS n", Ok(select!(n I64; 1)));
+
+    run!("CREATE SEQUENCE bounded START WITH 2 MAXVALUE 3");
+    test!("SELECT NEXTVAL('bounded') AS n", Ok(select!(n I64; 2)));
+    test!("SELECT NEXTVAL('bounded') AS n", Ok(select!(n I64; 3)));
+    test!(
+        "SELECT NEXTVAL('bounded') AS n",
+        Err(SequenceError::MaxValueReached {
+            name: "bounded".to_owned(),
+            max_value: 3,
+        }
+        .into())
+    );
+    test!(
+        "SELECT SETVAL('bounded', 5) AS n",
+        Err(SequenceError::ValueOutOfRange {
+            name: "bounded".to_owned(),
+            value: 5,
+            min_value: 1,
+            max_value: 3,
+        }
+        .into())
+    );
+
+    test!(
+        "CREATE SEQUENCE zero INCREMENT BY 0",
+        Err(SequenceError::ZeroIncrement("zero".to_owned()).into())
+    );
+    test!(
+        "CREATE SEQUENCE cached CACHE 10",
+        Err(TranslateError::UnsupportedSequenceOption("CACHE 10".to_owned()).into())
+    );
+
+    run!("CREATE TABLE Ticket (num INTEGER DEFAULT NEXTVAL('seq'), title TEXT)");
+    run!("INSERT INTO Ticket (title) VALUES ('a'), ('b')");
+    run!("INSERT INTO Ticket VALUES (NEXTVAL('seq') * 10, 'c')");
+    test!(
+        "SELECT num, title FROM Ticket",
+        Ok(select!(
+            num | title;
+            I64 | Str;
+            12    "a".to_owned();
+            13    "b".to_owned();
+            140   "c".to_owned()
+        ))
+    );
+    test!(
+        "
+        WITH Once AS (SELECT NEXTVAL('seq') AS n)
+        SELECT a.n AS a, b.n AS b FROM Once AS a JOIN Once AS b
+        ",
+        Ok(select!(
+            a   | b;
+            I64 | I64;
+            15    15
+        ))
+    );
+
+    test!("DROP SEQUENCE cycled, bounded", Ok(Payload::DropSequence));
+    test!(
+        "DROP SEQUENCE cycled",
+        Err(SequenceError::SequenceNotFound("cycled".to_owned()).into())
+    );
+    test!("DROP SEQUENCE IF EXISTS cycled", Ok(Payload::DropSequence));
+    test!(
+        "SELECT NEXTVAL('cycled') AS n",
+        Err(SequenceError::SequenceNotFound("cycled".to_owned()).into())
+    );
+    test!(
+        "SELECT CURRVAL('bounded') AS n",
+        Err(SequenceError::CurrentValueNotDefined("bounded".to_owned()).into())
+    );
+});
+
+test_case!(serial, async move {
+    run!("CREATE TABLE Item (id SERIAL, name TEXT)");
+    run!("INSERT INTO Item (name) VALUES ('a'), ('b')");
+    run!("INSERT INTO Item VALUES (DEFAULT, 'c')");
+    test!(
+        "SELECT id, name FROM Item",
+        Ok(select!(
+            id  | name;
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "c".to_owned()
+        ))
+    );
+    test!("SELECT CURRVAL('Item_id_seq') AS n", Ok(select!(n I64; 3)));
+
+    test!(
+        "CREATE TABLE Other (id BIGSERIAL DEFAULT 1)",
+        Err(TranslateError::DefaultOnSerialColumn("id".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Clash (id INTEGER, item_id SERIAL)",
+        Ok(Payload::Create)
+    );
+    test!(
+        "CREATE TABLE Clash_item (id SERIAL)",
+        Err(SequenceError::SequenceAlreadyExists("Clash_item_id_seq".to_owned()).into())
+    );
+
+    test!("DROP TABLE Item", Ok(Payload::DropTable));
+    test!(
+        "SELECT NEXTVAL('Item_id_seq') AS n",
+        Err(SequenceError::SequenceNotFound("Item_id_seq".to_owned()).into())
+    );
+});
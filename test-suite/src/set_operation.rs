@@ -0,0 +1,174 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::SetOperator,
+        error::SelectError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(except, async move {
+    run!("CREATE TABLE Cart (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Purchase (id INTEGER, name TEXT);");
+    run!("INSERT INTO Cart VALUES (1, 'a'), (1, 'a'), (1, 'a'), (2, 'b'), (2, 'b'), (3, 'c');");
+    run!("INSERT INTO Purchase VALUES (1, 'a'), (3, 'c'), (3, 'c');");
+
+    test!(
+        "SELECT id, name FROM Cart EXCEPT SELECT id, name FROM Purchase ORDER BY id;",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            2     "b".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id, name FROM Cart EXCEPT ALL SELECT id, name FROM Purchase ORDER BY id;",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            1     "a".to_owned();
+            2     "b".to_owned();
+            2     "b".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id FROM Cart EXCEPT ALL SELECT id FROM Purchase EXCEPT SELECT 2 FROM Purchase ORDER BY id;",
+        Ok(select!(id I64; 1))
+    );
+    test!(
+        "SELECT id FROM Cart EXCEPT ALL SELECT id FROM Purchase ORDER BY id DESC LIMIT 2;",
+        Ok(select!(
+            id
+            I64;
+            2;
+            2
+        ))
+    );
+    test!(
+        "VALUES (1, NULL), (1, NULL), (2, NULL) EXCEPT ALL VALUES (1, NULL);",
+        Ok(select_with_null!(
+            column1 | column2;
+            I64(1)    Null;
+            I64(2)    Null
+        ))
+    );
+    test!(
+        "SELECT id FROM Cart WHERE id = 1 EXCEPT ALL SELECT id FROM Purchase;",
+        Ok(select!(
+            id
+            I64;
+            1;
+            1
+        ))
+    );
+    test!(
+        "SELECT id FROM Cart WHERE id = 1 EXCEPT SELECT id FROM Purchase;",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![],
+        })
+    );
+
+    run!("CREATE TABLE Empty (id INTEGER, name TEXT);");
+    test!(
+        "SELECT id, name FROM Cart EXCEPT SELECT id, name FROM Empty ORDER BY id;",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "c".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id, name FROM Empty EXCEPT ALL SELECT id, name FROM Cart;",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: vec![],
+        })
+    );
+    test!(
+        "SELECT id FROM Cart EXCEPT SELECT id, name FROM Purchase;",
+        Err(SelectError::NumberOfColumnsDifferent(SetOperator::Except).into())
+    );
+});
+
+test_case!(intersect, async move {
+    run!("CREATE TABLE Cart (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Purchase (id INTEGER, name TEXT);");
+    run!("INSERT INTO Cart VALUES (1, 'a'), (1, 'a'), (1, 'a'), (2, 'b'), (3, 'c');");
+    run!("INSERT INTO Purchase VALUES (1, 'a'), (1, 'a'), (3, 'c'), (3, 'c'), (4, 'd');");
+
+    test!(
+        "SELECT id, name FROM Cart INTERSECT SELECT id, name FROM Purchase ORDER BY id;",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            3     "c".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id, name FROM Cart INTERSECT ALL SELECT id, name FROM Purchase ORDER BY id;",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            1     "a".to_owned();
+            3     "c".to_owned()
+        ))
+    );
+    test!(
+        "SELECT id FROM Cart INTERSECT ALL SELECT id FROM Purchase EXCEPT ALL SELECT 1;",
+        Ok(select!(
+            id
+            I64;
+            1;
+            3
+        ))
+    );
+    test!(
+        "VALUES (NULL), (NULL), (NULL) INTERSECT ALL VALUES (NULL), (NULL);",
+        Ok(select_with_null!(
+            column1;
+            Null;
+            Null
+        ))
+    );
+    test!(
+        "SELECT id AS n FROM Cart INTERSECT SELECT id FROM Purchase ORDER BY n DESC;",
+        Ok(select!(
+            n
+            I64;
+            3;
+            1
+        ))
+    );
+    test!(
+        "SELECT id FROM Cart WHERE id = 1 INTERSECT SELECT id FROM Purchase WHERE id = 1;",
+        Ok(select!(id I64; 1))
+    );
+    test!(
+        "SELECT id FROM Cart WHERE id = 1 INTERSECT ALL SELECT id FROM Purchase WHERE id = 1;",
+        Ok(select!(
+            id
+            I64;
+            1;
+            1
+        ))
+    );
+
+    run!("CREATE TABLE Empty (id INTEGER, name TEXT);");
+    test!(
+        "SELECT id, name FROM Cart INTERSECT ALL SELECT id, name FROM Empty;",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: vec![],
+        })
+    );
+    test!(
+        "SELECT id FROM Cart INTERSECT SELECT id, name FROM Purchase;",
+        Err(SelectError::NumberOfColumnsDifferent(SetOperator::Intersect).into())
+    );
+});
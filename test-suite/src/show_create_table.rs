@@ -0,0 +1,29 @@
+use {
+    crate::*,
+    gluesql_core::{error::ExecuteError, prelude::Value::*},
+};
+
+test_case!(show_create_table, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT UNIQUE DEFAULT 'glue',
+            rate FLOAT NULL
+        );
+    "
+    );
+
+    test!(
+        "SHOW CREATE TABLE Item",
+        Ok(select!(
+            "Create Table"
+            Str;
+            r#"CREATE TABLE "Item" ("id" INT NOT NULL PRIMARY KEY, "name" TEXT NULL DEFAULT 'glue' UNIQUE, "rate" FLOAT NULL);"#.to_owned()
+        ))
+    );
+    test!(
+        "SHOW CREATE TABLE Nothing",
+        Err(ExecuteError::TableNotFound("Nothing".to_owned()).into())
+    );
+});
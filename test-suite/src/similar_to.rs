@@ -0,0 +1,84 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::ValueError,
+        prelude::{Error, Value::*},
+    },
+};
+
+test_case!(similar_to, async move {
+    test! {
+        name: "basic usage - SIMILAR TO and NOT SIMILAR TO",
+        sql: "
+            VALUES
+                ('abc' SIMILAR TO 'abc'),
+                ('abc' SIMILAR TO '(a|x)%'),
+                ('abc' NOT SIMILAR TO 'b'),
+                ('abcabc' SIMILAR TO '(abc)+'),
+                ('a.c' SIMILAR TO 'a.c'),
+                ('abc' NOT SIMILAR TO 'a.c');
+        ",
+        expected: Ok(select!(column1 Bool; true; true; true; true; true; true))
+    };
+
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            name TEXT
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, name) VALUES
+            (1,    'Amelia'),
+            (2,      'Doll'),
+            (3, 'Gascoigne'),
+            (4,   'Gehrman'),
+            (5,     'Maria');
+    "
+    );
+
+    let test_cases = [
+        (2, "SELECT name FROM Item WHERE name SIMILAR TO 'G%'"),
+        (4, "SELECT name FROM Item WHERE name SIMILAR TO '(A|G|D)%'"),
+        (3, "SELECT name FROM Item WHERE name SIMILAR TO '%(ia|ll)'"),
+        (1, "SELECT name FROM Item WHERE name SIMILAR TO '_o%'"),
+        (1, "SELECT name FROM Item WHERE name SIMILAR TO '%l{2}'"),
+        (2, "SELECT name FROM Item WHERE name SIMILAR TO '[AM]%'"),
+        (4, "SELECT name FROM Item WHERE name NOT SIMILAR TO 'Doll'"),
+        // the pattern has to match the whole string
+        (0, "SELECT name FROM Item WHERE name SIMILAR TO 'G'"),
+        (0, "SELECT name FROM Item WHERE name SIMILAR TO 'ria'"),
+        (0, "SELECT name FROM Item WHERE name SIMILAR TO 'Mar|Dol'"),
+    ];
+
+    for (num, sql) in test_cases {
+        count!(num, sql);
+    }
+
+    test!(
+        "SELECT NULL SIMILAR TO 'a' AS l, name NOT SIMILAR TO NULL AS r FROM Item WHERE id = 1",
+        Ok(select_with_null!(l | r; Null Null))
+    );
+
+    test!(
+        "SELECT name FROM Item WHERE id = 1 AND name SIMILAR TO 10",
+        Err(ValueError::SimilarToOnNonString {
+            base: Str("Amelia".to_owned()),
+            pattern: I64(10),
+        }
+        .into())
+    );
+
+    assert!(matches!(
+        run(
+            "SELECT name FROM Item WHERE name SIMILAR TO '(a'",
+            glue,
+            None
+        )
+        .await,
+        Err(Error::Value(ValueError::InvalidRegexPattern(_)))
+    ));
+});
@@ -0,0 +1,34 @@
+use {crate::*, gluesql_core::stats::Stats};
+
+test_case!(stats, async move {
+    run!("CREATE TABLE Item (id INTEGER);");
+    run!("INSERT INTO Item VALUES (1), (2), (3);");
+    run!("UPDATE Item SET id = id + 1 WHERE id > 1;");
+    run!("SELECT * FROM Item;");
+    run!("DELETE FROM Item WHERE id = 1;");
+    run_err!("SELECT * FROM Nothing;");
+
+    let Stats {
+        rows_returned,
+        rows_written,
+        queries_executed,
+        errors,
+        ..
+    } = get_glue!().stats().clone();
+    assert_eq!(rows_returned, 3, "rows returned");
+    assert_eq!(rows_written, 6, "rows written");
+    assert_eq!(queries_executed, 6, "queries executed");
+    assert_eq!(errors, 1, "errors");
+
+    get_glue!().reset_stats();
+    assert_eq!(get_glue!().stats(), &Stats::default());
+
+    for sql in [
+        "SELECT * FROM Item WHERE id = 2;",
+        "SELECT * FROM Item WHERE id = 3;",
+        "SELECT * FROM Item WHERE id = 4;",
+    ] {
+        get_glue!().execute(sql).await.unwrap();
+    }
+    assert_eq!(get_glue!().stats().plan_cache_hits, 2, "plan cache hits");
+});
@@ -5,7 +5,7 @@ use {
         parse_sql::parse_expr,
         prelude::*,
         store::{GStore, GStoreMut},
-        translate::translate_expr,
+        translate::{translate_expr, translate_sql},
     },
 };
 
@@ -23,8 +23,7 @@ pub async fn run<T: GStore + GStoreMut>(
     indexes: Option<Vec<IndexItem>>,
 ) -> Result<Payload> {
     println!("[SQL] {}", sql);
-    let parsed = parse(sql)?;
-    let statement = translate(&parsed[0])?;
+    let statement = translate_sql(sql)?.remove(0);
     let statement = plan(&glue.storage, statement).await?;
 
     test_indexes(&statement, indexes);
@@ -0,0 +1,51 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::UpdateError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(locking, async move {
+    run!("CREATE TABLE TxAccount (id INTEGER, balance INTEGER, version INTEGER);");
+    run!("INSERT INTO TxAccount VALUES (1, 100, 2);");
+
+    run!("BEGIN;");
+    run!("INSERT INTO TxAccount VALUES (2, 200, 1);");
+
+    let payload = get_glue!()
+        .update_versioned(
+            "UPDATE TxAccount SET balance = 120 WHERE id = 1 AND version = 1;",
+            "version",
+        )
+        .await;
+    assert_eq!(
+        payload,
+        Err(UpdateError::OptimisticLockConflict("TxAccount".to_owned()).into()),
+        "stale version in a transaction"
+    );
+
+    let payload = get_glue!()
+        .update_versioned(
+            "UPDATE TxAccount SET balance = 120 WHERE id = 1 AND version = 2;",
+            "version",
+        )
+        .await;
+    assert_eq!(
+        payload,
+        Ok(Payload::Update(1)),
+        "retried in the same transaction"
+    );
+
+    run!("COMMIT;");
+
+    test!(
+        "SELECT * FROM TxAccount;",
+        Ok(select!(
+            id  | balance | version
+            I64 | I64     | I64;
+            1     120       3;
+            2     200       1
+        ))
+    );
+});
@@ -2,10 +2,16 @@ mod alter_table;
 mod basic;
 mod dictionary;
 mod index;
+mod locking;
+mod read_only;
 mod table;
+mod watch;
 
 pub use alter_table::*;
 pub use basic::basic;
 pub use dictionary::dictionary;
 pub use index::*;
+pub use locking::locking;
+pub use read_only::read_only;
 pub use table::*;
+pub use watch::watch;
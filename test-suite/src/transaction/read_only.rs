@@ -0,0 +1,70 @@
+use {
+    crate::*,
+    gluesql_core::{
+        data::{CurrentValues, TimeZone},
+        error::ExecuteError,
+        executor::{execute_with_option, ExecuteOption, Session},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(read_only, async move {
+    run!("CREATE TABLE ReadOnly (id INTEGER);");
+    run!("INSERT INTO ReadOnly VALUES (1);");
+
+    test!("BEGIN READ ONLY;", Ok(Payload::StartTransaction));
+    test!("SELECT id FROM ReadOnly;", Ok(select!(id I64; 1)));
+    test!(
+        "INSERT INTO ReadOnly VALUES (2);",
+        Err(ExecuteError::ReadOnlyTransaction.into())
+    );
+    test!(
+        "DELETE FROM ReadOnly;",
+        Err(ExecuteError::ReadOnlyTransaction.into())
+    );
+    test!(
+        "CREATE TABLE Temp (id INTEGER);",
+        Err(ExecuteError::ReadOnlyTransaction.into())
+    );
+    test!("COMMIT;", Ok(Payload::Commit));
+
+    test!("BEGIN;", Ok(Payload::StartTransaction));
+    test!("SET TRANSACTION READ ONLY;", Ok(Payload::SetTransaction));
+    test!(
+        "UPDATE ReadOnly SET id = 2;",
+        Err(ExecuteError::ReadOnlyTransaction.into())
+    );
+    test!("SET TRANSACTION READ WRITE;", Ok(Payload::SetTransaction));
+    test!("UPDATE ReadOnly SET id = 2;", Ok(Payload::Update(1)));
+    test!("ROLLBACK;", Ok(Payload::Rollback));
+
+    test!("INSERT INTO ReadOnly VALUES (3);", Ok(Payload::Insert(1)));
+
+    let payloads = get_glue!()
+        .with_readonly(|glue| Box::pin(glue.execute("SELECT id FROM ReadOnly;")))
+        .await;
+    assert_eq!(payloads, Ok(vec![select!(id I64; 1; 3)]));
+
+    let payloads = get_glue!()
+        .with_readonly(|glue| Box::pin(glue.execute("DROP TABLE ReadOnly;")))
+        .await;
+    assert_eq!(payloads, Err(ExecuteError::ReadOnlyTransaction.into()));
+
+    let statement = get_glue!()
+        .plan("DELETE FROM ReadOnly;")
+        .await
+        .unwrap()
+        .remove(0);
+    let current_values = CurrentValues::default();
+    let session = Session {
+        current_values: &current_values,
+        time_zone: TimeZone::UTC,
+    };
+    let option = ExecuteOption { read_only: true };
+    assert_eq!(
+        execute_with_option(&mut get_glue!().storage, session, &statement, option).await,
+        Err(ExecuteError::ReadOnlyTransaction.into())
+    );
+
+    test!("DROP TABLE ReadOnly;", Ok(Payload::DropTable));
+});
@@ -0,0 +1,33 @@
+use {
+    crate::{watch::collect_changes, *},
+    gluesql_core::{
+        data::{Row, Value::*},
+        executor::RowChange,
+    },
+    std::rc::Rc,
+};
+
+test_case!(watch, async move {
+    run!("CREATE TABLE TxWatch (id INTEGER);");
+    let mut changes = get_glue!().watch_table("TxWatch");
+
+    run!("BEGIN;");
+    run!("INSERT INTO TxWatch VALUES (1);");
+    run!("ROLLBACK;");
+    assert_eq!(collect_changes(&mut changes), Vec::new(), "rollback");
+
+    run!("BEGIN;");
+    run!("INSERT INTO TxWatch VALUES (2);");
+    assert_eq!(collect_changes(&mut changes), Vec::new(), "before commit");
+    run!("COMMIT;");
+
+    let row = Row::Vec {
+        columns: Rc::from(vec!["id".to_owned()]),
+        values: vec![I64(2)],
+    };
+    assert_eq!(
+        collect_changes(&mut changes),
+        vec![RowChange::Insert(row)],
+        "after commit"
+    );
+});
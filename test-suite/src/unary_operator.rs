@@ -111,6 +111,26 @@ test_case!(unary_operator, async move {
             "SELECT 1000! as v4 FROM Test",
             Err(ValueError::FactorialOverflow.into()),
         ),
+        (
+            "SELECT ~v1 as v1, ~v4 as v4, ~v6 as v6, ~5 as v7, ~(~v1) as v8 FROM Test",
+            Ok(select!(
+                v1  | v4  | v6 | v7  | v8
+                I64 | I64 | I8 | I64 | I64;
+                -11   4     -21  -6    10
+            )),
+        ),
+        (
+            "SELECT ~v2 as v2 FROM Test",
+            Err(ValueError::UnaryBitwiseNotOnNonInteger.into()),
+        ),
+        (
+            "SELECT ~v3 as v3 FROM Test",
+            Err(ValueError::UnaryBitwiseNotOnNonInteger.into()),
+        ),
+        (
+            "SELECT ~'errrr' as v1 FROM Test",
+            Err(ValueError::UnaryBitwiseNotOnNonInteger.into()),
+        ),
     ];
 
     for (sql, expected) in test_cases {
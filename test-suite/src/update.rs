@@ -91,6 +91,18 @@ test_case!(update, async move {
             "SELECT id, num, num2 FROM TableA",
             Ok(select!(id | num | num2; I64 | I64 | I64; 2 2 1; 4 9 9; 2 4 7; 2 7 4))
         ),
+        (
+            "UPDATE TableA AS A SET A.num2 = A.num + 1 WHERE A.id = 4",
+            Ok(Payload::Update(1))
+        ),
+        (
+            "UPDATE TableA A SET num2 = (SELECT rank FROM TableB WHERE TableB.num = A.num) WHERE A.num = 4",
+            Ok(Payload::Update(1))
+        ),
+        (
+            "SELECT id, num, num2 FROM TableA",
+            Ok(select!(id | num | num2; I64 | I64 | I64; 2 2 1; 4 9 10; 2 4 3; 2 7 4))
+        ),
 
     ];
 
@@ -115,7 +127,13 @@ test_case!(update, async move {
             ),
         ),
         (
-            "UPDATE ErrTestTable SET ErrTestTable.id = 1 WHERE id = 1",
+            "UPDATE ErrTestTable SET Other.id = 1 WHERE id = 1",
+            Err(
+                TranslateError::CompoundIdentOnUpdateNotSupported("Other.id = 1".to_owned()).into(),
+            ),
+        ),
+        (
+            "UPDATE ErrTestTable AS E SET ErrTestTable.id = 1 WHERE id = 1",
             Err(TranslateError::CompoundIdentOnUpdateNotSupported(
                 "ErrTestTable.id = 1".to_owned(),
             )
@@ -0,0 +1,126 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, ConstraintError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(check, async move {
+    macro_rules! date {
+        ($date: expr) => {
+            $date.parse().unwrap()
+        };
+    }
+
+    run!(
+        "
+CREATE TABLE Booking (
+    id INTEGER,
+    code TEXT CHECK (UPPER(code) = code),
+    guests INTEGER CONSTRAINT positive_guests CHECK (guests > 0),
+    start_date DATE,
+    end_date DATE,
+    CONSTRAINT valid_period CHECK (end_date > start_date)
+)"
+    );
+
+    test!(
+        "INSERT INTO Booking VALUES (1, 'AB', 2, '2023-01-01', '2023-01-05')",
+        Ok(Payload::Insert(1))
+    );
+    test!(
+        "INSERT INTO Booking VALUES (2, 'CD', NULL, NULL, '2023-01-05')",
+        Ok(Payload::Insert(1))
+    );
+
+    test!(
+        "INSERT INTO Booking VALUES (3, 'ef', 1, '2023-01-01', '2023-01-05')",
+        Err(ConstraintError::CheckFailed {
+            constraint_name: "Booking_code_check".to_owned(),
+            row: vec![
+                I64(3),
+                Str("ef".to_owned()),
+                I64(1),
+                Date(date!("2023-01-01")),
+                Date(date!("2023-01-05")),
+            ],
+        }
+        .into())
+    );
+    test!(
+        "INSERT INTO Booking VALUES (3, 'EF', 1, '2023-01-01', '2023-01-05'), (4, 'GH', 0, NULL, NULL)",
+        Err(ConstraintError::CheckFailed {
+            constraint_name: "positive_guests".to_owned(),
+            row: vec![I64(4), Str("GH".to_owned()), I64(0), Null, Null],
+        }
+        .into())
+    );
+    test!(
+        "INSERT INTO Booking VALUES (3, 'EF', 1, '2023-01-05', '2023-01-01')",
+        Err(ConstraintError::CheckFailed {
+            constraint_name: "valid_period".to_owned(),
+            row: vec![
+                I64(3),
+                Str("EF".to_owned()),
+                I64(1),
+                Date(date!("2023-01-05")),
+                Date(date!("2023-01-01")),
+            ],
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Booking SET guests = guests - 2 WHERE id = 1",
+        Err(ConstraintError::CheckFailed {
+            constraint_name: "positive_guests".to_owned(),
+            row: vec![
+                I64(1),
+                Str("AB".to_owned()),
+                I64(0),
+                Date(date!("2023-01-01")),
+                Date(date!("2023-01-05")),
+            ],
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Booking SET end_date = '2023-01-10' WHERE id = 1",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "SELECT id, guests, end_date FROM Booking",
+        Ok(select_with_null!(
+            id     | guests | end_date;
+            I64(1)   I64(2)   Date(date!("2023-01-10"));
+            I64(2)   Null     Date(date!("2023-01-05"))
+        ))
+    );
+
+    run!("CREATE TABLE Span (lo INTEGER, hi INTEGER, CHECK (lo < hi), CHECK (hi - lo <= 10))");
+    test!(
+        "INSERT INTO Span VALUES (1, 20)",
+        Err(ConstraintError::CheckFailed {
+            constraint_name: "Span_check1".to_owned(),
+            row: vec![I64(1), I64(20)],
+        }
+        .into())
+    );
+
+    test!(
+        "CREATE TABLE Invalid (id INTEGER CHECK (num > 0))",
+        Err(AlterError::UnavailableCheckReference {
+            check: "Invalid_id_check".to_owned(),
+            reference: "num".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "ALTER TABLE Booking DROP COLUMN start_date",
+        Err(AlterError::DependentObjectsExist {
+            column_name: "start_date".to_owned(),
+            dependents: vec!["valid_period".to_owned()],
+        }
+        .into())
+    );
+});
@@ -0,0 +1,158 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, ConstraintError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(foreign_key, async move {
+    run!("CREATE TABLE Author (id INTEGER PRIMARY KEY, code TEXT UNIQUE, name TEXT)");
+    run!(
+        "CREATE TABLE Post (id INTEGER PRIMARY KEY, author_id INTEGER REFERENCES Author (id) ON DELETE CASCADE)"
+    );
+    run!(
+        "
+CREATE TABLE Reply (
+    id INTEGER PRIMARY KEY,
+    post_id INTEGER,
+    CONSTRAINT reply_post FOREIGN KEY (post_id) REFERENCES Post (id) ON DELETE CASCADE
+)"
+    );
+    run!(
+        "CREATE TABLE Reaction (id INTEGER, reply_id INTEGER REFERENCES Reply (id) ON DELETE SET NULL)"
+    );
+    run!("CREATE TABLE Badge (id INTEGER, author_code TEXT REFERENCES Author (code) ON DELETE RESTRICT)");
+
+    run!("INSERT INTO Author VALUES (1, 'a', 'Alice'), (2, 'b', 'Bob'), (3, 'c', 'Carol')");
+    run!("INSERT INTO Post VALUES (1, 1), (2, 1), (3, 2)");
+    run!("INSERT INTO Reply VALUES (1, 1), (2, 2), (3, 3)");
+    run!("INSERT INTO Reaction VALUES (1, 1), (2, 3), (3, NULL)");
+    run!("INSERT INTO Badge VALUES (1, 'c')");
+
+    test!(
+        "INSERT INTO Post VALUES (4, 9)",
+        Err(ConstraintError::ForeignKeyViolation {
+            constraint_name: "Post_author_id_fkey".to_owned(),
+            value: I64(9),
+        }
+        .into())
+    );
+    test!("INSERT INTO Post VALUES (4, NULL)", Ok(Payload::Insert(1)));
+    test!(
+        "INSERT INTO Badge VALUES (2, 'x')",
+        Err(ConstraintError::ForeignKeyViolation {
+            constraint_name: "Badge_author_code_fkey".to_owned(),
+            value: Str("x".to_owned()),
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Reply SET post_id = 7 WHERE id = 1",
+        Err(ConstraintError::ForeignKeyViolation {
+            constraint_name: "reply_post".to_owned(),
+            value: I64(7),
+        }
+        .into())
+    );
+
+    test!(
+        "UPDATE Author SET code = 'z' WHERE id = 3",
+        Err(ConstraintError::ReferencedRowExists {
+            constraint_name: "Badge_author_code_fkey".to_owned(),
+            value: Str("c".to_owned()),
+        }
+        .into())
+    );
+    test!(
+        "UPDATE Author SET code = 'y' WHERE id = 2",
+        Ok(Payload::Update(1))
+    );
+    test!(
+        "DELETE FROM Author WHERE id = 3",
+        Err(ConstraintError::ReferencedRowExists {
+            constraint_name: "Badge_author_code_fkey".to_owned(),
+            value: Str("c".to_owned()),
+        }
+        .into())
+    );
+
+    test!("DELETE FROM Author WHERE id = 1", Ok(Payload::Delete(1)));
+    test!(
+        "SELECT id, author_id FROM Post ORDER BY id",
+        Ok(select_with_null!(
+            id     | author_id;
+            I64(3)   I64(2);
+            I64(4)   Null
+        ))
+    );
+    test!(
+        "SELECT id, post_id FROM Reply ORDER BY id",
+        Ok(select!(id | post_id I64 | I64; 3 3))
+    );
+    test!(
+        "SELECT id, reply_id FROM Reaction ORDER BY id",
+        Ok(select_with_null!(
+            id     | reply_id;
+            I64(1)   Null;
+            I64(2)   I64(3);
+            I64(3)   Null
+        ))
+    );
+
+    test!(
+        "CREATE TABLE Node (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES Node (id))",
+        Err(AlterError::CircularForeignKey("Node_parent_id_fkey".to_owned()).into())
+    );
+    test!(
+        "CREATE TABLE Strict (author_id INTEGER NOT NULL REFERENCES Author (id) ON DELETE SET NULL)",
+        Err(AlterError::SetNullOnNotNullColumn {
+            foreign_key: "Strict_author_id_fkey".to_owned(),
+            column: "author_id".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "CREATE TABLE Loose (author_name TEXT REFERENCES Author (name))",
+        Err(AlterError::ReferencedColumnNotUnique {
+            foreign_key: "Loose_author_name_fkey".to_owned(),
+            table_name: "Author".to_owned(),
+            column: "name".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "CREATE TABLE Lost (missing_id INTEGER REFERENCES Missing (id))",
+        Err(AlterError::ReferencedTableNotFound {
+            foreign_key: "Lost_missing_id_fkey".to_owned(),
+            table_name: "Missing".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "CREATE TABLE Pair (a INTEGER, b TEXT, FOREIGN KEY (a, b) REFERENCES Author (id, code))",
+        Err(TranslateError::CompositeForeignKeyNotSupported.into())
+    );
+    test!(
+        "CREATE TABLE Renamed (author_id INTEGER REFERENCES Author (id) ON UPDATE CASCADE)",
+        Err(TranslateError::UnsupportedReferentialAction("ON UPDATE CASCADE".to_owned()).into())
+    );
+
+    test!(
+        "DROP TABLE Reply",
+        Err(AlterError::ReferencedTableCannotBeDropped {
+            table_name: "Reply".to_owned(),
+            foreign_key: "Reaction_reply_id_fkey".to_owned(),
+        }
+        .into())
+    );
+    test!(
+        "ALTER TABLE Post DROP COLUMN author_id",
+        Err(AlterError::DependentObjectsExist {
+            column_name: "author_id".to_owned(),
+            dependents: vec!["Post_author_id_fkey".to_owned()],
+        }
+        .into())
+    );
+    test!("DROP TABLE Reaction, Reply", Ok(Payload::DropTable));
+});
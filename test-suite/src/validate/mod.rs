@@ -1,2 +1,4 @@
+pub mod check;
+pub mod foreign_key;
 pub mod types;
 pub mod unique;
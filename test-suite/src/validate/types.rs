@@ -51,6 +51,14 @@ test_case!(types, async move {
             }
             .into()),
         ),
+        (
+            "UPDATE TableC SET uid = SUBSTR('123', 1) WHERE uid = 1",
+            Err(ValueError::IncompatibleDataType {
+                data_type: DataType::Int,
+                value: Value::Str("123".to_owned()),
+            }
+            .into()),
+        ),
         (
             "UPDATE TableC SET uid = NULL;",
             Err(ValueError::NullValueOnNotNullField.into()),
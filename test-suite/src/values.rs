@@ -141,6 +141,14 @@ test_case!(values, async move {
             "INSERT INTO Items (id) VALUES (1);",
             Ok(Payload::Insert(1))
         ),
+        (
+            "INSERT INTO Items VALUES (2, 'glue', 'DONE');",
+            Ok(Payload::Insert(1))
+        ),
+        (
+            "INSERT INTO Items VALUES (3, 'sql');",
+            Ok(Payload::Insert(1))
+        ),
         (
             "INSERT INTO Items (id2) VALUES (1);",
             Err(InsertError::WrongColumnName("id2".to_owned()).into()),
@@ -165,4 +173,14 @@ test_case!(values, async move {
     for (sql, expected) in test_cases {
         test!(sql, expected);
     }
+
+    test!(
+        "SELECT id, name, status FROM Items",
+        Ok(select_with_null!(
+            id     | name                  | status;
+            I64(1)   Null                    Str("ACTIVE".to_owned());
+            I64(2)   Str("glue".to_owned())  Str("DONE".to_owned());
+            I64(3)   Str("sql".to_owned())   Str("ACTIVE".to_owned())
+        ))
+    );
 });
@@ -0,0 +1,120 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, PlanError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(view, async move {
+    run!("CREATE TABLE Item (id INTEGER, category TEXT, price INTEGER)");
+    run!("CREATE TABLE Category (name TEXT, label TEXT)");
+    run!(
+        "
+        INSERT INTO Item VALUES
+            (1, 'fruit', 3),
+            (2, 'fruit', 9),
+            (3, 'meat', 20);
+    "
+    );
+    run!("INSERT INTO Category VALUES ('fruit', 'Fruits'), ('meat', 'Meats')");
+
+    test!(
+        "CREATE VIEW Summary AS SELECT category, SUM(price) AS total FROM Item GROUP BY category",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT category, total FROM Summary ORDER BY category",
+        Ok(select!(
+            category       | total;
+            Str            | I64;
+            "fruit".to_owned() 12;
+            "meat".to_owned()  20
+        ))
+    );
+
+    run!(
+        "
+        CREATE VIEW Labeled (item_id, label) AS
+        SELECT Item.id, Category.label FROM Item JOIN Category ON Item.category = Category.name
+    "
+    );
+    test!(
+        "SELECT * FROM Labeled WHERE item_id > 1 ORDER BY item_id",
+        Ok(select!(
+            item_id | label;
+            I64     | Str;
+            2         "Fruits".to_owned();
+            3         "Meats".to_owned()
+        ))
+    );
+
+    run!("CREATE VIEW Expensive AS SELECT category FROM Summary WHERE total > 15");
+    test!(
+        "SELECT e.category FROM Expensive AS e",
+        Ok(select!(category Str; "meat".to_owned()))
+    );
+    test!(
+        "SELECT id FROM Item WHERE category IN (SELECT category FROM Expensive)",
+        Ok(select!(id I64; 3))
+    );
+
+    test!(
+        "CREATE VIEW Summary AS SELECT id FROM Item",
+        Err(AlterError::ViewAlreadyExists("Summary".to_owned()).into())
+    );
+    test!(
+        "CREATE VIEW Item AS SELECT id FROM Category",
+        Err(AlterError::TableAlreadyExists("Item".to_owned()).into())
+    );
+    test!(
+        "CREATE VIEW Missing AS SELECT id FROM Nothing",
+        Err(AlterError::TableNotFound("Nothing".to_owned()).into())
+    );
+    test!(
+        "CREATE OR REPLACE VIEW Summary AS
+         SELECT category, COUNT(*) AS total FROM Item GROUP BY category",
+        Ok(Payload::Create)
+    );
+    test!(
+        "SELECT category, total FROM Summary ORDER BY category",
+        Ok(select!(
+            category       | total;
+            Str            | I64;
+            "fruit".to_owned() 2;
+            "meat".to_owned()  1
+        ))
+    );
+
+    test!(
+        "UPDATE Summary SET total = 0",
+        Err(PlanError::ViewNotUpdatable("Summary".to_owned()).into())
+    );
+    test!(
+        "INSERT INTO Summary VALUES ('fish', 1)",
+        Err(PlanError::ViewNotUpdatable("Summary".to_owned()).into())
+    );
+    test!(
+        "DROP TABLE Summary",
+        Err(AlterError::NotATable("Summary".to_owned()).into())
+    );
+    test!(
+        "DROP VIEW Item",
+        Err(AlterError::NotAView("Item".to_owned()).into())
+    );
+
+    run!("CREATE VIEW First AS SELECT id FROM Item");
+    run!("CREATE VIEW Second AS SELECT id FROM First");
+    run!("CREATE OR REPLACE VIEW First AS SELECT id FROM Second");
+    test!(
+        "SELECT id FROM First",
+        Err(PlanError::ViewDepthExceeded(16).into())
+    );
+
+    test!("DROP VIEW First, Second", Ok(Payload::DropView));
+    test!(
+        "DROP VIEW First",
+        Err(AlterError::ViewNotFound("First".to_owned()).into())
+    );
+    test!("DROP VIEW IF EXISTS First", Ok(Payload::DropView));
+});
@@ -0,0 +1,63 @@
+use {
+    crate::*,
+    futures::{FutureExt, Stream, StreamExt},
+    gluesql_core::{
+        data::{Row, Value::*},
+        executor::RowChange,
+    },
+    std::{collections::HashMap, rc::Rc},
+};
+
+pub fn collect_changes(stream: &mut (impl Stream<Item = RowChange> + Unpin)) -> Vec<RowChange> {
+    let mut changes = Vec::new();
+    while let Some(Some(change)) = stream.next().now_or_never() {
+        changes.push(change);
+    }
+
+    changes
+}
+
+test_case!(watch_table, async move {
+    run!("CREATE TABLE Item (id INTEGER, name TEXT);");
+    run!("CREATE TABLE Other (id INTEGER);");
+    run!("CREATE TABLE Logs;");
+
+    let mut items = get_glue!().watch_table("Item");
+    let mut logs = get_glue!().watch_table("Logs");
+
+    run!("INSERT INTO Item VALUES (1, 'a'), (2, 'b');");
+    run!("INSERT INTO Other VALUES (1);");
+    run!("UPDATE Item SET name = 'c' WHERE id = 2;");
+    run!("DELETE FROM Item WHERE id = 1;");
+    run!(r#"INSERT INTO Logs VALUES ('{"id": 1}');"#);
+
+    let columns = Rc::from(vec!["id".to_owned(), "name".to_owned()]);
+    let row = |id, name: &str| Row::Vec {
+        columns: Rc::clone(&columns),
+        values: vec![I64(id), Str(name.to_owned())],
+    };
+    assert_eq!(
+        collect_changes(&mut items),
+        vec![
+            RowChange::Insert(row(1, "a")),
+            RowChange::Insert(row(2, "b")),
+            RowChange::Update(row(2, "b"), row(2, "c")),
+            RowChange::Delete(row(1, "a")),
+        ]
+    );
+    assert_eq!(
+        collect_changes(&mut logs),
+        vec![RowChange::Insert(Row::Map(HashMap::from([(
+            "id".to_owned(),
+            I64(1)
+        )])))]
+    );
+
+    drop(logs);
+    run!("INSERT INTO Logs VALUES ('{\"id\": 2}');");
+    run!("UPDATE Item SET id = 3;");
+    assert_eq!(
+        collect_changes(&mut items),
+        vec![RowChange::Update(row(2, "c"), row(3, "c"))]
+    );
+});
@@ -0,0 +1,131 @@
+use {crate::*, gluesql_core::prelude::Value::*};
+
+test_case!(aggregate, async move {
+    run!(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            val INTEGER NULL,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Item (id, val) VALUES
+            (1, 10),
+            (2, 20),
+            (3, NULL),
+            (4, 30);
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT id, COUNT(*) OVER () FROM Item",
+            select!(
+                id  | "COUNT(*) OVER ()"
+                I64 | I64;
+                1     4;
+                2     4;
+                3     4;
+                4     4
+            ),
+        ),
+        (
+            "SELECT id, COUNT(val) OVER () FROM Item",
+            select!(
+                id  | "COUNT(val) OVER ()"
+                I64 | I64;
+                1     3;
+                2     3;
+                3     3;
+                4     3
+            ),
+        ),
+        (
+            "SELECT id, SUM(val) OVER () FROM Item WHERE val IS NOT NULL",
+            select!(
+                id  | "SUM(val) OVER ()"
+                I64 | I64;
+                1     60;
+                2     60;
+                4     60
+            ),
+        ),
+        (
+            "SELECT id, AVG(val) OVER () FROM Item WHERE val IS NOT NULL",
+            select!(
+                id  | "AVG(val) OVER ()"
+                I64 | F64;
+                1     20.0;
+                2     20.0;
+                4     20.0
+            ),
+        ),
+        (
+            "SELECT id, val * 100 / SUM(val) OVER () AS ratio FROM Item WHERE id < 3",
+            select!(
+                id  | ratio
+                I64 | I64;
+                1     33;
+                2     66
+            ),
+        ),
+        (
+            "SELECT id, ROUND(SUM(val) OVER ()) AS total FROM Item WHERE val IS NOT NULL",
+            select!(
+                id  | total
+                I64 | I64;
+                1     60;
+                2     60;
+                4     60
+            ),
+        ),
+        (
+            "SELECT id, ROUND(AVG(val) OVER () / 3) AS third FROM Item WHERE val IS NOT NULL",
+            select!(
+                id  | third
+                I64 | F64;
+                1     7.0;
+                2     7.0;
+                4     7.0
+            ),
+        ),
+        (
+            "
+            SELECT id FROM Item
+            WHERE val IS NOT NULL
+            ORDER BY SUM(val) OVER (ORDER BY val DESC)
+            ",
+            select!(
+                id
+                I64;
+                4;
+                2;
+                1
+            ),
+        ),
+        (
+            "
+            SELECT id, SUM(val) OVER (ORDER BY id) AS running FROM Item
+            WHERE val IS NOT NULL
+            ORDER BY SUM(val) OVER (ORDER BY id) DESC
+            ",
+            select!(
+                id  | running
+                I64 | I64;
+                4     60;
+                2     30;
+                1     10
+            ),
+        ),
+        (
+            "SELECT id, SUM(val) OVER () FROM Item WHERE id > 10",
+            select!(id | "SUM(val) OVER ()"),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+});
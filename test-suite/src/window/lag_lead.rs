@@ -0,0 +1,81 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError},
+        prelude::Value::*,
+    },
+};
+
+test_case!(lag_lead, async move {
+    run!(
+        "
+        CREATE TABLE Sales (
+            id INTEGER,
+            region TEXT,
+            amount INTEGER
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Sales (id, region, amount) VALUES
+            (1, 'east', 10),
+            (2, 'east', 30),
+            (3, 'east', 20),
+            (4, 'west', 30),
+            (5, 'west', 30),
+            (6, 'west', 5),
+            (7, 'east', 30);
+    "
+    );
+
+    test!(
+        "
+        SELECT
+            id,
+            LAG(amount) OVER (PARTITION BY region ORDER BY id) AS prev,
+            LEAD(amount, 2, 0) OVER (PARTITION BY region ORDER BY id) AS next2
+        FROM Sales
+        ",
+        Ok(select_with_null!(
+            id     | prev      | next2;
+            I64(1)   Null        I64(20);
+            I64(2)   I64(10)     I64(30);
+            I64(3)   I64(30)     I64(0);
+            I64(4)   Null        I64(5);
+            I64(5)   I64(30)     I64(0);
+            I64(6)   I64(30)     I64(0);
+            I64(7)   I64(20)     I64(0)
+        ))
+    );
+    test!(
+        "
+        SELECT id, LAG(id, 0) OVER (ORDER BY amount DESC) AS same, LEAD(id) OVER () AS next
+        FROM Sales WHERE region = 'west'
+        ",
+        Ok(select_with_null!(
+            id     | same     | next;
+            I64(4)   I64(4)     I64(5);
+            I64(5)   I64(5)     I64(6);
+            I64(6)   I64(6)     Null
+        ))
+    );
+    test!(
+        "SELECT LAG(amount, -1) OVER (ORDER BY id) FROM Sales",
+        Err(EvaluateError::FunctionRequiresUSizeValue("LAG".to_owned()).into())
+    );
+    test!(
+        "SELECT LEAD(amount, 'a') OVER (ORDER BY id) FROM Sales",
+        Err(EvaluateError::FunctionRequiresIntegerValue("LEAD".to_owned()).into())
+    );
+    test!(
+        "SELECT LAG() OVER (ORDER BY id) FROM Sales",
+        Err(TranslateError::FunctionArgsLengthNotWithinRange {
+            name: "LAG".to_owned(),
+            expected_minimum: 1,
+            expected_maximum: 3,
+            found: 0,
+        }
+        .into())
+    );
+});
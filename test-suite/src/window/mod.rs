@@ -0,0 +1,4 @@
+pub mod aggregate;
+pub mod lag_lead;
+pub mod order_by;
+pub mod partition_by;
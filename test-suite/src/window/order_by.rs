@@ -0,0 +1,107 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::Value::*},
+};
+
+test_case!(order_by, async move {
+    run!(
+        "
+        CREATE TABLE Score (
+            id INTEGER,
+            a INTEGER,
+            b INTEGER NULL,
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Score (id, a, b) VALUES
+            (1, 1, 10),
+            (2, 1, 20),
+            (3, 2, NULL),
+            (4, 2, 5),
+            (5, 1, NULL);
+    "
+    );
+
+    let test_cases = [
+        (
+            "SELECT id, ROW_NUMBER() OVER (ORDER BY a ASC, b DESC NULLS LAST) AS rn FROM Score",
+            select!(
+                id  | rn
+                I64 | I64;
+                1     2;
+                2     1;
+                3     5;
+                4     4;
+                5     3
+            ),
+        ),
+        (
+            "SELECT id, ROW_NUMBER() OVER (ORDER BY a DESC, b NULLS FIRST) AS rn FROM Score",
+            select!(
+                id  | rn
+                I64 | I64;
+                1     4;
+                2     5;
+                3     1;
+                4     2;
+                5     3
+            ),
+        ),
+        (
+            "SELECT id, SUM(b) OVER (ORDER BY a) AS total FROM Score",
+            select!(
+                id  | total
+                I64 | I64;
+                1     30;
+                2     30;
+                3     35;
+                4     35;
+                5     30
+            ),
+        ),
+        (
+            "SELECT id, COUNT(*) OVER (ORDER BY a, b) AS cnt FROM Score",
+            select!(
+                id  | cnt
+                I64 | I64;
+                1     1;
+                2     2;
+                3     5;
+                4     4;
+                5     3
+            ),
+        ),
+        (
+            "
+            SELECT id, ROW_NUMBER() OVER (ORDER BY b DESC NULLS LAST, id) AS rn
+            FROM Score
+            ORDER BY id DESC
+            ",
+            select!(
+                id  | rn
+                I64 | I64;
+                5     5;
+                4     3;
+                3     4;
+                2     1;
+                1     2
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+
+    test!(
+        "SELECT ROW_NUMBER(id) OVER (ORDER BY id) FROM Score",
+        Err(TranslateError::FunctionArgsLengthNotMatching {
+            name: "ROW_NUMBER".to_owned(),
+            expected: 0,
+            found: 1,
+        }
+        .into())
+    );
+});
@@ -0,0 +1,99 @@
+use {crate::*, gluesql_core::prelude::Value::*};
+
+test_case!(partition_by, async move {
+    run!(
+        "
+        CREATE TABLE Sales (
+            id INTEGER,
+            region TEXT,
+            year INTEGER,
+            amount INTEGER
+        );
+    "
+    );
+    run!(
+        "
+        INSERT INTO Sales (id, region, year, amount) VALUES
+            (1, 'east', 2020, 10),
+            (2, 'east', 2020, 30),
+            (3, 'east', 2021, 20),
+            (4, 'west', 2020, 30),
+            (5, 'west', 2020, 30),
+            (6, 'west', 2021, 5),
+            (7, 'east', 2020, 30);
+    "
+    );
+
+    let test_cases = [
+        (
+            "
+            SELECT
+                id,
+                ROW_NUMBER() OVER (PARTITION BY region ORDER BY amount DESC) AS rn,
+                RANK() OVER (PARTITION BY region ORDER BY amount DESC) AS rk,
+                DENSE_RANK() OVER (PARTITION BY region ORDER BY amount DESC) AS drk
+            FROM Sales
+            ",
+            select!(
+                id  | rn  | rk  | drk
+                I64 | I64 | I64 | I64;
+                1     4     4     3;
+                2     1     1     1;
+                3     3     3     2;
+                4     1     1     1;
+                5     2     1     1;
+                6     3     3     2;
+                7     2     1     1
+            ),
+        ),
+        (
+            "
+            SELECT
+                id,
+                COUNT(*) OVER (PARTITION BY region, year) AS cnt,
+                SUM(amount) OVER (PARTITION BY region, year) AS total,
+                ROW_NUMBER() OVER (PARTITION BY region, year ORDER BY id DESC) AS rn
+            FROM Sales
+            ",
+            select!(
+                id  | cnt | total | rn
+                I64 | I64 | I64   | I64;
+                1     3     70      3;
+                2     3     70      2;
+                3     1     20      1;
+                4     2     60      2;
+                5     2     60      1;
+                6     1     5       1;
+                7     3     70      1
+            ),
+        ),
+        (
+            "SELECT id, RANK() OVER (ORDER BY amount) AS rk FROM Sales ORDER BY id DESC",
+            select!(
+                id  | rk
+                I64 | I64;
+                7     4;
+                6     1;
+                5     4;
+                4     4;
+                3     3;
+                2     4;
+                1     2
+            ),
+        ),
+        (
+            "SELECT id, DENSE_RANK() OVER () AS drk FROM Sales WHERE region = 'west'",
+            select!(
+                id  | drk
+                I64 | I64;
+                4     1;
+                5     1;
+                6     1
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        test!(sql, Ok(expected));
+    }
+});